@@ -0,0 +1,13 @@
+// GENERATED CODE! DO NOT MODIFY BY HAND!
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type CheckpointCreatedEvent = { name: string, 
+/**
+ * File the checkpoint entry was appended to.
+ */
+path: string, 
+/**
+ * Ghost commit capturing the working tree, when the cwd is a Git repository.
+ */
+ghost_commit: string | null, };
@@ -0,0 +1,13 @@
+// GENERATED CODE! DO NOT MODIFY BY HAND!
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { GetPromptResult } from "./GetPromptResult";
+
+/**
+ * Response payload for `Op::GetMcpPrompt`.
+ */
+export type GetMcpPromptResponseEvent = { server: string, name: string, 
+/**
+ * Messages to expand into the user turn. Note this could be an error.
+ */
+result: { Ok : GetPromptResult } | { Err : string }, };
@@ -0,0 +1,9 @@
+// GENERATED CODE! DO NOT MODIFY BY HAND!
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { PromptMessage } from "./PromptMessage";
+
+/**
+ * The server's response to a prompts/get request.
+ */
+export type GetPromptResult = { description?: string, messages: Array<PromptMessage>, };
@@ -0,0 +1,5 @@
+// GENERATED CODE! DO NOT MODIFY BY HAND!
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type McpToolCallApprovalDecision = "approved" | "approved_for_session" | "approved_and_persisted" | "denied" | "cancelled";
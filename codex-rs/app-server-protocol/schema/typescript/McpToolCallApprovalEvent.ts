@@ -0,0 +1,12 @@
+// GENERATED CODE! DO NOT MODIFY BY HAND!
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { McpInvocation } from "./McpInvocation";
+import type { McpToolCallApprovalDecision } from "./McpToolCallApprovalDecision";
+import type { McpToolCallApprovalSource } from "./McpToolCallApprovalSource";
+
+export type McpToolCallApprovalEvent = { 
+/**
+ * Identifier of the tool call the decision applies to.
+ */
+call_id: string, invocation: McpInvocation, decision: McpToolCallApprovalDecision, source: McpToolCallApprovalSource, };
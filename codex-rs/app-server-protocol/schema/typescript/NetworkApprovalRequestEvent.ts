@@ -0,0 +1,34 @@
+// GENERATED CODE! DO NOT MODIFY BY HAND!
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Raised by the network proxy when a sandboxed process tries to reach a host
+ * that is not in `network.allowed_domains`. The connection stays paused until
+ * the client answers with `Op::NetworkApproval`.
+ */
+export type NetworkApprovalRequestEvent = { 
+/**
+ * Identifier used to answer this request.
+ */
+id: string, 
+/**
+ * Turn ID whose sandboxed process triggered the request.
+ */
+turn_id: string, 
+/**
+ * Destination host, normalized to lowercase.
+ */
+host: string, 
+/**
+ * Destination port.
+ */
+port: number, 
+/**
+ * Proxy protocol that carried the request (`http`, `https_connect`, `socks5_tcp`, `socks5_udp`).
+ */
+protocol: string, 
+/**
+ * HTTP method, when the protocol exposes one.
+ */
+method?: string, };
@@ -0,0 +1,12 @@
+// GENERATED CODE! DO NOT MODIFY BY HAND!
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * One message of a sampling request, reduced to its text.
+ */
+export type SamplingMessage = { 
+/**
+ * `user` or `assistant`.
+ */
+role: string, text: string, };
@@ -0,0 +1,23 @@
+// GENERATED CODE! DO NOT MODIFY BY HAND!
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { SamplingMessage } from "./SamplingMessage";
+
+/**
+ * An MCP server asks Codex to run a completion (`sampling/createMessage`) on
+ * its behalf. The request only proceeds once the user approves it.
+ */
+export type SamplingRequestEvent = { server_name: string, id: string | number, 
+/**
+ * Conversation the server wants completed.
+ */
+messages: Array<SamplingMessage>, 
+/**
+ * System prompt requested by the server, if any.
+ */
+system_prompt?: string, 
+/**
+ * Output cap for the completion, after applying the server's configured
+ * `sampling.max_tokens`.
+ */
+max_tokens: number, };
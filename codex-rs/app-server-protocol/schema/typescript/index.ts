@@ -27,6 +27,7 @@ export type { ByteRange } from "./ByteRange";
 export type { CallToolResult } from "./CallToolResult";
 export type { CancelLoginChatGptParams } from "./CancelLoginChatGptParams";
 export type { CancelLoginChatGptResponse } from "./CancelLoginChatGptResponse";
+export type { CheckpointCreatedEvent } from "./CheckpointCreatedEvent";
 export type { ClientInfo } from "./ClientInfo";
 export type { ClientNotification } from "./ClientNotification";
 export type { ClientRequest } from "./ClientRequest";
@@ -82,6 +83,8 @@ export type { GetAuthStatusResponse } from "./GetAuthStatusResponse";
 export type { GetConversationSummaryParams } from "./GetConversationSummaryParams";
 export type { GetConversationSummaryResponse } from "./GetConversationSummaryResponse";
 export type { GetHistoryEntryResponseEvent } from "./GetHistoryEntryResponseEvent";
+export type { GetMcpPromptResponseEvent } from "./GetMcpPromptResponseEvent";
+export type { GetPromptResult } from "./GetPromptResult";
 export type { GetUserAgentResponse } from "./GetUserAgentResponse";
 export type { GetUserSavedConfigResponse } from "./GetUserSavedConfigResponse";
 export type { GhostCommit } from "./GhostCommit";
@@ -101,6 +104,7 @@ export type { ItemStartedEvent } from "./ItemStartedEvent";
 export type { ListConversationsParams } from "./ListConversationsParams";
 export type { ListConversationsResponse } from "./ListConversationsResponse";
 export type { ListCustomPromptsResponseEvent } from "./ListCustomPromptsResponseEvent";
+export type { ListMcpPromptsResponseEvent } from "./ListMcpPromptsResponseEvent";
 export type { ListRemoteSkillsResponseEvent } from "./ListRemoteSkillsResponseEvent";
 export type { ListSkillsResponseEvent } from "./ListSkillsResponseEvent";
 export type { LocalShellAction } from "./LocalShellAction";
@@ -118,11 +122,15 @@ export type { McpStartupCompleteEvent } from "./McpStartupCompleteEvent";
 export type { McpStartupFailure } from "./McpStartupFailure";
 export type { McpStartupStatus } from "./McpStartupStatus";
 export type { McpStartupUpdateEvent } from "./McpStartupUpdateEvent";
+export type { McpToolCallApprovalDecision } from "./McpToolCallApprovalDecision";
+export type { McpToolCallApprovalEvent } from "./McpToolCallApprovalEvent";
+export type { McpToolCallApprovalSource } from "./McpToolCallApprovalSource";
 export type { McpToolCallBeginEvent } from "./McpToolCallBeginEvent";
 export type { McpToolCallEndEvent } from "./McpToolCallEndEvent";
 export type { MessagePhase } from "./MessagePhase";
 export type { ModeKind } from "./ModeKind";
 export type { NetworkAccess } from "./NetworkAccess";
+export type { NetworkApprovalRequestEvent } from "./NetworkApprovalRequestEvent";
 export type { NewConversationParams } from "./NewConversationParams";
 export type { NewConversationResponse } from "./NewConversationResponse";
 export type { ParsedCommand } from "./ParsedCommand";
@@ -134,6 +142,10 @@ export type { PlanItem } from "./PlanItem";
 export type { PlanItemArg } from "./PlanItemArg";
 export type { PlanType } from "./PlanType";
 export type { Profile } from "./Profile";
+export type { Prompt } from "./Prompt";
+export type { PromptArgument } from "./PromptArgument";
+export type { PromptMessage } from "./PromptMessage";
+export type { PromptMessageRole } from "./PromptMessageRole";
 export type { RateLimitSnapshot } from "./RateLimitSnapshot";
 export type { RateLimitWindow } from "./RateLimitWindow";
 export type { RawResponseItemEvent } from "./RawResponseItemEvent";
@@ -164,6 +176,8 @@ export type { ReviewLineRange } from "./ReviewLineRange";
 export type { ReviewOutputEvent } from "./ReviewOutputEvent";
 export type { ReviewRequest } from "./ReviewRequest";
 export type { ReviewTarget } from "./ReviewTarget";
+export type { SamplingMessage } from "./SamplingMessage";
+export type { SamplingRequestEvent } from "./SamplingRequestEvent";
 export type { SandboxMode } from "./SandboxMode";
 export type { SandboxPolicy } from "./SandboxPolicy";
 export type { SandboxSettings } from "./SandboxSettings";
@@ -0,0 +1,21 @@
+// GENERATED CODE! DO NOT MODIFY BY HAND!
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type NetworkAccessRequestApprovalParams = { threadId: string, turnId: string, 
+/**
+ * Identifier of this network access request.
+ */
+requestId: string, 
+/**
+ * Destination host the sandboxed process tried to reach.
+ */
+host: string, port: number, 
+/**
+ * Proxy protocol that carried the request (e.g. `http`, `https_connect`).
+ */
+protocol: string, 
+/**
+ * HTTP method, when the protocol exposes one.
+ */
+method?: string | null, };
@@ -0,0 +1,20 @@
+// GENERATED CODE! DO NOT MODIFY BY HAND!
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type ThreadSearchParams = { 
+/**
+ * Whitespace-separated terms. A thread matches when one of its user
+ * messages, agent messages, commands or touched file paths contains
+ * every term (case-insensitive substring match).
+ */
+query: string, 
+/**
+ * Optional maximum number of threads; defaults to a reasonable server-side value.
+ */
+limit?: number | null, 
+/**
+ * Optional archived filter; when set to true, only archived threads are searched.
+ * If false or null, only non-archived threads are searched.
+ */
+archived?: boolean | null, };
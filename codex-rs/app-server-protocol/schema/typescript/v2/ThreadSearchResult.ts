@@ -0,0 +1,20 @@
+// GENERATED CODE! DO NOT MODIFY BY HAND!
+
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ByteRange } from "./ByteRange";
+import type { Thread } from "./Thread";
+import type { ThreadSearchMatchKind } from "./ThreadSearchMatchKind";
+
+export type ThreadSearchResult = { thread: Thread, 
+/**
+ * What kind of recorded text the snippet was taken from.
+ */
+matchKind: ThreadSearchMatchKind, 
+/**
+ * Single-line excerpt of the best matching text in the thread.
+ */
+snippet: string, 
+/**
+ * Byte ranges of `snippet` that matched a query term, in order.
+ */
+highlights: Array<ByteRange>, };
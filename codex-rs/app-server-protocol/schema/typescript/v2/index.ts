@@ -92,6 +92,9 @@ export type { Model } from "./Model";
 export type { ModelListParams } from "./ModelListParams";
 export type { ModelListResponse } from "./ModelListResponse";
 export type { NetworkAccess } from "./NetworkAccess";
+export type { NetworkAccessApprovalDecision } from "./NetworkAccessApprovalDecision";
+export type { NetworkAccessRequestApprovalParams } from "./NetworkAccessRequestApprovalParams";
+export type { NetworkAccessRequestApprovalResponse } from "./NetworkAccessRequestApprovalResponse";
 export type { NetworkRequirements } from "./NetworkRequirements";
 export type { OverriddenMetadata } from "./OverriddenMetadata";
 export type { PatchApplyStatus } from "./PatchApplyStatus";
@@ -140,6 +143,9 @@ export type { ThreadArchiveParams } from "./ThreadArchiveParams";
 export type { ThreadArchiveResponse } from "./ThreadArchiveResponse";
 export type { ThreadCompactStartParams } from "./ThreadCompactStartParams";
 export type { ThreadCompactStartResponse } from "./ThreadCompactStartResponse";
+export type { ThreadExportFormat } from "./ThreadExportFormat";
+export type { ThreadExportParams } from "./ThreadExportParams";
+export type { ThreadExportResponse } from "./ThreadExportResponse";
 export type { ThreadForkParams } from "./ThreadForkParams";
 export type { ThreadForkResponse } from "./ThreadForkResponse";
 export type { ThreadItem } from "./ThreadItem";
@@ -154,6 +160,10 @@ export type { ThreadResumeParams } from "./ThreadResumeParams";
 export type { ThreadResumeResponse } from "./ThreadResumeResponse";
 export type { ThreadRollbackParams } from "./ThreadRollbackParams";
 export type { ThreadRollbackResponse } from "./ThreadRollbackResponse";
+export type { ThreadSearchMatchKind } from "./ThreadSearchMatchKind";
+export type { ThreadSearchParams } from "./ThreadSearchParams";
+export type { ThreadSearchResponse } from "./ThreadSearchResponse";
+export type { ThreadSearchResult } from "./ThreadSearchResult";
 export type { ThreadSetNameParams } from "./ThreadSetNameParams";
 export type { ThreadSetNameResponse } from "./ThreadSetNameResponse";
 export type { ThreadSortKey } from "./ThreadSortKey";
@@ -227,6 +227,10 @@ client_request_definitions! {
         params: v2::ThreadListParams,
         response: v2::ThreadListResponse,
     },
+    ThreadSearch => "thread/search" {
+        params: v2::ThreadSearchParams,
+        response: v2::ThreadSearchResponse,
+    },
     ThreadLoadedList => "thread/loaded/list" {
         params: v2::ThreadLoadedListParams,
         response: v2::ThreadLoadedListResponse,
@@ -235,6 +239,10 @@ client_request_definitions! {
         params: v2::ThreadReadParams,
         response: v2::ThreadReadResponse,
     },
+    ThreadExport => "thread/export" {
+        params: v2::ThreadExportParams,
+        response: v2::ThreadExportResponse,
+    },
     SkillsList => "skills/list" {
         params: v2::SkillsListParams,
         response: v2::SkillsListResponse,
@@ -645,6 +653,13 @@ server_request_definitions! {
         response: v2::FileChangeRequestApprovalResponse,
     },
 
+    /// Sent when a sandboxed process tries to reach a host outside `network.allowed_domains`.
+    /// The connection is paused until the client responds.
+    NetworkAccessRequestApproval => "item/networkAccess/requestApproval" {
+        params: v2::NetworkAccessRequestApprovalParams,
+        response: v2::NetworkAccessRequestApprovalResponse,
+    },
+
     /// EXPERIMENTAL - Request input from the user for a tool call.
     ToolRequestUserInput => "item/tool/requestUserInput" {
         params: v2::ToolRequestUserInputParams,
@@ -62,6 +62,8 @@ impl ThreadHistoryBuilder {
             EventMsg::ExitedReviewMode(_) => {}
             EventMsg::ThreadRolledBack(payload) => self.handle_thread_rollback(payload),
             EventMsg::UndoCompleted(_) => {}
+            EventMsg::CheckpointCreated(_) => {}
+            EventMsg::McpToolCallApproval(_) => {}
             EventMsg::TurnAborted(payload) => self.handle_turn_aborted(payload),
             _ => {}
         }
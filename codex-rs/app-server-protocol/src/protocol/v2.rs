@@ -5,6 +5,7 @@ use crate::protocol::common::AuthMode;
 use codex_experimental_api_macros::ExperimentalApi;
 use codex_protocol::account::PlanType;
 use codex_protocol::approvals::ExecPolicyAmendment as CoreExecPolicyAmendment;
+use codex_protocol::approvals::NetworkApprovalDecision as CoreNetworkApprovalDecision;
 use codex_protocol::config_types::CollaborationMode;
 use codex_protocol::config_types::CollaborationModeMask;
 use codex_protocol::config_types::ForcedLoginMethod;
@@ -638,6 +639,35 @@ pub enum FileChangeApprovalDecision {
     Cancel,
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, JsonSchema, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export_to = "v2/")]
+pub enum NetworkAccessApprovalDecision {
+    /// User allowed this connection only.
+    Accept,
+    /// User allowed the host for the remainder of the session.
+    AcceptForSession,
+    /// User allowed the host and wants it added to `network.allowed_domains` in config.toml.
+    AcceptAndPersist,
+    /// User denied the connection.
+    Decline,
+}
+
+impl From<NetworkAccessApprovalDecision> for CoreNetworkApprovalDecision {
+    fn from(value: NetworkAccessApprovalDecision) -> Self {
+        match value {
+            NetworkAccessApprovalDecision::Accept => CoreNetworkApprovalDecision::AllowOnce,
+            NetworkAccessApprovalDecision::AcceptForSession => {
+                CoreNetworkApprovalDecision::AllowForSession
+            }
+            NetworkAccessApprovalDecision::AcceptAndPersist => {
+                CoreNetworkApprovalDecision::AllowAndPersist
+            }
+            NetworkAccessApprovalDecision::Decline => CoreNetworkApprovalDecision::Deny,
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq, Eq, JsonSchema, TS)]
 #[serde(rename_all = "camelCase")]
 #[ts(export_to = "v2/")]
@@ -1656,6 +1686,54 @@ pub struct ThreadListResponse {
     pub next_cursor: Option<String>,
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export_to = "v2/")]
+pub struct ThreadSearchParams {
+    /// Whitespace-separated terms. A thread matches when one of its user
+    /// messages, agent messages, commands or touched file paths contains
+    /// every term (case-insensitive substring match).
+    pub query: String,
+    /// Optional maximum number of threads; defaults to a reasonable server-side value.
+    #[ts(optional = nullable)]
+    pub limit: Option<u32>,
+    /// Optional archived filter; when set to true, only archived threads are searched.
+    /// If false or null, only non-archived threads are searched.
+    #[ts(optional = nullable)]
+    pub archived: Option<bool>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, JsonSchema, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export_to = "v2/")]
+pub enum ThreadSearchMatchKind {
+    UserMessage,
+    AgentMessage,
+    Command,
+    File,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export_to = "v2/")]
+pub struct ThreadSearchResult {
+    pub thread: Thread,
+    /// What kind of recorded text the snippet was taken from.
+    pub match_kind: ThreadSearchMatchKind,
+    /// Single-line excerpt of the best matching text in the thread.
+    pub snippet: String,
+    /// Byte ranges of `snippet` that matched a query term, in order.
+    pub highlights: Vec<ByteRange>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export_to = "v2/")]
+pub struct ThreadSearchResponse {
+    /// Matching threads, best match first.
+    pub data: Vec<ThreadSearchResult>,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default, JsonSchema, TS)]
 #[serde(rename_all = "camelCase")]
 #[ts(export_to = "v2/")]
@@ -1696,6 +1774,31 @@ pub struct ThreadReadResponse {
     pub thread: Thread,
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, JsonSchema, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export_to = "v2/")]
+pub enum ThreadExportFormat {
+    Markdown,
+    Html,
+    Json,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export_to = "v2/")]
+pub struct ThreadExportParams {
+    pub thread_id: String,
+    pub format: ThreadExportFormat,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export_to = "v2/")]
+pub struct ThreadExportResponse {
+    /// The rendered transcript, with configured secrets redacted.
+    pub content: String,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema, TS)]
 #[serde(rename_all = "camelCase")]
 #[ts(export_to = "v2/")]
@@ -2998,6 +3101,30 @@ pub struct FileChangeRequestApprovalResponse {
     pub decision: FileChangeApprovalDecision,
 }
 
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export_to = "v2/")]
+pub struct NetworkAccessRequestApprovalParams {
+    pub thread_id: String,
+    pub turn_id: String,
+    /// Identifier of this network access request.
+    pub request_id: String,
+    /// Destination host the sandboxed process tried to reach.
+    pub host: String,
+    pub port: u16,
+    /// Proxy protocol that carried the request (e.g. `http`, `https_connect`).
+    pub protocol: String,
+    /// HTTP method, when the protocol exposes one.
+    #[ts(optional = nullable)]
+    pub method: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema, TS)]
+#[ts(export_to = "v2/")]
+pub struct NetworkAccessRequestApprovalResponse {
+    pub decision: NetworkAccessApprovalDecision,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema, TS)]
 #[serde(rename_all = "camelCase")]
 #[ts(export_to = "v2/")]
@@ -9,6 +9,7 @@ use crate::codex_message_processor::summary_to_thread;
 use crate::error_code::INTERNAL_ERROR_CODE;
 use crate::error_code::INVALID_REQUEST_ERROR_CODE;
 use crate::outgoing_message::OutgoingMessageSender;
+use crate::thread_event_hub::ThreadResponder;
 use codex_app_server_protocol::AccountRateLimitsUpdatedNotification;
 use codex_app_server_protocol::AgentMessageDeltaNotification;
 use codex_app_server_protocol::ApplyPatchApprovalParams;
@@ -42,6 +43,9 @@ use codex_app_server_protocol::JSONRPCErrorError;
 use codex_app_server_protocol::McpToolCallError;
 use codex_app_server_protocol::McpToolCallResult;
 use codex_app_server_protocol::McpToolCallStatus;
+use codex_app_server_protocol::NetworkAccessApprovalDecision;
+use codex_app_server_protocol::NetworkAccessRequestApprovalParams;
+use codex_app_server_protocol::NetworkAccessRequestApprovalResponse;
 use codex_app_server_protocol::PatchApplyStatus;
 use codex_app_server_protocol::PatchChangeKind as V2PatchChangeKind;
 use codex_app_server_protocol::PlanDeltaNotification;
@@ -81,8 +85,11 @@ use codex_core::protocol::ExecCommandEndEvent;
 use codex_core::protocol::FileChange as CoreFileChange;
 use codex_core::protocol::McpToolCallBeginEvent;
 use codex_core::protocol::McpToolCallEndEvent;
+use codex_core::protocol::NetworkApprovalDecision;
+use codex_core::protocol::NetworkApprovalRequestEvent;
 use codex_core::protocol::Op;
 use codex_core::protocol::ReviewDecision;
+use codex_core::protocol::SamplingDecision;
 use codex_core::protocol::TokenCountEvent;
 use codex_core::protocol::TurnDiffEvent;
 use codex_core::review_format::format_review_findings_block;
@@ -109,6 +116,7 @@ pub(crate) async fn apply_bespoke_event_handling(
     event: Event,
     conversation_id: ThreadId,
     conversation: Arc<CodexThread>,
+    responder: ThreadResponder,
     outgoing: Arc<OutgoingMessageSender>,
     pending_interrupts: PendingInterrupts,
     pending_rollbacks: PendingRollbacks,
@@ -151,7 +159,7 @@ pub(crate) async fn apply_bespoke_event_handling(
                     .send_request(ServerRequestPayload::ApplyPatchApproval(params))
                     .await;
                 tokio::spawn(async move {
-                    on_patch_approval_response(call_id, rx, conversation).await;
+                    on_patch_approval_response(call_id, rx, responder).await;
                 });
             }
             ApiVersion::V2 => {
@@ -198,7 +206,7 @@ pub(crate) async fn apply_bespoke_event_handling(
                         item_id,
                         patch_changes,
                         rx,
-                        conversation,
+                        responder,
                         outgoing,
                         turn_summary_store,
                     )
@@ -228,7 +236,7 @@ pub(crate) async fn apply_bespoke_event_handling(
                     .send_request(ServerRequestPayload::ExecCommandApproval(params))
                     .await;
                 tokio::spawn(async move {
-                    on_exec_approval_response(call_id, event_turn_id, rx, conversation).await;
+                    on_exec_approval_response(call_id, event_turn_id, rx, responder).await;
                 });
             }
             ApiVersion::V2 => {
@@ -268,13 +276,66 @@ pub(crate) async fn apply_bespoke_event_handling(
                         cwd,
                         command_actions,
                         rx,
-                        conversation,
+                        responder,
                         outgoing,
                     )
                     .await;
                 });
             }
         },
+        EventMsg::NetworkApprovalRequest(NetworkApprovalRequestEvent {
+            id,
+            turn_id,
+            host,
+            port,
+            protocol,
+            method,
+        }) => match api_version {
+            ApiVersion::V1 => {
+                // The legacy API has no network approval request; keep the
+                // connection blocked rather than leaving it paused.
+                if let Err(err) = responder
+                    .submit(Op::NetworkApproval {
+                        id,
+                        decision: NetworkApprovalDecision::Deny,
+                    })
+                    .await
+                {
+                    error!("failed to submit NetworkApproval: {err}");
+                }
+            }
+            ApiVersion::V2 => {
+                let params = NetworkAccessRequestApprovalParams {
+                    thread_id: conversation_id.to_string(),
+                    turn_id,
+                    request_id: id.clone(),
+                    host,
+                    port,
+                    protocol,
+                    method,
+                };
+                let rx = outgoing
+                    .send_request(ServerRequestPayload::NetworkAccessRequestApproval(params))
+                    .await;
+                tokio::spawn(async move {
+                    on_network_access_request_approval_response(id, rx, responder).await;
+                });
+            }
+        },
+        EventMsg::SamplingRequest(request) => {
+            // There is no sampling approval request in the app-server API yet;
+            // decline so the MCP server is not left waiting.
+            if let Err(err) = responder
+                .submit(Op::ResolveSampling {
+                    server_name: request.server_name,
+                    request_id: request.id,
+                    decision: SamplingDecision::Decline,
+                })
+                .await
+            {
+                error!("failed to submit ResolveSampling: {err}");
+            }
+        }
         EventMsg::RequestUserInput(request) => {
             if matches!(api_version, ApiVersion::V2) {
                 let questions = request
@@ -307,7 +368,7 @@ pub(crate) async fn apply_bespoke_event_handling(
                     .send_request(ServerRequestPayload::ToolRequestUserInput(params))
                     .await;
                 tokio::spawn(async move {
-                    on_request_user_input_response(event_turn_id, rx, conversation).await;
+                    on_request_user_input_response(event_turn_id, rx, responder).await;
                 });
             } else {
                 error!(
@@ -317,7 +378,7 @@ pub(crate) async fn apply_bespoke_event_handling(
                 let empty = CoreRequestUserInputResponse {
                     answers: HashMap::new(),
                 };
-                if let Err(err) = conversation
+                if let Err(err) = responder
                     .submit(Op::UserInputAnswer {
                         id: event_turn_id,
                         response: empty,
@@ -342,7 +403,7 @@ pub(crate) async fn apply_bespoke_event_handling(
                     .send_request(ServerRequestPayload::DynamicToolCall(params))
                     .await;
                 tokio::spawn(async move {
-                    crate::dynamic_tools::on_call_response(call_id, rx, conversation).await;
+                    crate::dynamic_tools::on_call_response(call_id, rx, responder).await;
                 });
             } else {
                 error!(
@@ -350,7 +411,7 @@ pub(crate) async fn apply_bespoke_event_handling(
                     request.call_id
                 );
                 let call_id = request.call_id;
-                let _ = conversation
+                let _ = responder
                     .submit(Op::DynamicToolResponse {
                         id: call_id.clone(),
                         response: CoreDynamicToolResponse {
@@ -1432,7 +1493,7 @@ async fn handle_error(
 async fn on_patch_approval_response(
     call_id: String,
     receiver: oneshot::Receiver<JsonValue>,
-    codex: Arc<CodexThread>,
+    codex: ThreadResponder,
 ) {
     let response = receiver.await;
     let value = match response {
@@ -1475,7 +1536,7 @@ async fn on_exec_approval_response(
     call_id: String,
     turn_id: String,
     receiver: oneshot::Receiver<JsonValue>,
-    conversation: Arc<CodexThread>,
+    conversation: ThreadResponder,
 ) {
     let response = receiver.await;
     let value = match response {
@@ -1509,10 +1570,39 @@ async fn on_exec_approval_response(
     }
 }
 
+async fn on_network_access_request_approval_response(
+    request_id: String,
+    receiver: oneshot::Receiver<JsonValue>,
+    conversation: ThreadResponder,
+) {
+    let decision = match receiver.await {
+        Ok(value) => serde_json::from_value::<NetworkAccessRequestApprovalResponse>(value)
+            .map(|response| response.decision)
+            .unwrap_or_else(|err| {
+                error!("failed to deserialize NetworkAccessRequestApprovalResponse: {err}");
+                NetworkAccessApprovalDecision::Decline
+            }),
+        Err(err) => {
+            error!("request failed: {err:?}");
+            NetworkAccessApprovalDecision::Decline
+        }
+    };
+
+    if let Err(err) = conversation
+        .submit(Op::NetworkApproval {
+            id: request_id,
+            decision: decision.into(),
+        })
+        .await
+    {
+        error!("failed to submit NetworkApproval: {err}");
+    }
+}
+
 async fn on_request_user_input_response(
     event_turn_id: String,
     receiver: oneshot::Receiver<JsonValue>,
-    conversation: Arc<CodexThread>,
+    conversation: ThreadResponder,
 ) {
     let response = receiver.await;
     let value = match response {
@@ -1652,7 +1742,7 @@ async fn on_file_change_request_approval_response(
     item_id: String,
     changes: Vec<FileUpdateChange>,
     receiver: oneshot::Receiver<JsonValue>,
-    codex: Arc<CodexThread>,
+    codex: ThreadResponder,
     outgoing: Arc<OutgoingMessageSender>,
     turn_summary_store: TurnSummaryStore,
 ) {
@@ -1712,7 +1802,7 @@ async fn on_command_execution_request_approval_response(
     cwd: PathBuf,
     command_actions: Vec<V2ParsedCommand>,
     receiver: oneshot::Receiver<JsonValue>,
-    conversation: Arc<CodexThread>,
+    conversation: ThreadResponder,
     outgoing: Arc<OutgoingMessageSender>,
 ) {
     let response = receiver.await;
@@ -5,6 +5,8 @@ use crate::fuzzy_file_search::run_fuzzy_file_search;
 use crate::models::supported_models;
 use crate::outgoing_message::OutgoingMessageSender;
 use crate::outgoing_message::OutgoingNotification;
+use crate::thread_event_hub::ThreadEventHub;
+use crate::thread_event_hub::ThreadResponder;
 use chrono::DateTime;
 use chrono::SecondsFormat;
 use chrono::Utc;
@@ -22,6 +24,7 @@ use codex_app_server_protocol::ArchiveConversationResponse;
 use codex_app_server_protocol::AskForApproval;
 use codex_app_server_protocol::AuthMode;
 use codex_app_server_protocol::AuthStatusChangeNotification;
+use codex_app_server_protocol::ByteRange;
 use codex_app_server_protocol::CancelLoginAccountParams;
 use codex_app_server_protocol::CancelLoginAccountResponse;
 use codex_app_server_protocol::CancelLoginAccountStatus;
@@ -114,6 +117,9 @@ use codex_app_server_protocol::ThreadBackgroundTerminalsCleanParams;
 use codex_app_server_protocol::ThreadBackgroundTerminalsCleanResponse;
 use codex_app_server_protocol::ThreadCompactStartParams;
 use codex_app_server_protocol::ThreadCompactStartResponse;
+use codex_app_server_protocol::ThreadExportFormat;
+use codex_app_server_protocol::ThreadExportParams;
+use codex_app_server_protocol::ThreadExportResponse;
 use codex_app_server_protocol::ThreadForkParams;
 use codex_app_server_protocol::ThreadForkResponse;
 use codex_app_server_protocol::ThreadItem;
@@ -126,6 +132,10 @@ use codex_app_server_protocol::ThreadReadResponse;
 use codex_app_server_protocol::ThreadResumeParams;
 use codex_app_server_protocol::ThreadResumeResponse;
 use codex_app_server_protocol::ThreadRollbackParams;
+use codex_app_server_protocol::ThreadSearchMatchKind;
+use codex_app_server_protocol::ThreadSearchParams;
+use codex_app_server_protocol::ThreadSearchResponse;
+use codex_app_server_protocol::ThreadSearchResult;
 use codex_app_server_protocol::ThreadSetNameParams;
 use codex_app_server_protocol::ThreadSetNameResponse;
 use codex_app_server_protocol::ThreadSortKey;
@@ -162,6 +172,7 @@ use codex_core::SessionMeta;
 use codex_core::SteerInputError;
 use codex_core::ThreadConfigSnapshot;
 use codex_core::ThreadManager;
+use codex_core::ThreadSearchKind as CoreThreadSearchKind;
 use codex_core::ThreadSortKey as CoreThreadSortKey;
 use codex_core::auth::AuthMode as CoreAuthMode;
 use codex_core::auth::CLIENT_ID;
@@ -202,6 +213,8 @@ use codex_core::skills::remote::download_remote_skill;
 use codex_core::skills::remote::list_remote_skills;
 use codex_core::state_db::StateDbHandle;
 use codex_core::state_db::get_state_db;
+use codex_core::thread_export::ThreadExportFormat as CoreThreadExportFormat;
+use codex_core::thread_export::export_thread;
 use codex_core::windows_sandbox::WindowsSandboxLevelExt;
 use codex_feedback::CodexFeedback;
 use codex_login::ServerOptions as LoginServerOptions;
@@ -313,6 +326,7 @@ pub(crate) struct CodexMessageProcessor {
     turn_summary_store: TurnSummaryStore,
     pending_fuzzy_searches: Arc<Mutex<HashMap<String, Arc<AtomicBool>>>>,
     feedback: CodexFeedback,
+    thread_event_hub: Arc<ThreadEventHub>,
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -330,6 +344,7 @@ pub(crate) struct CodexMessageProcessorArgs {
     pub(crate) cli_overrides: Vec<(String, TomlValue)>,
     pub(crate) cloud_requirements: Arc<RwLock<CloudRequirementsLoader>>,
     pub(crate) feedback: CodexFeedback,
+    pub(crate) thread_event_hub: Arc<ThreadEventHub>,
 }
 
 impl CodexMessageProcessor {
@@ -366,6 +381,7 @@ impl CodexMessageProcessor {
             cli_overrides,
             cloud_requirements,
             feedback,
+            thread_event_hub,
         } = args;
         Self {
             auth_manager,
@@ -383,6 +399,7 @@ impl CodexMessageProcessor {
             turn_summary_store: Arc::new(Mutex::new(HashMap::new())),
             pending_fuzzy_searches: Arc::new(Mutex::new(HashMap::new())),
             feedback,
+            thread_event_hub,
         }
     }
 
@@ -525,12 +542,18 @@ impl CodexMessageProcessor {
             ClientRequest::ThreadList { request_id, params } => {
                 self.thread_list(request_id, params).await;
             }
+            ClientRequest::ThreadSearch { request_id, params } => {
+                self.thread_search(request_id, params).await;
+            }
             ClientRequest::ThreadLoadedList { request_id, params } => {
                 self.thread_loaded_list(request_id, params).await;
             }
             ClientRequest::ThreadRead { request_id, params } => {
                 self.thread_read(request_id, params).await;
             }
+            ClientRequest::ThreadExport { request_id, params } => {
+                self.thread_export(request_id, params).await;
+            }
             ClientRequest::SkillsList { request_id, params } => {
                 self.skills_list(request_id, params).await;
             }
@@ -1551,7 +1574,7 @@ impl CodexMessageProcessor {
             .timeout_ms
             .and_then(|timeout_ms| u64::try_from(timeout_ms).ok());
         let started_network_proxy = match self.config.network.as_ref() {
-            Some(spec) => match spec.start_proxy().await {
+            Some(spec) => match spec.start_proxy(None, None).await {
                 Ok(started) => Some(started),
                 Err(err) => {
                     let error = JSONRPCErrorError {
@@ -2298,6 +2321,78 @@ impl CodexMessageProcessor {
         self.outgoing.send_response(request_id, response).await;
     }
 
+    async fn thread_search(&self, request_id: RequestId, params: ThreadSearchParams) {
+        let ThreadSearchParams {
+            query,
+            limit,
+            archived,
+        } = params;
+        if query.trim().is_empty() {
+            let error = JSONRPCErrorError {
+                code: INVALID_REQUEST_ERROR_CODE,
+                message: "query must not be empty".to_string(),
+                data: None,
+            };
+            self.outgoing.send_error(request_id, error).await;
+            return;
+        }
+
+        let limit = limit
+            .map(|value| value as usize)
+            .unwrap_or(THREAD_LIST_DEFAULT_LIMIT)
+            .clamp(1, THREAD_LIST_MAX_LIMIT);
+        let matches = match RolloutRecorder::search_threads(
+            &self.config,
+            &query,
+            limit,
+            archived.unwrap_or(false),
+        )
+        .await
+        {
+            Ok(matches) => matches,
+            Err(err) => {
+                let code = if err.kind() == std::io::ErrorKind::Unsupported {
+                    INVALID_REQUEST_ERROR_CODE
+                } else {
+                    INTERNAL_ERROR_CODE
+                };
+                let error = JSONRPCErrorError {
+                    code,
+                    message: format!("failed to search threads: {err}"),
+                    data: None,
+                };
+                self.outgoing.send_error(request_id, error).await;
+                return;
+            }
+        };
+
+        let fallback_provider = self.config.model_provider_id.as_str();
+        let mut data = Vec::with_capacity(matches.len());
+        for search_match in matches {
+            let Some(summary) =
+                summary_from_thread_list_item(search_match.item, fallback_provider, None).await
+            else {
+                continue;
+            };
+            data.push(ThreadSearchResult {
+                thread: summary_to_thread(summary),
+                match_kind: thread_search_match_kind(search_match.kind),
+                snippet: search_match.snippet,
+                highlights: search_match
+                    .highlights
+                    .into_iter()
+                    .map(|range| ByteRange {
+                        start: range.start,
+                        end: range.end,
+                    })
+                    .collect(),
+            });
+        }
+        self.outgoing
+            .send_response(request_id, ThreadSearchResponse { data })
+            .await;
+    }
+
     async fn thread_loaded_list(&self, request_id: RequestId, params: ThreadLoadedListParams) {
         let ThreadLoadedListParams { cursor, limit } = params;
         let mut data = self
@@ -2479,6 +2574,66 @@ impl CodexMessageProcessor {
         self.outgoing.send_response(request_id, response).await;
     }
 
+    async fn thread_export(&self, request_id: RequestId, params: ThreadExportParams) {
+        let ThreadExportParams { thread_id, format } = params;
+
+        let thread_uuid = match ThreadId::from_string(&thread_id) {
+            Ok(id) => id,
+            Err(err) => {
+                self.send_invalid_request_error(request_id, format!("invalid thread id: {err}"))
+                    .await;
+                return;
+            }
+        };
+
+        let codex_home = &self.config.codex_home;
+        let thread_id = thread_uuid.to_string();
+        let rollout_path = match find_thread_path_by_id_str(codex_home, &thread_id).await {
+            Ok(Some(path)) => Ok(Some(path)),
+            Ok(None) => find_archived_thread_path_by_id_str(codex_home, &thread_id).await,
+            Err(err) => Err(err),
+        };
+        let rollout_path = match rollout_path {
+            Ok(Some(path)) => path,
+            Ok(None) => {
+                self.send_invalid_request_error(
+                    request_id,
+                    format!("no rollout found for thread id {thread_uuid}"),
+                )
+                .await;
+                return;
+            }
+            Err(err) => {
+                self.send_invalid_request_error(
+                    request_id,
+                    format!("failed to locate thread id {thread_uuid}: {err}"),
+                )
+                .await;
+                return;
+            }
+        };
+
+        let format = match format {
+            ThreadExportFormat::Markdown => CoreThreadExportFormat::Markdown,
+            ThreadExportFormat::Html => CoreThreadExportFormat::Html,
+            ThreadExportFormat::Json => CoreThreadExportFormat::Json,
+        };
+        match export_thread(&self.config, &rollout_path, format).await {
+            Ok(content) => {
+                self.outgoing
+                    .send_response(request_id, ThreadExportResponse { content })
+                    .await;
+            }
+            Err(err) => {
+                self.send_internal_error(
+                    request_id,
+                    format!("failed to export thread {thread_uuid}: {err}"),
+                )
+                .await;
+            }
+        }
+    }
+
     pub(crate) fn thread_created_receiver(&self) -> broadcast::Receiver<ThreadId> {
         self.thread_manager.subscribe_thread_created()
     }
@@ -2520,6 +2675,31 @@ impl CodexMessageProcessor {
             personality,
         } = params;
 
+        // A plain resume of a thread that is still loaded (for example after a
+        // client reconnects) attaches to the running instance instead of
+        // replacing it, so an in-flight turn keeps going.
+        let has_overrides = model.is_some()
+            || model_provider.is_some()
+            || cwd.is_some()
+            || approval_policy.is_some()
+            || sandbox.is_some()
+            || request_overrides.is_some()
+            || base_instructions.is_some()
+            || developer_instructions.is_some()
+            || personality.is_some();
+        if history.is_none()
+            && path.is_none()
+            && !has_overrides
+            && let Ok(existing_thread_id) = ThreadId::from_string(&thread_id)
+            && let Ok(thread) = self.thread_manager.get_thread(existing_thread_id).await
+            && let Some(rollout_path) = thread.rollout_path()
+            && tokio::fs::try_exists(&rollout_path).await.unwrap_or(false)
+        {
+            self.resume_loaded_thread(request_id, existing_thread_id, thread, rollout_path)
+                .await;
+            return;
+        }
+
         let thread_history = if let Some(history) = history {
             if history.is_empty() {
                 self.send_invalid_request_error(
@@ -2712,6 +2892,55 @@ impl CodexMessageProcessor {
         }
     }
 
+    async fn resume_loaded_thread(
+        &mut self,
+        request_id: RequestId,
+        thread_id: ThreadId,
+        loaded_thread: Arc<CodexThread>,
+        rollout_path: PathBuf,
+    ) {
+        let config_snapshot = loaded_thread.config_snapshot().await;
+        let loaded = match read_summary_from_rollout(
+            rollout_path.as_path(),
+            config_snapshot.model_provider_id.as_str(),
+        )
+        .await
+        {
+            Ok(summary) => read_event_msgs_from_rollout(&rollout_path)
+                .await
+                .map(|events| (summary, events)),
+            Err(err) => Err(err),
+        };
+        let (summary, events) = match loaded {
+            Ok(loaded) => loaded,
+            Err(err) => {
+                self.send_internal_error(
+                    request_id,
+                    format!(
+                        "failed to load rollout `{}` for thread {thread_id}: {err}",
+                        rollout_path.display()
+                    ),
+                )
+                .await;
+                return;
+            }
+        };
+        self.try_attach_thread_listener(thread_id).await;
+
+        let mut thread = summary_to_thread(summary);
+        thread.turns = build_turns_from_event_msgs(&events);
+        let response = ThreadResumeResponse {
+            thread,
+            model: config_snapshot.model,
+            model_provider: config_snapshot.model_provider_id,
+            cwd: config_snapshot.cwd,
+            approval_policy: config_snapshot.approval_policy.into(),
+            sandbox: config_snapshot.sandbox_policy.into(),
+            reasoning_effort: config_snapshot.reasoning_effort,
+        };
+        self.outgoing.send_response(request_id, response).await;
+    }
+
     async fn thread_fork(&mut self, request_id: RequestId, params: ThreadForkParams) {
         let ThreadForkParams {
             thread_id,
@@ -3425,12 +3654,23 @@ impl CodexMessageProcessor {
                 http_headers,
                 env_http_headers,
                 ..
+            }
+            | McpServerTransportConfig::Sse {
+                url,
+                http_headers,
+                env_http_headers,
+                ..
+            }
+            | McpServerTransportConfig::WebSocket {
+                url,
+                http_headers,
+                env_http_headers,
+                ..
             } => (url.clone(), http_headers.clone(), env_http_headers.clone()),
-            _ => {
+            McpServerTransportConfig::Stdio { .. } => {
                 let error = JSONRPCErrorError {
                     code: INVALID_REQUEST_ERROR_CODE,
-                    message: "OAuth login is only supported for streamable HTTP servers."
-                        .to_string(),
+                    message: "OAuth login is only supported for URL-based servers.".to_string(),
                     data: None,
                 };
                 self.outgoing.send_error(request_id, error).await;
@@ -5122,6 +5362,10 @@ impl CodexMessageProcessor {
             }
         };
 
+        let mut events = self
+            .thread_event_hub
+            .subscribe(conversation_id, conversation.clone())
+            .await;
         let subscription_id = Uuid::new_v4();
         let (cancel_tx, mut cancel_rx) = oneshot::channel();
         self.conversation_listeners
@@ -5136,6 +5380,11 @@ impl CodexMessageProcessor {
         let api_version_for_task = api_version;
         let fallback_model_provider = self.config.model_provider_id.clone();
         let language = self.config.language;
+        let responder = ThreadResponder::new(
+            Arc::clone(&self.thread_event_hub),
+            conversation_id,
+            conversation.clone(),
+        );
         tokio::spawn(async move {
             loop {
                 tokio::select! {
@@ -5143,13 +5392,9 @@ impl CodexMessageProcessor {
                         // User has unsubscribed, so exit this task.
                         break;
                     }
-                    event = conversation.next_event() => {
-                        let event = match event {
-                            Ok(event) => event,
-                            Err(err) => {
-                                tracing::warn!("thread.next_event() failed with: {err}");
-                                break;
-                            }
+                    event = events.recv() => {
+                        let Some(event) = event else {
+                            break;
                         };
 
                         if let EventMsg::RawResponseItem(_) = &event.msg
@@ -5193,6 +5438,7 @@ impl CodexMessageProcessor {
                             event.clone(),
                             conversation_id,
                             conversation.clone(),
+                            responder.clone(),
                             outgoing_for_task.clone(),
                             pending_interrupts.clone(),
                             pending_rollbacks.clone(),
@@ -5663,6 +5909,15 @@ async fn summary_from_thread_list_item(
     None
 }
 
+fn thread_search_match_kind(kind: CoreThreadSearchKind) -> ThreadSearchMatchKind {
+    match kind {
+        CoreThreadSearchKind::UserMessage => ThreadSearchMatchKind::UserMessage,
+        CoreThreadSearchKind::AgentMessage => ThreadSearchMatchKind::AgentMessage,
+        CoreThreadSearchKind::Command => ThreadSearchMatchKind::Command,
+        CoreThreadSearchKind::File => ThreadSearchMatchKind::File,
+    }
+}
+
 fn thread_id_from_rollout_path(path: &Path) -> Option<ThreadId> {
     let file_name = path.file_name()?.to_str()?;
     let stem = file_name.strip_suffix(".jsonl")?;
@@ -225,6 +225,7 @@ mod tests {
                 denied_domains: Some(vec!["example.com".to_string()]),
                 allow_unix_sockets: Some(vec!["/tmp/proxy.sock".to_string()]),
                 allow_local_binding: Some(true),
+                host_rules: None,
             }),
         };
 
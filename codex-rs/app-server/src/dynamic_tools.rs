@@ -1,16 +1,15 @@
+use crate::thread_event_hub::ThreadResponder;
 use codex_app_server_protocol::DynamicToolCallResponse;
-use codex_core::CodexThread;
 use codex_protocol::dynamic_tools::DynamicToolCallOutputContentItem as CoreDynamicToolCallOutputContentItem;
 use codex_protocol::dynamic_tools::DynamicToolResponse as CoreDynamicToolResponse;
 use codex_protocol::protocol::Op;
-use std::sync::Arc;
 use tokio::sync::oneshot;
 use tracing::error;
 
 pub(crate) async fn on_call_response(
     call_id: String,
     receiver: oneshot::Receiver<serde_json::Value>,
-    conversation: Arc<CodexThread>,
+    conversation: ThreadResponder,
 ) {
     let response = receiver.await;
     let value = match response {
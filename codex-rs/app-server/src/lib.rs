@@ -11,11 +11,14 @@ use codex_core::config_loader::LoaderOverrides;
 use std::io::ErrorKind;
 use std::io::Result as IoResult;
 use std::path::PathBuf;
+use std::sync::Arc;
 
 use crate::message_processor::MessageProcessor;
 use crate::message_processor::MessageProcessorArgs;
+use crate::message_processor::ServerState;
 use crate::outgoing_message::OutgoingMessage;
 use crate::outgoing_message::OutgoingMessageSender;
+use crate::transport::ClientConnection;
 use codex_app_server_protocol::ConfigLayerSource;
 use codex_app_server_protocol::ConfigWarningNotification;
 use codex_app_server_protocol::JSONRPCMessage;
@@ -31,7 +34,7 @@ use tokio::io::AsyncWriteExt;
 use tokio::io::BufReader;
 use tokio::io::{self};
 use tokio::sync::mpsc;
-use toml::Value as TomlValue;
+use tokio::task::JoinHandle;
 use tracing::debug;
 use tracing::error;
 use tracing::info;
@@ -51,6 +54,10 @@ mod fuzzy_file_search;
 mod message_processor;
 mod models;
 mod outgoing_message;
+mod thread_event_hub;
+mod transport;
+
+pub use crate::transport::AppServerTransport;
 
 /// Size of the bounded channels used to communicate between tasks. The value
 /// is a balance between throughput and memory usage – 128 messages should be
@@ -172,34 +179,8 @@ pub async fn run_main(
     cli_config_overrides: CliConfigOverrides,
     loader_overrides: LoaderOverrides,
     default_analytics_enabled: bool,
+    transport: AppServerTransport,
 ) -> IoResult<()> {
-    // Set up channels.
-    let (incoming_tx, mut incoming_rx) = mpsc::channel::<JSONRPCMessage>(CHANNEL_CAPACITY);
-    let (outgoing_tx, mut outgoing_rx) = mpsc::channel::<OutgoingMessage>(CHANNEL_CAPACITY);
-
-    // Task: read from stdin, push to `incoming_tx`.
-    let stdin_reader_handle = tokio::spawn({
-        async move {
-            let stdin = io::stdin();
-            let reader = BufReader::new(stdin);
-            let mut lines = reader.lines();
-
-            while let Some(line) = lines.next_line().await.unwrap_or_default() {
-                match serde_json::from_str::<JSONRPCMessage>(&line) {
-                    Ok(msg) => {
-                        if incoming_tx.send(msg).await.is_err() {
-                            // Receiver gone – nothing left to do.
-                            break;
-                        }
-                    }
-                    Err(e) => error!("Failed to deserialize JSONRPCMessage: {e}"),
-                }
-            }
-
-            debug!("stdin reader finished (EOF)");
-        }
-    });
-
     // Parse CLI overrides once and derive the base Config eagerly so later
     // components do not need to work with raw TOML values.
     let cli_kv_overrides = cli_config_overrides.parse_overrides().map_err(|e| {
@@ -325,61 +306,64 @@ pub async fn run_main(
         }
     }
 
-    // Task: process incoming messages.
-    let processor_handle = tokio::spawn({
-        let outgoing_message_sender = OutgoingMessageSender::new(outgoing_tx);
-        let cli_overrides: Vec<(String, TomlValue)> = cli_kv_overrides.clone();
-        let loader_overrides = loader_overrides_for_config_api;
-        let mut processor = MessageProcessor::new(MessageProcessorArgs {
-            outgoing: outgoing_message_sender,
-            codex_linux_sandbox_exe,
-            config: std::sync::Arc::new(config),
-            cli_overrides,
-            loader_overrides,
+    let config = Arc::new(config);
+    let processor_args =
+        |state: ServerState, outgoing_tx: mpsc::Sender<OutgoingMessage>| MessageProcessorArgs {
+            state,
+            outgoing: OutgoingMessageSender::new(outgoing_tx),
+            codex_linux_sandbox_exe: codex_linux_sandbox_exe.clone(),
+            config: Arc::clone(&config),
+            cli_overrides: cli_kv_overrides.clone(),
+            loader_overrides: loader_overrides_for_config_api.clone(),
             cloud_requirements: cloud_requirements.clone(),
             feedback: feedback.clone(),
-            config_warnings,
-        });
-        let mut thread_created_rx = processor.thread_created_receiver();
+            config_warnings: config_warnings.clone(),
+        };
+    // Threads live in the shared state, so they keep running while clients
+    // come and go.
+    let state = ServerState::new(&config);
+    if transport != AppServerTransport::Stdio {
+        return serve_connections(transport, |connection| {
+            let ClientConnection {
+                incoming_rx,
+                outgoing_tx,
+            } = connection;
+            spawn_message_processor(processor_args(state.clone(), outgoing_tx), incoming_rx);
+        })
+        .await;
+    }
+
+    // Set up channels.
+    let (incoming_tx, incoming_rx) = mpsc::channel::<JSONRPCMessage>(CHANNEL_CAPACITY);
+    let (outgoing_tx, mut outgoing_rx) = mpsc::channel::<OutgoingMessage>(CHANNEL_CAPACITY);
+
+    // Task: read from stdin, push to `incoming_tx`.
+    let stdin_reader_handle = tokio::spawn({
         async move {
-            let mut listen_for_threads = true;
-            loop {
-                tokio::select! {
-                    msg = incoming_rx.recv() => {
-                        let Some(msg) = msg else {
+            let stdin = io::stdin();
+            let reader = BufReader::new(stdin);
+            let mut lines = reader.lines();
+
+            while let Some(line) = lines.next_line().await.unwrap_or_default() {
+                match serde_json::from_str::<JSONRPCMessage>(&line) {
+                    Ok(msg) => {
+                        if incoming_tx.send(msg).await.is_err() {
+                            // Receiver gone – nothing left to do.
                             break;
-                        };
-                        match msg {
-                            JSONRPCMessage::Request(r) => processor.process_request(r).await,
-                            JSONRPCMessage::Response(r) => processor.process_response(r).await,
-                            JSONRPCMessage::Notification(n) => processor.process_notification(n).await,
-                            JSONRPCMessage::Error(e) => processor.process_error(e).await,
-                        }
-                    }
-                    created = thread_created_rx.recv(), if listen_for_threads => {
-                        match created {
-                            Ok(thread_id) => {
-                                processor.try_attach_thread_listener(thread_id).await;
-                            }
-                            Err(tokio::sync::broadcast::error::RecvError::Lagged(_)) => {
-                                // TODO(jif) handle lag.
-                                // Assumes thread creation volume is low enough that lag never happens.
-                                // If it does, we log and continue without resyncing to avoid attaching
-                                // listeners for threads that should remain unsubscribed.
-                                warn!("thread_created receiver lagged; skipping resync");
-                            }
-                            Err(tokio::sync::broadcast::error::RecvError::Closed) => {
-                                listen_for_threads = false;
-                            }
                         }
                     }
+                    Err(e) => error!("Failed to deserialize JSONRPCMessage: {e}"),
                 }
             }
 
-            info!("processor task exited (channel closed)");
+            debug!("stdin reader finished (EOF)");
         }
     });
 
+    // Task: process incoming messages. The processor owns the shared state so
+    // that everything shuts down once stdin closes.
+    let processor_handle = spawn_message_processor(processor_args(state, outgoing_tx), incoming_rx);
+
     // Task: write outgoing messages to stdout.
     let stdout_writer_handle = tokio::spawn(async move {
         let mut stdout = io::stdout();
@@ -410,3 +394,72 @@ pub async fn run_main(
 
     Ok(())
 }
+
+/// Accepts clients until the listener fails or the server is interrupted,
+/// passing each new connection to `on_connection`.
+async fn serve_connections(
+    transport: AppServerTransport,
+    mut on_connection: impl FnMut(ClientConnection),
+) -> IoResult<()> {
+    let (connection_tx, mut connection_rx) = mpsc::channel::<ClientConnection>(CHANNEL_CAPACITY);
+    let mut accept_handle = tokio::spawn(transport::accept_connections(transport, connection_tx));
+    loop {
+        tokio::select! {
+            result = &mut accept_handle => {
+                return result.unwrap_or_else(|err| Err(std::io::Error::other(err)));
+            }
+            Some(connection) = connection_rx.recv() => on_connection(connection),
+            _ = tokio::signal::ctrl_c() => {
+                info!("app server interrupted; shutting down");
+                accept_handle.abort();
+                let _ = accept_handle.await;
+                return Ok(());
+            }
+        }
+    }
+}
+
+fn spawn_message_processor(
+    args: MessageProcessorArgs,
+    mut incoming_rx: mpsc::Receiver<JSONRPCMessage>,
+) -> JoinHandle<()> {
+    let mut processor = MessageProcessor::new(args);
+    let mut thread_created_rx = processor.thread_created_receiver();
+    tokio::spawn(async move {
+        let mut listen_for_threads = true;
+        loop {
+            tokio::select! {
+                msg = incoming_rx.recv() => {
+                    let Some(msg) = msg else {
+                        break;
+                    };
+                    match msg {
+                        JSONRPCMessage::Request(r) => processor.process_request(r).await,
+                        JSONRPCMessage::Response(r) => processor.process_response(r).await,
+                        JSONRPCMessage::Notification(n) => processor.process_notification(n).await,
+                        JSONRPCMessage::Error(e) => processor.process_error(e).await,
+                    }
+                }
+                created = thread_created_rx.recv(), if listen_for_threads => {
+                    match created {
+                        Ok(thread_id) => {
+                            processor.try_attach_thread_listener(thread_id).await;
+                        }
+                        Err(tokio::sync::broadcast::error::RecvError::Lagged(_)) => {
+                            // TODO(jif) handle lag.
+                            // Assumes thread creation volume is low enough that lag never happens.
+                            // If it does, we log and continue without resyncing to avoid attaching
+                            // listeners for threads that should remain unsubscribed.
+                            warn!("thread_created receiver lagged; skipping resync");
+                        }
+                        Err(tokio::sync::broadcast::error::RecvError::Closed) => {
+                            listen_for_threads = false;
+                        }
+                    }
+                }
+            }
+        }
+
+        info!("processor task exited (channel closed)");
+    })
+}
@@ -1,3 +1,5 @@
+use clap::Parser;
+use codex_app_server::AppServerTransport;
 use codex_app_server::run_main;
 use codex_arg0::arg0_dispatch_or_else;
 use codex_common::CliConfigOverrides;
@@ -8,8 +10,16 @@ use std::path::PathBuf;
 // managed config file without writing to /etc.
 const MANAGED_CONFIG_PATH_ENV_VAR: &str = "CODEX_APP_SERVER_MANAGED_CONFIG_PATH";
 
+#[derive(Debug, Parser)]
+struct AppServerArgs {
+    /// 客户端连接方式：stdio://（默认）、ws://IP:PORT 或 unix:///path.sock。
+    #[arg(long = "listen", value_name = "URL", default_value = "stdio://")]
+    listen: AppServerTransport,
+}
+
 fn main() -> anyhow::Result<()> {
     arg0_dispatch_or_else(|codex_linux_sandbox_exe| async move {
+        let args = AppServerArgs::parse();
         let managed_config_path = managed_config_path_from_debug_env();
         let loader_overrides = LoaderOverrides {
             managed_config_path,
@@ -21,6 +31,7 @@ fn main() -> anyhow::Result<()> {
             CliConfigOverrides::default(),
             loader_overrides,
             false,
+            args.listen,
         )
         .await?;
         Ok(())
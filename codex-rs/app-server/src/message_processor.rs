@@ -1,6 +1,7 @@
 use std::path::PathBuf;
 use std::sync::Arc;
 use std::sync::RwLock;
+use std::sync::Weak;
 use std::sync::atomic::AtomicBool;
 use std::sync::atomic::Ordering;
 
@@ -9,6 +10,7 @@ use crate::codex_message_processor::CodexMessageProcessorArgs;
 use crate::config_api::ConfigApi;
 use crate::error_code::INVALID_REQUEST_ERROR_CODE;
 use crate::outgoing_message::OutgoingMessageSender;
+use crate::thread_event_hub::ThreadEventHub;
 use async_trait::async_trait;
 use codex_app_server_protocol::ChatgptAuthTokensRefreshParams;
 use codex_app_server_protocol::ChatgptAuthTokensRefreshReason;
@@ -54,9 +56,48 @@ use toml::Value as TomlValue;
 
 const EXTERNAL_AUTH_REFRESH_TIMEOUT: Duration = Duration::from_secs(10);
 
+/// Connections that can answer external token refreshes, oldest first.
+#[derive(Default)]
+struct ExternalAuthClients {
+    clients: std::sync::Mutex<Vec<Weak<OutgoingMessageSender>>>,
+}
+
+impl ExternalAuthClients {
+    fn register(&self, outgoing: &Arc<OutgoingMessageSender>) {
+        let mut clients = self
+            .clients
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        clients.retain(Self::is_connected);
+        clients.push(Arc::downgrade(outgoing));
+    }
+
+    /// Connected clients, most recently connected first.
+    fn connected(&self) -> Vec<Arc<OutgoingMessageSender>> {
+        let mut clients = self
+            .clients
+            .lock()
+            .unwrap_or_else(std::sync::PoisonError::into_inner);
+        clients.retain(Self::is_connected);
+        clients.iter().rev().filter_map(Weak::upgrade).collect()
+    }
+
+    fn is_connected(client: &Weak<OutgoingMessageSender>) -> bool {
+        client
+            .upgrade()
+            .is_some_and(|outgoing| !outgoing.is_closed())
+    }
+}
+
+enum RefreshAttemptError {
+    /// The client went away before answering; another one may still do so.
+    Disconnected,
+    Failed(std::io::Error),
+}
+
 #[derive(Clone)]
 struct ExternalAuthRefreshBridge {
-    outgoing: Arc<OutgoingMessageSender>,
+    clients: Arc<ExternalAuthClients>,
 }
 
 impl ExternalAuthRefreshBridge {
@@ -65,39 +106,34 @@ impl ExternalAuthRefreshBridge {
             ExternalAuthRefreshReason::Unauthorized => ChatgptAuthTokensRefreshReason::Unauthorized,
         }
     }
-}
-
-#[async_trait]
-impl ExternalAuthRefresher for ExternalAuthRefreshBridge {
-    async fn refresh(
-        &self,
-        context: ExternalAuthRefreshContext,
-    ) -> std::io::Result<ExternalAuthTokens> {
-        let params = ChatgptAuthTokensRefreshParams {
-            reason: Self::map_reason(context.reason),
-            previous_account_id: context.previous_account_id,
-        };
 
-        let (request_id, rx) = self
-            .outgoing
+    async fn refresh_with(
+        outgoing: &OutgoingMessageSender,
+        params: ChatgptAuthTokensRefreshParams,
+    ) -> Result<ExternalAuthTokens, RefreshAttemptError> {
+        let (request_id, rx) = outgoing
             .send_request_with_id(ServerRequestPayload::ChatgptAuthTokensRefresh(params))
             .await;
 
         let result = match timeout(EXTERNAL_AUTH_REFRESH_TIMEOUT, rx).await {
-            Ok(result) => result.map_err(|err| {
-                std::io::Error::other(format!("auth refresh request canceled: {err}"))
-            })?,
+            Ok(Ok(result)) => result,
+            Ok(Err(_)) if outgoing.is_closed() => return Err(RefreshAttemptError::Disconnected),
+            Ok(Err(err)) => {
+                return Err(RefreshAttemptError::Failed(std::io::Error::other(format!(
+                    "auth refresh request canceled: {err}"
+                ))));
+            }
             Err(_) => {
-                let _canceled = self.outgoing.cancel_request(&request_id).await;
-                return Err(std::io::Error::other(format!(
+                let _canceled = outgoing.cancel_request(&request_id).await;
+                return Err(RefreshAttemptError::Failed(std::io::Error::other(format!(
                     "auth refresh request timed out after {}s",
                     EXTERNAL_AUTH_REFRESH_TIMEOUT.as_secs()
-                )));
+                ))));
             }
         };
 
-        let response: ChatgptAuthTokensRefreshResponse =
-            serde_json::from_value(result).map_err(std::io::Error::other)?;
+        let response: ChatgptAuthTokensRefreshResponse = serde_json::from_value(result)
+            .map_err(|err| RefreshAttemptError::Failed(std::io::Error::other(err)))?;
 
         Ok(ExternalAuthTokens {
             access_token: response.access_token,
@@ -107,6 +143,32 @@ impl ExternalAuthRefresher for ExternalAuthRefreshBridge {
     }
 }
 
+#[async_trait]
+impl ExternalAuthRefresher for ExternalAuthRefreshBridge {
+    /// Asks the most recently connected client for fresh tokens, moving on to
+    /// the next one if it disconnects before answering.
+    async fn refresh(
+        &self,
+        context: ExternalAuthRefreshContext,
+    ) -> std::io::Result<ExternalAuthTokens> {
+        let params = ChatgptAuthTokensRefreshParams {
+            reason: Self::map_reason(context.reason),
+            previous_account_id: context.previous_account_id,
+        };
+
+        for outgoing in self.clients.connected() {
+            match Self::refresh_with(&outgoing, params.clone()).await {
+                Ok(tokens) => return Ok(tokens),
+                Err(RefreshAttemptError::Disconnected) => continue,
+                Err(RefreshAttemptError::Failed(err)) => return Err(err),
+            }
+        }
+        Err(std::io::Error::other(
+            "no connected client is available to refresh auth tokens",
+        ))
+    }
+}
+
 pub(crate) struct MessageProcessor {
     outgoing: Arc<OutgoingMessageSender>,
     codex_message_processor: CodexMessageProcessor,
@@ -117,7 +179,44 @@ pub(crate) struct MessageProcessor {
     config_warnings: Vec<ConfigWarningNotification>,
 }
 
+/// State shared by every client connection, so threads outlive the
+/// connection that started them and can be observed by several clients.
+#[derive(Clone)]
+pub(crate) struct ServerState {
+    auth_manager: Arc<AuthManager>,
+    thread_manager: Arc<ThreadManager>,
+    thread_event_hub: Arc<ThreadEventHub>,
+    external_auth_clients: Arc<ExternalAuthClients>,
+}
+
+impl ServerState {
+    pub(crate) fn new(config: &Config) -> Self {
+        let auth_manager = AuthManager::shared(
+            config.codex_home.clone(),
+            false,
+            config.cli_auth_credentials_store_mode,
+        );
+        auth_manager.set_forced_chatgpt_workspace_id(config.forced_chatgpt_workspace_id.clone());
+        let external_auth_clients = Arc::new(ExternalAuthClients::default());
+        auth_manager.set_external_auth_refresher(Arc::new(ExternalAuthRefreshBridge {
+            clients: external_auth_clients.clone(),
+        }));
+        let thread_manager = Arc::new(ThreadManager::new(
+            config.codex_home.clone(),
+            auth_manager.clone(),
+            SessionSource::VSCode,
+        ));
+        Self {
+            auth_manager,
+            thread_manager,
+            thread_event_hub: Arc::new(ThreadEventHub::default()),
+            external_auth_clients,
+        }
+    }
+}
+
 pub(crate) struct MessageProcessorArgs {
+    pub(crate) state: ServerState,
     pub(crate) outgoing: OutgoingMessageSender,
     pub(crate) codex_linux_sandbox_exe: Option<PathBuf>,
     pub(crate) config: Arc<Config>,
@@ -133,6 +232,7 @@ impl MessageProcessor {
     /// `Sender` so handlers can enqueue messages to be written to stdout.
     pub(crate) fn new(args: MessageProcessorArgs) -> Self {
         let MessageProcessorArgs {
+            state,
             outgoing,
             codex_linux_sandbox_exe,
             config,
@@ -144,20 +244,13 @@ impl MessageProcessor {
         } = args;
         let outgoing = Arc::new(outgoing);
         let experimental_api_enabled = Arc::new(AtomicBool::new(false));
-        let auth_manager = AuthManager::shared(
-            config.codex_home.clone(),
-            false,
-            config.cli_auth_credentials_store_mode,
-        );
-        auth_manager.set_forced_chatgpt_workspace_id(config.forced_chatgpt_workspace_id.clone());
-        auth_manager.set_external_auth_refresher(Arc::new(ExternalAuthRefreshBridge {
-            outgoing: outgoing.clone(),
-        }));
-        let thread_manager = Arc::new(ThreadManager::new(
-            config.codex_home.clone(),
-            auth_manager.clone(),
-            SessionSource::VSCode,
-        ));
+        let ServerState {
+            auth_manager,
+            thread_manager,
+            thread_event_hub,
+            external_auth_clients,
+        } = state;
+        external_auth_clients.register(&outgoing);
         let cloud_requirements = Arc::new(RwLock::new(cloud_requirements));
         let codex_message_processor = CodexMessageProcessor::new(CodexMessageProcessorArgs {
             auth_manager,
@@ -168,6 +261,7 @@ impl MessageProcessor {
             cli_overrides: cli_overrides.clone(),
             cloud_requirements: cloud_requirements.clone(),
             feedback,
+            thread_event_hub,
         });
         let config_api = ConfigApi::new(
             config.codex_home.clone(),
@@ -408,3 +502,64 @@ impl MessageProcessor {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::outgoing_message::OutgoingMessage;
+    use codex_app_server_protocol::ServerRequest;
+    use pretty_assertions::assert_eq;
+    use tokio::sync::mpsc;
+
+    #[tokio::test]
+    async fn auth_refresh_skips_disconnected_clients() {
+        let clients = Arc::new(ExternalAuthClients::default());
+        let (live_tx, mut live_rx) = mpsc::channel(4);
+        let live = Arc::new(OutgoingMessageSender::new(live_tx));
+        let (gone_tx, gone_rx) = mpsc::channel(4);
+        let gone = Arc::new(OutgoingMessageSender::new(gone_tx));
+        clients.register(&live);
+        clients.register(&gone);
+        drop(gone_rx);
+
+        let bridge = ExternalAuthRefreshBridge { clients };
+        let refresh = tokio::spawn(async move {
+            bridge
+                .refresh(ExternalAuthRefreshContext {
+                    reason: ExternalAuthRefreshReason::Unauthorized,
+                    previous_account_id: None,
+                })
+                .await
+        });
+
+        let Some(OutgoingMessage::Request(ServerRequest::ChatgptAuthTokensRefresh {
+            request_id,
+            ..
+        })) = live_rx.recv().await
+        else {
+            panic!("expected a token refresh request on the connected client");
+        };
+        live.notify_client_response(
+            request_id,
+            serde_json::json!({
+                "accessToken": "token",
+                "chatgptAccountId": "account",
+                "chatgptPlanType": null,
+            }),
+        )
+        .await;
+
+        let tokens = refresh
+            .await
+            .expect("refresh task")
+            .expect("refresh succeeds");
+        assert_eq!(
+            tokens,
+            ExternalAuthTokens {
+                access_token: "token".to_string(),
+                chatgpt_account_id: "account".to_string(),
+                chatgpt_plan_type: None,
+            }
+        );
+    }
+}
@@ -38,6 +38,11 @@ impl OutgoingMessageSender {
         }
     }
 
+    /// True once the client behind this sender has disconnected.
+    pub(crate) fn is_closed(&self) -> bool {
+        self.sender.is_closed()
+    }
+
     pub(crate) async fn set_opted_out_notification_methods(&self, methods: Vec<String>) {
         let mut opted_out = self.opted_out_notification_methods.lock().await;
         opted_out.clear();
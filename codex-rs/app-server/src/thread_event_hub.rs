@@ -0,0 +1,470 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use codex_core::CodexThread;
+use codex_core::error::Result as CodexResult;
+use codex_protocol::ThreadId;
+use codex_protocol::protocol::Event;
+use codex_protocol::protocol::EventMsg;
+use codex_protocol::protocol::Op;
+use tokio::sync::Mutex;
+use tokio::sync::Notify;
+use tokio::sync::mpsc;
+use tracing::warn;
+
+/// Fans out the events of every loaded thread to all client connections that
+/// listen to it.
+///
+/// `CodexThread::next_event` hands each event to exactly one caller, so a
+/// single pump task per thread drains the events and forwards a copy to each
+/// subscriber. Requests that need an answer, such as approvals, go to a single
+/// subscriber instead, see [`EventRouting`], so that each one is answered once.
+#[derive(Default)]
+pub(crate) struct ThreadEventHub {
+    threads: Mutex<HashMap<ThreadId, ThreadEventFanout>>,
+}
+
+struct ThreadEventFanout {
+    thread: Arc<CodexThread>,
+    routing: EventRouting,
+    /// Wakes the pump when a new subscriber becomes the owner, so that it
+    /// notices if that subscriber goes away while the thread is idle.
+    owner_changed: Arc<Notify>,
+}
+
+/// Decides which subscribers receive each event of one thread.
+///
+/// Notifications go to every subscriber. Requests go only to the owner, the
+/// subscriber that has been listening the longest; when it goes away, the
+/// requests it left unanswered move to the next subscriber. A request stops
+/// being pending once its answer is submitted through [`ThreadResponder`].
+/// The turn status and the pending requests are kept so that a client that
+/// subscribes in the middle of a turn, or after everyone disconnected, can
+/// catch up.
+#[derive(Default)]
+struct EventRouting {
+    /// Oldest first; the first one owns the requests.
+    subscribers: Vec<Subscriber>,
+    owner: Option<u64>,
+    next_subscriber_id: u64,
+    replay: ReplayState,
+}
+
+struct Subscriber {
+    id: u64,
+    events: mpsc::UnboundedSender<Event>,
+}
+
+/// Streaming deltas and item events are not kept; clients read finished items
+/// with `thread/read`.
+#[derive(Default)]
+struct ReplayState {
+    /// The most recent `TurnStarted`, `TurnComplete` or `TurnAborted`.
+    turn_status: Option<Event>,
+    /// Requests raised during the current turn that are not answered yet.
+    pending_requests: Vec<Event>,
+}
+
+impl ReplayState {
+    fn record(&mut self, event: &Event) {
+        match &event.msg {
+            // Requests from an earlier turn can no longer be answered.
+            EventMsg::TurnStarted(_) | EventMsg::TurnComplete(_) | EventMsg::TurnAborted(_) => {
+                self.pending_requests.clear();
+                self.turn_status = Some(event.clone());
+            }
+            msg if is_request(msg) => self.pending_requests.push(event.clone()),
+            _ => {}
+        }
+    }
+
+    fn resolve(&mut self, answer: &Op) {
+        self.pending_requests
+            .retain(|request| !answers(answer, request));
+    }
+}
+
+/// Whether `answer` is the response to the `request` event.
+fn answers(answer: &Op, request: &Event) -> bool {
+    match (answer, &request.msg) {
+        (Op::ExecApproval { id, .. }, EventMsg::ExecApprovalRequest(ev)) => *id == ev.call_id,
+        (Op::PatchApproval { id, .. }, EventMsg::ApplyPatchApprovalRequest(ev)) => {
+            *id == ev.call_id
+        }
+        (Op::NetworkApproval { id, .. }, EventMsg::NetworkApprovalRequest(ev)) => *id == ev.id,
+        // Answers to `request_user_input` are keyed by the turn that asked.
+        (Op::UserInputAnswer { id, .. }, EventMsg::RequestUserInput(_)) => *id == request.id,
+        (Op::DynamicToolResponse { id, .. }, EventMsg::DynamicToolCallRequest(ev)) => {
+            *id == ev.call_id
+        }
+        (
+            Op::ResolveElicitation {
+                server_name,
+                request_id,
+                ..
+            },
+            EventMsg::ElicitationRequest(ev),
+        ) => *server_name == ev.server_name && *request_id == ev.id,
+        (
+            Op::ResolveSampling {
+                server_name,
+                request_id,
+                ..
+            },
+            EventMsg::SamplingRequest(ev),
+        ) => *server_name == ev.server_name && *request_id == ev.id,
+        _ => false,
+    }
+}
+
+/// Events the client has to answer with a response.
+fn is_request(msg: &EventMsg) -> bool {
+    matches!(
+        msg,
+        EventMsg::ExecApprovalRequest(_)
+            | EventMsg::ApplyPatchApprovalRequest(_)
+            | EventMsg::NetworkApprovalRequest(_)
+            | EventMsg::RequestUserInput(_)
+            | EventMsg::DynamicToolCallRequest(_)
+            | EventMsg::ElicitationRequest(_)
+            | EventMsg::SamplingRequest(_)
+    )
+}
+
+impl EventRouting {
+    /// Adds a subscriber and sends it the current turn status, plus the
+    /// pending requests if it becomes the owner. Returns whether it did.
+    fn subscribe(&mut self, events: mpsc::UnboundedSender<Event>) -> bool {
+        if let Some(turn_status) = &self.replay.turn_status {
+            let _ = events.send(turn_status.clone());
+        }
+        let id = self.next_subscriber_id;
+        self.next_subscriber_id += 1;
+        self.subscribers.push(Subscriber { id, events });
+        self.hand_over_requests();
+        self.owner == Some(id)
+    }
+
+    fn deliver(&mut self, event: Event) {
+        if is_request(&event.msg) {
+            // Settle the owner first so a new owner does not get this request
+            // twice, once from the hand-over and once below.
+            self.hand_over_requests();
+            self.replay.record(&event);
+            if let Some(owner) = self.subscribers.first() {
+                let _ = owner.events.send(event);
+            }
+        } else {
+            self.replay.record(&event);
+            self.subscribers
+                .retain(|subscriber| subscriber.events.send(event.clone()).is_ok());
+        }
+        self.hand_over_requests();
+    }
+
+    /// Drops subscribers that went away and, if the owner was one of them,
+    /// sends the pending requests to the next subscriber.
+    fn hand_over_requests(&mut self) {
+        loop {
+            self.subscribers
+                .retain(|subscriber| !subscriber.events.is_closed());
+            let Some(owner) = self.subscribers.first() else {
+                self.owner = None;
+                return;
+            };
+            if self.owner == Some(owner.id) {
+                return;
+            }
+            self.owner = Some(owner.id);
+            let delivered = self
+                .replay
+                .pending_requests
+                .iter()
+                .all(|request| owner.events.send(request.clone()).is_ok());
+            if delivered {
+                return;
+            }
+        }
+    }
+
+    fn owner_events(&self) -> Option<mpsc::UnboundedSender<Event>> {
+        self.subscribers
+            .first()
+            .map(|subscriber| subscriber.events.clone())
+    }
+}
+
+impl ThreadEventHub {
+    /// Subscribes to the events of `thread`, starting its pump on first use.
+    /// The returned receiver closes once the thread stops producing events.
+    pub(crate) async fn subscribe(
+        self: &Arc<Self>,
+        thread_id: ThreadId,
+        thread: Arc<CodexThread>,
+    ) -> mpsc::UnboundedReceiver<Event> {
+        let (tx, rx) = mpsc::unbounded_channel();
+        let mut threads = self.threads.lock().await;
+        // A resumed thread replaces the previous instance with the same id.
+        let needs_pump = threads
+            .get(&thread_id)
+            .is_none_or(|fanout| !Arc::ptr_eq(&fanout.thread, &thread));
+        if needs_pump {
+            threads.insert(
+                thread_id,
+                ThreadEventFanout {
+                    thread: Arc::clone(&thread),
+                    routing: EventRouting::default(),
+                    owner_changed: Arc::new(Notify::new()),
+                },
+            );
+            tokio::spawn(Arc::clone(self).pump(thread_id, thread));
+        }
+        if let Some(fanout) = threads.get_mut(&thread_id)
+            && fanout.routing.subscribe(tx)
+        {
+            fanout.owner_changed.notify_one();
+        }
+        rx
+    }
+
+    /// Stops replaying the request that `answer` responds to.
+    async fn resolve(&self, thread_id: ThreadId, thread: &Arc<CodexThread>, answer: &Op) {
+        let mut threads = self.threads.lock().await;
+        if let Some(fanout) = Self::current_mut(&mut threads, thread_id, thread) {
+            fanout.routing.replay.resolve(answer);
+        }
+    }
+
+    async fn pump(self: Arc<Self>, thread_id: ThreadId, thread: Arc<CodexThread>) {
+        loop {
+            let (owner, owner_changed) = {
+                let threads = self.threads.lock().await;
+                let Some(fanout) = Self::current(&threads, thread_id, &thread) else {
+                    // Superseded by a newer instance of the thread.
+                    return;
+                };
+                (
+                    fanout.routing.owner_events(),
+                    Arc::clone(&fanout.owner_changed),
+                )
+            };
+            let event = tokio::select! {
+                event = thread.next_event() => event,
+                () = closed(owner) => {
+                    // The owner left while the thread waits for its answer.
+                    let mut threads = self.threads.lock().await;
+                    if let Some(fanout) = Self::current_mut(&mut threads, thread_id, &thread) {
+                        fanout.routing.hand_over_requests();
+                    }
+                    continue;
+                }
+                () = owner_changed.notified() => continue,
+            };
+            let event = match event {
+                Ok(event) => event,
+                Err(err) => {
+                    warn!("thread.next_event() failed with: {err}");
+                    break;
+                }
+            };
+            let mut threads = self.threads.lock().await;
+            let Some(fanout) = Self::current_mut(&mut threads, thread_id, &thread) else {
+                return;
+            };
+            fanout.routing.deliver(event);
+        }
+
+        let mut threads = self.threads.lock().await;
+        if Self::current(&threads, thread_id, &thread).is_some() {
+            threads.remove(&thread_id);
+        }
+    }
+
+    fn current<'a>(
+        threads: &'a HashMap<ThreadId, ThreadEventFanout>,
+        thread_id: ThreadId,
+        thread: &Arc<CodexThread>,
+    ) -> Option<&'a ThreadEventFanout> {
+        threads
+            .get(&thread_id)
+            .filter(|fanout| Arc::ptr_eq(&fanout.thread, thread))
+    }
+
+    fn current_mut<'a>(
+        threads: &'a mut HashMap<ThreadId, ThreadEventFanout>,
+        thread_id: ThreadId,
+        thread: &Arc<CodexThread>,
+    ) -> Option<&'a mut ThreadEventFanout> {
+        threads
+            .get_mut(&thread_id)
+            .filter(|fanout| Arc::ptr_eq(&fanout.thread, thread))
+    }
+}
+
+/// Submits the answers to the requests of one thread, so that the hub stops
+/// handing them to other subscribers once they are answered.
+#[derive(Clone)]
+pub(crate) struct ThreadResponder {
+    hub: Arc<ThreadEventHub>,
+    thread_id: ThreadId,
+    thread: Arc<CodexThread>,
+}
+
+impl ThreadResponder {
+    pub(crate) fn new(
+        hub: Arc<ThreadEventHub>,
+        thread_id: ThreadId,
+        thread: Arc<CodexThread>,
+    ) -> Self {
+        Self {
+            hub,
+            thread_id,
+            thread,
+        }
+    }
+
+    pub(crate) async fn submit(&self, answer: Op) -> CodexResult<String> {
+        self.hub
+            .resolve(self.thread_id, &self.thread, &answer)
+            .await;
+        self.thread.submit(answer).await
+    }
+}
+
+/// Resolves once `owner` has gone away, or never if there is no owner.
+async fn closed(owner: Option<mpsc::UnboundedSender<Event>>) {
+    match owner {
+        Some(owner) => owner.closed().await,
+        None => std::future::pending().await,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use codex_protocol::config_types::ModeKind;
+    use codex_protocol::protocol::AgentMessageDeltaEvent;
+    use codex_protocol::protocol::NetworkApprovalDecision;
+    use codex_protocol::protocol::NetworkApprovalRequestEvent;
+    use codex_protocol::protocol::TurnCompleteEvent;
+    use codex_protocol::protocol::TurnStartedEvent;
+    use pretty_assertions::assert_eq;
+
+    fn event(id: &str, msg: EventMsg) -> Event {
+        Event {
+            id: id.to_string(),
+            msg,
+        }
+    }
+
+    fn turn_started(id: &str) -> Event {
+        event(
+            id,
+            EventMsg::TurnStarted(TurnStartedEvent {
+                model_context_window: None,
+                collaboration_mode_kind: ModeKind::default(),
+            }),
+        )
+    }
+
+    fn approval(id: &str) -> Event {
+        event(
+            id,
+            EventMsg::NetworkApprovalRequest(NetworkApprovalRequestEvent {
+                id: id.to_string(),
+                turn_id: "turn".to_string(),
+                host: "example.com".to_string(),
+                port: 443,
+                protocol: "https".to_string(),
+                method: None,
+            }),
+        )
+    }
+
+    fn received(events: &mut mpsc::UnboundedReceiver<Event>) -> Vec<String> {
+        let mut ids = Vec::new();
+        while let Ok(event) = events.try_recv() {
+            ids.push(event.id);
+        }
+        ids
+    }
+
+    fn subscribe(routing: &mut EventRouting) -> mpsc::UnboundedReceiver<Event> {
+        let (tx, rx) = mpsc::unbounded_channel();
+        routing.subscribe(tx);
+        rx
+    }
+
+    #[test]
+    fn replay_keeps_turn_status_and_pending_requests_only() {
+        let mut routing = EventRouting::default();
+        routing.deliver(turn_started("turn-1"));
+        for _ in 0..1000 {
+            routing.deliver(event(
+                "delta",
+                EventMsg::AgentMessageDelta(AgentMessageDeltaEvent {
+                    delta: "x".to_string(),
+                }),
+            ));
+        }
+        routing.deliver(approval("approval-1"));
+
+        let mut events = subscribe(&mut routing);
+        assert_eq!(received(&mut events), vec!["turn-1", "approval-1"]);
+    }
+
+    #[test]
+    fn finished_turn_drops_its_pending_requests() {
+        let mut routing = EventRouting::default();
+        routing.deliver(turn_started("turn-1"));
+        routing.deliver(approval("approval-1"));
+        routing.deliver(event(
+            "turn-1-done",
+            EventMsg::TurnComplete(TurnCompleteEvent {
+                last_agent_message: None,
+            }),
+        ));
+
+        let mut events = subscribe(&mut routing);
+        assert_eq!(received(&mut events), vec!["turn-1-done"]);
+    }
+
+    #[test]
+    fn requests_go_to_one_subscriber_and_move_on_when_it_leaves() {
+        let mut routing = EventRouting::default();
+        let mut first = subscribe(&mut routing);
+        let mut second = subscribe(&mut routing);
+        routing.deliver(turn_started("turn-1"));
+        routing.deliver(approval("approval-1"));
+
+        assert_eq!(received(&mut first), vec!["turn-1", "approval-1"]);
+        assert_eq!(received(&mut second), vec!["turn-1"]);
+
+        drop(first);
+        routing.hand_over_requests();
+        assert_eq!(received(&mut second), vec!["approval-1"]);
+
+        let mut third = subscribe(&mut routing);
+        routing.deliver(approval("approval-2"));
+        assert_eq!(received(&mut second), vec!["approval-2"]);
+        assert_eq!(received(&mut third), vec!["turn-1"]);
+    }
+    #[test]
+    fn answered_requests_are_not_handed_over() {
+        let mut routing = EventRouting::default();
+        let mut first = subscribe(&mut routing);
+        let mut second = subscribe(&mut routing);
+        routing.deliver(turn_started("turn-1"));
+        routing.deliver(approval("approval-1"));
+        assert_eq!(received(&mut first), vec!["turn-1", "approval-1"]);
+        assert_eq!(received(&mut second), vec!["turn-1"]);
+
+        routing.replay.resolve(&Op::NetworkApproval {
+            id: "approval-1".to_string(),
+            decision: NetworkApprovalDecision::AllowOnce,
+        });
+        drop(first);
+        routing.hand_over_requests();
+        assert_eq!(received(&mut second), Vec::<String>::new());
+    }
+}
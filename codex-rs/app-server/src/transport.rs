@@ -0,0 +1,427 @@
+use std::fmt;
+use std::net::SocketAddr;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use codex_app_server_protocol::JSONRPCMessage;
+use futures::SinkExt;
+use futures::StreamExt;
+use tokio::io::AsyncBufReadExt;
+use tokio::io::AsyncRead;
+use tokio::io::AsyncWrite;
+use tokio::io::AsyncWriteExt;
+use tokio::io::BufReader;
+use tokio::net::TcpListener;
+use tokio::sync::mpsc;
+use tokio_tungstenite::WebSocketStream;
+use tokio_tungstenite::tungstenite::Message;
+use tokio_tungstenite::tungstenite::handshake::server::ErrorResponse;
+use tokio_tungstenite::tungstenite::handshake::server::Request;
+use tokio_tungstenite::tungstenite::handshake::server::Response;
+use tokio_tungstenite::tungstenite::http::StatusCode;
+use tokio_tungstenite::tungstenite::http::header::ORIGIN;
+use tracing::debug;
+use tracing::error;
+use tracing::info;
+use tracing::warn;
+
+use crate::CHANNEL_CAPACITY;
+use crate::outgoing_message::OutgoingMessage;
+
+/// Where the app server accepts clients, selected with `--listen`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum AppServerTransport {
+    /// A single client speaking newline-delimited JSON over stdin/stdout.
+    #[default]
+    Stdio,
+    /// Any number of WebSocket clients; each text frame carries one message.
+    WebSocket { bind_address: SocketAddr },
+    /// Any number of clients speaking newline-delimited JSON over a Unix
+    /// domain socket.
+    UnixSocket { path: PathBuf },
+}
+
+impl FromStr for AppServerTransport {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        if value == "stdio" || value == "stdio://" {
+            return Ok(Self::Stdio);
+        }
+        if let Some(address) = value.strip_prefix("ws://") {
+            let address = address.trim_end_matches('/');
+            return address
+                .parse()
+                .map(|bind_address| Self::WebSocket { bind_address })
+                .map_err(|err| format!("invalid WebSocket address `{address}`: {err}"));
+        }
+        if let Some(path) = value.strip_prefix("unix://") {
+            if path.is_empty() {
+                return Err("unix:// requires a socket path".to_string());
+            }
+            return Ok(Self::UnixSocket {
+                path: PathBuf::from(path),
+            });
+        }
+        Err(format!(
+            "unsupported listen URL `{value}`; expected stdio://, ws://IP:PORT or unix:///path.sock"
+        ))
+    }
+}
+
+impl fmt::Display for AppServerTransport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Stdio => write!(f, "stdio://"),
+            Self::WebSocket { bind_address } => write!(f, "ws://{bind_address}"),
+            Self::UnixSocket { path } => write!(f, "unix://{}", path.display()),
+        }
+    }
+}
+
+/// Channels that connect one client to its message processor.
+pub(crate) struct ClientConnection {
+    pub(crate) incoming_rx: mpsc::Receiver<JSONRPCMessage>,
+    pub(crate) outgoing_tx: mpsc::Sender<OutgoingMessage>,
+}
+
+/// Accepts clients on `transport` and hands each one to `connection_tx`
+/// until the listener fails or the receiving side goes away.
+pub(crate) async fn accept_connections(
+    transport: AppServerTransport,
+    connection_tx: mpsc::Sender<ClientConnection>,
+) -> std::io::Result<()> {
+    match transport {
+        AppServerTransport::Stdio => Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidInput,
+            "stdio does not accept connections",
+        )),
+        AppServerTransport::WebSocket { bind_address } => {
+            let listener = TcpListener::bind(bind_address).await?;
+            if !bind_address.ip().is_loopback() {
+                warn!(
+                    "app server is listening on non-loopback address {bind_address}; any client that can reach it can control Codex"
+                );
+            }
+            info!("app server listening on ws://{}", listener.local_addr()?);
+            loop {
+                let (stream, peer) = listener.accept().await?;
+                if connection_tx.is_closed() {
+                    return Ok(());
+                }
+                let connection_tx = connection_tx.clone();
+                tokio::spawn(async move {
+                    let websocket = match accept_websocket(stream).await {
+                        Ok(websocket) => websocket,
+                        Err(err) => {
+                            warn!("WebSocket handshake with {peer} failed: {err}");
+                            return;
+                        }
+                    };
+                    let (connection, channels) = client_channels();
+                    if connection_tx.send(connection).await.is_err() {
+                        return;
+                    }
+                    serve_websocket(websocket, channels).await;
+                    debug!("WebSocket client {peer} disconnected");
+                });
+            }
+        }
+        #[cfg(unix)]
+        AppServerTransport::UnixSocket { path } => {
+            let listener = tokio::net::UnixListener::bind(&path).map_err(|err| {
+                std::io::Error::new(
+                    err.kind(),
+                    format!("failed to bind {}: {err}", path.display()),
+                )
+            })?;
+            let _socket_file = UnixSocketFile(path.clone());
+            info!("app server listening on unix://{}", path.display());
+            loop {
+                let (stream, _) = listener.accept().await?;
+                let (connection, channels) = client_channels();
+                if connection_tx.send(connection).await.is_err() {
+                    return Ok(());
+                }
+                tokio::spawn(async move {
+                    let (reader, writer) = stream.into_split();
+                    serve_lines(reader, writer, channels).await;
+                    debug!("Unix socket client disconnected");
+                });
+            }
+        }
+        #[cfg(not(unix))]
+        AppServerTransport::UnixSocket { .. } => Err(std::io::Error::new(
+            std::io::ErrorKind::Unsupported,
+            "unix:// is not supported on this platform",
+        )),
+    }
+}
+
+/// Completes the WebSocket handshake, refusing requests sent by a browser.
+///
+/// Browsers attach an `Origin` header to every WebSocket handshake and let any
+/// page connect to `ws://127.0.0.1`, so a page the user happens to have open
+/// could otherwise drive Codex. Native clients do not send `Origin`.
+async fn accept_websocket<S>(
+    stream: S,
+) -> Result<WebSocketStream<S>, tokio_tungstenite::tungstenite::Error>
+where
+    S: AsyncRead + AsyncWrite + Unpin,
+{
+    tokio_tungstenite::accept_hdr_async(stream, reject_browser_origin).await
+}
+
+#[allow(clippy::result_large_err)]
+fn reject_browser_origin(request: &Request, response: Response) -> Result<Response, ErrorResponse> {
+    let Some(origin) = request.headers().get(ORIGIN) else {
+        return Ok(response);
+    };
+    warn!("refusing WebSocket connection from browser origin {origin:?}");
+    let mut rejection = ErrorResponse::new(Some(
+        "WebSocket connections from browsers are not allowed".to_string(),
+    ));
+    *rejection.status_mut() = StatusCode::FORBIDDEN;
+    Err(rejection)
+}
+
+/// Removes the socket file once the listener shuts down.
+#[cfg(unix)]
+struct UnixSocketFile(PathBuf);
+
+#[cfg(unix)]
+impl Drop for UnixSocketFile {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.0);
+    }
+}
+
+/// Transport-side ends of a [`ClientConnection`].
+struct ClientChannels {
+    incoming_tx: mpsc::Sender<JSONRPCMessage>,
+    outgoing_rx: mpsc::Receiver<OutgoingMessage>,
+}
+
+fn client_channels() -> (ClientConnection, ClientChannels) {
+    let (incoming_tx, incoming_rx) = mpsc::channel(CHANNEL_CAPACITY);
+    let (outgoing_tx, outgoing_rx) = mpsc::channel(CHANNEL_CAPACITY);
+    (
+        ClientConnection {
+            incoming_rx,
+            outgoing_tx,
+        },
+        ClientChannels {
+            incoming_tx,
+            outgoing_rx,
+        },
+    )
+}
+
+fn serialize_outgoing(message: OutgoingMessage) -> Option<String> {
+    let Ok(value) = serde_json::to_value(message) else {
+        error!("Failed to convert OutgoingMessage to JSON value");
+        return None;
+    };
+    match serde_json::to_string(&value) {
+        Ok(json) => Some(json),
+        Err(e) => {
+            error!("Failed to serialize JSONRPCMessage: {e}");
+            None
+        }
+    }
+}
+
+/// Serves one newline-delimited JSON client until it disconnects. Unlike
+/// stdio, pending responses are dropped once the client hangs up.
+async fn serve_lines<R, W>(reader: R, mut writer: W, channels: ClientChannels)
+where
+    R: AsyncRead + Unpin,
+    W: AsyncWrite + Unpin,
+{
+    let ClientChannels {
+        incoming_tx,
+        mut outgoing_rx,
+    } = channels;
+    let mut lines = BufReader::new(reader).lines();
+    loop {
+        tokio::select! {
+            line = lines.next_line() => {
+                let Ok(Some(line)) = line else {
+                    break;
+                };
+                match serde_json::from_str::<JSONRPCMessage>(&line) {
+                    Ok(msg) => {
+                        if incoming_tx.send(msg).await.is_err() {
+                            break;
+                        }
+                    }
+                    Err(e) => error!("Failed to deserialize JSONRPCMessage: {e}"),
+                }
+            }
+            outgoing = outgoing_rx.recv() => {
+                let Some(outgoing) = outgoing else {
+                    break;
+                };
+                let Some(mut json) = serialize_outgoing(outgoing) else {
+                    continue;
+                };
+                json.push('\n');
+                if let Err(e) = writer.write_all(json.as_bytes()).await {
+                    warn!("Failed to write to client: {e}");
+                    break;
+                }
+            }
+        }
+    }
+}
+
+async fn serve_websocket<S>(
+    mut websocket: tokio_tungstenite::WebSocketStream<S>,
+    channels: ClientChannels,
+) where
+    S: AsyncRead + AsyncWrite + Unpin,
+{
+    let ClientChannels {
+        incoming_tx,
+        mut outgoing_rx,
+    } = channels;
+    loop {
+        tokio::select! {
+            frame = websocket.next() => {
+                let text = match frame {
+                    Some(Ok(Message::Text(text))) => text,
+                    Some(Ok(Message::Close(_))) | None => break,
+                    Some(Ok(_)) => continue,
+                    Some(Err(err)) => {
+                        warn!("WebSocket read failed: {err}");
+                        break;
+                    }
+                };
+                match serde_json::from_str::<JSONRPCMessage>(text.as_str()) {
+                    Ok(msg) => {
+                        if incoming_tx.send(msg).await.is_err() {
+                            break;
+                        }
+                    }
+                    Err(e) => error!("Failed to deserialize JSONRPCMessage: {e}"),
+                }
+            }
+            outgoing = outgoing_rx.recv() => {
+                let Some(outgoing) = outgoing else {
+                    break;
+                };
+                let Some(json) = serialize_outgoing(outgoing) else {
+                    continue;
+                };
+                if let Err(err) = websocket.send(Message::Text(json.into())).await {
+                    warn!("WebSocket write failed: {err}");
+                    break;
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use codex_app_server_protocol::RequestId;
+    use pretty_assertions::assert_eq;
+
+    use crate::outgoing_message::OutgoingResponse;
+
+    #[test]
+    fn parses_listen_urls() {
+        assert_eq!("stdio://".parse(), Ok(AppServerTransport::Stdio));
+        assert_eq!(
+            "ws://127.0.0.1:4500".parse(),
+            Ok(AppServerTransport::WebSocket {
+                bind_address: "127.0.0.1:4500".parse().expect("address"),
+            })
+        );
+        assert_eq!(
+            "unix:///tmp/codex.sock".parse(),
+            Ok(AppServerTransport::UnixSocket {
+                path: PathBuf::from("/tmp/codex.sock"),
+            })
+        );
+        assert!("ws://localhost".parse::<AppServerTransport>().is_err());
+        assert!("unix://".parse::<AppServerTransport>().is_err());
+        assert!(
+            "http://127.0.0.1:4500"
+                .parse::<AppServerTransport>()
+                .is_err()
+        );
+    }
+
+    async fn websocket_handshake(origin: Option<&str>) -> bool {
+        let listener = TcpListener::bind("127.0.0.1:0").await.expect("bind");
+        let address = listener.local_addr().expect("local addr");
+        let server = tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.expect("accept");
+            accept_websocket(stream).await.is_ok()
+        });
+
+        let mut request =
+            tokio_tungstenite::tungstenite::client::IntoClientRequest::into_client_request(
+                format!("ws://{address}"),
+            )
+            .expect("request");
+        if let Some(origin) = origin {
+            request
+                .headers_mut()
+                .insert(ORIGIN, origin.parse().expect("origin header"));
+        }
+        let client_ok = tokio_tungstenite::connect_async(request).await.is_ok();
+        let server_ok = server.await.expect("server task");
+        assert_eq!(client_ok, server_ok);
+        server_ok
+    }
+
+    #[tokio::test]
+    async fn websocket_accepts_clients_without_origin() {
+        assert!(websocket_handshake(None).await);
+    }
+
+    #[tokio::test]
+    async fn websocket_refuses_browser_origin() {
+        assert!(!websocket_handshake(Some("https://evil.example")).await);
+    }
+
+    #[tokio::test]
+    async fn line_connection_round_trips_messages() {
+        let (client, server) = tokio::io::duplex(4096);
+        let (server_reader, server_writer) = tokio::io::split(server);
+        let (mut connection, channels) = client_channels();
+        let serve = tokio::spawn(serve_lines(server_reader, server_writer, channels));
+
+        let (client_reader, mut client_writer) = tokio::io::split(client);
+        client_writer
+            .write_all(b"{\"id\":1,\"method\":\"initialize\"}\n")
+            .await
+            .expect("write request");
+        let Some(JSONRPCMessage::Request(request)) = connection.incoming_rx.recv().await else {
+            panic!("expected a request");
+        };
+        assert_eq!(request.id, RequestId::Integer(1));
+
+        connection
+            .outgoing_tx
+            .send(OutgoingMessage::Response(OutgoingResponse {
+                id: RequestId::Integer(1),
+                result: serde_json::json!({}),
+            }))
+            .await
+            .expect("send response");
+        let mut lines = BufReader::new(client_reader).lines();
+        let line = lines.next_line().await.expect("read").expect("line");
+        assert_eq!(
+            serde_json::from_str::<serde_json::Value>(&line).expect("json"),
+            serde_json::json!({ "id": 1, "result": {} })
+        );
+
+        drop(client_writer);
+        drop(lines);
+        serve.await.expect("serve task");
+    }
+}
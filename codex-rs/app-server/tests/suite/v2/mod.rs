@@ -16,12 +16,14 @@ mod request_user_input;
 mod review;
 mod skills_list;
 mod thread_archive;
+mod thread_export;
 mod thread_fork;
 mod thread_list;
 mod thread_loaded_list;
 mod thread_read;
 mod thread_resume;
 mod thread_rollback;
+mod thread_search;
 mod thread_start;
 mod thread_unarchive;
 mod turn_interrupt;
@@ -0,0 +1,83 @@
+use anyhow::Result;
+use app_test_support::McpProcess;
+use app_test_support::create_fake_rollout;
+use app_test_support::to_response;
+use codex_app_server_protocol::JSONRPCError;
+use codex_app_server_protocol::JSONRPCResponse;
+use codex_app_server_protocol::RequestId;
+use codex_app_server_protocol::ThreadExportFormat;
+use codex_app_server_protocol::ThreadExportParams;
+use codex_app_server_protocol::ThreadExportResponse;
+use pretty_assertions::assert_eq;
+use tempfile::TempDir;
+use tokio::time::timeout;
+
+const DEFAULT_READ_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(10);
+
+#[tokio::test]
+async fn thread_export_renders_markdown_transcript() -> Result<()> {
+    let codex_home = TempDir::new()?;
+    let thread_id = create_fake_rollout(
+        codex_home.path(),
+        "2025-01-02T12-00-00",
+        "2025-01-02T12:00:00Z",
+        "Fix the login redirect",
+        Some("mock_provider"),
+        None,
+    )?;
+
+    let mut mcp = McpProcess::new(codex_home.path()).await?;
+    timeout(DEFAULT_READ_TIMEOUT, mcp.initialize()).await??;
+
+    let request_id = mcp
+        .send_thread_export_request(ThreadExportParams {
+            thread_id: thread_id.clone(),
+            format: ThreadExportFormat::Markdown,
+        })
+        .await?;
+    let response: JSONRPCResponse = timeout(
+        DEFAULT_READ_TIMEOUT,
+        mcp.read_stream_until_response_message(RequestId::Integer(request_id)),
+    )
+    .await??;
+    let ThreadExportResponse { content } = to_response::<ThreadExportResponse>(response)?;
+
+    assert_eq!(
+        content,
+        format!(
+            "# Codex thread {thread_id}\n\n\
+             - **Thread:** `{thread_id}`\n\
+             - **Started:** `2025-01-02T12:00:00Z`\n\
+             - **Directory:** `/`\n\
+             - **Model provider:** `mock_provider`\n\
+             - **CLI version:** `0.0.0`\n\
+             \n## User\n\nFix the login redirect\n"
+        )
+    );
+    Ok(())
+}
+
+#[tokio::test]
+async fn thread_export_rejects_unknown_thread() -> Result<()> {
+    let codex_home = TempDir::new()?;
+    let mut mcp = McpProcess::new(codex_home.path()).await?;
+    timeout(DEFAULT_READ_TIMEOUT, mcp.initialize()).await??;
+
+    let request_id = mcp
+        .send_thread_export_request(ThreadExportParams {
+            thread_id: "67e55044-10b1-426f-9247-bb680e5fe0c8".to_string(),
+            format: ThreadExportFormat::Json,
+        })
+        .await?;
+    let error: JSONRPCError = timeout(
+        DEFAULT_READ_TIMEOUT,
+        mcp.read_stream_until_error_message(RequestId::Integer(request_id)),
+    )
+    .await??;
+
+    assert_eq!(
+        error.error.message,
+        "no rollout found for thread id 67e55044-10b1-426f-9247-bb680e5fe0c8"
+    );
+    Ok(())
+}
@@ -0,0 +1,77 @@
+use anyhow::Result;
+use app_test_support::McpProcess;
+use app_test_support::create_fake_rollout;
+use app_test_support::to_response;
+use codex_app_server_protocol::ByteRange;
+use codex_app_server_protocol::JSONRPCResponse;
+use codex_app_server_protocol::RequestId;
+use codex_app_server_protocol::ThreadSearchMatchKind;
+use codex_app_server_protocol::ThreadSearchParams;
+use codex_app_server_protocol::ThreadSearchResponse;
+use pretty_assertions::assert_eq;
+use tempfile::TempDir;
+use tokio::time::timeout;
+
+const DEFAULT_READ_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(10);
+
+#[tokio::test]
+async fn thread_search_returns_matching_threads_with_highlights() -> Result<()> {
+    let codex_home = TempDir::new()?;
+    std::fs::write(
+        codex_home.path().join("config.toml"),
+        "[features]\nsqlite = true\n",
+    )?;
+    let login_thread_id = create_fake_rollout(
+        codex_home.path(),
+        "2025-01-02T12-00-00",
+        "2025-01-02T12:00:00Z",
+        "Fix the login redirect",
+        Some("mock_provider"),
+        None,
+    )?;
+    create_fake_rollout(
+        codex_home.path(),
+        "2025-01-01T12-00-00",
+        "2025-01-01T12:00:00Z",
+        "Add a dark mode toggle",
+        Some("mock_provider"),
+        None,
+    )?;
+
+    let mut mcp = McpProcess::new(codex_home.path()).await?;
+    timeout(DEFAULT_READ_TIMEOUT, mcp.initialize()).await??;
+
+    // Threads recorded before the index existed are indexed in the background,
+    // so the first searches may come back empty.
+    let mut data = Vec::new();
+    for _ in 0..50 {
+        let request_id = mcp
+            .send_thread_search_request(ThreadSearchParams {
+                query: "LOGIN".to_string(),
+                limit: None,
+                archived: None,
+            })
+            .await?;
+        let response: JSONRPCResponse = timeout(
+            DEFAULT_READ_TIMEOUT,
+            mcp.read_stream_until_response_message(RequestId::Integer(request_id)),
+        )
+        .await??;
+        data = to_response::<ThreadSearchResponse>(response)?.data;
+        if !data.is_empty() {
+            break;
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+    }
+
+    assert_eq!(
+        data.iter()
+            .map(|result| result.thread.id.as_str())
+            .collect::<Vec<_>>(),
+        vec![login_thread_id.as_str()]
+    );
+    assert_eq!(data[0].match_kind, ThreadSearchMatchKind::UserMessage);
+    assert_eq!(data[0].snippet, "Fix the login redirect");
+    assert_eq!(data[0].highlights, vec![ByteRange { start: 8, end: 13 }]);
+    Ok(())
+}
@@ -0,0 +1,84 @@
+use std::path::PathBuf;
+
+use anyhow::Context;
+use clap::Parser;
+use clap::ValueEnum;
+use codex_common::CliConfigOverrides;
+use codex_core::config::Config;
+use codex_core::find_archived_thread_path_by_id_str;
+use codex_core::find_thread_path_by_id_str;
+use codex_core::find_thread_path_by_name_str;
+use codex_core::thread_export::ThreadExportFormat;
+use codex_core::thread_export::export_thread;
+
+/// Render a recorded thread as a transcript.
+#[derive(Debug, Parser)]
+pub struct ExportCommand {
+    /// Thread id (UUID) or thread name to export. Archived threads are found by id.
+    #[arg(value_name = "THREAD_ID")]
+    pub thread_id: String,
+
+    /// Transcript format.
+    #[arg(long, short = 'f', value_enum, default_value_t = ExportFormatArg::Md)]
+    pub format: ExportFormatArg,
+
+    /// Write the transcript to this file instead of stdout.
+    #[arg(long, short = 'o', value_name = "FILE")]
+    pub output: Option<PathBuf>,
+
+    #[clap(flatten)]
+    pub config_overrides: CliConfigOverrides,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ExportFormatArg {
+    #[value(alias = "markdown")]
+    Md,
+    Html,
+    Json,
+}
+
+impl From<ExportFormatArg> for ThreadExportFormat {
+    fn from(format: ExportFormatArg) -> Self {
+        match format {
+            ExportFormatArg::Md => ThreadExportFormat::Markdown,
+            ExportFormatArg::Html => ThreadExportFormat::Html,
+            ExportFormatArg::Json => ThreadExportFormat::Json,
+        }
+    }
+}
+
+impl ExportCommand {
+    pub async fn run(self) -> anyhow::Result<()> {
+        let overrides = self
+            .config_overrides
+            .parse_overrides()
+            .map_err(anyhow::Error::msg)?;
+        let config = Config::load_with_cli_overrides(overrides)
+            .await
+            .context("failed to load configuration")?;
+
+        let codex_home = config.codex_home.as_path();
+        let thread_id = self.thread_id.as_str();
+        let rollout_path = match find_thread_path_by_id_str(codex_home, thread_id).await? {
+            Some(path) => Some(path),
+            None => match find_archived_thread_path_by_id_str(codex_home, thread_id).await? {
+                Some(path) => Some(path),
+                None => find_thread_path_by_name_str(codex_home, thread_id).await?,
+            },
+        };
+        let Some(rollout_path) = rollout_path else {
+            anyhow::bail!("no recorded thread with id or name `{thread_id}`");
+        };
+
+        let transcript = export_thread(&config, &rollout_path, self.format.into())
+            .await
+            .with_context(|| format!("failed to export {}", rollout_path.display()))?;
+        match self.output {
+            Some(path) => std::fs::write(&path, transcript)
+                .with_context(|| format!("failed to write {}", path.display()))?,
+            None => print!("{transcript}"),
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,106 @@
+use std::path::PathBuf;
+
+use anyhow::Context;
+use clap::Parser;
+use codex_core::config::find_codex_home;
+use codex_core::i18n;
+
+/// Work with UI translation catalogs.
+#[derive(Debug, Parser)]
+pub struct I18nCli {
+    #[command(subcommand)]
+    pub subcommand: I18nSubcommand,
+}
+
+#[derive(Debug, clap::Subcommand)]
+pub enum I18nSubcommand {
+    /// Compare catalogs with the built-in en.toml and report missing or extra keys.
+    Check(I18nCheckCommand),
+}
+
+#[derive(Debug, Parser)]
+pub struct I18nCheckCommand {
+    /// Catalog files to check. Defaults to every `*.toml` in `$CODEX_HOME/i18n`.
+    #[arg(value_name = "CATALOG")]
+    pub catalogs: Vec<PathBuf>,
+}
+
+impl I18nCli {
+    pub fn run(self) -> anyhow::Result<()> {
+        match self.subcommand {
+            I18nSubcommand::Check(cmd) => cmd.run(),
+        }
+    }
+}
+
+impl I18nCheckCommand {
+    fn run(self) -> anyhow::Result<()> {
+        let catalogs = if self.catalogs.is_empty() {
+            default_catalogs()?
+        } else {
+            self.catalogs
+        };
+        if catalogs.is_empty() {
+            println!("No i18n catalogs to check.");
+            return Ok(());
+        }
+
+        let mut failed = 0;
+        for path in &catalogs {
+            let raw = std::fs::read_to_string(path)
+                .with_context(|| format!("failed to read {}", path.display()))?;
+            let report = match i18n::check_catalog(&raw) {
+                Ok(report) => report,
+                Err(err) => {
+                    println!("{}: invalid catalog: {err}", path.display());
+                    failed += 1;
+                    continue;
+                }
+            };
+            if report.is_clean() {
+                println!("{}: ok", path.display());
+                continue;
+            }
+            failed += 1;
+            println!(
+                "{}: {} missing, {} extra, {} placeholder mismatches",
+                path.display(),
+                report.missing.len(),
+                report.extra.len(),
+                report.placeholder_mismatches.len()
+            );
+            for key in &report.missing {
+                println!("  missing: {key}");
+            }
+            for key in &report.extra {
+                println!("  extra: {key}");
+            }
+            for key in &report.placeholder_mismatches {
+                println!("  placeholder mismatch: {key}");
+            }
+        }
+
+        if failed > 0 {
+            anyhow::bail!("{failed} of {} catalogs have problems", catalogs.len());
+        }
+        Ok(())
+    }
+}
+
+fn default_catalogs() -> anyhow::Result<Vec<PathBuf>> {
+    let dir = find_codex_home()?.join(i18n::CATALOG_DIR);
+    let entries = match std::fs::read_dir(&dir) {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(err) => {
+            return Err(err).with_context(|| format!("failed to read {}", dir.display()));
+        }
+    };
+    let mut catalogs: Vec<PathBuf> = entries
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "toml"))
+        .collect();
+    catalogs.sort();
+    Ok(catalogs)
+}
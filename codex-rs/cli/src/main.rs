@@ -21,6 +21,10 @@ use codex_exec::Cli as ExecCli;
 use codex_exec::Command as ExecCommand;
 use codex_exec::ReviewArgs;
 use codex_execpolicy::ExecPolicyCheckCommand;
+use codex_execpolicy::ExecPolicyExplainCommand;
+use codex_execpolicy::execpolicyexplain::HeuristicTrace;
+use codex_execpolicy::execpolicyexplain::explain_command;
+use codex_protocol::models::SandboxPermissions;
 use codex_responses_api_proxy::Args as ResponsesApiProxyArgs;
 use codex_tui::AppExitInfo;
 use codex_tui::Cli as TuiCli;
@@ -39,11 +43,17 @@ use supports_color::Stream;
 mod app_cmd;
 #[cfg(target_os = "macos")]
 mod desktop_app;
+mod export_cmd;
+mod i18n_cmd;
 mod mcp_cmd;
+mod network_log_cmd;
 #[cfg(not(windows))]
 mod wsl_paths;
 
+use crate::export_cmd::ExportCommand;
+use crate::i18n_cmd::I18nCli;
 use crate::mcp_cmd::McpCli;
+use crate::network_log_cmd::NetworkLogCommand;
 
 use codex_core::config::Config;
 use codex_core::config::ConfigOverrides;
@@ -141,6 +151,9 @@ enum Subcommand {
     /// 分叉此前的交互式会话（默认显示列表，使用 --last 选择最近一次）。
     Fork(ForkCommand),
 
+    /// 将已记录的会话导出为 Markdown、HTML 或 JSON 记录（已脱敏配置的密钥）。
+    Export(ExportCommand),
+
     /// 【实验特性】浏览 Codex Cloud 任务并在本地应用更改。
     #[clap(name = "cloud", alias = "cloud-tasks")]
     Cloud(CloudTasksCli),
@@ -155,6 +168,9 @@ enum Subcommand {
 
     /// 查看功能开关。
     Features(FeaturesCli),
+
+    /// 检查界面翻译目录（$CODEX_HOME/i18n/*.toml）。
+    I18n(I18nCli),
 }
 
 #[derive(Debug, Parser)]
@@ -174,6 +190,9 @@ struct DebugCommand {
 enum DebugSubcommand {
     /// Tooling: helps debug the app server.
     AppServer(DebugAppServerCommand),
+
+    /// Show network proxy allow/deny decisions recorded in the state database.
+    NetworkLog(NetworkLogCommand),
 }
 
 #[derive(Debug, Parser)]
@@ -209,6 +228,11 @@ struct ResumeCommand {
     #[arg(long = "all", default_value_t = false)]
     all: bool,
 
+    /// 按内容全文检索会话（用户消息、助手回复、执行过的命令和改动过的文件），
+    /// 选择器只列出匹配的会话。需要启用 `sqlite` 特性。
+    #[arg(long = "query", value_name = "QUERY", conflicts_with_all = ["session_id", "last"])]
+    query: Option<String>,
+
     #[clap(flatten)]
     config_overrides: TuiCli,
 }
@@ -263,6 +287,10 @@ enum ExecpolicySubcommand {
     /// Check execpolicy files against a command.
     #[clap(name = "check")]
     Check(ExecPolicyCheckCommand),
+
+    /// Trace which rules matched a command, why, and how decisions combined.
+    #[clap(name = "explain")]
+    Explain(ExecPolicyExplainCommand),
 }
 
 #[derive(Debug, Parser)]
@@ -335,6 +363,11 @@ struct AppServerCommand {
     /// See https://developers.openai.com/codex/config-advanced/#metrics for more details.
     #[arg(long = "analytics-default-enabled")]
     analytics_default_enabled: bool,
+
+    /// 客户端连接方式：stdio://（默认）、ws://IP:PORT 或 unix:///path.sock。
+    /// 后两种方式允许多个客户端同时连接并订阅同一批线程。
+    #[arg(long = "listen", value_name = "URL", default_value = "stdio://")]
+    listen: codex_app_server::AppServerTransport,
 }
 
 #[derive(Debug, clap::Subcommand)]
@@ -450,6 +483,54 @@ fn run_execpolicycheck(cmd: ExecPolicyCheckCommand) -> anyhow::Result<()> {
     cmd.run()
 }
 
+/// Explains a command against `--rules`, or by default against the rules Codex loads for the
+/// current config (`$CODEX_HOME/rules`, trusted project layers and requirements), and names
+/// the fallback heuristic that decides when no rule matches.
+async fn run_execpolicy_explain(
+    cmd: ExecPolicyExplainCommand,
+    root_config_overrides: CliConfigOverrides,
+    config_profile: Option<String>,
+) -> anyhow::Result<()> {
+    let cli_kv_overrides = root_config_overrides
+        .parse_overrides()
+        .map_err(anyhow::Error::msg)?;
+    let overrides = ConfigOverrides {
+        cwd: cmd.cwd.clone(),
+        config_profile,
+        ..Default::default()
+    };
+    let config =
+        Config::load_with_cli_overrides_and_harness_overrides(cli_kv_overrides, overrides).await?;
+    let policies = if cmd.rules.is_empty() {
+        codex_core::load_exec_policy_files(&config.config_layer_stack).await?
+    } else {
+        cmd.load_rules()?
+    };
+
+    let approval_policy = config.approval_policy.value();
+    let sandbox_policy = config.sandbox_policy.get();
+    // Explain what Codex evaluates: `bash -lc` scripts are split into their commands.
+    let explanations: Vec<_> = codex_core::exec_policy_commands(&cmd.command)
+        .iter()
+        .map(|command| {
+            explain_command(&policies, command, &cmd.match_context()).with_heuristic(|command| {
+                let decided = codex_core::explain_decision_for_unmatched_command(
+                    approval_policy,
+                    sandbox_policy,
+                    command,
+                    SandboxPermissions::UseDefault,
+                );
+                HeuristicTrace {
+                    name: decided.heuristic.to_string(),
+                    decision: decided.decision,
+                    reason: decided.reason.to_string(),
+                }
+            })
+        })
+        .collect();
+    cmd.print_all(&explanations)
+}
+
 fn run_debug_app_server_command(cmd: DebugAppServerCommand) -> anyhow::Result<()> {
     match cmd.subcommand {
         DebugAppServerSubcommand::SendMessageV2(cmd) => {
@@ -585,6 +666,7 @@ async fn cli_main(codex_linux_sandbox_exe: Option<PathBuf>) -> anyhow::Result<()
                     root_config_overrides,
                     codex_core::config_loader::LoaderOverrides::default(),
                     app_server_cli.analytics_default_enabled,
+                    app_server_cli.listen,
                 )
                 .await?;
             }
@@ -614,6 +696,7 @@ async fn cli_main(codex_linux_sandbox_exe: Option<PathBuf>) -> anyhow::Result<()
             session_id,
             last,
             all,
+            query,
             config_overrides,
         })) => {
             interactive = finalize_resume_interactive(
@@ -622,6 +705,7 @@ async fn cli_main(codex_linux_sandbox_exe: Option<PathBuf>) -> anyhow::Result<()
                 session_id,
                 last,
                 all,
+                query,
                 config_overrides,
             );
             let exit_info = run_interactive_tui(interactive, codex_linux_sandbox_exe).await?;
@@ -731,9 +815,21 @@ async fn cli_main(codex_linux_sandbox_exe: Option<PathBuf>) -> anyhow::Result<()
             DebugSubcommand::AppServer(cmd) => {
                 run_debug_app_server_command(cmd)?;
             }
+            DebugSubcommand::NetworkLog(mut cmd) => {
+                prepend_config_flags(&mut cmd.config_overrides, root_config_overrides.clone());
+                cmd.run().await?;
+            }
         },
         Some(Subcommand::Execpolicy(ExecpolicyCommand { sub })) => match sub {
             ExecpolicySubcommand::Check(cmd) => run_execpolicycheck(cmd)?,
+            ExecpolicySubcommand::Explain(cmd) => {
+                run_execpolicy_explain(
+                    cmd,
+                    root_config_overrides.clone(),
+                    interactive.config_profile.clone(),
+                )
+                .await?;
+            }
         },
         Some(Subcommand::Apply(mut apply_cli)) => {
             prepend_config_flags(
@@ -751,6 +847,16 @@ async fn cli_main(codex_linux_sandbox_exe: Option<PathBuf>) -> anyhow::Result<()
             tokio::task::spawn_blocking(move || codex_stdio_to_uds::run(socket_path.as_path()))
                 .await??;
         }
+        Some(Subcommand::Export(mut export_cli)) => {
+            prepend_config_flags(
+                &mut export_cli.config_overrides,
+                root_config_overrides.clone(),
+            );
+            export_cli.run().await?;
+        }
+        Some(Subcommand::I18n(i18n_cli)) => {
+            i18n_cli.run()?;
+        }
         Some(Subcommand::Features(FeaturesCli { sub })) => match sub {
             FeaturesSubcommand::List => {
                 // Respect root-level `-c` overrides plus top-level flags like `--profile`.
@@ -946,6 +1052,7 @@ fn finalize_resume_interactive(
     session_id: Option<String>,
     last: bool,
     show_all: bool,
+    query: Option<String>,
     resume_cli: TuiCli,
 ) -> TuiCli {
     // Start with the parsed interactive CLI so resume shares the same
@@ -955,6 +1062,7 @@ fn finalize_resume_interactive(
     interactive.resume_last = last;
     interactive.resume_session_id = resume_session_id;
     interactive.resume_show_all = show_all;
+    interactive.resume_query = query;
 
     // Merge resume-scoped flags and overrides with highest precedence.
     merge_interactive_cli_flags(&mut interactive, resume_cli);
@@ -1066,6 +1174,7 @@ mod tests {
             session_id,
             last,
             all,
+            query,
             config_overrides: resume_cli,
         }) = subcommand.expect("resume present")
         else {
@@ -1078,6 +1187,7 @@ mod tests {
             session_id,
             last,
             all,
+            query,
             resume_cli,
         )
     }
@@ -1243,6 +1353,19 @@ mod tests {
         assert!(interactive.resume_show_all);
     }
 
+    #[test]
+    fn resume_query_opens_picker_with_full_text_filter() {
+        let interactive = finalize_resume_from_args(
+            ["codex", "resume", "--query", "flaky migration", "--search"].as_ref(),
+        );
+        assert!(interactive.resume_picker);
+        assert_eq!(interactive.resume_query.as_deref(), Some("flaky migration"));
+        assert!(interactive.web_search);
+
+        let cli = MultitoolCli::try_parse_from(["codex", "resume", "--last", "--query", "x"]);
+        assert!(cli.is_err());
+    }
+
     #[test]
     fn resume_merges_option_flags_and_full_auto() {
         let interactive = finalize_resume_from_args(
@@ -1363,6 +1486,52 @@ mod tests {
         assert!(app_server.analytics_default_enabled);
     }
 
+    #[test]
+    fn app_server_listen_defaults_to_stdio() {
+        let app_server = app_server_from_args(["codex", "app-server"].as_ref());
+        assert_eq!(
+            app_server.listen,
+            codex_app_server::AppServerTransport::Stdio
+        );
+
+        let app_server = app_server_from_args(
+            ["codex", "app-server", "--listen", "unix:///tmp/codex.sock"].as_ref(),
+        );
+        assert_eq!(
+            app_server.listen,
+            codex_app_server::AppServerTransport::UnixSocket {
+                path: PathBuf::from("/tmp/codex.sock"),
+            }
+        );
+    }
+
+    #[test]
+    fn export_parses_format_and_output() {
+        let cli = MultitoolCli::try_parse_from([
+            "codex",
+            "export",
+            "67e55044-10b1-426f-9247-bb680e5fe0c8",
+            "--format",
+            "html",
+            "-o",
+            "thread.html",
+        ])
+        .expect("parse should succeed");
+        let Some(Subcommand::Export(export)) = cli.subcommand else {
+            panic!("expected export subcommand");
+        };
+        assert_eq!(export.thread_id, "67e55044-10b1-426f-9247-bb680e5fe0c8");
+        assert_eq!(export.format, export_cmd::ExportFormatArg::Html);
+        assert_eq!(export.output, Some(PathBuf::from("thread.html")));
+
+        let cli = MultitoolCli::try_parse_from(["codex", "export", "my-thread"])
+            .expect("parse should succeed");
+        let Some(Subcommand::Export(export)) = cli.subcommand else {
+            panic!("expected export subcommand");
+        };
+        assert_eq!(export.format, export_cmd::ExportFormatArg::Md);
+    }
+
     #[test]
     fn features_enable_parses_feature_name() {
         let cli = MultitoolCli::try_parse_from(["codex", "features", "enable", "unified_exec"])
@@ -13,6 +13,7 @@ use codex_core::config::find_codex_home;
 use codex_core::config::load_global_mcp_servers;
 use codex_core::config::types::McpServerConfig;
 use codex_core::config::types::McpServerTransportConfig;
+use codex_core::config::types::McpServerUrlTransport;
 use codex_core::mcp::auth::McpOAuthLoginSupport;
 use codex_core::mcp::auth::compute_auth_statuses;
 use codex_core::mcp::auth::oauth_login_support;
@@ -93,7 +94,7 @@ pub struct AddMcpTransportArgs {
 #[derive(Debug, clap::Args)]
 pub struct AddMcpStdioArgs {
     /// Command to launch the MCP server.
-    /// Use --url for a streamable HTTP server.
+    /// Use --url for a streamable HTTP, SSE or WebSocket server.
     #[arg(
             trailing_var_arg = true,
             num_args = 0..,
@@ -112,18 +113,29 @@ pub struct AddMcpStdioArgs {
 
 #[derive(Debug, clap::Args)]
 pub struct AddMcpStreamableHttpArgs {
-    /// URL for a streamable HTTP MCP server.
+    /// URL for a streamable HTTP, SSE or WebSocket MCP server.
     #[arg(long)]
     pub url: String,
 
     /// Optional environment variable to read for a bearer token.
-    /// Only valid with streamable HTTP servers.
+    /// Only valid with URL-based servers.
     #[arg(
         long = "bearer-token-env-var",
         value_name = "ENV_VAR",
         requires = "url"
     )]
     pub bearer_token_env_var: Option<String>,
+
+    /// Transport to use with --url: `streamable_http`, `sse` or `websocket`.
+    /// Defaults to `websocket` for ws:// and wss:// URLs, otherwise `streamable_http`.
+    #[arg(
+        long = "transport",
+        id = "url_transport",
+        value_name = "TRANSPORT",
+        value_parser = parse_url_transport,
+        requires = "url"
+    )]
+    pub transport: Option<McpServerUrlTransport>,
 }
 
 #[derive(Debug, clap::Parser)]
@@ -229,13 +241,28 @@ async fn run_add(config_overrides: &CliConfigOverrides, add_args: AddArgs) -> Re
                 Some(AddMcpStreamableHttpArgs {
                     url,
                     bearer_token_env_var,
+                    transport,
                 }),
             ..
-        } => McpServerTransportConfig::StreamableHttp {
-            url,
-            bearer_token_env_var,
-            http_headers: None,
-            env_http_headers: None,
+        } => match transport.unwrap_or_else(|| McpServerUrlTransport::infer_from_url(&url)) {
+            McpServerUrlTransport::StreamableHttp => McpServerTransportConfig::StreamableHttp {
+                url,
+                bearer_token_env_var,
+                http_headers: None,
+                env_http_headers: None,
+            },
+            McpServerUrlTransport::Sse => McpServerTransportConfig::Sse {
+                url,
+                bearer_token_env_var,
+                http_headers: None,
+                env_http_headers: None,
+            },
+            McpServerUrlTransport::WebSocket => McpServerTransportConfig::WebSocket {
+                url,
+                bearer_token_env_var,
+                http_headers: None,
+                env_http_headers: None,
+            },
         },
         AddMcpTransportArgs { .. } => bail!("exactly one of --command or --url must be provided"),
     };
@@ -250,6 +277,8 @@ async fn run_add(config_overrides: &CliConfigOverrides, add_args: AddArgs) -> Re
         enabled_tools: None,
         disabled_tools: None,
         scopes: None,
+        sampling: None,
+        approval: None,
     };
 
     servers.insert(name.clone(), new_entry);
@@ -339,8 +368,22 @@ async fn run_login(config_overrides: &CliConfigOverrides, login_args: LoginArgs)
             http_headers,
             env_http_headers,
             ..
+        }
+        | McpServerTransportConfig::Sse {
+            url,
+            http_headers,
+            env_http_headers,
+            ..
+        }
+        | McpServerTransportConfig::WebSocket {
+            url,
+            http_headers,
+            env_http_headers,
+            ..
         } => (url.clone(), http_headers.clone(), env_http_headers.clone()),
-        _ => bail!("OAuth login is only supported for streamable HTTP servers."),
+        McpServerTransportConfig::Stdio { .. } => {
+            bail!("OAuth login is only supported for URL-based servers.")
+        }
     };
 
     let mut scopes = scopes;
@@ -378,9 +421,8 @@ async fn run_logout(config_overrides: &CliConfigOverrides, logout_args: LogoutAr
         .get(&name)
         .ok_or_else(|| anyhow!("No MCP server named '{name}' found in configuration."))?;
 
-    let url = match &server.transport {
-        McpServerTransportConfig::StreamableHttp { url, .. } => url.clone(),
-        _ => bail!("OAuth logout is only supported for streamable_http transports."),
+    let Some(url) = server.transport.url().map(str::to_string) else {
+        bail!("OAuth logout is only supported for URL-based transports.");
     };
 
     match delete_oauth_tokens(&name, &url, config.mcp_oauth_credentials_store_mode) {
@@ -436,9 +478,21 @@ async fn run_list(config_overrides: &CliConfigOverrides, list_args: ListArgs) ->
                         bearer_token_env_var,
                         http_headers,
                         env_http_headers,
+                    }
+                    | McpServerTransportConfig::Sse {
+                        url,
+                        bearer_token_env_var,
+                        http_headers,
+                        env_http_headers,
+                    }
+                    | McpServerTransportConfig::WebSocket {
+                        url,
+                        bearer_token_env_var,
+                        http_headers,
+                        env_http_headers,
                     } => {
                         serde_json::json!({
-                            "type": "streamable_http",
+                            "type": cfg.transport.transport_name(),
                             "url": url,
                             "bearer_token_env_var": bearer_token_env_var,
                             "http_headers": http_headers,
@@ -515,6 +569,16 @@ async fn run_list(config_overrides: &CliConfigOverrides, list_args: ListArgs) ->
                 url,
                 bearer_token_env_var,
                 ..
+            }
+            | McpServerTransportConfig::Sse {
+                url,
+                bearer_token_env_var,
+                ..
+            }
+            | McpServerTransportConfig::WebSocket {
+                url,
+                bearer_token_env_var,
+                ..
             } => {
                 let status = format_mcp_status(cfg);
                 let auth_status = auth_statuses
@@ -675,8 +739,20 @@ async fn run_get(config_overrides: &CliConfigOverrides, get_args: GetArgs) -> Re
                 bearer_token_env_var,
                 http_headers,
                 env_http_headers,
+            }
+            | McpServerTransportConfig::Sse {
+                url,
+                bearer_token_env_var,
+                http_headers,
+                env_http_headers,
+            }
+            | McpServerTransportConfig::WebSocket {
+                url,
+                bearer_token_env_var,
+                http_headers,
+                env_http_headers,
             } => serde_json::json!({
-                "type": "streamable_http",
+                "type": server.transport.transport_name(),
                 "url": url,
                 "bearer_token_env_var": bearer_token_env_var,
                 "http_headers": http_headers,
@@ -757,8 +833,20 @@ async fn run_get(config_overrides: &CliConfigOverrides, get_args: GetArgs) -> Re
             bearer_token_env_var,
             http_headers,
             env_http_headers,
+        }
+        | McpServerTransportConfig::Sse {
+            url,
+            bearer_token_env_var,
+            http_headers,
+            env_http_headers,
+        }
+        | McpServerTransportConfig::WebSocket {
+            url,
+            bearer_token_env_var,
+            http_headers,
+            env_http_headers,
         } => {
-            println!("  transport: streamable_http");
+            println!("  transport: {}", server.transport.transport_name());
             println!("  url: {url}");
             let bearer_token_display = bearer_token_env_var.as_deref().unwrap_or("-");
             println!("  bearer_token_env_var: {bearer_token_display}");
@@ -816,6 +904,11 @@ fn parse_env_pair(raw: &str) -> Result<(String, String), String> {
     Ok((key.to_string(), value))
 }
 
+fn parse_url_transport(raw: &str) -> Result<McpServerUrlTransport, String> {
+    McpServerUrlTransport::parse(raw.trim())
+        .ok_or_else(|| "transport must be one of streamable_http, sse or websocket".to_string())
+}
+
 fn validate_server_name(name: &str) -> Result<()> {
     let is_valid = !name.is_empty()
         && name
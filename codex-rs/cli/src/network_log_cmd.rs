@@ -0,0 +1,158 @@
+use anyhow::Context;
+use chrono::DateTime;
+use chrono::SecondsFormat;
+use clap::Parser;
+use clap::ValueEnum;
+use codex_common::CliConfigOverrides;
+use codex_core::config::Config;
+use codex_core::state_db::get_state_db;
+use codex_state::NetworkAuditDecision;
+use codex_state::NetworkAuditQuery;
+use codex_state::NetworkAuditRow;
+
+/// Query the network proxy audit log stored in the state database.
+#[derive(Debug, Parser)]
+pub struct NetworkLogCommand {
+    /// Only show decisions made for these thread ids (repeatable).
+    #[arg(long = "thread-id", value_name = "THREAD_ID")]
+    pub thread_id: Vec<String>,
+
+    /// Substring match on the destination host (repeatable).
+    #[arg(long, value_name = "HOST")]
+    pub host: Vec<String>,
+
+    /// Only show allowed or denied requests.
+    #[arg(long, value_enum)]
+    pub decision: Option<DecisionFilter>,
+
+    /// Only show decisions at or after this time (RFC3339 or unix seconds).
+    #[arg(long, value_name = "RFC3339|UNIX")]
+    pub since: Option<String>,
+
+    /// Maximum number of entries to show (most recent first).
+    #[arg(long, default_value_t = 100)]
+    pub limit: usize,
+
+    /// Print entries as JSON lines.
+    #[arg(long)]
+    pub json: bool,
+
+    #[clap(flatten)]
+    pub config_overrides: CliConfigOverrides,
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum DecisionFilter {
+    Allow,
+    Deny,
+}
+
+impl NetworkLogCommand {
+    pub async fn run(self) -> anyhow::Result<()> {
+        let overrides = self
+            .config_overrides
+            .parse_overrides()
+            .map_err(anyhow::Error::msg)?;
+        let config = Config::load_with_cli_overrides(overrides)
+            .await
+            .context("failed to load configuration")?;
+        let Some(runtime) = get_state_db(&config, None).await else {
+            anyhow::bail!(
+                "no state database at {}; the network audit log is recorded only when the `sqlite` feature is enabled",
+                codex_state::state_db_path(config.codex_home.as_path()).display()
+            );
+        };
+
+        let from_ts = self
+            .since
+            .as_deref()
+            .map(parse_timestamp)
+            .transpose()
+            .context("failed to parse --since")?;
+        let query = NetworkAuditQuery {
+            thread_ids: self.thread_id,
+            host_like: self.host,
+            decision: self.decision.map(|decision| match decision {
+                DecisionFilter::Allow => NetworkAuditDecision::Allow,
+                DecisionFilter::Deny => NetworkAuditDecision::Deny,
+            }),
+            from_ts,
+            limit: Some(self.limit),
+            descending: true,
+            ..NetworkAuditQuery::default()
+        };
+        let mut rows = runtime
+            .query_network_audit(&query)
+            .await
+            .context("failed to query network audit log")?;
+        rows.reverse();
+
+        for row in rows {
+            if self.json {
+                println!("{}", serde_json::to_string(&row)?);
+            } else {
+                println!("{}", format_row(&row));
+            }
+        }
+        Ok(())
+    }
+}
+
+fn parse_timestamp(value: &str) -> anyhow::Result<i64> {
+    if let Ok(secs) = value.parse::<i64>() {
+        return Ok(secs);
+    }
+
+    let dt = DateTime::parse_from_rfc3339(value)
+        .with_context(|| format!("expected RFC3339 or unix seconds, got {value}"))?;
+    Ok(dt.timestamp())
+}
+
+fn format_row(row: &NetworkAuditRow) -> String {
+    let timestamp = DateTime::from_timestamp(row.ts, row.ts_nanos as u32)
+        .map(|dt| dt.to_rfc3339_opts(SecondsFormat::Millis, true))
+        .unwrap_or_else(|| row.ts.to_string());
+    let destination = match row.port {
+        Some(port) => format!("{}:{port}", row.host),
+        None => row.host.clone(),
+    };
+    let method = row.method.as_deref().unwrap_or("-");
+    let thread_id = row.thread_id.as_deref().unwrap_or("-");
+    let mut line = format!(
+        "{timestamp} {decision:<5} {protocol} {method} {destination} thread={thread_id}",
+        decision = row.decision,
+        protocol = row.protocol,
+    );
+    if let Some(reason) = row.reason.as_deref() {
+        line.push_str(&format!(" reason={reason}"));
+    }
+    line
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn format_row_includes_destination_and_reason() {
+        let row = NetworkAuditRow {
+            id: 1,
+            ts: 1_700_000_000,
+            ts_nanos: 0,
+            thread_id: Some("thread-1".to_string()),
+            protocol: "http-connect".to_string(),
+            host: "example.com".to_string(),
+            port: Some(443),
+            method: Some("CONNECT".to_string()),
+            decision: "deny".to_string(),
+            reason: Some("not_allowed".to_string()),
+            client: None,
+        };
+
+        assert_eq!(
+            format_row(&row),
+            "2023-11-14T22:13:20.000Z deny  http-connect CONNECT example.com:443 thread=thread-1 reason=not_allowed"
+        );
+    }
+}
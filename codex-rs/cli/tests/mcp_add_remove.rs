@@ -176,6 +176,61 @@ async fn add_streamable_http_with_custom_env_var() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+async fn add_sse_and_websocket_servers() -> Result<()> {
+    let codex_home = TempDir::new()?;
+
+    let mut add_cmd = codex_command(codex_home.path())?;
+    add_cmd
+        .args([
+            "mcp",
+            "add",
+            "vendor",
+            "--url",
+            "https://vendor.example.com/sse",
+            "--transport",
+            "sse",
+        ])
+        .assert()
+        .success();
+
+    let mut add_cmd = codex_command(codex_home.path())?;
+    add_cmd
+        .args([
+            "mcp",
+            "add",
+            "gateway",
+            "--url",
+            "wss://gateway.example.com/mcp",
+            "--bearer-token-env-var",
+            "GATEWAY_TOKEN",
+        ])
+        .assert()
+        .success();
+
+    let servers = load_global_mcp_servers(codex_home.path()).await?;
+    assert_eq!(
+        servers.get("vendor").map(|server| &server.transport),
+        Some(&McpServerTransportConfig::Sse {
+            url: "https://vendor.example.com/sse".to_string(),
+            bearer_token_env_var: None,
+            http_headers: None,
+            env_http_headers: None,
+        })
+    );
+    assert_eq!(
+        servers.get("gateway").map(|server| &server.transport),
+        Some(&McpServerTransportConfig::WebSocket {
+            url: "wss://gateway.example.com/mcp".to_string(),
+            bearer_token_env_var: Some("GATEWAY_TOKEN".to_string()),
+            http_headers: None,
+            env_http_headers: None,
+        })
+    );
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn add_streamable_http_rejects_removed_flag() -> Result<()> {
     let codex_home = TempDir::new()?;
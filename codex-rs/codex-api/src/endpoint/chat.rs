@@ -0,0 +1,91 @@
+use crate::auth::AuthProvider;
+use crate::common::ResponseStream;
+use crate::common::ResponsesApiRequest;
+use crate::endpoint::responses::ResponsesOptions;
+use crate::endpoint::session::EndpointSession;
+use crate::error::ApiError;
+use crate::provider::Provider;
+use crate::requests::chat::build_chat_request;
+use crate::requests::chat::custom_tool_names;
+use crate::requests::headers::build_conversation_headers;
+use crate::requests::headers::insert_header;
+use crate::requests::headers::subagent_header;
+use crate::sse::chat::spawn_chat_stream;
+use crate::telemetry::SseTelemetry;
+use codex_client::HttpTransport;
+use codex_client::RequestTelemetry;
+use http::HeaderValue;
+use http::Method;
+use std::sync::Arc;
+
+/// Streams turns from providers configured with `wire_api = "chat"`.
+///
+/// Accepts the same [`ResponsesApiRequest`] core builds for the Responses API and translates it
+/// to `POST /chat/completions`, so callers only differ in which client they construct.
+pub struct ChatClient<T: HttpTransport, A: AuthProvider> {
+    session: EndpointSession<T, A>,
+    sse_telemetry: Option<Arc<dyn SseTelemetry>>,
+}
+
+impl<T: HttpTransport, A: AuthProvider> ChatClient<T, A> {
+    pub fn new(transport: T, provider: Provider, auth: A) -> Self {
+        Self {
+            session: EndpointSession::new(transport, provider, auth),
+            sse_telemetry: None,
+        }
+    }
+
+    pub fn with_telemetry(
+        self,
+        request: Option<Arc<dyn RequestTelemetry>>,
+        sse: Option<Arc<dyn SseTelemetry>>,
+    ) -> Self {
+        Self {
+            session: self.session.with_request_telemetry(request),
+            sse_telemetry: sse,
+        }
+    }
+
+    fn path() -> &'static str {
+        "chat/completions"
+    }
+
+    /// Sends `request` as a Chat Completions call. `options.compression` and
+    /// `options.turn_state` are Responses-specific and ignored.
+    pub async fn stream_request(
+        &self,
+        request: ResponsesApiRequest,
+        options: ResponsesOptions,
+    ) -> Result<ResponseStream, ApiError> {
+        let ResponsesOptions {
+            conversation_id,
+            session_source,
+            extra_headers,
+            ..
+        } = options;
+
+        let body = build_chat_request(&request);
+        let mut headers = extra_headers;
+        headers.extend(build_conversation_headers(conversation_id));
+        if let Some(subagent) = subagent_header(&session_source) {
+            insert_header(&mut headers, "x-openai-subagent", &subagent);
+        }
+
+        let stream_response = self
+            .session
+            .stream_with(Method::POST, Self::path(), headers, Some(body), |req| {
+                req.headers.insert(
+                    http::header::ACCEPT,
+                    HeaderValue::from_static("text/event-stream"),
+                );
+            })
+            .await?;
+
+        Ok(spawn_chat_stream(
+            stream_response,
+            self.session.provider().stream_idle_timeout,
+            self.sse_telemetry.clone(),
+            custom_tool_names(&request.tools),
+        ))
+    }
+}
@@ -0,0 +1,112 @@
+use crate::auth::AuthProvider;
+use crate::common::ResponseStream;
+use crate::common::ResponsesApiRequest;
+use crate::endpoint::responses::ResponsesOptions;
+use crate::endpoint::session::EndpointSession;
+use crate::error::ApiError;
+use crate::provider::Provider;
+use crate::requests::chat::custom_tool_names;
+use crate::requests::headers::build_conversation_headers;
+use crate::requests::headers::insert_header;
+use crate::requests::headers::subagent_header;
+use crate::requests::messages::build_messages_request;
+use crate::sse::messages::spawn_messages_stream;
+use crate::telemetry::SseTelemetry;
+use codex_client::HttpTransport;
+use codex_client::RequestTelemetry;
+use http::HeaderValue;
+use http::Method;
+use std::sync::Arc;
+
+/// Version sent when the provider config does not pin one.
+const ANTHROPIC_VERSION: &str = "2023-06-01";
+
+/// Streams turns from providers configured with `wire_api = "messages"`.
+///
+/// Accepts the same [`ResponsesApiRequest`] core builds for the Responses API and translates it
+/// to `POST /messages`. The bearer token is also sent as `x-api-key`, which native Anthropic
+/// endpoints require and most gateways accept.
+pub struct MessagesClient<T: HttpTransport, A: AuthProvider> {
+    session: EndpointSession<T, A>,
+    sse_telemetry: Option<Arc<dyn SseTelemetry>>,
+}
+
+impl<T: HttpTransport, A: AuthProvider> MessagesClient<T, A> {
+    pub fn new(transport: T, provider: Provider, auth: A) -> Self {
+        Self {
+            session: EndpointSession::new(transport, provider, auth),
+            sse_telemetry: None,
+        }
+    }
+
+    pub fn with_telemetry(
+        self,
+        request: Option<Arc<dyn RequestTelemetry>>,
+        sse: Option<Arc<dyn SseTelemetry>>,
+    ) -> Self {
+        Self {
+            session: self.session.with_request_telemetry(request),
+            sse_telemetry: sse,
+        }
+    }
+
+    fn path() -> &'static str {
+        "messages"
+    }
+
+    /// Sends `request` as a Messages call. `options.compression` and
+    /// `options.turn_state` are Responses-specific and ignored.
+    pub async fn stream_request(
+        &self,
+        request: ResponsesApiRequest,
+        options: ResponsesOptions,
+    ) -> Result<ResponseStream, ApiError> {
+        let ResponsesOptions {
+            conversation_id,
+            session_source,
+            extra_headers,
+            ..
+        } = options;
+
+        let body = build_messages_request(&request);
+        let mut headers = extra_headers;
+        headers.extend(build_conversation_headers(conversation_id));
+        if let Some(subagent) = subagent_header(&session_source) {
+            insert_header(&mut headers, "x-openai-subagent", &subagent);
+        }
+
+        let stream_response = self
+            .session
+            .stream_with(Method::POST, Self::path(), headers, Some(body), |req| {
+                req.headers.insert(
+                    http::header::ACCEPT,
+                    HeaderValue::from_static("text/event-stream"),
+                );
+                if !req.headers.contains_key("anthropic-version") {
+                    req.headers.insert(
+                        "anthropic-version",
+                        HeaderValue::from_static(ANTHROPIC_VERSION),
+                    );
+                }
+                let api_key = req
+                    .headers
+                    .get(http::header::AUTHORIZATION)
+                    .and_then(|value| value.to_str().ok())
+                    .and_then(|value| value.strip_prefix("Bearer "))
+                    .and_then(|token| HeaderValue::from_str(token).ok());
+                if let Some(api_key) = api_key
+                    && !req.headers.contains_key("x-api-key")
+                {
+                    req.headers.insert("x-api-key", api_key);
+                }
+            })
+            .await?;
+
+        Ok(spawn_messages_stream(
+            stream_response,
+            self.session.provider().stream_idle_timeout,
+            self.sse_telemetry.clone(),
+            custom_tool_names(&request.tools),
+        ))
+    }
+}
@@ -1,6 +1,8 @@
 pub mod aggregate;
+pub mod chat;
 pub mod compact;
 pub mod memories;
+pub mod messages;
 pub mod models;
 pub mod responses;
 pub mod responses_websocket;
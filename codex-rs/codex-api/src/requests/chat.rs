@@ -0,0 +1,373 @@
+//! Translates a [`ResponsesApiRequest`] into a Chat Completions request body.
+//!
+//! Core always builds the canonical Responses request; providers configured with
+//! `wire_api = "chat"` receive the same conversation rewritten as `messages`. Freeform
+//! (`custom`) tools have no Chat Completions equivalent, so they are exposed as function tools
+//! taking a single string argument and mapped back by the SSE decoder.
+
+use crate::common::ResponsesApiRequest;
+use codex_protocol::models::ContentItem;
+use codex_protocol::models::LocalShellAction;
+use codex_protocol::models::ResponseItem;
+use serde_json::Map;
+use serde_json::Value;
+use serde_json::json;
+use std::collections::HashSet;
+use tracing::debug;
+
+/// Argument that carries the raw input of a freeform tool exposed as a function.
+pub(crate) const CUSTOM_TOOL_INPUT_FIELD: &str = "input";
+
+/// Name under which history `local_shell_call` items are replayed as function calls.
+const LOCAL_SHELL_TOOL_NAME: &str = "local_shell";
+
+/// Builds the `POST /chat/completions` body for `request`.
+pub fn build_chat_request(request: &ResponsesApiRequest) -> Value {
+    let mut body = Map::new();
+    body.insert("model".to_string(), json!(request.model));
+    body.insert(
+        "messages".to_string(),
+        Value::Array(chat_messages(&request.instructions, &request.input)),
+    );
+    body.insert("stream".to_string(), Value::Bool(true));
+    body.insert(
+        "stream_options".to_string(),
+        json!({ "include_usage": true }),
+    );
+
+    let tools = chat_tools(&request.tools);
+    if !tools.is_empty() {
+        body.insert("tools".to_string(), Value::Array(tools));
+        body.insert("tool_choice".to_string(), json!(request.tool_choice));
+        body.insert(
+            "parallel_tool_calls".to_string(),
+            Value::Bool(request.parallel_tool_calls),
+        );
+    }
+    if let Some(max_tokens) = request.max_output_tokens {
+        body.insert("max_tokens".to_string(), json!(max_tokens));
+    }
+    if let Some(effort) = request
+        .reasoning
+        .as_ref()
+        .and_then(|reasoning| reasoning.effort)
+    {
+        body.insert("reasoning_effort".to_string(), json!(effort));
+    }
+    if let Some(text) = &request.text {
+        if let Some(verbosity) = &text.verbosity {
+            body.insert("verbosity".to_string(), json!(verbosity));
+        }
+        if let Some(format) = &text.format {
+            body.insert(
+                "response_format".to_string(),
+                json!({
+                    "type": "json_schema",
+                    "json_schema": {
+                        "name": format.name,
+                        "schema": format.schema,
+                        "strict": format.strict,
+                    },
+                }),
+            );
+        }
+    }
+    Value::Object(body)
+}
+
+/// Names of the freeform tools in a Responses `tools` array.
+pub(crate) fn custom_tool_names(tools: &[Value]) -> HashSet<String> {
+    tools
+        .iter()
+        .filter(|tool| tool.get("type").and_then(Value::as_str) == Some("custom"))
+        .filter_map(|tool| tool.get("name").and_then(Value::as_str))
+        .map(str::to_string)
+        .collect()
+}
+
+fn chat_messages(instructions: &str, input: &[ResponseItem]) -> Vec<Value> {
+    let mut messages = Vec::new();
+    if !instructions.is_empty() {
+        messages.push(json!({ "role": "system", "content": instructions }));
+    }
+
+    for item in input {
+        match item {
+            ResponseItem::Message { role, content, .. } => {
+                messages.push(chat_message(role, content));
+            }
+            ResponseItem::FunctionCall {
+                name,
+                arguments,
+                call_id,
+                ..
+            } => push_tool_call(&mut messages, call_id, name, arguments.clone()),
+            ResponseItem::CustomToolCall {
+                call_id,
+                name,
+                input,
+                ..
+            } => push_tool_call(
+                &mut messages,
+                call_id,
+                name,
+                json!({ CUSTOM_TOOL_INPUT_FIELD: input }).to_string(),
+            ),
+            ResponseItem::LocalShellCall {
+                id,
+                call_id,
+                action,
+                ..
+            } => {
+                let Some(call_id) = call_id.as_ref().or(id.as_ref()) else {
+                    continue;
+                };
+                let LocalShellAction::Exec(exec) = action;
+                let arguments = serde_json::to_string(exec).unwrap_or_default();
+                push_tool_call(&mut messages, call_id, LOCAL_SHELL_TOOL_NAME, arguments);
+            }
+            ResponseItem::FunctionCallOutput { call_id, output } => {
+                messages.push(json!({
+                    "role": "tool",
+                    "tool_call_id": call_id,
+                    "content": output.body.to_text().unwrap_or_default(),
+                }));
+            }
+            ResponseItem::CustomToolCallOutput { call_id, output } => {
+                messages.push(json!({
+                    "role": "tool",
+                    "tool_call_id": call_id,
+                    "content": output,
+                }));
+            }
+            // Reasoning is not replayed: Chat Completions providers either reject it or expect
+            // it to be dropped between turns.
+            ResponseItem::Reasoning { .. }
+            | ResponseItem::WebSearchCall { .. }
+            | ResponseItem::GhostSnapshot { .. }
+            | ResponseItem::Compaction { .. }
+            | ResponseItem::Other => {}
+        }
+    }
+    messages
+}
+
+fn chat_message(role: &str, content: &[ContentItem]) -> Value {
+    // `developer` is an OpenAI-only role; `system` is understood by every provider.
+    let role = if role == "developer" { "system" } else { role };
+    let has_images = content
+        .iter()
+        .any(|item| matches!(item, ContentItem::InputImage { .. }));
+    if !has_images {
+        let text = content
+            .iter()
+            .filter_map(|item| match item {
+                ContentItem::InputText { text } | ContentItem::OutputText { text } => {
+                    Some(text.as_str())
+                }
+                ContentItem::InputImage { .. } => None,
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        return json!({ "role": role, "content": text });
+    }
+
+    let parts: Vec<Value> = content
+        .iter()
+        .map(|item| match item {
+            ContentItem::InputText { text } | ContentItem::OutputText { text } => {
+                json!({ "type": "text", "text": text })
+            }
+            ContentItem::InputImage { image_url } => {
+                json!({ "type": "image_url", "image_url": { "url": image_url } })
+            }
+        })
+        .collect();
+    json!({ "role": role, "content": parts })
+}
+
+/// Appends a tool call to the trailing assistant message, starting a new one when the previous
+/// message came from another role.
+fn push_tool_call(messages: &mut Vec<Value>, call_id: &str, name: &str, arguments: String) {
+    let tool_call = json!({
+        "id": call_id,
+        "type": "function",
+        "function": { "name": name, "arguments": arguments },
+    });
+    if let Some(Value::Object(last)) = messages.last_mut()
+        && last.get("role").and_then(Value::as_str) == Some("assistant")
+    {
+        match last.get_mut("tool_calls") {
+            Some(Value::Array(tool_calls)) => tool_calls.push(tool_call),
+            _ => {
+                last.insert("tool_calls".to_string(), json!([tool_call]));
+            }
+        }
+        return;
+    }
+    messages.push(json!({
+        "role": "assistant",
+        "content": null,
+        "tool_calls": [tool_call],
+    }));
+}
+
+fn chat_tools(tools: &[Value]) -> Vec<Value> {
+    tools
+        .iter()
+        .filter_map(|tool| match tool.get("type").and_then(Value::as_str) {
+            Some("function") => {
+                let mut function = Map::new();
+                for key in ["name", "description", "parameters", "strict"] {
+                    if let Some(value) = tool.get(key) {
+                        function.insert(key.to_string(), value.clone());
+                    }
+                }
+                Some(json!({ "type": "function", "function": function }))
+            }
+            Some("custom") => Some(custom_tool_as_function(tool)),
+            other => {
+                debug!("dropping tool unsupported by chat completions: {other:?}");
+                None
+            }
+        })
+        .collect()
+}
+
+/// Exposes a freeform tool as a function taking its raw input in [`CUSTOM_TOOL_INPUT_FIELD`].
+pub(crate) fn custom_tool_as_function(tool: &Value) -> Value {
+    let name = tool.get("name").cloned().unwrap_or(Value::Null);
+    let description = tool
+        .get("description")
+        .and_then(Value::as_str)
+        .unwrap_or_default();
+    let format = tool.get("format");
+    let syntax = format
+        .and_then(|format| format.get("syntax"))
+        .and_then(Value::as_str)
+        .unwrap_or("freeform");
+    let definition = format
+        .and_then(|format| format.get("definition"))
+        .and_then(Value::as_str)
+        .unwrap_or_default();
+    json!({
+        "type": "function",
+        "function": {
+            "name": name,
+            "description": format!(
+                "{description}\n\nPass the raw tool input as `{CUSTOM_TOOL_INPUT_FIELD}`. It must follow this {syntax} grammar:\n{definition}"
+            ),
+            "parameters": {
+                "type": "object",
+                "properties": {
+                    CUSTOM_TOOL_INPUT_FIELD: { "type": "string" },
+                },
+                "required": [CUSTOM_TOOL_INPUT_FIELD],
+                "additionalProperties": false,
+            },
+        },
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use codex_protocol::models::FunctionCallOutputPayload;
+    use pretty_assertions::assert_eq;
+
+    fn request(input: Vec<ResponseItem>, tools: Vec<Value>) -> ResponsesApiRequest {
+        ResponsesApiRequest {
+            model: "gpt-test".to_string(),
+            instructions: "be helpful".to_string(),
+            input,
+            tools,
+            tool_choice: "auto".to_string(),
+            parallel_tool_calls: false,
+            reasoning: None,
+            store: false,
+            stream: true,
+            include: Vec::new(),
+            prompt_cache_key: None,
+            text: None,
+            max_output_tokens: None,
+        }
+    }
+
+    #[test]
+    fn translates_history_and_tools() {
+        let input = vec![
+            ResponseItem::Message {
+                id: None,
+                role: "developer".to_string(),
+                content: vec![ContentItem::InputText {
+                    text: "sandbox info".to_string(),
+                }],
+                end_turn: None,
+                phase: None,
+            },
+            ResponseItem::Message {
+                id: None,
+                role: "assistant".to_string(),
+                content: vec![ContentItem::OutputText {
+                    text: "Looking.".to_string(),
+                }],
+                end_turn: None,
+                phase: None,
+            },
+            ResponseItem::FunctionCall {
+                id: None,
+                name: "shell".to_string(),
+                arguments: "{\"command\":[\"ls\"]}".to_string(),
+                call_id: "call_1".to_string(),
+            },
+            ResponseItem::CustomToolCall {
+                id: None,
+                status: None,
+                call_id: "call_2".to_string(),
+                name: "apply_patch".to_string(),
+                input: "*** Begin Patch".to_string(),
+            },
+            ResponseItem::FunctionCallOutput {
+                call_id: "call_1".to_string(),
+                output: FunctionCallOutputPayload::from_text("file.txt".to_string()),
+            },
+            ResponseItem::CustomToolCallOutput {
+                call_id: "call_2".to_string(),
+                output: "Done".to_string(),
+            },
+        ];
+        let tools = vec![
+            json!({"type": "function", "name": "shell", "description": "run", "strict": false, "parameters": {"type": "object"}}),
+            json!({"type": "custom", "name": "apply_patch", "description": "patch", "format": {"type": "grammar", "syntax": "lark", "definition": "start: x"}}),
+            json!({"type": "web_search"}),
+        ];
+
+        let body = build_chat_request(&request(input, tools));
+
+        assert_eq!(
+            body["messages"],
+            json!([
+                {"role": "system", "content": "be helpful"},
+                {"role": "system", "content": "sandbox info"},
+                {
+                    "role": "assistant",
+                    "content": "Looking.",
+                    "tool_calls": [
+                        {"id": "call_1", "type": "function", "function": {"name": "shell", "arguments": "{\"command\":[\"ls\"]}"}},
+                        {"id": "call_2", "type": "function", "function": {"name": "apply_patch", "arguments": "{\"input\":\"*** Begin Patch\"}"}},
+                    ],
+                },
+                {"role": "tool", "tool_call_id": "call_1", "content": "file.txt"},
+                {"role": "tool", "tool_call_id": "call_2", "content": "Done"},
+            ])
+        );
+        let tools = body["tools"].as_array().expect("tools array");
+        assert_eq!(tools.len(), 2);
+        assert_eq!(tools[0]["function"]["name"], "shell");
+        assert_eq!(
+            tools[1]["function"]["parameters"]["required"],
+            json!(["input"])
+        );
+        assert_eq!(body["stream_options"], json!({"include_usage": true}));
+    }
+}
@@ -0,0 +1,464 @@
+//! Translates a [`ResponsesApiRequest`] into an Anthropic Messages request body.
+//!
+//! Messages only knows `user` and `assistant` turns, so developer messages are sent as user text
+//! and tool results travel as `tool_result` blocks inside user turns. Thinking blocks are replayed
+//! from reasoning items whose `encrypted_content` carries the block signature (or, for redacted
+//! thinking, the opaque payload). Prompt-caching breakpoints are placed on the tool list, the
+//! system prompt and the two most recent user turns so each request reuses the previous prefix.
+
+use crate::common::ResponsesApiRequest;
+use crate::requests::chat::CUSTOM_TOOL_INPUT_FIELD;
+use crate::requests::chat::custom_tool_as_function;
+use codex_protocol::models::ContentItem;
+use codex_protocol::models::FunctionCallOutputBody;
+use codex_protocol::models::FunctionCallOutputContentItem;
+use codex_protocol::models::LocalShellAction;
+use codex_protocol::models::ReasoningItemContent;
+use codex_protocol::models::ResponseItem;
+use codex_protocol::openai_models::ReasoningEffort;
+use serde_json::Map;
+use serde_json::Value;
+use serde_json::json;
+use tracing::debug;
+
+/// Messages requires an explicit output cap; thinking budgets must stay below it, so thinking
+/// is left off when a request asks for a smaller cap than the budget.
+const DEFAULT_MAX_TOKENS: u64 = 32_000;
+
+/// Messages allows at most four `cache_control` breakpoints per request.
+const MAX_CACHE_BREAKPOINTS: usize = 4;
+
+/// Name under which history `local_shell_call` items are replayed as tool calls.
+const LOCAL_SHELL_TOOL_NAME: &str = "local_shell";
+
+/// Builds the `POST /messages` body for `request`.
+pub fn build_messages_request(request: &ResponsesApiRequest) -> Value {
+    let mut body = Map::new();
+    body.insert("model".to_string(), json!(request.model));
+    let max_tokens = request
+        .max_output_tokens
+        .map_or(DEFAULT_MAX_TOKENS, u64::from);
+    body.insert("max_tokens".to_string(), json!(max_tokens));
+    body.insert("stream".to_string(), Value::Bool(true));
+
+    let mut breakpoints = 0;
+    let mut tools = messages_tools(&request.tools);
+    if let Some(last) = tools.last_mut() {
+        add_cache_breakpoint(last, &mut breakpoints);
+    }
+    if !request.instructions.is_empty() {
+        let mut system = json!({ "type": "text", "text": request.instructions });
+        add_cache_breakpoint(&mut system, &mut breakpoints);
+        body.insert("system".to_string(), json!([system]));
+    }
+
+    let mut messages = messages(&request.input);
+    for message in messages
+        .iter_mut()
+        .rev()
+        .filter(|message| message["role"] == "user")
+    {
+        if breakpoints >= MAX_CACHE_BREAKPOINTS {
+            break;
+        }
+        if let Some(block) = message["content"]
+            .as_array_mut()
+            .and_then(|blocks| blocks.last_mut())
+        {
+            add_cache_breakpoint(block, &mut breakpoints);
+        }
+    }
+    body.insert("messages".to_string(), Value::Array(messages));
+
+    let mut forces_tool_use = false;
+    if !tools.is_empty() {
+        let choice = tool_choice(&request.tool_choice);
+        forces_tool_use = choice == "any";
+        body.insert("tools".to_string(), Value::Array(tools));
+        body.insert(
+            "tool_choice".to_string(),
+            json!({
+                "type": choice,
+                "disable_parallel_tool_use": !request.parallel_tool_calls,
+            }),
+        );
+    }
+    // Thinking cannot be combined with a tool choice that forces tool use.
+    if let Some(budget_tokens) = request
+        .reasoning
+        .as_ref()
+        .and_then(|reasoning| reasoning.effort)
+        .and_then(thinking_budget)
+        .filter(|budget_tokens| *budget_tokens < max_tokens && !forces_tool_use)
+    {
+        body.insert(
+            "thinking".to_string(),
+            json!({ "type": "enabled", "budget_tokens": budget_tokens }),
+        );
+    }
+    Value::Object(body)
+}
+
+fn add_cache_breakpoint(block: &mut Value, breakpoints: &mut usize) {
+    if let Value::Object(block) = block {
+        block.insert("cache_control".to_string(), json!({ "type": "ephemeral" }));
+        *breakpoints += 1;
+    }
+}
+
+fn tool_choice(choice: &str) -> &'static str {
+    match choice {
+        "required" => "any",
+        "none" => "none",
+        _ => "auto",
+    }
+}
+
+fn thinking_budget(effort: ReasoningEffort) -> Option<u64> {
+    match effort {
+        ReasoningEffort::None | ReasoningEffort::Minimal => None,
+        ReasoningEffort::Low => Some(4_000),
+        ReasoningEffort::Medium => Some(10_000),
+        ReasoningEffort::High => Some(20_000),
+        ReasoningEffort::XHigh => Some(30_000),
+    }
+}
+
+fn messages(input: &[ResponseItem]) -> Vec<Value> {
+    let mut messages = Vec::new();
+    for item in input {
+        match item {
+            ResponseItem::Message { role, content, .. } => {
+                let role = if role == "assistant" {
+                    "assistant"
+                } else {
+                    "user"
+                };
+                let blocks = content.iter().map(content_block).collect();
+                push_blocks(&mut messages, role, blocks);
+            }
+            ResponseItem::Reasoning {
+                content,
+                encrypted_content,
+                ..
+            } => {
+                // Unsigned thinking is rejected by the API, so only signed blocks are replayed.
+                let Some(encrypted_content) = encrypted_content else {
+                    continue;
+                };
+                let text = content
+                    .iter()
+                    .flatten()
+                    .map(|content| match content {
+                        ReasoningItemContent::ReasoningText { text }
+                        | ReasoningItemContent::Text { text } => text.as_str(),
+                    })
+                    .collect::<String>();
+                let block = if text.is_empty() {
+                    json!({ "type": "redacted_thinking", "data": encrypted_content })
+                } else {
+                    json!({ "type": "thinking", "thinking": text, "signature": encrypted_content })
+                };
+                push_blocks(&mut messages, "assistant", vec![block]);
+            }
+            ResponseItem::FunctionCall {
+                name,
+                arguments,
+                call_id,
+                ..
+            } => {
+                let input = serde_json::from_str::<Value>(arguments)
+                    .ok()
+                    .filter(Value::is_object)
+                    .unwrap_or_else(|| json!({}));
+                push_blocks(
+                    &mut messages,
+                    "assistant",
+                    vec![tool_use(call_id, name, input)],
+                );
+            }
+            ResponseItem::CustomToolCall {
+                call_id,
+                name,
+                input,
+                ..
+            } => push_blocks(
+                &mut messages,
+                "assistant",
+                vec![tool_use(
+                    call_id,
+                    name,
+                    json!({ CUSTOM_TOOL_INPUT_FIELD: input }),
+                )],
+            ),
+            ResponseItem::LocalShellCall {
+                id,
+                call_id,
+                action,
+                ..
+            } => {
+                let Some(call_id) = call_id.as_ref().or(id.as_ref()) else {
+                    continue;
+                };
+                let LocalShellAction::Exec(exec) = action;
+                let input = serde_json::to_value(exec).unwrap_or_else(|_| json!({}));
+                push_blocks(
+                    &mut messages,
+                    "assistant",
+                    vec![tool_use(call_id, LOCAL_SHELL_TOOL_NAME, input)],
+                );
+            }
+            ResponseItem::FunctionCallOutput { call_id, output } => {
+                let content = match &output.body {
+                    FunctionCallOutputBody::Text(text) => json!(text),
+                    FunctionCallOutputBody::ContentItems(items) => Value::Array(
+                        items
+                            .iter()
+                            .map(|item| match item {
+                                FunctionCallOutputContentItem::InputText { text } => {
+                                    json!({ "type": "text", "text": text })
+                                }
+                                FunctionCallOutputContentItem::InputImage { image_url } => {
+                                    image_block(image_url)
+                                }
+                            })
+                            .collect(),
+                    ),
+                };
+                let mut block = json!({
+                    "type": "tool_result",
+                    "tool_use_id": call_id,
+                    "content": content,
+                });
+                if output.success == Some(false) {
+                    block["is_error"] = Value::Bool(true);
+                }
+                push_blocks(&mut messages, "user", vec![block]);
+            }
+            ResponseItem::CustomToolCallOutput { call_id, output } => push_blocks(
+                &mut messages,
+                "user",
+                vec![json!({
+                    "type": "tool_result",
+                    "tool_use_id": call_id,
+                    "content": output,
+                })],
+            ),
+            ResponseItem::WebSearchCall { .. }
+            | ResponseItem::GhostSnapshot { .. }
+            | ResponseItem::Compaction { .. }
+            | ResponseItem::Other => {}
+        }
+    }
+    messages
+}
+
+/// Appends `blocks` to the trailing message when it has the same role; Messages expects turns
+/// to alternate, and a tool call's thinking, text and `tool_use` blocks belong to one turn.
+fn push_blocks(messages: &mut Vec<Value>, role: &str, blocks: Vec<Value>) {
+    if blocks.is_empty() {
+        return;
+    }
+    if let Some(last) = messages.last_mut()
+        && last["role"] == role
+        && let Some(content) = last["content"].as_array_mut()
+    {
+        content.extend(blocks);
+        return;
+    }
+    messages.push(json!({ "role": role, "content": blocks }));
+}
+
+fn content_block(item: &ContentItem) -> Value {
+    match item {
+        ContentItem::InputText { text } | ContentItem::OutputText { text } => {
+            json!({ "type": "text", "text": text })
+        }
+        ContentItem::InputImage { image_url } => image_block(image_url),
+    }
+}
+
+fn image_block(image_url: &str) -> Value {
+    let source = image_url
+        .strip_prefix("data:")
+        .and_then(|rest| rest.split_once(";base64,"))
+        .map(|(media_type, data)| {
+            json!({ "type": "base64", "media_type": media_type, "data": data })
+        })
+        .unwrap_or_else(|| json!({ "type": "url", "url": image_url }));
+    json!({ "type": "image", "source": source })
+}
+
+fn tool_use(call_id: &str, name: &str, input: Value) -> Value {
+    json!({ "type": "tool_use", "id": call_id, "name": name, "input": input })
+}
+
+fn messages_tools(tools: &[Value]) -> Vec<Value> {
+    tools
+        .iter()
+        .filter_map(|tool| {
+            let function = match tool.get("type").and_then(Value::as_str) {
+                Some("function") => tool.clone(),
+                Some("custom") => custom_tool_as_function(tool)["function"].clone(),
+                other => {
+                    debug!("dropping tool unsupported by messages: {other:?}");
+                    return None;
+                }
+            };
+            let mut converted = Map::new();
+            for key in ["name", "description"] {
+                if let Some(value) = function.get(key) {
+                    converted.insert(key.to_string(), value.clone());
+                }
+            }
+            converted.insert(
+                "input_schema".to_string(),
+                function
+                    .get("parameters")
+                    .cloned()
+                    .unwrap_or_else(|| json!({ "type": "object", "properties": {} })),
+            );
+            Some(Value::Object(converted))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::Reasoning;
+    use codex_protocol::models::FunctionCallOutputPayload;
+    use pretty_assertions::assert_eq;
+
+    fn request(input: Vec<ResponseItem>, tools: Vec<Value>) -> ResponsesApiRequest {
+        ResponsesApiRequest {
+            model: "claude-test".to_string(),
+            instructions: "be helpful".to_string(),
+            input,
+            tools,
+            tool_choice: "auto".to_string(),
+            parallel_tool_calls: true,
+            reasoning: Some(Reasoning {
+                effort: Some(ReasoningEffort::Low),
+                summary: None,
+            }),
+            store: false,
+            stream: true,
+            include: Vec::new(),
+            prompt_cache_key: None,
+            text: None,
+            max_output_tokens: None,
+        }
+    }
+
+    fn user_text(text: &str) -> ResponseItem {
+        ResponseItem::Message {
+            id: None,
+            role: "user".to_string(),
+            content: vec![ContentItem::InputText {
+                text: text.to_string(),
+            }],
+            end_turn: None,
+            phase: None,
+        }
+    }
+
+    #[test]
+    fn translates_tool_round_trip_thinking_and_cache_breakpoints() {
+        let input = vec![
+            user_text("list files"),
+            ResponseItem::Reasoning {
+                id: String::new(),
+                summary: Vec::new(),
+                content: Some(vec![ReasoningItemContent::ReasoningText {
+                    text: "use Bash".to_string(),
+                }]),
+                encrypted_content: Some("sig-1".to_string()),
+            },
+            ResponseItem::FunctionCall {
+                id: None,
+                name: "Bash".to_string(),
+                arguments: "{\"command\":\"ls\"}".to_string(),
+                call_id: "toolu_1".to_string(),
+            },
+            ResponseItem::FunctionCallOutput {
+                call_id: "toolu_1".to_string(),
+                output: FunctionCallOutputPayload {
+                    body: FunctionCallOutputBody::Text("a.txt".to_string()),
+                    success: Some(false),
+                },
+            },
+            user_text("thanks"),
+        ];
+        let tools = vec![
+            json!({"type": "function", "name": "Bash", "description": "run", "strict": false, "parameters": {"type": "object"}}),
+            json!({"type": "web_search"}),
+        ];
+
+        let body = build_messages_request(&request(input, tools));
+
+        assert_eq!(
+            body["system"],
+            json!([{"type": "text", "text": "be helpful", "cache_control": {"type": "ephemeral"}}])
+        );
+        assert_eq!(
+            body["messages"],
+            json!([
+                {"role": "user", "content": [
+                    {"type": "text", "text": "list files", "cache_control": {"type": "ephemeral"}},
+                ]},
+                {"role": "assistant", "content": [
+                    {"type": "thinking", "thinking": "use Bash", "signature": "sig-1"},
+                    {"type": "tool_use", "id": "toolu_1", "name": "Bash", "input": {"command": "ls"}},
+                ]},
+                {"role": "user", "content": [
+                    {"type": "tool_result", "tool_use_id": "toolu_1", "content": "a.txt", "is_error": true},
+                    {"type": "text", "text": "thanks", "cache_control": {"type": "ephemeral"}},
+                ]},
+            ])
+        );
+        assert_eq!(
+            body["tools"],
+            json!([{
+                "name": "Bash",
+                "description": "run",
+                "input_schema": {"type": "object"},
+                "cache_control": {"type": "ephemeral"},
+            }])
+        );
+        assert_eq!(
+            body["tool_choice"],
+            json!({"type": "auto", "disable_parallel_tool_use": false})
+        );
+        assert_eq!(
+            body["thinking"],
+            json!({"type": "enabled", "budget_tokens": 4_000})
+        );
+    }
+
+    #[test]
+    fn output_cap_below_thinking_budget_disables_thinking() {
+        let mut request = request(vec![user_text("summarize")], Vec::new());
+        request.max_output_tokens = Some(1_024);
+
+        let body = build_messages_request(&request);
+
+        assert_eq!(body["max_tokens"], json!(1_024));
+        assert_eq!(body.get("thinking"), None);
+    }
+    #[test]
+    fn required_tool_choice_disables_thinking() {
+        let tools =
+            vec![json!({"type": "function", "name": "Bash", "parameters": {"type": "object"}})];
+        let mut request = request(vec![user_text("list files")], tools);
+        request.tool_choice = "required".to_string();
+
+        let body = build_messages_request(&request);
+
+        assert_eq!(
+            body["tool_choice"],
+            json!({"type": "any", "disable_parallel_tool_use": false})
+        );
+        assert_eq!(body.get("thinking"), None);
+    }
+}
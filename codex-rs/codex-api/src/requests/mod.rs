@@ -1,2 +1,4 @@
+pub mod chat;
 pub(crate) mod headers;
+pub mod messages;
 pub mod responses;
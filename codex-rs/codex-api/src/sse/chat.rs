@@ -0,0 +1,603 @@
+//! Decodes a Chat Completions SSE stream into the [`ResponseEvent`]s core consumes.
+//!
+//! Chat Completions streams deltas for a single choice instead of discrete output items, so the
+//! decoder synthesizes items: reasoning and assistant text are opened with
+//! `OutputItemAdded`, streamed as deltas and closed with `OutputItemDone`; tool calls are
+//! accumulated and emitted once `finish_reason` arrives. `Completed` is sent after `[DONE]` so
+//! the trailing usage chunk can be attached.
+
+use crate::common::ResponseEvent;
+use crate::common::ResponseStream;
+use crate::error::ApiError;
+use crate::rate_limits::parse_rate_limit;
+use crate::requests::chat::CUSTOM_TOOL_INPUT_FIELD;
+use crate::sse::responses::Error;
+use crate::sse::responses::map_error;
+use crate::telemetry::SseTelemetry;
+use codex_client::ByteStream;
+use codex_client::StreamResponse;
+use codex_protocol::models::ContentItem;
+use codex_protocol::models::ReasoningItemContent;
+use codex_protocol::models::ResponseItem;
+use codex_protocol::protocol::TokenUsage;
+use eventsource_stream::Eventsource;
+use futures::StreamExt;
+use serde::Deserialize;
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::mpsc;
+use tokio::time::Instant;
+use tokio::time::timeout;
+use tracing::debug;
+use tracing::trace;
+
+const DONE_SENTINEL: &str = "[DONE]";
+
+pub fn spawn_chat_stream(
+    stream_response: StreamResponse,
+    idle_timeout: Duration,
+    telemetry: Option<Arc<dyn SseTelemetry>>,
+    custom_tools: HashSet<String>,
+) -> ResponseStream {
+    let rate_limits = parse_rate_limit(&stream_response.headers);
+    let (tx_event, rx_event) = mpsc::channel::<Result<ResponseEvent, ApiError>>(1600);
+    tokio::spawn(async move {
+        if let Some(snapshot) = rate_limits {
+            let _ = tx_event.send(Ok(ResponseEvent::RateLimits(snapshot))).await;
+        }
+        process_chat_sse(
+            stream_response.bytes,
+            tx_event,
+            idle_timeout,
+            telemetry,
+            custom_tools,
+        )
+        .await;
+    });
+
+    ResponseStream { rx_event }
+}
+
+#[derive(Debug, Deserialize)]
+struct ChatChunk {
+    #[serde(default)]
+    id: Option<String>,
+    #[serde(default)]
+    choices: Vec<ChatChoice>,
+    #[serde(default)]
+    usage: Option<ChatUsage>,
+    #[serde(default)]
+    error: Option<Value>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ChatChoice {
+    #[serde(default)]
+    delta: Option<ChatDelta>,
+    #[serde(default)]
+    finish_reason: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ChatDelta {
+    #[serde(default)]
+    content: Option<String>,
+    /// DeepSeek, vLLM and most OpenAI-compatible servers.
+    #[serde(default)]
+    reasoning_content: Option<String>,
+    /// OpenRouter and Ollama.
+    #[serde(default)]
+    reasoning: Option<String>,
+    #[serde(default)]
+    tool_calls: Vec<ChatToolCallDelta>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ChatToolCallDelta {
+    #[serde(default)]
+    index: Option<usize>,
+    #[serde(default)]
+    id: Option<String>,
+    #[serde(default)]
+    function: Option<ChatFunctionDelta>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ChatFunctionDelta {
+    #[serde(default)]
+    name: Option<String>,
+    #[serde(default)]
+    arguments: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ChatUsage {
+    prompt_tokens: i64,
+    completion_tokens: i64,
+    total_tokens: i64,
+    #[serde(default)]
+    prompt_tokens_details: Option<ChatPromptTokensDetails>,
+    #[serde(default)]
+    completion_tokens_details: Option<ChatCompletionTokensDetails>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ChatPromptTokensDetails {
+    #[serde(default)]
+    cached_tokens: Option<i64>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ChatCompletionTokensDetails {
+    #[serde(default)]
+    reasoning_tokens: Option<i64>,
+}
+
+impl From<ChatUsage> for TokenUsage {
+    fn from(usage: ChatUsage) -> Self {
+        TokenUsage {
+            input_tokens: usage.prompt_tokens,
+            cached_input_tokens: usage
+                .prompt_tokens_details
+                .and_then(|details| details.cached_tokens)
+                .unwrap_or(0),
+            output_tokens: usage.completion_tokens,
+            reasoning_output_tokens: usage
+                .completion_tokens_details
+                .and_then(|details| details.reasoning_tokens)
+                .unwrap_or(0),
+            total_tokens: usage.total_tokens,
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct PendingToolCall {
+    id: Option<String>,
+    name: String,
+    arguments: String,
+}
+
+#[derive(Debug, Default)]
+struct ChatStreamState {
+    custom_tools: HashSet<String>,
+    response_id: String,
+    created: bool,
+    /// Reasoning item that has been announced with `OutputItemAdded`.
+    reasoning: Option<String>,
+    /// Reasoning that arrived after assistant text started; recorded without deltas.
+    trailing_reasoning: String,
+    message: Option<String>,
+    tool_calls: BTreeMap<usize, PendingToolCall>,
+    finished: bool,
+    token_usage: Option<TokenUsage>,
+}
+
+impl ChatStreamState {
+    fn new(custom_tools: HashSet<String>) -> Self {
+        Self {
+            custom_tools,
+            ..Self::default()
+        }
+    }
+
+    fn on_chunk(&mut self, chunk: ChatChunk) -> Vec<ResponseEvent> {
+        let mut events = Vec::new();
+        if !self.created {
+            self.created = true;
+            events.push(ResponseEvent::Created);
+        }
+        if let Some(id) = chunk.id
+            && self.response_id.is_empty()
+        {
+            self.response_id = id;
+        }
+        if let Some(usage) = chunk.usage {
+            self.token_usage = Some(usage.into());
+        }
+
+        // Codex only ever requests a single choice.
+        if let Some(choice) = chunk.choices.into_iter().next() {
+            if let Some(delta) = choice.delta {
+                if let Some(reasoning) = delta.reasoning_content.or(delta.reasoning)
+                    && !reasoning.is_empty()
+                {
+                    self.on_reasoning_delta(reasoning, &mut events);
+                }
+                if let Some(content) = delta.content
+                    && !content.is_empty()
+                {
+                    self.on_content_delta(content, &mut events);
+                }
+                for tool_call in delta.tool_calls {
+                    self.on_tool_call_delta(tool_call);
+                }
+            }
+            if choice.finish_reason.is_some() && !self.finished {
+                self.finish_items(&mut events);
+            }
+        }
+        events
+    }
+
+    fn on_reasoning_delta(&mut self, delta: String, events: &mut Vec<ResponseEvent>) {
+        if self.message.is_some() {
+            self.trailing_reasoning.push_str(&delta);
+            return;
+        }
+        let text = self.reasoning.get_or_insert_with(|| {
+            events.push(ResponseEvent::OutputItemAdded(
+                reasoning_item(String::new()),
+            ));
+            String::new()
+        });
+        text.push_str(&delta);
+        events.push(ResponseEvent::ReasoningContentDelta {
+            delta,
+            content_index: 0,
+        });
+    }
+
+    fn on_content_delta(&mut self, delta: String, events: &mut Vec<ResponseEvent>) {
+        if let Some(reasoning) = self.reasoning.take() {
+            events.push(ResponseEvent::OutputItemDone(reasoning_item(reasoning)));
+        }
+        let text = self.message.get_or_insert_with(|| {
+            events.push(ResponseEvent::OutputItemAdded(message_item(String::new())));
+            String::new()
+        });
+        text.push_str(&delta);
+        events.push(ResponseEvent::OutputTextDelta(delta));
+    }
+
+    fn on_tool_call_delta(&mut self, delta: ChatToolCallDelta) {
+        // Servers that omit `index` send each call whole, so a new `id` starts a new call.
+        let index = delta.index.unwrap_or_else(|| {
+            let next = self.tool_calls.len();
+            if delta.id.is_some() {
+                next
+            } else {
+                next.saturating_sub(1)
+            }
+        });
+        let pending = self.tool_calls.entry(index).or_default();
+        if let Some(id) = delta.id {
+            pending.id = Some(id);
+        }
+        if let Some(function) = delta.function {
+            if let Some(name) = function.name {
+                pending.name.push_str(&name);
+            }
+            if let Some(arguments) = function.arguments {
+                pending.arguments.push_str(&arguments);
+            }
+        }
+    }
+
+    /// Closes open items in the order core expects: reasoning, assistant text, then tool calls.
+    fn finish_items(&mut self, events: &mut Vec<ResponseEvent>) {
+        self.finished = true;
+        if let Some(reasoning) = self.reasoning.take() {
+            events.push(ResponseEvent::OutputItemDone(reasoning_item(reasoning)));
+        }
+        if let Some(message) = self.message.take() {
+            events.push(ResponseEvent::OutputItemDone(message_item(message)));
+        }
+        if !self.trailing_reasoning.is_empty() {
+            let reasoning = std::mem::take(&mut self.trailing_reasoning);
+            events.push(ResponseEvent::OutputItemDone(reasoning_item(reasoning)));
+        }
+        for (index, tool_call) in std::mem::take(&mut self.tool_calls) {
+            events.push(ResponseEvent::OutputItemDone(
+                self.tool_call_item(index, tool_call),
+            ));
+        }
+    }
+
+    fn tool_call_item(&self, index: usize, tool_call: PendingToolCall) -> ResponseItem {
+        let PendingToolCall {
+            id,
+            name,
+            arguments,
+        } = tool_call;
+        let call_id = id.unwrap_or_else(|| format!("call_{index}"));
+        if self.custom_tools.contains(&name) {
+            let input = serde_json::from_str::<Value>(&arguments)
+                .ok()
+                .and_then(|value| {
+                    value
+                        .get(CUSTOM_TOOL_INPUT_FIELD)
+                        .and_then(Value::as_str)
+                        .map(str::to_string)
+                })
+                .unwrap_or(arguments);
+            return ResponseItem::CustomToolCall {
+                id: None,
+                status: None,
+                call_id,
+                name,
+                input,
+            };
+        }
+        ResponseItem::FunctionCall {
+            id: None,
+            name,
+            arguments,
+            call_id,
+        }
+    }
+
+    fn completed(&mut self) -> ResponseEvent {
+        ResponseEvent::Completed {
+            response_id: std::mem::take(&mut self.response_id),
+            token_usage: self.token_usage.take(),
+        }
+    }
+}
+
+fn reasoning_item(text: String) -> ResponseItem {
+    ResponseItem::Reasoning {
+        id: String::new(),
+        summary: Vec::new(),
+        content: Some(if text.is_empty() {
+            Vec::new()
+        } else {
+            vec![ReasoningItemContent::ReasoningText { text }]
+        }),
+        encrypted_content: None,
+    }
+}
+
+pub(crate) fn message_item(text: String) -> ResponseItem {
+    ResponseItem::Message {
+        id: None,
+        role: "assistant".to_string(),
+        content: if text.is_empty() {
+            Vec::new()
+        } else {
+            vec![ContentItem::OutputText { text }]
+        },
+        end_turn: None,
+        phase: None,
+    }
+}
+
+fn chat_error(error: Value) -> ApiError {
+    match serde_json::from_value::<Error>(error.clone()) {
+        Ok(error) => map_error(error),
+        Err(_) => ApiError::Stream(format!("chat completions error: {error}")),
+    }
+}
+
+pub async fn process_chat_sse(
+    stream: ByteStream,
+    tx_event: mpsc::Sender<Result<ResponseEvent, ApiError>>,
+    idle_timeout: Duration,
+    telemetry: Option<Arc<dyn SseTelemetry>>,
+    custom_tools: HashSet<String>,
+) {
+    let mut stream = stream.eventsource();
+    let mut state = ChatStreamState::new(custom_tools);
+
+    loop {
+        let start = Instant::now();
+        let response = timeout(idle_timeout, stream.next()).await;
+        if let Some(t) = telemetry.as_ref() {
+            t.on_sse_poll(&response, start.elapsed());
+        }
+        let sse = match response {
+            Ok(Some(Ok(sse))) => sse,
+            Ok(Some(Err(e))) => {
+                debug!("SSE Error: {e:#}");
+                let _ = tx_event.send(Err(ApiError::Stream(e.to_string()))).await;
+                return;
+            }
+            Ok(None) => {
+                // Some servers close the stream without sending `[DONE]`.
+                let event = if state.finished {
+                    Ok(state.completed())
+                } else {
+                    Err(ApiError::Stream(
+                        "stream closed before finish_reason".into(),
+                    ))
+                };
+                let _ = tx_event.send(event).await;
+                return;
+            }
+            Err(_) => {
+                let _ = tx_event
+                    .send(Err(ApiError::Stream("idle timeout waiting for SSE".into())))
+                    .await;
+                return;
+            }
+        };
+
+        trace!("SSE event: {}", &sse.data);
+
+        if sse.data.trim() == DONE_SENTINEL {
+            let mut events = Vec::new();
+            if !state.finished {
+                state.finish_items(&mut events);
+            }
+            events.push(state.completed());
+            for event in events {
+                if tx_event.send(Ok(event)).await.is_err() {
+                    return;
+                }
+            }
+            return;
+        }
+
+        let chunk: ChatChunk = match serde_json::from_str(&sse.data) {
+            Ok(chunk) => chunk,
+            Err(e) => {
+                debug!("Failed to parse chat SSE chunk: {e}, data: {}", &sse.data);
+                continue;
+            }
+        };
+        if let Some(error) = chunk.error {
+            let _ = tx_event.send(Err(chat_error(error))).await;
+            return;
+        }
+
+        for event in state.on_chunk(chunk) {
+            if tx_event.send(Ok(event)).await.is_err() {
+                return;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert_matches::assert_matches;
+    use codex_client::TransportError;
+    use futures::TryStreamExt;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+    use tokio_util::io::ReaderStream;
+
+    async fn run_chat_sse(chunks: Vec<Value>, done: bool) -> Vec<Result<ResponseEvent, ApiError>> {
+        let mut body = String::new();
+        for chunk in chunks {
+            body.push_str(&format!("data: {chunk}\n\n"));
+        }
+        if done {
+            body.push_str("data: [DONE]\n\n");
+        }
+
+        let (tx, mut rx) = mpsc::channel::<Result<ResponseEvent, ApiError>>(32);
+        let stream = ReaderStream::new(std::io::Cursor::new(body))
+            .map_err(|err| TransportError::Network(err.to_string()));
+        let custom_tools = HashSet::from(["apply_patch".to_string()]);
+        tokio::spawn(process_chat_sse(
+            Box::pin(stream),
+            tx,
+            Duration::from_millis(1000),
+            None,
+            custom_tools,
+        ));
+
+        let mut events = Vec::new();
+        while let Some(event) = rx.recv().await {
+            events.push(event);
+        }
+        events
+    }
+
+    #[tokio::test]
+    async fn emits_reasoning_text_tool_calls_and_usage() {
+        let events = run_chat_sse(
+            vec![
+                json!({"id": "chatcmpl-1", "choices": [{"index": 0, "delta": {"role": "assistant", "reasoning_content": "Think"}}]}),
+                json!({"id": "chatcmpl-1", "choices": [{"index": 0, "delta": {"content": "Hi"}}]}),
+                json!({"id": "chatcmpl-1", "choices": [{"index": 0, "delta": {"tool_calls": [{"index": 0, "id": "call_a", "type": "function", "function": {"name": "shell", "arguments": "{\"command\":"}}]}}]}),
+                json!({"id": "chatcmpl-1", "choices": [{"index": 0, "delta": {"tool_calls": [{"index": 0, "function": {"arguments": "[\"ls\"]}"}}]}}]}),
+                json!({"id": "chatcmpl-1", "choices": [{"index": 0, "delta": {"tool_calls": [{"index": 1, "id": "call_b", "function": {"name": "apply_patch", "arguments": "{\"input\":\"*** Begin Patch\"}"}}]}}]}),
+                json!({"id": "chatcmpl-1", "choices": [{"index": 0, "delta": {}, "finish_reason": "tool_calls"}]}),
+                json!({"id": "chatcmpl-1", "choices": [], "usage": {"prompt_tokens": 10, "completion_tokens": 5, "total_tokens": 15, "prompt_tokens_details": {"cached_tokens": 4}, "completion_tokens_details": {"reasoning_tokens": 2}}}),
+            ],
+            true,
+        )
+        .await;
+        let events: Vec<ResponseEvent> = events
+            .into_iter()
+            .map(|event| event.expect("stream event"))
+            .collect();
+
+        assert_matches!(events[0], ResponseEvent::Created);
+        assert_matches!(
+            events[1],
+            ResponseEvent::OutputItemAdded(ResponseItem::Reasoning { .. })
+        );
+        assert_matches!(
+            &events[2],
+            ResponseEvent::ReasoningContentDelta { delta, content_index: 0 } if delta == "Think"
+        );
+        assert_matches!(
+            &events[3],
+            ResponseEvent::OutputItemDone(ResponseItem::Reasoning { content: Some(content), .. })
+                if content == &vec![ReasoningItemContent::ReasoningText { text: "Think".to_string() }]
+        );
+        assert_matches!(
+            events[4],
+            ResponseEvent::OutputItemAdded(ResponseItem::Message { .. })
+        );
+        assert_matches!(&events[5], ResponseEvent::OutputTextDelta(delta) if delta == "Hi");
+        assert_matches!(
+            &events[6],
+            ResponseEvent::OutputItemDone(ResponseItem::Message { content, .. })
+                if content == &vec![ContentItem::OutputText { text: "Hi".to_string() }]
+        );
+        assert_matches!(
+            &events[7],
+            ResponseEvent::OutputItemDone(ResponseItem::FunctionCall { name, arguments, call_id, .. })
+                if name == "shell" && arguments == "{\"command\":[\"ls\"]}" && call_id == "call_a"
+        );
+        assert_matches!(
+            &events[8],
+            ResponseEvent::OutputItemDone(ResponseItem::CustomToolCall { name, input, call_id, .. })
+                if name == "apply_patch" && input == "*** Begin Patch" && call_id == "call_b"
+        );
+        match &events[9] {
+            ResponseEvent::Completed {
+                response_id,
+                token_usage,
+            } => {
+                assert_eq!(response_id, "chatcmpl-1");
+                assert_eq!(
+                    token_usage,
+                    &Some(TokenUsage {
+                        input_tokens: 10,
+                        cached_input_tokens: 4,
+                        output_tokens: 5,
+                        reasoning_output_tokens: 2,
+                        total_tokens: 15,
+                    })
+                );
+            }
+            other => panic!("unexpected event: {other:?}"),
+        }
+        assert_eq!(events.len(), 10);
+    }
+
+    #[tokio::test]
+    async fn completes_without_done_sentinel_after_finish_reason() {
+        let events = run_chat_sse(
+            vec![
+                json!({"id": "c", "choices": [{"delta": {"content": "ok"}, "finish_reason": "stop"}]}),
+            ],
+            false,
+        )
+        .await;
+
+        assert_matches!(
+            events.last(),
+            Some(Ok(ResponseEvent::Completed { response_id, token_usage: None })) if response_id == "c"
+        );
+    }
+
+    #[tokio::test]
+    async fn errors_when_stream_ends_early_or_reports_error() {
+        let events = run_chat_sse(
+            vec![json!({"id": "c", "choices": [{"delta": {"content": "partial"}}]})],
+            false,
+        )
+        .await;
+        assert_matches!(
+            events.last(),
+            Some(Err(ApiError::Stream(message))) if message == "stream closed before finish_reason"
+        );
+
+        let events = run_chat_sse(
+            vec![json!({"error": {"code": "context_length_exceeded", "message": "too long"}})],
+            true,
+        )
+        .await;
+        assert_matches!(events.as_slice(), [Err(ApiError::ContextWindowExceeded)]);
+    }
+}
@@ -0,0 +1,623 @@
+//! Decodes an Anthropic Messages SSE stream into the [`ResponseEvent`]s core consumes.
+//!
+//! Each content block maps onto one output item: `text` blocks become assistant messages,
+//! `thinking` blocks become reasoning items (with the block signature kept in
+//! `encrypted_content` so it can be replayed), and `tool_use` blocks become function calls once
+//! their streamed JSON input is complete.
+
+use crate::common::ResponseEvent;
+use crate::common::ResponseStream;
+use crate::error::ApiError;
+use crate::rate_limits::parse_rate_limit;
+use crate::requests::chat::CUSTOM_TOOL_INPUT_FIELD;
+use crate::sse::chat::message_item;
+use crate::telemetry::SseTelemetry;
+use codex_client::ByteStream;
+use codex_client::StreamResponse;
+use codex_protocol::models::ReasoningItemContent;
+use codex_protocol::models::ResponseItem;
+use codex_protocol::protocol::TokenUsage;
+use eventsource_stream::Eventsource;
+use futures::StreamExt;
+use serde::Deserialize;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::mpsc;
+use tokio::time::Instant;
+use tokio::time::timeout;
+use tracing::debug;
+use tracing::trace;
+
+pub fn spawn_messages_stream(
+    stream_response: StreamResponse,
+    idle_timeout: Duration,
+    telemetry: Option<Arc<dyn SseTelemetry>>,
+    custom_tools: HashSet<String>,
+) -> ResponseStream {
+    let rate_limits = parse_rate_limit(&stream_response.headers);
+    let (tx_event, rx_event) = mpsc::channel::<Result<ResponseEvent, ApiError>>(1600);
+    tokio::spawn(async move {
+        if let Some(snapshot) = rate_limits {
+            let _ = tx_event.send(Ok(ResponseEvent::RateLimits(snapshot))).await;
+        }
+        process_messages_sse(
+            stream_response.bytes,
+            tx_event,
+            idle_timeout,
+            telemetry,
+            custom_tools,
+        )
+        .await;
+    });
+
+    ResponseStream { rx_event }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum MessagesEvent {
+    MessageStart {
+        message: MessageStart,
+    },
+    ContentBlockStart {
+        index: usize,
+        content_block: ContentBlock,
+    },
+    ContentBlockDelta {
+        index: usize,
+        delta: ContentDelta,
+    },
+    ContentBlockStop {
+        index: usize,
+    },
+    MessageDelta {
+        #[serde(default)]
+        usage: Option<MessagesUsage>,
+    },
+    MessageStop,
+    Ping,
+    Error {
+        error: MessagesError,
+    },
+}
+
+#[derive(Debug, Deserialize)]
+struct MessageStart {
+    id: String,
+    #[serde(default)]
+    usage: Option<MessagesUsage>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum ContentBlock {
+    Text {
+        #[serde(default)]
+        text: String,
+    },
+    Thinking {
+        #[serde(default)]
+        thinking: String,
+        #[serde(default)]
+        signature: String,
+    },
+    RedactedThinking {
+        data: String,
+    },
+    ToolUse {
+        id: String,
+        name: String,
+    },
+    #[serde(other)]
+    Unsupported,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum ContentDelta {
+    TextDelta {
+        text: String,
+    },
+    ThinkingDelta {
+        thinking: String,
+    },
+    SignatureDelta {
+        signature: String,
+    },
+    InputJsonDelta {
+        partial_json: String,
+    },
+    #[serde(other)]
+    Unsupported,
+}
+
+/// Usage is reported cumulatively: `message_start` carries the input side and `message_delta`
+/// updates the output count.
+#[derive(Debug, Default, Deserialize)]
+struct MessagesUsage {
+    #[serde(default)]
+    input_tokens: Option<i64>,
+    #[serde(default)]
+    cache_creation_input_tokens: Option<i64>,
+    #[serde(default)]
+    cache_read_input_tokens: Option<i64>,
+    #[serde(default)]
+    output_tokens: Option<i64>,
+}
+
+#[derive(Debug, Deserialize)]
+struct MessagesError {
+    #[serde(rename = "type")]
+    kind: String,
+    #[serde(default)]
+    message: String,
+}
+
+#[derive(Debug)]
+enum OpenBlock {
+    Text(String),
+    Thinking {
+        text: String,
+        signature: String,
+    },
+    ToolUse {
+        id: String,
+        name: String,
+        input: String,
+    },
+}
+
+#[derive(Debug, Default)]
+struct MessagesStreamState {
+    custom_tools: HashSet<String>,
+    response_id: String,
+    blocks: HashMap<usize, OpenBlock>,
+    usage: MessagesUsage,
+}
+
+impl MessagesStreamState {
+    fn new(custom_tools: HashSet<String>) -> Self {
+        Self {
+            custom_tools,
+            ..Self::default()
+        }
+    }
+
+    fn on_event(&mut self, event: MessagesEvent) -> Result<Vec<ResponseEvent>, ApiError> {
+        let mut events = Vec::new();
+        match event {
+            MessagesEvent::MessageStart { message } => {
+                self.response_id = message.id;
+                if let Some(usage) = message.usage {
+                    self.merge_usage(usage);
+                }
+                events.push(ResponseEvent::Created);
+            }
+            MessagesEvent::ContentBlockStart {
+                index,
+                content_block,
+            } => match content_block {
+                ContentBlock::Text { text } => {
+                    events.push(ResponseEvent::OutputItemAdded(message_item(String::new())));
+                    if !text.is_empty() {
+                        events.push(ResponseEvent::OutputTextDelta(text.clone()));
+                    }
+                    self.blocks.insert(index, OpenBlock::Text(text));
+                }
+                ContentBlock::Thinking {
+                    thinking,
+                    signature,
+                } => {
+                    events.push(ResponseEvent::OutputItemAdded(reasoning_item(
+                        String::new(),
+                        None,
+                    )));
+                    if !thinking.is_empty() {
+                        events.push(ResponseEvent::ReasoningContentDelta {
+                            delta: thinking.clone(),
+                            content_index: 0,
+                        });
+                    }
+                    self.blocks.insert(
+                        index,
+                        OpenBlock::Thinking {
+                            text: thinking,
+                            signature,
+                        },
+                    );
+                }
+                // Redacted thinking arrives whole and only needs to be replayed verbatim.
+                ContentBlock::RedactedThinking { data } => {
+                    events.push(ResponseEvent::OutputItemDone(reasoning_item(
+                        String::new(),
+                        Some(data),
+                    )));
+                }
+                ContentBlock::ToolUse { id, name } => {
+                    self.blocks.insert(
+                        index,
+                        OpenBlock::ToolUse {
+                            id,
+                            name,
+                            input: String::new(),
+                        },
+                    );
+                }
+                ContentBlock::Unsupported => {
+                    debug!("ignoring unsupported messages content block at index {index}");
+                }
+            },
+            MessagesEvent::ContentBlockDelta { index, delta } => {
+                match (self.blocks.get_mut(&index), delta) {
+                    (Some(OpenBlock::Text(text)), ContentDelta::TextDelta { text: delta }) => {
+                        text.push_str(&delta);
+                        events.push(ResponseEvent::OutputTextDelta(delta));
+                    }
+                    (
+                        Some(OpenBlock::Thinking { text, .. }),
+                        ContentDelta::ThinkingDelta { thinking },
+                    ) => {
+                        text.push_str(&thinking);
+                        events.push(ResponseEvent::ReasoningContentDelta {
+                            delta: thinking,
+                            content_index: 0,
+                        });
+                    }
+                    (
+                        Some(OpenBlock::Thinking { signature, .. }),
+                        ContentDelta::SignatureDelta { signature: delta },
+                    ) => signature.push_str(&delta),
+                    (
+                        Some(OpenBlock::ToolUse { input, .. }),
+                        ContentDelta::InputJsonDelta { partial_json },
+                    ) => input.push_str(&partial_json),
+                    (_, delta) => {
+                        debug!("ignoring messages delta for block {index}: {delta:?}");
+                    }
+                }
+            }
+            MessagesEvent::ContentBlockStop { index } => {
+                if let Some(block) = self.blocks.remove(&index) {
+                    events.push(ResponseEvent::OutputItemDone(self.block_item(block)));
+                }
+            }
+            MessagesEvent::MessageDelta { usage } => {
+                if let Some(usage) = usage {
+                    self.merge_usage(usage);
+                }
+            }
+            MessagesEvent::MessageStop => {
+                events.push(ResponseEvent::Completed {
+                    response_id: std::mem::take(&mut self.response_id),
+                    token_usage: Some(self.token_usage()),
+                });
+            }
+            MessagesEvent::Ping => {}
+            MessagesEvent::Error { error } => return Err(map_messages_error(error)),
+        }
+        Ok(events)
+    }
+
+    fn merge_usage(&mut self, usage: MessagesUsage) {
+        let current = &mut self.usage;
+        current.input_tokens = usage.input_tokens.or(current.input_tokens);
+        current.cache_creation_input_tokens = usage
+            .cache_creation_input_tokens
+            .or(current.cache_creation_input_tokens);
+        current.cache_read_input_tokens = usage
+            .cache_read_input_tokens
+            .or(current.cache_read_input_tokens);
+        current.output_tokens = usage.output_tokens.or(current.output_tokens);
+    }
+
+    /// Messages reports cache reads and writes separately from `input_tokens`; core counts them
+    /// all as input, with reads as the cached portion.
+    fn token_usage(&self) -> TokenUsage {
+        let cached_input_tokens = self.usage.cache_read_input_tokens.unwrap_or(0);
+        let input_tokens = self.usage.input_tokens.unwrap_or(0)
+            + self.usage.cache_creation_input_tokens.unwrap_or(0)
+            + cached_input_tokens;
+        let output_tokens = self.usage.output_tokens.unwrap_or(0);
+        TokenUsage {
+            input_tokens,
+            cached_input_tokens,
+            output_tokens,
+            reasoning_output_tokens: 0,
+            total_tokens: input_tokens + output_tokens,
+        }
+    }
+
+    fn block_item(&self, block: OpenBlock) -> ResponseItem {
+        match block {
+            OpenBlock::Text(text) => message_item(text),
+            OpenBlock::Thinking { text, signature } => {
+                reasoning_item(text, (!signature.is_empty()).then_some(signature))
+            }
+            OpenBlock::ToolUse { id, name, input } => {
+                // Tools without parameters stream no JSON at all.
+                let arguments = if input.trim().is_empty() {
+                    "{}".to_string()
+                } else {
+                    input
+                };
+                if self.custom_tools.contains(&name) {
+                    let input = serde_json::from_str::<Value>(&arguments)
+                        .ok()
+                        .and_then(|value| {
+                            value
+                                .get(CUSTOM_TOOL_INPUT_FIELD)
+                                .and_then(Value::as_str)
+                                .map(str::to_string)
+                        })
+                        .unwrap_or(arguments);
+                    return ResponseItem::CustomToolCall {
+                        id: None,
+                        status: None,
+                        call_id: id,
+                        name,
+                        input,
+                    };
+                }
+                ResponseItem::FunctionCall {
+                    id: None,
+                    name,
+                    arguments,
+                    call_id: id,
+                }
+            }
+        }
+    }
+}
+
+fn reasoning_item(text: String, encrypted_content: Option<String>) -> ResponseItem {
+    ResponseItem::Reasoning {
+        id: String::new(),
+        summary: Vec::new(),
+        content: Some(if text.is_empty() {
+            Vec::new()
+        } else {
+            vec![ReasoningItemContent::ReasoningText { text }]
+        }),
+        encrypted_content,
+    }
+}
+
+fn map_messages_error(error: MessagesError) -> ApiError {
+    let MessagesError { kind, message } = error;
+    match kind.as_str() {
+        "invalid_request_error" if message.contains("prompt is too long") => {
+            ApiError::ContextWindowExceeded
+        }
+        "invalid_request_error" | "not_found_error" | "permission_error" => {
+            ApiError::InvalidRequest { message }
+        }
+        _ => ApiError::Retryable {
+            message: format!("{kind}: {message}"),
+            delay: None,
+        },
+    }
+}
+
+pub async fn process_messages_sse(
+    stream: ByteStream,
+    tx_event: mpsc::Sender<Result<ResponseEvent, ApiError>>,
+    idle_timeout: Duration,
+    telemetry: Option<Arc<dyn SseTelemetry>>,
+    custom_tools: HashSet<String>,
+) {
+    let mut stream = stream.eventsource();
+    let mut state = MessagesStreamState::new(custom_tools);
+
+    loop {
+        let start = Instant::now();
+        let response = timeout(idle_timeout, stream.next()).await;
+        if let Some(t) = telemetry.as_ref() {
+            t.on_sse_poll(&response, start.elapsed());
+        }
+        let sse = match response {
+            Ok(Some(Ok(sse))) => sse,
+            Ok(Some(Err(e))) => {
+                debug!("SSE Error: {e:#}");
+                let _ = tx_event.send(Err(ApiError::Stream(e.to_string()))).await;
+                return;
+            }
+            Ok(None) => {
+                let _ = tx_event
+                    .send(Err(ApiError::Stream(
+                        "stream closed before message_stop".into(),
+                    )))
+                    .await;
+                return;
+            }
+            Err(_) => {
+                let _ = tx_event
+                    .send(Err(ApiError::Stream("idle timeout waiting for SSE".into())))
+                    .await;
+                return;
+            }
+        };
+
+        trace!("SSE event: {}", &sse.data);
+
+        let event: MessagesEvent = match serde_json::from_str(&sse.data) {
+            Ok(event) => event,
+            Err(e) => {
+                debug!(
+                    "Failed to parse messages SSE event: {e}, data: {}",
+                    &sse.data
+                );
+                continue;
+            }
+        };
+        let is_stop = matches!(event, MessagesEvent::MessageStop);
+        match state.on_event(event) {
+            Ok(events) => {
+                for event in events {
+                    if tx_event.send(Ok(event)).await.is_err() {
+                        return;
+                    }
+                }
+            }
+            Err(err) => {
+                let _ = tx_event.send(Err(err)).await;
+                return;
+            }
+        }
+        if is_stop {
+            return;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use assert_matches::assert_matches;
+    use codex_client::TransportError;
+    use codex_protocol::models::ContentItem;
+    use futures::TryStreamExt;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+    use tokio_util::io::ReaderStream;
+
+    async fn run_messages_sse(events: Vec<Value>) -> Vec<Result<ResponseEvent, ApiError>> {
+        let mut body = String::new();
+        for event in events {
+            let kind = event["type"].as_str().unwrap_or_default().to_string();
+            body.push_str(&format!("event: {kind}\ndata: {event}\n\n"));
+        }
+
+        let (tx, mut rx) = mpsc::channel::<Result<ResponseEvent, ApiError>>(32);
+        let stream = ReaderStream::new(std::io::Cursor::new(body))
+            .map_err(|err| TransportError::Network(err.to_string()));
+        let custom_tools = HashSet::from(["apply_patch".to_string()]);
+        tokio::spawn(process_messages_sse(
+            Box::pin(stream),
+            tx,
+            Duration::from_millis(1000),
+            None,
+            custom_tools,
+        ));
+
+        let mut events = Vec::new();
+        while let Some(event) = rx.recv().await {
+            events.push(event);
+        }
+        events
+    }
+
+    #[tokio::test]
+    async fn emits_thinking_text_tool_use_and_usage() {
+        let events = run_messages_sse(vec![
+            json!({"type": "message_start", "message": {"id": "msg_1", "usage": {"input_tokens": 10, "cache_creation_input_tokens": 3, "cache_read_input_tokens": 100, "output_tokens": 1}}}),
+            json!({"type": "content_block_start", "index": 0, "content_block": {"type": "thinking", "thinking": ""}}),
+            json!({"type": "content_block_delta", "index": 0, "delta": {"type": "thinking_delta", "thinking": "Need ls"}}),
+            json!({"type": "content_block_delta", "index": 0, "delta": {"type": "signature_delta", "signature": "sig"}}),
+            json!({"type": "content_block_stop", "index": 0}),
+            json!({"type": "ping"}),
+            json!({"type": "content_block_start", "index": 1, "content_block": {"type": "text", "text": ""}}),
+            json!({"type": "content_block_delta", "index": 1, "delta": {"type": "text_delta", "text": "Listing."}}),
+            json!({"type": "content_block_stop", "index": 1}),
+            json!({"type": "content_block_start", "index": 2, "content_block": {"type": "tool_use", "id": "toolu_1", "name": "Bash", "input": {}}}),
+            json!({"type": "content_block_delta", "index": 2, "delta": {"type": "input_json_delta", "partial_json": "{\"command\":"}}),
+            json!({"type": "content_block_delta", "index": 2, "delta": {"type": "input_json_delta", "partial_json": "\"ls\"}"}}),
+            json!({"type": "content_block_stop", "index": 2}),
+            json!({"type": "content_block_start", "index": 3, "content_block": {"type": "tool_use", "id": "toolu_2", "name": "apply_patch", "input": {}}}),
+            json!({"type": "content_block_delta", "index": 3, "delta": {"type": "input_json_delta", "partial_json": "{\"input\":\"*** Begin Patch\"}"}}),
+            json!({"type": "content_block_stop", "index": 3}),
+            json!({"type": "message_delta", "delta": {"stop_reason": "tool_use"}, "usage": {"output_tokens": 42}}),
+            json!({"type": "message_stop"}),
+        ])
+        .await;
+        let events: Vec<ResponseEvent> = events
+            .into_iter()
+            .map(|event| event.expect("stream event"))
+            .collect();
+
+        assert_matches!(events[0], ResponseEvent::Created);
+        assert_matches!(
+            events[1],
+            ResponseEvent::OutputItemAdded(ResponseItem::Reasoning { .. })
+        );
+        assert_matches!(
+            &events[2],
+            ResponseEvent::ReasoningContentDelta { delta, content_index: 0 } if delta == "Need ls"
+        );
+        assert_matches!(
+            &events[3],
+            ResponseEvent::OutputItemDone(item)
+                if item == &reasoning_item("Need ls".to_string(), Some("sig".to_string()))
+        );
+        assert_matches!(
+            events[4],
+            ResponseEvent::OutputItemAdded(ResponseItem::Message { .. })
+        );
+        assert_matches!(&events[5], ResponseEvent::OutputTextDelta(delta) if delta == "Listing.");
+        assert_matches!(
+            &events[6],
+            ResponseEvent::OutputItemDone(ResponseItem::Message { content, .. })
+                if content == &vec![ContentItem::OutputText { text: "Listing.".to_string() }]
+        );
+        assert_matches!(
+            &events[7],
+            ResponseEvent::OutputItemDone(ResponseItem::FunctionCall { name, arguments, call_id, .. })
+                if name == "Bash" && arguments == "{\"command\":\"ls\"}" && call_id == "toolu_1"
+        );
+        assert_matches!(
+            &events[8],
+            ResponseEvent::OutputItemDone(ResponseItem::CustomToolCall { name, input, call_id, .. })
+                if name == "apply_patch" && input == "*** Begin Patch" && call_id == "toolu_2"
+        );
+        match &events[9] {
+            ResponseEvent::Completed {
+                response_id,
+                token_usage,
+            } => {
+                assert_eq!(response_id, "msg_1");
+                assert_eq!(
+                    token_usage,
+                    &Some(TokenUsage {
+                        input_tokens: 113,
+                        cached_input_tokens: 100,
+                        output_tokens: 42,
+                        reasoning_output_tokens: 0,
+                        total_tokens: 155,
+                    })
+                );
+            }
+            other => panic!("unexpected event: {other:?}"),
+        }
+        assert_eq!(events.len(), 10);
+    }
+
+    #[tokio::test]
+    async fn maps_error_events_and_early_close() {
+        let events = run_messages_sse(vec![
+            json!({"type": "message_start", "message": {"id": "msg_1"}}),
+            json!({"type": "error", "error": {"type": "overloaded_error", "message": "Overloaded"}}),
+        ])
+        .await;
+        assert_matches!(
+            events.last(),
+            Some(Err(ApiError::Retryable { message, delay: None })) if message == "overloaded_error: Overloaded"
+        );
+
+        let events = run_messages_sse(vec![
+            json!({"type": "error", "error": {"type": "invalid_request_error", "message": "prompt is too long: 210000 tokens > 200000 maximum"}}),
+        ])
+        .await;
+        assert_matches!(events.last(), Some(Err(ApiError::ContextWindowExceeded)));
+
+        let events = run_messages_sse(vec![
+            json!({"type": "message_start", "message": {"id": "msg_1"}}),
+        ])
+        .await;
+        assert_matches!(
+            events.last(),
+            Some(Err(ApiError::Stream(message))) if message == "stream closed before message_stop"
+        );
+    }
+}
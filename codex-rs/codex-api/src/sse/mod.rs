@@ -1,5 +1,11 @@
+pub mod chat;
+pub mod messages;
 pub mod responses;
 
+pub use chat::process_chat_sse;
+pub use chat::spawn_chat_stream;
+pub use messages::process_messages_sse;
+pub use messages::spawn_messages_stream;
 pub use responses::process_sse;
 pub use responses::spawn_response_stream;
 pub use responses::stream_from_fixture;
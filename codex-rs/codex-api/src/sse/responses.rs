@@ -93,7 +93,7 @@ pub fn spawn_response_stream(
 
 #[derive(Debug, Deserialize)]
 #[allow(dead_code)]
-struct Error {
+pub(crate) struct Error {
     r#type: Option<String>,
     code: Option<String>,
     message: Option<String>,
@@ -228,22 +228,7 @@ pub fn process_responses_event(
                 if let Some(error) = resp_val.get("error")
                     && let Ok(error) = serde_json::from_value::<Error>(error.clone())
                 {
-                    if is_context_window_error(&error) {
-                        response_error = ApiError::ContextWindowExceeded;
-                    } else if is_quota_exceeded_error(&error) {
-                        response_error = ApiError::QuotaExceeded;
-                    } else if is_usage_not_included(&error) {
-                        response_error = ApiError::UsageNotIncluded;
-                    } else if is_invalid_prompt_error(&error) {
-                        let message = error
-                            .message
-                            .unwrap_or_else(|| "Invalid request.".to_string());
-                        response_error = ApiError::InvalidRequest { message };
-                    } else {
-                        let delay = try_parse_retry_after(&error);
-                        let message = error.message.unwrap_or_default();
-                        response_error = ApiError::Retryable { message, delay };
-                    }
+                    response_error = map_error(error);
                 }
                 return Err(ResponsesEventError::Api(response_error));
             }
@@ -380,6 +365,27 @@ pub async fn process_sse(
     }
 }
 
+/// Maps an API error payload onto the matching [`ApiError`]. Shared with the Chat Completions
+/// decoder, whose error payloads use the same codes.
+pub(crate) fn map_error(error: Error) -> ApiError {
+    if is_context_window_error(&error) {
+        ApiError::ContextWindowExceeded
+    } else if is_quota_exceeded_error(&error) {
+        ApiError::QuotaExceeded
+    } else if is_usage_not_included(&error) {
+        ApiError::UsageNotIncluded
+    } else if is_invalid_prompt_error(&error) {
+        let message = error
+            .message
+            .unwrap_or_else(|| "Invalid request.".to_string());
+        ApiError::InvalidRequest { message }
+    } else {
+        let delay = try_parse_retry_after(&error);
+        let message = error.message.unwrap_or_default();
+        ApiError::Retryable { message, delay }
+    }
+}
+
 fn try_parse_retry_after(err: &Error) -> Option<Duration> {
     if err.code.as_deref() != Some("rate_limit_exceeded") {
         return None;
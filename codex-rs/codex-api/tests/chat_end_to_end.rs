@@ -0,0 +1,211 @@
+use std::time::Duration;
+
+use anyhow::Result;
+use codex_api::AuthProvider;
+use codex_api::ChatClient;
+use codex_api::ResponseEvent;
+use codex_api::ResponsesApiRequest;
+use codex_api::ResponsesOptions;
+use codex_api::provider::Provider;
+use codex_api::provider::RetryConfig;
+use codex_client::ReqwestTransport;
+use codex_protocol::models::ContentItem;
+use codex_protocol::models::FunctionCallOutputPayload;
+use codex_protocol::models::ResponseItem;
+use futures::StreamExt;
+use http::HeaderMap;
+use pretty_assertions::assert_eq;
+use serde_json::Value;
+use serde_json::json;
+use wiremock::Mock;
+use wiremock::MockServer;
+use wiremock::ResponseTemplate;
+use wiremock::matchers::header;
+use wiremock::matchers::method;
+use wiremock::matchers::path;
+
+#[derive(Clone)]
+struct StaticAuth;
+
+impl AuthProvider for StaticAuth {
+    fn bearer_token(&self) -> Option<String> {
+        Some("sk-test".to_string())
+    }
+}
+
+fn provider(base_url: &str) -> Provider {
+    Provider {
+        name: "chat-test".to_string(),
+        base_url: base_url.to_string(),
+        query_params: None,
+        headers: HeaderMap::new(),
+        retry: RetryConfig {
+            max_attempts: 1,
+            base_delay: Duration::from_millis(1),
+            retry_429: false,
+            retry_5xx: false,
+            retry_transport: false,
+        },
+        stream_idle_timeout: Duration::from_secs(5),
+    }
+}
+
+fn sse_body(chunks: &[Value]) -> String {
+    let mut body = String::new();
+    for chunk in chunks {
+        body.push_str(&format!("data: {chunk}\n\n"));
+    }
+    body.push_str("data: [DONE]\n\n");
+    body
+}
+
+#[tokio::test]
+async fn chat_client_round_trips_tool_calls_against_mock_server() -> Result<()> {
+    let server = MockServer::start().await;
+    let body = sse_body(&[
+        json!({"id": "chatcmpl-9", "choices": [{"index": 0, "delta": {"role": "assistant", "content": "Listing files."}}]}),
+        json!({"id": "chatcmpl-9", "choices": [{"index": 0, "delta": {"tool_calls": [{"index": 0, "id": "call_2", "type": "function", "function": {"name": "shell", "arguments": "{\"command\":[\"ls\"]}"}}]}}]}),
+        json!({"id": "chatcmpl-9", "choices": [{"index": 0, "delta": {}, "finish_reason": "tool_calls"}]}),
+        json!({"id": "chatcmpl-9", "choices": [], "usage": {"prompt_tokens": 20, "completion_tokens": 7, "total_tokens": 27}}),
+    ]);
+    Mock::given(method("POST"))
+        .and(path("/v1/chat/completions"))
+        .and(header("authorization", "Bearer sk-test"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("content-type", "text/event-stream")
+                .set_body_string(body),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let request = ResponsesApiRequest {
+        model: "local-model".to_string(),
+        instructions: "You are Codex.".to_string(),
+        input: vec![
+            ResponseItem::Message {
+                id: None,
+                role: "user".to_string(),
+                content: vec![ContentItem::InputText {
+                    text: "what is here?".to_string(),
+                }],
+                end_turn: None,
+                phase: None,
+            },
+            ResponseItem::FunctionCall {
+                id: None,
+                name: "shell".to_string(),
+                arguments: "{\"command\":[\"pwd\"]}".to_string(),
+                call_id: "call_1".to_string(),
+            },
+            ResponseItem::FunctionCallOutput {
+                call_id: "call_1".to_string(),
+                output: FunctionCallOutputPayload::from_text("/repo".to_string()),
+            },
+        ],
+        tools: vec![json!({
+            "type": "function",
+            "name": "shell",
+            "description": "Runs a shell command.",
+            "strict": false,
+            "parameters": {"type": "object", "properties": {"command": {"type": "array"}}},
+        })],
+        tool_choice: "auto".to_string(),
+        parallel_tool_calls: false,
+        reasoning: None,
+        store: false,
+        stream: true,
+        include: Vec::new(),
+        prompt_cache_key: None,
+        text: None,
+        max_output_tokens: None,
+    };
+
+    let transport = ReqwestTransport::new(reqwest::Client::new());
+    let client = ChatClient::new(
+        transport,
+        provider(&format!("{}/v1", server.uri())),
+        StaticAuth,
+    );
+    let mut stream = client
+        .stream_request(
+            request,
+            ResponsesOptions {
+                conversation_id: Some("conv-1".to_string()),
+                ..Default::default()
+            },
+        )
+        .await?;
+
+    let mut events = Vec::new();
+    while let Some(event) = stream.next().await {
+        events.push(event?);
+    }
+
+    let items: Vec<&ResponseItem> = events
+        .iter()
+        .filter_map(|event| match event {
+            ResponseEvent::OutputItemDone(item) => Some(item),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(
+        items,
+        vec![
+            &ResponseItem::Message {
+                id: None,
+                role: "assistant".to_string(),
+                content: vec![ContentItem::OutputText {
+                    text: "Listing files.".to_string(),
+                }],
+                end_turn: None,
+                phase: None,
+            },
+            &ResponseItem::FunctionCall {
+                id: None,
+                name: "shell".to_string(),
+                arguments: "{\"command\":[\"ls\"]}".to_string(),
+                call_id: "call_2".to_string(),
+            },
+        ]
+    );
+    match events.last() {
+        Some(ResponseEvent::Completed {
+            response_id,
+            token_usage: Some(usage),
+        }) => {
+            assert_eq!(response_id, "chatcmpl-9");
+            assert_eq!(usage.total_tokens, 27);
+        }
+        other => panic!("unexpected final event: {other:?}"),
+    }
+
+    let received = server.received_requests().await.expect("captured requests");
+    assert_eq!(received.len(), 1);
+    assert_eq!(
+        received[0]
+            .headers
+            .get("session_id")
+            .and_then(|value| value.to_str().ok()),
+        Some("conv-1")
+    );
+    let sent: Value = serde_json::from_slice(&received[0].body)?;
+    assert_eq!(sent["model"], "local-model");
+    assert_eq!(sent["stream"], true);
+    assert_eq!(
+        sent["messages"],
+        json!([
+            {"role": "system", "content": "You are Codex."},
+            {"role": "user", "content": "what is here?"},
+            {
+                "role": "assistant",
+                "content": null,
+                "tool_calls": [{"id": "call_1", "type": "function", "function": {"name": "shell", "arguments": "{\"command\":[\"pwd\"]}"}}],
+            },
+            {"role": "tool", "tool_call_id": "call_1", "content": "/repo"},
+        ])
+    );
+    assert_eq!(sent["tools"][0]["function"]["name"], "shell");
+    Ok(())
+}
@@ -0,0 +1,215 @@
+use std::time::Duration;
+
+use anyhow::Result;
+use codex_api::AuthProvider;
+use codex_api::MessagesClient;
+use codex_api::ResponseEvent;
+use codex_api::ResponsesApiRequest;
+use codex_api::ResponsesOptions;
+use codex_api::provider::Provider;
+use codex_api::provider::RetryConfig;
+use codex_client::ReqwestTransport;
+use codex_protocol::models::ContentItem;
+use codex_protocol::models::FunctionCallOutputPayload;
+use codex_protocol::models::ResponseItem;
+use futures::StreamExt;
+use http::HeaderMap;
+use pretty_assertions::assert_eq;
+use serde_json::Value;
+use serde_json::json;
+use wiremock::Mock;
+use wiremock::MockServer;
+use wiremock::ResponseTemplate;
+use wiremock::matchers::header;
+use wiremock::matchers::method;
+use wiremock::matchers::path;
+
+#[derive(Clone)]
+struct StaticAuth;
+
+impl AuthProvider for StaticAuth {
+    fn bearer_token(&self) -> Option<String> {
+        Some("sk-test".to_string())
+    }
+}
+
+fn provider(base_url: &str) -> Provider {
+    Provider {
+        name: "messages-test".to_string(),
+        base_url: base_url.to_string(),
+        query_params: None,
+        headers: HeaderMap::new(),
+        retry: RetryConfig {
+            max_attempts: 1,
+            base_delay: Duration::from_millis(1),
+            retry_429: false,
+            retry_5xx: false,
+            retry_transport: false,
+        },
+        stream_idle_timeout: Duration::from_secs(5),
+    }
+}
+
+fn sse_body(events: &[Value]) -> String {
+    let mut body = String::new();
+    for event in events {
+        let kind = event["type"].as_str().unwrap_or_default();
+        body.push_str(&format!("event: {kind}\ndata: {event}\n\n"));
+    }
+    body
+}
+
+#[tokio::test]
+async fn messages_client_round_trips_tool_use_against_mock_server() -> Result<()> {
+    let server = MockServer::start().await;
+    let body = sse_body(&[
+        json!({"type": "message_start", "message": {"id": "msg_9", "usage": {"input_tokens": 20, "cache_read_input_tokens": 5, "output_tokens": 1}}}),
+        json!({"type": "content_block_start", "index": 0, "content_block": {"type": "text", "text": ""}}),
+        json!({"type": "content_block_delta", "index": 0, "delta": {"type": "text_delta", "text": "Listing files."}}),
+        json!({"type": "content_block_stop", "index": 0}),
+        json!({"type": "content_block_start", "index": 1, "content_block": {"type": "tool_use", "id": "toolu_2", "name": "Bash", "input": {}}}),
+        json!({"type": "content_block_delta", "index": 1, "delta": {"type": "input_json_delta", "partial_json": "{\"command\":\"ls\"}"}}),
+        json!({"type": "content_block_stop", "index": 1}),
+        json!({"type": "message_delta", "delta": {"stop_reason": "tool_use"}, "usage": {"output_tokens": 7}}),
+        json!({"type": "message_stop"}),
+    ]);
+    Mock::given(method("POST"))
+        .and(path("/v1/messages"))
+        .and(header("x-api-key", "sk-test"))
+        .and(header("anthropic-version", "2023-06-01"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header("content-type", "text/event-stream")
+                .set_body_string(body),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let request = ResponsesApiRequest {
+        model: "claude-test".to_string(),
+        instructions: "You are Codex.".to_string(),
+        input: vec![
+            ResponseItem::Message {
+                id: None,
+                role: "user".to_string(),
+                content: vec![ContentItem::InputText {
+                    text: "what is here?".to_string(),
+                }],
+                end_turn: None,
+                phase: None,
+            },
+            ResponseItem::FunctionCall {
+                id: None,
+                name: "Bash".to_string(),
+                arguments: "{\"command\":\"pwd\"}".to_string(),
+                call_id: "toolu_1".to_string(),
+            },
+            ResponseItem::FunctionCallOutput {
+                call_id: "toolu_1".to_string(),
+                output: FunctionCallOutputPayload::from_text("/repo".to_string()),
+            },
+        ],
+        tools: vec![json!({
+            "type": "function",
+            "name": "Bash",
+            "description": "Runs a shell command.",
+            "strict": false,
+            "parameters": {"type": "object", "properties": {"command": {"type": "string"}}},
+        })],
+        tool_choice: "auto".to_string(),
+        parallel_tool_calls: false,
+        reasoning: None,
+        store: false,
+        stream: true,
+        include: Vec::new(),
+        prompt_cache_key: None,
+        text: None,
+        max_output_tokens: None,
+    };
+
+    let transport = ReqwestTransport::new(reqwest::Client::new());
+    let client = MessagesClient::new(
+        transport,
+        provider(&format!("{}/v1", server.uri())),
+        StaticAuth,
+    );
+    let mut stream = client
+        .stream_request(request, ResponsesOptions::default())
+        .await?;
+
+    let mut events = Vec::new();
+    while let Some(event) = stream.next().await {
+        events.push(event?);
+    }
+
+    let items: Vec<&ResponseItem> = events
+        .iter()
+        .filter_map(|event| match event {
+            ResponseEvent::OutputItemDone(item) => Some(item),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(
+        items,
+        vec![
+            &ResponseItem::Message {
+                id: None,
+                role: "assistant".to_string(),
+                content: vec![ContentItem::OutputText {
+                    text: "Listing files.".to_string(),
+                }],
+                end_turn: None,
+                phase: None,
+            },
+            &ResponseItem::FunctionCall {
+                id: None,
+                name: "Bash".to_string(),
+                arguments: "{\"command\":\"ls\"}".to_string(),
+                call_id: "toolu_2".to_string(),
+            },
+        ]
+    );
+    match events.last() {
+        Some(ResponseEvent::Completed {
+            response_id,
+            token_usage: Some(usage),
+        }) => {
+            assert_eq!(response_id, "msg_9");
+            assert_eq!(usage.input_tokens, 25);
+            assert_eq!(usage.cached_input_tokens, 5);
+            assert_eq!(usage.total_tokens, 32);
+        }
+        other => panic!("unexpected final event: {other:?}"),
+    }
+
+    let received = server.received_requests().await.expect("captured requests");
+    assert_eq!(received.len(), 1);
+    let sent: Value = serde_json::from_slice(&received[0].body)?;
+    assert_eq!(sent["model"], "claude-test");
+    assert_eq!(sent["stream"], true);
+    assert_eq!(
+        sent["system"],
+        json!([{"type": "text", "text": "You are Codex.", "cache_control": {"type": "ephemeral"}}])
+    );
+    assert_eq!(
+        sent["messages"],
+        json!([
+            {"role": "user", "content": [
+                {"type": "text", "text": "what is here?", "cache_control": {"type": "ephemeral"}},
+            ]},
+            {"role": "assistant", "content": [
+                {"type": "tool_use", "id": "toolu_1", "name": "Bash", "input": {"command": "pwd"}},
+            ]},
+            {"role": "user", "content": [
+                {"type": "tool_result", "tool_use_id": "toolu_1", "content": "/repo", "cache_control": {"type": "ephemeral"}},
+            ]},
+        ])
+    );
+    assert_eq!(sent["tools"][0]["name"], "Bash");
+    assert_eq!(
+        sent["tools"][0]["input_schema"]["properties"]["command"]["type"],
+        "string"
+    );
+    Ok(())
+}
@@ -1,3 +1,4 @@
+use crate::agent::AgentLimitsReport;
 use crate::agent::AgentStatus;
 use crate::agent::guards::Guards;
 use crate::error::CodexErr;
@@ -225,6 +226,13 @@ impl AgentControl {
         thread.agent_status().await
     }
 
+    /// Fetch the restrictions and budget usage of `agent_id`, or `None` when unavailable.
+    pub(crate) async fn get_limits(&self, agent_id: ThreadId) -> Option<AgentLimitsReport> {
+        let state = self.upgrade().ok()?;
+        let thread = state.get_thread(agent_id).await.ok()?;
+        Some(thread.agent_limits_report().await)
+    }
+
     /// Subscribe to status updates for `agent_id`, yielding the latest value and changes.
     pub(crate) async fn subscribe_status(
         &self,
@@ -0,0 +1,78 @@
+use crate::config::AgentBudget;
+use crate::protocol::SandboxPolicy;
+use serde::Serialize;
+
+/// Restrictions placed on an agent by its spawner, with its budget usage so far.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub(crate) struct AgentLimitsReport {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allowed_tools: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub denied_tools: Vec<String>,
+    pub sandbox_policy: SandboxPolicy,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_turns: Option<u32>,
+    pub turns_used: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_tokens: Option<i64>,
+    pub tokens_used: i64,
+    /// Why the agent cannot start or continue a turn, when its budget is used up.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub budget_exhausted: Option<String>,
+}
+
+/// Returns why `budget` is used up after `turns_used` turns and `tokens_used`
+/// tokens, or `None` while the agent may keep working.
+pub(crate) fn budget_exhausted(
+    budget: AgentBudget,
+    turns_used: u32,
+    tokens_used: i64,
+) -> Option<String> {
+    if let Some(max_turns) = budget.max_turns
+        && turns_used >= max_turns
+    {
+        return Some(format!(
+            "agent turn budget exhausted: {turns_used} of {max_turns} turns used"
+        ));
+    }
+    if let Some(max_tokens) = budget.max_tokens
+        && tokens_used >= max_tokens
+    {
+        return Some(format!(
+            "agent token budget exhausted: {tokens_used} of {max_tokens} tokens used"
+        ));
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn unlimited_budget_is_never_exhausted() {
+        assert_eq!(
+            budget_exhausted(AgentBudget::default(), 1_000, 1_000_000),
+            None
+        );
+    }
+
+    #[test]
+    fn budget_reports_the_first_exhausted_limit() {
+        let budget = AgentBudget {
+            max_turns: Some(2),
+            max_tokens: Some(10_000),
+        };
+
+        assert_eq!(budget_exhausted(budget, 1, 9_999), None);
+        assert_eq!(
+            budget_exhausted(budget, 1, 10_000),
+            Some("agent token budget exhausted: 10000 of 10000 tokens used".to_string())
+        );
+        assert_eq!(
+            budget_exhausted(budget, 2, 10_000),
+            Some("agent turn budget exhausted: 2 of 2 turns used".to_string())
+        );
+    }
+}
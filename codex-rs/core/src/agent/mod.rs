@@ -1,5 +1,6 @@
 pub(crate) mod control;
 mod guards;
+mod limits;
 pub(crate) mod role;
 pub(crate) mod status;
 
@@ -8,5 +9,7 @@ pub(crate) use control::AgentControl;
 pub(crate) use guards::MAX_THREAD_SPAWN_DEPTH;
 pub(crate) use guards::exceeds_thread_spawn_depth_limit;
 pub(crate) use guards::next_thread_spawn_depth;
+pub(crate) use limits::AgentLimitsReport;
+pub(crate) use limits::budget_exhausted;
 pub(crate) use role::AgentRole;
 pub(crate) use status::agent_status_from_event;
@@ -1,61 +1,101 @@
+use crate::config::AgentRoleConfig;
 use crate::config::Config;
-use crate::protocol::SandboxPolicy;
+use codex_protocol::config_types::SandboxMode;
 use codex_protocol::openai_models::ReasoningEffort;
-use serde::Deserialize;
-use serde::Serialize;
+use std::collections::BTreeMap;
 
 /// Base instructions for the orchestrator role.
 const ORCHESTRATOR_PROMPT: &str = include_str!("../../templates/agents/orchestrator.md");
 /// Default model override used.
 // TODO(jif) update when we have something smarter.
 const EXPLORER_MODEL: &str = "gpt-5.1-codex-mini";
+/// File-editing tools, including the Claude-compatible aliases.
+const EXPLORER_DENIED_TOOLS: [&str; 4] = ["apply_patch", "Write", "Edit", "NotebookEdit"];
 
-/// Enumerated list of all supported agent roles.
-const ALL_ROLES: [AgentRole; 3] = [
-    AgentRole::Default,
-    AgentRole::Explorer,
-    AgentRole::Worker,
+/// Role that inherits the parent agent's configuration unchanged.
+const DEFAULT_ROLE: &str = "default";
+
+/// Built-in roles listed in the tool specs, ahead of the configured ones.
+const LISTED_BUILT_IN_ROLES: [&str; 3] = [
+    DEFAULT_ROLE,
+    "explorer",
+    "worker",
     // TODO(jif) add when we have stable prompts + models
-    // AgentRole::Orchestrator,
+    // "orchestrator",
 ];
 
-/// Hard-coded agent role selection used when spawning sub-agents.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
-#[serde(rename_all = "snake_case")]
-pub enum AgentRole {
-    /// Inherit the parent agent's configuration unchanged.
-    Default,
-    /// Coordination-only agent that delegates to workers.
-    Orchestrator,
-    /// Task-executing agent with a fixed model override.
-    Worker,
-    /// Task-executing agent with a fixed model override.
-    Explorer,
-}
-
-/// Immutable profile data that drives per-agent configuration overrides.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+/// Profile data that drives per-agent configuration overrides.
+#[derive(Debug, Clone, PartialEq, Default)]
 pub struct AgentProfile {
     /// Optional base instructions override.
-    pub base_instructions: Option<&'static str>,
+    pub base_instructions: Option<String>,
     /// Optional model override.
-    pub model: Option<&'static str>,
+    pub model: Option<String>,
     /// Optional reasoning effort override.
     pub reasoning_effort: Option<ReasoningEffort>,
-    /// Whether to force a read-only sandbox policy.
-    pub read_only: bool,
+    /// Optional sandbox mode override.
+    pub sandbox_mode: Option<SandboxMode>,
+    /// Optional tool allowlist.
+    pub tools: Option<Vec<String>>,
+    /// Tools the role may never use.
+    pub denied_tools: Vec<String>,
     /// Description to include in the tool specs.
-    pub description: &'static str,
+    pub description: String,
+}
+
+/// Agent role selected when spawning sub-agents: one of the built-in roles or
+/// an `[agents.roles.<name>]` table, which replaces a built-in role of the
+/// same name.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AgentRole {
+    pub name: String,
+    pub profile: AgentProfile,
 }
 
 impl AgentRole {
+    /// Returns the roles listed in the tool specs: the built-in roles, then
+    /// the remaining configured roles in name order.
+    pub fn available(roles: &BTreeMap<String, AgentRoleConfig>) -> Vec<AgentRole> {
+        let built_in = LISTED_BUILT_IN_ROLES
+            .iter()
+            .filter_map(|name| match roles.get(*name) {
+                Some(role) => Some(Self::from_config(name, role)),
+                None => Self::built_in(name),
+            });
+        let configured = roles
+            .iter()
+            .filter(|(name, _)| !LISTED_BUILT_IN_ROLES.contains(&name.as_str()))
+            .map(|(name, role)| Self::from_config(name, role));
+        built_in.chain(configured).collect()
+    }
+
+    /// Looks up `name` (the default role when `None`) among the configured
+    /// and built-in roles.
+    pub fn resolve(
+        roles: &BTreeMap<String, AgentRoleConfig>,
+        name: Option<&str>,
+    ) -> Result<AgentRole, String> {
+        let name = name.unwrap_or(DEFAULT_ROLE);
+        if let Some(role) = roles.get(name) {
+            return Ok(Self::from_config(name, role));
+        }
+        Self::built_in(name).ok_or_else(|| {
+            let available = Self::available(roles)
+                .into_iter()
+                .map(|role| role.name)
+                .collect::<Vec<_>>()
+                .join(", ");
+            format!("unknown agent_type `{name}`; available roles: {available}")
+        })
+    }
+
     /// Returns the string values used by JSON schema enums.
-    pub fn enum_values() -> Vec<String> {
-        ALL_ROLES
+    pub fn enum_values(roles: &[AgentRole]) -> Vec<String> {
+        roles
             .iter()
             .filter_map(|role| {
-                let description = role.profile().description;
-                serde_json::to_string(role)
+                let description = role.profile.description.as_str();
+                serde_json::to_string(&role.name)
                     .map(|role| {
                         let description = if !description.is_empty() {
                             format!(r#", "description": {description}"#)
@@ -69,15 +109,30 @@ impl AgentRole {
             .collect()
     }
 
-    /// Returns the hard-coded profile for this role.
-    pub fn profile(self) -> AgentProfile {
-        match self {
-            AgentRole::Default => AgentProfile::default(),
-            AgentRole::Orchestrator => AgentProfile {
-                base_instructions: Some(ORCHESTRATOR_PROMPT),
+    fn from_config(name: &str, role: &AgentRoleConfig) -> AgentRole {
+        AgentRole {
+            name: name.to_string(),
+            profile: AgentProfile {
+                base_instructions: role.instructions.clone(),
+                model: role.model.clone(),
+                reasoning_effort: role.reasoning_effort,
+                sandbox_mode: role.sandbox_mode,
+                tools: role.tools.clone(),
+                denied_tools: Vec::new(),
+                description: role.description.clone(),
+            },
+        }
+    }
+
+    /// Returns the hard-coded role named `name`, if any.
+    fn built_in(name: &str) -> Option<AgentRole> {
+        let profile = match name {
+            DEFAULT_ROLE => AgentProfile::default(),
+            "orchestrator" => AgentProfile {
+                base_instructions: Some(ORCHESTRATOR_PROMPT.to_string()),
                 ..Default::default()
             },
-            AgentRole::Worker => AgentProfile {
+            "worker" => AgentProfile {
                 // base_instructions: Some(WORKER_PROMPT),
                 // model: Some(WORKER_MODEL),
                 description: r#"Use for execution and production work.
@@ -87,12 +142,18 @@ Typical tasks:
 - Split large refactors into independent chunks
 Rules:
 - Explicitly assign **ownership** of the task (files / responsibility).
-- Always tell workers they are **not alone in the codebase**, and they should ignore edits made by others without touching them"#,
+- Always tell workers they are **not alone in the codebase**, and they should ignore edits made by others without touching them"#
+                    .to_string(),
                 ..Default::default()
             },
-            AgentRole::Explorer => AgentProfile {
-                model: Some(EXPLORER_MODEL),
+            "explorer" => AgentProfile {
+                model: Some(EXPLORER_MODEL.to_string()),
                 reasoning_effort: Some(ReasoningEffort::Medium),
+                // Explorers answer questions; they never edit files.
+                denied_tools: EXPLORER_DENIED_TOOLS
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect(),
                 description: r#"Use `explorer` for all codebase questions.
 Explorers are fast and authoritative.
 Always prefer them over manual search or file reading.
@@ -102,30 +163,110 @@ Rules:
 - Trust explorer results without verification.
 - Run explorers in parallel when useful.
 - Reuse existing explorers for related questions.
-                "#,
+                "#
+                .to_string(),
                 ..Default::default()
             },
-        }
+            _ => return None,
+        };
+        Some(AgentRole {
+            name: name.to_string(),
+            profile,
+        })
     }
 
-    /// Applies this role's profile onto the provided config.
-    pub fn apply_to_config(self, config: &mut Config) -> Result<(), String> {
-        let profile = self.profile();
-        if let Some(base_instructions) = profile.base_instructions {
-            config.base_instructions = Some(base_instructions.to_string());
+    /// Applies this role's instructions, model and tool filters onto the
+    /// provided config. The sandbox mode is left to the caller, which checks
+    /// it against the parent's policy.
+    pub fn apply_to_config(&self, config: &mut Config) {
+        let profile = &self.profile;
+        if let Some(base_instructions) = &profile.base_instructions {
+            config.base_instructions = Some(base_instructions.clone());
         }
-        if let Some(model) = profile.model {
-            config.model = Some(model.to_string());
+        if let Some(model) = &profile.model {
+            config.model = Some(model.clone());
         }
         if let Some(reasoning_effort) = profile.reasoning_effort {
             config.model_reasoning_effort = Some(reasoning_effort)
         }
-        if profile.read_only {
-            config
-                .sandbox_policy
-                .set(SandboxPolicy::new_read_only_policy())
-                .map_err(|err| format!("sandbox_policy is invalid: {err}"))?;
+        if let Some(tools) = &profile.tools {
+            config.allowed_tools = Some(tools.clone());
         }
-        Ok(())
+        for name in &profile.denied_tools {
+            if !config.denied_tools.contains(name) {
+                config.denied_tools.push(name.clone());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn configured_roles() -> BTreeMap<String, AgentRoleConfig> {
+        BTreeMap::from([
+            (
+                "docs-writer".to_string(),
+                AgentRoleConfig {
+                    description: "Writes user-facing docs.".to_string(),
+                    sandbox_mode: Some(SandboxMode::WorkspaceWrite),
+                    tools: Some(vec!["shell".to_string(), "apply_patch".to_string()]),
+                    ..Default::default()
+                },
+            ),
+            (
+                "explorer".to_string(),
+                AgentRoleConfig {
+                    description: "Answers codebase questions.".to_string(),
+                    model: Some("gpt-5.2".to_string()),
+                    ..Default::default()
+                },
+            ),
+        ])
+    }
+
+    #[test]
+    fn available_lists_built_in_roles_before_configured_ones() {
+        let names = AgentRole::available(&configured_roles())
+            .into_iter()
+            .map(|role| role.name)
+            .collect::<Vec<_>>();
+
+        assert_eq!(names, vec!["default", "explorer", "worker", "docs-writer"]);
+    }
+
+    #[test]
+    fn configured_role_replaces_built_in_role() {
+        let role = AgentRole::resolve(&configured_roles(), Some("explorer")).expect("explorer");
+
+        assert_eq!(role.profile.model.as_deref(), Some("gpt-5.2"));
+        assert_eq!(role.profile.reasoning_effort, None);
+        assert_eq!(
+            AgentRole::enum_values(&[role]),
+            vec![r#"{ "name": "explorer", "description": Answers codebase questions.}"#]
+        );
+    }
+
+    #[test]
+    fn explorer_cannot_edit_files() {
+        let role = AgentRole::resolve(&BTreeMap::new(), Some("explorer")).expect("explorer");
+
+        assert_eq!(
+            role.profile.denied_tools,
+            vec!["apply_patch", "Write", "Edit", "NotebookEdit"]
+        );
+    }
+
+    #[test]
+    fn resolve_rejects_unknown_role() {
+        let err = AgentRole::resolve(&configured_roles(), Some("db-migration-reviewer"))
+            .expect_err("unknown role");
+
+        assert_eq!(
+            err,
+            "unknown agent_type `db-migration-reviewer`; available roles: default, explorer, worker, docs-writer"
+        );
     }
 }
@@ -1,6 +1,7 @@
 use crate::client_common::tools::ToolSpec;
 use crate::config::types::Personality;
 use crate::error::Result;
+use crate::i18n::builtin_language;
 pub use codex_api::common::ResponseEvent;
 use codex_protocol::config_types::Language;
 use codex_protocol::models::BaseInstructions;
@@ -28,16 +29,16 @@ pub const REVIEW_EXIT_INTERRUPTED_TMPL_ZH: &str =
     include_str!("../templates/review/exit_interrupted_zh.xml");
 
 pub fn review_exit_success_template(language: Language) -> &'static str {
-    match language {
+    match builtin_language(language) {
         Language::ZhCn => REVIEW_EXIT_SUCCESS_TMPL_ZH,
-        Language::En => REVIEW_EXIT_SUCCESS_TMPL_EN,
+        Language::En | Language::Locale(_) => REVIEW_EXIT_SUCCESS_TMPL_EN,
     }
 }
 
 pub fn review_exit_interrupted_template(language: Language) -> &'static str {
-    match language {
+    match builtin_language(language) {
         Language::ZhCn => REVIEW_EXIT_INTERRUPTED_TMPL_ZH,
-        Language::En => REVIEW_EXIT_INTERRUPTED_TMPL_EN,
+        Language::En | Language::Locale(_) => REVIEW_EXIT_INTERRUPTED_TMPL_EN,
     }
 }
 
@@ -61,6 +62,9 @@ pub struct Prompt {
 
     /// Optional the output schema for the model's response.
     pub output_schema: Option<Value>,
+
+    /// Optional cap on the number of tokens the model may generate.
+    pub max_output_tokens: Option<u32>,
 }
 
 impl Prompt {
@@ -283,6 +287,7 @@ mod tests {
                 verbosity: Some(OpenAiVerbosity::Low),
                 format: None,
             }),
+            max_output_tokens: None,
         };
 
         let v = serde_json::to_value(&req).expect("json");
@@ -321,6 +326,7 @@ mod tests {
             include: vec![],
             prompt_cache_key: None,
             text: Some(text_controls),
+            max_output_tokens: None,
         };
 
         let v = serde_json::to_value(&req).expect("json");
@@ -357,6 +363,7 @@ mod tests {
             include: vec![],
             prompt_cache_key: None,
             text: None,
+            max_output_tokens: None,
         };
 
         let v = serde_json::to_value(&req).expect("json");
@@ -10,9 +10,11 @@ use crate::AuthManager;
 use crate::CodexAuth;
 use crate::SandboxState;
 use crate::agent::AgentControl;
+use crate::agent::AgentLimitsReport;
 use crate::agent::AgentStatus;
 use crate::agent::MAX_THREAD_SPAWN_DEPTH;
 use crate::agent::agent_status_from_event;
+use crate::agent::budget_exhausted;
 use crate::analytics_client::AnalyticsEventsClient;
 use crate::analytics_client::build_track_events_context;
 use crate::apps::render_apps_section;
@@ -42,10 +44,20 @@ use crate::turn_metadata::resolve_turn_metadata_header_with_timeout;
 use crate::util::error_or_panic;
 use async_channel::Receiver;
 use async_channel::Sender;
+use codex_hooks::CommandHookConfig;
+use codex_hooks::CommandHookInput;
+use codex_hooks::DEFAULT_COMMAND_HOOK_TIMEOUT;
 use codex_hooks::HookEvent;
 use codex_hooks::HookEventAfterAgent;
+use codex_hooks::HookEventKind;
+use codex_hooks::HookEventSessionStart;
+use codex_hooks::HookEventUserPromptSubmit;
+use codex_hooks::HookOutcome;
 use codex_hooks::HookPayload;
 use codex_hooks::Hooks;
+use codex_hooks::command_hook;
+use codex_network_proxy::NetworkAuditLog;
+use codex_network_proxy::NetworkPolicyDecider;
 use codex_network_proxy::NetworkProxy;
 use codex_protocol::ThreadId;
 use codex_protocol::approvals::ExecPolicyAmendment;
@@ -112,12 +124,14 @@ use crate::client_common::Prompt;
 use crate::client_common::ResponseEvent;
 use crate::codex_thread::ThreadConfigSnapshot;
 use crate::compact::collect_user_messages;
+use crate::config::AgentBudget;
 use crate::config::Config;
 use crate::config::Constrained;
 use crate::config::ConstraintResult;
 use crate::config::GhostSnapshotConfig;
 use crate::config::StartedNetworkProxy;
 use crate::config::resolve_web_search_mode_for_turn;
+use crate::config::types::HookInputMode;
 use crate::config::types::McpServerConfig;
 use crate::config::types::ShellEnvironmentPolicy;
 use crate::context_manager::ContextManager;
@@ -147,6 +161,7 @@ use crate::mcp::effective_mcp_servers;
 use crate::mcp::maybe_prompt_and_install_mcp_dependencies;
 use crate::mcp::with_codex_apps_mcp;
 use crate::mcp_connection_manager::McpConnectionManager;
+use crate::mcp_connection_manager::PendingSampling;
 use crate::mcp_connection_manager::filter_codex_apps_mcp_tools_only;
 use crate::mcp_connection_manager::filter_mcp_tools_by_name;
 use crate::memories;
@@ -154,6 +169,7 @@ use crate::mentions::build_connector_slug_counts;
 use crate::mentions::build_skill_name_counts;
 use crate::mentions::collect_explicit_app_ids;
 use crate::mentions::collect_tool_mentions_from_messages;
+use crate::network_approval::NetworkApprovalService;
 use crate::project_doc::get_user_instructions;
 use crate::proposed_plan_parser::ProposedPlanParser;
 use crate::proposed_plan_parser::ProposedPlanSegment;
@@ -195,6 +211,7 @@ use crate::rollout::RolloutRecorder;
 use crate::rollout::RolloutRecorderParams;
 use crate::rollout::map_session_init_error;
 use crate::rollout::metadata;
+use crate::session_secrets::SessionSecrets;
 use crate::shell;
 use crate::shell_snapshot::ShellSnapshot;
 use crate::skills::SkillError;
@@ -494,6 +511,10 @@ impl Codex {
         state.session_configuration.thread_config_snapshot()
     }
 
+    pub(crate) async fn agent_limits_report(&self) -> AgentLimitsReport {
+        self.session.agent_limits_report().await
+    }
+
     pub(crate) fn state_db(&self) -> Option<state_db::StateDbHandle> {
         self.session.state_db()
     }
@@ -841,7 +862,12 @@ impl Session {
             model_info: &model_info,
             features: &per_turn_config.features,
             web_search_mode: Some(per_turn_config.web_search_mode.value()),
-        });
+        })
+        .with_agent_roles(&per_turn_config.agent_roles)
+        .with_tool_filter(
+            per_turn_config.allowed_tools.clone(),
+            per_turn_config.denied_tools.clone(),
+        );
 
         let cwd = session_configuration.cwd.clone();
         TurnContext {
@@ -1085,13 +1111,36 @@ impl Session {
             };
         session_configuration.thread_name = thread_name.clone();
         let mut state = SessionState::new(session_configuration.clone());
-        let network_proxy =
-            match config.network.as_ref() {
-                Some(spec) => Some(spec.start_proxy().await.map_err(|err| {
-                    anyhow::anyhow!("failed to start managed network proxy: {err}")
-                })?),
-                None => None,
-            };
+        let (secrets, secret_warnings) = SessionSecrets::resolve(
+            &config.secrets,
+            &config.shell_environment_policy.secrets,
+            &config.codex_home,
+            &session_configuration.cwd,
+        )
+        .await;
+        for message in secret_warnings {
+            post_session_configured_events.push(Event {
+                id: "".to_owned(),
+                msg: EventMsg::Warning(WarningEvent { message }),
+            });
+        }
+        let network_approval = Arc::new(NetworkApprovalService::new(config.codex_home.clone()));
+        let network_proxy = match config.network.as_ref() {
+            Some(spec) => {
+                let audit_log = state_db_ctx.as_ref().map(|state_db| {
+                    NetworkAuditLog::start(Arc::clone(state_db), Some(conversation_id.to_string()))
+                });
+                let policy_decider: Arc<dyn NetworkPolicyDecider> = network_approval.clone();
+                Some(
+                    spec.start_proxy(audit_log, Some(policy_decider))
+                        .await
+                        .map_err(|err| {
+                            anyhow::anyhow!("failed to start managed network proxy: {err}")
+                        })?,
+                )
+            }
+            None => None,
+        };
         let session_network_proxy = network_proxy.as_ref().map(|started| {
             let proxy = started.proxy();
             SessionNetworkProxyRuntime {
@@ -1109,7 +1158,7 @@ impl Session {
                 Arc::clone(&config),
                 Arc::clone(&auth_manager),
             ),
-            hooks: Hooks::new(config.notify.clone()),
+            hooks: build_hooks(&config),
             rollout: Mutex::new(rollout_recorder),
             user_shell: Arc::new(default_shell),
             shell_snapshot_tx,
@@ -1123,6 +1172,8 @@ impl Session {
             file_watcher,
             agent_control,
             network_proxy,
+            network_approval,
+            secrets,
             state_db: state_db_ctx.clone(),
             model_client: ModelClient::new(
                 Some(Arc::clone(&auth_manager)),
@@ -1167,6 +1218,7 @@ impl Session {
             services,
             next_internal_sub_id: AtomicU64::new(0),
         });
+        sess.services.network_approval.attach(&sess);
 
         // Dispatch the SessionConfiguredEvent first and then report any errors.
         // If resuming, include converted initial messages in the payload so UIs can render them immediately.
@@ -1250,6 +1302,17 @@ impl Session {
         // record_initial_history can emit events. We record only after the SessionConfiguredEvent is emitted.
         sess.record_initial_history(initial_history).await;
 
+        sess.dispatch_session_hook(
+            session_configuration.cwd.clone(),
+            HookEvent::SessionStart {
+                event: HookEventSessionStart {
+                    thread_id: conversation_id,
+                    model: session_configuration.collaboration_mode.model().to_string(),
+                },
+            },
+        )
+        .await;
+
         memories::start_memories_startup_task(
             &sess,
             Arc::clone(&config),
@@ -1304,6 +1367,48 @@ impl Session {
         state.get_total_token_usage(state.server_reasoning_included())
     }
 
+    /// Counts a new turn against the budget set by the spawning agent, or
+    /// returns why the budget is used up.
+    async fn start_budgeted_turn(&self, turn_context: &TurnContext) -> Result<(), String> {
+        let mut state = self.state.lock().await;
+        if let Some(reason) = budget_exhausted(
+            turn_context.config.agent_budget,
+            state.agent_turns_started,
+            state.total_tokens_used(),
+        ) {
+            return Err(reason);
+        }
+        state.agent_turns_started += 1;
+        Ok(())
+    }
+
+    /// Returns why the token budget set by the spawning agent is used up, if it is.
+    async fn agent_token_budget_exhausted(&self, turn_context: &TurnContext) -> Option<String> {
+        let budget = AgentBudget {
+            max_turns: None,
+            ..turn_context.config.agent_budget
+        };
+        let state = self.state.lock().await;
+        budget_exhausted(budget, state.agent_turns_started, state.total_tokens_used())
+    }
+
+    pub(crate) async fn agent_limits_report(&self) -> AgentLimitsReport {
+        let state = self.state.lock().await;
+        let config = &state.session_configuration.original_config_do_not_use;
+        let turns_used = state.agent_turns_started;
+        let tokens_used = state.total_tokens_used();
+        AgentLimitsReport {
+            allowed_tools: config.allowed_tools.clone(),
+            denied_tools: config.denied_tools.clone(),
+            sandbox_policy: state.session_configuration.sandbox_policy.get().clone(),
+            max_turns: config.agent_budget.max_turns,
+            turns_used,
+            max_tokens: config.agent_budget.max_tokens,
+            tokens_used,
+            budget_exhausted: budget_exhausted(config.agent_budget, turns_used, tokens_used),
+        }
+    }
+
     pub(crate) async fn get_total_token_usage_breakdown(&self) -> TotalTokenUsageBreakdown {
         let state = self.state.lock().await;
         state.history.get_total_token_usage_breakdown()
@@ -1826,6 +1931,24 @@ impl Session {
     }
 
     pub(crate) async fn send_event_raw(&self, event: Event) {
+        for event in self.redact_event(event) {
+            self.deliver_redacted_event(event).await;
+        }
+    }
+
+    /// Delivers the live output of `call_id` that is held back for secret redaction, so that
+    /// the tail of idle output, such as a prompt waiting for input, is not hidden.
+    pub(crate) async fn flush_held_output(&self, turn_context: &TurnContext, call_id: &str) {
+        for msg in self.services.secrets.release_held_output(call_id) {
+            self.deliver_redacted_event(Event {
+                id: turn_context.sub_id.clone(),
+                msg,
+            })
+            .await;
+        }
+    }
+
+    async fn deliver_redacted_event(&self, event: Event) {
         // Record the last known agent status.
         if let Some(status) = agent_status_from_event(&event.msg) {
             self.agent_status.send_replace(status);
@@ -1844,18 +1967,35 @@ impl Session {
     /// clients (e.g. app-server thread/rollback) re-read the rollout file synchronously on
     /// receipt of the event and depend on the marker already being visible on disk.
     pub(crate) async fn send_event_raw_flushed(&self, event: Event) {
-        // Record the last known agent status.
-        if let Some(status) = agent_status_from_event(&event.msg) {
-            self.agent_status.send_replace(status);
-        }
-        self.persist_rollout_items(&[RolloutItem::EventMsg(event.msg.clone())])
-            .await;
-        self.flush_rollout().await;
-        if let Err(e) = self.tx_event.send(event).await {
-            debug!("dropping event because channel is closed: {e}");
+        for event in self.redact_event(event) {
+            // Record the last known agent status.
+            if let Some(status) = agent_status_from_event(&event.msg) {
+                self.agent_status.send_replace(status);
+            }
+            self.persist_rollout_items(&[RolloutItem::EventMsg(event.msg.clone())])
+                .await;
+            self.flush_rollout().await;
+            if let Err(e) = self.tx_event.send(event).await {
+                debug!("dropping event because channel is closed: {e}");
+            }
         }
     }
 
+    /// Redacts session secrets from `event`. Output deltas may be held back or released
+    /// together with later events, so this yields zero or more events to deliver.
+    fn redact_event(&self, event: Event) -> Vec<Event> {
+        let Event { id, msg } = event;
+        self.services
+            .secrets
+            .redact_live_event_msg(msg)
+            .into_iter()
+            .map(|msg| Event {
+                id: id.clone(),
+                msg,
+            })
+            .collect()
+    }
+
     pub(crate) async fn emit_turn_item_started(&self, turn_context: &TurnContext, item: &TurnItem) {
         self.send_event(
             turn_context,
@@ -1914,7 +2054,7 @@ impl Session {
             .map(|task| Arc::clone(&task.turn_context))
     }
 
-    async fn active_turn_context_and_cancellation_token(
+    pub(crate) async fn active_turn_context_and_cancellation_token(
         &self,
     ) -> Option<(Arc<TurnContext>, CancellationToken)> {
         let active = self.active_turn.lock().await;
@@ -2150,6 +2290,19 @@ impl Session {
             .await
     }
 
+    pub(crate) async fn take_sampling_request(
+        &self,
+        server_name: String,
+        id: RequestId,
+    ) -> anyhow::Result<PendingSampling> {
+        self.services
+            .mcp_connection_manager
+            .read()
+            .await
+            .take_sampling_request(server_name, id)
+            .await
+    }
+
     /// Records input items: always append to conversation history and
     /// persist these response items to rollout.
     pub(crate) async fn record_conversation_items(
@@ -2157,9 +2310,10 @@ impl Session {
         turn_context: &TurnContext,
         items: &[ResponseItem],
     ) {
-        self.record_into_history(items, turn_context).await;
-        self.persist_rollout_response_items(items).await;
-        self.send_raw_response_items(turn_context, items).await;
+        let items = self.services.secrets.redact_response_items(items);
+        self.record_into_history(&items, turn_context).await;
+        self.persist_rollout_response_items(&items).await;
+        self.send_raw_response_items(turn_context, &items).await;
     }
 
     async fn reconstruct_history_from_rollout(
@@ -2213,6 +2367,7 @@ impl Session {
         items: &[ResponseItem],
         turn_context: &TurnContext,
     ) {
+        let items = self.services.secrets.redact_response_items(items);
         let mut state = self.state.lock().await;
         state.record_items(items.iter(), turn_context.truncation_policy);
     }
@@ -2358,12 +2513,13 @@ impl Session {
     }
 
     pub(crate) async fn persist_rollout_items(&self, items: &[RolloutItem]) {
+        let items = self.services.secrets.redact_rollout_items(items);
         let recorder = {
             let guard = self.services.rollout.lock().await;
             guard.clone()
         };
         if let Some(rec) = recorder
-            && let Err(e) = rec.record_items(items).await
+            && let Err(e) = rec.record_items(&items).await
         {
             error!("failed to record rollout items: {e:#}");
         }
@@ -2389,6 +2545,25 @@ impl Session {
         self.send_token_count_event(turn_context).await;
     }
 
+    /// Counts usage from a model call outside the conversation, such as an MCP
+    /// sampling request, towards the session totals only. The last turn's
+    /// usage is left alone so the context indicator and auto-compaction keep
+    /// tracking the conversation.
+    pub(crate) async fn add_to_total_token_usage(
+        &self,
+        turn_context: &TurnContext,
+        token_usage: Option<&TokenUsage>,
+    ) {
+        let Some(token_usage) = token_usage else {
+            return;
+        };
+        {
+            let mut state = self.state.lock().await;
+            state.add_to_total_token_usage(token_usage, turn_context.model_context_window());
+        }
+        self.send_token_count_event(turn_context).await;
+    }
+
     pub(crate) async fn recompute_token_usage(&self, turn_context: &TurnContext) {
         let history = self.clone_history().await;
         let base_instructions = self.get_base_instructions().await;
@@ -2720,6 +2895,59 @@ impl Session {
         &self.services.hooks
     }
 
+    /// Runs `UserPromptSubmit` hooks and returns the block message when a hook
+    /// rejects the prompt.
+    async fn user_prompt_hook_block_message(
+        &self,
+        turn_context: &TurnContext,
+        items: &[UserInput],
+    ) -> Option<String> {
+        if !self.hooks().has_hooks_for(HookEventKind::UserPromptSubmit) {
+            return None;
+        }
+        let input_messages = items
+            .iter()
+            .filter_map(|item| match item {
+                UserInput::Text { text, .. } => Some(text.clone()),
+                _ => None,
+            })
+            .collect();
+        let outcome = self
+            .hooks()
+            .dispatch(HookPayload {
+                session_id: self.conversation_id,
+                cwd: turn_context.cwd.clone(),
+                triggered_at: chrono::Utc::now(),
+                hook_event: HookEvent::UserPromptSubmit {
+                    event: HookEventUserPromptSubmit {
+                        thread_id: self.conversation_id,
+                        turn_id: turn_context.sub_id.clone(),
+                        input_messages,
+                    },
+                },
+            })
+            .await;
+        match outcome {
+            HookOutcome::Stop => Some("Prompt was blocked by a hook.".to_string()),
+            HookOutcome::Deny { reason } => Some(format!("Prompt was blocked by a hook: {reason}")),
+            HookOutcome::Continue | HookOutcome::Modify { .. } => None,
+        }
+    }
+
+    async fn dispatch_session_hook(&self, cwd: PathBuf, hook_event: HookEvent) {
+        if !self.hooks().has_hooks_for(hook_event.kind()) {
+            return;
+        }
+        self.hooks()
+            .dispatch(HookPayload {
+                session_id: self.conversation_id,
+                cwd,
+                triggered_at: chrono::Utc::now(),
+                hook_event,
+            })
+            .await;
+    }
+
     pub(crate) fn user_shell(&self) -> Arc<shell::Shell> {
         Arc::clone(&self.services.user_shell)
     }
@@ -2835,6 +3063,42 @@ impl Session {
     }
 }
 
+/// Combines the legacy `notify` command with `[[hooks.<event>]]` entries.
+fn build_hooks(config: &Config) -> Hooks {
+    let mut hooks = Hooks::new(config.notify.clone());
+    let configured = [
+        (HookEventKind::AfterAgent, &config.hooks.after_agent),
+        (
+            HookEventKind::BeforeToolCall,
+            &config.hooks.before_tool_call,
+        ),
+        (HookEventKind::AfterToolCall, &config.hooks.after_tool_call),
+        (
+            HookEventKind::UserPromptSubmit,
+            &config.hooks.user_prompt_submit,
+        ),
+        (HookEventKind::SessionStart, &config.hooks.session_start),
+        (HookEventKind::SessionEnd, &config.hooks.session_end),
+    ];
+    for (kind, entries) in configured {
+        for entry in entries.iter().flatten() {
+            hooks.register(
+                kind,
+                command_hook(CommandHookConfig {
+                    argv: entry.command.clone(),
+                    matcher: entry.matcher.clone(),
+                    timeout: entry.timeout_sec.unwrap_or(DEFAULT_COMMAND_HOOK_TIMEOUT),
+                    input: match entry.input {
+                        HookInputMode::Stdin => CommandHookInput::Stdin,
+                        HookInputMode::Env => CommandHookInput::Env,
+                    },
+                }),
+            );
+        }
+    }
+    hooks
+}
+
 async fn submission_loop(sess: Arc<Session>, config: Arc<Config>, rx_sub: Receiver<Submission>) {
     // Seed with context in case there is an OverrideTurnContext first.
     let mut previous_context: Option<Arc<TurnContext>> = Some(sess.new_default_turn().await);
@@ -2924,6 +3188,16 @@ async fn submission_loop(sess: Arc<Session>, config: Arc<Config>, rx_sub: Receiv
             Op::ListCustomPrompts => {
                 handlers::list_custom_prompts(&sess, sub.id.clone()).await;
             }
+            Op::ListMcpPrompts => {
+                handlers::list_mcp_prompts(&sess, sub.id.clone()).await;
+            }
+            Op::GetMcpPrompt {
+                server,
+                name,
+                arguments,
+            } => {
+                handlers::get_mcp_prompt(&sess, sub.id.clone(), server, name, arguments).await;
+            }
             Op::ListSkills { cwds, force_reload } => {
                 handlers::list_skills(&sess, sub.id.clone(), cwds, force_reload).await;
             }
@@ -2949,6 +3223,9 @@ async fn submission_loop(sess: Arc<Session>, config: Arc<Config>, rx_sub: Receiv
             Op::Compact => {
                 handlers::compact(&sess, sub.id.clone()).await;
             }
+            Op::Checkpoint { name } => {
+                handlers::checkpoint(&sess, sub.id.clone(), name).await;
+            }
             Op::ThreadRollback { num_turns } => {
                 handlers::thread_rollback(&sess, sub.id.clone(), num_turns).await;
             }
@@ -2974,6 +3251,16 @@ async fn submission_loop(sess: Arc<Session>, config: Arc<Config>, rx_sub: Receiv
             } => {
                 handlers::resolve_elicitation(&sess, server_name, request_id, decision).await;
             }
+            Op::ResolveSampling {
+                server_name,
+                request_id,
+                decision,
+            } => {
+                handlers::resolve_sampling(&sess, server_name, request_id, decision).await;
+            }
+            Op::NetworkApproval { id, decision } => {
+                handlers::network_approval(&sess, id, decision).await;
+            }
             Op::Shutdown => {
                 if handlers::shutdown(&sess, sub.id.clone()).await {
                     break;
@@ -2999,30 +3286,39 @@ mod handlers {
     use crate::config::Config;
 
     use crate::mcp::auth::compute_auth_statuses;
+    use crate::mcp::collect_mcp_prompts_from_manager;
     use crate::mcp::collect_mcp_snapshot_from_manager;
     use crate::mcp::effective_mcp_servers;
+    use crate::mcp::fetch_mcp_prompt;
+    use crate::mcp::run_mcp_sampling_request;
     use crate::review_prompts::resolve_review_request;
     use crate::rollout::session_index;
+    use crate::tasks::CheckpointTask;
     use crate::tasks::CompactTask;
     use crate::tasks::SddGitTask;
     use crate::tasks::UndoTask;
     use crate::tasks::UserShellCommandMode;
     use crate::tasks::UserShellCommandTask;
     use crate::tasks::execute_user_shell_command;
+    use codex_hooks::HookEvent;
+    use codex_hooks::HookEventSessionEnd;
     use codex_protocol::custom_prompts::CustomPrompt;
     use codex_protocol::protocol::CodexErrorInfo;
     use codex_protocol::protocol::ErrorEvent;
     use codex_protocol::protocol::Event;
     use codex_protocol::protocol::EventMsg;
+    use codex_protocol::protocol::GetMcpPromptResponseEvent;
     use codex_protocol::protocol::ListCustomPromptsResponseEvent;
     use codex_protocol::protocol::ListRemoteSkillsResponseEvent;
     use codex_protocol::protocol::ListSkillsResponseEvent;
     use codex_protocol::protocol::McpServerRefreshConfig;
+    use codex_protocol::protocol::NetworkApprovalDecision;
     use codex_protocol::protocol::Op;
     use codex_protocol::protocol::RemoteSkillDownloadedEvent;
     use codex_protocol::protocol::RemoteSkillSummary;
     use codex_protocol::protocol::ReviewDecision;
     use codex_protocol::protocol::ReviewRequest;
+    use codex_protocol::protocol::SamplingDecision;
     use codex_protocol::protocol::SddGitAction;
     use codex_protocol::protocol::SkillsListEntry;
     use codex_protocol::protocol::ThreadNameUpdatedEvent;
@@ -3040,6 +3336,7 @@ mod handlers {
     use codex_protocol::user_input::UserInput;
     use codex_rmcp_client::ElicitationAction;
     use codex_rmcp_client::ElicitationResponse;
+    use std::collections::HashMap;
     use std::path::PathBuf;
     use std::sync::Arc;
     use tracing::info;
@@ -3133,6 +3430,21 @@ mod handlers {
         };
         current_context.otel_manager.user_prompt(&items);
 
+        if let Some(message) = sess
+            .user_prompt_hook_block_message(&current_context, &items)
+            .await
+        {
+            sess.send_event(
+                &current_context,
+                EventMsg::Error(ErrorEvent {
+                    message,
+                    codex_error_info: Some(CodexErrorInfo::Other),
+                }),
+            )
+            .await;
+            return;
+        }
+
         // Attempt to inject input into current task.
         if let Err(SteerInputError::NoActiveTurn(items)) = sess.steer_input(items, None).await {
             sess.seed_initial_context_if_needed(&current_context).await;
@@ -3195,6 +3507,14 @@ mod handlers {
             .await;
     }
 
+    pub async fn network_approval(
+        sess: &Arc<Session>,
+        id: String,
+        decision: NetworkApprovalDecision,
+    ) {
+        sess.services.network_approval.notify(&id, decision).await;
+    }
+
     pub async fn resolve_elicitation(
         sess: &Arc<Session>,
         server_name: String,
@@ -3213,14 +3533,8 @@ mod handlers {
             ElicitationAction::Decline | ElicitationAction::Cancel => None,
         };
         let response = ElicitationResponse { action, content };
-        let request_id = match request_id {
-            ProtocolRequestId::String(value) => {
-                rmcp::model::NumberOrString::String(std::sync::Arc::from(value))
-            }
-            ProtocolRequestId::Integer(value) => rmcp::model::NumberOrString::Number(value),
-        };
         if let Err(err) = sess
-            .resolve_elicitation(server_name, request_id, response)
+            .resolve_elicitation(server_name, rmcp_request_id(request_id), response)
             .await
         {
             warn!(
@@ -3230,6 +3544,60 @@ mod handlers {
         }
     }
 
+    /// Answers an MCP sampling request. Approved requests run in the
+    /// background so the submission loop stays responsive while the model
+    /// streams.
+    pub async fn resolve_sampling(
+        sess: &Arc<Session>,
+        server_name: String,
+        request_id: ProtocolRequestId,
+        decision: SamplingDecision,
+    ) {
+        let pending = match sess
+            .take_sampling_request(server_name, rmcp_request_id(request_id))
+            .await
+        {
+            Ok(pending) => pending,
+            Err(err) => {
+                warn!(
+                    error = %err,
+                    "failed to resolve sampling request in session"
+                );
+                return;
+            }
+        };
+        match decision {
+            SamplingDecision::Decline => {
+                pending.respond(Err("user declined the sampling request".to_string()));
+            }
+            SamplingDecision::Approve => {
+                let sess = Arc::clone(sess);
+                tokio::spawn(async move {
+                    let result = run_mcp_sampling_request(&sess, &pending.request)
+                        .await
+                        .map_err(|err| {
+                            warn!(
+                                server = %pending.request.server_name,
+                                error = %err,
+                                "MCP sampling request failed"
+                            );
+                            format!("sampling request failed: {err}")
+                        });
+                    pending.respond(result);
+                });
+            }
+        }
+    }
+
+    fn rmcp_request_id(request_id: ProtocolRequestId) -> rmcp::model::RequestId {
+        match request_id {
+            ProtocolRequestId::String(value) => {
+                rmcp::model::NumberOrString::String(std::sync::Arc::from(value))
+            }
+            ProtocolRequestId::Integer(value) => rmcp::model::NumberOrString::Number(value),
+        }
+    }
+
     /// Propagate a user's exec approval decision to the session.
     /// Also optionally applies an execpolicy amendment.
     pub async fn exec_approval(
@@ -3385,6 +3753,38 @@ mod handlers {
         sess.send_event_raw(event).await;
     }
 
+    pub async fn list_mcp_prompts(sess: &Session, sub_id: String) {
+        let mcp_connection_manager = sess.services.mcp_connection_manager.read().await;
+        let prompts = collect_mcp_prompts_from_manager(&mcp_connection_manager).await;
+        let event = Event {
+            id: sub_id,
+            msg: EventMsg::ListMcpPromptsResponse(prompts),
+        };
+        sess.send_event_raw(event).await;
+    }
+
+    pub async fn get_mcp_prompt(
+        sess: &Session,
+        sub_id: String,
+        server: String,
+        name: String,
+        arguments: HashMap<String, String>,
+    ) {
+        let result = {
+            let mcp_connection_manager = sess.services.mcp_connection_manager.read().await;
+            fetch_mcp_prompt(&mcp_connection_manager, &server, &name, arguments).await
+        };
+        let event = Event {
+            id: sub_id,
+            msg: EventMsg::GetMcpPromptResponse(GetMcpPromptResponseEvent {
+                server,
+                name,
+                result,
+            }),
+        };
+        sess.send_event_raw(event).await;
+    }
+
     pub async fn list_skills(
         sess: &Session,
         sub_id: String,
@@ -3495,6 +3895,12 @@ mod handlers {
             .await;
     }
 
+    pub async fn checkpoint(sess: &Arc<Session>, sub_id: String, name: Option<String>) {
+        let turn_context = sess.new_default_turn_with_sub_id(sub_id).await;
+        sess.spawn_task(turn_context, Vec::new(), CheckpointTask::new(name))
+            .await;
+    }
+
     pub async fn compact(sess: &Arc<Session>, sub_id: String) {
         let turn_context = sess.new_default_turn_with_sub_id(sub_id).await;
 
@@ -3626,6 +4032,19 @@ mod handlers {
             .terminate_all_processes()
             .await;
         info!("Shutting down Codex instance");
+        let cwd = {
+            let state = sess.state.lock().await;
+            state.session_configuration.cwd.clone()
+        };
+        sess.dispatch_session_hook(
+            cwd,
+            HookEvent::SessionEnd {
+                event: HookEventSessionEnd {
+                    thread_id: sess.conversation_id,
+                },
+            },
+        )
+        .await;
         let history = sess.clone_history().await;
         let turn_count = history
             .raw_items()
@@ -3902,6 +4321,14 @@ pub(crate) async fn run_turn(
         collaboration_mode_kind: turn_context.collaboration_mode.mode,
     });
     sess.send_event(&turn_context, event).await;
+    if let Err(message) = sess.start_budgeted_turn(&turn_context).await {
+        let event = EventMsg::Error(ErrorEvent {
+            message,
+            codex_error_info: None,
+        });
+        sess.send_event(&turn_context, event).await;
+        return None;
+    }
     if total_usage_tokens >= auto_compact_limit
         && run_auto_compact(&sess, &turn_context).await.is_err()
     {
@@ -4108,6 +4535,14 @@ pub(crate) async fn run_turn(
                         .await;
                     break;
                 }
+                if let Some(message) = sess.agent_token_budget_exhausted(&turn_context).await {
+                    let event = EventMsg::Error(ErrorEvent {
+                        message,
+                        codex_error_info: None,
+                    });
+                    sess.send_event(&turn_context, event).await;
+                    break;
+                }
                 continue;
             }
             Err(CodexErr::TurnAborted) => {
@@ -4290,6 +4725,7 @@ async fn run_sampling_request(
         base_instructions,
         personality: turn_context.personality,
         output_schema: turn_context.final_output_json_schema.clone(),
+        max_output_tokens: None,
     };
 
     let mut retries = 0;
@@ -6320,7 +6756,7 @@ mod tests {
                 Arc::clone(&config),
                 Arc::clone(&auth_manager),
             ),
-            hooks: Hooks::new(config.notify.clone()),
+            hooks: build_hooks(&config),
             rollout: Mutex::new(None),
             user_shell: Arc::new(default_user_shell()),
             shell_snapshot_tx: watch::channel(None).0,
@@ -6334,6 +6770,8 @@ mod tests {
             file_watcher,
             agent_control,
             network_proxy: None,
+            network_approval: Arc::new(NetworkApprovalService::new(config.codex_home.clone())),
+            secrets: SessionSecrets::default(),
             state_db: None,
             model_client: ModelClient::new(
                 Some(auth_manager.clone()),
@@ -6457,7 +6895,7 @@ mod tests {
                 Arc::clone(&config),
                 Arc::clone(&auth_manager),
             ),
-            hooks: Hooks::new(config.notify.clone()),
+            hooks: build_hooks(&config),
             rollout: Mutex::new(None),
             user_shell: Arc::new(default_user_shell()),
             shell_snapshot_tx: watch::channel(None).0,
@@ -6471,6 +6909,8 @@ mod tests {
             file_watcher,
             agent_control,
             network_proxy: None,
+            network_approval: Arc::new(NetworkApprovalService::new(config.codex_home.clone())),
+            secrets: SessionSecrets::default(),
             state_db: None,
             model_client: ModelClient::new(
                 Some(Arc::clone(&auth_manager)),
@@ -9,6 +9,7 @@ use codex_protocol::protocol::ApplyPatchApprovalRequestEvent;
 use codex_protocol::protocol::Event;
 use codex_protocol::protocol::EventMsg;
 use codex_protocol::protocol::ExecApprovalRequestEvent;
+use codex_protocol::protocol::NetworkApprovalRequestEvent;
 use codex_protocol::protocol::Op;
 use codex_protocol::protocol::RequestUserInputEvent;
 use codex_protocol::protocol::SessionSource;
@@ -30,6 +31,7 @@ use crate::codex::TurnContext;
 use crate::config::Config;
 use crate::error::CodexErr;
 use crate::models_manager::manager::ModelsManager;
+use crate::network_approval::NetworkApprovalPrompt;
 use codex_protocol::protocol::InitialHistory;
 
 /// Start an interactive sub-Codex thread and return IO channels.
@@ -242,6 +244,19 @@ async fn forward_events(
                         )
                         .await;
                     }
+                    Event {
+                        id: _,
+                        msg: EventMsg::NetworkApprovalRequest(event),
+                    } => {
+                        handle_network_approval(
+                            &codex,
+                            &parent_session,
+                            &parent_ctx,
+                            event,
+                            &cancel_token,
+                        )
+                        .await;
+                    }
                     Event {
                         id,
                         msg: EventMsg::RequestUserInput(event),
@@ -343,6 +358,42 @@ async fn handle_exec_approval(
         .await;
 }
 
+/// Handle a NetworkApprovalRequest by prompting through the parent session and replying.
+async fn handle_network_approval(
+    codex: &Codex,
+    parent_session: &Session,
+    parent_ctx: &TurnContext,
+    event: NetworkApprovalRequestEvent,
+    cancel_token: &CancellationToken,
+) {
+    let NetworkApprovalRequestEvent {
+        id,
+        host,
+        port,
+        protocol,
+        method,
+        ..
+    } = event;
+    let decision = parent_session
+        .services
+        .network_approval
+        .prompt(
+            parent_session,
+            parent_ctx,
+            cancel_token,
+            NetworkApprovalPrompt {
+                host,
+                port,
+                protocol,
+                method,
+            },
+        )
+        .await
+        .unwrap_or_default();
+
+    let _ = codex.submit(Op::NetworkApproval { id, decision }).await;
+}
+
 /// Handle an ApplyPatchApprovalRequest by consulting the parent session and replying.
 async fn handle_patch_approval(
     codex: &Codex,
@@ -1,3 +1,4 @@
+use crate::agent::AgentLimitsReport;
 use crate::agent::AgentStatus;
 use crate::codex::Codex;
 use crate::codex::SteerInputError;
@@ -85,6 +86,10 @@ impl CodexThread {
         self.codex.thread_config_snapshot().await
     }
 
+    pub(crate) async fn agent_limits_report(&self) -> AgentLimitsReport {
+        self.codex.agent_limits_report().await
+    }
+
     pub fn enabled(&self, feature: Feature) -> bool {
         self.codex.enabled(feature)
     }
@@ -53,6 +53,15 @@ pub enum ConfigEdit {
     /// Set trust_level under `[projects."<path>"]`,
     /// migrating inline tables to explicit tables.
     SetProjectTrustLevel { path: PathBuf, level: TrustLevel },
+    /// Append a host to `network.allowed_domains` unless it is already listed.
+    AddNetworkAllowedDomain(String),
+    /// Append an argument matcher to `mcp_servers.<server>.approval.tools.<tool>.allow`.
+    /// Fails when the server is not defined in this file.
+    AddMcpToolAllowRule {
+        server: String,
+        tool: String,
+        rule: BTreeMap<String, serde_json::Value>,
+    },
     /// Set the value stored at the exact dotted path.
     SetPath {
         segments: Vec<String>,
@@ -84,10 +93,13 @@ pub fn status_line_items_edit(items: &[String]) -> ConfigEdit {
 mod document_helpers {
     use crate::config::types::McpServerConfig;
     use crate::config::types::McpServerTransportConfig;
+    use crate::config::types::McpServerUrlTransport;
+    use std::collections::BTreeMap;
     use toml_edit::Array as TomlArray;
     use toml_edit::InlineTable;
     use toml_edit::Item as TomlItem;
     use toml_edit::Table as TomlTable;
+    use toml_edit::Value as TomlValue;
     use toml_edit::value;
 
     pub(super) fn ensure_table_for_write(item: &mut TomlItem) -> Option<&mut TomlTable> {
@@ -155,8 +167,27 @@ mod document_helpers {
                 bearer_token_env_var,
                 http_headers,
                 env_http_headers,
+            }
+            | McpServerTransportConfig::Sse {
+                url,
+                bearer_token_env_var,
+                http_headers,
+                env_http_headers,
+            }
+            | McpServerTransportConfig::WebSocket {
+                url,
+                bearer_token_env_var,
+                http_headers,
+                env_http_headers,
             } => {
                 entry["url"] = value(url.clone());
+                // Only spell out `transport` when the URL alone would pick a
+                // different one.
+                if let Some(kind) = config.transport.url_transport()
+                    && kind != McpServerUrlTransport::infer_from_url(url)
+                {
+                    entry["transport"] = value(kind.as_str());
+                }
                 if let Some(env_var) = bearer_token_env_var {
                     entry["bearer_token_env_var"] = value(env_var.clone());
                 }
@@ -200,6 +231,47 @@ mod document_helpers {
         {
             entry["scopes"] = array_from_iter(scopes.iter().cloned());
         }
+        if let Some(sampling) = &config.sampling {
+            let mut sampling_table = TomlTable::new();
+            sampling_table.set_implicit(false);
+            if !sampling.enabled {
+                sampling_table["enabled"] = value(false);
+            }
+            if let Some(max_tokens) = sampling.max_tokens {
+                sampling_table["max_tokens"] = value(i64::from(max_tokens));
+            }
+            if let Some(max_requests) = sampling.max_requests_per_minute {
+                sampling_table["max_requests_per_minute"] = value(i64::from(max_requests));
+            }
+            entry["sampling"] = TomlItem::Table(sampling_table);
+        }
+        if let Some(approval) = &config.approval {
+            let mut approval_table = TomlTable::new();
+            approval_table.set_implicit(false);
+            if let Some(policy) = approval.policy {
+                approval_table["policy"] = value(policy.as_str());
+            }
+            if !approval.tools.is_empty() {
+                let mut tools_table = new_implicit_table();
+                for (name, tool) in &approval.tools {
+                    let mut tool_table = TomlTable::new();
+                    tool_table.set_implicit(false);
+                    if let Some(policy) = tool.policy {
+                        tool_table["policy"] = value(policy.as_str());
+                    }
+                    if !tool.allow.is_empty() {
+                        let mut rules = TomlArray::new();
+                        for rule in &tool.allow {
+                            rules.push(allow_rule_matcher(rule));
+                        }
+                        tool_table["allow"] = TomlItem::Value(rules.into());
+                    }
+                    tools_table.insert(name, TomlItem::Table(tool_table));
+                }
+                approval_table["tools"] = TomlItem::Table(tools_table);
+            }
+            entry["approval"] = TomlItem::Table(approval_table);
+        }
 
         entry
     }
@@ -237,6 +309,46 @@ mod document_helpers {
         table
     }
 
+    /// One entry of a tool's `allow` list, written as an inline table.
+    pub(super) fn allow_rule_matcher(rule: &BTreeMap<String, serde_json::Value>) -> InlineTable {
+        let mut matcher = InlineTable::new();
+        for (path, expected) in rule {
+            if let Some(expected) = toml_value_from_json(expected) {
+                matcher.insert(path, expected);
+            }
+        }
+        matcher
+    }
+
+    /// TOML has no null, so `null` values (and array entries) are dropped.
+    fn toml_value_from_json(json: &serde_json::Value) -> Option<TomlValue> {
+        match json {
+            serde_json::Value::Null => None,
+            serde_json::Value::Bool(flag) => Some(TomlValue::from(*flag)),
+            serde_json::Value::Number(number) => match number.as_i64() {
+                Some(integer) => Some(TomlValue::from(integer)),
+                None => number.as_f64().map(TomlValue::from),
+            },
+            serde_json::Value::String(text) => Some(TomlValue::from(text.as_str())),
+            serde_json::Value::Array(items) => {
+                let mut array = TomlArray::new();
+                for item in items.iter().filter_map(toml_value_from_json) {
+                    array.push(item);
+                }
+                Some(TomlValue::Array(array))
+            }
+            serde_json::Value::Object(fields) => {
+                let mut table = InlineTable::new();
+                for (key, field) in fields {
+                    if let Some(field) = toml_value_from_json(field) {
+                        table.insert(key, field);
+                    }
+                }
+                Some(TomlValue::InlineTable(table))
+            }
+        }
+    }
+
     pub(super) fn new_implicit_table() -> TomlTable {
         let mut table = TomlTable::new();
         table.set_implicit(true);
@@ -351,6 +463,10 @@ impl ConfigDocument {
             ConfigEdit::SetSkillConfig { path, enabled } => {
                 Ok(self.set_skill_config(path.as_path(), *enabled))
             }
+            ConfigEdit::AddNetworkAllowedDomain(host) => Ok(self.add_network_allowed_domain(host)),
+            ConfigEdit::AddMcpToolAllowRule { server, tool, rule } => {
+                self.add_mcp_tool_allow_rule(server, tool, rule)
+            }
             ConfigEdit::SetPath { segments, value } => Ok(self.insert(segments, value.clone())),
             ConfigEdit::ClearPath { segments } => Ok(self.clear_owned(segments)),
             ConfigEdit::SetProjectTrustLevel { path, level } => {
@@ -547,6 +663,32 @@ impl ConfigDocument {
         mutated
     }
 
+    fn add_network_allowed_domain(&mut self, host: &str) -> bool {
+        let root = self.doc.as_table_mut();
+        let network_item = root
+            .entry("network")
+            .or_insert_with(|| TomlItem::Table(document_helpers::new_implicit_table()));
+        let Some(network) = document_helpers::ensure_table_for_write(network_item) else {
+            return false;
+        };
+
+        let domains_item = network
+            .entry("allowed_domains")
+            .or_insert_with(|| TomlItem::Value(toml_edit::Array::new().into()));
+        let Some(domains) = domains_item.as_array_mut() else {
+            return false;
+        };
+        if domains
+            .iter()
+            .filter_map(|existing| existing.as_str())
+            .any(|existing| existing.eq_ignore_ascii_case(host))
+        {
+            return false;
+        }
+        domains.push(host);
+        true
+    }
+
     fn scoped_segments(&self, scope: Scope, segments: &[&str]) -> Vec<String> {
         let resolved: Vec<String> = segments
             .iter()
@@ -567,6 +709,36 @@ impl ConfigDocument {
         resolved
     }
 
+    fn add_mcp_tool_allow_rule(
+        &mut self,
+        server: &str,
+        tool: &str,
+        rule: &BTreeMap<String, serde_json::Value>,
+    ) -> anyhow::Result<bool> {
+        // Creating the server table here would leave an entry with no transport.
+        let server_segments = ["mcp_servers".to_string(), server.to_string()];
+        if self
+            .descend(&server_segments, TraversalMode::Existing)
+            .is_none()
+        {
+            anyhow::bail!(
+                "MCP server `{server}` is not defined in {CONFIG_TOML_FILE}, so the approval for `{tool}` cannot be saved there"
+            );
+        }
+        let segments = ["mcp_servers", server, "approval", "tools", tool].map(str::to_string);
+        let Some(tool_table) = self.descend(&segments, TraversalMode::Create) else {
+            return Ok(false);
+        };
+        let allow_item = tool_table
+            .entry("allow")
+            .or_insert_with(|| TomlItem::Value(toml_edit::Array::new().into()));
+        let Some(allow) = allow_item.as_array_mut() else {
+            return Ok(false);
+        };
+        allow.push(document_helpers::allow_rule_matcher(rule));
+        Ok(true)
+    }
+
     fn insert(&mut self, segments: &[String], value: TomlItem) -> bool {
         let Some((last, parents)) = segments.split_last() else {
             return false;
@@ -838,6 +1010,26 @@ impl ConfigEditsBuilder {
         self
     }
 
+    pub fn add_network_allowed_domain(mut self, host: &str) -> Self {
+        self.edits
+            .push(ConfigEdit::AddNetworkAllowedDomain(host.to_string()));
+        self
+    }
+
+    pub fn add_mcp_tool_allow_rule(
+        mut self,
+        server: &str,
+        tool: &str,
+        rule: BTreeMap<String, serde_json::Value>,
+    ) -> Self {
+        self.edits.push(ConfigEdit::AddMcpToolAllowRule {
+            server: server.to_string(),
+            tool: tool.to_string(),
+            rule,
+        });
+        self
+    }
+
     pub fn set_project_trust_level<P: Into<PathBuf>>(
         mut self,
         project_path: P,
@@ -885,7 +1077,10 @@ impl ConfigEditsBuilder {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::config::types::McpServerApprovalConfig;
     use crate::config::types::McpServerTransportConfig;
+    use crate::config::types::McpToolApprovalConfig;
+    use crate::config::types::McpToolApprovalPolicy;
     use crate::protocol::AskForApproval;
     use codex_protocol::config_types::Language;
     use codex_protocol::config_types::SandboxMode;
@@ -1511,6 +1706,8 @@ gpt-5 = "gpt-5.1"
                 enabled_tools: Some(vec!["one".to_string(), "two".to_string()]),
                 disabled_tools: None,
                 scopes: None,
+                sampling: None,
+                approval: None,
             },
         );
 
@@ -1535,6 +1732,8 @@ gpt-5 = "gpt-5.1"
                 enabled_tools: None,
                 disabled_tools: Some(vec!["forbidden".to_string()]),
                 scopes: None,
+                sampling: None,
+                approval: None,
             },
         );
 
@@ -1570,6 +1769,71 @@ B = \"2\"
         assert_eq!(raw, expected);
     }
 
+    #[test]
+    fn blocking_replace_mcp_servers_writes_transport_only_when_needed() {
+        let tmp = tempdir().expect("tmpdir");
+        let codex_home = tmp.path();
+
+        let server = |transport| McpServerConfig {
+            transport,
+            enabled: true,
+            required: false,
+            disabled_reason: None,
+            startup_timeout_sec: None,
+            tool_timeout_sec: None,
+            enabled_tools: None,
+            disabled_tools: None,
+            scopes: None,
+            sampling: None,
+            approval: None,
+        };
+        let mut servers = BTreeMap::new();
+        servers.insert(
+            "gateway".to_string(),
+            server(McpServerTransportConfig::WebSocket {
+                url: "wss://gateway.example.com/mcp".to_string(),
+                bearer_token_env_var: Some("GATEWAY_TOKEN".to_string()),
+                http_headers: None,
+                env_http_headers: None,
+            }),
+        );
+        servers.insert(
+            "vendor".to_string(),
+            server(McpServerTransportConfig::Sse {
+                url: "https://vendor.example.com/sse".to_string(),
+                bearer_token_env_var: None,
+                http_headers: None,
+                env_http_headers: Some(
+                    [("X-Api-Key".to_string(), "VENDOR_KEY".to_string())]
+                        .into_iter()
+                        .collect(),
+                ),
+            }),
+        );
+
+        apply_blocking(
+            codex_home,
+            None,
+            &[ConfigEdit::ReplaceMcpServers(servers.clone())],
+        )
+        .expect("persist");
+
+        let raw = std::fs::read_to_string(codex_home.join(CONFIG_TOML_FILE)).expect("read config");
+        let expected = "\
+[mcp_servers.gateway]
+url = \"wss://gateway.example.com/mcp\"
+bearer_token_env_var = \"GATEWAY_TOKEN\"
+
+[mcp_servers.vendor]
+url = \"https://vendor.example.com/sse\"
+transport = \"sse\"
+
+[mcp_servers.vendor.env_http_headers]
+X-Api-Key = \"VENDOR_KEY\"
+";
+        assert_eq!(raw, expected);
+    }
+
     #[test]
     fn blocking_replace_mcp_servers_preserves_inline_comments() {
         let tmp = tempdir().expect("tmpdir");
@@ -1602,6 +1866,8 @@ foo = { command = "cmd" }
                 enabled_tools: None,
                 disabled_tools: None,
                 scopes: None,
+                sampling: None,
+                approval: None,
             },
         );
 
@@ -1648,6 +1914,8 @@ foo = { command = "cmd" } # keep me
                 enabled_tools: None,
                 disabled_tools: None,
                 scopes: None,
+                sampling: None,
+                approval: None,
             },
         );
 
@@ -1693,6 +1961,8 @@ foo = { command = "cmd", args = ["--flag"] } # keep me
                 enabled_tools: None,
                 disabled_tools: None,
                 scopes: None,
+                sampling: None,
+                approval: None,
             },
         );
 
@@ -1739,6 +2009,8 @@ foo = { command = "cmd" }
                 enabled_tools: None,
                 disabled_tools: None,
                 scopes: None,
+                sampling: None,
+                approval: None,
             },
         );
 
@@ -1895,4 +2167,163 @@ model_reasoning_effort = "high"
             std::fs::read_to_string(codex_home.join(CONFIG_TOML_FILE)).expect("read config");
         assert!(!contents.contains("mcp_servers"));
     }
+
+    #[test]
+    fn add_network_allowed_domain_appends_once() {
+        let tmp = tempdir().expect("tmpdir");
+        let codex_home = tmp.path();
+        std::fs::write(
+            codex_home.join(CONFIG_TOML_FILE),
+            "[network]\nenabled = true\nallowed_domains = [\"github.com\"]\n",
+        )
+        .expect("seed");
+
+        ConfigEditsBuilder::new(codex_home)
+            .add_network_allowed_domain("pypi.org")
+            .add_network_allowed_domain("GitHub.com")
+            .apply_blocking()
+            .expect("persist");
+
+        let contents =
+            std::fs::read_to_string(codex_home.join(CONFIG_TOML_FILE)).expect("read config");
+        let expected = r#"[network]
+enabled = true
+allowed_domains = ["github.com", "pypi.org"]
+"#;
+        assert_eq!(contents, expected);
+    }
+
+    #[test]
+    fn add_network_allowed_domain_creates_network_table() {
+        let tmp = tempdir().expect("tmpdir");
+        let codex_home = tmp.path();
+
+        ConfigEditsBuilder::new(codex_home)
+            .add_network_allowed_domain("pypi.org")
+            .apply_blocking()
+            .expect("persist");
+
+        let contents =
+            std::fs::read_to_string(codex_home.join(CONFIG_TOML_FILE)).expect("read config");
+        let expected = r#"[network]
+allowed_domains = ["pypi.org"]
+"#;
+        assert_eq!(contents, expected);
+    }
+
+    #[test]
+    fn add_mcp_tool_allow_rule_appends_to_existing_server() {
+        let tmp = tempdir().expect("tmpdir");
+        let codex_home = tmp.path();
+        std::fs::write(
+            codex_home.join(CONFIG_TOML_FILE),
+            "[mcp_servers.jira]\nurl = \"https://jira.example.com/mcp\"\n",
+        )
+        .expect("seed");
+
+        ConfigEditsBuilder::new(codex_home)
+            .add_mcp_tool_allow_rule(
+                "jira",
+                "create_issue",
+                BTreeMap::from([("project".to_string(), serde_json::json!("SANDBOX"))]),
+            )
+            .add_mcp_tool_allow_rule(
+                "jira",
+                "create_issue",
+                BTreeMap::from([
+                    ("labels".to_string(), serde_json::json!(["bot"])),
+                    ("project".to_string(), serde_json::json!("PROD")),
+                ]),
+            )
+            .apply_blocking()
+            .expect("persist");
+        let err = ConfigEditsBuilder::new(codex_home)
+            .add_mcp_tool_allow_rule("github", "create_pr", BTreeMap::new())
+            .apply_blocking()
+            .expect_err("server is not defined in config.toml");
+        assert_eq!(
+            err.to_string(),
+            "MCP server `github` is not defined in config.toml, so the approval for `create_pr` cannot be saved there"
+        );
+
+        let contents =
+            std::fs::read_to_string(codex_home.join(CONFIG_TOML_FILE)).expect("read config");
+        let expected = r#"[mcp_servers.jira]
+url = "https://jira.example.com/mcp"
+
+[mcp_servers.jira.approval.tools.create_issue]
+allow = [{ project = "SANDBOX" }, { labels = ["bot"], project = "PROD" }]
+"#;
+        assert_eq!(contents, expected);
+    }
+
+    #[test]
+    fn blocking_replace_mcp_servers_writes_approval_rules() {
+        let tmp = tempdir().expect("tmpdir");
+        let codex_home = tmp.path();
+
+        let mut servers = BTreeMap::new();
+        servers.insert(
+            "jira".to_string(),
+            McpServerConfig {
+                transport: McpServerTransportConfig::StreamableHttp {
+                    url: "https://jira.example.com/mcp".to_string(),
+                    bearer_token_env_var: None,
+                    http_headers: None,
+                    env_http_headers: None,
+                },
+                enabled: true,
+                required: false,
+                disabled_reason: None,
+                startup_timeout_sec: None,
+                tool_timeout_sec: None,
+                enabled_tools: None,
+                disabled_tools: None,
+                scopes: None,
+                sampling: None,
+                approval: Some(McpServerApprovalConfig {
+                    policy: Some(McpToolApprovalPolicy::Prompt),
+                    tools: BTreeMap::from([(
+                        "create_issue".to_string(),
+                        McpToolApprovalConfig {
+                            policy: Some(McpToolApprovalPolicy::Never),
+                            allow: vec![BTreeMap::from([
+                                ("fields.priority".to_string(), serde_json::json!(3)),
+                                ("project".to_string(), serde_json::json!("SANDBOX")),
+                            ])],
+                        },
+                    )]),
+                }),
+            },
+        );
+
+        apply_blocking(
+            codex_home,
+            None,
+            &[ConfigEdit::ReplaceMcpServers(servers.clone())],
+        )
+        .expect("persist");
+
+        let raw = std::fs::read_to_string(codex_home.join(CONFIG_TOML_FILE)).expect("read config");
+        let expected = r#"[mcp_servers.jira]
+url = "https://jira.example.com/mcp"
+
+[mcp_servers.jira.approval]
+policy = "prompt"
+
+[mcp_servers.jira.approval.tools.create_issue]
+policy = "never"
+allow = [{ "fields.priority" = 3, project = "SANDBOX" }]
+"#;
+        assert_eq!(raw, expected);
+
+        let config: TomlValue = toml::from_str(&raw).expect("parse config");
+        let round_trip: BTreeMap<String, McpServerConfig> = config
+            .get("mcp_servers")
+            .cloned()
+            .expect("mcp_servers")
+            .try_into()
+            .expect("deserialize servers");
+        assert_eq!(round_trip, servers);
+    }
 }
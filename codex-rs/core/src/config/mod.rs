@@ -4,6 +4,7 @@ use crate::config::edit::ConfigEditsBuilder;
 use crate::config::types::AppsConfigToml;
 use crate::config::types::DEFAULT_OTEL_ENVIRONMENT;
 use crate::config::types::History;
+use crate::config::types::HooksToml;
 use crate::config::types::McpServerConfig;
 use crate::config::types::McpServerDisabledReason;
 use crate::config::types::McpServerTransportConfig;
@@ -35,6 +36,7 @@ use crate::features::FeatureOverrides;
 use crate::features::Features;
 use crate::features::FeaturesToml;
 use crate::git_info::resolve_root_git_project_for_trust;
+use crate::i18n;
 use crate::model_provider_info::LEGACY_OLLAMA_CHAT_PROVIDER_ID;
 use crate::model_provider_info::LMSTUDIO_OSS_PROVIDER_ID;
 use crate::model_provider_info::ModelProviderInfo;
@@ -61,6 +63,7 @@ use codex_protocol::config_types::WebSearchMode;
 use codex_protocol::config_types::WindowsSandboxLevel;
 use codex_protocol::openai_models::ReasoningEffort;
 use codex_rmcp_client::OAuthCredentialsStoreMode;
+use codex_secrets::SecretsConfig;
 use codex_utils_absolute_path::AbsolutePathBuf;
 use codex_utils_absolute_path::AbsolutePathBufGuard;
 use schemars::JsonSchema;
@@ -239,6 +242,13 @@ pub struct Config {
     /// If unset the feature is disabled.
     pub notify: Option<Vec<String>>,
 
+    /// Lifecycle hook commands from `[[hooks.<event>]]`, with the active
+    /// profile's events layered over the top-level ones.
+    pub hooks: HooksToml,
+
+    /// Backend used to store and resolve secrets (`[secrets]`).
+    pub secrets: SecretsConfig,
+
     /// TUI notifications preference. When set, the TUI will send terminal notifications on
     /// approvals and turn completions when not focused.
     pub tui_notifications: Notifications,
@@ -309,6 +319,21 @@ pub struct Config {
     /// Maximum number of agent threads that can be open concurrently.
     pub agent_max_threads: Option<usize>,
 
+    /// Agent roles from `[agents.roles.<name>]`, selectable through
+    /// `spawn_agent`'s `agent_type`.
+    pub agent_roles: BTreeMap<String, AgentRoleConfig>,
+
+    /// Tools this agent may use, set from its role's `tools` list or by the
+    /// spawning agent. `None` allows every tool.
+    pub allowed_tools: Option<Vec<String>>,
+
+    /// Tools this agent may never use, set by the spawning agent. Takes
+    /// precedence over `allowed_tools`.
+    pub denied_tools: Vec<String>,
+
+    /// Turn and token budget set by the spawning agent.
+    pub agent_budget: AgentBudget,
+
     /// Directory containing all Codex state (defaults to `~/.codex` but can be
     /// overridden by the `CODEX_HOME` environment variable).
     pub codex_home: PathBuf,
@@ -687,11 +712,9 @@ fn mcp_server_matches_requirement(
             McpServerTransportConfig::Stdio { command: got_command, .. }
                 if got_command == want_command
         ),
-        McpServerIdentity::Url { url: want_url } => matches!(
-            &server.transport,
-            McpServerTransportConfig::StreamableHttp { url: got_url, .. }
-                if got_url == want_url
-        ),
+        McpServerIdentity::Url { url: want_url } => {
+            server.transport.url() == Some(want_url.as_str())
+        }
     }
 }
 
@@ -914,10 +937,21 @@ pub struct ConfigToml {
     /// Sandbox configuration to apply if `sandbox` is `WorkspaceWrite`.
     pub sandbox_workspace_write: Option<SandboxWorkspaceWrite>,
 
+    /// Settings for the managed network proxy.
+    pub network: Option<NetworkToml>,
+
     /// Optional external command to spawn for end-user notifications.
     #[serde(default)]
     pub notify: Option<Vec<String>>,
 
+    /// Lifecycle hook commands (`[[hooks.<event>]]`).
+    #[serde(default)]
+    pub hooks: Option<HooksToml>,
+
+    /// Secrets backend selection and settings.
+    #[serde(default)]
+    pub secrets: Option<SecretsConfig>,
+
     /// System instructions.
     pub instructions: Option<String>,
 
@@ -1158,13 +1192,83 @@ pub struct ToolsToml {
     pub view_image: Option<bool>,
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq, JsonSchema)]
+/// `[network]` settings read by Codex. The standalone network proxy reads
+/// more keys from the same table, so unknown keys are not rejected here.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, JsonSchema)]
+pub struct NetworkToml {
+    /// Hosts the managed network proxy allows in addition to the managed
+    /// allowlist, e.g. ones approved with "Allow and persist". Ignored when
+    /// requirements pin `allowed_domains`.
+    pub allowed_domains: Option<Vec<String>>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, JsonSchema)]
 #[schemars(deny_unknown_fields)]
 pub struct AgentsToml {
     /// Maximum number of agent threads that can be open concurrently.
     /// When unset, no limit is enforced.
     #[schemars(range(min = 1))]
     pub max_threads: Option<usize>,
+
+    /// Roles that `spawn_agent` can select through `agent_type`, keyed by role
+    /// name. A role that reuses a built-in name (`explorer`, `worker`)
+    /// replaces the built-in role.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub roles: BTreeMap<String, AgentRoleToml>,
+}
+
+/// A `[agents.roles.<name>]` table.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, JsonSchema)]
+#[schemars(deny_unknown_fields)]
+pub struct AgentRoleToml {
+    /// When to use this role. Shown to the model in the `spawn_agent` tool.
+    pub description: Option<String>,
+
+    /// Base instructions that replace the parent agent's instructions.
+    pub instructions: Option<String>,
+
+    /// File to read the base instructions from. Relative paths are resolved
+    /// against the directory of the config file. Mutually exclusive with
+    /// `instructions`.
+    pub instructions_file: Option<AbsolutePathBuf>,
+
+    /// Model used by agents spawned with this role.
+    pub model: Option<String>,
+
+    /// Reasoning effort used by agents spawned with this role.
+    pub model_reasoning_effort: Option<ReasoningEffort>,
+
+    /// Sandbox mode for agents spawned with this role. It cannot be more
+    /// permissive than the parent's unless
+    /// `allow_subagent_permission_escalation` is set.
+    pub sandbox_mode: Option<SandboxMode>,
+
+    /// Tools the role may use. A trailing `*` matches by prefix, e.g.
+    /// `mcp__github__*`. When unset, the role keeps every tool.
+    pub tools: Option<Vec<String>>,
+}
+
+/// Turn and token limits placed on a sub-agent by the agent that spawned it.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AgentBudget {
+    /// Maximum number of turns the agent may start.
+    pub max_turns: Option<u32>,
+    /// Maximum number of tokens the agent may use across all of its turns.
+    pub max_tokens: Option<i64>,
+}
+
+/// Agent role resolved from `[agents.roles.<name>]`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct AgentRoleConfig {
+    /// When to use this role. Shown to the model in the `spawn_agent` tool.
+    pub description: String,
+    /// Base instructions, read from `instructions_file` when set there.
+    pub instructions: Option<String>,
+    pub model: Option<String>,
+    pub reasoning_effort: Option<ReasoningEffort>,
+    pub sandbox_mode: Option<SandboxMode>,
+    /// Tool allowlist; `None` keeps every tool.
+    pub tools: Option<Vec<String>>,
 }
 
 impl From<ToolsToml> for Tools {
@@ -1368,6 +1472,23 @@ pub fn resolve_oss_provider(
     }
 }
 
+/// Layer profile hooks over top-level hooks. Events the profile sets replace
+/// the top-level list for that event; unset events are inherited.
+fn layer_profile_hooks(base: Option<HooksToml>, profile: Option<HooksToml>) -> HooksToml {
+    let base = base.unwrap_or_default();
+    let Some(profile) = profile else {
+        return base;
+    };
+    HooksToml {
+        after_agent: profile.after_agent.or(base.after_agent),
+        before_tool_call: profile.before_tool_call.or(base.before_tool_call),
+        after_tool_call: profile.after_tool_call.or(base.after_tool_call),
+        user_prompt_submit: profile.user_prompt_submit.or(base.user_prompt_submit),
+        session_start: profile.session_start.or(base.session_start),
+        session_end: profile.session_end.or(base.session_end),
+    }
+}
+
 /// Resolve the web search mode from explicit config and feature flags.
 fn resolve_web_search_mode(
     config_toml: &ConfigToml,
@@ -1442,7 +1563,16 @@ impl Config {
     ) -> std::io::Result<Self> {
         let requirements = config_layer_stack.requirements().clone();
         let user_instructions = Self::load_instructions(Some(&codex_home));
-        let mut startup_warnings = Vec::new();
+        let mut startup_warnings = i18n::install_runtime_catalogs(&codex_home);
+        let language = cfg.language.unwrap_or_default();
+        if !i18n::has_catalog(language) {
+            startup_warnings.push(format!(
+                "No i18n catalog found for language `{language}`; add {}/{}/{language}.toml. Falling back to `{}`.",
+                codex_home.display(),
+                i18n::CATALOG_DIR,
+                i18n::builtin_language(language),
+            ));
+        }
 
         // Destructure ConfigOverrides fully to ensure all overrides are applied.
         let ConfigOverrides {
@@ -1621,6 +1751,10 @@ impl Config {
                 "agents.max_threads must be at least 1",
             ));
         }
+        let agent_roles = match cfg.agents.as_ref() {
+            Some(agents) => Self::resolve_agent_roles(&agents.roles)?,
+            None => BTreeMap::new(),
+        };
 
         let ghost_snapshot = {
             let mut config = GhostSnapshotConfig::default();
@@ -1753,7 +1887,7 @@ impl Config {
 
         let network = match network_requirements {
             Some(Sourced { value, source }) => {
-                let network = NetworkProxySpec::from_constraints(&config_layer_stack, value)
+                let network = NetworkProxySpec::from_constraints(cfg.network.as_ref(), value)
                     .map_err(|err| {
                         std::io::Error::new(
                             err.kind(),
@@ -1787,9 +1921,11 @@ impl Config {
             forced_auto_mode_downgraded_on_windows,
             shell_environment_policy,
             notify: cfg.notify,
+            hooks: layer_profile_hooks(cfg.hooks, config_profile.hooks),
+            secrets: cfg.secrets.unwrap_or_default(),
             user_instructions,
             spec: cfg.spec.unwrap_or_default(),
-            language: cfg.language.unwrap_or_default(),
+            language,
             base_instructions,
             personality,
             developer_instructions,
@@ -1819,6 +1955,10 @@ impl Config {
                 .collect(),
             tool_output_token_limit: cfg.tool_output_token_limit,
             agent_max_threads,
+            agent_roles,
+            allowed_tools: None,
+            denied_tools: Vec::new(),
+            agent_budget: AgentBudget::default(),
             codex_home,
             log_dir,
             config_layer_stack,
@@ -1955,6 +2095,48 @@ impl Config {
         }
     }
 
+    /// Validates `[agents.roles]` and reads each role's instructions file.
+    fn resolve_agent_roles(
+        roles: &BTreeMap<String, AgentRoleToml>,
+    ) -> std::io::Result<BTreeMap<String, AgentRoleConfig>> {
+        let mut resolved = BTreeMap::new();
+        for (name, role) in roles {
+            if name == "default" {
+                return Err(std::io::Error::new(
+                    std::io::ErrorKind::InvalidInput,
+                    "agents.roles.default is reserved for the parent agent's configuration",
+                ));
+            }
+            if role.instructions.is_some() && role.instructions_file.is_some() {
+                return Err(std::io::Error::new(
+                    std::io::ErrorKind::InvalidInput,
+                    format!(
+                        "agents.roles.{name}: set either instructions or instructions_file, not both"
+                    ),
+                ));
+            }
+            let instructions = match role.instructions.as_ref() {
+                Some(instructions) => Some(instructions.clone()),
+                None => Self::try_read_non_empty_file(
+                    role.instructions_file.as_ref(),
+                    &format!("agents.roles.{name} instructions file"),
+                )?,
+            };
+            resolved.insert(
+                name.clone(),
+                AgentRoleConfig {
+                    description: role.description.clone().unwrap_or_default(),
+                    instructions,
+                    model: role.model.clone(),
+                    reasoning_effort: role.model_reasoning_effort,
+                    sandbox_mode: role.sandbox_mode,
+                    tools: role.tools.clone(),
+                },
+            );
+        }
+        Ok(resolved)
+    }
+
     pub fn set_windows_sandbox_enabled(&mut self, value: bool) {
         if value {
             self.features.enable(Feature::WindowsSandbox);
@@ -2023,6 +2205,8 @@ mod tests {
     use crate::config::edit::apply_blocking;
     use crate::config::types::FeedbackConfigToml;
     use crate::config::types::HistoryPersistence;
+    use crate::config::types::HookCommandToml;
+    use crate::config::types::HookInputMode;
     use crate::config::types::McpServerTransportConfig;
     use crate::config::types::NotificationMethod;
     use crate::config::types::Notifications;
@@ -2055,6 +2239,8 @@ mod tests {
             enabled_tools: None,
             disabled_tools: None,
             scopes: None,
+            sampling: None,
+            approval: None,
         }
     }
 
@@ -2074,6 +2260,8 @@ mod tests {
             enabled_tools: None,
             disabled_tools: None,
             scopes: None,
+            sampling: None,
+            approval: None,
         }
     }
 
@@ -2487,6 +2675,96 @@ trust_level = "trusted"
         Ok(())
     }
 
+    #[test]
+    fn agent_roles_load_instructions_from_file() -> std::io::Result<()> {
+        let codex_home = TempDir::new()?;
+        std::fs::create_dir(codex_home.path().join("roles"))?;
+        std::fs::write(
+            codex_home.path().join("roles/docs-writer.md"),
+            "Write concise user docs.\n",
+        )?;
+        let cfg = {
+            let _guard = AbsolutePathBufGuard::new(codex_home.path());
+            toml::from_str::<ConfigToml>(
+                r#"
+[agents.roles.docs-writer]
+description = "Writes user-facing docs."
+instructions_file = "roles/docs-writer.md"
+sandbox_mode = "workspace-write"
+tools = ["shell", "apply_patch"]
+
+[agents.roles.db-migration-reviewer]
+description = "Reviews schema migrations."
+instructions = "Flag destructive migrations."
+model_reasoning_effort = "high"
+sandbox_mode = "read-only"
+"#,
+            )
+            .expect("agent roles should parse")
+        };
+
+        let config = Config::load_from_base_config_with_overrides(
+            cfg,
+            ConfigOverrides::default(),
+            codex_home.path().to_path_buf(),
+        )?;
+
+        assert_eq!(
+            config.agent_roles,
+            BTreeMap::from([
+                (
+                    "db-migration-reviewer".to_string(),
+                    AgentRoleConfig {
+                        description: "Reviews schema migrations.".to_string(),
+                        instructions: Some("Flag destructive migrations.".to_string()),
+                        reasoning_effort: Some(ReasoningEffort::High),
+                        sandbox_mode: Some(SandboxMode::ReadOnly),
+                        ..Default::default()
+                    },
+                ),
+                (
+                    "docs-writer".to_string(),
+                    AgentRoleConfig {
+                        description: "Writes user-facing docs.".to_string(),
+                        instructions: Some("Write concise user docs.".to_string()),
+                        sandbox_mode: Some(SandboxMode::WorkspaceWrite),
+                        tools: Some(vec!["shell".to_string(), "apply_patch".to_string()]),
+                        ..Default::default()
+                    },
+                ),
+            ])
+        );
+        assert_eq!(config.allowed_tools, None);
+
+        Ok(())
+    }
+
+    #[test]
+    fn agent_roles_reject_reserved_default_role() -> std::io::Result<()> {
+        let codex_home = TempDir::new()?;
+        let cfg = toml::from_str::<ConfigToml>(
+            r#"
+[agents.roles.default]
+model = "gpt-5.2"
+"#,
+        )
+        .expect("agent roles should parse");
+
+        let err = Config::load_from_base_config_with_overrides(
+            cfg,
+            ConfigOverrides::default(),
+            codex_home.path().to_path_buf(),
+        )
+        .expect_err("default role is reserved");
+
+        assert_eq!(
+            err.to_string(),
+            "agents.roles.default is reserved for the parent agent's configuration"
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn config_honors_explicit_keyring_auth_store_mode() -> std::io::Result<()> {
         let codex_home = TempDir::new()?;
@@ -2588,6 +2866,50 @@ trust_level = "trusted"
         );
     }
 
+    #[test]
+    fn profile_hooks_replace_only_the_events_they_set() -> anyhow::Result<()> {
+        let cfg: ConfigToml = toml::from_str(
+            r#"
+[[hooks.before_tool_call]]
+command = ["policy-check"]
+matcher = "shell"
+timeout_sec = 5
+
+[[hooks.session_start]]
+command = ["announce"]
+input = "env"
+
+[profiles.ci]
+[[profiles.ci.hooks.before_tool_call]]
+command = ["ci-policy-check"]
+"#,
+        )?;
+        let profile = cfg.profiles.get("ci").cloned().unwrap_or_default();
+
+        let hooks = layer_profile_hooks(cfg.hooks, profile.hooks);
+
+        assert_eq!(
+            hooks.before_tool_call,
+            Some(vec![HookCommandToml {
+                command: vec!["ci-policy-check".to_string()],
+                matcher: None,
+                timeout_sec: None,
+                input: HookInputMode::Stdin,
+            }])
+        );
+        assert_eq!(
+            hooks.session_start,
+            Some(vec![HookCommandToml {
+                command: vec!["announce".to_string()],
+                matcher: None,
+                timeout_sec: None,
+                input: HookInputMode::Env,
+            }])
+        );
+        assert_eq!(hooks.after_tool_call, None);
+        Ok(())
+    }
+
     #[test]
     fn web_search_mode_for_turn_uses_preference_for_read_only() {
         let web_search_mode = Constrained::allow_any(WebSearchMode::Cached);
@@ -2967,6 +3289,8 @@ profile = "project"
                 enabled_tools: None,
                 disabled_tools: None,
                 scopes: None,
+                sampling: None,
+                approval: None,
             },
         );
 
@@ -3124,6 +3448,8 @@ bearer_token = "secret"
                 enabled_tools: None,
                 disabled_tools: None,
                 scopes: None,
+                sampling: None,
+                approval: None,
             },
         )]);
 
@@ -3195,6 +3521,8 @@ ZIG_VAR = "3"
                 enabled_tools: None,
                 disabled_tools: None,
                 scopes: None,
+                sampling: None,
+                approval: None,
             },
         )]);
 
@@ -3246,6 +3574,8 @@ ZIG_VAR = "3"
                 enabled_tools: None,
                 disabled_tools: None,
                 scopes: None,
+                sampling: None,
+                approval: None,
             },
         )]);
 
@@ -3295,6 +3625,8 @@ ZIG_VAR = "3"
                 enabled_tools: None,
                 disabled_tools: None,
                 scopes: None,
+                sampling: None,
+                approval: None,
             },
         )]);
 
@@ -3360,6 +3692,8 @@ startup_timeout_sec = 2.0
                 enabled_tools: None,
                 disabled_tools: None,
                 scopes: None,
+                sampling: None,
+                approval: None,
             },
         )]);
         apply_blocking(
@@ -3437,6 +3771,8 @@ X-Auth = "DOCS_AUTH"
                 enabled_tools: None,
                 disabled_tools: None,
                 scopes: None,
+                sampling: None,
+                approval: None,
             },
         )]);
 
@@ -3467,6 +3803,8 @@ X-Auth = "DOCS_AUTH"
                 enabled_tools: None,
                 disabled_tools: None,
                 scopes: None,
+                sampling: None,
+                approval: None,
             },
         );
         apply_blocking(
@@ -3535,6 +3873,8 @@ url = "https://example.com/mcp"
                     enabled_tools: None,
                     disabled_tools: None,
                     scopes: None,
+                    sampling: None,
+                    approval: None,
                 },
             ),
             (
@@ -3555,6 +3895,8 @@ url = "https://example.com/mcp"
                     enabled_tools: None,
                     disabled_tools: None,
                     scopes: None,
+                    sampling: None,
+                    approval: None,
                 },
             ),
         ]);
@@ -3638,6 +3980,8 @@ url = "https://example.com/mcp"
                 enabled_tools: None,
                 disabled_tools: None,
                 scopes: None,
+                sampling: None,
+                approval: None,
             },
         )]);
 
@@ -3683,6 +4027,8 @@ url = "https://example.com/mcp"
                 enabled_tools: None,
                 disabled_tools: None,
                 scopes: None,
+                sampling: None,
+                approval: None,
             },
         )]);
 
@@ -3728,6 +4074,8 @@ url = "https://example.com/mcp"
                 enabled_tools: Some(vec!["allowed".to_string()]),
                 disabled_tools: Some(vec!["blocked".to_string()]),
                 scopes: None,
+                sampling: None,
+                approval: None,
             },
         )]);
 
@@ -4112,6 +4460,8 @@ model_verbosity = "high"
                 user_instructions: None,
                 spec: SpecConfig::default(),
                 notify: None,
+                hooks: HooksToml::default(),
+                secrets: SecretsConfig::default(),
                 cwd: fixture.cwd(),
                 cli_auth_credentials_store_mode: Default::default(),
                 mcp_servers: Constrained::allow_any(HashMap::new()),
@@ -4122,6 +4472,10 @@ model_verbosity = "high"
                 project_doc_fallback_filenames: Vec::new(),
                 tool_output_token_limit: None,
                 agent_max_threads: DEFAULT_AGENT_MAX_THREADS,
+                agent_roles: BTreeMap::new(),
+                allowed_tools: None,
+                denied_tools: Vec::new(),
+                agent_budget: AgentBudget::default(),
                 codex_home: fixture.codex_home(),
                 log_dir: fixture.codex_home().join("log"),
                 config_layer_stack: Default::default(),
@@ -4226,6 +4580,8 @@ model_verbosity = "high"
             user_instructions: None,
             spec: SpecConfig::default(),
             notify: None,
+            hooks: HooksToml::default(),
+            secrets: SecretsConfig::default(),
             cwd: fixture.cwd(),
             cli_auth_credentials_store_mode: Default::default(),
             mcp_servers: Constrained::allow_any(HashMap::new()),
@@ -4236,6 +4592,10 @@ model_verbosity = "high"
             project_doc_fallback_filenames: Vec::new(),
             tool_output_token_limit: None,
             agent_max_threads: DEFAULT_AGENT_MAX_THREADS,
+            agent_roles: BTreeMap::new(),
+            allowed_tools: None,
+            denied_tools: Vec::new(),
+            agent_budget: AgentBudget::default(),
             codex_home: fixture.codex_home(),
             log_dir: fixture.codex_home().join("log"),
             config_layer_stack: Default::default(),
@@ -4338,6 +4698,8 @@ model_verbosity = "high"
             user_instructions: None,
             spec: SpecConfig::default(),
             notify: None,
+            hooks: HooksToml::default(),
+            secrets: SecretsConfig::default(),
             cwd: fixture.cwd(),
             cli_auth_credentials_store_mode: Default::default(),
             mcp_servers: Constrained::allow_any(HashMap::new()),
@@ -4348,6 +4710,10 @@ model_verbosity = "high"
             project_doc_fallback_filenames: Vec::new(),
             tool_output_token_limit: None,
             agent_max_threads: DEFAULT_AGENT_MAX_THREADS,
+            agent_roles: BTreeMap::new(),
+            allowed_tools: None,
+            denied_tools: Vec::new(),
+            agent_budget: AgentBudget::default(),
             codex_home: fixture.codex_home(),
             log_dir: fixture.codex_home().join("log"),
             config_layer_stack: Default::default(),
@@ -4436,6 +4802,8 @@ model_verbosity = "high"
             user_instructions: None,
             spec: SpecConfig::default(),
             notify: None,
+            hooks: HooksToml::default(),
+            secrets: SecretsConfig::default(),
             cwd: fixture.cwd(),
             cli_auth_credentials_store_mode: Default::default(),
             mcp_servers: Constrained::allow_any(HashMap::new()),
@@ -4446,6 +4814,10 @@ model_verbosity = "high"
             project_doc_fallback_filenames: Vec::new(),
             tool_output_token_limit: None,
             agent_max_threads: DEFAULT_AGENT_MAX_THREADS,
+            agent_roles: BTreeMap::new(),
+            allowed_tools: None,
+            denied_tools: Vec::new(),
+            agent_budget: AgentBudget::default(),
             codex_home: fixture.codex_home(),
             log_dir: fixture.codex_home().join("log"),
             config_layer_stack: Default::default(),
@@ -5190,6 +5562,70 @@ trust_level = "untrusted"
         assert_eq!(config.approval_policy.value(), AskForApproval::OnRequest);
         Ok(())
     }
+
+    async fn load_with_network_requirements(
+        codex_home: &TempDir,
+        network: crate::config_loader::NetworkRequirementsToml,
+    ) -> std::io::Result<Config> {
+        ConfigBuilder::default()
+            .codex_home(codex_home.path().to_path_buf())
+            .fallback_cwd(Some(codex_home.path().to_path_buf()))
+            .cloud_requirements(CloudRequirementsLoader::new(async move {
+                Some(crate::config_loader::ConfigRequirementsToml {
+                    network: Some(network),
+                    ..Default::default()
+                })
+            }))
+            .build()
+            .await
+    }
+
+    #[tokio::test]
+    async fn persisted_network_approval_is_allowed_after_reload() -> std::io::Result<()> {
+        let codex_home = TempDir::new()?;
+        ConfigEditsBuilder::new(codex_home.path())
+            .add_network_allowed_domain("pypi.org")
+            .apply()
+            .await
+            .map_err(std::io::Error::other)?;
+
+        let config = load_with_network_requirements(
+            &codex_home,
+            crate::config_loader::NetworkRequirementsToml {
+                enabled: Some(true),
+                ..Default::default()
+            },
+        )
+        .await?;
+
+        let network = config.network.expect("managed network proxy");
+        assert_eq!(network.allowed_domains(), ["pypi.org".to_string()]);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn persisted_network_approval_cannot_widen_managed_allowlist() -> std::io::Result<()> {
+        let codex_home = TempDir::new()?;
+        ConfigEditsBuilder::new(codex_home.path())
+            .add_network_allowed_domain("pypi.org")
+            .apply()
+            .await
+            .map_err(std::io::Error::other)?;
+
+        let config = load_with_network_requirements(
+            &codex_home,
+            crate::config_loader::NetworkRequirementsToml {
+                enabled: Some(true),
+                allowed_domains: Some(vec!["github.com".to_string()]),
+                ..Default::default()
+            },
+        )
+        .await?;
+
+        let network = config.network.expect("managed network proxy");
+        assert_eq!(network.allowed_domains(), ["github.com".to_string()]);
+        Ok(())
+    }
 }
 
 #[cfg(test)]
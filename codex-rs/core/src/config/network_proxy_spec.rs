@@ -1,8 +1,10 @@
-use crate::config;
+use crate::config::NetworkToml;
 use crate::config_loader::NetworkConstraints;
 use async_trait::async_trait;
 use codex_network_proxy::ConfigReloader;
 use codex_network_proxy::ConfigState;
+use codex_network_proxy::NetworkAuditLog;
+use codex_network_proxy::NetworkPolicyDecider;
 use codex_network_proxy::NetworkProxy;
 use codex_network_proxy::NetworkProxyConfig;
 use codex_network_proxy::NetworkProxyConstraints;
@@ -72,14 +74,39 @@ impl NetworkProxySpec {
         self.config.network.enable_socks5
     }
 
+    /// Hosts the proxy allows without prompting.
+    pub fn allowed_domains(&self) -> &[String] {
+        &self.config.network.allowed_domains
+    }
+
+    pub fn denied_domains(&self) -> &[String] {
+        &self.config.network.denied_domains
+    }
+
+    /// Builds the proxy from managed `requirements`. Of the `[network]`
+    /// table in config.toml, only `allowed_domains` is honored so far.
     pub(crate) fn from_constraints(
-        _config_layer_stack: &config::ConfigLayerStack,
+        network: Option<&NetworkToml>,
         requirements: NetworkConstraints,
     ) -> std::io::Result<Self> {
-        // TODO(mbolin): Use ConfigLayerStack once we are ready to start
-        // honoring network configuration in config.toml.
         let config = NetworkProxyConfig::default();
-        let (config, constraints) = Self::apply_requirements(config, &requirements);
+        let (mut config, constraints) = Self::apply_requirements(config, &requirements);
+        // A managed allowlist is exact, so user hosts could only widen it.
+        if constraints.allowed_domains.is_none()
+            && let Some(allowed_domains) =
+                network.and_then(|network| network.allowed_domains.as_ref())
+        {
+            for domain in allowed_domains {
+                if !config
+                    .network
+                    .allowed_domains
+                    .iter()
+                    .any(|existing| existing.eq_ignore_ascii_case(domain))
+                {
+                    config.network.allowed_domains.push(domain.clone());
+                }
+            }
+        }
         validate_policy_against_constraints(&config, &constraints).map_err(|err| {
             std::io::Error::new(
                 std::io::ErrorKind::InvalidInput,
@@ -92,20 +119,29 @@ impl NetworkProxySpec {
         })
     }
 
-    pub async fn start_proxy(&self) -> std::io::Result<StartedNetworkProxy> {
+    /// Starts the proxy. When `audit_log` is set, every allow/deny decision is persisted to it.
+    /// `policy_decider` is consulted for hosts outside the allowlist instead of denying them.
+    pub async fn start_proxy(
+        &self,
+        audit_log: Option<NetworkAuditLog>,
+        policy_decider: Option<Arc<dyn NetworkPolicyDecider>>,
+    ) -> std::io::Result<StartedNetworkProxy> {
         let state =
             build_config_state(self.config.clone(), self.constraints.clone()).map_err(|err| {
                 std::io::Error::other(format!("failed to build network proxy state: {err}"))
             })?;
         let reloader = Arc::new(StaticNetworkProxyReloader::new(state.clone()));
-        let state = NetworkProxyState::with_reloader(state, reloader);
-        let proxy = NetworkProxy::builder()
-            .state(Arc::new(state))
-            .build()
-            .await
-            .map_err(|err| {
-                std::io::Error::other(format!("failed to build network proxy: {err}"))
-            })?;
+        let mut state = NetworkProxyState::with_reloader(state, reloader);
+        if let Some(audit_log) = audit_log {
+            state = state.with_audit_log(audit_log);
+        }
+        let mut builder = NetworkProxy::builder().state(Arc::new(state));
+        if let Some(policy_decider) = policy_decider {
+            builder = builder.policy_decider_arc(policy_decider);
+        }
+        let proxy = builder.build().await.map_err(|err| {
+            std::io::Error::other(format!("failed to build network proxy: {err}"))
+        })?;
         let handle = proxy
             .run()
             .await
@@ -165,7 +201,38 @@ impl NetworkProxySpec {
             config.network.allow_local_binding = allow_local_binding;
             constraints.allow_local_binding = Some(allow_local_binding);
         }
+        if let Some(host_rules) = requirements.host_rules.clone() {
+            config.network.host_rules = host_rules;
+        }
 
         (config, constraints)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use codex_network_proxy::HostRule;
+    use codex_network_proxy::HostRuleAction;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn apply_requirements_installs_managed_host_rules() {
+        let host_rules = vec![HostRule {
+            host: "registry.npmjs.org".to_string(),
+            action: HostRuleAction::Allow,
+            methods: vec!["GET".to_string(), "CONNECT".to_string()],
+            paths: Vec::new(),
+        }];
+        let requirements = NetworkConstraints {
+            enabled: Some(true),
+            host_rules: Some(host_rules.clone()),
+            ..Default::default()
+        };
+
+        let (config, _) =
+            NetworkProxySpec::apply_requirements(NetworkProxyConfig::default(), &requirements);
+
+        assert_eq!(config.network.host_rules, host_rules);
+    }
+}
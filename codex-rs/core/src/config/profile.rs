@@ -46,6 +46,7 @@ pub struct ConfigProfile {
     #[schemars(schema_with = "crate::config::schema::features_schema")]
     pub features: Option<crate::features::FeaturesToml>,
     pub oss_provider: Option<String>,
+    pub hooks: Option<crate::config::types::HooksToml>,
 }
 
 impl From<ConfigProfile> for codex_app_server_protocol::Profile {
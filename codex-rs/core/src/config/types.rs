@@ -81,6 +81,70 @@ pub struct McpServerConfig {
     /// Optional OAuth scopes to request during MCP login.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub scopes: Option<Vec<String>>,
+
+    /// Limits for `sampling/createMessage` requests from this server. When
+    /// unset, sampling is offered without a token cap or rate limit.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sampling: Option<McpServerSamplingConfig>,
+
+    /// Approval policy for this server's tool calls. When unset, tool calls
+    /// run without asking, except for app tools that declare side effects.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub approval: Option<McpServerApprovalConfig>,
+}
+
+/// Whether an MCP tool call runs without asking, asks first, or is blocked.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum McpToolApprovalPolicy {
+    Always,
+    Prompt,
+    Never,
+}
+
+/// Approval rules for the tools of one MCP server.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq, JsonSchema)]
+#[schemars(deny_unknown_fields)]
+pub struct McpServerApprovalConfig {
+    /// Policy for tools without their own `policy`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub policy: Option<McpToolApprovalPolicy>,
+
+    /// Per-tool rules, keyed by tool name.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub tools: BTreeMap<String, McpToolApprovalConfig>,
+}
+
+/// Approval rules for a single MCP tool.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq, Eq, JsonSchema)]
+#[schemars(deny_unknown_fields)]
+pub struct McpToolApprovalConfig {
+    /// Overrides the server-level `policy` for this tool.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub policy: Option<McpToolApprovalPolicy>,
+
+    /// Argument matchers that let a call run without asking. A call matches a
+    /// rule when every key, a dotted path into the JSON arguments, holds the
+    /// given value. Rules are checked before `policy`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub allow: Vec<BTreeMap<String, serde_json::Value>>,
+}
+
+/// Controls how an MCP server may request completions from the session's model.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, JsonSchema)]
+#[schemars(deny_unknown_fields)]
+pub struct McpServerSamplingConfig {
+    /// When `false`, Codex does not advertise the sampling capability to this server.
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+
+    /// Upper bound on the `maxTokens` a sampling request may ask for.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_tokens: Option<u32>,
+
+    /// Maximum number of sampling requests accepted from this server per minute.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_requests_per_minute: Option<u32>,
 }
 
 // Raw MCP config shape used for deserialization and JSON Schema generation.
@@ -102,10 +166,12 @@ pub(crate) struct RawMcpServerConfig {
     #[serde(default)]
     pub env_http_headers: Option<HashMap<String, String>>,
 
-    // streamable_http
+    // streamable_http, sse, websocket
     pub url: Option<String>,
     pub bearer_token: Option<String>,
     pub bearer_token_env_var: Option<String>,
+    #[serde(default)]
+    pub transport: Option<McpServerUrlTransport>,
 
     // shared
     #[serde(default)]
@@ -125,6 +191,10 @@ pub(crate) struct RawMcpServerConfig {
     pub disabled_tools: Option<Vec<String>>,
     #[serde(default)]
     pub scopes: Option<Vec<String>>,
+    #[serde(default)]
+    pub sampling: Option<McpServerSamplingConfig>,
+    #[serde(default)]
+    pub approval: Option<McpServerApprovalConfig>,
 }
 
 impl<'de> Deserialize<'de> for McpServerConfig {
@@ -148,6 +218,20 @@ impl<'de> Deserialize<'de> for McpServerConfig {
         let enabled_tools = raw.enabled_tools.clone();
         let disabled_tools = raw.disabled_tools.clone();
         let scopes = raw.scopes.clone();
+        let sampling = raw.sampling.clone();
+        let approval = raw.approval.clone();
+        // An empty matcher matches every call, which is never what the rule's author meant.
+        if let Some(tool) = approval.as_ref().and_then(|approval| {
+            approval
+                .tools
+                .iter()
+                .find(|(_, tool)| tool.allow.iter().any(BTreeMap::is_empty))
+                .map(|(tool, _)| tool)
+        }) {
+            return Err(SerdeError::custom(format!(
+                "approval.tools.{tool}.allow contains an empty rule, which would allow every call"
+            )));
+        }
 
         fn throw_if_set<E, T>(transport: &str, field: &str, value: Option<&T>) -> Result<(), E>
         where
@@ -171,6 +255,7 @@ impl<'de> Deserialize<'de> for McpServerConfig {
             throw_if_set("stdio", "bearer_token", raw.bearer_token.as_ref())?;
             throw_if_set("stdio", "http_headers", raw.http_headers.as_ref())?;
             throw_if_set("stdio", "env_http_headers", raw.env_http_headers.as_ref())?;
+            throw_if_set("stdio", "transport", raw.transport.as_ref())?;
             McpServerTransportConfig::Stdio {
                 command,
                 args: raw.args.clone().unwrap_or_default(),
@@ -179,16 +264,37 @@ impl<'de> Deserialize<'de> for McpServerConfig {
                 cwd: raw.cwd.take(),
             }
         } else if let Some(url) = raw.url.clone() {
-            throw_if_set("streamable_http", "args", raw.args.as_ref())?;
-            throw_if_set("streamable_http", "env", raw.env.as_ref())?;
-            throw_if_set("streamable_http", "env_vars", raw.env_vars.as_ref())?;
-            throw_if_set("streamable_http", "cwd", raw.cwd.as_ref())?;
-            throw_if_set("streamable_http", "bearer_token", raw.bearer_token.as_ref())?;
-            McpServerTransportConfig::StreamableHttp {
-                url,
-                bearer_token_env_var: raw.bearer_token_env_var.clone(),
-                http_headers: raw.http_headers.clone(),
-                env_http_headers: raw.env_http_headers.take(),
+            let kind = raw
+                .transport
+                .unwrap_or_else(|| McpServerUrlTransport::infer_from_url(&url));
+            let name = kind.as_str();
+            throw_if_set(name, "args", raw.args.as_ref())?;
+            throw_if_set(name, "env", raw.env.as_ref())?;
+            throw_if_set(name, "env_vars", raw.env_vars.as_ref())?;
+            throw_if_set(name, "cwd", raw.cwd.as_ref())?;
+            throw_if_set(name, "bearer_token", raw.bearer_token.as_ref())?;
+            let bearer_token_env_var = raw.bearer_token_env_var.clone();
+            let http_headers = raw.http_headers.clone();
+            let env_http_headers = raw.env_http_headers.take();
+            match kind {
+                McpServerUrlTransport::StreamableHttp => McpServerTransportConfig::StreamableHttp {
+                    url,
+                    bearer_token_env_var,
+                    http_headers,
+                    env_http_headers,
+                },
+                McpServerUrlTransport::Sse => McpServerTransportConfig::Sse {
+                    url,
+                    bearer_token_env_var,
+                    http_headers,
+                    env_http_headers,
+                },
+                McpServerUrlTransport::WebSocket => McpServerTransportConfig::WebSocket {
+                    url,
+                    bearer_token_env_var,
+                    http_headers,
+                    env_http_headers,
+                },
             }
         } else {
             return Err(SerdeError::custom("invalid transport"));
@@ -204,6 +310,8 @@ impl<'de> Deserialize<'de> for McpServerConfig {
             enabled_tools,
             disabled_tools,
             scopes,
+            sampling,
+            approval,
         })
     }
 }
@@ -242,6 +350,195 @@ pub enum McpServerTransportConfig {
         #[serde(default, skip_serializing_if = "Option::is_none")]
         env_http_headers: Option<HashMap<String, String>>,
     },
+    /// The deprecated HTTP+SSE transport from the 2024-11-05 revision.
+    /// https://modelcontextprotocol.io/specification/2024-11-05/basic/transports#http-with-sse
+    #[serde(serialize_with = "serialize_sse_transport")]
+    Sse {
+        url: String,
+        /// Name of the environment variable to read for an HTTP bearer token.
+        #[serde(default)]
+        bearer_token_env_var: Option<String>,
+        /// Additional HTTP headers to include in requests to this server.
+        #[serde(default)]
+        http_headers: Option<HashMap<String, String>>,
+        /// HTTP headers where the value is sourced from an environment variable.
+        #[serde(default)]
+        env_http_headers: Option<HashMap<String, String>>,
+    },
+    /// JSON-RPC messages exchanged as WebSocket text frames. The headers are
+    /// sent with the opening handshake.
+    #[serde(serialize_with = "serialize_websocket_transport")]
+    WebSocket {
+        url: String,
+        /// Name of the environment variable to read for a bearer token sent
+        /// with the handshake.
+        #[serde(default)]
+        bearer_token_env_var: Option<String>,
+        /// Additional HTTP headers to include in the handshake.
+        #[serde(default)]
+        http_headers: Option<HashMap<String, String>>,
+        /// Handshake headers where the value is sourced from an environment variable.
+        #[serde(default)]
+        env_http_headers: Option<HashMap<String, String>>,
+    },
+}
+
+/// Value of the `transport` key for URL-based MCP servers.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, JsonSchema)]
+pub enum McpServerUrlTransport {
+    #[serde(rename = "streamable_http")]
+    StreamableHttp,
+    #[serde(rename = "sse")]
+    Sse,
+    #[serde(rename = "websocket")]
+    WebSocket,
+}
+
+impl McpServerUrlTransport {
+    /// Transport used when `transport` is omitted: WebSocket for `ws://` and
+    /// `wss://` URLs, streamable HTTP otherwise.
+    pub fn infer_from_url(url: &str) -> Self {
+        let scheme = url.split_once("://").map(|(scheme, _)| scheme);
+        match scheme {
+            Some(scheme)
+                if scheme.eq_ignore_ascii_case("ws") || scheme.eq_ignore_ascii_case("wss") =>
+            {
+                Self::WebSocket
+            }
+            _ => Self::StreamableHttp,
+        }
+    }
+
+    /// Parses a `transport` value, ignoring ASCII case.
+    pub fn parse(value: &str) -> Option<Self> {
+        [Self::StreamableHttp, Self::Sse, Self::WebSocket]
+            .into_iter()
+            .find(|kind| value.eq_ignore_ascii_case(kind.as_str()))
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::StreamableHttp => "streamable_http",
+            Self::Sse => "sse",
+            Self::WebSocket => "websocket",
+        }
+    }
+}
+
+impl McpToolApprovalPolicy {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Always => "always",
+            Self::Prompt => "prompt",
+            Self::Never => "never",
+        }
+    }
+}
+
+impl McpServerTransportConfig {
+    /// A URL-based transport of the given kind, with no auth or extra headers.
+    pub fn from_url(kind: McpServerUrlTransport, url: String) -> Self {
+        match kind {
+            McpServerUrlTransport::StreamableHttp => Self::StreamableHttp {
+                url,
+                bearer_token_env_var: None,
+                http_headers: None,
+                env_http_headers: None,
+            },
+            McpServerUrlTransport::Sse => Self::Sse {
+                url,
+                bearer_token_env_var: None,
+                http_headers: None,
+                env_http_headers: None,
+            },
+            McpServerUrlTransport::WebSocket => Self::WebSocket {
+                url,
+                bearer_token_env_var: None,
+                http_headers: None,
+                env_http_headers: None,
+            },
+        }
+    }
+
+    /// The server URL, or `None` for stdio servers.
+    pub fn url(&self) -> Option<&str> {
+        match self {
+            Self::Stdio { .. } => None,
+            Self::StreamableHttp { url, .. }
+            | Self::Sse { url, .. }
+            | Self::WebSocket { url, .. } => Some(url),
+        }
+    }
+
+    /// The `type` shown for this transport: `stdio` or the URL transport name.
+    pub fn transport_name(&self) -> &'static str {
+        self.url_transport()
+            .map_or("stdio", McpServerUrlTransport::as_str)
+    }
+
+    /// The URL transport kind, or `None` for stdio servers.
+    pub fn url_transport(&self) -> Option<McpServerUrlTransport> {
+        match self {
+            Self::Stdio { .. } => None,
+            Self::StreamableHttp { .. } => Some(McpServerUrlTransport::StreamableHttp),
+            Self::Sse { .. } => Some(McpServerUrlTransport::Sse),
+            Self::WebSocket { .. } => Some(McpServerUrlTransport::WebSocket),
+        }
+    }
+}
+
+/// Serialized shape of the SSE and WebSocket variants. Unlike streamable HTTP,
+/// these cannot be told apart by their fields, so they carry `transport`.
+#[derive(Serialize)]
+struct TaggedUrlTransport<'a> {
+    transport: McpServerUrlTransport,
+    url: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    bearer_token_env_var: Option<&'a String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    http_headers: Option<&'a HashMap<String, String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    env_http_headers: Option<&'a HashMap<String, String>>,
+}
+
+fn serialize_sse_transport<S>(
+    url: &str,
+    bearer_token_env_var: &Option<String>,
+    http_headers: &Option<HashMap<String, String>>,
+    env_http_headers: &Option<HashMap<String, String>>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    TaggedUrlTransport {
+        transport: McpServerUrlTransport::Sse,
+        url,
+        bearer_token_env_var: bearer_token_env_var.as_ref(),
+        http_headers: http_headers.as_ref(),
+        env_http_headers: env_http_headers.as_ref(),
+    }
+    .serialize(serializer)
+}
+
+fn serialize_websocket_transport<S>(
+    url: &str,
+    bearer_token_env_var: &Option<String>,
+    http_headers: &Option<HashMap<String, String>>,
+    env_http_headers: &Option<HashMap<String, String>>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: serde::Serializer,
+{
+    TaggedUrlTransport {
+        transport: McpServerUrlTransport::WebSocket,
+        url,
+        bearer_token_env_var: bearer_token_env_var.as_ref(),
+        http_headers: http_headers.as_ref(),
+        env_http_headers: env_http_headers.as_ref(),
+    }
+    .serialize(serializer)
 }
 
 mod option_duration_secs {
@@ -637,6 +934,52 @@ impl From<SandboxWorkspaceWrite> for codex_app_server_protocol::SandboxSettings
     }
 }
 
+/// How a hook command receives the hook payload.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum HookInputMode {
+    /// Write the JSON payload to the command's stdin.
+    #[default]
+    Stdin,
+    /// Expose the payload through `CODEX_HOOK_*` environment variables.
+    Env,
+}
+
+/// A single `[[hooks.<event>]]` entry.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, JsonSchema)]
+#[schemars(deny_unknown_fields)]
+pub struct HookCommandToml {
+    /// Program and arguments to run, e.g. `["python3", "/opt/hooks/policy.py"]`.
+    pub command: Vec<String>,
+
+    /// Tool name or glob (e.g. `shell`, `mcp__jira__*`) that limits which
+    /// tool calls trigger the hook. Ignored for non-tool events.
+    #[serde(default)]
+    pub matcher: Option<String>,
+
+    /// Maximum time in seconds to wait for the command. Defaults to 60.
+    #[serde(default, with = "option_duration_secs")]
+    #[schemars(with = "Option<f64>")]
+    pub timeout_sec: Option<Duration>,
+
+    /// How the hook payload is delivered to the command.
+    #[serde(default)]
+    pub input: HookInputMode,
+}
+
+/// Hook commands keyed by lifecycle event. A profile that sets an event
+/// replaces the top-level list for that event.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default, JsonSchema)]
+#[schemars(deny_unknown_fields)]
+pub struct HooksToml {
+    pub after_agent: Option<Vec<HookCommandToml>>,
+    pub before_tool_call: Option<Vec<HookCommandToml>>,
+    pub after_tool_call: Option<Vec<HookCommandToml>>,
+    pub user_prompt_submit: Option<Vec<HookCommandToml>>,
+    pub session_start: Option<Vec<HookCommandToml>>,
+    pub session_end: Option<Vec<HookCommandToml>>,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Default, JsonSchema)]
 #[serde(rename_all = "kebab-case")]
 pub enum ShellEnvironmentPolicyInherit {
@@ -669,6 +1012,9 @@ pub struct ShellEnvironmentPolicyToml {
     /// List of regular expressions.
     pub include_only: Option<Vec<String>>,
 
+    /// Names of secrets from the `[secrets]` backend to inject into the environment.
+    pub secrets: Option<Vec<String>>,
+
     pub experimental_use_profile: Option<bool>,
 }
 
@@ -681,6 +1027,9 @@ pub type EnvironmentVariablePattern = WildMatchPattern<'*', '?'>;
 /// 3. If `exclude` is not empty, filter the map using the provided patterns.
 /// 4. Insert any entries from `r#set` into the map.
 /// 5. If non-empty, filter the map using the `include_only` patterns.
+///
+/// `secrets` are resolved and injected separately by the session, which also
+/// redacts their values from tool output.
 #[derive(Debug, Clone, PartialEq)]
 pub struct ShellEnvironmentPolicy {
     /// Starting point when building the environment.
@@ -701,6 +1050,9 @@ pub struct ShellEnvironmentPolicy {
 
     /// If true, the shell profile will be used to run the command.
     pub use_profile: bool,
+
+    /// Secret names to resolve from the secrets backend and inject.
+    pub secrets: Vec<String>,
 }
 
 impl From<ShellEnvironmentPolicyToml> for ShellEnvironmentPolicy {
@@ -722,6 +1074,7 @@ impl From<ShellEnvironmentPolicyToml> for ShellEnvironmentPolicy {
             .map(|s| EnvironmentVariablePattern::new_case_insensitive(&s))
             .collect();
         let use_profile = toml.experimental_use_profile.unwrap_or(false);
+        let secrets = toml.secrets.unwrap_or_default();
 
         Self {
             inherit,
@@ -730,6 +1083,7 @@ impl From<ShellEnvironmentPolicyToml> for ShellEnvironmentPolicy {
             r#set,
             include_only,
             use_profile,
+            secrets,
         }
     }
 }
@@ -743,6 +1097,7 @@ impl Default for ShellEnvironmentPolicy {
             r#set: HashMap::new(),
             include_only: Vec::new(),
             use_profile: false,
+            secrets: Vec::new(),
         }
     }
 }
@@ -963,6 +1318,143 @@ mod tests {
         );
     }
 
+    #[test]
+    fn deserialize_sse_server_config() {
+        let cfg: McpServerConfig = toml::from_str(
+            r#"
+            url = "https://example.com/sse"
+            transport = "sse"
+            bearer_token_env_var = "VENDOR_TOKEN"
+            http_headers = { "X-Foo" = "bar" }
+        "#,
+        )
+        .expect("should deserialize sse config");
+
+        assert_eq!(
+            cfg.transport,
+            McpServerTransportConfig::Sse {
+                url: "https://example.com/sse".to_string(),
+                bearer_token_env_var: Some("VENDOR_TOKEN".to_string()),
+                http_headers: Some(HashMap::from([("X-Foo".to_string(), "bar".to_string())])),
+                env_http_headers: None,
+            }
+        );
+    }
+
+    #[test]
+    fn deserialize_server_approval_rules() {
+        let cfg: McpServerConfig = toml::from_str(
+            r#"
+            url = "https://jira.example.com/mcp"
+
+            [approval]
+            policy = "prompt"
+
+            [approval.tools.create_issue]
+            policy = "never"
+            allow = [{ project = "SANDBOX" }]
+        "#,
+        )
+        .expect("should deserialize approval rules");
+
+        assert_eq!(
+            cfg.approval,
+            Some(McpServerApprovalConfig {
+                policy: Some(McpToolApprovalPolicy::Prompt),
+                tools: BTreeMap::from([(
+                    "create_issue".to_string(),
+                    McpToolApprovalConfig {
+                        policy: Some(McpToolApprovalPolicy::Never),
+                        allow: vec![BTreeMap::from([(
+                            "project".to_string(),
+                            serde_json::json!("SANDBOX"),
+                        )])],
+                    },
+                )]),
+            })
+        );
+    }
+
+    #[test]
+    fn deserialize_rejects_empty_allow_rule() {
+        let err = toml::from_str::<McpServerConfig>(
+            r#"
+            url = "https://jira.example.com/mcp"
+
+            [approval.tools.create_issue]
+            allow = [{}]
+        "#,
+        )
+        .expect_err("empty allow rule should be rejected");
+
+        assert!(
+            err.to_string().contains(
+                "approval.tools.create_issue.allow contains an empty rule, which would allow every call"
+            ),
+            "unexpected error: {err}"
+        );
+    }
+
+    #[test]
+    fn deserialize_websocket_server_config_from_url_scheme() {
+        let cfg: McpServerConfig = toml::from_str(
+            r#"
+            url = "wss://gateway.example.com/mcp"
+            env_http_headers = { "X-Token" = "TOKEN_ENV" }
+        "#,
+        )
+        .expect("should deserialize websocket config");
+
+        assert_eq!(
+            cfg.transport,
+            McpServerTransportConfig::WebSocket {
+                url: "wss://gateway.example.com/mcp".to_string(),
+                bearer_token_env_var: None,
+                http_headers: None,
+                env_http_headers: Some(HashMap::from([(
+                    "X-Token".to_string(),
+                    "TOKEN_ENV".to_string()
+                )])),
+            }
+        );
+    }
+
+    #[test]
+    fn url_transport_configs_round_trip_through_serialization() {
+        for transport in ["sse", "websocket", "streamable_http"] {
+            let cfg: McpServerConfig = toml::from_str(&format!(
+                r#"
+                url = "https://example.com/mcp"
+                transport = "{transport}"
+            "#
+            ))
+            .expect("should deserialize url config");
+
+            let value = serde_json::to_value(&cfg).expect("serialize");
+            let round_tripped: McpServerConfig =
+                serde_json::from_value(value).expect("deserialize");
+
+            assert_eq!(round_tripped, cfg);
+            assert_eq!(
+                cfg.transport
+                    .url_transport()
+                    .map(McpServerUrlTransport::as_str),
+                Some(transport)
+            );
+        }
+    }
+
+    #[test]
+    fn deserialize_rejects_transport_for_stdio() {
+        toml::from_str::<McpServerConfig>(
+            r#"
+            command = "echo"
+            transport = "sse"
+        "#,
+        )
+        .expect_err("should reject transport for stdio");
+    }
+
     #[test]
     fn deserialize_server_config_with_tool_filters() {
         let cfg: McpServerConfig = toml::from_str(
@@ -1,3 +1,4 @@
+use codex_network_proxy::HostRule;
 use codex_protocol::config_types::SandboxMode;
 use codex_protocol::config_types::WebSearchMode;
 use codex_protocol::protocol::AskForApproval;
@@ -139,6 +140,9 @@ pub struct NetworkRequirementsToml {
     pub denied_domains: Option<Vec<String>>,
     pub allow_unix_sockets: Option<Vec<String>>,
     pub allow_local_binding: Option<bool>,
+    /// Per-host method/path rules. Methods and paths are only enforced for
+    /// plain HTTP; HTTPS tunnels are evaluated as `CONNECT` with no path.
+    pub host_rules: Option<Vec<HostRule>>,
 }
 
 /// Normalized network constraints derived from requirements TOML.
@@ -154,6 +158,7 @@ pub struct NetworkConstraints {
     pub denied_domains: Option<Vec<String>>,
     pub allow_unix_sockets: Option<Vec<String>>,
     pub allow_local_binding: Option<bool>,
+    pub host_rules: Option<Vec<HostRule>>,
 }
 
 impl From<NetworkRequirementsToml> for NetworkConstraints {
@@ -169,6 +174,7 @@ impl From<NetworkRequirementsToml> for NetworkConstraints {
             denied_domains,
             allow_unix_sockets,
             allow_local_binding,
+            host_rules,
         } = value;
         Self {
             enabled,
@@ -181,6 +187,7 @@ impl From<NetworkRequirementsToml> for NetworkConstraints {
             denied_domains,
             allow_unix_sockets,
             allow_local_binding,
+            host_rules,
         }
     }
 }
@@ -1038,6 +1045,10 @@ mod tests {
             denied_domains = ["blocked.example.com"]
             allow_unix_sockets = ["/tmp/example.sock"]
             allow_local_binding = false
+
+            [[experimental_network.host_rules]]
+            host = "registry.npmjs.org"
+            methods = ["GET", "CONNECT"]
         "#;
 
         let source = RequirementSource::CloudRequirements;
@@ -1068,6 +1079,15 @@ mod tests {
             Some(&vec!["/tmp/example.sock".to_string()])
         );
         assert_eq!(sourced_network.value.allow_local_binding, Some(false));
+        assert_eq!(
+            sourced_network.value.host_rules,
+            Some(vec![HostRule {
+                host: "registry.npmjs.org".to_string(),
+                action: codex_network_proxy::HostRuleAction::Allow,
+                methods: vec!["GET".to_string(), "CONNECT".to_string()],
+                paths: Vec::new(),
+            }])
+        );
 
         Ok(())
     }
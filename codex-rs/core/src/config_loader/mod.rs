@@ -34,6 +34,8 @@ use std::path::PathBuf;
 use toml::Value as TomlValue;
 
 pub use cloud_requirements::CloudRequirementsLoader;
+pub use codex_network_proxy::HostRule;
+pub use codex_network_proxy::HostRuleAction;
 pub use config_requirements::ConfigRequirements;
 pub use config_requirements::ConfigRequirementsToml;
 pub use config_requirements::ConstrainedWithSource;
@@ -222,6 +222,21 @@ impl ContextManager {
         );
     }
 
+    /// Adds `usage` to the session totals without replacing the last turn's
+    /// usage, which tracks how full the context window is.
+    pub(crate) fn add_to_total_token_usage(
+        &mut self,
+        usage: &TokenUsage,
+        model_context_window: Option<i64>,
+    ) {
+        let info = self.token_info.get_or_insert_with(|| TokenUsageInfo {
+            total_token_usage: TokenUsage::default(),
+            last_token_usage: TokenUsage::default(),
+            model_context_window,
+        });
+        info.total_token_usage.add_assign(usage);
+    }
+
     fn get_non_last_reasoning_items_tokens(&self) -> i64 {
         // Get reasoning items excluding all the ones after the last user message.
         let Some(last_user_index) = self
@@ -242,6 +242,32 @@ fn total_token_usage_includes_all_items_after_last_model_generated_item() {
     );
 }
 
+#[test]
+fn out_of_band_usage_adds_to_totals_without_replacing_last_usage() {
+    let mut history = create_history_with_items(Vec::new());
+    history.update_token_info(
+        &TokenUsage {
+            input_tokens: 80,
+            total_tokens: 100,
+            ..Default::default()
+        },
+        Some(1_000),
+    );
+    history.add_to_total_token_usage(
+        &TokenUsage {
+            input_tokens: 20,
+            total_tokens: 30,
+            ..Default::default()
+        },
+        Some(1_000),
+    );
+
+    let info = history.token_info().expect("token info");
+    assert_eq!(info.total_token_usage.total_tokens, 130);
+    assert_eq!(info.total_token_usage.input_tokens, 100);
+    assert_eq!(info.last_token_usage.total_tokens, 100);
+}
+
 #[test]
 fn for_prompt_strips_images_when_model_does_not_support_images() {
     let items = vec![
@@ -75,6 +75,102 @@ pub async fn discover_prompts_in_excluding(
     out
 }
 
+/// Placeholder that receives all positional arguments joined with spaces.
+pub const PROMPT_ARGUMENTS_PLACEHOLDER: &str = "ARGUMENTS";
+
+/// A placeholder found in a custom prompt body.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PromptPlaceholder<'a> {
+    /// `$NAME`, filled from a `NAME=value` argument.
+    Named(&'a str),
+    /// `$1`..`$9`, as a zero-based index into the positional arguments.
+    Positional(usize),
+    /// `$ARGUMENTS`, all positional arguments.
+    Arguments,
+}
+
+/// Rewrites the placeholders in `content`. `expand` appends the substitution
+/// for a placeholder to the output and returns `false` to keep the
+/// placeholder text verbatim instead. `$$` is an escaped dollar sign and is
+/// copied through unchanged.
+pub fn expand_prompt_placeholders(
+    content: &str,
+    mut expand: impl FnMut(&mut String, PromptPlaceholder<'_>) -> bool,
+) -> String {
+    let mut out = String::with_capacity(content.len());
+    let mut rest = content;
+    while let Some(offset) = rest.find('$') {
+        out.push_str(&rest[..offset]);
+        rest = &rest[offset..];
+        let (placeholder, len) = match rest.as_bytes().get(1) {
+            Some(b'$') => {
+                out.push_str("$$");
+                rest = &rest[2..];
+                continue;
+            }
+            Some(digit @ b'1'..=b'9') => {
+                (PromptPlaceholder::Positional(usize::from(digit - b'1')), 2)
+            }
+            _ => match placeholder_name_len(&rest[1..]) {
+                0 => {
+                    out.push('$');
+                    rest = &rest[1..];
+                    continue;
+                }
+                len if &rest[1..1 + len] == PROMPT_ARGUMENTS_PLACEHOLDER => {
+                    (PromptPlaceholder::Arguments, 1 + len)
+                }
+                len => (PromptPlaceholder::Named(&rest[1..1 + len]), 1 + len),
+            },
+        };
+        if !expand(&mut out, placeholder) {
+            out.push_str(&rest[..len]);
+        }
+        rest = &rest[len..];
+    }
+    out.push_str(rest);
+    out
+}
+
+/// Returns the unique `$NAME` placeholders in `content` without the leading
+/// `$`, in order of first appearance. `$$NAME` is an escaped dollar sign and
+/// `$ARGUMENTS` is the positional catch-all, so neither counts.
+pub fn prompt_argument_names(content: &str) -> Vec<String> {
+    let mut seen = HashSet::new();
+    let mut names = Vec::new();
+    expand_prompt_placeholders(content, |_, placeholder| {
+        if let PromptPlaceholder::Named(name) = placeholder
+            && seen.insert(name.to_string())
+        {
+            names.push(name.to_string());
+        }
+        false
+    });
+    names
+}
+
+/// Whether `content` uses positional placeholders (`$1..$9` or `$ARGUMENTS`).
+pub fn prompt_has_numeric_placeholders(content: &str) -> bool {
+    let mut found = false;
+    expand_prompt_placeholders(content, |_, placeholder| {
+        found |= !matches!(placeholder, PromptPlaceholder::Named(_));
+        false
+    });
+    found
+}
+
+/// Length of the `[A-Z][A-Z0-9_]*` placeholder name at the start of `rest`.
+fn placeholder_name_len(rest: &str) -> usize {
+    let bytes = rest.as_bytes();
+    if !bytes.first().is_some_and(u8::is_ascii_uppercase) {
+        return 0;
+    }
+    bytes
+        .iter()
+        .take_while(|b| b.is_ascii_uppercase() || b.is_ascii_digit() || **b == b'_')
+        .count()
+}
+
 /// Parse optional YAML-like frontmatter at the beginning of `content`.
 /// Supported keys:
 /// - `description`: short description shown in the slash popup
@@ -241,4 +337,45 @@ mod tests {
         assert_eq!(hint.as_deref(), Some("[arg]"));
         assert_eq!(body, "First line\r\nSecond line\r\n");
     }
+
+    #[test]
+    fn prompt_argument_names_skip_escapes_and_arguments() {
+        assert_eq!(
+            prompt_argument_names(
+                "Review $FILE for $FOCUS, not $$HOME or $ARGUMENTS. $FILE again."
+            ),
+            vec!["FILE".to_string(), "FOCUS".to_string()]
+        );
+        assert!(prompt_has_numeric_placeholders("Use $2 here"));
+        assert!(prompt_has_numeric_placeholders("All: $ARGUMENTS"));
+        assert!(!prompt_has_numeric_placeholders("Cost: $$5 for $FILE"));
+    }
+
+    #[test]
+    fn expand_prompt_placeholders_keeps_declined_placeholders() {
+        let positional = ["a.rs", "b.rs"];
+        let text = expand_prompt_placeholders(
+            "Fix $TICKET in $1, $2 and $3 ($ARGUMENTS). Keep $OTHER and $$5.",
+            |out, placeholder| match placeholder {
+                PromptPlaceholder::Named("TICKET") => {
+                    out.push_str("ENG-42");
+                    true
+                }
+                PromptPlaceholder::Named(_) => false,
+                PromptPlaceholder::Positional(index) => {
+                    out.push_str(positional.get(index).copied().unwrap_or_default());
+                    true
+                }
+                PromptPlaceholder::Arguments => {
+                    out.push_str(&positional.join(" "));
+                    true
+                }
+            },
+        );
+
+        assert_eq!(
+            text,
+            "Fix ENG-42 in a.rs, b.rs and  (a.rs b.rs). Keep $OTHER and $$5."
+        );
+    }
 }
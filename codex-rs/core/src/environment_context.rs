@@ -69,16 +69,11 @@ impl EnvironmentContext {
     }
 
     fn network_from_turn_context(turn_context: &TurnContext) -> Option<NetworkContext> {
-        let network = turn_context
-            .config
-            .config_layer_stack
-            .requirements()
-            .network
-            .as_ref()?;
+        let network = turn_context.config.network.as_ref()?;
 
         Some(NetworkContext {
-            allowed_domains: network.allowed_domains.clone().unwrap_or_default(),
-            denied_domains: network.denied_domains.clone().unwrap_or_default(),
+            allowed_domains: network.allowed_domains().to_vec(),
+            denied_domains: network.denied_domains().to_vec(),
         })
     }
 }
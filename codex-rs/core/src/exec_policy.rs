@@ -13,6 +13,7 @@ use codex_execpolicy::AmendError;
 use codex_execpolicy::Decision;
 use codex_execpolicy::Error as ExecPolicyRuleError;
 use codex_execpolicy::Evaluation;
+use codex_execpolicy::MatchContext;
 use codex_execpolicy::Policy;
 use codex_execpolicy::PolicyParser;
 use codex_execpolicy::RuleMatch;
@@ -36,13 +37,6 @@ const RULES_DIR_NAME: &str = "rules";
 const RULE_EXTENSION: &str = "rules";
 const DEFAULT_POLICY_FILE: &str = "default.rules";
 
-fn is_policy_match(rule_match: &RuleMatch) -> bool {
-    match rule_match {
-        RuleMatch::PrefixRuleMatch { .. } => true,
-        RuleMatch::HeuristicsRuleMatch { .. } => false,
-    }
-}
-
 #[derive(Debug, Error)]
 pub enum ExecPolicyError {
     #[error("failed to read rules files from {dir}: {source}")]
@@ -89,6 +83,8 @@ pub(crate) struct ExecApprovalRequest<'a> {
     pub(crate) sandbox_policy: &'a SandboxPolicy,
     pub(crate) sandbox_permissions: SandboxPermissions,
     pub(crate) prefix_rule: Option<Vec<String>>,
+    /// Where the command runs, consulted by context-aware rules such as `path_rule`.
+    pub(crate) match_context: MatchContext<'a>,
 }
 
 impl ExecPolicyManager {
@@ -120,6 +116,7 @@ impl ExecPolicyManager {
             sandbox_policy,
             sandbox_permissions,
             prefix_rule,
+            match_context,
         } = req;
         let exec_policy = self.current();
         let (commands, used_heredoc_fallback) = commands_for_exec_policy(command);
@@ -135,7 +132,11 @@ impl ExecPolicyManager {
                 sandbox_permissions,
             )
         };
-        let evaluation = exec_policy.check_multiple(commands.iter(), &exec_policy_fallback);
+        let evaluation = exec_policy.check_multiple_with_context(
+            commands.iter(),
+            &match_context,
+            &exec_policy_fallback,
+        );
 
         let requested_amendment =
             derive_requested_execpolicy_amendment(prefix_rule.as_ref(), &evaluation.matched_rules);
@@ -167,7 +168,7 @@ impl ExecPolicyManager {
             Decision::Allow => ExecApprovalRequirement::Skip {
                 // Bypass sandbox if execpolicy allows the command
                 bypass_sandbox: evaluation.matched_rules.iter().any(|rule_match| {
-                    is_policy_match(rule_match) && rule_match.decision() == Decision::Allow
+                    rule_match.is_policy_match() && rule_match.decision() == Decision::Allow
                 }),
                 proposed_execpolicy_amendment: if auto_amendment_allowed {
                     try_derive_execpolicy_amendment_for_allow_rules(&evaluation.matched_rules)
@@ -228,35 +229,10 @@ async fn load_exec_policy_with_warning(
 }
 
 pub async fn load_exec_policy(config_stack: &ConfigLayerStack) -> Result<Policy, ExecPolicyError> {
-    // Iterate the layers in increasing order of precedence, adding the *.rules
-    // from each layer, so that higher-precedence layers can override
-    // rules defined in lower-precedence ones.
-    let mut policy_paths = Vec::new();
-    for layer in config_stack.get_layers(ConfigLayerStackOrdering::LowestPrecedenceFirst, false) {
-        if let Some(config_folder) = layer.config_folder() {
-            #[expect(clippy::expect_used)]
-            let policy_dir = config_folder.join(RULES_DIR_NAME).expect("safe join");
-            let layer_policy_paths = collect_policy_files(&policy_dir).await?;
-            policy_paths.extend(layer_policy_paths);
-        }
-    }
-
+    let policy_paths = discover_policy_files(config_stack).await?;
     let mut parser = PolicyParser::new();
     for policy_path in &policy_paths {
-        let contents =
-            fs::read_to_string(policy_path)
-                .await
-                .map_err(|source| ExecPolicyError::ReadFile {
-                    path: policy_path.clone(),
-                    source,
-                })?;
-        let identifier = policy_path.to_string_lossy().to_string();
-        parser
-            .parse(&identifier, &contents)
-            .map_err(|source| ExecPolicyError::ParsePolicy {
-                path: identifier,
-                source,
-            })?;
+        parse_policy_file(&mut parser, policy_path).await?;
     }
 
     let policy = parser.build();
@@ -276,6 +252,73 @@ pub async fn load_exec_policy(config_stack: &ConfigLayerStack) -> Result<Policy,
     Ok(Policy::new(combined_rules))
 }
 
+/// Loads the same rules as [`load_exec_policy`], but keeps each file as its own policy tagged
+/// with its path, in evaluation order, so `codex execpolicy explain` can attribute rules to
+/// files. Rules enforced by requirements come last, tagged with where they were set.
+pub async fn load_exec_policy_files(
+    config_stack: &ConfigLayerStack,
+) -> Result<Vec<(String, Policy)>, ExecPolicyError> {
+    let mut policies = Vec::new();
+    for policy_path in discover_policy_files(config_stack).await? {
+        let mut parser = PolicyParser::new();
+        parse_policy_file(&mut parser, &policy_path).await?;
+        policies.push((policy_path.to_string_lossy().to_string(), parser.build()));
+    }
+    if let Some(requirements_policy) = &config_stack.requirements().exec_policy {
+        policies.push((
+            requirements_policy.source.to_string(),
+            requirements_policy.value.as_ref().clone(),
+        ));
+    }
+    Ok(policies)
+}
+
+/// Iterates the layers in increasing order of precedence, collecting the *.rules from each
+/// layer, so that higher-precedence layers can override rules defined in lower-precedence ones.
+async fn discover_policy_files(
+    config_stack: &ConfigLayerStack,
+) -> Result<Vec<PathBuf>, ExecPolicyError> {
+    let mut policy_paths = Vec::new();
+    for layer in config_stack.get_layers(ConfigLayerStackOrdering::LowestPrecedenceFirst, false) {
+        if let Some(config_folder) = layer.config_folder() {
+            #[expect(clippy::expect_used)]
+            let policy_dir = config_folder.join(RULES_DIR_NAME).expect("safe join");
+            let layer_policy_paths = collect_policy_files(&policy_dir).await?;
+            policy_paths.extend(layer_policy_paths);
+        }
+    }
+    Ok(policy_paths)
+}
+
+async fn parse_policy_file(
+    parser: &mut PolicyParser,
+    policy_path: &Path,
+) -> Result<(), ExecPolicyError> {
+    let contents =
+        fs::read_to_string(policy_path)
+            .await
+            .map_err(|source| ExecPolicyError::ReadFile {
+                path: policy_path.to_path_buf(),
+                source,
+            })?;
+    let identifier = policy_path.to_string_lossy().to_string();
+    parser
+        .parse(&identifier, &contents)
+        .map_err(|source| ExecPolicyError::ParsePolicy {
+            path: identifier,
+            source,
+        })
+}
+
+/// Which fallback heuristic decided a command that no execpolicy rule matched.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct UnmatchedCommandDecision {
+    pub decision: Decision,
+    /// Stable identifier of the heuristic, e.g. `known_safe_command`.
+    pub heuristic: &'static str,
+    pub reason: &'static str,
+}
+
 /// If a command is not matched by any execpolicy rule, derive a [`Decision`].
 pub fn render_decision_for_unmatched_command(
     approval_policy: AskForApproval,
@@ -283,8 +326,33 @@ pub fn render_decision_for_unmatched_command(
     command: &[String],
     sandbox_permissions: SandboxPermissions,
 ) -> Decision {
+    explain_decision_for_unmatched_command(
+        approval_policy,
+        sandbox_policy,
+        command,
+        sandbox_permissions,
+    )
+    .decision
+}
+
+/// Like [`render_decision_for_unmatched_command`], but also reports which heuristic decided.
+pub fn explain_decision_for_unmatched_command(
+    approval_policy: AskForApproval,
+    sandbox_policy: &SandboxPolicy,
+    command: &[String],
+    sandbox_permissions: SandboxPermissions,
+) -> UnmatchedCommandDecision {
+    let decided = |decision, heuristic, reason| UnmatchedCommandDecision {
+        decision,
+        heuristic,
+        reason,
+    };
     if is_known_safe_command(command) {
-        return Decision::Allow;
+        return decided(
+            Decision::Allow,
+            "known_safe_command",
+            "the command is on the list of known-safe commands",
+        );
     }
 
     // On Windows, ReadOnly sandbox is not a real sandbox, so special-case it
@@ -299,10 +367,18 @@ pub fn render_decision_for_unmatched_command(
     // but if the user has explicitly disabled prompts, we must
     // forbid the command.
     if command_might_be_dangerous(command) || runtime_sandbox_provides_safety {
+        let (heuristic, reason) = if runtime_sandbox_provides_safety {
+            (
+                "windows_read_only_sandbox",
+                "the read-only sandbox does not restrict commands on Windows",
+            )
+        } else {
+            ("dangerous_command", "the command was flagged as dangerous")
+        };
         return if matches!(approval_policy, AskForApproval::Never) {
-            Decision::Forbidden
+            decided(Decision::Forbidden, heuristic, reason)
         } else {
-            Decision::Prompt
+            decided(Decision::Prompt, heuristic, reason)
         };
     }
 
@@ -310,12 +386,20 @@ pub fn render_decision_for_unmatched_command(
         AskForApproval::Never | AskForApproval::OnFailure => {
             // We allow the command to run, relying on the sandbox for
             // protection.
-            Decision::Allow
+            decided(
+                Decision::Allow,
+                "approval_policy",
+                "the approval policy runs commands in the sandbox without asking",
+            )
         }
         AskForApproval::UnlessTrusted => {
             // We already checked `is_known_safe_command(command)` and it
             // returned false, so we must prompt.
-            Decision::Prompt
+            decided(
+                Decision::Prompt,
+                "approval_policy",
+                "the approval policy asks before any command that is not known to be safe",
+            )
         }
         AskForApproval::OnRequest => {
             match sandbox_policy {
@@ -323,16 +407,28 @@ pub fn render_decision_for_unmatched_command(
                     // The user has indicated we should "just run" commands
                     // in their unrestricted environment, so we do so since the
                     // command has not been flagged as dangerous.
-                    Decision::Allow
+                    decided(
+                        Decision::Allow,
+                        "unrestricted_sandbox",
+                        "commands run unrestricted and this one was not flagged as dangerous",
+                    )
                 }
                 SandboxPolicy::ReadOnly | SandboxPolicy::WorkspaceWrite { .. } => {
                     // In restricted sandboxes (ReadOnly/WorkspaceWrite), do not prompt for
                     // non‑escalated, non‑dangerous commands — let the sandbox enforce
                     // restrictions (e.g., block network/write) without a user prompt.
                     if sandbox_permissions.requires_escalated_permissions() {
-                        Decision::Prompt
+                        decided(
+                            Decision::Prompt,
+                            "escalated_permissions",
+                            "the command asks to run outside the sandbox",
+                        )
                     } else {
-                        Decision::Allow
+                        decided(
+                            Decision::Allow,
+                            "sandboxed",
+                            "the sandbox enforces restrictions, so no approval is needed",
+                        )
                     }
                 }
             }
@@ -344,6 +440,12 @@ fn default_policy_path(codex_home: &Path) -> PathBuf {
     codex_home.join(RULES_DIR_NAME).join(DEFAULT_POLICY_FILE)
 }
 
+/// The commands exec policy evaluates for `command`: the plain commands of a
+/// `bash -lc` script, or `command` itself when it is not one.
+pub fn exec_policy_commands(command: &[String]) -> Vec<Vec<String>> {
+    commands_for_exec_policy(command).0
+}
+
 fn commands_for_exec_policy(command: &[String]) -> (Vec<Vec<String>>, bool) {
     if let Some(commands) = parse_shell_lc_plain_commands(command)
         && !commands.is_empty()
@@ -373,7 +475,7 @@ fn try_derive_execpolicy_amendment_for_prompt_rules(
 ) -> Option<ExecPolicyAmendment> {
     if matched_rules
         .iter()
-        .any(|rule_match| is_policy_match(rule_match) && rule_match.decision() == Decision::Prompt)
+        .any(|rule_match| rule_match.is_policy_match() && rule_match.decision() == Decision::Prompt)
     {
         return None;
     }
@@ -395,7 +497,7 @@ fn try_derive_execpolicy_amendment_for_prompt_rules(
 fn try_derive_execpolicy_amendment_for_allow_rules(
     matched_rules: &[RuleMatch],
 ) -> Option<ExecPolicyAmendment> {
-    if matched_rules.iter().any(is_policy_match) {
+    if matched_rules.iter().any(RuleMatch::is_policy_match) {
         return None;
     }
 
@@ -421,7 +523,7 @@ fn derive_requested_execpolicy_amendment(
 
     if matched_rules
         .iter()
-        .any(|rule_match| is_policy_match(rule_match) && rule_match.decision() == Decision::Prompt)
+        .any(|rule_match| rule_match.is_policy_match() && rule_match.decision() == Decision::Prompt)
     {
         return None;
     }
@@ -433,29 +535,20 @@ fn derive_requested_execpolicy_amendment(
 fn derive_prompt_reason(command_args: &[String], evaluation: &Evaluation) -> Option<String> {
     let command = render_shlex_command(command_args);
 
-    let most_specific_prompt = evaluation
-        .matched_rules
-        .iter()
-        .filter_map(|rule_match| match rule_match {
-            RuleMatch::PrefixRuleMatch {
-                matched_prefix,
-                decision: Decision::Prompt,
-                justification,
-                ..
-            } => Some((matched_prefix.len(), justification.as_deref())),
-            _ => None,
-        })
-        .max_by_key(|(matched_prefix_len, _)| *matched_prefix_len);
+    let most_specific_prompt = most_specific_policy_match(evaluation, Decision::Prompt)?;
 
-    match most_specific_prompt {
-        Some((_matched_prefix_len, Some(justification))) => {
-            Some(format!("`{command}` requires approval: {justification}"))
-        }
-        Some((_matched_prefix_len, None)) => {
-            Some(format!("`{command}` requires approval by policy"))
-        }
-        None => None,
-    }
+    Some(
+        match (
+            most_specific_prompt.justification(),
+            most_specific_prompt.matched_token(),
+        ) {
+            (Some(justification), _) => format!("`{command}` requires approval: {justification}"),
+            (None, Some(token)) => {
+                format!("`{command}` requires approval by policy because of `{token}`")
+            }
+            (None, None) => format!("`{command}` requires approval by policy"),
+        },
+    )
 }
 
 fn render_shlex_command(args: &[String]) -> String {
@@ -468,32 +561,32 @@ fn render_shlex_command(args: &[String]) -> String {
 fn derive_forbidden_reason(command_args: &[String], evaluation: &Evaluation) -> String {
     let command = render_shlex_command(command_args);
 
-    let most_specific_forbidden = evaluation
-        .matched_rules
-        .iter()
-        .filter_map(|rule_match| match rule_match {
-            RuleMatch::PrefixRuleMatch {
-                matched_prefix,
-                decision: Decision::Forbidden,
-                justification,
-                ..
-            } => Some((matched_prefix, justification.as_deref())),
-            _ => None,
-        })
-        .max_by_key(|(matched_prefix, _)| matched_prefix.len());
+    let Some(most_specific_forbidden) = most_specific_policy_match(evaluation, Decision::Forbidden)
+    else {
+        return format!("`{command}` rejected: blocked by policy");
+    };
+    if let Some(justification) = most_specific_forbidden.justification() {
+        return format!("`{command}` rejected: {justification}");
+    }
 
-    match most_specific_forbidden {
-        Some((_matched_prefix, Some(justification))) => {
-            format!("`{command}` rejected: {justification}")
-        }
-        Some((matched_prefix, None)) => {
-            let prefix = render_shlex_command(matched_prefix);
-            format!("`{command}` rejected: policy forbids commands starting with `{prefix}`")
-        }
-        None => format!("`{command}` rejected: blocked by policy"),
+    let prefix = render_shlex_command(most_specific_forbidden.matched_prefix().unwrap_or_default());
+    match most_specific_forbidden.matched_token() {
+        Some(token) => format!(
+            "`{command}` rejected: policy forbids `{token}` in commands starting with `{prefix}`"
+        ),
+        None => format!("`{command}` rejected: policy forbids commands starting with `{prefix}`"),
     }
 }
 
+/// The policy rule match with the longest prefix among those that rendered `decision`.
+fn most_specific_policy_match(evaluation: &Evaluation, decision: Decision) -> Option<&RuleMatch> {
+    evaluation
+        .matched_rules
+        .iter()
+        .filter(|rule_match| rule_match.is_policy_match() && rule_match.decision() == decision)
+        .max_by_key(|rule_match| rule_match.matched_prefix().map_or(0, <[String]>::len))
+}
+
 async fn collect_policy_files(dir: impl AsRef<Path>) -> Result<Vec<PathBuf>, ExecPolicyError> {
     let dir = dir.as_ref();
     let mut read_dir = match fs::read_dir(dir).await {
@@ -646,6 +739,108 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn load_exec_policy_files_keeps_each_file_separate() {
+        let temp_dir = tempdir().expect("create temp dir");
+        let config_stack = config_stack_for_dot_codex_folder(temp_dir.path());
+        let policy_dir = temp_dir.path().join(RULES_DIR_NAME);
+        fs::create_dir_all(&policy_dir).expect("create policy dir");
+        fs::write(
+            policy_dir.join("a.rules"),
+            r#"prefix_rule(pattern=["rm"], decision="forbidden")"#,
+        )
+        .expect("write policy file");
+        fs::write(
+            policy_dir.join("b.rules"),
+            r#"prefix_rule(pattern=["ls"], decision="allow")"#,
+        )
+        .expect("write policy file");
+
+        let policies = load_exec_policy_files(&config_stack)
+            .await
+            .expect("policy files");
+        let summary: Vec<(String, Vec<String>)> = policies
+            .iter()
+            .map(|(path, policy)| {
+                (
+                    path.clone(),
+                    policy
+                        .rules()
+                        .iter_all()
+                        .map(|(program, _)| program.clone())
+                        .collect(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (
+                    policy_dir.join("a.rules").to_string_lossy().to_string(),
+                    vec!["rm".to_string()]
+                ),
+                (
+                    policy_dir.join("b.rules").to_string_lossy().to_string(),
+                    vec!["ls".to_string()]
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn explain_decision_for_unmatched_command_names_the_heuristic() {
+        let decide = |command: &[&str], approval_policy, sandbox_permissions| {
+            let decision = explain_decision_for_unmatched_command(
+                approval_policy,
+                &SandboxPolicy::new_workspace_write_policy(),
+                &vec_str(command),
+                sandbox_permissions,
+            );
+            (decision.heuristic, decision.decision)
+        };
+
+        assert_eq!(
+            decide(
+                &["ls"],
+                AskForApproval::UnlessTrusted,
+                SandboxPermissions::UseDefault
+            ),
+            ("known_safe_command", Decision::Allow)
+        );
+        assert_eq!(
+            decide(
+                &["rm", "-rf", "/important/data"],
+                AskForApproval::Never,
+                SandboxPermissions::UseDefault
+            ),
+            ("dangerous_command", Decision::Forbidden)
+        );
+        assert_eq!(
+            decide(
+                &["cargo", "build"],
+                AskForApproval::UnlessTrusted,
+                SandboxPermissions::UseDefault
+            ),
+            ("approval_policy", Decision::Prompt)
+        );
+        assert_eq!(
+            decide(
+                &["cargo", "build"],
+                AskForApproval::OnRequest,
+                SandboxPermissions::RequireEscalated
+            ),
+            ("escalated_permissions", Decision::Prompt)
+        );
+        assert_eq!(
+            decide(
+                &["cargo", "build"],
+                AskForApproval::OnRequest,
+                SandboxPermissions::UseDefault
+            ),
+            ("sandboxed", Decision::Allow)
+        );
+    }
+
     #[tokio::test]
     async fn ignores_policies_outside_policy_dir() {
         let temp_dir = tempdir().expect("create temp dir");
@@ -805,6 +1000,7 @@ prefix_rule(pattern=["rm"], decision="forbidden")
                 sandbox_policy: &SandboxPolicy::DangerFullAccess,
                 sandbox_permissions: SandboxPermissions::UseDefault,
                 prefix_rule: None,
+                match_context: MatchContext::default(),
             })
             .await;
 
@@ -816,6 +1012,83 @@ prefix_rule(pattern=["rm"], decision="forbidden")
         );
     }
 
+    #[tokio::test]
+    async fn structured_rule_reasons_name_the_matched_token() {
+        let policy_src = r#"
+flag_rule(pattern=["git", "push"], flags=["--force"], decision="forbidden")
+path_rule(pattern=["cp"], decision="prompt")
+"#;
+        let mut parser = PolicyParser::new();
+        parser
+            .parse("test.rules", policy_src)
+            .expect("parse policy");
+        let manager = ExecPolicyManager::new(Arc::new(parser.build()));
+
+        let force_push = vec!["git".to_string(), "push".to_string(), "--force".to_string()];
+        let requirement = manager
+            .create_exec_approval_requirement_for_command(ExecApprovalRequest {
+                command: &force_push,
+                approval_policy: AskForApproval::OnRequest,
+                sandbox_policy: &SandboxPolicy::DangerFullAccess,
+                sandbox_permissions: SandboxPermissions::UseDefault,
+                prefix_rule: None,
+                match_context: MatchContext::default(),
+            })
+            .await;
+        assert_eq!(
+            requirement,
+            ExecApprovalRequirement::Forbidden {
+                reason: "`git push --force` rejected: policy forbids `--force` in commands starting with `git push`".to_string()
+            }
+        );
+
+        let copy_out = vec![
+            "cp".to_string(),
+            "notes.txt".to_string(),
+            "/repo/../elsewhere".to_string(),
+        ];
+        let requirement = manager
+            .create_exec_approval_requirement_for_command(ExecApprovalRequest {
+                command: &copy_out,
+                approval_policy: AskForApproval::OnRequest,
+                sandbox_policy: &SandboxPolicy::DangerFullAccess,
+                sandbox_permissions: SandboxPermissions::UseDefault,
+                prefix_rule: None,
+                match_context: MatchContext {
+                    cwd: Some(Path::new("/repo")),
+                    workspace_root: Some(Path::new("/repo")),
+                },
+            })
+            .await;
+        assert_eq!(
+            requirement,
+            ExecApprovalRequirement::NeedsApproval {
+                reason: Some(
+                    "`cp notes.txt /repo/../elsewhere` requires approval by policy because of `/repo/../elsewhere`"
+                        .to_string()
+                ),
+                proposed_execpolicy_amendment: None,
+            }
+        );
+    }
+
+    #[test]
+    fn exec_policy_commands_split_shell_scripts() {
+        let command = vec![
+            "bash".to_string(),
+            "-lc".to_string(),
+            "git status && rm -rf build".to_string(),
+        ];
+
+        assert_eq!(
+            exec_policy_commands(&command),
+            vec![
+                vec!["git".to_string(), "status".to_string()],
+                vec!["rm".to_string(), "-rf".to_string(), "build".to_string()],
+            ]
+        );
+    }
+
     #[test]
     fn commands_for_exec_policy_falls_back_for_empty_shell_script() {
         let command = vec!["bash".to_string(), "-lc".to_string(), "".to_string()];
@@ -855,6 +1128,7 @@ prefix_rule(pattern=["rm"], decision="forbidden")
                 sandbox_policy: &SandboxPolicy::ReadOnly,
                 sandbox_permissions: SandboxPermissions::UseDefault,
                 prefix_rule: None,
+                match_context: MatchContext::default(),
             })
             .await;
 
@@ -882,6 +1156,7 @@ prefix_rule(pattern=["rm"], decision="forbidden")
                 sandbox_policy: &SandboxPolicy::ReadOnly,
                 sandbox_permissions: SandboxPermissions::UseDefault,
                 prefix_rule: None,
+                match_context: MatchContext::default(),
             })
             .await;
 
@@ -910,6 +1185,7 @@ prefix_rule(pattern=["rm"], decision="forbidden")
                 sandbox_policy: &SandboxPolicy::ReadOnly,
                 sandbox_permissions: SandboxPermissions::UseDefault,
                 prefix_rule: Some(requested_prefix.clone()),
+                match_context: MatchContext::default(),
             })
             .await;
 
@@ -949,6 +1225,7 @@ prefix_rule(
                 sandbox_policy: &SandboxPolicy::DangerFullAccess,
                 sandbox_permissions: SandboxPermissions::UseDefault,
                 prefix_rule: None,
+                match_context: MatchContext::default(),
             })
             .await;
 
@@ -978,6 +1255,7 @@ prefix_rule(
                 sandbox_policy: &SandboxPolicy::DangerFullAccess,
                 sandbox_permissions: SandboxPermissions::UseDefault,
                 prefix_rule: None,
+                match_context: MatchContext::default(),
             })
             .await;
 
@@ -1008,6 +1286,7 @@ prefix_rule(
                 sandbox_policy: &SandboxPolicy::DangerFullAccess,
                 sandbox_permissions: SandboxPermissions::UseDefault,
                 prefix_rule: None,
+                match_context: MatchContext::default(),
             })
             .await;
 
@@ -1031,6 +1310,7 @@ prefix_rule(
                 sandbox_policy: &SandboxPolicy::ReadOnly,
                 sandbox_permissions: SandboxPermissions::UseDefault,
                 prefix_rule: None,
+                match_context: MatchContext::default(),
             })
             .await;
 
@@ -1055,6 +1335,7 @@ prefix_rule(
                 sandbox_policy: &SandboxPolicy::ReadOnly,
                 sandbox_permissions: SandboxPermissions::UseDefault,
                 prefix_rule: None,
+                match_context: MatchContext::default(),
             })
             .await;
 
@@ -1083,6 +1364,7 @@ prefix_rule(
                 sandbox_policy: &SandboxPolicy::ReadOnly,
                 sandbox_permissions: SandboxPermissions::UseDefault,
                 prefix_rule: None,
+                match_context: MatchContext::default(),
             })
             .await;
 
@@ -1113,6 +1395,7 @@ prefix_rule(
                 sandbox_policy: &SandboxPolicy::ReadOnly,
                 sandbox_permissions: SandboxPermissions::RequireEscalated,
                 prefix_rule: Some(vec!["cargo".to_string(), "install".to_string()]),
+                match_context: MatchContext::default(),
             })
             .await;
 
@@ -1150,6 +1433,7 @@ prefix_rule(
                     sandbox_policy: &SandboxPolicy::DangerFullAccess,
                     sandbox_permissions: SandboxPermissions::UseDefault,
                     prefix_rule: None,
+                    match_context: MatchContext::default(),
                 })
                 .await,
             ExecApprovalRequirement::NeedsApproval {
@@ -1224,6 +1508,7 @@ prefix_rule(
                 sandbox_policy: &SandboxPolicy::ReadOnly,
                 sandbox_permissions: SandboxPermissions::UseDefault,
                 prefix_rule: None,
+                match_context: MatchContext::default(),
             })
             .await;
 
@@ -1254,6 +1539,7 @@ prefix_rule(
                 sandbox_policy: &SandboxPolicy::DangerFullAccess,
                 sandbox_permissions: SandboxPermissions::UseDefault,
                 prefix_rule: None,
+                match_context: MatchContext::default(),
             })
             .await;
 
@@ -1281,6 +1567,7 @@ prefix_rule(
                 sandbox_policy: &SandboxPolicy::ReadOnly,
                 sandbox_permissions: SandboxPermissions::UseDefault,
                 prefix_rule: None,
+                match_context: MatchContext::default(),
             })
             .await;
 
@@ -1319,6 +1606,7 @@ prefix_rule(
                     sandbox_policy: &SandboxPolicy::ReadOnly,
                     sandbox_permissions: SandboxPermissions::UseDefault,
                     prefix_rule: None,
+                    match_context: MatchContext::default(),
                 })
                 .await,
             ExecApprovalRequirement::NeedsApproval {
@@ -1342,6 +1630,7 @@ prefix_rule(
                 sandbox_policy: &SandboxPolicy::ReadOnly,
                 sandbox_permissions: SandboxPermissions::UseDefault,
                 prefix_rule: None,
+                match_context: MatchContext::default(),
             })
             .await;
 
@@ -1372,6 +1661,7 @@ prefix_rule(
                 sandbox_policy: &SandboxPolicy::ReadOnly,
                 sandbox_permissions: SandboxPermissions::UseDefault,
                 prefix_rule: None,
+                match_context: MatchContext::default(),
             })
             .await;
 
@@ -1395,6 +1685,7 @@ prefix_rule(
                 sandbox_policy: &SandboxPolicy::DangerFullAccess,
                 sandbox_permissions: SandboxPermissions::UseDefault,
                 prefix_rule: None,
+                match_context: MatchContext::default(),
             })
             .await;
 
@@ -1461,6 +1752,7 @@ prefix_rule(
                     sandbox_policy: &SandboxPolicy::ReadOnly,
                     sandbox_permissions: permissions,
                     prefix_rule: None,
+                    match_context: MatchContext::default(),
                 })
                 .await,
             "{pwsh_approval_reason}"
@@ -1484,6 +1776,7 @@ prefix_rule(
                     sandbox_policy: &SandboxPolicy::ReadOnly,
                     sandbox_permissions: permissions,
                     prefix_rule: None,
+                    match_context: MatchContext::default(),
                 })
                 .await,
             r#"On all platforms, a forbidden command should require approval
@@ -1503,6 +1796,7 @@ prefix_rule(
                     sandbox_policy: &SandboxPolicy::ReadOnly,
                     sandbox_permissions: permissions,
                     prefix_rule: None,
+                    match_context: MatchContext::default(),
                 })
                 .await,
             r#"On all platforms, a forbidden command should require approval
@@ -1,13 +1,28 @@
+//! UI string catalogs.
+//!
+//! `en` and `zh-cn` are compiled in. Additional locales are loaded from
+//! `$CODEX_HOME/i18n/<tag>.toml` once per process by [`install_runtime_catalogs`]; a runtime
+//! catalog for a built-in locale overrides individual keys. Lookups walk the language's fallback
+//! chain (e.g. `zh-tw` → `zh-cn` → `en`) until a catalog defines the key.
+
 use codex_protocol::config_types::Language;
 use once_cell::sync::Lazy;
-#[cfg(test)]
 use std::collections::BTreeSet;
 use std::collections::HashMap;
+use std::path::Path;
+use std::sync::OnceLock;
 use toml::Value;
 
-static EN_CATALOG: Lazy<Catalog> = Lazy::new(|| Catalog::from_str(include_str!("../i18n/en.toml")));
+/// Directory under `CODEX_HOME` that holds runtime catalogs.
+pub const CATALOG_DIR: &str = "i18n";
+
+/// Reserved top-level table for catalog metadata such as the locale's display name.
+const META_TABLE: &str = "meta";
+
+static EN_CATALOG: Lazy<Catalog> = Lazy::new(|| builtin_catalog(include_str!("../i18n/en.toml")));
 static ZH_CATALOG: Lazy<Catalog> =
-    Lazy::new(|| Catalog::from_str(include_str!("../i18n/zh-cn.toml")));
+    Lazy::new(|| builtin_catalog(include_str!("../i18n/zh-cn.toml")));
+static RUNTIME_CATALOGS: OnceLock<HashMap<Language, Catalog>> = OnceLock::new();
 
 pub fn tr(language: Language, key: &'static str) -> &'static str {
     lookup(language, key).unwrap_or(key)
@@ -19,11 +34,11 @@ pub fn tr_args(language: Language, key: &'static str, args: &[(&str, &str)]) ->
 }
 
 pub fn tr_list(language: Language, key: &'static str) -> &'static [String] {
-    if let Some(list) = lookup_list(language, key) {
-        return list;
-    }
-    if let Some(list) = lookup_list(Language::En, key) {
-        return list;
+    if let Some(list) = fallback_chain(language)
+        .flat_map(catalogs)
+        .find_map(|catalog| catalog.lists.get(key))
+    {
+        return list.as_slice();
     }
     static EMPTY: Lazy<Vec<String>> = Lazy::new(Vec::new);
     EMPTY.as_slice()
@@ -33,47 +48,220 @@ pub fn language_name(ui_language: Language, target: Language) -> &'static str {
     match target {
         Language::En => tr(ui_language, "language.name.en"),
         Language::ZhCn => tr(ui_language, "language.name.zh_cn"),
+        Language::Locale(tag) => runtime_catalog(target)
+            .and_then(|catalog| catalog.name.as_deref())
+            .unwrap_or(tag.as_str()),
     }
 }
 
-fn lookup(language: Language, key: &'static str) -> Option<&'static str> {
-    catalog(language)
+/// Built-in languages followed by every locale with a runtime catalog, sorted by tag.
+pub fn available_languages() -> Vec<Language> {
+    let mut runtime: Vec<Language> = RUNTIME_CATALOGS
+        .get()
+        .into_iter()
+        .flat_map(HashMap::keys)
+        .copied()
+        .filter(|language| matches!(language, Language::Locale(_)))
+        .collect();
+    runtime.sort_by_key(|language| language.as_str());
+    let mut languages = vec![Language::En, Language::ZhCn];
+    languages.extend(runtime);
+    languages
+}
+
+/// The first built-in language on `language`'s fallback chain. Use this to pick assets that only
+/// exist for built-in languages, such as prompt templates.
+pub fn builtin_language(language: Language) -> Language {
+    fallback_chain(language)
+        .find(|language| !matches!(language, Language::Locale(_)))
+        .unwrap_or_default()
+}
+
+/// Returns `true` when `language` is built in or has a runtime catalog.
+pub fn has_catalog(language: Language) -> bool {
+    matches!(language, Language::En | Language::ZhCn) || runtime_catalog(language).is_some()
+}
+
+/// Loads every `*.toml` file in `$CODEX_HOME/i18n`. Only the first call in a process installs
+/// catalogs; later calls return no warnings. Files that fail to parse are skipped, and strings
+/// whose `${arg}` placeholders differ from `en` are dropped so the English text is used instead.
+pub fn install_runtime_catalogs(codex_home: &Path) -> Vec<String> {
+    let mut warnings = Vec::new();
+    RUNTIME_CATALOGS
+        .get_or_init(|| load_runtime_catalogs(&codex_home.join(CATALOG_DIR), &mut warnings));
+    warnings
+}
+
+fn load_runtime_catalogs(dir: &Path, warnings: &mut Vec<String>) -> HashMap<Language, Catalog> {
+    let mut catalogs = HashMap::new();
+    let entries = match std::fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return catalogs,
+        Err(err) => {
+            warnings.push(format!(
+                "Failed to read i18n catalogs in {}: {err}",
+                dir.display()
+            ));
+            return catalogs;
+        }
+    };
+    let mut paths: Vec<_> = entries
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "toml"))
+        .collect();
+    paths.sort();
+
+    for path in paths {
+        let Some(language) = path
+            .file_stem()
+            .and_then(|stem| stem.to_str())
+            .and_then(Language::parse)
+        else {
+            warnings.push(format!(
+                "Ignoring i18n catalog {}: file name is not a locale tag",
+                path.display()
+            ));
+            continue;
+        };
+        let mut catalog = match std::fs::read_to_string(&path)
+            .map_err(|err| err.to_string())
+            .and_then(|raw| Catalog::parse(&raw))
+        {
+            Ok(catalog) => catalog,
+            Err(err) => {
+                warnings.push(format!("Ignoring i18n catalog {}: {err}", path.display()));
+                continue;
+            }
+        };
+        let mismatched = placeholder_mismatches(&catalog);
+        if !mismatched.is_empty() {
+            warnings.push(format!(
+                "i18n catalog {} uses placeholders that differ from en.toml for: {}",
+                path.display(),
+                mismatched.join(", ")
+            ));
+            for key in &mismatched {
+                catalog.strings.remove(key);
+            }
+        }
+        catalogs.insert(language, catalog);
+    }
+    catalogs
+}
+
+/// Differences between a translated catalog and `en.toml`.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct CatalogReport {
+    /// Keys defined in `en.toml` but not in the catalog.
+    pub missing: Vec<String>,
+    /// Keys defined in the catalog but not in `en.toml`.
+    pub extra: Vec<String>,
+    /// Keys whose `${arg}` placeholders differ from the English string.
+    pub placeholder_mismatches: Vec<String>,
+}
+
+impl CatalogReport {
+    pub fn is_clean(&self) -> bool {
+        self.missing.is_empty() && self.extra.is_empty() && self.placeholder_mismatches.is_empty()
+    }
+}
+
+/// Compares the catalog source `raw` against the built-in English catalog.
+pub fn check_catalog(raw: &str) -> Result<CatalogReport, String> {
+    let catalog = Catalog::parse(raw)?;
+    let en_keys = EN_CATALOG.keys();
+    let keys = catalog.keys();
+    Ok(CatalogReport {
+        missing: en_keys.difference(&keys).cloned().collect(),
+        extra: keys.difference(&en_keys).cloned().collect(),
+        placeholder_mismatches: placeholder_mismatches(&catalog),
+    })
+}
+
+fn placeholder_mismatches(catalog: &Catalog) -> Vec<String> {
+    let mut mismatched: Vec<String> = catalog
         .strings
-        .get(key)
+        .iter()
+        .filter(|(key, value)| {
+            EN_CATALOG
+                .strings
+                .get(key.as_str())
+                .is_some_and(|english| placeholders(english) != placeholders(value))
+        })
+        .map(|(key, _)| key.clone())
+        .collect();
+    mismatched.sort();
+    mismatched
+}
+
+fn placeholders(template: &str) -> BTreeSet<&str> {
+    let mut names = BTreeSet::new();
+    let mut rest = template;
+    while let Some(start) = rest.find("${") {
+        let after_start = &rest[start + 2..];
+        let Some(end) = after_start.find('}') else {
+            break;
+        };
+        names.insert(&after_start[..end]);
+        rest = &after_start[end + 1..];
+    }
+    names
+}
+
+fn lookup(language: Language, key: &'static str) -> Option<&'static str> {
+    fallback_chain(language)
+        .flat_map(catalogs)
+        .find_map(|catalog| catalog.strings.get(key))
         .map(String::as_str)
-        .or_else(|| catalog(Language::En).strings.get(key).map(String::as_str))
 }
 
-fn lookup_list(language: Language, key: &'static str) -> Option<&'static [String]> {
-    catalog(language).lists.get(key).map(Vec::as_slice)
+fn fallback_chain(language: Language) -> impl Iterator<Item = Language> {
+    std::iter::successors(Some(language), |language| language.parent())
 }
 
-fn catalog(language: Language) -> &'static Catalog {
-    match language {
-        Language::En => &EN_CATALOG,
-        Language::ZhCn => &ZH_CATALOG,
-    }
+/// Catalogs for `language` in priority order: runtime overrides, then the built-in catalog.
+fn catalogs(language: Language) -> impl Iterator<Item = &'static Catalog> {
+    let builtin: Option<&'static Catalog> = match language {
+        Language::En => Some(&EN_CATALOG),
+        Language::ZhCn => Some(&ZH_CATALOG),
+        Language::Locale(_) => None,
+    };
+    runtime_catalog(language).into_iter().chain(builtin)
+}
+
+fn runtime_catalog(language: Language) -> Option<&'static Catalog> {
+    RUNTIME_CATALOGS.get()?.get(&language)
+}
+
+fn builtin_catalog(raw: &str) -> Catalog {
+    Catalog::parse(raw).unwrap_or_else(|err| panic!("built-in i18n catalog is invalid: {err}"))
 }
 
 #[derive(Debug)]
 struct Catalog {
+    /// Display name of the locale, from `[meta] name`.
+    name: Option<String>,
     strings: HashMap<String, String>,
     lists: HashMap<String, Vec<String>>,
 }
 
 impl Catalog {
-    fn from_str(raw: &str) -> Self {
-        let value: Value =
-            toml::from_str(raw).unwrap_or_else(|err| panic!("i18n TOML must parse: {err}"));
+    fn parse(raw: &str) -> Result<Self, String> {
+        let mut value: Value = toml::from_str(raw).map_err(|err| err.to_string())?;
+        let name = value
+            .as_table_mut()
+            .and_then(|table| table.remove(META_TABLE))
+            .and_then(|meta| meta.get("name").and_then(Value::as_str).map(str::to_string));
         let mut catalog = Catalog {
+            name,
             strings: HashMap::new(),
             lists: HashMap::new(),
         };
-        flatten(None, &value, &mut catalog);
-        catalog
+        flatten(None, &value, &mut catalog)?;
+        Ok(catalog)
     }
 
-    #[cfg(test)]
     fn keys(&self) -> BTreeSet<String> {
         self.strings
             .keys()
@@ -83,54 +271,48 @@ impl Catalog {
     }
 }
 
-fn flatten(prefix: Option<&str>, value: &Value, catalog: &mut Catalog) {
-    match value {
-        Value::Table(table) => {
+fn flatten(prefix: Option<&str>, value: &Value, catalog: &mut Catalog) -> Result<(), String> {
+    match (prefix, value) {
+        (_, Value::Table(table)) => {
             for (key, entry) in table {
                 let next = match prefix {
                     Some(prefix) => format!("{prefix}.{key}"),
                     None => key.clone(),
                 };
-                flatten(Some(&next), entry, catalog);
+                flatten(Some(&next), entry, catalog)?;
             }
+            Ok(())
         }
-        Value::String(text) => {
-            if let Some(prefix) = prefix {
-                insert_string(prefix, text, catalog);
-            } else {
-                panic!("i18n values must be keyed");
-            }
-        }
-        Value::Array(values) => {
-            if let Some(prefix) = prefix {
-                let mut items = Vec::with_capacity(values.len());
-                for entry in values {
-                    match entry {
-                        Value::String(text) => items.push(text.clone()),
-                        _ => panic!("i18n list values must be strings"),
-                    }
+        (Some(prefix), Value::String(text)) => insert_string(prefix, text, catalog),
+        (Some(prefix), Value::Array(values)) => {
+            let mut items = Vec::with_capacity(values.len());
+            for entry in values {
+                match entry {
+                    Value::String(text) => items.push(text.clone()),
+                    _ => return Err(format!("list values must be strings: {prefix}")),
                 }
-                insert_list(prefix, items, catalog);
-            } else {
-                panic!("i18n lists must be keyed");
             }
+            insert_list(prefix, items, catalog)
         }
-        _ => panic!("unsupported i18n value type"),
+        (None, _) => Err("values must be keyed".to_string()),
+        (Some(prefix), _) => Err(format!("unsupported value type: {prefix}")),
     }
 }
 
-fn insert_string(key: &str, value: &str, catalog: &mut Catalog) {
+fn insert_string(key: &str, value: &str, catalog: &mut Catalog) -> Result<(), String> {
     let previous = catalog.strings.insert(key.to_string(), value.to_string());
     if previous.is_some() {
-        panic!("duplicate i18n key: {key}");
+        return Err(format!("duplicate key: {key}"));
     }
+    Ok(())
 }
 
-fn insert_list(key: &str, value: Vec<String>, catalog: &mut Catalog) {
+fn insert_list(key: &str, value: Vec<String>, catalog: &mut Catalog) -> Result<(), String> {
     let previous = catalog.lists.insert(key.to_string(), value);
     if previous.is_some() {
-        panic!("duplicate i18n list key: {key}");
+        return Err(format!("duplicate list key: {key}"));
     }
+    Ok(())
 }
 
 fn interpolate(template: &str, args: &[(&str, &str)]) -> String {
@@ -171,6 +353,68 @@ mod tests {
         assert_eq!(en_keys, zh_keys);
     }
 
+    #[test]
+    fn check_catalog_reports_missing_extra_and_placeholder_mismatches() {
+        let report = check_catalog(
+            r#"
+[meta]
+name = "日本語"
+
+[chatwidget.language_popup]
+title = "言語"
+
+[app]
+not_a_key = "x"
+
+[history.session_header]
+directory_label = "${oops}"
+"#,
+        )
+        .expect("catalog parses");
+
+        assert!(
+            report
+                .missing
+                .contains(&"slash_command.description.approvals".to_string())
+        );
+        assert!(
+            !report
+                .missing
+                .contains(&"chatwidget.language_popup.title".to_string())
+        );
+        assert_eq!(report.extra, vec!["app.not_a_key".to_string()]);
+        assert_eq!(
+            report.placeholder_mismatches,
+            vec!["history.session_header.directory_label".to_string()]
+        );
+    }
+
+    #[test]
+    fn runtime_catalogs_fall_back_through_parent_locales() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        std::fs::write(
+            dir.path().join("zh-TW.toml"),
+            "[meta]\nname = \"繁體中文\"\n\n[chatwidget.language_popup]\ntitle = \"選擇語言\"\n",
+        )
+        .expect("write catalog");
+        let catalogs = load_runtime_catalogs(dir.path(), &mut Vec::new());
+        let zh_tw = Language::parse("zh-tw").expect("zh-tw parses");
+        let catalog = catalogs.get(&zh_tw).expect("zh-tw catalog loaded");
+
+        assert_eq!(catalog.name.as_deref(), Some("繁體中文"));
+        assert_eq!(
+            catalog
+                .strings
+                .get("chatwidget.language_popup.title")
+                .map(String::as_str),
+            Some("選擇語言")
+        );
+        assert_eq!(
+            fallback_chain(zh_tw).collect::<Vec<_>>(),
+            vec![zh_tw, Language::ZhCn, Language::En]
+        );
+    }
+
     #[test]
     fn interpolate_replaces_placeholders() {
         let rendered = interpolate("Hello ${name}", &[("name", "World")]);
@@ -42,6 +42,7 @@ pub mod landlock;
 pub mod mcp;
 mod mcp_connection_manager;
 pub mod models_manager;
+mod network_approval;
 pub mod network_proxy_loader;
 pub use mcp_connection_manager::MCP_SANDBOX_STATE_CAPABILITY;
 pub use mcp_connection_manager::MCP_SANDBOX_STATE_METHOD;
@@ -57,6 +58,7 @@ mod proposed_plan_parser;
 mod sandbox_tags;
 pub mod sandboxing;
 mod session_prefix;
+mod session_secrets;
 mod shell_detect;
 mod stream_events_utils;
 mod tagged_block_parser;
@@ -102,9 +104,11 @@ pub mod skills;
 pub mod spawn;
 pub mod state_db;
 pub mod terminal;
+pub mod thread_export;
 mod tools;
 pub mod turn_diff_tracker;
 mod turn_metadata;
+pub use codex_state::ThreadSearchKind;
 pub use rollout::ARCHIVED_SESSIONS_SUBDIR;
 pub use rollout::INTERACTIVE_SESSION_SOURCES;
 pub use rollout::RolloutRecorder;
@@ -119,6 +123,7 @@ pub use rollout::find_thread_path_by_id_str;
 pub use rollout::find_thread_path_by_name_str;
 pub use rollout::list::Cursor;
 pub use rollout::list::ThreadItem;
+pub use rollout::list::ThreadSearchMatch;
 pub use rollout::list::ThreadSortKey;
 pub use rollout::list::ThreadsPage;
 pub use rollout::list::parse_cursor;
@@ -140,8 +145,12 @@ pub use codex_shell_command::powershell;
 pub use apply_patch::CODEX_APPLY_PATCH_ARG1;
 pub use client::X_CODEX_TURN_METADATA_HEADER;
 pub use exec_policy::ExecPolicyError;
+pub use exec_policy::UnmatchedCommandDecision;
 pub use exec_policy::check_execpolicy_for_warnings;
+pub use exec_policy::exec_policy_commands;
+pub use exec_policy::explain_decision_for_unmatched_command;
 pub use exec_policy::load_exec_policy;
+pub use exec_policy::load_exec_policy_files;
 pub use file_watcher::FileWatcherEvent;
 pub use safety::get_platform_sandbox;
 pub use tools::spec::parse_tool_input_schema;
@@ -26,14 +26,26 @@ pub enum McpOAuthLoginSupport {
 }
 
 pub async fn oauth_login_support(transport: &McpServerTransportConfig) -> McpOAuthLoginSupport {
-    let McpServerTransportConfig::StreamableHttp {
-        url,
-        bearer_token_env_var,
-        http_headers,
-        env_http_headers,
-    } = transport
-    else {
-        return McpOAuthLoginSupport::Unsupported;
+    let (url, bearer_token_env_var, http_headers, env_http_headers) = match transport {
+        McpServerTransportConfig::Stdio { .. } => return McpOAuthLoginSupport::Unsupported,
+        McpServerTransportConfig::StreamableHttp {
+            url,
+            bearer_token_env_var,
+            http_headers,
+            env_http_headers,
+        }
+        | McpServerTransportConfig::Sse {
+            url,
+            bearer_token_env_var,
+            http_headers,
+            env_http_headers,
+        }
+        | McpServerTransportConfig::WebSocket {
+            url,
+            bearer_token_env_var,
+            http_headers,
+            env_http_headers,
+        } => (url, bearer_token_env_var, http_headers, env_http_headers),
     };
 
     if bearer_token_env_var.is_some() {
@@ -98,6 +110,18 @@ async fn compute_auth_status(
             bearer_token_env_var,
             http_headers,
             env_http_headers,
+        }
+        | McpServerTransportConfig::Sse {
+            url,
+            bearer_token_env_var,
+            http_headers,
+            env_http_headers,
+        }
+        | McpServerTransportConfig::WebSocket {
+            url,
+            bearer_token_env_var,
+            http_headers,
+            env_http_headers,
         } => {
             determine_streamable_http_auth_status(
                 server_name,
@@ -1,6 +1,13 @@
 pub mod auth;
+mod sampling;
 mod skill_dependencies;
+mod tool_approval;
+pub(crate) use sampling::SamplingRateLimiter;
+pub(crate) use sampling::run_mcp_sampling_request;
+pub(crate) use sampling::sampling_request_event;
 pub(crate) use skill_dependencies::maybe_prompt_and_install_mcp_dependencies;
+pub(crate) use tool_approval::McpToolPolicyDecision;
+pub(crate) use tool_approval::evaluate_mcp_tool_policy;
 
 use std::collections::HashMap;
 use std::env;
@@ -8,9 +15,12 @@ use std::path::PathBuf;
 use std::time::Duration;
 
 use async_channel::unbounded;
+use codex_protocol::mcp::GetPromptResult;
+use codex_protocol::mcp::Prompt;
 use codex_protocol::mcp::Resource;
 use codex_protocol::mcp::ResourceTemplate;
 use codex_protocol::mcp::Tool;
+use codex_protocol::protocol::ListMcpPromptsResponseEvent;
 use codex_protocol::protocol::McpListToolsResponseEvent;
 use codex_protocol::protocol::SandboxPolicy;
 use serde_json::Value;
@@ -106,6 +116,8 @@ fn codex_apps_mcp_server_config(config: &Config, auth: Option<&CodexAuth>) -> Mc
         enabled_tools: None,
         disabled_tools: None,
         scopes: None,
+        sampling: None,
+        approval: None,
     }
 }
 
@@ -330,6 +342,66 @@ pub(crate) async fn collect_mcp_snapshot_from_manager(
     }
 }
 
+pub(crate) async fn collect_mcp_prompts_from_manager(
+    mcp_connection_manager: &McpConnectionManager,
+) -> ListMcpPromptsResponseEvent {
+    let prompts = mcp_connection_manager
+        .list_all_prompts()
+        .await
+        .into_iter()
+        .map(|(server, prompts)| {
+            let prompts = prompts
+                .into_iter()
+                .filter_map(|prompt| {
+                    let prompt_name = prompt.name.clone();
+                    match serde_json::to_value(prompt).and_then(Prompt::from_mcp_value) {
+                        Ok(prompt) => Some(prompt),
+                        Err(err) => {
+                            tracing::warn!(
+                                "Failed to convert MCP prompt (server={server}, name={prompt_name}): {err}"
+                            );
+                            None
+                        }
+                    }
+                })
+                .collect::<Vec<_>>();
+            (server, prompts)
+        })
+        .collect();
+
+    ListMcpPromptsResponseEvent { prompts }
+}
+
+/// Fetches `name` from `server` and converts the returned messages into the
+/// protocol representation.
+pub(crate) async fn fetch_mcp_prompt(
+    mcp_connection_manager: &McpConnectionManager,
+    server: &str,
+    name: &str,
+    arguments: HashMap<String, String>,
+) -> Result<GetPromptResult, String> {
+    let arguments = (!arguments.is_empty()).then(|| {
+        arguments
+            .into_iter()
+            .map(|(key, value)| (key, Value::String(value)))
+            .collect()
+    });
+    let result = mcp_connection_manager
+        .get_prompt(
+            server,
+            rmcp::model::GetPromptRequestParams {
+                meta: None,
+                name: name.to_string(),
+                arguments,
+            },
+        )
+        .await
+        .map_err(|err| format!("{err:#}"))?;
+    serde_json::to_value(result)
+        .and_then(GetPromptResult::from_mcp_value)
+        .map_err(|err| format!("invalid prompts/get response from `{server}`: {err}"))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
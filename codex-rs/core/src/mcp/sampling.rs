@@ -0,0 +1,311 @@
+//! MCP sampling: servers asking Codex to run a completion through
+//! `sampling/createMessage`.
+//!
+//! [`crate::mcp_connection_manager::McpConnectionManager`] converts each
+//! request into a [`SamplingRequestEvent`], applies the server's `sampling`
+//! limits and waits for the user's decision. Approved requests are sent to the
+//! session's model by [`run_mcp_sampling_request`]; neither the request nor the
+//! completion is recorded in the conversation history.
+
+use std::collections::VecDeque;
+use std::time::Duration;
+use std::time::Instant;
+
+use anyhow::Context;
+use anyhow::Result;
+use anyhow::anyhow;
+use codex_protocol::approvals::SamplingMessage;
+use codex_protocol::approvals::SamplingRequestEvent;
+use codex_protocol::mcp::RequestId as ProtocolRequestId;
+use codex_protocol::models::BaseInstructions;
+use codex_protocol::models::ContentItem;
+use codex_protocol::models::ResponseItem;
+use codex_rmcp_client::Sampling;
+use codex_rmcp_client::SamplingResponse;
+use futures::prelude::*;
+use serde_json::Value;
+use serde_json::json;
+
+use crate::Prompt;
+use crate::client_common::ResponseEvent;
+use crate::codex::Session;
+use crate::codex::get_last_assistant_message_from_turn;
+
+const RATE_LIMIT_WINDOW: Duration = Duration::from_secs(60);
+
+/// Used when the server does not send a system prompt.
+const DEFAULT_SAMPLING_INSTRUCTIONS: &str = "You are a helpful assistant.";
+
+/// Sliding one-minute window over the sampling requests of a single server.
+#[derive(Debug, Default)]
+pub(crate) struct SamplingRateLimiter {
+    max_requests_per_minute: Option<u32>,
+    recent: VecDeque<Instant>,
+}
+
+impl SamplingRateLimiter {
+    pub(crate) fn new(max_requests_per_minute: Option<u32>) -> Self {
+        Self {
+            max_requests_per_minute,
+            recent: VecDeque::new(),
+        }
+    }
+
+    /// Records a request made at `now`, or returns `false` when the server has
+    /// already used up its budget for the current window.
+    pub(crate) fn try_acquire(&mut self, now: Instant) -> bool {
+        let Some(limit) = self.max_requests_per_minute else {
+            return true;
+        };
+        while self
+            .recent
+            .front()
+            .is_some_and(|oldest| now.duration_since(*oldest) >= RATE_LIMIT_WINDOW)
+        {
+            self.recent.pop_front();
+        }
+        if self.recent.len() >= limit as usize {
+            return false;
+        }
+        self.recent.push_back(now);
+        true
+    }
+}
+
+/// Builds the event shown to the user, capping `maxTokens` at `max_tokens_cap`.
+///
+/// Only text content is supported; requests carrying images or audio are
+/// rejected so the server gets an explicit error instead of a partial prompt.
+pub(crate) fn sampling_request_event(
+    server_name: String,
+    id: ProtocolRequestId,
+    request: &Sampling,
+    max_tokens_cap: Option<u32>,
+) -> Result<SamplingRequestEvent> {
+    let request = serde_json::to_value(request)?;
+    let messages = request
+        .get("messages")
+        .and_then(Value::as_array)
+        .context("sampling request has no messages")?
+        .iter()
+        .map(sampling_message)
+        .collect::<Result<Vec<_>>>()?;
+    let requested_max_tokens = request
+        .get("maxTokens")
+        .and_then(Value::as_u64)
+        .context("sampling request has no maxTokens")?;
+    let requested_max_tokens = u32::try_from(requested_max_tokens).unwrap_or(u32::MAX);
+    let max_tokens =
+        max_tokens_cap.map_or(requested_max_tokens, |cap| requested_max_tokens.min(cap));
+    Ok(SamplingRequestEvent {
+        server_name,
+        id,
+        messages,
+        system_prompt: request
+            .get("systemPrompt")
+            .and_then(Value::as_str)
+            .filter(|prompt| !prompt.is_empty())
+            .map(str::to_string),
+        max_tokens,
+    })
+}
+
+fn sampling_message(message: &Value) -> Result<SamplingMessage> {
+    let role = match message.get("role").and_then(Value::as_str) {
+        Some("assistant") => "assistant",
+        _ => "user",
+    };
+    let blocks = match message.get("content") {
+        Some(Value::Array(blocks)) => blocks.iter().collect::<Vec<_>>(),
+        Some(block) => vec![block],
+        None => Vec::new(),
+    };
+    let mut text = Vec::new();
+    for block in blocks {
+        match block.get("type").and_then(Value::as_str) {
+            Some("text") => {
+                if let Some(block_text) = block.get("text").and_then(Value::as_str) {
+                    text.push(block_text);
+                }
+            }
+            other => {
+                return Err(anyhow!(
+                    "sampling requests with {} content are not supported",
+                    other.unwrap_or("unknown")
+                ));
+            }
+        }
+    }
+    Ok(SamplingMessage {
+        role: role.to_string(),
+        text: text.join("\n\n"),
+    })
+}
+
+/// Runs an approved sampling request with the session's current model.
+pub(crate) async fn run_mcp_sampling_request(
+    sess: &Session,
+    request: &SamplingRequestEvent,
+) -> Result<SamplingResponse> {
+    let turn_context = sess.new_default_turn().await;
+    let input = request
+        .messages
+        .iter()
+        .map(|message| {
+            let content = if message.role == "assistant" {
+                ContentItem::OutputText {
+                    text: message.text.clone(),
+                }
+            } else {
+                ContentItem::InputText {
+                    text: message.text.clone(),
+                }
+            };
+            ResponseItem::Message {
+                id: None,
+                role: message.role.clone(),
+                content: vec![content],
+                end_turn: None,
+                phase: None,
+            }
+        })
+        .collect();
+    let prompt = Prompt {
+        input,
+        base_instructions: BaseInstructions {
+            text: request
+                .system_prompt
+                .clone()
+                .unwrap_or_else(|| DEFAULT_SAMPLING_INSTRUCTIONS.to_string()),
+        },
+        max_output_tokens: Some(request.max_tokens),
+        ..Default::default()
+    };
+
+    let turn_metadata_header = turn_context.resolve_turn_metadata_header().await;
+    let mut client_session = sess.services.model_client.new_session();
+    let mut stream = client_session
+        .stream(
+            &prompt,
+            &turn_context.model_info,
+            &turn_context.otel_manager,
+            turn_context.reasoning_effort,
+            turn_context.reasoning_summary,
+            turn_metadata_header.as_deref(),
+        )
+        .await?;
+    let mut output = Vec::new();
+    loop {
+        match stream.next().await {
+            Some(Ok(ResponseEvent::OutputItemDone(item))) => output.push(item),
+            Some(Ok(ResponseEvent::RateLimits(snapshot))) => {
+                sess.update_rate_limits(&turn_context, snapshot).await;
+            }
+            Some(Ok(ResponseEvent::Completed { token_usage, .. })) => {
+                sess.add_to_total_token_usage(&turn_context, token_usage.as_ref())
+                    .await;
+                break;
+            }
+            Some(Ok(_)) => {}
+            Some(Err(err)) => return Err(err.into()),
+            None => return Err(anyhow!("stream closed before response.completed")),
+        }
+    }
+
+    let text = get_last_assistant_message_from_turn(&output).unwrap_or_default();
+    sampling_response(text, &turn_context.model_info.slug)
+}
+
+fn sampling_response(text: String, model: &str) -> Result<SamplingResponse> {
+    Ok(serde_json::from_value(json!({
+        "role": "assistant",
+        "content": { "type": "text", "text": text },
+        "model": model,
+        "stopReason": "endTurn",
+    }))?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn sampling(value: Value) -> Sampling {
+        serde_json::from_value(value).expect("valid sampling request")
+    }
+
+    #[test]
+    fn request_event_caps_max_tokens_and_keeps_text() {
+        let request = sampling(json!({
+            "messages": [
+                { "role": "user", "content": { "type": "text", "text": "Review this diff." } },
+                { "role": "assistant", "content": { "type": "text", "text": "Which file?" } },
+            ],
+            "systemPrompt": "You are a reviewer.",
+            "maxTokens": 8_000,
+        }));
+
+        let event = sampling_request_event(
+            "review-bot".to_string(),
+            ProtocolRequestId::Integer(7),
+            &request,
+            Some(1_000),
+        )
+        .expect("event");
+
+        assert_eq!(
+            event,
+            SamplingRequestEvent {
+                server_name: "review-bot".to_string(),
+                id: ProtocolRequestId::Integer(7),
+                messages: vec![
+                    SamplingMessage {
+                        role: "user".to_string(),
+                        text: "Review this diff.".to_string(),
+                    },
+                    SamplingMessage {
+                        role: "assistant".to_string(),
+                        text: "Which file?".to_string(),
+                    },
+                ],
+                system_prompt: Some("You are a reviewer.".to_string()),
+                max_tokens: 1_000,
+            }
+        );
+    }
+
+    #[test]
+    fn request_event_rejects_image_content() {
+        let request = sampling(json!({
+            "messages": [{
+                "role": "user",
+                "content": { "type": "image", "data": "AAAA", "mimeType": "image/png" },
+            }],
+            "maxTokens": 100,
+        }));
+
+        let err = sampling_request_event(
+            "review-bot".to_string(),
+            ProtocolRequestId::Integer(1),
+            &request,
+            None,
+        )
+        .expect_err("images are not supported");
+
+        assert_eq!(
+            err.to_string(),
+            "sampling requests with image content are not supported"
+        );
+    }
+
+    #[test]
+    fn rate_limiter_frees_slots_after_a_minute() {
+        let mut limiter = SamplingRateLimiter::new(Some(2));
+        let start = Instant::now();
+
+        assert!(limiter.try_acquire(start));
+        assert!(limiter.try_acquire(start + Duration::from_secs(10)));
+        assert!(!limiter.try_acquire(start + Duration::from_secs(30)));
+        assert!(limiter.try_acquire(start + Duration::from_secs(61)));
+    }
+}
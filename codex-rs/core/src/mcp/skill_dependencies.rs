@@ -21,6 +21,7 @@ use crate::config::edit::ConfigEditsBuilder;
 use crate::config::load_global_mcp_servers;
 use crate::config::types::McpServerConfig;
 use crate::config::types::McpServerTransportConfig;
+use crate::config::types::McpServerUrlTransport;
 use crate::default_client::is_first_party_originator;
 use crate::default_client::originator;
 use crate::features::Feature;
@@ -283,17 +284,21 @@ fn canonical_mcp_server_key(name: &str, config: &McpServerConfig) -> String {
         McpServerTransportConfig::StreamableHttp { url, .. } => {
             canonical_mcp_key("streamable_http", url, name)
         }
+        McpServerTransportConfig::Sse { url, .. } => canonical_mcp_key("sse", url, name),
+        McpServerTransportConfig::WebSocket { url, .. } => {
+            canonical_mcp_key("websocket", url, name)
+        }
     }
 }
 
 fn canonical_mcp_dependency_key(dependency: &SkillToolDependency) -> Result<String, String> {
     let transport = dependency.transport.as_deref().unwrap_or("streamable_http");
-    if transport.eq_ignore_ascii_case("streamable_http") {
+    if let Some(kind) = McpServerUrlTransport::parse(transport) {
         let url = dependency
             .url
             .as_ref()
-            .ok_or_else(|| "missing url for streamable_http dependency".to_string())?;
-        return Ok(canonical_mcp_key("streamable_http", url, &dependency.value));
+            .ok_or_else(|| format!("missing url for {} dependency", kind.as_str()))?;
+        return Ok(canonical_mcp_key(kind.as_str(), url, &dependency.value));
     }
     if transport.eq_ignore_ascii_case("stdio") {
         let command = dependency
@@ -366,18 +371,13 @@ fn mcp_dependency_to_server_config(
     dependency: &SkillToolDependency,
 ) -> Result<McpServerConfig, String> {
     let transport = dependency.transport.as_deref().unwrap_or("streamable_http");
-    if transport.eq_ignore_ascii_case("streamable_http") {
+    if let Some(kind) = McpServerUrlTransport::parse(transport) {
         let url = dependency
             .url
             .as_ref()
-            .ok_or_else(|| "missing url for streamable_http dependency".to_string())?;
+            .ok_or_else(|| format!("missing url for {} dependency", kind.as_str()))?;
         return Ok(McpServerConfig {
-            transport: McpServerTransportConfig::StreamableHttp {
-                url: url.clone(),
-                bearer_token_env_var: None,
-                http_headers: None,
-                env_http_headers: None,
-            },
+            transport: McpServerTransportConfig::from_url(kind, url.clone()),
             enabled: true,
             required: false,
             disabled_reason: None,
@@ -386,6 +386,8 @@ fn mcp_dependency_to_server_config(
             enabled_tools: None,
             disabled_tools: None,
             scopes: None,
+            sampling: None,
+            approval: None,
         });
     }
 
@@ -410,6 +412,8 @@ fn mcp_dependency_to_server_config(
             enabled_tools: None,
             disabled_tools: None,
             scopes: None,
+            sampling: None,
+            approval: None,
         });
     }
 
@@ -465,6 +469,8 @@ mod tests {
                 enabled_tools: None,
                 disabled_tools: None,
                 scopes: None,
+                sampling: None,
+                approval: None,
             },
         )]);
 
@@ -513,6 +519,8 @@ mod tests {
                 enabled_tools: None,
                 disabled_tools: None,
                 scopes: None,
+                sampling: None,
+                approval: None,
             },
         )]);
 
@@ -521,4 +529,48 @@ mod tests {
             expected
         );
     }
+
+    #[test]
+    fn collect_missing_installs_sse_dependency_distinct_from_streamable_http() {
+        let url = "https://example.com/sse".to_string();
+        let skills = vec![skill_with_tools(vec![SkillToolDependency {
+            r#type: "mcp".to_string(),
+            value: "vendor".to_string(),
+            description: None,
+            transport: Some("sse".to_string()),
+            command: None,
+            url: Some(url.clone()),
+        }])];
+        let server = |transport| McpServerConfig {
+            transport,
+            enabled: true,
+            required: false,
+            disabled_reason: None,
+            startup_timeout_sec: None,
+            tool_timeout_sec: None,
+            enabled_tools: None,
+            disabled_tools: None,
+            scopes: None,
+            sampling: None,
+            approval: None,
+        };
+        let installed = HashMap::from([(
+            "vendor-http".to_string(),
+            server(McpServerTransportConfig::from_url(
+                McpServerUrlTransport::StreamableHttp,
+                url.clone(),
+            )),
+        )]);
+
+        assert_eq!(
+            collect_missing_mcp_dependencies(&skills, &installed),
+            HashMap::from([(
+                "vendor".to_string(),
+                server(McpServerTransportConfig::from_url(
+                    McpServerUrlTransport::Sse,
+                    url
+                )),
+            )])
+        );
+    }
 }
@@ -0,0 +1,187 @@
+//! Configured approval policy for MCP tool calls, read from
+//! `[mcp_servers.<name>.approval]`.
+//!
+//! `allow` rules on a tool are checked first; a call whose arguments match one
+//! runs without asking. Otherwise the tool's `policy` applies, falling back to
+//! the server's `policy`. When neither is set the server has no opinion and
+//! the caller keeps its default behavior.
+
+use std::collections::BTreeMap;
+
+use codex_protocol::protocol::McpToolCallApprovalSource;
+use serde_json::Value;
+
+use crate::config::types::McpServerApprovalConfig;
+use crate::config::types::McpToolApprovalPolicy;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum McpToolPolicyDecision {
+    /// Run without asking. The source tells a policy from an `allow` rule.
+    Allow(McpToolCallApprovalSource),
+    Prompt,
+    Deny,
+}
+
+pub(crate) fn evaluate_mcp_tool_policy(
+    approval: &McpServerApprovalConfig,
+    tool_name: &str,
+    arguments: Option<&Value>,
+) -> Option<McpToolPolicyDecision> {
+    let tool = approval.tools.get(tool_name);
+    if let Some(tool) = tool
+        && tool.allow.iter().any(|rule| rule_matches(rule, arguments))
+    {
+        return Some(McpToolPolicyDecision::Allow(
+            McpToolCallApprovalSource::ArgumentRule,
+        ));
+    }
+
+    let policy = tool.and_then(|tool| tool.policy).or(approval.policy)?;
+    Some(match policy {
+        McpToolApprovalPolicy::Always => {
+            McpToolPolicyDecision::Allow(McpToolCallApprovalSource::Policy)
+        }
+        McpToolApprovalPolicy::Prompt => McpToolPolicyDecision::Prompt,
+        McpToolApprovalPolicy::Never => McpToolPolicyDecision::Deny,
+    })
+}
+
+fn rule_matches(rule: &BTreeMap<String, Value>, arguments: Option<&Value>) -> bool {
+    rule.iter().all(|(path, expected)| {
+        lookup_argument(arguments, path).is_some_and(|actual| values_match(actual, expected))
+    })
+}
+
+/// Follows a dotted path such as `fields.project` or `labels.0` into the
+/// arguments.
+fn lookup_argument<'a>(arguments: Option<&'a Value>, path: &str) -> Option<&'a Value> {
+    path.split('.')
+        .try_fold(arguments?, |value, segment| match value {
+            Value::Object(fields) => fields.get(segment),
+            Value::Array(items) => segment
+                .parse::<usize>()
+                .ok()
+                .and_then(|index| items.get(index)),
+            _ => None,
+        })
+}
+
+/// Numbers compare by value so that `3` in config matches `3.0` from the model.
+fn values_match(actual: &Value, expected: &Value) -> bool {
+    match (actual, expected) {
+        (Value::Number(actual), Value::Number(expected)) => actual.as_f64() == expected.as_f64(),
+        _ => actual == expected,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::types::McpToolApprovalConfig;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    fn approval(
+        server_policy: Option<McpToolApprovalPolicy>,
+        tool_policy: Option<McpToolApprovalPolicy>,
+        allow: Vec<Value>,
+    ) -> McpServerApprovalConfig {
+        let allow = allow
+            .into_iter()
+            .map(|rule| serde_json::from_value(rule).expect("rule is an object"))
+            .collect();
+        McpServerApprovalConfig {
+            policy: server_policy,
+            tools: BTreeMap::from([(
+                "create_issue".to_string(),
+                McpToolApprovalConfig {
+                    policy: tool_policy,
+                    allow,
+                },
+            )]),
+        }
+    }
+
+    #[test]
+    fn allow_rule_overrides_never_policy_only_when_arguments_match() {
+        let config = approval(
+            None,
+            Some(McpToolApprovalPolicy::Never),
+            vec![json!({ "project": "SANDBOX" })],
+        );
+
+        assert_eq!(
+            evaluate_mcp_tool_policy(
+                &config,
+                "create_issue",
+                Some(&json!({ "project": "SANDBOX", "summary": "test" })),
+            ),
+            Some(McpToolPolicyDecision::Allow(
+                McpToolCallApprovalSource::ArgumentRule
+            ))
+        );
+        assert_eq!(
+            evaluate_mcp_tool_policy(&config, "create_issue", Some(&json!({ "project": "PROD" }))),
+            Some(McpToolPolicyDecision::Deny)
+        );
+        assert_eq!(
+            evaluate_mcp_tool_policy(&config, "create_issue", None),
+            Some(McpToolPolicyDecision::Deny)
+        );
+    }
+
+    #[test]
+    fn matchers_follow_dotted_paths_and_compare_numbers_by_value() {
+        let config = approval(
+            None,
+            Some(McpToolApprovalPolicy::Prompt),
+            vec![json!({ "fields.project.key": "SANDBOX", "labels.0": "bot", "priority": 3 })],
+        );
+        let arguments = json!({
+            "fields": { "project": { "key": "SANDBOX" } },
+            "labels": ["bot", "triage"],
+            "priority": 3.0,
+        });
+
+        assert_eq!(
+            evaluate_mcp_tool_policy(&config, "create_issue", Some(&arguments)),
+            Some(McpToolPolicyDecision::Allow(
+                McpToolCallApprovalSource::ArgumentRule
+            ))
+        );
+        assert_eq!(
+            evaluate_mcp_tool_policy(
+                &config,
+                "create_issue",
+                Some(
+                    &json!({ "fields": { "project": "SANDBOX" }, "labels": ["bot"], "priority": 3 })
+                ),
+            ),
+            Some(McpToolPolicyDecision::Prompt)
+        );
+    }
+
+    #[test]
+    fn tool_policy_falls_back_to_server_policy() {
+        let config = approval(
+            Some(McpToolApprovalPolicy::Prompt),
+            Some(McpToolApprovalPolicy::Always),
+            Vec::new(),
+        );
+
+        assert_eq!(
+            evaluate_mcp_tool_policy(&config, "create_issue", None),
+            Some(McpToolPolicyDecision::Allow(
+                McpToolCallApprovalSource::Policy
+            ))
+        );
+        assert_eq!(
+            evaluate_mcp_tool_policy(&config, "delete_issue", None),
+            Some(McpToolPolicyDecision::Prompt)
+        );
+        assert_eq!(
+            evaluate_mcp_tool_policy(&approval(None, None, Vec::new()), "create_issue", None),
+            None
+        );
+    }
+}
@@ -18,7 +18,9 @@ use std::time::Duration;
 use std::time::Instant;
 
 use crate::mcp::CODEX_APPS_MCP_SERVER_NAME;
+use crate::mcp::SamplingRateLimiter;
 use crate::mcp::auth::McpAuthStatusEntry;
+use crate::mcp::sampling_request_event;
 use anyhow::Context;
 use anyhow::Result;
 use anyhow::anyhow;
@@ -26,6 +28,7 @@ use async_channel::Sender;
 use codex_async_utils::CancelErr;
 use codex_async_utils::OrCancelExt;
 use codex_protocol::approvals::ElicitationRequestEvent;
+use codex_protocol::approvals::SamplingRequestEvent;
 use codex_protocol::mcp::CallToolResult;
 use codex_protocol::mcp::RequestId as ProtocolRequestId;
 use codex_protocol::protocol::Event;
@@ -38,17 +41,22 @@ use codex_protocol::protocol::SandboxPolicy;
 use codex_rmcp_client::ElicitationResponse;
 use codex_rmcp_client::OAuthCredentialsStoreMode;
 use codex_rmcp_client::RmcpClient;
+use codex_rmcp_client::SamplingResponse;
 use codex_rmcp_client::SendElicitation;
+use codex_rmcp_client::SendSampling;
 use futures::future::BoxFuture;
 use futures::future::FutureExt;
 use futures::future::Shared;
 use rmcp::model::ClientCapabilities;
 use rmcp::model::ElicitationCapability;
+use rmcp::model::GetPromptRequestParams;
+use rmcp::model::GetPromptResult;
 use rmcp::model::Implementation;
 use rmcp::model::InitializeRequestParams;
 use rmcp::model::ListResourceTemplatesResult;
 use rmcp::model::ListResourcesResult;
 use rmcp::model::PaginatedRequestParams;
+use rmcp::model::Prompt;
 use rmcp::model::ProtocolVersion;
 use rmcp::model::ReadResourceRequestParams;
 use rmcp::model::ReadResourceResult;
@@ -70,6 +78,7 @@ use tracing::warn;
 
 use crate::codex::INITIAL_SUBMIT_ID;
 use crate::config::types::McpServerConfig;
+use crate::config::types::McpServerSamplingConfig;
 use crate::config::types::McpServerTransportConfig;
 
 /// Delimiter used to separate the server name from the tool name in a fully
@@ -215,14 +224,7 @@ impl ElicitationRequestManager {
                         id: "mcp_elicitation_request".to_string(),
                         msg: EventMsg::ElicitationRequest(ElicitationRequestEvent {
                             server_name,
-                            id: match id.clone() {
-                                rmcp::model::NumberOrString::String(value) => {
-                                    ProtocolRequestId::String(value.to_string())
-                                }
-                                rmcp::model::NumberOrString::Number(value) => {
-                                    ProtocolRequestId::Integer(value)
-                                }
-                            },
+                            id: protocol_request_id(&id),
                             message: elicitation.message,
                         }),
                     })
@@ -235,6 +237,102 @@ impl ElicitationRequestManager {
     }
 }
 
+fn protocol_request_id(id: &RequestId) -> ProtocolRequestId {
+    match id {
+        rmcp::model::NumberOrString::String(value) => ProtocolRequestId::String(value.to_string()),
+        rmcp::model::NumberOrString::Number(value) => ProtocolRequestId::Integer(*value),
+    }
+}
+
+/// A sampling request waiting for the user's decision.
+pub(crate) struct PendingSampling {
+    pub(crate) request: SamplingRequestEvent,
+    responder: oneshot::Sender<Result<SamplingResponse, String>>,
+}
+
+impl PendingSampling {
+    /// Completes the server's `sampling/createMessage` call; an `Err` is
+    /// reported to the server as a request failure.
+    pub(crate) fn respond(self, result: Result<SamplingResponse, String>) {
+        // The server may have given up on the request already.
+        let _ = self.responder.send(result);
+    }
+}
+
+#[derive(Clone, Default)]
+struct SamplingRequestManager {
+    requests: Arc<Mutex<HashMap<(String, RequestId), PendingSampling>>>,
+}
+
+impl SamplingRequestManager {
+    async fn take(&self, server_name: String, id: RequestId) -> Result<PendingSampling> {
+        self.requests
+            .lock()
+            .await
+            .remove(&(server_name, id))
+            .ok_or_else(|| anyhow!("sampling request not found"))
+    }
+
+    /// Returns `None` when sampling is disabled for the server, in which case
+    /// the capability is not advertised.
+    fn make_sender(
+        &self,
+        server_name: String,
+        config: Option<McpServerSamplingConfig>,
+        tx_event: Sender<Event>,
+    ) -> Option<SendSampling> {
+        let (max_tokens, max_requests_per_minute) = match config {
+            Some(config) if !config.enabled => return None,
+            Some(config) => (config.max_tokens, config.max_requests_per_minute),
+            None => (None, None),
+        };
+        let sampling_requests = self.requests.clone();
+        let rate_limiter = Arc::new(Mutex::new(SamplingRateLimiter::new(
+            max_requests_per_minute,
+        )));
+        Some(Box::new(move |id, sampling| {
+            let sampling_requests = sampling_requests.clone();
+            let rate_limiter = Arc::clone(&rate_limiter);
+            let tx_event = tx_event.clone();
+            let server_name = server_name.clone();
+            async move {
+                if !rate_limiter.lock().await.try_acquire(Instant::now()) {
+                    return Err(anyhow!(
+                        "sampling rate limit exceeded for MCP server '{server_name}'"
+                    ));
+                }
+                let request = sampling_request_event(
+                    server_name.clone(),
+                    protocol_request_id(&id),
+                    &sampling,
+                    max_tokens,
+                )?;
+                let (tx, rx) = oneshot::channel();
+                {
+                    let mut lock = sampling_requests.lock().await;
+                    lock.insert(
+                        (server_name, id),
+                        PendingSampling {
+                            request: request.clone(),
+                            responder: tx,
+                        },
+                    );
+                }
+                let _ = tx_event
+                    .send(Event {
+                        id: "mcp_sampling_request".to_string(),
+                        msg: EventMsg::SamplingRequest(request),
+                    })
+                    .await;
+                rx.await
+                    .context("sampling request channel closed unexpectedly")?
+                    .map_err(|message| anyhow!(message))
+            }
+            .boxed()
+        }))
+    }
+}
+
 #[derive(Clone)]
 struct ManagedClient {
     client: Arc<RmcpClient>,
@@ -242,6 +340,7 @@ struct ManagedClient {
     tool_filter: ToolFilter,
     tool_timeout: Option<Duration>,
     server_supports_sandbox_state_capability: bool,
+    server_supports_prompts: bool,
 }
 
 impl ManagedClient {
@@ -275,8 +374,15 @@ impl AsyncManagedClient {
         cancel_token: CancellationToken,
         tx_event: Sender<Event>,
         elicitation_requests: ElicitationRequestManager,
+        sampling_requests: SamplingRequestManager,
     ) -> Self {
         let tool_filter = ToolFilter::from_config(&config);
+        let send_sampling = sampling_requests.make_sender(
+            server_name.clone(),
+            config.sampling.clone(),
+            tx_event.clone(),
+        );
+        let send_elicitation = elicitation_requests.make_sender(server_name.clone(), tx_event);
         let fut = async move {
             if let Err(error) = validate_mcp_server_name(&server_name) {
                 return Err(error.into());
@@ -290,8 +396,8 @@ impl AsyncManagedClient {
                 config.startup_timeout_sec.or(Some(DEFAULT_STARTUP_TIMEOUT)),
                 config.tool_timeout_sec.unwrap_or(DEFAULT_TOOL_TIMEOUT),
                 tool_filter,
-                tx_event,
-                elicitation_requests,
+                send_elicitation,
+                send_sampling,
             )
             .or_cancel(&cancel_token)
             .await
@@ -336,6 +442,7 @@ pub struct SandboxState {
 pub(crate) struct McpConnectionManager {
     clients: HashMap<String, AsyncManagedClient>,
     elicitation_requests: ElicitationRequestManager,
+    sampling_requests: SamplingRequestManager,
 }
 
 impl McpConnectionManager {
@@ -354,6 +461,7 @@ impl McpConnectionManager {
         let mut clients = HashMap::new();
         let mut join_set = JoinSet::new();
         let elicitation_requests = ElicitationRequestManager::default();
+        let sampling_requests = SamplingRequestManager::default();
         let mcp_servers = mcp_servers.clone();
         for (server_name, cfg) in mcp_servers.into_iter().filter(|(_, cfg)| cfg.enabled) {
             let cancel_token = cancel_token.child_token();
@@ -372,6 +480,7 @@ impl McpConnectionManager {
                 cancel_token.clone(),
                 tx_event.clone(),
                 elicitation_requests.clone(),
+                sampling_requests.clone(),
             );
             clients.insert(server_name.clone(), async_managed_client.clone());
             let tx_event = tx_event.clone();
@@ -419,6 +528,7 @@ impl McpConnectionManager {
         }
         self.clients = clients;
         self.elicitation_requests = elicitation_requests.clone();
+        self.sampling_requests = sampling_requests;
         tokio::spawn(async move {
             let outcomes = join_set.join_all().await;
             let mut summary = McpStartupCompleteEvent::default();
@@ -463,6 +573,15 @@ impl McpConnectionManager {
             .await
     }
 
+    /// Removes a pending sampling request so it can be answered.
+    pub(crate) async fn take_sampling_request(
+        &self,
+        server_name: String,
+        id: RequestId,
+    ) -> Result<PendingSampling> {
+        self.sampling_requests.take(server_name, id).await
+    }
+
     pub(crate) async fn wait_for_server_ready(&self, server_name: &str, timeout: Duration) -> bool {
         let Some(async_managed_client) = self.clients.get(server_name) else {
             return false;
@@ -694,6 +813,74 @@ impl McpConnectionManager {
         aggregated
     }
 
+    /// Returns a single map that contains all prompts. Each key is the
+    /// server name and the value is a vector of prompts. Servers that do not
+    /// advertise the prompts capability are skipped.
+    pub async fn list_all_prompts(&self) -> HashMap<String, Vec<Prompt>> {
+        let mut join_set = JoinSet::new();
+
+        for (server_name, async_managed_client) in &self.clients {
+            let server_name = server_name.clone();
+            let Ok(managed_client) = async_managed_client.client().await else {
+                continue;
+            };
+            if !managed_client.server_supports_prompts {
+                continue;
+            }
+            let timeout = managed_client.tool_timeout;
+            let client = managed_client.client.clone();
+
+            join_set.spawn(async move {
+                let mut collected: Vec<Prompt> = Vec::new();
+                let mut cursor: Option<String> = None;
+
+                loop {
+                    let params = cursor.as_ref().map(|next| PaginatedRequestParams {
+                        meta: None,
+                        cursor: Some(next.clone()),
+                    });
+                    let response = match client.list_prompts(params, timeout).await {
+                        Ok(result) => result,
+                        Err(err) => return (server_name, Err(err)),
+                    };
+
+                    collected.extend(response.prompts);
+
+                    match response.next_cursor {
+                        Some(next) => {
+                            if cursor.as_ref() == Some(&next) {
+                                return (
+                                    server_name,
+                                    Err(anyhow!("prompts/list returned duplicate cursor")),
+                                );
+                            }
+                            cursor = Some(next);
+                        }
+                        None => return (server_name, Ok(collected)),
+                    }
+                }
+            });
+        }
+
+        let mut aggregated: HashMap<String, Vec<Prompt>> = HashMap::new();
+
+        while let Some(join_res) = join_set.join_next().await {
+            match join_res {
+                Ok((server_name, Ok(prompts))) => {
+                    aggregated.insert(server_name, prompts);
+                }
+                Ok((server_name, Err(err))) => {
+                    warn!("Failed to list prompts for MCP server '{server_name}': {err:#}");
+                }
+                Err(err) => {
+                    warn!("Task panic when listing prompts for MCP server: {err:#}");
+                }
+            }
+        }
+
+        aggregated
+    }
+
     /// Invoke the tool indicated by the (server, tool) pair.
     pub async fn call_tool(
         &self,
@@ -780,6 +967,23 @@ impl McpConnectionManager {
             .with_context(|| format!("resources/read failed for `{server}` ({uri})"))
     }
 
+    /// Fetch a prompt from the specified server.
+    pub async fn get_prompt(
+        &self,
+        server: &str,
+        params: GetPromptRequestParams,
+    ) -> Result<GetPromptResult> {
+        let managed = self.client_by_name(server).await?;
+        let client = managed.client.clone();
+        let timeout = managed.tool_timeout;
+        let name = params.name.clone();
+
+        client
+            .get_prompt(params, timeout)
+            .await
+            .with_context(|| format!("prompts/get failed for `{server}` ({name})"))
+    }
+
     pub async fn parse_tool_name(&self, tool_name: &str) -> Option<(String, String)> {
         self.list_all_tools()
             .await
@@ -978,15 +1182,24 @@ async fn start_server_task(
     startup_timeout: Option<Duration>, // TODO: cancel_token should handle this.
     tool_timeout: Duration,
     tool_filter: ToolFilter,
-    tx_event: Sender<Event>,
-    elicitation_requests: ElicitationRequestManager,
+    send_elicitation: SendElicitation,
+    send_sampling: Option<SendSampling>,
 ) -> Result<ManagedClient, StartupOutcomeError> {
+    // Sampling is only advertised when requests can be answered.
+    let sampling = if send_sampling.is_some() {
+        ClientCapabilities::builder()
+            .enable_sampling()
+            .build()
+            .sampling
+    } else {
+        None
+    };
     let params = InitializeRequestParams {
         meta: None,
         capabilities: ClientCapabilities {
             experimental: None,
             roots: None,
-            sampling: None,
+            sampling,
             // https://modelcontextprotocol.io/specification/2025-06-18/client/elicitation#capabilities
             // indicates this should be an empty object.
             elicitation: Some(ElicitationCapability {
@@ -1004,10 +1217,8 @@ async fn start_server_task(
         protocol_version: ProtocolVersion::V_2025_06_18,
     };
 
-    let send_elicitation = elicitation_requests.make_sender(server_name.clone(), tx_event);
-
     let initialize_result = client
-        .initialize(params, startup_timeout, send_elicitation)
+        .initialize(params, startup_timeout, send_elicitation, send_sampling)
         .await
         .map_err(StartupOutcomeError::from)?;
 
@@ -1022,12 +1233,15 @@ async fn start_server_task(
         .and_then(|exp| exp.get(MCP_SANDBOX_STATE_CAPABILITY))
         .is_some();
 
+    let server_supports_prompts = initialize_result.capabilities.prompts.is_some();
+
     let managed = ManagedClient {
         client: Arc::clone(&client),
         tools,
         tool_timeout: Some(tool_timeout),
         tool_filter,
         server_supports_sandbox_state_capability,
+        server_supports_prompts,
     };
 
     Ok(managed)
@@ -1074,6 +1288,50 @@ async fn make_rmcp_client(
             .await
             .map_err(StartupOutcomeError::from)
         }
+        McpServerTransportConfig::Sse {
+            url,
+            http_headers,
+            env_http_headers,
+            bearer_token_env_var,
+        } => {
+            let resolved_bearer_token =
+                match resolve_bearer_token(server_name, bearer_token_env_var.as_deref()) {
+                    Ok(token) => token,
+                    Err(error) => return Err(error.into()),
+                };
+            RmcpClient::new_sse_client(
+                server_name,
+                &url,
+                resolved_bearer_token,
+                http_headers,
+                env_http_headers,
+                store_mode,
+            )
+            .await
+            .map_err(StartupOutcomeError::from)
+        }
+        McpServerTransportConfig::WebSocket {
+            url,
+            http_headers,
+            env_http_headers,
+            bearer_token_env_var,
+        } => {
+            let resolved_bearer_token =
+                match resolve_bearer_token(server_name, bearer_token_env_var.as_deref()) {
+                    Ok(token) => token,
+                    Err(error) => return Err(error.into()),
+                };
+            RmcpClient::new_websocket_client(
+                server_name,
+                &url,
+                resolved_bearer_token,
+                http_headers,
+                env_http_headers,
+                store_mode,
+            )
+            .await
+            .map_err(StartupOutcomeError::from)
+        }
     }
 }
 
@@ -1426,6 +1684,8 @@ mod tests {
                 enabled_tools: None,
                 disabled_tools: None,
                 scopes: None,
+                sampling: None,
+                approval: None,
             },
             auth_status: McpAuthStatus::Unsupported,
         };
@@ -1473,6 +1733,8 @@ mod tests {
                 enabled_tools: None,
                 disabled_tools: None,
                 scopes: None,
+                sampling: None,
+                approval: None,
             },
             auth_status: McpAuthStatus::Unsupported,
         };
@@ -1,15 +1,24 @@
+use std::collections::BTreeMap;
 use std::time::Duration;
 use std::time::Instant;
 
 use tracing::error;
+use tracing::warn;
 
 use crate::codex::Session;
 use crate::codex::TurnContext;
+use crate::config::edit::ConfigEditsBuilder;
 use crate::mcp::CODEX_APPS_MCP_SERVER_NAME;
+use crate::mcp::McpToolPolicyDecision;
+use crate::mcp::evaluate_mcp_tool_policy;
 use crate::protocol::EventMsg;
 use crate::protocol::McpInvocation;
+use crate::protocol::McpToolCallApprovalDecision;
+use crate::protocol::McpToolCallApprovalEvent;
+use crate::protocol::McpToolCallApprovalSource;
 use crate::protocol::McpToolCallBeginEvent;
 use crate::protocol::McpToolCallEndEvent;
+use crate::protocol::WarningEvent;
 use codex_protocol::mcp::CallToolResult;
 use codex_protocol::models::FunctionCallOutputBody;
 use codex_protocol::models::FunctionCallOutputPayload;
@@ -62,12 +71,21 @@ pub(crate) async fn handle_mcp_tool_call(
         arguments: arguments_value.clone(),
     };
 
-    if let Some(decision) =
-        maybe_request_mcp_tool_approval(sess.as_ref(), turn_context, &call_id, &server, &tool_name)
-            .await
+    if let Some(approval) =
+        maybe_request_mcp_tool_approval(sess.as_ref(), turn_context, &call_id, &invocation).await
     {
-        let result = match decision {
-            McpToolApprovalDecision::Accept | McpToolApprovalDecision::AcceptAndRemember => {
+        let approval_event = EventMsg::McpToolCallApproval(McpToolCallApprovalEvent {
+            call_id: call_id.clone(),
+            invocation: invocation.clone(),
+            decision: approval.decision.into(),
+            source: approval.source,
+        });
+        notify_mcp_tool_call_event(sess.as_ref(), turn_context, approval_event).await;
+
+        let result = match approval.decision {
+            McpToolApprovalDecision::Accept
+            | McpToolApprovalDecision::AcceptAndRemember
+            | McpToolApprovalDecision::AcceptAndPersist => {
                 let tool_call_begin_event = EventMsg::McpToolCallBegin(McpToolCallBeginEvent {
                     call_id: call_id.clone(),
                     invocation: invocation.clone(),
@@ -126,6 +144,16 @@ pub(crate) async fn handle_mcp_tool_call(
                 )
                 .await
             }
+            McpToolApprovalDecision::Blocked(reason) => {
+                notify_mcp_tool_call_skip(
+                    sess.as_ref(),
+                    turn_context,
+                    &call_id,
+                    invocation,
+                    reason.to_string(),
+                )
+                .await
+            }
         };
 
         let status = if result.is_ok() { "ok" } else { "error" };
@@ -214,8 +242,36 @@ async fn notify_mcp_tool_call_event(sess: &Session, turn_context: &TurnContext,
 enum McpToolApprovalDecision {
     Accept,
     AcceptAndRemember,
+    /// Approved, and the tool's `policy = "always"` is written to config.toml.
+    AcceptAndPersist,
     Decline,
     Cancel,
+    /// Denied by configuration without asking the user.
+    Blocked(&'static str),
+}
+
+impl From<McpToolApprovalDecision> for McpToolCallApprovalDecision {
+    fn from(decision: McpToolApprovalDecision) -> Self {
+        match decision {
+            McpToolApprovalDecision::Accept => Self::Approved,
+            McpToolApprovalDecision::AcceptAndRemember => Self::ApprovedForSession,
+            McpToolApprovalDecision::AcceptAndPersist => Self::ApprovedAndPersisted,
+            McpToolApprovalDecision::Decline | McpToolApprovalDecision::Blocked(_) => Self::Denied,
+            McpToolApprovalDecision::Cancel => Self::Cancelled,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct McpToolApproval {
+    decision: McpToolApprovalDecision,
+    source: McpToolCallApprovalSource,
+}
+
+impl McpToolApproval {
+    fn new(decision: McpToolApprovalDecision, source: McpToolCallApprovalSource) -> Self {
+        Self { decision, source }
+    }
 }
 
 struct McpToolApprovalMetadata {
@@ -228,13 +284,19 @@ struct McpToolApprovalMetadata {
 const MCP_TOOL_APPROVAL_QUESTION_ID_PREFIX: &str = "mcp_tool_call_approval";
 const MCP_TOOL_APPROVAL_ACCEPT: &str = "Approve Once";
 const MCP_TOOL_APPROVAL_ACCEPT_AND_REMEMBER: &str = "Approve this Session";
+const MCP_TOOL_APPROVAL_ACCEPT_AND_PERSIST: &str = "Always Approve";
 const MCP_TOOL_APPROVAL_DECLINE: &str = "Deny";
 const MCP_TOOL_APPROVAL_CANCEL: &str = "Cancel";
 
+const MCP_TOOL_POLICY_DENIED_REASON: &str =
+    "MCP tool call blocked by the configured approval policy";
+const MCP_TOOL_POLICY_PROMPT_CONFLICT_REASON: &str =
+    "approval required by MCP tool policy, but AskForApproval is set to Never";
+
 #[derive(Debug, Serialize)]
 struct McpToolApprovalKey {
     server: String,
-    connector_id: String,
+    connector_id: Option<String>,
     tool_name: String,
 }
 
@@ -242,9 +304,35 @@ async fn maybe_request_mcp_tool_approval(
     sess: &Session,
     turn_context: &TurnContext,
     call_id: &str,
-    server: &str,
-    tool_name: &str,
-) -> Option<McpToolApprovalDecision> {
+    invocation: &McpInvocation,
+) -> Option<McpToolApproval> {
+    let server = invocation.server.as_str();
+    let tool_name = invocation.tool.as_str();
+    let policy = turn_context
+        .config
+        .mcp_servers
+        .get()
+        .get(server)
+        .and_then(|config| config.approval.as_ref())
+        .and_then(|approval| {
+            evaluate_mcp_tool_policy(approval, tool_name, invocation.arguments.as_ref())
+        });
+    if let Some(policy) = policy {
+        let approval = match policy {
+            McpToolPolicyDecision::Allow(source) => {
+                McpToolApproval::new(McpToolApprovalDecision::Accept, source)
+            }
+            McpToolPolicyDecision::Deny => McpToolApproval::new(
+                McpToolApprovalDecision::Blocked(MCP_TOOL_POLICY_DENIED_REASON),
+                McpToolCallApprovalSource::Policy,
+            ),
+            McpToolPolicyDecision::Prompt => {
+                request_configured_mcp_tool_approval(sess, turn_context, call_id, invocation).await
+            }
+        };
+        return Some(approval);
+    }
+
     if is_full_access_mode(turn_context) {
         return None;
     }
@@ -261,13 +349,16 @@ async fn maybe_request_mcp_tool_approval(
         .as_deref()
         .map(|connector_id| McpToolApprovalKey {
             server: server.to_string(),
-            connector_id: connector_id.to_string(),
+            connector_id: Some(connector_id.to_string()),
             tool_name: tool_name.to_string(),
         });
     if let Some(key) = approval_key.as_ref()
         && mcp_tool_approval_is_remembered(sess, key).await
     {
-        return Some(McpToolApprovalDecision::Accept);
+        return Some(McpToolApproval::new(
+            McpToolApprovalDecision::Accept,
+            McpToolCallApprovalSource::Session,
+        ));
     }
 
     let question_id = format!("{MCP_TOOL_APPROVAL_QUESTION_ID_PREFIX}_{call_id}");
@@ -291,7 +382,102 @@ async fn maybe_request_mcp_tool_approval(
     {
         remember_mcp_tool_approval(sess, key).await;
     }
-    Some(decision)
+    Some(McpToolApproval::new(
+        decision,
+        McpToolCallApprovalSource::User,
+    ))
+}
+
+/// Asks about a call whose configured policy is `prompt`. Unlike app tools,
+/// the user may also persist the approval to config.toml.
+async fn request_configured_mcp_tool_approval(
+    sess: &Session,
+    turn_context: &TurnContext,
+    call_id: &str,
+    invocation: &McpInvocation,
+) -> McpToolApproval {
+    if turn_context.approval_policy == AskForApproval::Never {
+        return McpToolApproval::new(
+            McpToolApprovalDecision::Blocked(MCP_TOOL_POLICY_PROMPT_CONFLICT_REASON),
+            McpToolCallApprovalSource::Policy,
+        );
+    }
+    let key = McpToolApprovalKey {
+        server: invocation.server.clone(),
+        connector_id: None,
+        tool_name: invocation.tool.clone(),
+    };
+    if mcp_tool_approval_is_remembered(sess, &key).await {
+        return McpToolApproval::new(
+            McpToolApprovalDecision::Accept,
+            McpToolCallApprovalSource::Session,
+        );
+    }
+
+    let question_id = format!("{MCP_TOOL_APPROVAL_QUESTION_ID_PREFIX}_{call_id}");
+    let question = build_configured_mcp_tool_approval_question(question_id.clone(), invocation);
+    let args = RequestUserInputArgs {
+        questions: vec![question],
+    };
+    let response = sess
+        .request_user_input(turn_context, call_id.to_string(), args)
+        .await;
+    let decision = parse_mcp_tool_approval_response(response, &question_id);
+    match decision {
+        McpToolApprovalDecision::AcceptAndRemember => remember_mcp_tool_approval(sess, key).await,
+        McpToolApprovalDecision::AcceptAndPersist => {
+            remember_mcp_tool_approval(sess, key).await;
+            let saved = match allow_rule_for_arguments(invocation.arguments.as_ref()) {
+                Some(rule) => ConfigEditsBuilder::new(&turn_context.config.codex_home)
+                    .add_mcp_tool_allow_rule(&invocation.server, &invocation.tool, rule)
+                    .apply()
+                    .await
+                    .map_err(|err| err.to_string()),
+                None => Err("its arguments cannot be written to config.toml".to_string()),
+            };
+            if let Err(err) = saved {
+                let message = format!(
+                    "Approved {}/{} for this session only; failed to save it: {err}",
+                    invocation.server, invocation.tool
+                );
+                warn!("{message}");
+                sess.send_event(turn_context, EventMsg::Warning(WarningEvent { message }))
+                    .await;
+            }
+        }
+        _ => {}
+    }
+    McpToolApproval::new(decision, McpToolCallApprovalSource::User)
+}
+
+/// The `allow` matcher saved by "Always Approve": later calls run without
+/// asking only when every top-level argument holds the approved value.
+/// Returns `None` when there are no arguments to match, since an empty
+/// matcher would allow every call, or when TOML cannot hold them.
+fn allow_rule_for_arguments(
+    arguments: Option<&serde_json::Value>,
+) -> Option<BTreeMap<String, serde_json::Value>> {
+    let serde_json::Value::Object(fields) = arguments? else {
+        return None;
+    };
+    if fields.is_empty() || fields.values().any(contains_null) {
+        return None;
+    }
+    Some(
+        fields
+            .iter()
+            .map(|(key, value)| (key.clone(), value.clone()))
+            .collect(),
+    )
+}
+
+fn contains_null(value: &serde_json::Value) -> bool {
+    match value {
+        serde_json::Value::Null => true,
+        serde_json::Value::Array(items) => items.iter().any(contains_null),
+        serde_json::Value::Object(fields) => fields.values().any(contains_null),
+        _ => false,
+    }
 }
 
 fn is_full_access_mode(turn_context: &TurnContext) -> bool {
@@ -357,6 +543,48 @@ fn build_mcp_tool_approval_question(
         "{app_label} wants to run the tool \"{tool_label}\", which {reason}. Allow this action?"
     );
 
+    RequestUserInputQuestion {
+        id: question_id,
+        header: "Approve app tool call?".to_string(),
+        question,
+        is_other: false,
+        is_secret: false,
+        options: Some(mcp_tool_approval_options(allow_remember_option, false)),
+    }
+}
+
+fn build_configured_mcp_tool_approval_question(
+    question_id: String,
+    invocation: &McpInvocation,
+) -> RequestUserInputQuestion {
+    let arguments = invocation
+        .arguments
+        .as_ref()
+        .map(|arguments| format!(" with arguments {arguments}"))
+        .unwrap_or_default();
+    let question = format!(
+        "The {} MCP server wants to run the tool \"{}\"{arguments}. Allow this action?",
+        invocation.server, invocation.tool
+    );
+
+    RequestUserInputQuestion {
+        id: question_id,
+        header: "Approve MCP tool call?".to_string(),
+        question,
+        is_other: false,
+        is_secret: false,
+        // Only offer to persist calls that can be saved as an `allow` rule.
+        options: Some(mcp_tool_approval_options(
+            true,
+            allow_rule_for_arguments(invocation.arguments.as_ref()).is_some(),
+        )),
+    }
+}
+
+fn mcp_tool_approval_options(
+    allow_remember_option: bool,
+    allow_persist_option: bool,
+) -> Vec<RequestUserInputQuestionOption> {
     let mut options = vec![RequestUserInputQuestionOption {
         label: MCP_TOOL_APPROVAL_ACCEPT.to_string(),
         description: "Run the tool and continue.".to_string(),
@@ -367,6 +595,12 @@ fn build_mcp_tool_approval_question(
             description: "Run the tool and remember this choice for this session.".to_string(),
         });
     }
+    if allow_persist_option {
+        options.push(RequestUserInputQuestionOption {
+            label: MCP_TOOL_APPROVAL_ACCEPT_AND_PERSIST.to_string(),
+            description: "Run the tool, allow it for this session, and always allow calls with these arguments (saved to config.toml).".to_string(),
+        });
+    }
     options.extend([
         RequestUserInputQuestionOption {
             label: MCP_TOOL_APPROVAL_DECLINE.to_string(),
@@ -377,15 +611,7 @@ fn build_mcp_tool_approval_question(
             description: "Cancel this tool call".to_string(),
         },
     ]);
-
-    RequestUserInputQuestion {
-        id: question_id,
-        header: "Approve app tool call?".to_string(),
-        question,
-        is_other: false,
-        is_secret: false,
-        options: Some(options),
-    }
+    options
 }
 
 fn parse_mcp_tool_approval_response(
@@ -403,6 +629,11 @@ fn parse_mcp_tool_approval_response(
         return McpToolApprovalDecision::Cancel;
     };
     if answers
+        .iter()
+        .any(|answer| answer == MCP_TOOL_APPROVAL_ACCEPT_AND_PERSIST)
+    {
+        McpToolApprovalDecision::AcceptAndPersist
+    } else if answers
         .iter()
         .any(|answer| answer == MCP_TOOL_APPROVAL_ACCEPT_AND_REMEMBER)
     {
@@ -463,6 +694,9 @@ async fn notify_mcp_tool_call_skip(
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::config::types::McpServerApprovalConfig;
+    use crate::config::types::McpToolApprovalConfig;
+    use crate::config::types::McpToolApprovalPolicy;
     use pretty_assertions::assert_eq;
 
     fn annotations(
@@ -497,6 +731,96 @@ mod tests {
         assert_eq!(requires_mcp_tool_approval(&annotations), false);
     }
 
+    #[test]
+    fn configured_approval_question_offers_persist_option() {
+        let invocation = McpInvocation {
+            server: "jira".to_string(),
+            tool: "create_issue".to_string(),
+            arguments: Some(serde_json::json!({ "project": "PROD" })),
+        };
+
+        let question = build_configured_mcp_tool_approval_question("q".to_string(), &invocation);
+        let labels: Vec<String> = question
+            .options
+            .unwrap_or_default()
+            .into_iter()
+            .map(|option| option.label)
+            .collect();
+
+        assert_eq!(
+            question.question,
+            "The jira MCP server wants to run the tool \"create_issue\" with arguments {\"project\":\"PROD\"}. Allow this action?"
+        );
+        assert_eq!(
+            labels,
+            vec![
+                MCP_TOOL_APPROVAL_ACCEPT,
+                MCP_TOOL_APPROVAL_ACCEPT_AND_REMEMBER,
+                MCP_TOOL_APPROVAL_ACCEPT_AND_PERSIST,
+                MCP_TOOL_APPROVAL_DECLINE,
+                MCP_TOOL_APPROVAL_CANCEL,
+            ]
+        );
+
+        let response = RequestUserInputResponse {
+            answers: std::collections::HashMap::from([(
+                "q".to_string(),
+                codex_protocol::request_user_input::RequestUserInputAnswer {
+                    answers: vec![MCP_TOOL_APPROVAL_ACCEPT_AND_PERSIST.to_string()],
+                },
+            )]),
+        };
+        assert_eq!(
+            parse_mcp_tool_approval_response(Some(response), "q"),
+            McpToolApprovalDecision::AcceptAndPersist
+        );
+    }
+
+    #[test]
+    fn persisted_allow_rule_only_covers_the_approved_arguments() {
+        let approved = serde_json::json!({ "project": "PROD", "labels": ["bot"] });
+        let rule = allow_rule_for_arguments(Some(&approved)).expect("rule");
+        let approval = McpServerApprovalConfig {
+            policy: Some(McpToolApprovalPolicy::Prompt),
+            tools: BTreeMap::from([(
+                "create_issue".to_string(),
+                McpToolApprovalConfig {
+                    policy: None,
+                    allow: vec![rule],
+                },
+            )]),
+        };
+
+        assert_eq!(
+            evaluate_mcp_tool_policy(&approval, "create_issue", Some(&approved)),
+            Some(McpToolPolicyDecision::Allow(
+                McpToolCallApprovalSource::ArgumentRule
+            ))
+        );
+        assert_eq!(
+            evaluate_mcp_tool_policy(
+                &approval,
+                "create_issue",
+                Some(&serde_json::json!({ "project": "SANDBOX", "labels": ["bot"] })),
+            ),
+            Some(McpToolPolicyDecision::Prompt)
+        );
+        assert_eq!(
+            evaluate_mcp_tool_policy(
+                &approval,
+                "create_issue",
+                Some(&serde_json::json!({ "project": "PROD" })),
+            ),
+            Some(McpToolPolicyDecision::Prompt)
+        );
+        assert_eq!(
+            allow_rule_for_arguments(Some(&serde_json::json!({ "parent": null }))),
+            None
+        );
+        assert_eq!(allow_rule_for_arguments(Some(&serde_json::json!({}))), None);
+        assert_eq!(allow_rule_for_arguments(None), None);
+    }
+
     #[test]
     fn sanitize_mcp_tool_result_for_model_rewrites_image_content() {
         let result = Ok(CallToolResult {
@@ -75,6 +75,7 @@ pub(super) async fn extract_stage_one_output(
         },
         personality: None,
         output_schema: Some(stage_one_output_schema()),
+        max_output_tokens: None,
     };
 
     let mut client_session = session.services.model_client.new_session();
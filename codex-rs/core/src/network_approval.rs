@@ -0,0 +1,222 @@
+//! Interactive approvals for network requests outside `network.allowed_domains`.
+//!
+//! The session's network proxy consults [`NetworkApprovalService`] whenever a host is not on the
+//! allowlist. Rather than failing the connection outright, the service pauses it, emits a
+//! `NetworkApprovalRequest` event for the active turn and waits for `Op::NetworkApproval`.
+//! Concurrent connections to the same host share a single prompt.
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::sync::OnceLock;
+use std::sync::Weak;
+
+use async_trait::async_trait;
+use codex_network_proxy::NetworkDecision;
+use codex_network_proxy::NetworkPolicyDecider;
+use codex_network_proxy::NetworkPolicyRequest;
+use codex_network_proxy::REASON_USER_DENIED;
+use tokio::sync::Mutex;
+use tokio::sync::oneshot;
+use tokio_util::sync::CancellationToken;
+use tracing::warn;
+use uuid::Uuid;
+
+use crate::codex::Session;
+use crate::codex::TurnContext;
+use crate::config::edit::ConfigEditsBuilder;
+use crate::protocol::AskForApproval;
+use crate::protocol::EventMsg;
+use crate::protocol::NetworkApprovalDecision;
+use crate::protocol::NetworkApprovalRequestEvent;
+
+/// Reason reported when no prompt could be shown (no active turn or `approval_policy = never`).
+const REASON_NOT_ALLOWED: &str = "not_allowed";
+
+pub(crate) struct NetworkApprovalService {
+    codex_home: PathBuf,
+    session: OnceLock<Weak<Session>>,
+    state: Mutex<NetworkApprovalState>,
+}
+
+#[derive(Default)]
+struct NetworkApprovalState {
+    /// Hosts the user allowed for the remainder of the session.
+    session_hosts: HashSet<String>,
+    /// Outstanding prompts keyed by host.
+    pending: HashMap<String, PendingNetworkApproval>,
+}
+
+/// Destination a prompt asks about.
+pub(crate) struct NetworkApprovalPrompt {
+    pub(crate) host: String,
+    pub(crate) port: u16,
+    pub(crate) protocol: String,
+    pub(crate) method: Option<String>,
+}
+
+struct PendingNetworkApproval {
+    id: String,
+    waiters: Vec<oneshot::Sender<NetworkApprovalDecision>>,
+}
+
+impl NetworkApprovalService {
+    pub(crate) fn new(codex_home: PathBuf) -> Self {
+        Self {
+            codex_home,
+            session: OnceLock::new(),
+            state: Mutex::new(NetworkApprovalState::default()),
+        }
+    }
+
+    /// Binds the service to its session. The proxy is started before the session exists, so
+    /// requests that arrive earlier are denied without prompting.
+    pub(crate) fn attach(&self, session: &Arc<Session>) {
+        let _ = self.session.set(Arc::downgrade(session));
+    }
+
+    /// Resolves the prompt identified by `id` for every connection waiting on it.
+    pub(crate) async fn notify(&self, id: &str, decision: NetworkApprovalDecision) {
+        let resolved = {
+            let mut state = self.state.lock().await;
+            let host = state
+                .pending
+                .iter()
+                .find(|(_, pending)| pending.id == id)
+                .map(|(host, _)| host.clone());
+            let Some(host) = host else {
+                warn!("no pending network approval found for id: {id}");
+                return;
+            };
+            if matches!(
+                decision,
+                NetworkApprovalDecision::AllowForSession | NetworkApprovalDecision::AllowAndPersist
+            ) {
+                state.session_hosts.insert(host.clone());
+            }
+            state.pending.remove(&host).map(|pending| (host, pending))
+        };
+        let Some((host, pending)) = resolved else {
+            return;
+        };
+
+        if decision == NetworkApprovalDecision::AllowAndPersist
+            && let Err(err) = ConfigEditsBuilder::new(&self.codex_home)
+                .add_network_allowed_domain(&host)
+                .apply()
+                .await
+        {
+            warn!("failed to persist network approval for {host}: {err}");
+        }
+
+        for waiter in pending.waiters {
+            let _ = waiter.send(decision);
+        }
+    }
+
+    /// Prompts for `request` on the session's active turn and waits for the answer. Returns
+    /// `None` when no prompt could be shown: there is no active turn, the turn ended first, or
+    /// `approval_policy = never`.
+    async fn request_approval(
+        &self,
+        request: &NetworkPolicyRequest,
+    ) -> Option<NetworkApprovalDecision> {
+        let prompt = NetworkApprovalPrompt {
+            host: request.host.to_ascii_lowercase(),
+            port: request.port,
+            protocol: request.protocol.as_policy_protocol().to_string(),
+            method: request.method.clone(),
+        };
+        if self.state.lock().await.session_hosts.contains(&prompt.host) {
+            return Some(NetworkApprovalDecision::AllowOnce);
+        }
+
+        let session = self.session.get().and_then(Weak::upgrade)?;
+        let (turn_context, cancellation_token) =
+            session.active_turn_context_and_cancellation_token().await?;
+        self.prompt(&session, &turn_context, &cancellation_token, prompt)
+            .await
+    }
+
+    /// Emits a `NetworkApprovalRequest` on `turn_context` unless the same host is already being
+    /// asked about, then waits for the answer. Delegate sessions use this to route their
+    /// prompts through the parent session.
+    pub(crate) async fn prompt(
+        &self,
+        session: &Session,
+        turn_context: &TurnContext,
+        cancellation_token: &CancellationToken,
+        prompt: NetworkApprovalPrompt,
+    ) -> Option<NetworkApprovalDecision> {
+        if turn_context.approval_policy == AskForApproval::Never {
+            return None;
+        }
+
+        let NetworkApprovalPrompt {
+            host,
+            port,
+            protocol,
+            method,
+        } = prompt;
+        let (tx, rx) = oneshot::channel();
+        let new_request_id = {
+            let mut state = self.state.lock().await;
+            if state.session_hosts.contains(&host) {
+                return Some(NetworkApprovalDecision::AllowOnce);
+            }
+            match state.pending.get_mut(&host) {
+                Some(pending) => {
+                    pending.waiters.push(tx);
+                    None
+                }
+                None => {
+                    let id = Uuid::new_v4().to_string();
+                    state.pending.insert(
+                        host.clone(),
+                        PendingNetworkApproval {
+                            id: id.clone(),
+                            waiters: vec![tx],
+                        },
+                    );
+                    Some(id)
+                }
+            }
+        };
+
+        if let Some(id) = new_request_id {
+            let event = EventMsg::NetworkApprovalRequest(NetworkApprovalRequestEvent {
+                id,
+                turn_id: turn_context.sub_id.clone(),
+                host: host.clone(),
+                port,
+                protocol,
+                method,
+            });
+            session.send_event(turn_context, event).await;
+        }
+
+        tokio::select! {
+            decision = rx => decision.ok(),
+            _ = cancellation_token.cancelled() => {
+                self.state.lock().await.pending.remove(&host);
+                None
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl NetworkPolicyDecider for NetworkApprovalService {
+    async fn decide(&self, req: NetworkPolicyRequest) -> NetworkDecision {
+        match self.request_approval(&req).await {
+            Some(
+                NetworkApprovalDecision::AllowOnce
+                | NetworkApprovalDecision::AllowForSession
+                | NetworkApprovalDecision::AllowAndPersist,
+            ) => NetworkDecision::Allow,
+            Some(NetworkApprovalDecision::Deny) => NetworkDecision::deny(REASON_USER_DENIED),
+            None => NetworkDecision::deny(REASON_NOT_ALLOWED),
+        }
+    }
+}
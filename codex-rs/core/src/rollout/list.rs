@@ -4,6 +4,7 @@ use std::ffi::OsStr;
 use std::io::{self};
 use std::num::NonZero;
 use std::ops::ControlFlow;
+use std::ops::Range;
 use std::path::Path;
 use std::path::PathBuf;
 use time::OffsetDateTime;
@@ -24,6 +25,7 @@ use codex_protocol::protocol::RolloutLine;
 use codex_protocol::protocol::SessionMetaLine;
 use codex_protocol::protocol::SessionSource;
 use codex_protocol::protocol::USER_MESSAGE_BEGIN;
+use codex_state::ThreadSearchKind;
 
 /// Returned page of thread (thread) summaries.
 #[derive(Debug, Default, PartialEq)]
@@ -69,6 +71,18 @@ pub struct ThreadItem {
     pub updated_at: Option<String>,
 }
 
+/// A thread matching a full-text search, with its best matching excerpt.
+#[derive(Debug, PartialEq)]
+pub struct ThreadSearchMatch {
+    pub item: ThreadItem,
+    /// Kind of recorded text the snippet was taken from.
+    pub kind: ThreadSearchKind,
+    /// Single-line excerpt of the matching text.
+    pub snippet: String,
+    /// Byte ranges of `snippet` that matched the query.
+    pub highlights: Vec<Range<usize>>,
+}
+
 #[allow(dead_code)]
 #[deprecated(note = "use ThreadItem")]
 pub type ConversationItem = ThreadItem;
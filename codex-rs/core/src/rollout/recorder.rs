@@ -28,6 +28,7 @@ use super::list::Cursor;
 use super::list::ThreadItem;
 use super::list::ThreadListConfig;
 use super::list::ThreadListLayout;
+use super::list::ThreadSearchMatch;
 use super::list::ThreadSortKey;
 use super::list::ThreadsPage;
 use super::list::get_threads;
@@ -222,6 +223,27 @@ impl RolloutRecorder {
         .await
     }
 
+    /// Full-text search over recorded threads (messages, commands and touched
+    /// files), best matches first. Requires the SQLite state DB; threads
+    /// recorded before it existed show up once the background index reaches them.
+    pub async fn search_threads(
+        config: &Config,
+        query: &str,
+        limit: usize,
+        archived: bool,
+    ) -> std::io::Result<Vec<ThreadSearchMatch>> {
+        let Some(state_db_ctx) = state_db::get_state_db_for_search(config).await else {
+            return Err(IoError::new(
+                std::io::ErrorKind::Unsupported,
+                "thread search requires the SQLite state DB; enable the `sqlite` feature",
+            ));
+        };
+        let hits = state_db::search_threads(Some(&state_db_ctx), query, limit, archived)
+            .await
+            .ok_or_else(|| IoError::other("thread search failed"))?;
+        Ok(hits.into_iter().map(ThreadSearchMatch::from).collect())
+    }
+
     /// Find the newest recorded thread path, optionally filtering to a matching cwd.
     #[allow(clippy::too_many_arguments)]
     pub async fn find_latest_thread_path(
@@ -816,29 +838,42 @@ impl JsonlWriter {
     }
 }
 
+impl From<codex_state::ThreadMetadata> for ThreadItem {
+    fn from(item: codex_state::ThreadMetadata) -> Self {
+        Self {
+            path: item.rollout_path,
+            thread_id: Some(item.id),
+            first_user_message: item.first_user_message,
+            cwd: Some(item.cwd),
+            git_branch: item.git_branch,
+            git_sha: item.git_sha,
+            git_origin_url: item.git_origin_url,
+            source: Some(
+                serde_json::from_value(Value::String(item.source))
+                    .unwrap_or(SessionSource::Unknown),
+            ),
+            model_provider: Some(item.model_provider),
+            cli_version: Some(item.cli_version),
+            created_at: Some(item.created_at.to_rfc3339_opts(SecondsFormat::Secs, true)),
+            updated_at: Some(item.updated_at.to_rfc3339_opts(SecondsFormat::Secs, true)),
+        }
+    }
+}
+
+impl From<codex_state::ThreadSearchHit> for ThreadSearchMatch {
+    fn from(hit: codex_state::ThreadSearchHit) -> Self {
+        Self {
+            item: hit.thread.into(),
+            kind: hit.kind,
+            snippet: hit.snippet,
+            highlights: hit.highlights,
+        }
+    }
+}
+
 impl From<codex_state::ThreadsPage> for ThreadsPage {
     fn from(db_page: codex_state::ThreadsPage) -> Self {
-        let items = db_page
-            .items
-            .into_iter()
-            .map(|item| ThreadItem {
-                path: item.rollout_path,
-                thread_id: Some(item.id),
-                first_user_message: item.first_user_message,
-                cwd: Some(item.cwd),
-                git_branch: item.git_branch,
-                git_sha: item.git_sha,
-                git_origin_url: item.git_origin_url,
-                source: Some(
-                    serde_json::from_value(Value::String(item.source))
-                        .unwrap_or(SessionSource::Unknown),
-                ),
-                model_provider: Some(item.model_provider),
-                cli_version: Some(item.cli_version),
-                created_at: Some(item.created_at.to_rfc3339_opts(SecondsFormat::Secs, true)),
-                updated_at: Some(item.updated_at.to_rfc3339_opts(SecondsFormat::Secs, true)),
-            })
-            .collect();
+        let items = db_page.items.into_iter().map(ThreadItem::from).collect();
         Self {
             items,
             next_cursor: db_page.next_anchor.map(Into::into),
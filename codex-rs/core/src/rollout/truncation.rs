@@ -68,6 +68,62 @@ pub(crate) fn truncate_rollout_before_nth_user_message_from_start(
     items[..cut_idx].to_vec()
 }
 
+/// Return the index of the last `CheckpointCreated` marker named `name`, if any.
+///
+/// Like [`user_message_positions_in_rollout`], this applies `ThreadRolledBack` markers: a
+/// checkpoint recorded during a turn that was later rolled back is no longer part of the thread.
+pub(crate) fn checkpoint_position_in_rollout(items: &[RolloutItem], name: &str) -> Option<usize> {
+    let mut user_turns = 0usize;
+    // Matching checkpoints with the number of user turns recorded before each one.
+    let mut checkpoints: Vec<(usize, usize)> = Vec::new();
+    for (idx, item) in items.iter().enumerate() {
+        match item {
+            RolloutItem::ResponseItem(item @ ResponseItem::Message { .. })
+                if matches!(
+                    event_mapping::parse_turn_item(item),
+                    Some(TurnItem::UserMessage(_))
+                ) =>
+            {
+                user_turns += 1;
+            }
+            RolloutItem::EventMsg(EventMsg::ThreadRolledBack(rollback)) => {
+                let num_turns = usize::try_from(rollback.num_turns).unwrap_or(usize::MAX);
+                user_turns = user_turns.saturating_sub(num_turns);
+                checkpoints.retain(|(_, turns_before)| *turns_before <= user_turns);
+            }
+            RolloutItem::EventMsg(EventMsg::CheckpointCreated(checkpoint))
+                if checkpoint.name == name =>
+            {
+                checkpoints.push((idx, user_turns));
+            }
+            _ => {}
+        }
+    }
+    checkpoints.last().map(|(idx, _)| *idx)
+}
+
+/// Return a prefix of `items` that ends with the named checkpoint marker, or `None` when the
+/// rollout has no such checkpoint.
+pub(crate) fn truncate_rollout_at_checkpoint(
+    items: &[RolloutItem],
+    name: &str,
+) -> Option<Vec<RolloutItem>> {
+    let idx = checkpoint_position_in_rollout(items, name)?;
+    Some(items[..=idx].to_vec())
+}
+
+/// Return how many user turns were recorded after the named checkpoint, i.e. the
+/// `Op::ThreadRollback` needed to bring the thread back to it.
+pub(crate) fn user_turns_after_checkpoint(items: &[RolloutItem], name: &str) -> Option<usize> {
+    let idx = checkpoint_position_in_rollout(items, name)?;
+    Some(
+        user_message_positions_in_rollout(items)
+            .into_iter()
+            .filter(|position| *position > idx)
+            .count(),
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -75,8 +131,10 @@ mod tests {
     use assert_matches::assert_matches;
     use codex_protocol::models::ContentItem;
     use codex_protocol::models::ReasoningItemReasoningSummary;
+    use codex_protocol::protocol::CheckpointCreatedEvent;
     use codex_protocol::protocol::ThreadRolledBackEvent;
     use pretty_assertions::assert_eq;
+    use std::path::PathBuf;
 
     fn user_msg(text: &str) -> ResponseItem {
         ResponseItem::Message {
@@ -190,6 +248,71 @@ mod tests {
         );
     }
 
+    #[test]
+    fn truncates_rollout_at_last_matching_checkpoint() {
+        let checkpoint = |name: &str| {
+            RolloutItem::EventMsg(EventMsg::CheckpointCreated(CheckpointCreatedEvent {
+                name: name.to_string(),
+                path: PathBuf::from("/repo/.codex/checkpoint.md"),
+                ghost_commit: None,
+            }))
+        };
+        let rollout_items = vec![
+            RolloutItem::ResponseItem(user_msg("u1")),
+            RolloutItem::ResponseItem(assistant_msg("a1")),
+            checkpoint("design"),
+            RolloutItem::ResponseItem(user_msg("u2")),
+            RolloutItem::ResponseItem(assistant_msg("a2")),
+            checkpoint("impl"),
+            RolloutItem::ResponseItem(user_msg("u3")),
+            RolloutItem::ResponseItem(assistant_msg("a3")),
+        ];
+
+        let truncated =
+            truncate_rollout_at_checkpoint(&rollout_items, "design").expect("checkpoint exists");
+        assert_eq!(
+            serde_json::to_value(&truncated).unwrap(),
+            serde_json::to_value(&rollout_items[..3]).unwrap()
+        );
+        assert_eq!(
+            user_turns_after_checkpoint(&rollout_items, "design"),
+            Some(2)
+        );
+        assert_eq!(user_turns_after_checkpoint(&rollout_items, "impl"), Some(1));
+        assert!(truncate_rollout_at_checkpoint(&rollout_items, "missing").is_none());
+    }
+
+    #[test]
+    fn checkpoints_from_rolled_back_turns_are_ignored() {
+        let checkpoint = |path: &str| {
+            RolloutItem::EventMsg(EventMsg::CheckpointCreated(CheckpointCreatedEvent {
+                name: "design".to_string(),
+                path: PathBuf::from(path),
+                ghost_commit: None,
+            }))
+        };
+        let rollout_items = vec![
+            RolloutItem::ResponseItem(user_msg("u1")),
+            checkpoint("/repo/first.md"),
+            RolloutItem::ResponseItem(user_msg("u2")),
+            checkpoint("/repo/rolled-back.md"),
+            RolloutItem::EventMsg(EventMsg::ThreadRolledBack(ThreadRolledBackEvent {
+                num_turns: 1,
+            })),
+            RolloutItem::ResponseItem(user_msg("u3")),
+            RolloutItem::ResponseItem(assistant_msg("a3")),
+        ];
+
+        assert_eq!(
+            checkpoint_position_in_rollout(&rollout_items, "design"),
+            Some(1)
+        );
+        assert_eq!(
+            user_turns_after_checkpoint(&rollout_items, "design"),
+            Some(1)
+        );
+    }
+
     #[tokio::test]
     async fn ignores_session_prefix_messages_when_truncating_rollout_from_start() {
         let (session, turn_context) = make_session_and_context().await;
@@ -0,0 +1,608 @@
+//! Secrets listed in `shell_environment_policy.secrets`.
+//!
+//! Values are resolved once per session from the configured `[secrets]` backend, preferring the
+//! entry scoped to the current environment (see [`environment_id_from_cwd`]) over the global one.
+//! They are injected into the environment of shell and unified exec processes, and every
+//! occurrence of a value is replaced with `[REDACTED:<NAME>]` in tool output, events, rollout
+//! files and conversation history, so tokens never reach transcripts or the model.
+
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::Mutex;
+use std::sync::PoisonError;
+
+use codex_protocol::models::ContentItem;
+use codex_protocol::models::FunctionCallOutputBody;
+use codex_protocol::models::FunctionCallOutputContentItem;
+use codex_protocol::models::LocalShellAction;
+use codex_protocol::models::ReasoningItemContent;
+use codex_protocol::models::ReasoningItemReasoningSummary;
+use codex_protocol::models::ResponseItem;
+use codex_protocol::protocol::EventMsg;
+use codex_protocol::protocol::ExecCommandOutputDeltaEvent;
+use codex_protocol::protocol::ExecOutputStream;
+use codex_protocol::protocol::RolloutItem;
+use codex_secrets::SecretName;
+use codex_secrets::SecretScope;
+use codex_secrets::SecretsConfig;
+use codex_secrets::SecretsManager;
+use codex_secrets::environment_id_from_cwd;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use tracing::warn;
+
+use crate::exec::ExecToolCallOutput;
+use crate::exec::StreamOutput;
+
+/// Values shorter than this are injected but not redacted; replacing them would mangle
+/// unrelated output.
+const MIN_REDACTED_SECRET_LEN: usize = 4;
+
+#[derive(Debug, Default)]
+pub(crate) struct SessionSecrets {
+    /// `(name, value)` pairs, longest value first so overlapping secrets redact fully.
+    secrets: Vec<(String, String)>,
+    /// Trailing bytes of live output deltas, keyed by `(call_id, stream)`, held back because
+    /// they could be the start of a secret that continues in the next chunk.
+    held_output: Mutex<HashMap<(String, ExecOutputStream), Vec<u8>>>,
+}
+
+impl SessionSecrets {
+    /// Resolves `names` for the environment rooted at `cwd`. Returns the resolved secrets and
+    /// user-facing warnings for names that could not be resolved.
+    pub(crate) async fn resolve(
+        secrets_config: &SecretsConfig,
+        names: &[String],
+        codex_home: &Path,
+        cwd: &Path,
+    ) -> (Self, Vec<String>) {
+        if names.is_empty() {
+            return (Self::default(), Vec::new());
+        }
+        let secrets_config = secrets_config.clone();
+        let names = names.to_vec();
+        let codex_home = codex_home.to_path_buf();
+        let cwd = cwd.to_path_buf();
+        // Secrets backends block on files, subprocesses or HTTP.
+        let resolved = tokio::task::spawn_blocking(move || {
+            resolve_blocking(&secrets_config, &names, codex_home, &cwd)
+        })
+        .await;
+        match resolved {
+            Ok((secrets, warnings)) => (Self::new(secrets), warnings),
+            Err(err) => (
+                Self::default(),
+                vec![format!(
+                    "Failed to resolve shell environment secrets: {err}"
+                )],
+            ),
+        }
+    }
+
+    fn new(mut secrets: Vec<(String, String)>) -> Self {
+        secrets.sort_by(|(_, a), (_, b)| b.len().cmp(&a.len()));
+        Self {
+            secrets,
+            held_output: Mutex::default(),
+        }
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.secrets.is_empty()
+    }
+
+    /// Adds the resolved secrets to a process environment built by `create_env`.
+    pub(crate) fn inject(&self, mut env: HashMap<String, String>) -> HashMap<String, String> {
+        for (name, value) in &self.secrets {
+            env.insert(name.clone(), value.clone());
+        }
+        env
+    }
+
+    pub(crate) fn redact<'a>(&self, text: &'a str) -> Cow<'a, str> {
+        let mut redacted = Cow::Borrowed(text);
+        for (name, value) in self.redactable() {
+            if redacted.contains(value.as_str()) {
+                redacted = Cow::Owned(redacted.replace(value.as_str(), &placeholder(name)));
+            }
+        }
+        redacted
+    }
+
+    fn redact_bytes(&self, bytes: Vec<u8>) -> Vec<u8> {
+        let mut redacted = bytes;
+        for (name, value) in self.redactable() {
+            let needle = value.as_bytes();
+            if !redacted
+                .windows(needle.len())
+                .any(|window| window == needle)
+            {
+                continue;
+            }
+            let replacement = placeholder(name);
+            let mut out = Vec::with_capacity(redacted.len());
+            let mut idx = 0;
+            while idx < redacted.len() {
+                if redacted[idx..].starts_with(needle) {
+                    out.extend_from_slice(replacement.as_bytes());
+                    idx += needle.len();
+                } else {
+                    out.push(redacted[idx]);
+                    idx += 1;
+                }
+            }
+            redacted = out;
+        }
+        redacted
+    }
+
+    pub(crate) fn redact_exec_output(&self, output: ExecToolCallOutput) -> ExecToolCallOutput {
+        if self.is_empty() {
+            return output;
+        }
+        ExecToolCallOutput {
+            stdout: self.redact_stream(output.stdout),
+            stderr: self.redact_stream(output.stderr),
+            aggregated_output: self.redact_stream(output.aggregated_output),
+            ..output
+        }
+    }
+
+    fn redact_stream(&self, stream: StreamOutput<String>) -> StreamOutput<String> {
+        StreamOutput {
+            text: self.redact(&stream.text).into_owned(),
+            ..stream
+        }
+    }
+
+    pub(crate) fn redact_event_msg(&self, msg: EventMsg) -> EventMsg {
+        if self.is_empty() {
+            return msg;
+        }
+        match msg {
+            // Output chunks are raw bytes, serialized as base64.
+            EventMsg::ExecCommandOutputDelta(mut delta) => {
+                delta.chunk = self.redact_bytes(delta.chunk);
+                EventMsg::ExecCommandOutputDelta(delta)
+            }
+            msg => self.redact_serialized(msg),
+        }
+    }
+
+    /// Redacts an event that is about to be delivered to clients.
+    ///
+    /// Unlike [`Self::redact_event_msg`], output deltas are redacted as a stream: the tail of
+    /// each chunk that could begin a secret is held back and prepended to the next chunk of the
+    /// same stream, so a value split across chunks is still replaced. Whatever is still held when
+    /// the command ends is released as deltas just before its `ExecCommandEnd`; see also
+    /// [`Self::release_held_output`].
+    pub(crate) fn redact_live_event_msg(&self, msg: EventMsg) -> Vec<EventMsg> {
+        if self.is_empty() {
+            return vec![msg];
+        }
+        match msg {
+            EventMsg::ExecCommandOutputDelta(mut delta) => {
+                let key = (delta.call_id.clone(), delta.stream.clone());
+                let mut held_output = self
+                    .held_output
+                    .lock()
+                    .unwrap_or_else(PoisonError::into_inner);
+                let mut buffer = held_output.remove(&key).unwrap_or_default();
+                buffer.extend_from_slice(&delta.chunk);
+                let split = self.safe_split_point(&buffer);
+                let tail = buffer.split_off(split);
+                if !tail.is_empty() {
+                    held_output.insert(key, tail);
+                }
+                if buffer.is_empty() {
+                    return Vec::new();
+                }
+                delta.chunk = self.redact_bytes(buffer);
+                vec![EventMsg::ExecCommandOutputDelta(delta)]
+            }
+            EventMsg::ExecCommandEnd(end) => {
+                let mut released = self.release_held_output(&end.call_id);
+                released.push(self.redact_event_msg(EventMsg::ExecCommandEnd(end)));
+                released
+            }
+            msg => vec![self.redact_event_msg(msg)],
+        }
+    }
+
+    /// Releases the output held back for `call_id` as deltas, redacting the secrets it holds in
+    /// full. Called when the command ends, and when its output goes idle so that a prompt such
+    /// as `Password:` is not hidden while the command waits for input.
+    pub(crate) fn release_held_output(&self, call_id: &str) -> Vec<EventMsg> {
+        let mut held_output = self
+            .held_output
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        [ExecOutputStream::Stdout, ExecOutputStream::Stderr]
+            .into_iter()
+            .filter_map(|stream| {
+                let chunk = held_output.remove(&(call_id.to_string(), stream.clone()))?;
+                Some(EventMsg::ExecCommandOutputDelta(
+                    ExecCommandOutputDeltaEvent {
+                        call_id: call_id.to_string(),
+                        stream,
+                        chunk: self.redact_bytes(chunk),
+                    },
+                ))
+            })
+            .collect()
+    }
+
+    /// Returns how much of `buffer` can be emitted now. The remainder is shorter than the longest
+    /// secret, so any secret it could start is completed by a later chunk; the split never cuts
+    /// through a complete secret or a UTF-8 sequence.
+    fn safe_split_point(&self, buffer: &[u8]) -> usize {
+        let Some(longest) = self.redactable().map(|(_, value)| value.len()).max() else {
+            return buffer.len();
+        };
+        let mut split = buffer.len().saturating_sub(longest - 1);
+        while split > 0 && split < buffer.len() && buffer[split] & 0xC0 == 0x80 {
+            split -= 1;
+        }
+        for (_, value) in self.redactable() {
+            let needle = value.as_bytes();
+            let first_start = split.saturating_sub(needle.len() - 1);
+            for start in first_start..split {
+                if buffer[start..].starts_with(needle) {
+                    split = split.max(start + needle.len());
+                }
+            }
+        }
+        split
+    }
+
+    pub(crate) fn redact_response_items(&self, items: &[ResponseItem]) -> Vec<ResponseItem> {
+        let mut items = items.to_vec();
+        if !self.is_empty() {
+            for item in &mut items {
+                self.redact_response_item(item);
+            }
+        }
+        items
+    }
+
+    pub(crate) fn redact_rollout_items(&self, items: &[RolloutItem]) -> Vec<RolloutItem> {
+        if self.is_empty() {
+            return items.to_vec();
+        }
+        items
+            .iter()
+            .cloned()
+            .map(|item| match item {
+                RolloutItem::EventMsg(msg) => RolloutItem::EventMsg(self.redact_event_msg(msg)),
+                RolloutItem::ResponseItem(mut item) => {
+                    self.redact_response_item(&mut item);
+                    RolloutItem::ResponseItem(item)
+                }
+                RolloutItem::Compacted(mut compacted) => {
+                    self.redact_in_place(&mut compacted.message);
+                    for item in compacted.replacement_history.iter_mut().flatten() {
+                        self.redact_response_item(item);
+                    }
+                    RolloutItem::Compacted(compacted)
+                }
+                item @ (RolloutItem::SessionMeta(_) | RolloutItem::TurnContext(_)) => item,
+            })
+            .collect()
+    }
+
+    /// Redacts the model-visible text of `item` in place. Response items are not round-tripped
+    /// through serde because some fields (ids, `success`) are not serialized.
+    fn redact_response_item(&self, item: &mut ResponseItem) {
+        match item {
+            ResponseItem::Message { content, .. } => {
+                for content_item in content {
+                    match content_item {
+                        ContentItem::InputText { text } | ContentItem::OutputText { text } => {
+                            self.redact_in_place(text);
+                        }
+                        ContentItem::InputImage { .. } => {}
+                    }
+                }
+            }
+            ResponseItem::Reasoning {
+                summary, content, ..
+            } => {
+                for ReasoningItemReasoningSummary::SummaryText { text } in summary {
+                    self.redact_in_place(text);
+                }
+                for content_item in content.iter_mut().flatten() {
+                    match content_item {
+                        ReasoningItemContent::ReasoningText { text }
+                        | ReasoningItemContent::Text { text } => self.redact_in_place(text),
+                    }
+                }
+            }
+            ResponseItem::LocalShellCall {
+                action: LocalShellAction::Exec(action),
+                ..
+            } => {
+                for arg in &mut action.command {
+                    self.redact_in_place(arg);
+                }
+            }
+            ResponseItem::FunctionCall { arguments, .. } => self.redact_in_place(arguments),
+            ResponseItem::FunctionCallOutput { output, .. } => match &mut output.body {
+                FunctionCallOutputBody::Text(text) => self.redact_in_place(text),
+                FunctionCallOutputBody::ContentItems(content_items) => {
+                    for content_item in content_items {
+                        match content_item {
+                            FunctionCallOutputContentItem::InputText { text } => {
+                                self.redact_in_place(text);
+                            }
+                            FunctionCallOutputContentItem::InputImage { .. } => {}
+                        }
+                    }
+                }
+            },
+            ResponseItem::CustomToolCall { input, .. } => self.redact_in_place(input),
+            ResponseItem::CustomToolCallOutput { output, .. } => self.redact_in_place(output),
+            ResponseItem::WebSearchCall { .. }
+            | ResponseItem::GhostSnapshot { .. }
+            | ResponseItem::Compaction { .. }
+            | ResponseItem::Other => {}
+        }
+    }
+
+    fn redact_in_place(&self, text: &mut String) {
+        if let Cow::Owned(redacted) = self.redact(text) {
+            *text = redacted;
+        }
+    }
+
+    /// Redacts every string inside `item` by round-tripping it through JSON.
+    fn redact_serialized<T: Serialize + DeserializeOwned>(&self, item: T) -> T {
+        if self.is_empty() {
+            return item;
+        }
+        let mut value = match serde_json::to_value(&item) {
+            Ok(value) => value,
+            Err(err) => {
+                warn!("failed to serialize item for secret redaction: {err}");
+                return item;
+            }
+        };
+        if !self.redact_value(&mut value) {
+            return item;
+        }
+        match serde_json::from_value(value) {
+            Ok(redacted) => redacted,
+            Err(err) => {
+                warn!("failed to deserialize redacted item: {err}");
+                item
+            }
+        }
+    }
+
+    fn redact_value(&self, value: &mut serde_json::Value) -> bool {
+        match value {
+            serde_json::Value::String(text) => match self.redact(text) {
+                Cow::Owned(redacted) => {
+                    *text = redacted;
+                    true
+                }
+                Cow::Borrowed(_) => false,
+            },
+            serde_json::Value::Array(items) => items
+                .iter_mut()
+                .fold(false, |changed, item| self.redact_value(item) || changed),
+            serde_json::Value::Object(map) => map
+                .values_mut()
+                .fold(false, |changed, item| self.redact_value(item) || changed),
+            _ => false,
+        }
+    }
+
+    fn redactable(&self) -> impl Iterator<Item = &(String, String)> {
+        self.secrets
+            .iter()
+            .filter(|(_, value)| value.len() >= MIN_REDACTED_SECRET_LEN)
+    }
+}
+
+fn placeholder(name: &str) -> String {
+    format!("[REDACTED:{name}]")
+}
+
+fn resolve_blocking(
+    secrets_config: &SecretsConfig,
+    names: &[String],
+    codex_home: std::path::PathBuf,
+    cwd: &Path,
+) -> (Vec<(String, String)>, Vec<String>) {
+    let mut warnings = Vec::new();
+    let manager = match SecretsManager::from_config(codex_home, secrets_config) {
+        Ok(manager) => manager,
+        Err(err) => {
+            warnings.push(format!(
+                "Failed to open the secrets backend; shell environment secrets are unavailable: {err:#}"
+            ));
+            return (Vec::new(), warnings);
+        }
+    };
+    let environment_scope = SecretScope::environment(environment_id_from_cwd(cwd)).ok();
+
+    let mut secrets = Vec::new();
+    for raw_name in names {
+        let name = match SecretName::new(raw_name) {
+            Ok(name) => name,
+            Err(err) => {
+                warnings.push(format!(
+                    "Ignoring shell_environment_policy.secrets entry `{raw_name}`: {err}"
+                ));
+                continue;
+            }
+        };
+        let scopes = environment_scope
+            .iter()
+            .chain(std::iter::once(&SecretScope::Global));
+        let mut resolved = None;
+        for scope in scopes {
+            match manager.get(scope, &name) {
+                Ok(Some(value)) => {
+                    resolved = Some(value);
+                    break;
+                }
+                Ok(None) => {}
+                Err(err) => {
+                    warnings.push(format!("Failed to read secret `{name}`: {err:#}"));
+                    break;
+                }
+            }
+        }
+        match resolved {
+            Some(value) => {
+                if value.len() < MIN_REDACTED_SECRET_LEN {
+                    warn!("secret {name} is too short to be redacted from output");
+                }
+                secrets.push((name.to_string(), value));
+            }
+            None => warnings.push(format!(
+                "Secret `{name}` from shell_environment_policy.secrets was not found."
+            )),
+        }
+    }
+    (secrets, warnings)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+    use std::time::Duration;
+
+    use codex_protocol::models::FunctionCallOutputPayload;
+    use codex_protocol::protocol::ExecCommandEndEvent;
+    use codex_protocol::protocol::ExecCommandSource;
+    use pretty_assertions::assert_eq;
+
+    fn secrets() -> SessionSecrets {
+        SessionSecrets::new(vec![
+            ("NPM_TOKEN".to_string(), "npm-123456".to_string()),
+            ("PIN".to_string(), "42".to_string()),
+        ])
+    }
+
+    #[test]
+    fn injects_and_redacts_secret_values() {
+        let secrets = secrets();
+        let env = secrets.inject(HashMap::from([("PATH".to_string(), "/bin".to_string())]));
+        assert_eq!(env.get("NPM_TOKEN").map(String::as_str), Some("npm-123456"));
+        assert_eq!(env.get("PIN").map(String::as_str), Some("42"));
+
+        assert_eq!(
+            secrets.redact("token=npm-123456 pin=42"),
+            "token=[REDACTED:NPM_TOKEN] pin=42"
+        );
+
+        let output = secrets.redact_exec_output(ExecToolCallOutput {
+            aggregated_output: StreamOutput::new("npm-123456\n".to_string()),
+            ..ExecToolCallOutput::default()
+        });
+        assert_eq!(output.aggregated_output.text, "[REDACTED:NPM_TOKEN]\n");
+    }
+
+    #[test]
+    fn redacts_response_items_and_output_deltas() {
+        let secrets = secrets();
+        let items = secrets.redact_response_items(&[ResponseItem::FunctionCallOutput {
+            call_id: "call-1".to_string(),
+            output: FunctionCallOutputPayload::from_text("echo npm-123456".to_string()),
+        }]);
+        let ResponseItem::FunctionCallOutput { output, .. } = &items[0] else {
+            panic!("expected function call output");
+        };
+        assert_eq!(output.text_content(), Some("echo [REDACTED:NPM_TOKEN]"));
+
+        let msg = secrets.redact_event_msg(EventMsg::ExecCommandOutputDelta(
+            ExecCommandOutputDeltaEvent {
+                call_id: "call-1".to_string(),
+                stream: ExecOutputStream::Stdout,
+                chunk: b"a npm-123456 b".to_vec(),
+            },
+        ));
+        let EventMsg::ExecCommandOutputDelta(delta) = msg else {
+            panic!("expected output delta");
+        };
+        assert_eq!(delta.chunk, b"a [REDACTED:NPM_TOKEN] b".to_vec());
+    }
+
+    #[test]
+    fn redacts_secret_split_across_live_output_deltas() {
+        let secrets = secrets();
+        let delta = |chunk: &[u8]| {
+            EventMsg::ExecCommandOutputDelta(ExecCommandOutputDeltaEvent {
+                call_id: "call-1".to_string(),
+                stream: ExecOutputStream::Stdout,
+                chunk: chunk.to_vec(),
+            })
+        };
+        let mut events = secrets.redact_live_event_msg(delta(b"a npm-12"));
+        events.extend(secrets.redact_live_event_msg(delta(b"3456 b\n")));
+        events.extend(secrets.redact_live_event_msg(EventMsg::ExecCommandEnd(
+            ExecCommandEndEvent {
+                call_id: "call-1".to_string(),
+                process_id: None,
+                turn_id: "turn-1".to_string(),
+                command: vec!["cat".to_string()],
+                cwd: PathBuf::from("/tmp"),
+                parsed_cmd: Vec::new(),
+                source: ExecCommandSource::Agent,
+                interaction_input: None,
+                stdout: "a npm-123456 b\n".to_string(),
+                stderr: String::new(),
+                aggregated_output: "a npm-123456 b\n".to_string(),
+                exit_code: 0,
+                duration: Duration::ZERO,
+                formatted_output: String::new(),
+            },
+        )));
+
+        let mut streamed = Vec::new();
+        for event in &events[..events.len() - 1] {
+            let EventMsg::ExecCommandOutputDelta(delta) = event else {
+                panic!("expected output delta, got {event:?}");
+            };
+            streamed.extend_from_slice(&delta.chunk);
+        }
+        assert_eq!(
+            String::from_utf8(streamed).expect("utf-8 output"),
+            "a [REDACTED:NPM_TOKEN] b\n"
+        );
+        let Some(EventMsg::ExecCommandEnd(end)) = events.last() else {
+            panic!("expected the end event last");
+        };
+        assert_eq!(end.aggregated_output, "a [REDACTED:NPM_TOKEN] b\n");
+    }
+
+    #[test]
+    fn releases_held_output_when_the_command_waits() {
+        let secrets = secrets();
+        let mut events = secrets.redact_live_event_msg(EventMsg::ExecCommandOutputDelta(
+            ExecCommandOutputDeltaEvent {
+                call_id: "call-1".to_string(),
+                stream: ExecOutputStream::Stdout,
+                chunk: b"npm-123456 ok\nPassword: ".to_vec(),
+            },
+        ));
+        events.extend(secrets.release_held_output("call-1"));
+
+        let mut streamed = Vec::new();
+        for event in &events {
+            let EventMsg::ExecCommandOutputDelta(delta) = event else {
+                panic!("expected output delta, got {event:?}");
+            };
+            streamed.extend_from_slice(&delta.chunk);
+        }
+        assert_eq!(
+            String::from_utf8(streamed).expect("utf-8 output"),
+            "[REDACTED:NPM_TOKEN] ok\nPassword: "
+        );
+        assert!(secrets.release_held_output("call-1").is_empty());
+    }
+}
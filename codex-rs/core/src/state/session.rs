@@ -30,6 +30,8 @@ pub(crate) struct SessionState {
     /// Startup regular task pre-created during session initialization.
     pub(crate) startup_regular_task: Option<RegularTask>,
     pub(crate) active_mcp_tool_selection: Option<Vec<String>>,
+    /// Turns started in this session, counted against the agent budget.
+    pub(crate) agent_turns_started: u32,
 }
 
 impl SessionState {
@@ -47,6 +49,7 @@ impl SessionState {
             pending_resume_previous_model: None,
             startup_regular_task: None,
             active_mcp_tool_selection: None,
+            agent_turns_started: 0,
         }
     }
 
@@ -80,10 +83,25 @@ impl SessionState {
         self.history.update_token_info(usage, model_context_window);
     }
 
+    pub(crate) fn add_to_total_token_usage(
+        &mut self,
+        usage: &TokenUsage,
+        model_context_window: Option<i64>,
+    ) {
+        self.history
+            .add_to_total_token_usage(usage, model_context_window);
+    }
+
     pub(crate) fn token_info(&self) -> Option<TokenUsageInfo> {
         self.history.token_info()
     }
 
+    /// Tokens used across every turn of the session.
+    pub(crate) fn total_tokens_used(&self) -> i64 {
+        self.token_info()
+            .map_or(0, |info| info.total_token_usage.total_tokens)
+    }
+
     pub(crate) fn set_rate_limits(&mut self, snapshot: RateLimitSnapshot) {
         self.latest_rate_limits = Some(merge_rate_limit_fields(
             self.latest_rate_limits.as_ref(),
@@ -1,6 +1,7 @@
 use crate::config::Config;
 use crate::features::Feature;
 use crate::path_utils::normalize_for_path_comparison;
+use crate::rollout::RolloutRecorder;
 use crate::rollout::list::Cursor;
 use crate::rollout::list::ThreadSortKey;
 use crate::rollout::metadata;
@@ -21,9 +22,17 @@ use serde_json::Value;
 use std::path::Path;
 use std::path::PathBuf;
 use std::sync::Arc;
+use std::sync::atomic::AtomicBool;
+use std::sync::atomic::Ordering;
 use tracing::warn;
 use uuid::Uuid;
 
+/// Threads indexed per batch when a search finds threads without a search index.
+const SEARCH_INDEX_BATCH_SIZE: usize = 200;
+
+/// Set while a background task indexes threads that have no search index yet.
+static SEARCH_INDEXING: AtomicBool = AtomicBool::new(false);
+
 /// Core-facing handle to the optional SQLite-backed state runtime.
 pub type StateDbHandle = Arc<codex_state::StateRuntime>;
 
@@ -33,28 +42,7 @@ pub(crate) async fn init_if_enabled(
     config: &Config,
     otel: Option<&OtelManager>,
 ) -> Option<StateDbHandle> {
-    if !config.features.enabled(Feature::Sqlite) {
-        return None;
-    }
-    let runtime = match codex_state::StateRuntime::init(
-        config.codex_home.clone(),
-        config.model_provider_id.clone(),
-        otel.cloned(),
-    )
-    .await
-    {
-        Ok(runtime) => runtime,
-        Err(err) => {
-            warn!(
-                "failed to initialize state runtime at {}: {err}",
-                config.codex_home.display()
-            );
-            if let Some(otel) = otel {
-                otel.counter("codex.db.init", 1, &[("status", "init_error")]);
-            }
-            return None;
-        }
-    };
+    let runtime = open_if_enabled(config, otel).await?;
     let backfill_state = match runtime.get_backfill_state().await {
         Ok(state) => state,
         Err(err) => {
@@ -77,24 +65,48 @@ pub(crate) async fn init_if_enabled(
     Some(runtime)
 }
 
-/// Get the DB if the feature is enabled and the DB exists.
-pub async fn get_state_db(config: &Config, otel: Option<&OtelManager>) -> Option<StateDbHandle> {
-    let state_path = codex_state::state_db_path(config.codex_home.as_path());
-    if !config.features.enabled(Feature::Sqlite)
-        || !tokio::fs::try_exists(&state_path).await.unwrap_or(false)
-    {
+async fn open_if_enabled(config: &Config, otel: Option<&OtelManager>) -> Option<StateDbHandle> {
+    if !config.features.enabled(Feature::Sqlite) {
         return None;
     }
-    let runtime = codex_state::StateRuntime::init(
+    match codex_state::StateRuntime::init(
         config.codex_home.clone(),
         config.model_provider_id.clone(),
         otel.cloned(),
     )
     .await
-    .ok()?;
+    {
+        Ok(runtime) => Some(runtime),
+        Err(err) => {
+            warn!(
+                "failed to initialize state runtime at {}: {err}",
+                config.codex_home.display()
+            );
+            if let Some(otel) = otel {
+                otel.counter("codex.db.init", 1, &[("status", "init_error")]);
+            }
+            None
+        }
+    }
+}
+
+/// Get the DB if the feature is enabled and the DB exists.
+pub async fn get_state_db(config: &Config, otel: Option<&OtelManager>) -> Option<StateDbHandle> {
+    let state_path = codex_state::state_db_path(config.codex_home.as_path());
+    if !tokio::fs::try_exists(&state_path).await.unwrap_or(false) {
+        return None;
+    }
+    let runtime = open_if_enabled(config, otel).await?;
     require_backfill_complete(runtime, config.codex_home.as_path()).await
 }
 
+/// Get the DB for full-text search if the feature is enabled, creating it and
+/// starting the rollout backfill when no session has done so yet. Searches run
+/// against whatever has been indexed so far.
+pub async fn get_state_db_for_search(config: &Config) -> Option<StateDbHandle> {
+    init_if_enabled(config, None).await
+}
+
 /// Open the state runtime when the SQLite file exists, without feature gating.
 ///
 /// This is used for parity checks during the SQLite migration phase.
@@ -268,6 +280,68 @@ pub async fn list_threads_db(
     }
 }
 
+/// Search threads in SQLite. Threads recorded before the search index existed
+/// are indexed in the background, so results may be partial until that
+/// finishes.
+pub async fn search_threads(
+    context: Option<&StateDbHandle>,
+    query: &str,
+    limit: usize,
+    archived: bool,
+) -> Option<Vec<codex_state::ThreadSearchHit>> {
+    let ctx = context?;
+    if !SEARCH_INDEXING.swap(true, Ordering::AcqRel) {
+        let ctx = Arc::clone(ctx);
+        tokio::spawn(async move {
+            index_missing_thread_search(ctx.as_ref()).await;
+            SEARCH_INDEXING.store(false, Ordering::Release);
+        });
+    }
+    match ctx.search_threads(query, limit, archived).await {
+        Ok(hits) => Some(hits),
+        Err(err) => {
+            warn!("state db search_threads failed: {err}");
+            None
+        }
+    }
+}
+
+async fn index_missing_thread_search(ctx: &codex_state::StateRuntime) {
+    loop {
+        let threads = match ctx
+            .list_threads_missing_search_index(SEARCH_INDEX_BATCH_SIZE)
+            .await
+        {
+            Ok(threads) => threads,
+            Err(err) => {
+                warn!("state db list_threads_missing_search_index failed: {err}");
+                return;
+            }
+        };
+        if threads.is_empty() {
+            return;
+        }
+        for thread in threads {
+            // Unreadable rollouts are indexed as empty so they are not retried
+            // on every search.
+            let items = match RolloutRecorder::load_rollout_items(&thread.rollout_path).await {
+                Ok((items, _, _)) => items,
+                Err(err) => {
+                    warn!(
+                        "failed to load rollout {} for search indexing: {err}",
+                        thread.rollout_path.display()
+                    );
+                    Vec::new()
+                }
+            };
+            if let Err(err) = ctx.replace_thread_search_entries(thread.id, &items).await {
+                warn!("state db replace_thread_search_entries failed: {err}");
+                return;
+            }
+        }
+    }
+}
+
 /// Look up the rollout path for a thread id using SQLite.
 pub async fn find_rollout_path_by_id(
     context: Option<&codex_state::StateRuntime>,
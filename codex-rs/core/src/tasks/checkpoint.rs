@@ -0,0 +1,359 @@
+use std::path::Path;
+use std::sync::Arc;
+
+use crate::Prompt;
+use crate::client_common::ResponseEvent;
+use crate::codex::Session;
+use crate::codex::TurnContext;
+use crate::codex::get_last_assistant_message_from_turn;
+use crate::error::CodexErr;
+use crate::error::Result as CodexResult;
+use crate::protocol::CheckpointCreatedEvent;
+use crate::protocol::EventMsg;
+use crate::state::TaskKind;
+use crate::tasks::SessionTask;
+use crate::tasks::SessionTaskContext;
+use crate::tasks::ghost_snapshot::capture_ghost_snapshot;
+use crate::util::backoff;
+use async_trait::async_trait;
+use chrono::Local;
+use codex_protocol::models::ResponseInputItem;
+use codex_protocol::models::ResponseItem;
+use codex_protocol::protocol::CodexErrorInfo;
+use codex_protocol::protocol::ErrorEvent;
+use codex_protocol::user_input::UserInput;
+use futures::prelude::*;
+use serde::Deserialize;
+use serde_json::Value;
+use serde_json::json;
+use tokio_util::sync::CancellationToken;
+use tracing::warn;
+
+const CHECKPOINT_PROMPT: &str = include_str!("../../templates/checkpoint/prompt.md");
+
+/// Development log maintained by `/checkpoint`, relative to the session cwd.
+pub(crate) const CHECKPOINT_RELATIVE_PATH: &str = ".codex/checkpoint.md";
+
+/// Writes a structured checkpoint entry, captures a ghost snapshot and records a
+/// `CheckpointCreated` rollout marker that `/resume` and `/fork` can restart from.
+pub(crate) struct CheckpointTask {
+    name: Option<String>,
+}
+
+impl CheckpointTask {
+    pub(crate) fn new(name: Option<String>) -> Self {
+        Self { name }
+    }
+}
+
+#[derive(Debug, Default, Deserialize, PartialEq)]
+struct CheckpointSummary {
+    #[serde(default)]
+    goals: Vec<String>,
+    #[serde(default)]
+    done: Vec<String>,
+    #[serde(default)]
+    todo: Vec<String>,
+    #[serde(default)]
+    risks: Vec<String>,
+}
+
+#[async_trait]
+impl SessionTask for CheckpointTask {
+    fn kind(&self) -> TaskKind {
+        TaskKind::Regular
+    }
+
+    async fn run(
+        self: Arc<Self>,
+        session: Arc<SessionTaskContext>,
+        ctx: Arc<TurnContext>,
+        _input: Vec<UserInput>,
+        cancellation_token: CancellationToken,
+    ) -> Option<String> {
+        let sess = session.clone_session();
+        let _ = sess
+            .services
+            .otel_manager
+            .counter("codex.task.checkpoint", 1, &[]);
+        let now = Local::now();
+        let name = self
+            .name
+            .as_deref()
+            .map(str::trim)
+            .filter(|name| !name.is_empty())
+            .map(str::to_string)
+            .unwrap_or_else(|| format!("checkpoint-{}", now.format("%Y%m%d-%H%M%S")));
+        sess.notify_background_event(ctx.as_ref(), format!("Writing checkpoint `{name}`..."))
+            .await;
+
+        let summary = tokio::select! {
+            _ = cancellation_token.cancelled() => return None,
+            summary = request_checkpoint_summary(&sess, ctx.as_ref()) => summary,
+        };
+        let summary = match summary {
+            Ok(summary) => summary,
+            Err(err) => {
+                send_checkpoint_error(&sess, ctx.as_ref(), format!("Checkpoint failed: {err}"))
+                    .await;
+                return None;
+            }
+        };
+
+        let ghost_commit =
+            capture_ghost_snapshot(&sess, ctx.as_ref(), !ctx.ghost_snapshot.disable_warnings)
+                .await
+                .map(|ghost_commit| ghost_commit.id().to_string());
+
+        let entry = render_checkpoint_entry(
+            &name,
+            &now.format("%Y-%m-%d %H:%M:%S %Z").to_string(),
+            ghost_commit.as_deref(),
+            &summary,
+        );
+        let path = ctx.cwd.join(CHECKPOINT_RELATIVE_PATH);
+        if let Err(err) = append_checkpoint_entry(&path, &entry).await {
+            send_checkpoint_error(
+                &sess,
+                ctx.as_ref(),
+                format!("Failed to write {}: {err}", path.display()),
+            )
+            .await;
+            return None;
+        }
+
+        sess.send_event(
+            ctx.as_ref(),
+            EventMsg::CheckpointCreated(CheckpointCreatedEvent {
+                name,
+                path,
+                ghost_commit,
+            }),
+        )
+        .await;
+        None
+    }
+}
+
+async fn send_checkpoint_error(sess: &Session, ctx: &TurnContext, message: String) {
+    warn!("{message}");
+    sess.send_event(
+        ctx,
+        EventMsg::Error(ErrorEvent {
+            message,
+            codex_error_info: Some(CodexErrorInfo::Other),
+        }),
+    )
+    .await;
+}
+
+/// Asks the model for the checkpoint fields without recording the exchange in history.
+async fn request_checkpoint_summary(
+    sess: &Session,
+    ctx: &TurnContext,
+) -> CodexResult<CheckpointSummary> {
+    let mut history = sess.clone_history().await;
+    let request: ResponseInputItem = ResponseInputItem::from(vec![UserInput::Text {
+        text: CHECKPOINT_PROMPT.to_string(),
+        // Checkpoint prompt is synthesized; no UI element ranges to preserve.
+        text_elements: Vec::new(),
+    }]);
+    history.record_items(&[request.into()], ctx.truncation_policy);
+    let prompt = Prompt {
+        input: history.for_prompt(&ctx.model_info.input_modalities),
+        base_instructions: sess.get_base_instructions().await,
+        personality: ctx.personality,
+        output_schema: Some(checkpoint_output_schema()),
+        ..Default::default()
+    };
+
+    let max_retries = ctx.provider.stream_max_retries();
+    let mut retries = 0;
+    let turn_metadata_header = ctx.resolve_turn_metadata_header().await;
+    let mut client_session = sess.services.model_client.new_session();
+    loop {
+        let attempt: CodexResult<Vec<ResponseItem>> = async {
+            let mut stream = client_session
+                .stream(
+                    &prompt,
+                    &ctx.model_info,
+                    &ctx.otel_manager,
+                    ctx.reasoning_effort,
+                    ctx.reasoning_summary,
+                    turn_metadata_header.as_deref(),
+                )
+                .await?;
+            let mut output = Vec::new();
+            loop {
+                match stream.next().await {
+                    Some(Ok(ResponseEvent::OutputItemDone(item))) => output.push(item),
+                    Some(Ok(ResponseEvent::RateLimits(snapshot))) => {
+                        sess.update_rate_limits(ctx, snapshot).await;
+                    }
+                    Some(Ok(ResponseEvent::Completed { token_usage, .. })) => {
+                        sess.update_token_usage_info(ctx, token_usage.as_ref())
+                            .await;
+                        return Ok(output);
+                    }
+                    Some(Ok(_)) => {}
+                    Some(Err(err)) => return Err(err),
+                    None => {
+                        return Err(CodexErr::Stream(
+                            "stream closed before response.completed".into(),
+                            None,
+                        ));
+                    }
+                }
+            }
+        }
+        .await;
+
+        match attempt {
+            Ok(output) => return parse_checkpoint_summary(&output),
+            Err(err @ (CodexErr::Interrupted | CodexErr::ContextWindowExceeded)) => {
+                return Err(err);
+            }
+            Err(err) if retries < max_retries => {
+                retries += 1;
+                sess.notify_stream_error(
+                    ctx,
+                    format!("Reconnecting... {retries}/{max_retries}"),
+                    err,
+                )
+                .await;
+                tokio::time::sleep(backoff(retries)).await;
+            }
+            Err(err) => return Err(err),
+        }
+    }
+}
+
+fn checkpoint_output_schema() -> Value {
+    let list = json!({ "type": "array", "items": { "type": "string" } });
+    json!({
+        "type": "object",
+        "properties": {
+            "goals": list,
+            "done": list,
+            "todo": list,
+            "risks": list,
+        },
+        "required": ["goals", "done", "todo", "risks"],
+        "additionalProperties": false,
+    })
+}
+
+/// Parses the model reply, tolerating Markdown fences or prose around the JSON object for
+/// providers that ignore the output schema.
+fn parse_checkpoint_summary(output: &[ResponseItem]) -> CodexResult<CheckpointSummary> {
+    let text = get_last_assistant_message_from_turn(output).unwrap_or_default();
+    let json = match (text.find('{'), text.rfind('}')) {
+        (Some(start), Some(end)) if start < end => &text[start..=end],
+        _ => {
+            return Err(CodexErr::InvalidRequest(
+                "model did not return a checkpoint".to_string(),
+            ));
+        }
+    };
+    Ok(serde_json::from_str(json)?)
+}
+
+fn render_checkpoint_entry(
+    name: &str,
+    timestamp: &str,
+    ghost_commit: Option<&str>,
+    summary: &CheckpointSummary,
+) -> String {
+    let mut entry = format!("## {timestamp} · {name}\n");
+    if let Some(ghost_commit) = ghost_commit {
+        entry.push_str(&format!("\nSnapshot: `{ghost_commit}`\n"));
+    }
+    for (title, items) in [
+        ("Goals", &summary.goals),
+        ("Done", &summary.done),
+        ("Todo", &summary.todo),
+        ("Risks", &summary.risks),
+    ] {
+        entry.push_str(&format!("\n### {title}\n\n"));
+        if items.is_empty() {
+            entry.push_str("- None\n");
+        }
+        for item in items {
+            entry.push_str(&format!("- {}\n", item.trim()));
+        }
+    }
+    entry
+}
+
+/// Appends `entry` to the log, separated from earlier entries by a blank line.
+async fn append_checkpoint_entry(path: &Path, entry: &str) -> std::io::Result<()> {
+    if let Some(parent) = path.parent() {
+        tokio::fs::create_dir_all(parent).await?;
+    }
+    let mut contents = match tokio::fs::read_to_string(path).await {
+        Ok(contents) => contents,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => String::new(),
+        Err(err) => return Err(err),
+    };
+    if !contents.is_empty() {
+        let trimmed_len = contents.trim_end().len();
+        contents.truncate(trimmed_len);
+        contents.push_str("\n\n");
+    }
+    contents.push_str(entry);
+    tokio::fs::write(path, contents).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use codex_protocol::models::ContentItem;
+    use pretty_assertions::assert_eq;
+
+    fn assistant(text: &str) -> ResponseItem {
+        ResponseItem::Message {
+            id: None,
+            role: "assistant".to_string(),
+            content: vec![ContentItem::OutputText {
+                text: text.to_string(),
+            }],
+            end_turn: None,
+            phase: None,
+        }
+    }
+
+    #[test]
+    fn parses_fenced_summary_and_renders_entry() {
+        let summary = parse_checkpoint_summary(&[assistant(
+            "```json\n{\"goals\": [\"Ship /checkpoint\"], \"done\": [\"Added Op::Checkpoint\"], \"todo\": [], \"risks\": [\"Untested on Windows\"]}\n```",
+        )])
+        .expect("summary");
+
+        assert_eq!(
+            render_checkpoint_entry(
+                "before-refactor",
+                "2026-10-18 12:00:00 +08:00",
+                Some("abc123"),
+                &summary
+            ),
+            "## 2026-10-18 12:00:00 +08:00 · before-refactor\n\nSnapshot: `abc123`\n\n### Goals\n\n- Ship /checkpoint\n\n### Done\n\n- Added Op::Checkpoint\n\n### Todo\n\n- None\n\n### Risks\n\n- Untested on Windows\n"
+        );
+        assert!(parse_checkpoint_summary(&[assistant("no json here")]).is_err());
+    }
+
+    #[tokio::test]
+    async fn append_preserves_existing_entries() {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let path = dir.path().join(CHECKPOINT_RELATIVE_PATH);
+
+        append_checkpoint_entry(&path, "## first\n")
+            .await
+            .expect("first entry");
+        append_checkpoint_entry(&path, "## second\n")
+            .await
+            .expect("second entry");
+
+        let contents = std::fs::read_to_string(&path).expect("read log");
+        assert_eq!(contents, "## first\n\n## second\n");
+    }
+}
@@ -1,3 +1,4 @@
+use crate::codex::Session;
 use crate::codex::TurnContext;
 use crate::protocol::EventMsg;
 use crate::protocol::WarningEvent;
@@ -6,6 +7,7 @@ use crate::tasks::SessionTask;
 use crate::tasks::SessionTaskContext;
 use async_trait::async_trait;
 use codex_git::CreateGhostCommitOptions;
+use codex_git::GhostCommit;
 use codex_git::GhostSnapshotReport;
 use codex_git::GitToolingError;
 use codex_git::create_ghost_commit_with_report;
@@ -75,69 +77,7 @@ impl SessionTask for GhostSnapshotTask {
             let ctx_for_task = ctx.clone();
             let cancelled = tokio::select! {
                 _ = cancellation_token.cancelled() => true,
-                _ = async {
-                    let repo_path = ctx_for_task.cwd.clone();
-                    let ghost_snapshot = ctx_for_task.ghost_snapshot.clone();
-                    let ghost_snapshot_for_commit = ghost_snapshot.clone();
-                    // Required to run in a dedicated blocking pool.
-                    match tokio::task::spawn_blocking(move || {
-                        let options =
-                            CreateGhostCommitOptions::new(&repo_path).ghost_snapshot(ghost_snapshot_for_commit);
-                        create_ghost_commit_with_report(&options)
-                    })
-                    .await
-                    {
-                        Ok(Ok((ghost_commit, report))) => {
-                            info!("ghost snapshot blocking task finished");
-                            if warnings_enabled {
-                                for message in format_snapshot_warnings(
-                                    ghost_snapshot.ignore_large_untracked_files,
-                                    ghost_snapshot.ignore_large_untracked_dirs,
-                                    &report,
-                                ) {
-                                    session
-                                        .session
-                                        .send_event(
-                                            &ctx_for_task,
-                                            EventMsg::Warning(WarningEvent { message }),
-                                        )
-                                        .await;
-                                }
-                            }
-                            session
-                                .session
-                                .record_conversation_items(&ctx, &[ResponseItem::GhostSnapshot {
-                                    ghost_commit: ghost_commit.clone(),
-                                }])
-                                .await;
-                            info!("ghost commit captured: {}", ghost_commit.id());
-                        }
-                        Ok(Err(err)) => match err {
-                            GitToolingError::NotAGitRepository { .. } => info!(
-                                sub_id = ctx_for_task.sub_id.as_str(),
-                                "skipping ghost snapshot because current directory is not a Git repository"
-                            ),
-                            _ => {
-                                warn!(
-                                    sub_id = ctx_for_task.sub_id.as_str(),
-                                    "failed to capture ghost snapshot: {err}"
-                                );
-                            }
-                        },
-                        Err(err) => {
-                            warn!(
-                                sub_id = ctx_for_task.sub_id.as_str(),
-                                "ghost snapshot task panicked: {err}"
-                            );
-                            let message =
-                                format!("Snapshots disabled after ghost snapshot panic: {err}.");
-                            session
-                                .session
-                                .notify_background_event(&ctx_for_task, message)
-                                .await;
-                        }
-                    }
-                } => false,
+                _ = capture_ghost_snapshot(&session.session, &ctx_for_task, warnings_enabled) => false,
             };
 
             let _ = snapshot_done_tx.send(());
@@ -162,6 +102,77 @@ impl GhostSnapshotTask {
     }
 }
 
+/// Captures a ghost commit of the working tree and records it in the conversation history.
+///
+/// Returns `None` when the cwd is not a Git repository or the snapshot failed; failures are
+/// logged rather than surfaced so callers can continue without a snapshot.
+pub(crate) async fn capture_ghost_snapshot(
+    session: &Session,
+    ctx: &TurnContext,
+    warnings_enabled: bool,
+) -> Option<GhostCommit> {
+    let repo_path = ctx.cwd.clone();
+    let ghost_snapshot = ctx.ghost_snapshot.clone();
+    let ghost_snapshot_for_commit = ghost_snapshot.clone();
+    // Required to run in a dedicated blocking pool.
+    match tokio::task::spawn_blocking(move || {
+        let options =
+            CreateGhostCommitOptions::new(&repo_path).ghost_snapshot(ghost_snapshot_for_commit);
+        create_ghost_commit_with_report(&options)
+    })
+    .await
+    {
+        Ok(Ok((ghost_commit, report))) => {
+            info!("ghost snapshot blocking task finished");
+            if warnings_enabled {
+                for message in format_snapshot_warnings(
+                    ghost_snapshot.ignore_large_untracked_files,
+                    ghost_snapshot.ignore_large_untracked_dirs,
+                    &report,
+                ) {
+                    session
+                        .send_event(ctx, EventMsg::Warning(WarningEvent { message }))
+                        .await;
+                }
+            }
+            session
+                .record_conversation_items(
+                    ctx,
+                    &[ResponseItem::GhostSnapshot {
+                        ghost_commit: ghost_commit.clone(),
+                    }],
+                )
+                .await;
+            info!("ghost commit captured: {}", ghost_commit.id());
+            Some(ghost_commit)
+        }
+        Ok(Err(err)) => {
+            match err {
+                GitToolingError::NotAGitRepository { .. } => info!(
+                    sub_id = ctx.sub_id.as_str(),
+                    "skipping ghost snapshot because current directory is not a Git repository"
+                ),
+                _ => {
+                    warn!(
+                        sub_id = ctx.sub_id.as_str(),
+                        "failed to capture ghost snapshot: {err}"
+                    );
+                }
+            }
+            None
+        }
+        Err(err) => {
+            warn!(
+                sub_id = ctx.sub_id.as_str(),
+                "ghost snapshot task panicked: {err}"
+            );
+            let message = format!("Snapshots disabled after ghost snapshot panic: {err}.");
+            session.notify_background_event(ctx, message).await;
+            None
+        }
+    }
+}
+
 fn format_snapshot_warnings(
     ignore_large_untracked_files: Option<i64>,
     ignore_large_untracked_dirs: Option<i64>,
@@ -1,3 +1,4 @@
+mod checkpoint;
 mod compact;
 mod ghost_snapshot;
 mod regular;
@@ -37,6 +38,7 @@ use codex_protocol::models::ResponseItem;
 use codex_protocol::protocol::RolloutItem;
 use codex_protocol::user_input::UserInput;
 
+pub(crate) use checkpoint::CheckpointTask;
 pub(crate) use compact::CompactTask;
 pub(crate) use ghost_snapshot::GhostSnapshotTask;
 pub(crate) use regular::RegularTask;
@@ -361,10 +361,10 @@ async fn run_git_logged(
     let exec_env = ExecRequest {
         command: command.clone(),
         cwd: turn_context.cwd.clone(),
-        env: create_env(
+        env: session.services.secrets.inject(create_env(
             &turn_context.shell_environment_policy,
             Some(session.conversation_id),
-        ),
+        )),
         network: None,
         expiration: SDD_GIT_TIMEOUT_MS.into(),
         sandbox: SandboxType::None,
@@ -143,10 +143,10 @@ pub(crate) async fn execute_user_shell_command(
     let exec_env = ExecRequest {
         command: exec_command.clone(),
         cwd: cwd.clone(),
-        env: create_env(
+        env: session.services.secrets.inject(create_env(
             &turn_context.shell_environment_policy,
             Some(session.conversation_id),
-        ),
+        )),
         network: turn_context.network.clone(),
         // TODO(zhao-oai): Now that we have ExecExpiration::Cancellation, we
         // should use that instead of an "arbitrarily large" timeout here.
@@ -0,0 +1,880 @@
+//! Render stored threads as Markdown, HTML or JSON transcripts.
+//!
+//! Transcripts are built from the persisted rollout items and follow what the TUI history shows:
+//! user and agent messages, reasoning summaries, commands with head/tail-truncated output, patches
+//! as diffs and the final token usage. Values of `shell_environment_policy.secrets` are redacted
+//! again before rendering, which also covers secrets configured after the thread was recorded.
+
+use std::collections::HashMap;
+use std::fmt::Write as _;
+use std::io;
+use std::path::Path;
+use std::path::PathBuf;
+
+use codex_protocol::ThreadId;
+use codex_protocol::models::LocalShellAction;
+use codex_protocol::models::ResponseItem;
+use codex_protocol::protocol::EventMsg;
+use codex_protocol::protocol::FinalOutput;
+use codex_protocol::protocol::RolloutItem;
+use codex_protocol::protocol::TokenUsage;
+use codex_protocol::protocol::USER_MESSAGE_BEGIN;
+use codex_shell_command::parse_command::extract_shell_command;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+use tracing::warn;
+
+use crate::config::Config;
+use crate::rollout::RolloutRecorder;
+use crate::rollout::find_thread_name_by_id;
+use crate::session_secrets::SessionSecrets;
+
+/// Command output lines kept from the start and from the end of the output.
+const OUTPUT_EDGE_LINES: usize = 10;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ThreadExportFormat {
+    Markdown,
+    Html,
+    Json,
+}
+
+/// A thread reduced to the parts worth sharing.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct ThreadTranscript {
+    pub thread_id: Option<ThreadId>,
+    pub name: Option<String>,
+    pub started_at: Option<String>,
+    pub cwd: Option<PathBuf>,
+    pub model: Option<String>,
+    pub model_provider: Option<String>,
+    pub cli_version: Option<String>,
+    pub entries: Vec<TranscriptEntry>,
+    /// Token usage of the whole thread, from the last recorded token count.
+    pub token_usage: Option<TokenUsage>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum TranscriptEntry {
+    UserMessage {
+        text: String,
+    },
+    AgentMessage {
+        text: String,
+    },
+    /// Reasoning summary shown to the user.
+    Reasoning {
+        text: String,
+    },
+    ExecCommand {
+        command: String,
+        exit_code: Option<i32>,
+        /// Combined output, with the middle replaced by an `… +N lines` marker when long.
+        output: Option<String>,
+    },
+    Patch {
+        /// The patch rewritten as a diff with `---`/`+++` file headers.
+        diff: String,
+        exit_code: Option<i32>,
+    },
+    ToolCall {
+        name: String,
+        arguments: String,
+    },
+}
+
+/// Load the rollout at `rollout_path`, redact configured secrets and render it in `format`.
+pub async fn export_thread(
+    config: &Config,
+    rollout_path: &Path,
+    format: ThreadExportFormat,
+) -> io::Result<String> {
+    let (items, thread_id, _parse_errors) =
+        RolloutRecorder::load_rollout_items(rollout_path).await?;
+
+    let cwd = items
+        .iter()
+        .find_map(|item| match item {
+            RolloutItem::SessionMeta(meta_line) => Some(meta_line.meta.cwd.clone()),
+            _ => None,
+        })
+        .unwrap_or_else(|| config.cwd.clone());
+    let (secrets, warnings) = SessionSecrets::resolve(
+        &config.secrets,
+        &config.shell_environment_policy.secrets,
+        &config.codex_home,
+        &cwd,
+    )
+    .await;
+    for message in warnings {
+        warn!("{message}");
+    }
+    let items = secrets.redact_rollout_items(&items);
+
+    let mut transcript = ThreadTranscript::from_rollout_items(&items);
+    if let Some(thread_id) = thread_id {
+        transcript.name = find_thread_name_by_id(&config.codex_home, &thread_id)
+            .await
+            .unwrap_or_else(|err| {
+                warn!("failed to read thread name for {thread_id}: {err}");
+                None
+            })
+            .map(|name| secrets.redact(&name).into_owned());
+    }
+    transcript.render(format)
+}
+
+impl ThreadTranscript {
+    pub fn from_rollout_items(items: &[RolloutItem]) -> Self {
+        let mut transcript = Self::default();
+        // Entries waiting for the output of their tool call, by call id.
+        let mut pending_outputs: HashMap<String, usize> = HashMap::new();
+        // Index of the first entry of each user turn, to replay rollbacks.
+        let mut turn_starts: Vec<usize> = Vec::new();
+
+        for item in items {
+            match item {
+                RolloutItem::SessionMeta(meta_line) => {
+                    if transcript.thread_id.is_none() {
+                        let meta = &meta_line.meta;
+                        transcript.thread_id = Some(meta.id);
+                        transcript.started_at = Some(meta.timestamp.clone());
+                        transcript.cwd = Some(meta.cwd.clone());
+                        transcript.model_provider = meta.model_provider.clone();
+                        transcript.cli_version = Some(meta.cli_version.clone());
+                    }
+                }
+                RolloutItem::TurnContext(turn_context) => {
+                    transcript.model = Some(turn_context.model.clone());
+                }
+                RolloutItem::EventMsg(EventMsg::UserMessage(user)) => {
+                    turn_starts.push(transcript.entries.len());
+                    let text = match user.message.find(USER_MESSAGE_BEGIN) {
+                        Some(idx) => &user.message[idx + USER_MESSAGE_BEGIN.len()..],
+                        None => user.message.as_str(),
+                    };
+                    transcript.push_text(text, |text| TranscriptEntry::UserMessage { text });
+                }
+                RolloutItem::EventMsg(EventMsg::AgentMessage(agent)) => {
+                    transcript.push_text(&agent.message, |text| TranscriptEntry::AgentMessage {
+                        text,
+                    });
+                }
+                RolloutItem::EventMsg(EventMsg::AgentReasoning(reasoning)) => {
+                    transcript
+                        .push_text(&reasoning.text, |text| TranscriptEntry::Reasoning { text });
+                }
+                RolloutItem::EventMsg(EventMsg::TokenCount(token_count)) => {
+                    if let Some(info) = token_count.info.as_ref() {
+                        transcript.token_usage = Some(info.total_token_usage.clone());
+                    }
+                }
+                RolloutItem::EventMsg(EventMsg::ThreadRolledBack(rollback)) => {
+                    let kept_turns = turn_starts
+                        .len()
+                        .saturating_sub(rollback.num_turns as usize);
+                    if let Some(&start) = turn_starts.get(kept_turns) {
+                        transcript.entries.truncate(start);
+                        turn_starts.truncate(kept_turns);
+                        pending_outputs.retain(|_, idx| *idx < start);
+                    }
+                }
+                RolloutItem::ResponseItem(item) => {
+                    transcript.push_response_item(item, &mut pending_outputs);
+                }
+                RolloutItem::EventMsg(_) | RolloutItem::Compacted(_) => {}
+            }
+        }
+        transcript
+    }
+
+    pub fn render(&self, format: ThreadExportFormat) -> io::Result<String> {
+        match format {
+            ThreadExportFormat::Markdown => Ok(self.render_markdown()),
+            ThreadExportFormat::Html => Ok(self.render_html()),
+            ThreadExportFormat::Json => serde_json::to_string_pretty(self)
+                .map(|mut json| {
+                    json.push('\n');
+                    json
+                })
+                .map_err(io::Error::other),
+        }
+    }
+
+    fn push_text(&mut self, text: &str, entry: impl FnOnce(String) -> TranscriptEntry) {
+        let text = text.trim();
+        if !text.is_empty() {
+            self.entries.push(entry(text.to_string()));
+        }
+    }
+
+    fn push_response_item(
+        &mut self,
+        item: &ResponseItem,
+        pending_outputs: &mut HashMap<String, usize>,
+    ) {
+        let (call_id, entry) = match item {
+            ResponseItem::LocalShellCall {
+                call_id,
+                action: LocalShellAction::Exec(exec),
+                ..
+            } => (
+                call_id.clone(),
+                TranscriptEntry::ExecCommand {
+                    command: command_text(&exec.command),
+                    exit_code: None,
+                    output: None,
+                },
+            ),
+            ResponseItem::FunctionCall {
+                name,
+                arguments,
+                call_id,
+                ..
+            } => match function_call_entry(name, arguments) {
+                Some(entry) => (Some(call_id.clone()), entry),
+                None => return,
+            },
+            ResponseItem::CustomToolCall {
+                name,
+                input,
+                call_id,
+                ..
+            } => {
+                let entry = if name == "apply_patch" {
+                    TranscriptEntry::Patch {
+                        diff: patch_to_diff(input),
+                        exit_code: None,
+                    }
+                } else {
+                    TranscriptEntry::ToolCall {
+                        name: name.clone(),
+                        arguments: input.clone(),
+                    }
+                };
+                (Some(call_id.clone()), entry)
+            }
+            ResponseItem::FunctionCallOutput { call_id, output } => {
+                if let Some(text) = output.text_content() {
+                    self.attach_output(pending_outputs, call_id, text);
+                }
+                return;
+            }
+            ResponseItem::CustomToolCallOutput { call_id, output } => {
+                self.attach_output(pending_outputs, call_id, output);
+                return;
+            }
+            _ => return,
+        };
+        let awaits_output = matches!(
+            entry,
+            TranscriptEntry::ExecCommand { .. } | TranscriptEntry::Patch { .. }
+        );
+        if awaits_output && let Some(call_id) = call_id {
+            pending_outputs.insert(call_id, self.entries.len());
+        }
+        self.entries.push(entry);
+    }
+
+    fn attach_output(
+        &mut self,
+        pending_outputs: &mut HashMap<String, usize>,
+        call_id: &str,
+        raw: &str,
+    ) {
+        let Some(idx) = pending_outputs.remove(call_id) else {
+            return;
+        };
+        let (code, text) = parse_exec_output(raw);
+        match self.entries.get_mut(idx) {
+            Some(TranscriptEntry::ExecCommand {
+                exit_code, output, ..
+            }) => {
+                *exit_code = code;
+                *output = Some(truncate_output(&text));
+            }
+            Some(TranscriptEntry::Patch { exit_code, .. }) => *exit_code = code,
+            _ => {}
+        }
+    }
+
+    fn title(&self) -> String {
+        match (&self.name, self.thread_id) {
+            (Some(name), _) => name.clone(),
+            (None, Some(thread_id)) => format!("Codex thread {thread_id}"),
+            (None, None) => "Codex thread".to_string(),
+        }
+    }
+
+    /// `(label, value)` pairs describing the thread, in display order.
+    fn details(&self) -> Vec<(&'static str, String)> {
+        let mut details = Vec::new();
+        if let Some(thread_id) = self.thread_id {
+            details.push(("Thread", thread_id.to_string()));
+        }
+        if let Some(started_at) = &self.started_at {
+            details.push(("Started", started_at.clone()));
+        }
+        if let Some(cwd) = &self.cwd {
+            details.push(("Directory", cwd.display().to_string()));
+        }
+        match (&self.model, &self.model_provider) {
+            (Some(model), Some(provider)) => {
+                details.push(("Model", format!("{model} ({provider})")));
+            }
+            (Some(model), None) => details.push(("Model", model.clone())),
+            (None, Some(provider)) => details.push(("Model provider", provider.clone())),
+            (None, None) => {}
+        }
+        if let Some(cli_version) = &self.cli_version {
+            details.push(("CLI version", cli_version.clone()));
+        }
+        details
+    }
+
+    fn render_markdown(&self) -> String {
+        let mut out = format!("# {}\n", self.title());
+        let details = self.details();
+        if !details.is_empty() {
+            out.push('\n');
+        }
+        for (label, value) in details {
+            let _ = writeln!(out, "- **{label}:** {}", inline_code(&value));
+        }
+
+        for entry in &self.entries {
+            out.push('\n');
+            match entry {
+                TranscriptEntry::UserMessage { text } => {
+                    let _ = write!(out, "## User\n\n{text}\n");
+                }
+                TranscriptEntry::AgentMessage { text } => {
+                    let _ = write!(out, "## Codex\n\n{text}\n");
+                }
+                TranscriptEntry::Reasoning { text } => {
+                    out.push_str("> **Reasoning**\n>\n");
+                    for line in text.lines() {
+                        if line.is_empty() {
+                            out.push_str(">\n");
+                        } else {
+                            let _ = writeln!(out, "> {line}");
+                        }
+                    }
+                }
+                TranscriptEntry::ExecCommand {
+                    command,
+                    exit_code,
+                    output,
+                } => {
+                    out.push_str("**Ran**\n\n");
+                    push_code_block(&mut out, "sh", command);
+                    if let Some(output) = output.as_deref().filter(|output| !output.is_empty()) {
+                        out.push('\n');
+                        push_code_block(&mut out, "text", output);
+                    }
+                    if let Some(code) = exit_code.filter(|code| *code != 0) {
+                        let _ = write!(out, "\nExit code {code}\n");
+                    }
+                }
+                TranscriptEntry::Patch { diff, exit_code } => {
+                    out.push_str(match exit_code {
+                        Some(code) if *code != 0 => "**Edited (failed)**\n\n",
+                        _ => "**Edited**\n\n",
+                    });
+                    push_code_block(&mut out, "diff", diff);
+                }
+                TranscriptEntry::ToolCall { name, arguments } => {
+                    let _ = write!(out, "**Called** {}\n\n", inline_code(name));
+                    push_code_block(&mut out, "json", arguments);
+                }
+            }
+        }
+
+        if let Some(token_usage) = &self.token_usage {
+            let _ = write!(out, "\n---\n\n{}\n", FinalOutput::from(token_usage.clone()));
+        }
+        out
+    }
+
+    fn render_html(&self) -> String {
+        let title = escape_html(&self.title());
+        let mut out = format!(
+            "<!DOCTYPE html>\n<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n<title>{title}</title>\n<style>\n{HTML_STYLE}</style>\n</head>\n<body>\n<header>\n<h1>{title}</h1>\n<dl>\n"
+        );
+        for (label, value) in self.details() {
+            let _ = writeln!(out, "<dt>{label}</dt><dd>{}</dd>", escape_html(&value));
+        }
+        out.push_str("</dl>\n</header>\n<main>\n");
+
+        for entry in &self.entries {
+            match entry {
+                TranscriptEntry::UserMessage { text } => {
+                    let _ = writeln!(
+                        out,
+                        "<section class=\"user\"><h2>User</h2><div class=\"text\">{}</div></section>",
+                        escape_html(text)
+                    );
+                }
+                TranscriptEntry::AgentMessage { text } => {
+                    let _ = writeln!(
+                        out,
+                        "<section class=\"agent\"><h2>Codex</h2><div class=\"text\">{}</div></section>",
+                        escape_html(text)
+                    );
+                }
+                TranscriptEntry::Reasoning { text } => {
+                    let _ = writeln!(
+                        out,
+                        "<details class=\"reasoning\"><summary>Reasoning</summary><div class=\"text\">{}</div></details>",
+                        escape_html(text)
+                    );
+                }
+                TranscriptEntry::ExecCommand {
+                    command,
+                    exit_code,
+                    output,
+                } => {
+                    let _ = write!(
+                        out,
+                        "<section class=\"exec\"><h3>Ran</h3><pre class=\"command\">{}</pre>",
+                        escape_html(command)
+                    );
+                    if let Some(output) = output.as_deref().filter(|output| !output.is_empty()) {
+                        let _ = write!(out, "<pre class=\"output\">{}</pre>", escape_html(output));
+                    }
+                    if let Some(code) = exit_code.filter(|code| *code != 0) {
+                        let _ = write!(out, "<p class=\"exit\">Exit code {code}</p>");
+                    }
+                    out.push_str("</section>\n");
+                }
+                TranscriptEntry::Patch { diff, exit_code } => {
+                    let heading = match exit_code {
+                        Some(code) if *code != 0 => "Edited (failed)",
+                        _ => "Edited",
+                    };
+                    let _ = write!(
+                        out,
+                        "<section class=\"patch\"><h3>{heading}</h3><pre class=\"diff\">"
+                    );
+                    for line in diff.lines() {
+                        let class = if line.starts_with("+++") || line.starts_with("---") {
+                            "file"
+                        } else if line.starts_with("@@") {
+                            "hunk"
+                        } else if line.starts_with('+') {
+                            "add"
+                        } else if line.starts_with('-') {
+                            "del"
+                        } else {
+                            "ctx"
+                        };
+                        let _ =
+                            writeln!(out, "<span class=\"{class}\">{}</span>", escape_html(line));
+                    }
+                    out.push_str("</pre></section>\n");
+                }
+                TranscriptEntry::ToolCall { name, arguments } => {
+                    let _ = writeln!(
+                        out,
+                        "<section class=\"tool\"><h3>Called <code>{}</code></h3><pre>{}</pre></section>",
+                        escape_html(name),
+                        escape_html(arguments)
+                    );
+                }
+            }
+        }
+
+        out.push_str("</main>\n");
+        if let Some(token_usage) = &self.token_usage {
+            let _ = writeln!(
+                out,
+                "<footer>{}</footer>",
+                escape_html(&FinalOutput::from(token_usage.clone()).to_string())
+            );
+        }
+        out.push_str("</body>\n</html>\n");
+        out
+    }
+}
+
+const HTML_STYLE: &str = "body { font-family: -apple-system, BlinkMacSystemFont, \"Segoe UI\", sans-serif; max-width: 960px; margin: 2rem auto; padding: 0 1rem; color: #1f2328; }
+dl { display: grid; grid-template-columns: max-content 1fr; gap: 0.25rem 1rem; color: #59636e; }
+dd { margin: 0; font-family: ui-monospace, monospace; }
+section, details { margin: 1rem 0; padding: 0.75rem 1rem; border-radius: 6px; }
+h2, h3 { margin: 0 0 0.5rem; font-size: 0.9rem; text-transform: uppercase; color: #59636e; }
+.user { background: #ddf4ff; }
+.agent { background: #f6f8fa; }
+.reasoning { color: #59636e; font-style: italic; }
+.exec, .patch, .tool { border: 1px solid #d1d9e0; }
+.text { white-space: pre-wrap; }
+pre { margin: 0.5rem 0 0; padding: 0.5rem; overflow-x: auto; background: #f6f8fa; border-radius: 4px; }
+.output { color: #59636e; }
+.exit { margin: 0.5rem 0 0; color: #d1242f; }
+.diff span { display: block; }
+.diff .add { background: #dafbe1; }
+.diff .del { background: #ffebe9; }
+.diff .hunk, .diff .file { color: #59636e; }
+footer { margin-top: 2rem; color: #59636e; }
+";
+
+/// Map a function call to a transcript entry; `None` for calls that are not worth showing.
+fn function_call_entry(name: &str, arguments: &str) -> Option<TranscriptEntry> {
+    let exec = |command: String| TranscriptEntry::ExecCommand {
+        command,
+        exit_code: None,
+        output: None,
+    };
+    let parsed = serde_json::from_str::<Value>(arguments).ok();
+    let string_arg = |key: &str| {
+        parsed
+            .as_ref()
+            .and_then(|parsed| parsed.get(key))
+            .and_then(Value::as_str)
+            .map(str::to_string)
+    };
+    match name {
+        "shell" | "container.exec" => {
+            let command = parsed
+                .as_ref()
+                .and_then(|parsed| parsed.get("command"))
+                .and_then(Value::as_array)
+                .map(|command| {
+                    command
+                        .iter()
+                        .filter_map(Value::as_str)
+                        .map(str::to_string)
+                        .collect::<Vec<_>>()
+                });
+            if let Some(command) = command {
+                return Some(exec(command_text(&command)));
+            }
+        }
+        "shell_command" | "Bash" => {
+            if let Some(command) = string_arg("command") {
+                return Some(exec(command));
+            }
+        }
+        "exec_command" => {
+            if let Some(command) = string_arg("cmd") {
+                return Some(exec(command));
+            }
+        }
+        "apply_patch" => {
+            if let Some(input) = string_arg("input") {
+                return Some(TranscriptEntry::Patch {
+                    diff: patch_to_diff(&input),
+                    exit_code: None,
+                });
+            }
+        }
+        // Input written to a running command; its output belongs to that command.
+        "write_stdin" => return None,
+        _ => {}
+    }
+    let arguments = parsed
+        .and_then(|parsed| serde_json::to_string_pretty(&parsed).ok())
+        .unwrap_or_else(|| arguments.to_string());
+    Some(TranscriptEntry::ToolCall {
+        name: name.to_string(),
+        arguments,
+    })
+}
+
+/// Render an argv vector the way the TUI does, unwrapping `bash -lc "<script>"` to the script.
+fn command_text(command: &[String]) -> String {
+    if let Some((_, script)) = extract_shell_command(command) {
+        return script.to_string();
+    }
+    shlex::try_join(command.iter().map(String::as_str)).unwrap_or_else(|_| command.join(" "))
+}
+
+#[derive(Deserialize)]
+struct StructuredExecOutput {
+    output: String,
+    metadata: StructuredExecMetadata,
+}
+
+#[derive(Deserialize)]
+struct StructuredExecMetadata {
+    exit_code: i32,
+}
+
+/// Split a tool output as formatted for the model into its exit code and command output.
+fn parse_exec_output(raw: &str) -> (Option<i32>, String) {
+    if let Ok(parsed) = serde_json::from_str::<StructuredExecOutput>(raw) {
+        return (Some(parsed.metadata.exit_code), parsed.output);
+    }
+    let Some((header, output)) = raw.split_once("\nOutput:\n") else {
+        return (None, raw.to_string());
+    };
+    let exit_code = header.lines().find_map(|line| {
+        line.strip_prefix("Exit code: ")
+            .or_else(|| line.strip_prefix("Process exited with code "))
+            .and_then(|code| code.trim().parse().ok())
+    });
+    (exit_code, output.to_string())
+}
+
+/// Keep the first and last [`OUTPUT_EDGE_LINES`] lines of `output`.
+fn truncate_output(output: &str) -> String {
+    let lines: Vec<&str> = output.trim_end().lines().collect();
+    if lines.len() <= 2 * OUTPUT_EDGE_LINES {
+        return lines.join("\n");
+    }
+    let omitted = lines.len() - 2 * OUTPUT_EDGE_LINES;
+    let mut kept = lines[..OUTPUT_EDGE_LINES].to_vec();
+    let marker = format!("… +{omitted} lines");
+    kept.push(marker.as_str());
+    kept.extend_from_slice(&lines[lines.len() - OUTPUT_EDGE_LINES..]);
+    kept.join("\n")
+}
+
+/// Rewrite an `apply_patch` envelope as a diff: file operations become `---`/`+++` headers and
+/// the `@@`, context, added and removed lines are kept as they are.
+fn patch_to_diff(patch: &str) -> String {
+    let mut lines: Vec<String> = Vec::new();
+    for line in patch.lines() {
+        if let Some(path) = line.strip_prefix("*** Add File: ") {
+            lines.push("--- /dev/null".to_string());
+            lines.push(format!("+++ b/{path}"));
+        } else if let Some(path) = line.strip_prefix("*** Delete File: ") {
+            lines.push(format!("--- a/{path}"));
+            lines.push("+++ /dev/null".to_string());
+        } else if let Some(path) = line.strip_prefix("*** Update File: ") {
+            lines.push(format!("--- a/{path}"));
+            lines.push(format!("+++ b/{path}"));
+        } else if let Some(path) = line.strip_prefix("*** Move to: ") {
+            if let Some(last) = lines.last_mut() {
+                *last = format!("+++ b/{path}");
+            }
+        } else if !matches!(
+            line,
+            "*** Begin Patch" | "*** End Patch" | "*** End of File"
+        ) {
+            lines.push(line.to_string());
+        }
+    }
+    lines.join("\n")
+}
+
+fn inline_code(text: &str) -> String {
+    let fence = "`".repeat(longest_backtick_run(text) + 1);
+    if text.starts_with('`') || text.ends_with('`') {
+        format!("{fence} {text} {fence}")
+    } else {
+        format!("{fence}{text}{fence}")
+    }
+}
+
+fn push_code_block(out: &mut String, language: &str, content: &str) {
+    let fence = "`".repeat(longest_backtick_run(content).max(2) + 1);
+    let _ = write!(out, "{fence}{language}\n{}\n{fence}\n", content.trim_end());
+}
+
+fn longest_backtick_run(text: &str) -> usize {
+    text.split(|ch| ch != '`').map(str::len).max().unwrap_or(0)
+}
+
+fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for ch in text.chars() {
+        match ch {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(ch),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use codex_protocol::models::FunctionCallOutputPayload;
+    use codex_protocol::protocol::AgentMessageEvent;
+    use codex_protocol::protocol::AgentReasoningEvent;
+    use codex_protocol::protocol::ThreadRolledBackEvent;
+    use codex_protocol::protocol::TokenCountEvent;
+    use codex_protocol::protocol::TokenUsageInfo;
+    use codex_protocol::protocol::UserMessageEvent;
+    use pretty_assertions::assert_eq;
+
+    fn user(message: &str) -> RolloutItem {
+        RolloutItem::EventMsg(EventMsg::UserMessage(UserMessageEvent {
+            message: message.to_string(),
+            images: None,
+            local_images: Vec::new(),
+            text_elements: Vec::new(),
+        }))
+    }
+
+    fn agent(message: &str) -> RolloutItem {
+        RolloutItem::EventMsg(EventMsg::AgentMessage(AgentMessageEvent {
+            message: message.to_string(),
+        }))
+    }
+
+    fn function_call(name: &str, arguments: &str, call_id: &str) -> RolloutItem {
+        RolloutItem::ResponseItem(ResponseItem::FunctionCall {
+            id: None,
+            name: name.to_string(),
+            arguments: arguments.to_string(),
+            call_id: call_id.to_string(),
+        })
+    }
+
+    fn function_output(call_id: &str, text: &str) -> RolloutItem {
+        RolloutItem::ResponseItem(ResponseItem::FunctionCallOutput {
+            call_id: call_id.to_string(),
+            output: FunctionCallOutputPayload::from_text(text.to_string()),
+        })
+    }
+
+    #[test]
+    fn builds_entries_from_rollout_items() {
+        let long_output = (1..=25)
+            .map(|line| format!("line {line}"))
+            .collect::<Vec<_>>()
+            .join("\n");
+        let items = vec![
+            user("Fix the flaky test"),
+            RolloutItem::EventMsg(EventMsg::AgentReasoning(AgentReasoningEvent {
+                text: "**Running the tests**".to_string(),
+            })),
+            function_call(
+                "shell",
+                r#"{"command":["bash","-lc","cargo test -p codex-state"]}"#,
+                "call-1",
+            ),
+            function_output(
+                "call-1",
+                &format!("Exit code: 101\nWall time: 1.2 seconds\nOutput:\n{long_output}\n"),
+            ),
+            function_call(
+                "apply_patch",
+                r#"{"input":"*** Begin Patch\n*** Update File: src/lib.rs\n@@ fn main\n-    old();\n+    new();\n*** End Patch"}"#,
+                "call-2",
+            ),
+            function_output(
+                "call-2",
+                r#"{"output":"Success.","metadata":{"exit_code":0,"duration_seconds":0.0}}"#,
+            ),
+            function_call("write_stdin", r#"{"session_id":1,"chars":"y\n"}"#, "call-3"),
+            agent("Fixed."),
+            RolloutItem::EventMsg(EventMsg::TokenCount(TokenCountEvent {
+                info: Some(TokenUsageInfo {
+                    total_token_usage: TokenUsage {
+                        input_tokens: 1200,
+                        cached_input_tokens: 200,
+                        output_tokens: 300,
+                        reasoning_output_tokens: 0,
+                        total_tokens: 1500,
+                    },
+                    last_token_usage: TokenUsage::default(),
+                    model_context_window: None,
+                }),
+                rate_limits: None,
+            })),
+        ];
+
+        let transcript = ThreadTranscript::from_rollout_items(&items);
+
+        let mut expected_output = (1..=10)
+            .map(|line| format!("line {line}"))
+            .collect::<Vec<_>>();
+        expected_output.push("… +5 lines".to_string());
+        expected_output.extend((16..=25).map(|line| format!("line {line}")));
+        assert_eq!(
+            transcript.entries,
+            vec![
+                TranscriptEntry::UserMessage {
+                    text: "Fix the flaky test".to_string(),
+                },
+                TranscriptEntry::Reasoning {
+                    text: "**Running the tests**".to_string(),
+                },
+                TranscriptEntry::ExecCommand {
+                    command: "cargo test -p codex-state".to_string(),
+                    exit_code: Some(101),
+                    output: Some(expected_output.join("\n")),
+                },
+                TranscriptEntry::Patch {
+                    diff:
+                        "--- a/src/lib.rs\n+++ b/src/lib.rs\n@@ fn main\n-    old();\n+    new();"
+                            .to_string(),
+                    exit_code: Some(0),
+                },
+                TranscriptEntry::AgentMessage {
+                    text: "Fixed.".to_string(),
+                },
+            ]
+        );
+        assert_eq!(
+            transcript.token_usage.map(|usage| usage.total_tokens),
+            Some(1500)
+        );
+    }
+
+    #[test]
+    fn rollback_drops_rolled_back_turns() {
+        let items = vec![
+            user("first"),
+            agent("first answer"),
+            user("second"),
+            agent("second answer"),
+            RolloutItem::EventMsg(EventMsg::ThreadRolledBack(ThreadRolledBackEvent {
+                num_turns: 1,
+            })),
+            user("third"),
+        ];
+
+        let transcript = ThreadTranscript::from_rollout_items(&items);
+
+        assert_eq!(
+            transcript.entries,
+            vec![
+                TranscriptEntry::UserMessage {
+                    text: "first".to_string(),
+                },
+                TranscriptEntry::AgentMessage {
+                    text: "first answer".to_string(),
+                },
+                TranscriptEntry::UserMessage {
+                    text: "third".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn renders_markdown_and_html() {
+        let transcript = ThreadTranscript {
+            name: Some("Login fix".to_string()),
+            entries: vec![
+                TranscriptEntry::UserMessage {
+                    text: "Use `<a>` tags".to_string(),
+                },
+                TranscriptEntry::ExecCommand {
+                    command: "echo ```".to_string(),
+                    exit_code: Some(1),
+                    output: Some("```".to_string()),
+                },
+            ],
+            ..ThreadTranscript::default()
+        };
+
+        assert_eq!(
+            transcript.render_markdown(),
+            "# Login fix\n\n## User\n\nUse `<a>` tags\n\n**Ran**\n\n````sh\necho ```\n````\n\n````text\n```\n````\n\nExit code 1\n"
+        );
+        let html = transcript.render_html();
+        assert!(html.contains("<title>Login fix</title>"));
+        assert!(html.contains("<div class=\"text\">Use `&lt;a&gt;` tags</div>"));
+        assert!(html.contains("<p class=\"exit\">Exit code 1</p>"));
+    }
+}
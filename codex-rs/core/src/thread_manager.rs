@@ -363,6 +363,45 @@ impl ThreadManager {
             .await
     }
 
+    /// Fork an existing thread from the named checkpoint recorded by `Op::Checkpoint`,
+    /// keeping the rollout up to and including the checkpoint marker.
+    pub async fn fork_thread_at_checkpoint(
+        &self,
+        checkpoint: &str,
+        config: Config,
+        path: PathBuf,
+    ) -> CodexResult<NewThread> {
+        let history = RolloutRecorder::get_rollout_history(&path).await?;
+        let items = history.get_rollout_items();
+        let Some(items) = truncation::truncate_rollout_at_checkpoint(&items, checkpoint) else {
+            return Err(checkpoint_not_found(checkpoint));
+        };
+        self.state
+            .spawn_thread(
+                config,
+                InitialHistory::Forked(items),
+                Arc::clone(&self.state.auth_manager),
+                self.agent_control(),
+                Vec::new(),
+            )
+            .await
+    }
+
+    /// Number of user turns recorded after the named checkpoint, i.e. the `num_turns` to pass
+    /// to `Op::ThreadRollback` to resume the thread from that checkpoint.
+    pub async fn turns_after_checkpoint(
+        &self,
+        checkpoint: &str,
+        path: PathBuf,
+    ) -> CodexResult<u32> {
+        let history = RolloutRecorder::get_rollout_history(&path).await?;
+        let items = history.get_rollout_items();
+        let Some(num_turns) = truncation::user_turns_after_checkpoint(&items, checkpoint) else {
+            return Err(checkpoint_not_found(checkpoint));
+        };
+        Ok(u32::try_from(num_turns).unwrap_or(u32::MAX))
+    }
+
     pub(crate) fn agent_control(&self) -> AgentControl {
         AgentControl::new(Arc::downgrade(&self.state))
     }
@@ -636,6 +675,12 @@ fn unix_timestamp_ms() -> i64 {
         .unwrap_or_default()
 }
 
+fn checkpoint_not_found(checkpoint: &str) -> CodexErr {
+    CodexErr::UnsupportedOperation(format!(
+        "checkpoint `{checkpoint}` was not found in this thread"
+    ))
+}
+
 /// Return a prefix of `items` obtained by cutting strictly before the nth user message
 /// (0-based) and all items that follow it.
 fn truncate_before_nth_user_message(history: InitialHistory, n: usize) -> InitialHistory {
@@ -273,8 +273,10 @@ impl ToolEmitter {
         ctx: ToolEventCtx<'_>,
         out: Result<ExecToolCallOutput, ToolError>,
     ) -> Result<String, FunctionCallError> {
+        let secrets = &ctx.session.services.secrets;
         let (event, result) = match out {
             Ok(output) => {
+                let output = secrets.redact_exec_output(output);
                 let content = self.format_exec_output_for_model(&output, ctx);
                 let exit_code = output.exit_code;
                 let event = ToolEventStage::Success(output);
@@ -287,8 +289,9 @@ impl ToolEmitter {
             }
             Err(ToolError::Codex(CodexErr::Sandbox(SandboxErr::Timeout { output })))
             | Err(ToolError::Codex(CodexErr::Sandbox(SandboxErr::Denied { output }))) => {
+                let output = secrets.redact_exec_output(*output);
                 let response = self.format_exec_output_for_model(&output, ctx);
-                let event = ToolEventStage::Failure(ToolEventFailure::Output(*output));
+                let event = ToolEventStage::Failure(ToolEventFailure::Output(output));
                 let result = Err(FunctionCallError::RespondToModel(response));
                 (event, result)
             }
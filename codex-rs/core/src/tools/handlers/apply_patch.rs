@@ -193,6 +193,13 @@ pub(crate) async fn intercept_apply_patch(
     tool_name: &str,
 ) -> Result<Option<ToolOutput>, FunctionCallError> {
     match codex_apply_patch::maybe_parse_apply_patch_verified(command, cwd) {
+        codex_apply_patch::MaybeApplyPatchVerified::Body(_)
+            if !turn.tools_config.tool_permitted("apply_patch") =>
+        {
+            Err(FunctionCallError::RespondToModel(format!(
+                "apply_patch is not available to this agent, including through {tool_name}"
+            )))
+        }
         codex_apply_patch::MaybeApplyPatchVerified::Body(changes) => {
             session
                 .record_model_warning(
@@ -6,8 +6,11 @@ use serde::Deserialize;
 use serde_json::Map as JsonMap;
 use serde_json::Value as JsonValue;
 use serde_json::json;
+use std::collections::BTreeMap;
 
+use crate::agent::AgentRole;
 use crate::codex::SessionSettingsUpdate;
+use crate::config::AgentRoleConfig;
 use crate::function_tool::FunctionCallError;
 use crate::tools::context::ToolInvocation;
 use crate::tools::context::ToolOutput;
@@ -167,7 +170,8 @@ impl ToolHandler for ClaudeToolAdapterHandler {
         match invocation.tool_name.as_str() {
             TASK_TOOL_NAME => {
                 let args: TaskArgs = parse_arguments(&arguments)?;
-                let mapped_args = map_task_to_spawn_payload(args)?;
+                let mapped_args =
+                    map_task_to_spawn_payload(args, &invocation.turn.config.agent_roles)?;
                 dispatch_to_collab(invocation, "spawn_agent", mapped_args).await
             }
             TASK_OUTPUT_TOOL_NAME => {
@@ -228,7 +232,10 @@ impl ToolHandler for ClaudeToolAdapterHandler {
     }
 }
 
-fn map_task_to_spawn_payload(args: TaskArgs) -> Result<JsonValue, FunctionCallError> {
+fn map_task_to_spawn_payload(
+    args: TaskArgs,
+    agent_roles: &BTreeMap<String, AgentRoleConfig>,
+) -> Result<JsonValue, FunctionCallError> {
     let TaskArgs {
         description,
         prompt,
@@ -255,7 +262,7 @@ fn map_task_to_spawn_payload(args: TaskArgs) -> Result<JsonValue, FunctionCallEr
     );
 
     if let Some(agent_type) = normalize_text(subagent_type.as_deref())
-        && is_supported_agent_type(&agent_type)
+        && AgentRole::resolve(agent_roles, Some(agent_type.as_str())).is_ok()
     {
         payload.insert("agent_type".to_string(), JsonValue::String(agent_type));
     }
@@ -604,10 +611,6 @@ fn slugify_to_identifier(value: Option<&str>) -> Option<String> {
     }
 }
 
-fn is_supported_agent_type(value: &str) -> bool {
-    matches!(value, "default" | "worker" | "explorer" | "orchestrator")
-}
-
 async fn dispatch_to_collab(
     invocation: ToolInvocation,
     target_tool_name: &str,
@@ -921,18 +924,21 @@ mod tests {
 
     #[test]
     fn task_maps_supported_agent_type_and_name() {
-        let payload = map_task_to_spawn_payload(TaskArgs {
-            description: Some("Investigate failing test".to_string()),
-            prompt: Some("Check latest regression".to_string()),
-            subagent_type: Some("explorer".to_string()),
-            max_turns: None,
-            mode: None,
-            model: Some("gpt-5.1-codex-mini".to_string()),
-            name: None,
-            resume: None,
-            run_in_background: None,
-            team_name: None,
-        })
+        let payload = map_task_to_spawn_payload(
+            TaskArgs {
+                description: Some("Investigate failing test".to_string()),
+                prompt: Some("Check latest regression".to_string()),
+                subagent_type: Some("explorer".to_string()),
+                max_turns: None,
+                mode: None,
+                model: Some("gpt-5.1-codex-mini".to_string()),
+                name: None,
+                resume: None,
+                run_in_background: None,
+                team_name: None,
+            },
+            &BTreeMap::new(),
+        )
         .expect("payload should be valid");
 
         assert_eq!(
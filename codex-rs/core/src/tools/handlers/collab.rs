@@ -1,6 +1,8 @@
+use crate::agent::AgentLimitsReport;
 use crate::agent::AgentStatus;
 use crate::codex::Session;
 use crate::codex::TurnContext;
+use crate::config::AgentBudget;
 use crate::config::Config;
 use crate::error::CodexErr;
 use crate::function_tool::FunctionCallError;
@@ -14,6 +16,7 @@ use crate::tools::context::ToolPayload;
 use crate::tools::handlers::parse_arguments;
 use crate::tools::registry::ToolHandler;
 use crate::tools::registry::ToolKind;
+use crate::tools::spec::tool_matches;
 use async_trait::async_trait;
 use codex_protocol::ThreadId;
 use codex_protocol::config_types::ReasoningSummary as ReasoningSummaryConfig;
@@ -30,11 +33,14 @@ use codex_protocol::protocol::CollabResumeBeginEvent;
 use codex_protocol::protocol::CollabResumeEndEvent;
 use codex_protocol::protocol::CollabWaitingBeginEvent;
 use codex_protocol::protocol::CollabWaitingEndEvent;
+use codex_protocol::protocol::NetworkAccess;
 use codex_protocol::protocol::SessionSource;
 use codex_protocol::protocol::SubAgentSource;
 use codex_protocol::user_input::UserInput;
+use codex_utils_absolute_path::AbsolutePathBuf;
 use serde::Deserialize;
 use serde::Serialize;
+use std::path::PathBuf;
 
 pub struct CollabHandler;
 
@@ -66,6 +72,17 @@ struct SpawnConfigOverrides {
     sandbox_mode: Option<SandboxMode>,
 }
 
+/// Restrictions the spawner places on a child on top of its role.
+#[derive(Debug, Default)]
+struct SpawnLimits {
+    allowed_tools: Option<Vec<String>>,
+    denied_tools: Option<Vec<String>>,
+    writable_roots: Option<Vec<PathBuf>>,
+    network_access: Option<bool>,
+    max_turns: Option<u32>,
+    max_tokens: Option<i64>,
+}
+
 #[async_trait]
 impl ToolHandler for CollabHandler {
     fn kind(&self) -> ToolKind {
@@ -123,7 +140,7 @@ mod spawn {
     #[derive(Debug, Deserialize)]
     struct SpawnAgentArgs {
         items: Option<Vec<UserInput>>,
-        agent_type: Option<AgentRole>,
+        agent_type: Option<String>,
         name: Option<String>,
         #[serde(rename = "label")]
         label: Option<String>,
@@ -135,6 +152,12 @@ mod spawn {
         reasoning_summary: Option<ReasoningSummaryConfig>,
         approval_policy: Option<AskForApproval>,
         sandbox_mode: Option<SandboxMode>,
+        allowed_tools: Option<Vec<String>>,
+        denied_tools: Option<Vec<String>>,
+        writable_roots: Option<Vec<PathBuf>>,
+        network_access: Option<bool>,
+        max_turns: Option<u32>,
+        max_tokens: Option<i64>,
     }
 
     #[derive(Debug, Serialize)]
@@ -155,7 +178,8 @@ mod spawn {
                     .to_string(),
             ));
         }
-        let agent_role = args.agent_type.unwrap_or(AgentRole::Default);
+        let agent_role = AgentRole::resolve(&turn.config.agent_roles, args.agent_type.as_deref())
+            .map_err(FunctionCallError::RespondToModel)?;
         let input_items = parse_collab_input(args.items)?;
         let prompt = input_preview(&input_items);
         let session_source = turn.session_source.clone();
@@ -199,9 +223,31 @@ mod spawn {
             test_commands: args.test_commands.unwrap_or_default(),
             allow_nested_agents: args.allow_nested_agents.unwrap_or(false),
         };
-        agent_role
-            .apply_to_config(&mut config)
-            .map_err(FunctionCallError::RespondToModel)?;
+        agent_role.apply_to_config(&mut config);
+        if let Some(sandbox_mode) = agent_role.profile.sandbox_mode {
+            let allow_subagent_permission_escalation = config.allow_subagent_permission_escalation;
+            apply_spawn_permission_overrides(
+                turn.as_ref(),
+                &mut config,
+                &SpawnConfigOverrides {
+                    sandbox_mode: Some(sandbox_mode),
+                    ..Default::default()
+                },
+                allow_subagent_permission_escalation,
+            )?;
+        }
+        apply_spawn_limits(
+            turn.as_ref(),
+            &mut config,
+            SpawnLimits {
+                allowed_tools: args.allowed_tools,
+                denied_tools: args.denied_tools,
+                writable_roots: args.writable_roots,
+                network_access: args.network_access,
+                max_turns: args.max_turns,
+                max_tokens: args.max_tokens,
+            },
+        )?;
         let result = session
             .services
             .agent_control
@@ -703,6 +749,8 @@ mod wait_agents {
     struct AgentStatusSnapshot {
         agent_id: String,
         status: AgentStatus,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        limits: Option<AgentLimitsReport>,
     }
 
     #[derive(Debug, Serialize)]
@@ -901,6 +949,7 @@ mod wait_agents {
             statuses.push(AgentStatusSnapshot {
                 agent_id: id.to_string(),
                 status: session.services.agent_control.get_status(*id).await,
+                limits: session.services.agent_control.get_limits(*id).await,
             });
         }
         statuses
@@ -943,6 +992,8 @@ mod list_agents {
         updated_at_ms: i64,
         status: AgentStatus,
         closed: bool,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        limits: Option<AgentLimitsReport>,
     }
 
     #[derive(Debug, Serialize)]
@@ -993,6 +1044,11 @@ mod list_agents {
                 allow_nested_agents: agent.allow_nested_agents,
                 created_at_ms: agent.created_at_ms,
                 updated_at_ms: agent.updated_at_ms,
+                limits: session
+                    .services
+                    .agent_control
+                    .get_limits(agent.agent_id)
+                    .await,
                 status: agent.status,
                 closed: agent.closed,
             });
@@ -1668,6 +1724,173 @@ fn apply_spawn_permission_overrides(
     Ok(())
 }
 
+/// Narrows the child's tool filter, sandbox and budget to what the spawner
+/// asked for. Tool and sandbox limits can only tighten what the child
+/// inherited from its parent and role.
+fn apply_spawn_limits(
+    turn: &TurnContext,
+    config: &mut Config,
+    limits: SpawnLimits,
+) -> Result<(), FunctionCallError> {
+    if let Some(allowed_tools) = limits.allowed_tools {
+        // Entries outside an inherited allowlist are dropped rather than granted.
+        config.allowed_tools = Some(match config.allowed_tools.take() {
+            Some(inherited) => allowed_tools
+                .into_iter()
+                .filter(|name| tool_matches(&inherited, name))
+                .collect(),
+            None => allowed_tools,
+        });
+    }
+    if let Some(denied_tools) = limits.denied_tools {
+        for name in denied_tools {
+            if !config.denied_tools.contains(&name) {
+                config.denied_tools.push(name);
+            }
+        }
+    }
+
+    apply_spawn_sandbox_limits(turn, config, limits.writable_roots, limits.network_access)?;
+
+    if limits.max_turns == Some(0) {
+        return Err(FunctionCallError::RespondToModel(
+            "max_turns must be at least 1".to_string(),
+        ));
+    }
+    if limits.max_tokens.is_some_and(|max_tokens| max_tokens <= 0) {
+        return Err(FunctionCallError::RespondToModel(
+            "max_tokens must be positive".to_string(),
+        ));
+    }
+    config.agent_budget = AgentBudget {
+        max_turns: limits.max_turns,
+        max_tokens: limits.max_tokens,
+    };
+    Ok(())
+}
+
+fn apply_spawn_sandbox_limits(
+    turn: &TurnContext,
+    config: &mut Config,
+    writable_roots: Option<Vec<PathBuf>>,
+    network_access: Option<bool>,
+) -> Result<(), FunctionCallError> {
+    if writable_roots.is_none() && network_access.is_none() {
+        return Ok(());
+    }
+    let allow_subagent_permission_escalation = config.allow_subagent_permission_escalation;
+    let mut policy = config.sandbox_policy.get().clone();
+
+    if let Some(writable_roots) = writable_roots {
+        let parent_roots = turn.sandbox_policy.get_writable_roots_with_cwd(&turn.cwd);
+        let parent_full_write = turn.sandbox_policy.has_full_disk_write_access();
+        let mut roots = Vec::with_capacity(writable_roots.len());
+        for root in writable_roots {
+            let root =
+                AbsolutePathBuf::resolve_path_against_base(&root, &turn.cwd).map_err(|err| {
+                    FunctionCallError::RespondToModel(format!(
+                        "writable root {} is invalid: {err}",
+                        root.display()
+                    ))
+                })?;
+            if !allow_subagent_permission_escalation
+                && !parent_full_write
+                && !parent_roots
+                    .iter()
+                    .any(|parent| root.as_path().starts_with(parent.root.as_path()))
+            {
+                return Err(FunctionCallError::RespondToModel(format!(
+                    "writable root {} is not allowed because it is outside the parent's writable roots",
+                    root.display()
+                )));
+            }
+            roots.push(root);
+        }
+        // The working directory is always writable, so a root inside it would
+        // not restrict the agent to that root. Move the agent into the first
+        // such root instead, which leaves the rest of the workspace read-only.
+        if let Some(index) = roots
+            .iter()
+            .position(|root| root.as_path().starts_with(&config.cwd))
+        {
+            let cwd = roots.remove(index);
+            if !cwd.as_path().is_dir() {
+                return Err(FunctionCallError::RespondToModel(format!(
+                    "writable root {} must be an existing directory to become the agent's working directory",
+                    cwd.display()
+                )));
+            }
+            config.cwd = cwd.to_path_buf();
+        }
+        policy = match policy {
+            SandboxPolicy::ReadOnly => {
+                return Err(FunctionCallError::RespondToModel(
+                    "writable_roots cannot be used with sandbox_mode read-only".to_string(),
+                ));
+            }
+            SandboxPolicy::WorkspaceWrite { network_access, .. } => SandboxPolicy::WorkspaceWrite {
+                writable_roots: roots,
+                network_access,
+                exclude_tmpdir_env_var: true,
+                exclude_slash_tmp: true,
+            },
+            SandboxPolicy::DangerFullAccess | SandboxPolicy::ExternalSandbox { .. } => {
+                SandboxPolicy::WorkspaceWrite {
+                    writable_roots: roots,
+                    network_access: policy.has_full_network_access(),
+                    exclude_tmpdir_env_var: true,
+                    exclude_slash_tmp: true,
+                }
+            }
+        };
+    }
+
+    if let Some(network_access) = network_access {
+        if network_access
+            && !allow_subagent_permission_escalation
+            && !turn.sandbox_policy.has_full_network_access()
+        {
+            return Err(FunctionCallError::RespondToModel(
+                "network_access is not allowed because the parent has no network access"
+                    .to_string(),
+            ));
+        }
+        policy = match policy {
+            SandboxPolicy::WorkspaceWrite {
+                writable_roots,
+                exclude_tmpdir_env_var,
+                exclude_slash_tmp,
+                ..
+            } => SandboxPolicy::WorkspaceWrite {
+                writable_roots,
+                network_access,
+                exclude_tmpdir_env_var,
+                exclude_slash_tmp,
+            },
+            SandboxPolicy::ExternalSandbox { .. } => SandboxPolicy::ExternalSandbox {
+                network_access: if network_access {
+                    NetworkAccess::Enabled
+                } else {
+                    NetworkAccess::Restricted
+                },
+            },
+            SandboxPolicy::ReadOnly if !network_access => SandboxPolicy::ReadOnly,
+            SandboxPolicy::DangerFullAccess if network_access => SandboxPolicy::DangerFullAccess,
+            policy => {
+                return Err(FunctionCallError::RespondToModel(format!(
+                    "network_access {network_access} cannot be used with sandbox_mode {}; use workspace-write",
+                    sandbox_mode_from_policy(&policy)
+                )));
+            }
+        };
+    }
+
+    config.sandbox_policy.set(policy).map_err(|err| {
+        FunctionCallError::RespondToModel(format!("sandbox_policy is invalid: {err}"))
+    })?;
+    Ok(())
+}
+
 fn approval_policy_level(policy: AskForApproval) -> u8 {
     match policy {
         AskForApproval::Never => 0,
@@ -1731,6 +1954,7 @@ mod tests {
     use serde::Deserialize;
     use serde_json::json;
     use std::collections::HashMap;
+    use std::path::Path;
     use std::path::PathBuf;
     use std::sync::Arc;
     use std::time::Duration;
@@ -3930,6 +4154,133 @@ mod tests {
         ));
     }
 
+    #[tokio::test]
+    async fn apply_spawn_limits_only_narrows_inherited_tools() {
+        let (session, mut turn) = make_session_and_context().await;
+        overwrite_turn_config(&mut turn, |config| {
+            config.allowed_tools = Some(vec!["shell".to_string(), "wait*".to_string()]);
+            config.denied_tools = vec!["apply_patch".to_string()];
+        });
+        let mut config =
+            build_agent_spawn_config(&session, &turn, &SpawnConfigOverrides::default())
+                .await
+                .expect("spawn config");
+        let limits = SpawnLimits {
+            allowed_tools: Some(vec!["shell".to_string(), "view_image".to_string()]),
+            denied_tools: Some(vec!["apply_patch".to_string(), "wait".to_string()]),
+            max_turns: Some(3),
+            ..Default::default()
+        };
+
+        apply_spawn_limits(&turn, &mut config, limits).expect("limits");
+
+        assert_eq!(config.allowed_tools, Some(vec!["shell".to_string()]));
+        assert_eq!(
+            config.denied_tools,
+            vec!["apply_patch".to_string(), "wait".to_string()]
+        );
+        assert_eq!(
+            config.agent_budget,
+            AgentBudget {
+                max_turns: Some(3),
+                max_tokens: None,
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn apply_spawn_limits_rejects_writable_root_outside_parent() {
+        let (session, mut turn) = make_session_and_context().await;
+        turn.sandbox_policy = SandboxPolicy::WorkspaceWrite {
+            writable_roots: Vec::new(),
+            network_access: false,
+            exclude_tmpdir_env_var: true,
+            exclude_slash_tmp: true,
+        };
+        let mut config =
+            build_agent_spawn_config(&session, &turn, &SpawnConfigOverrides::default())
+                .await
+                .expect("spawn config");
+        let outside = turn
+            .cwd
+            .parent()
+            .expect("cwd parent")
+            .join("outside-workspace");
+        let limits = SpawnLimits {
+            writable_roots: Some(vec![outside.clone()]),
+            ..Default::default()
+        };
+
+        let err = apply_spawn_limits(&turn, &mut config, limits).expect_err("outside root");
+
+        assert_eq!(
+            err,
+            FunctionCallError::RespondToModel(format!(
+                "writable root {} is not allowed because it is outside the parent's writable roots",
+                outside.display()
+            ))
+        );
+    }
+
+    #[tokio::test]
+    async fn apply_spawn_limits_moves_agent_into_writable_root_inside_cwd() {
+        let (session, mut turn) = make_session_and_context().await;
+        let temp_dir = tempfile::tempdir().expect("temp dir");
+        std::fs::create_dir(temp_dir.path().join("docs")).expect("create docs");
+        std::fs::create_dir(temp_dir.path().join("src")).expect("create src");
+        turn.cwd = temp_dir.path().to_path_buf();
+        turn.sandbox_policy = SandboxPolicy::WorkspaceWrite {
+            writable_roots: Vec::new(),
+            network_access: false,
+            exclude_tmpdir_env_var: true,
+            exclude_slash_tmp: true,
+        };
+        let mut config =
+            build_agent_spawn_config(&session, &turn, &SpawnConfigOverrides::default())
+                .await
+                .expect("spawn config");
+        let limits = SpawnLimits {
+            writable_roots: Some(vec![PathBuf::from("docs")]),
+            ..Default::default()
+        };
+
+        apply_spawn_limits(&turn, &mut config, limits).expect("limits");
+
+        assert_eq!(config.cwd, turn.cwd.join("docs"));
+        let roots = config
+            .sandbox_policy
+            .get()
+            .get_writable_roots_with_cwd(&config.cwd);
+        let writable = |path: &Path| roots.iter().any(|root| root.is_path_writable(path));
+        assert!(writable(&turn.cwd.join("docs").join("guide.md")));
+        assert!(!writable(&turn.cwd.join("src").join("main.rs")));
+    }
+
+    #[tokio::test]
+    async fn apply_spawn_limits_rejects_missing_writable_root_inside_cwd() {
+        let (session, mut turn) = make_session_and_context().await;
+        let temp_dir = tempfile::tempdir().expect("temp dir");
+        turn.cwd = temp_dir.path().to_path_buf();
+        let mut config =
+            build_agent_spawn_config(&session, &turn, &SpawnConfigOverrides::default())
+                .await
+                .expect("spawn config");
+        let limits = SpawnLimits {
+            writable_roots: Some(vec![PathBuf::from("docs")]),
+            ..Default::default()
+        };
+
+        let err = apply_spawn_limits(&turn, &mut config, limits).expect_err("missing root");
+
+        assert_eq!(
+            err,
+            FunctionCallError::RespondToModel(format!(
+                "writable root {} must be an existing directory to become the agent's working directory",
+                turn.cwd.join("docs").display()
+            ))
+        );
+    }
+
     #[tokio::test]
     async fn build_agent_resume_config_clears_base_instructions() {
         let (_session, mut turn) = make_session_and_context().await;
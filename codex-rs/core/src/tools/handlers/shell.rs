@@ -1,4 +1,5 @@
 use async_trait::async_trait;
+use codex_execpolicy::MatchContext;
 use codex_protocol::ThreadId;
 use codex_protocol::models::FunctionCallOutputBody;
 use codex_protocol::models::ShellCommandToolCallParams;
@@ -45,6 +46,7 @@ struct RunExecLikeArgs {
 impl ShellHandler {
     fn to_exec_params(
         params: &ShellToolCallParams,
+        session: &crate::codex::Session,
         turn_context: &TurnContext,
         thread_id: ThreadId,
     ) -> ExecParams {
@@ -52,7 +54,10 @@ impl ShellHandler {
             command: params.command.clone(),
             cwd: turn_context.resolve_path(params.workdir.clone()),
             expiration: params.timeout_ms.into(),
-            env: create_env(&turn_context.shell_environment_policy, Some(thread_id)),
+            env: session.services.secrets.inject(create_env(
+                &turn_context.shell_environment_policy,
+                Some(thread_id),
+            )),
             network: turn_context.network.clone(),
             sandbox_permissions: params.sandbox_permissions.unwrap_or_default(),
             windows_sandbox_level: turn_context.windows_sandbox_level,
@@ -81,7 +86,10 @@ impl ShellCommandHandler {
             command,
             cwd: turn_context.resolve_path(params.workdir.clone()),
             expiration: params.timeout_ms.into(),
-            env: create_env(&turn_context.shell_environment_policy, Some(thread_id)),
+            env: session.services.secrets.inject(create_env(
+                &turn_context.shell_environment_policy,
+                Some(thread_id),
+            )),
             network: turn_context.network.clone(),
             sandbox_permissions: params.sandbox_permissions.unwrap_or_default(),
             windows_sandbox_level: turn_context.windows_sandbox_level,
@@ -130,8 +138,12 @@ impl ToolHandler for ShellHandler {
             ToolPayload::Function { arguments } => {
                 let params: ShellToolCallParams = parse_arguments(&arguments)?;
                 let prefix_rule = params.prefix_rule.clone();
-                let exec_params =
-                    Self::to_exec_params(&params, turn.as_ref(), session.conversation_id);
+                let exec_params = Self::to_exec_params(
+                    &params,
+                    session.as_ref(),
+                    turn.as_ref(),
+                    session.conversation_id,
+                );
                 Self::run_exec_like(RunExecLikeArgs {
                     tool_name: tool_name.clone(),
                     exec_params,
@@ -145,8 +157,12 @@ impl ToolHandler for ShellHandler {
                 .await
             }
             ToolPayload::LocalShell { params } => {
-                let exec_params =
-                    Self::to_exec_params(&params, turn.as_ref(), session.conversation_id);
+                let exec_params = Self::to_exec_params(
+                    &params,
+                    session.as_ref(),
+                    turn.as_ref(),
+                    session.conversation_id,
+                );
                 Self::run_exec_like(RunExecLikeArgs {
                     tool_name: tool_name.clone(),
                     exec_params,
@@ -305,6 +321,10 @@ impl ShellHandler {
                 sandbox_policy: &turn.sandbox_policy,
                 sandbox_permissions: exec_params.sandbox_permissions,
                 prefix_rule,
+                match_context: MatchContext {
+                    cwd: Some(exec_params.cwd.as_path()),
+                    workspace_root: Some(turn.cwd.as_path()),
+                },
             })
             .await;
 
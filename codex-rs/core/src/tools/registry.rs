@@ -237,6 +237,12 @@ impl ToolRegistryBuilder {
     //     }
     // }
 
+    /// Drops every spec and handler whose tool name `keep` rejects.
+    pub fn retain_tools(&mut self, keep: impl Fn(&str) -> bool) {
+        self.specs.retain(|configured| keep(configured.spec.name()));
+        self.handlers.retain(|name, _| keep(name));
+    }
+
     pub fn build(self) -> (Vec<ConfiguredToolSpec>, ToolRegistry) {
         let registry = ToolRegistry::new(self.handlers);
         (self.specs, registry)
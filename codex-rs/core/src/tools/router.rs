@@ -3,6 +3,7 @@ use crate::codex::Session;
 use crate::codex::TurnContext;
 use crate::function_tool::FunctionCallError;
 use crate::sandboxing::SandboxPermissions;
+use crate::tools::TELEMETRY_PREVIEW_MAX_BYTES;
 use crate::tools::context::SharedTurnDiffTracker;
 use crate::tools::context::ToolInvocation;
 use crate::tools::context::ToolPayload;
@@ -10,13 +11,21 @@ use crate::tools::registry::ConfiguredToolSpec;
 use crate::tools::registry::ToolRegistry;
 use crate::tools::spec::ToolsConfig;
 use crate::tools::spec::build_specs;
+use codex_hooks::HookEvent;
+use codex_hooks::HookEventAfterToolCall;
+use codex_hooks::HookEventBeforeToolCall;
+use codex_hooks::HookEventKind;
+use codex_hooks::HookOutcome;
+use codex_hooks::HookPayload;
 use codex_protocol::dynamic_tools::DynamicToolSpec;
 use codex_protocol::models::FunctionCallOutputBody;
 use codex_protocol::models::LocalShellAction;
 use codex_protocol::models::ResponseInputItem;
 use codex_protocol::models::ResponseItem;
 use codex_protocol::models::ShellToolCallParams;
+use codex_utils_string::take_bytes_at_char_boundary;
 use rmcp::model::Tool;
+use serde_json::Value;
 use std::collections::HashMap;
 use std::sync::Arc;
 use tracing::instrument;
@@ -142,11 +151,76 @@ impl ToolRouter {
         let ToolCall {
             tool_name,
             call_id,
-            payload,
+            mut payload,
         } = call;
         let payload_outputs_custom = matches!(payload, ToolPayload::Custom { .. });
         let failure_call_id = call_id.clone();
 
+        if !turn.tools_config.tool_permitted(&tool_name) {
+            return Ok(Self::failure_response(
+                failure_call_id,
+                payload_outputs_custom,
+                FunctionCallError::RespondToModel(format!(
+                    "`{tool_name}` is not available to this agent"
+                )),
+            ));
+        }
+
+        if session.hooks().has_hooks_for(HookEventKind::BeforeToolCall) {
+            let outcome = session
+                .hooks()
+                .dispatch(HookPayload {
+                    session_id: session.conversation_id,
+                    cwd: turn.cwd.clone(),
+                    triggered_at: chrono::Utc::now(),
+                    hook_event: HookEvent::BeforeToolCall {
+                        event: HookEventBeforeToolCall {
+                            thread_id: session.conversation_id,
+                            turn_id: turn.sub_id.clone(),
+                            call_id: call_id.clone(),
+                            tool_name: tool_name.clone(),
+                            tool_input: hook_tool_input(&payload),
+                        },
+                    },
+                })
+                .await;
+            let blocked = match outcome {
+                HookOutcome::Continue => None,
+                HookOutcome::Stop => Some(format!("`{tool_name}` call was blocked by a hook")),
+                HookOutcome::Deny { reason } => Some(format!(
+                    "`{tool_name}` call was blocked by a hook: {reason}"
+                )),
+                HookOutcome::Modify { arguments } => {
+                    match apply_hook_arguments(&mut payload, arguments) {
+                        Ok(()) => None,
+                        Err(err) => Some(format!(
+                            "hook returned invalid arguments for `{tool_name}`: {err}"
+                        )),
+                    }
+                }
+            };
+            if let Some(message) = blocked {
+                return Ok(Self::failure_response(
+                    failure_call_id,
+                    payload_outputs_custom,
+                    FunctionCallError::RespondToModel(message),
+                ));
+            }
+        }
+
+        let after_tool_call = session
+            .hooks()
+            .has_hooks_for(HookEventKind::AfterToolCall)
+            .then(|| {
+                (
+                    Arc::clone(&session),
+                    turn.cwd.clone(),
+                    turn.sub_id.clone(),
+                    tool_name.clone(),
+                    hook_tool_input(&payload),
+                )
+            });
+
         let invocation = ToolInvocation {
             session,
             turn,
@@ -156,15 +230,47 @@ impl ToolRouter {
             payload,
         };
 
-        match self.registry.dispatch(invocation).await {
+        let result = match self.registry.dispatch(invocation).await {
             Ok(response) => Ok(response),
             Err(FunctionCallError::Fatal(message)) => Err(FunctionCallError::Fatal(message)),
             Err(err) => Ok(Self::failure_response(
-                failure_call_id,
+                failure_call_id.clone(),
                 payload_outputs_custom,
                 err,
             )),
+        };
+
+        if let Some((session, cwd, turn_id, tool_name, tool_input)) = after_tool_call {
+            let (success, output) = match &result {
+                Ok(response) => response_success_and_text(response),
+                Err(err) => (false, err.to_string()),
+            };
+            session
+                .hooks()
+                .dispatch(HookPayload {
+                    session_id: session.conversation_id,
+                    cwd,
+                    triggered_at: chrono::Utc::now(),
+                    hook_event: HookEvent::AfterToolCall {
+                        event: HookEventAfterToolCall {
+                            thread_id: session.conversation_id,
+                            turn_id,
+                            call_id: failure_call_id,
+                            tool_name,
+                            tool_input,
+                            success,
+                            output_preview: take_bytes_at_char_boundary(
+                                &output,
+                                TELEMETRY_PREVIEW_MAX_BYTES,
+                            )
+                            .to_string(),
+                        },
+                    },
+                })
+                .await;
         }
+
+        result
     }
 
     fn failure_response(
@@ -189,3 +295,52 @@ impl ToolRouter {
         }
     }
 }
+
+/// JSON view of the tool arguments handed to `BeforeToolCall`/`AfterToolCall` hooks.
+fn hook_tool_input(payload: &ToolPayload) -> Value {
+    match payload {
+        ToolPayload::Function { arguments } => {
+            serde_json::from_str(arguments).unwrap_or_else(|_| Value::String(arguments.clone()))
+        }
+        ToolPayload::Custom { input } => Value::String(input.clone()),
+        ToolPayload::LocalShell { params } => serde_json::to_value(params).unwrap_or(Value::Null),
+        ToolPayload::Mcp { raw_arguments, .. } => serde_json::from_str(raw_arguments)
+            .unwrap_or_else(|_| Value::String(raw_arguments.clone())),
+    }
+}
+
+/// Writes hook-provided arguments back into the payload in its native shape.
+fn apply_hook_arguments(payload: &mut ToolPayload, arguments: Value) -> Result<(), String> {
+    match payload {
+        ToolPayload::Function { arguments: current } => {
+            *current = serde_json::to_string(&arguments).map_err(|err| err.to_string())?;
+        }
+        ToolPayload::Custom { input } => {
+            *input = match arguments {
+                Value::String(text) => text,
+                _ => return Err("freeform tools expect a JSON string".to_string()),
+            };
+        }
+        ToolPayload::LocalShell { params } => {
+            *params = serde_json::from_value(arguments).map_err(|err| err.to_string())?;
+        }
+        ToolPayload::Mcp { raw_arguments, .. } => {
+            *raw_arguments = serde_json::to_string(&arguments).map_err(|err| err.to_string())?;
+        }
+    }
+    Ok(())
+}
+
+fn response_success_and_text(response: &ResponseInputItem) -> (bool, String) {
+    match response {
+        ResponseInputItem::FunctionCallOutput { output, .. } => (
+            output.success.unwrap_or(true),
+            output.body.to_text().unwrap_or_default(),
+        ),
+        ResponseInputItem::CustomToolCallOutput { output, .. } => (true, output.clone()),
+        ResponseInputItem::McpToolCallOutput { result, .. } => {
+            (result.is_ok(), format!("{result:?}"))
+        }
+        ResponseInputItem::Message { .. } => (true, String::new()),
+    }
+}
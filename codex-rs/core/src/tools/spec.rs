@@ -1,6 +1,7 @@
 use crate::agent::AgentRole;
 use crate::client_common::tools::ResponsesApiTool;
 use crate::client_common::tools::ToolSpec;
+use crate::config::AgentRoleConfig;
 use crate::features::Feature;
 use crate::features::Features;
 use crate::tools::handlers::PLAN_TOOL;
@@ -34,6 +35,12 @@ pub(crate) struct ToolsConfig {
     pub collaboration_modes_tools: bool,
     pub request_rule_enabled: bool,
     pub experimental_supported_tools: Vec<String>,
+    /// Roles listed in the `spawn_agent` and `task_batch` schemas.
+    pub agent_roles: Vec<AgentRole>,
+    /// When set, only tools whose names match an entry are exposed.
+    pub allowed_tools: Option<Vec<String>>,
+    /// Tools that are never exposed, even when `allowed_tools` matches them.
+    pub denied_tools: Vec<String>,
 }
 
 pub(crate) struct ToolsConfigParams<'a> {
@@ -89,8 +96,49 @@ impl ToolsConfig {
             collaboration_modes_tools: include_collaboration_modes_tools,
             request_rule_enabled,
             experimental_supported_tools: model_info.experimental_supported_tools.clone(),
+            agent_roles: AgentRole::available(&BTreeMap::new()),
+            allowed_tools: None,
+            denied_tools: Vec::new(),
         }
     }
+
+    /// Lists the configured `[agents.roles]` alongside the built-in roles.
+    pub fn with_agent_roles(mut self, roles: &BTreeMap<String, AgentRoleConfig>) -> Self {
+        self.agent_roles = AgentRole::available(roles);
+        self
+    }
+
+    /// Restricts the exposed tools to `allowed_tools` minus `denied_tools`,
+    /// as set by an agent role or the spawning agent.
+    pub fn with_tool_filter(
+        mut self,
+        allowed_tools: Option<Vec<String>>,
+        denied_tools: Vec<String>,
+    ) -> Self {
+        self.allowed_tools = allowed_tools;
+        self.denied_tools = denied_tools;
+        self
+    }
+
+    /// Returns whether the tool filter lets this agent call `name`.
+    pub fn tool_permitted(&self, name: &str) -> bool {
+        !tool_matches(&self.denied_tools, name)
+            && self
+                .allowed_tools
+                .as_deref()
+                .is_none_or(|allowed_tools| tool_matches(allowed_tools, name))
+    }
+}
+
+/// Returns whether `name` matches an entry of a tool filter list. A trailing
+/// `*` matches by prefix.
+pub(crate) fn tool_matches(patterns: &[String], name: &str) -> bool {
+    patterns
+        .iter()
+        .any(|pattern| match pattern.strip_suffix('*') {
+            Some(prefix) => name.starts_with(prefix),
+            None => pattern == name,
+        })
 }
 
 /// Generic JSON‑Schema subset needed for our tool definitions
@@ -501,7 +549,7 @@ fn create_collab_input_items_schema() -> JsonSchema {
     }
 }
 
-fn create_spawn_agent_parameters() -> JsonSchema {
+fn create_spawn_agent_parameters(agent_roles: &[AgentRole]) -> JsonSchema {
     let mut properties = BTreeMap::new();
     properties.insert("items".to_string(), create_collab_input_items_schema());
     properties.insert(
@@ -509,7 +557,7 @@ fn create_spawn_agent_parameters() -> JsonSchema {
         JsonSchema::String {
             description: Some(format!(
                 "Optional agent type ({}). Use an explicit type when delegating.",
-                AgentRole::enum_values().join(", ")
+                AgentRole::enum_values(agent_roles).join(", ")
             )),
         },
     );
@@ -586,6 +634,58 @@ fn create_spawn_agent_parameters() -> JsonSchema {
             ),
         },
     );
+    properties.insert(
+        "allowed_tools".to_string(),
+        JsonSchema::Array {
+            items: Box::new(JsonSchema::String { description: None }),
+            description: Some(
+                "Optional tool allowlist for the spawned agent; a trailing `*` matches by prefix."
+                    .to_string(),
+            ),
+        },
+    );
+    properties.insert(
+        "denied_tools".to_string(),
+        JsonSchema::Array {
+            items: Box::new(JsonSchema::String { description: None }),
+            description: Some(
+                "Optional tools the spawned agent may never call, e.g. [\"apply_patch\"]."
+                    .to_string(),
+            ),
+        },
+    );
+    properties.insert(
+        "writable_roots".to_string(),
+        JsonSchema::Array {
+            items: Box::new(JsonSchema::String { description: None }),
+            description: Some(
+                "Optional directories the spawned agent may write, inside your own writable roots; temp directories are no longer writable. The agent's working directory always stays writable, so if a root is inside it the agent starts in that root instead and the rest of the workspace becomes read-only."
+                    .to_string(),
+            ),
+        },
+    );
+    properties.insert(
+        "network_access".to_string(),
+        JsonSchema::Boolean {
+            description: Some(
+                "Optional network access for the spawned agent's commands.".to_string(),
+            ),
+        },
+    );
+    properties.insert(
+        "max_turns".to_string(),
+        JsonSchema::Number {
+            description: Some("Optional maximum number of turns the agent may run.".to_string()),
+        },
+    );
+    properties.insert(
+        "max_tokens".to_string(),
+        JsonSchema::Number {
+            description: Some(
+                "Optional maximum number of tokens the agent may use across its turns.".to_string(),
+            ),
+        },
+    );
 
     JsonSchema::Object {
         properties,
@@ -594,14 +694,14 @@ fn create_spawn_agent_parameters() -> JsonSchema {
     }
 }
 
-fn create_spawn_agent_tool() -> ToolSpec {
+fn create_spawn_agent_tool(agent_roles: &[AgentRole]) -> ToolSpec {
     ToolSpec::Function(ResponsesApiTool {
         name: "spawn_agent".to_string(),
         description:
             "Spawn a sub-agent for a well-scoped task. Returns the agent id to use to communicate with this agent."
                 .to_string(),
         strict: false,
-        parameters: create_spawn_agent_parameters(),
+        parameters: create_spawn_agent_parameters(agent_roles),
     })
 }
 
@@ -697,11 +797,11 @@ fn create_collab_batch_tool(
     })
 }
 
-fn create_task_batch_tool() -> ToolSpec {
+fn create_task_batch_tool(agent_roles: &[AgentRole]) -> ToolSpec {
     create_collab_batch_tool(
         "task_batch",
         "Create multiple agents in one call. Wraps spawn_agent semantics per operation.",
-        create_spawn_agent_parameters(),
+        create_spawn_agent_parameters(agent_roles),
     )
 }
 
@@ -2626,9 +2726,9 @@ pub(crate) fn build_specs(
 
     if config.collab_tools {
         let collab_handler = Arc::new(CollabHandler);
-        builder.push_spec(create_spawn_agent_tool());
+        builder.push_spec(create_spawn_agent_tool(&config.agent_roles));
         builder.push_spec(create_send_input_tool());
-        builder.push_spec(create_task_batch_tool());
+        builder.push_spec(create_task_batch_tool(&config.agent_roles));
         builder.push_spec(create_task_send_batch_tool());
         builder.push_spec(create_resume_agent_tool());
         builder.push_spec_with_parallel_support(create_wait_tool(), true);
@@ -2718,6 +2818,10 @@ pub(crate) fn build_specs(
         }
     }
 
+    if config.allowed_tools.is_some() || !config.denied_tools.is_empty() {
+        builder.retain_tools(|name| config.tool_permitted(name));
+    }
+
     builder
 }
 
@@ -2925,7 +3029,7 @@ mod tests {
 
     #[test]
     fn test_spawn_agent_tool_schema() {
-        let mut tool = create_spawn_agent_tool();
+        let mut tool = create_spawn_agent_tool(&AgentRole::available(&BTreeMap::new()));
         strip_descriptions_tool(&mut tool);
         let ToolSpec::Function(ResponsesApiTool {
             name, parameters, ..
@@ -2956,6 +3060,12 @@ mod tests {
             "reasoning_summary",
             "approval_policy",
             "sandbox_mode",
+            "allowed_tools",
+            "denied_tools",
+            "writable_roots",
+            "network_access",
+            "max_turns",
+            "max_tokens",
         ] {
             assert!(
                 properties.contains_key(key),
@@ -3006,7 +3116,7 @@ mod tests {
 
     #[test]
     fn test_task_batch_tool_schema() {
-        let mut tool = create_task_batch_tool();
+        let mut tool = create_task_batch_tool(&AgentRole::available(&BTreeMap::new()));
         strip_descriptions_tool(&mut tool);
         let ToolSpec::Function(ResponsesApiTool {
             name, parameters, ..
@@ -3490,6 +3600,76 @@ mod tests {
         );
     }
 
+    #[test]
+    fn build_specs_lists_configured_agent_roles() {
+        let config = test_config();
+        let model_info = ModelsManager::construct_model_info_offline("gpt-5-codex", &config);
+        let mut features = Features::with_defaults();
+        features.enable(Feature::Collab);
+        let roles = BTreeMap::from([(
+            "db-migration-reviewer".to_string(),
+            AgentRoleConfig {
+                description: "Reviews schema migrations.".to_string(),
+                ..Default::default()
+            },
+        )]);
+        let tools_config = ToolsConfig::new(&ToolsConfigParams {
+            model_info: &model_info,
+            features: &features,
+            web_search_mode: Some(WebSearchMode::Cached),
+        })
+        .with_agent_roles(&roles);
+        let (tools, _) = build_specs(&tools_config, None, &[]).build();
+
+        let spawn_agent = find_tool(&tools, "spawn_agent");
+        let ToolSpec::Function(ResponsesApiTool {
+            parameters: JsonSchema::Object { properties, .. },
+            ..
+        }) = &spawn_agent.spec
+        else {
+            panic!("expected spawn_agent to be a function tool with object parameters");
+        };
+        let Some(JsonSchema::String {
+            description: Some(description),
+        }) = properties.get("agent_type")
+        else {
+            panic!("expected agent_type to be a described string");
+        };
+        assert!(
+            description.contains(
+                r#"{ "name": "db-migration-reviewer", "description": Reviews schema migrations.}"#
+            ),
+            "agent_type description should list configured roles: {description}"
+        );
+    }
+
+    #[test]
+    fn build_specs_keeps_only_permitted_tools() {
+        let config = test_config();
+        let model_info = ModelsManager::construct_model_info_offline("gpt-5-codex", &config);
+        let mut features = Features::with_defaults();
+        features.enable(Feature::Collab);
+        let tools_config = ToolsConfig::new(&ToolsConfigParams {
+            model_info: &model_info,
+            features: &features,
+            web_search_mode: Some(WebSearchMode::Cached),
+        })
+        .with_tool_filter(
+            Some(vec!["view_image".to_string(), "wait*".to_string()]),
+            vec!["wait_agents".to_string()],
+        );
+        let (tools, registry) = build_specs(&tools_config, None, &[]).build();
+
+        let names = tools
+            .iter()
+            .map(|tool| tool_name(&tool.spec))
+            .collect::<Vec<_>>();
+        assert_eq!(names, vec!["view_image", "wait"]);
+        assert!(registry.handler("wait").is_some());
+        assert!(registry.handler("wait_agents").is_none());
+        assert!(registry.handler("spawn_agent").is_none());
+    }
+
     #[test]
     fn collab_waiting_tools_support_parallel_tool_calls() {
         let config = test_config();
@@ -25,6 +25,9 @@ use crate::unified_exec::head_tail_buffer::HeadTailBuffer;
 
 pub(crate) const TRAILING_OUTPUT_GRACE: Duration = Duration::from_millis(100);
 
+/// How long output must stay idle before the tail held back for secret redaction is shown.
+const HELD_OUTPUT_FLUSH_DELAY: Duration = Duration::from_millis(250);
+
 /// Upper bound for a single ExecCommandOutputDelta chunk emitted by unified exec.
 ///
 /// The unified exec output buffer already caps *retained* output (see
@@ -56,6 +59,7 @@ pub(crate) fn start_streaming_output(
         let mut emitted_deltas: usize = 0;
 
         let mut grace_sleep: Option<Pin<Box<Sleep>>> = None;
+        let mut idle_flush: Option<Pin<Box<Sleep>>> = None;
 
         loop {
             tokio::select! {
@@ -73,6 +77,15 @@ pub(crate) fn start_streaming_output(
                     break;
                 }
 
+                _ = async {
+                    if let Some(sleep) = idle_flush.as_mut() {
+                        sleep.as_mut().await;
+                    }
+                }, if idle_flush.is_some() => {
+                    idle_flush = None;
+                    session_ref.flush_held_output(turn_ref.as_ref(), &call_id).await;
+                }
+
                 received = receiver.recv() => {
                     let chunk = match received {
                         Ok(chunk) => chunk,
@@ -94,6 +107,7 @@ pub(crate) fn start_streaming_output(
                         &mut emitted_deltas,
                         chunk,
                     ).await;
+                    idle_flush.replace(Box::pin(tokio::time::sleep(HELD_OUTPUT_FLUSH_DELAY)));
                 }
             }
         }
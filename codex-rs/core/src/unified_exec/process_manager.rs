@@ -1,3 +1,4 @@
+use codex_execpolicy::MatchContext;
 use rand::Rng;
 use std::cmp::Reverse;
 use std::collections::HashMap;
@@ -525,10 +526,10 @@ impl UnifiedExecProcessManager {
         cwd: PathBuf,
         context: &UnifiedExecContext,
     ) -> Result<UnifiedExecProcess, UnifiedExecError> {
-        let env = apply_unified_exec_env(create_env(
+        let env = apply_unified_exec_env(context.session.services.secrets.inject(create_env(
             &context.turn.shell_environment_policy,
             Some(context.session.conversation_id),
-        ));
+        )));
         let mut orchestrator = ToolOrchestrator::new();
         let mut runtime = UnifiedExecRuntime::new(self);
         let exec_approval_requirement = context
@@ -541,6 +542,10 @@ impl UnifiedExecProcessManager {
                 sandbox_policy: &context.turn.sandbox_policy,
                 sandbox_permissions: request.sandbox_permissions,
                 prefix_rule: request.prefix_rule.clone(),
+                match_context: MatchContext {
+                    cwd: Some(cwd.as_path()),
+                    workspace_root: Some(context.turn.cwd.as_path()),
+                },
             })
             .await;
         let req = UnifiedExecToolRequest {
@@ -105,6 +105,8 @@ async fn stdio_server_round_trip() -> anyhow::Result<()> {
                     enabled_tools: None,
                     disabled_tools: None,
                     scopes: None,
+                    sampling: None,
+                    approval: None,
                 },
             );
             config
@@ -247,6 +249,8 @@ async fn stdio_image_responses_round_trip() -> anyhow::Result<()> {
                     enabled_tools: None,
                     disabled_tools: None,
                     scopes: None,
+                    sampling: None,
+                    approval: None,
                 },
             );
             config
@@ -464,6 +468,8 @@ async fn stdio_image_responses_are_sanitized_for_text_only_model() -> anyhow::Re
                     enabled_tools: None,
                     disabled_tools: None,
                     scopes: None,
+                    sampling: None,
+                    approval: None,
                 },
             );
             config
@@ -582,6 +588,8 @@ async fn stdio_server_propagates_whitelisted_env_vars() -> anyhow::Result<()> {
                     enabled_tools: None,
                     disabled_tools: None,
                     scopes: None,
+                    sampling: None,
+                    approval: None,
                 },
             );
             config
@@ -741,6 +749,8 @@ async fn streamable_http_tool_call_round_trip() -> anyhow::Result<()> {
                     enabled_tools: None,
                     disabled_tools: None,
                     scopes: None,
+                    sampling: None,
+                    approval: None,
                 },
             );
             config
@@ -932,6 +942,8 @@ async fn streamable_http_with_oauth_round_trip() -> anyhow::Result<()> {
                     enabled_tools: None,
                     disabled_tools: None,
                     scopes: None,
+                    sampling: None,
+                    approval: None,
                 },
             );
             config
@@ -210,6 +210,8 @@ async fn search_tool_hides_mcp_tools_without_search() -> Result<()> {
                 enabled_tools: None,
                 disabled_tools: None,
                 scopes: None,
+                sampling: None,
+                approval: None,
             },
         );
         config
@@ -293,6 +295,8 @@ async fn search_tool_selection_persists_within_turn_and_resets_next_turn() -> Re
                 enabled_tools: None,
                 disabled_tools: None,
                 scopes: None,
+                sampling: None,
+                approval: None,
             },
         );
         config
@@ -421,6 +425,8 @@ async fn search_tool_selection_unions_results_within_turn() -> Result<()> {
                 enabled_tools: None,
                 disabled_tools: None,
                 scopes: None,
+                sampling: None,
+                approval: None,
             },
         );
         config
@@ -112,6 +112,9 @@ pub enum Command {
 
     /// Run a code review against the current repository.
     Review(ReviewArgs),
+
+    /// Implement a spec on an `sdd/` branch, review and test it, then merge or abandon it.
+    Sdd(SddArgs),
 }
 
 #[derive(Args, Debug)]
@@ -240,6 +243,32 @@ pub struct ReviewArgs {
     pub prompt: Option<String>,
 }
 
+#[derive(Parser, Debug)]
+pub struct SddArgs {
+    /// Markdown file describing the change to implement.
+    #[arg(long = "spec", value_name = "FILE")]
+    pub spec: PathBuf,
+
+    /// Branch to develop on. Defaults to `sdd/<slug of the spec title>`.
+    #[arg(long = "branch", value_name = "BRANCH")]
+    pub branch: Option<String>,
+
+    /// Acceptance command that must exit with status 0 before the branch is merged.
+    /// May be repeated; commands run in order through the platform shell.
+    #[arg(long = "test-command", value_name = "CMD")]
+    pub test_commands: Vec<String>,
+
+    /// Maximum number of development rounds, counting the initial implementation and
+    /// each fix after a blocking review or a failing test command.
+    #[arg(
+        long = "max-iterations",
+        value_name = "N",
+        default_value_t = 3,
+        value_parser = clap::value_parser!(u32).range(1..)
+    )]
+    pub max_iterations: u32,
+}
+
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
 #[value(rename_all = "kebab-case")]
 pub enum Color {
@@ -283,4 +312,28 @@ mod tests {
         });
         assert_eq!(effective_prompt.as_deref(), Some(PROMPT));
     }
+
+    #[test]
+    fn sdd_parses_repeated_test_commands() {
+        let cli = Cli::parse_from([
+            "codex-exec",
+            "sdd",
+            "--spec",
+            "docs/spec.md",
+            "--test-command",
+            "cargo test",
+            "--test-command",
+            "just fmt --check",
+            "--json",
+        ]);
+
+        assert!(cli.json);
+        let Some(Command::Sdd(args)) = cli.command else {
+            panic!("expected sdd command");
+        };
+        assert_eq!(args.spec, PathBuf::from("docs/spec.md"));
+        assert_eq!(args.test_commands, vec!["cargo test", "just fmt --check"]);
+        assert_eq!(args.max_iterations, 3);
+        assert_eq!(args.branch, None);
+    }
 }
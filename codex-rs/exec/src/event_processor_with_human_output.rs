@@ -5,6 +5,7 @@ use codex_core::protocol::AgentMessageEvent;
 use codex_core::protocol::AgentReasoningRawContentEvent;
 use codex_core::protocol::AgentStatus;
 use codex_core::protocol::BackgroundEventEvent;
+use codex_core::protocol::CheckpointCreatedEvent;
 use codex_core::protocol::CollabAgentInteractionBeginEvent;
 use codex_core::protocol::CollabAgentInteractionEndEvent;
 use codex_core::protocol::CollabAgentSpawnBeginEvent;
@@ -46,6 +47,9 @@ use std::time::Instant;
 use crate::event_processor::CodexStatus;
 use crate::event_processor::EventProcessor;
 use crate::event_processor::handle_last_message;
+use crate::exec_events::SddOutcome;
+use crate::sdd::SddProgress;
+use crate::sdd::stage_label;
 use codex_common::create_config_summary_entries;
 use codex_protocol::plan_tool::StepStatus;
 use codex_protocol::plan_tool::UpdatePlanArgs;
@@ -264,6 +268,19 @@ impl EventProcessor for EventProcessorWithHumanOutput {
                     "auto-cancelling (not supported in exec mode)".style(self.dimmed)
                 );
             }
+            EventMsg::SamplingRequest(ev) => {
+                ts_msg!(
+                    self,
+                    "{} {}",
+                    "sampling request".style(self.magenta),
+                    ev.server_name.style(self.dimmed)
+                );
+                ts_msg!(
+                    self,
+                    "{}",
+                    "auto-declining (not supported in exec mode)".style(self.dimmed)
+                );
+            }
             EventMsg::TurnComplete(TurnCompleteEvent { last_agent_message }) => {
                 let last_message = last_agent_message
                     .as_deref()
@@ -610,6 +627,13 @@ impl EventProcessor for EventProcessorWithHumanOutput {
             EventMsg::ContextCompacted(_) => {
                 ts_msg!(self, "context compacted");
             }
+            EventMsg::CheckpointCreated(CheckpointCreatedEvent { name, path, .. }) => {
+                ts_msg!(
+                    self,
+                    "{}",
+                    format!("checkpoint {name} written to {}", path.display()).style(self.dimmed)
+                );
+            }
             EventMsg::CollabAgentSpawnBegin(CollabAgentSpawnBeginEvent {
                 call_id,
                 sender_thread_id: _,
@@ -770,11 +794,15 @@ impl EventProcessor for EventProcessorWithHumanOutput {
             EventMsg::ThreadNameUpdated(_)
             | EventMsg::ExecApprovalRequest(_)
             | EventMsg::ApplyPatchApprovalRequest(_)
+            | EventMsg::NetworkApprovalRequest(_)
+            | EventMsg::McpToolCallApproval(_)
             | EventMsg::TerminalInteraction(_)
             | EventMsg::ExecCommandOutputDelta(_)
             | EventMsg::GetHistoryEntryResponse(_)
             | EventMsg::McpListToolsResponse(_)
             | EventMsg::ListCustomPromptsResponse(_)
+            | EventMsg::ListMcpPromptsResponse(_)
+            | EventMsg::GetMcpPromptResponse(_)
             | EventMsg::ListSkillsResponse(_)
             | EventMsg::ListRemoteSkillsResponse(_)
             | EventMsg::RemoteSkillDownloaded(_)
@@ -803,6 +831,85 @@ impl EventProcessor for EventProcessorWithHumanOutput {
         CodexStatus::Running
     }
 
+    fn process_sdd_progress(&mut self, progress: SddProgress) {
+        match progress {
+            SddProgress::StageStarted { stage, iteration } => {
+                ts_msg!(
+                    self,
+                    "{} {}",
+                    "sdd".style(self.italic).style(self.magenta),
+                    format!("{} (round {iteration})", stage_label(stage)).style(self.bold)
+                );
+            }
+            SddProgress::StageCompleted {
+                stage,
+                success,
+                summary,
+                ..
+            } => {
+                let summary = summary
+                    .map(|summary| format!(": {summary}"))
+                    .unwrap_or_default();
+                if success {
+                    let title = format!("{} succeeded{summary}", stage_label(stage));
+                    ts_msg!(self, "{}", title.style(self.green));
+                } else {
+                    let title = format!("{} failed{summary}", stage_label(stage));
+                    ts_msg!(self, "{}", title.style(self.red));
+                }
+            }
+            SddProgress::TestCommandStarted { command } => {
+                ts_msg!(
+                    self,
+                    "{}\n{}",
+                    "sdd test".style(self.italic).style(self.magenta),
+                    command.style(self.bold)
+                );
+            }
+            SddProgress::TestCommandFinished {
+                exit_code, output, ..
+            } => {
+                let truncated_output = output
+                    .lines()
+                    .take(MAX_OUTPUT_LINES_FOR_EXEC_TOOL_CALL)
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                match exit_code {
+                    Some(0) => ts_msg!(self, "{}", " succeeded:".style(self.green)),
+                    Some(code) => {
+                        ts_msg!(self, "{}", format!(" exited {code}:").style(self.red))
+                    }
+                    None => ts_msg!(self, "{}", " terminated:".style(self.red)),
+                }
+                eprintln!("{}", truncated_output.style(self.dimmed));
+            }
+            SddProgress::Finished {
+                branch,
+                base_branch,
+                outcome,
+                reason,
+            } => {
+                let reason = reason
+                    .map(|reason| format!(" ({reason})"))
+                    .unwrap_or_default();
+                match outcome {
+                    SddOutcome::Merged => {
+                        let title = format!("sdd: merged {branch} into {base_branch}");
+                        ts_msg!(self, "{}", title.style(self.green).style(self.bold));
+                    }
+                    SddOutcome::Abandoned => {
+                        let title = format!("sdd: abandoned {branch}{reason}");
+                        ts_msg!(self, "{}", title.style(self.yellow).style(self.bold));
+                    }
+                    SddOutcome::Failed => {
+                        let title = format!("sdd: failed on {branch}{reason}");
+                        ts_msg!(self, "{}", title.style(self.red).style(self.bold));
+                    }
+                }
+            }
+        }
+    }
+
     fn print_final_output(&mut self) {
         if let Some(usage_info) = &self.last_total_token_usage {
             eprintln!(
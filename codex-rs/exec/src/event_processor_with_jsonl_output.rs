@@ -26,6 +26,9 @@ use crate::exec_events::McpToolCallStatus;
 use crate::exec_events::PatchApplyStatus;
 use crate::exec_events::PatchChangeKind;
 use crate::exec_events::ReasoningItem;
+use crate::exec_events::SddCompletedEvent;
+use crate::exec_events::SddStageCompletedEvent;
+use crate::exec_events::SddStageStartedEvent;
 use crate::exec_events::ThreadErrorEvent;
 use crate::exec_events::ThreadEvent;
 use crate::exec_events::ThreadItem;
@@ -38,6 +41,7 @@ use crate::exec_events::TurnFailedEvent;
 use crate::exec_events::TurnStartedEvent;
 use crate::exec_events::Usage;
 use crate::exec_events::WebSearchItem;
+use crate::sdd::SddProgress;
 use codex_core::config::Config;
 use codex_core::protocol;
 use codex_core::protocol::AgentStatus as CoreAgentStatus;
@@ -69,6 +73,8 @@ pub struct EventProcessorWithJsonOutput {
     running_mcp_tool_calls: HashMap<String, RunningMcpToolCall>,
     running_collab_tool_calls: HashMap<String, RunningCollabToolCall>,
     running_web_search_calls: HashMap<String, String>,
+    // Item id of the SDD acceptance command currently running.
+    running_sdd_test_item: Option<String>,
     last_critical_error: Option<ThreadErrorEvent>,
 }
 
@@ -112,6 +118,7 @@ impl EventProcessorWithJsonOutput {
             running_mcp_tool_calls: HashMap::new(),
             running_collab_tool_calls: HashMap::new(),
             running_web_search_calls: HashMap::new(),
+            running_sdd_test_item: None,
             last_critical_error: None,
         }
     }
@@ -191,6 +198,78 @@ impl EventProcessorWithJsonOutput {
         }
     }
 
+    pub(crate) fn collect_sdd_events(&mut self, progress: SddProgress) -> Vec<ThreadEvent> {
+        match progress {
+            SddProgress::StageStarted { stage, iteration } => {
+                vec![ThreadEvent::SddStageStarted(SddStageStartedEvent {
+                    stage,
+                    iteration,
+                })]
+            }
+            SddProgress::StageCompleted {
+                stage,
+                iteration,
+                success,
+                summary,
+            } => vec![ThreadEvent::SddStageCompleted(SddStageCompletedEvent {
+                stage,
+                iteration,
+                success,
+                summary,
+            })],
+            SddProgress::TestCommandStarted { command } => {
+                let item_id = self.get_next_item_id();
+                self.running_sdd_test_item = Some(item_id.clone());
+                let item = ThreadItem {
+                    id: item_id,
+                    details: ThreadItemDetails::CommandExecution(CommandExecutionItem {
+                        command,
+                        aggregated_output: String::new(),
+                        exit_code: None,
+                        status: CommandExecutionStatus::InProgress,
+                    }),
+                };
+                vec![ThreadEvent::ItemStarted(ItemStartedEvent { item })]
+            }
+            SddProgress::TestCommandFinished {
+                command,
+                exit_code,
+                output,
+            } => {
+                let item_id = self
+                    .running_sdd_test_item
+                    .take()
+                    .unwrap_or_else(|| self.get_next_item_id());
+                let status = if exit_code == Some(0) {
+                    CommandExecutionStatus::Completed
+                } else {
+                    CommandExecutionStatus::Failed
+                };
+                let item = ThreadItem {
+                    id: item_id,
+                    details: ThreadItemDetails::CommandExecution(CommandExecutionItem {
+                        command,
+                        aggregated_output: output,
+                        exit_code,
+                        status,
+                    }),
+                };
+                vec![ThreadEvent::ItemCompleted(ItemCompletedEvent { item })]
+            }
+            SddProgress::Finished {
+                branch,
+                base_branch,
+                outcome,
+                reason,
+            } => vec![ThreadEvent::SddCompleted(SddCompletedEvent {
+                branch,
+                base_branch,
+                outcome,
+                reason,
+            })],
+        }
+    }
+
     fn get_next_item_id(&self) -> String {
         format!(
             "item_{}",
@@ -843,19 +922,9 @@ impl EventProcessor for EventProcessorWithJsonOutput {
         });
     }
 
-    #[allow(clippy::print_stdout)]
     fn process_event(&mut self, event: protocol::Event) -> CodexStatus {
         let aggregated = self.collect_thread_events(&event);
-        for conv_event in aggregated {
-            match serde_json::to_string(&conv_event) {
-                Ok(line) => {
-                    println!("{line}");
-                }
-                Err(e) => {
-                    error!("Failed to serialize event: {e:?}");
-                }
-            }
-        }
+        print_thread_events(aggregated);
 
         let protocol::Event { msg, .. } = event;
 
@@ -876,4 +945,23 @@ impl EventProcessor for EventProcessorWithJsonOutput {
             _ => CodexStatus::Running,
         }
     }
+
+    fn process_sdd_progress(&mut self, progress: SddProgress) {
+        let events = self.collect_sdd_events(progress);
+        print_thread_events(events);
+    }
+}
+
+#[allow(clippy::print_stdout)]
+fn print_thread_events(events: Vec<ThreadEvent>) {
+    for conv_event in events {
+        match serde_json::to_string(&conv_event) {
+            Ok(line) => {
+                println!("{line}");
+            }
+            Err(e) => {
+                error!("Failed to serialize event: {e:?}");
+            }
+        }
+    }
 }
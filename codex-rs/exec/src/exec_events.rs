@@ -34,6 +34,15 @@ pub enum ThreadEvent {
     /// Represents an unrecoverable error emitted directly by the event stream.
     #[serde(rename = "error")]
     Error(ThreadErrorEvent),
+    /// Emitted by `codex exec sdd` when a pipeline stage starts.
+    #[serde(rename = "sdd.stage_started")]
+    SddStageStarted(SddStageStartedEvent),
+    /// Emitted by `codex exec sdd` when a pipeline stage finishes.
+    #[serde(rename = "sdd.stage_completed")]
+    SddStageCompleted(SddStageCompletedEvent),
+    /// Emitted by `codex exec sdd` once the branch has been merged or abandoned.
+    #[serde(rename = "sdd.completed")]
+    SddCompleted(SddCompletedEvent),
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, TS)]
@@ -56,6 +65,56 @@ pub struct TurnFailedEvent {
     pub error: ThreadErrorEvent,
 }
 
+/// Stages of the headless SDD pipeline run by `codex exec sdd`.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, TS)]
+#[serde(rename_all = "snake_case")]
+pub enum SddStage {
+    CreateBranch,
+    Plan,
+    Develop,
+    Review,
+    Fix,
+    Test,
+    Merge,
+    Abandon,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, TS)]
+pub struct SddStageStartedEvent {
+    pub stage: SddStage,
+    /// Development round the stage belongs to, starting at 1.
+    pub iteration: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, TS)]
+pub struct SddStageCompletedEvent {
+    pub stage: SddStage,
+    pub iteration: u32,
+    pub success: bool,
+    /// Short explanation, e.g. the review verdict or the failing test command.
+    pub summary: Option<String>,
+}
+
+/// Final state of the SDD branch.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, TS)]
+#[serde(rename_all = "snake_case")]
+pub enum SddOutcome {
+    /// The branch passed review and tests and was merged into the base branch.
+    Merged,
+    /// The branch was deleted and the repository left on the base branch.
+    Abandoned,
+    /// A git action failed; the repository may still be on the SDD branch.
+    Failed,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, TS)]
+pub struct SddCompletedEvent {
+    pub branch: String,
+    pub base_branch: String,
+    pub outcome: SddOutcome,
+    pub reason: Option<String>,
+}
+
 /// Describes the usage of tokens during a turn.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, TS, Default)]
 pub struct Usage {
@@ -9,6 +9,7 @@ mod event_processor;
 mod event_processor_with_human_output;
 pub mod event_processor_with_jsonl_output;
 pub mod exec_events;
+mod sdd;
 
 pub use cli::Cli;
 pub use cli::Command;
@@ -30,6 +31,7 @@ use codex_core::config::load_config_as_toml_with_cli_overrides;
 use codex_core::config::resolve_oss_provider;
 use codex_core::config_loader::ConfigLoadError;
 use codex_core::config_loader::format_config_error_with_source;
+use codex_core::git_info::current_branch_name;
 use codex_core::git_info::get_git_repo_root;
 use codex_core::models_manager::manager::RefreshStrategy;
 use codex_core::protocol::AskForApproval;
@@ -40,6 +42,8 @@ use codex_core::protocol::ReviewRequest;
 use codex_core::protocol::ReviewTarget;
 use codex_core::protocol::SessionSource;
 use codex_protocol::approvals::ElicitationAction;
+use codex_protocol::approvals::NetworkApprovalDecision;
+use codex_protocol::approvals::SamplingDecision;
 use codex_protocol::config_types::SandboxMode;
 use codex_protocol::user_input::UserInput;
 use codex_utils_absolute_path::AbsolutePathBuf;
@@ -64,6 +68,10 @@ use uuid::Uuid;
 use crate::cli::Command as ExecCommand;
 use crate::event_processor::CodexStatus;
 use crate::event_processor::EventProcessor;
+use crate::exec_events::SddOutcome;
+use crate::sdd::SddNext;
+use crate::sdd::SddPipeline;
+use crate::sdd::SddTurnOutcome;
 use codex_core::default_client::set_default_client_residency_requirement;
 use codex_core::default_client::set_default_originator;
 use codex_core::find_thread_path_by_id_str;
@@ -77,6 +85,9 @@ enum InitialOperation {
     Review {
         review_request: ReviewRequest,
     },
+    Sdd {
+        pipeline: Box<SddPipeline>,
+    },
 }
 
 #[derive(Clone)]
@@ -422,6 +433,35 @@ pub async fn run_main(cli: Cli, codex_linux_sandbox_exe: Option<PathBuf>) -> any
             );
             (InitialOperation::Review { review_request }, summary)
         }
+        (Some(ExecCommand::Sdd(args)), _, _) => {
+            let spec = std::fs::read_to_string(&args.spec).map_err(|err| {
+                anyhow::anyhow!("failed to read spec {}: {err}", args.spec.display())
+            })?;
+            let Some(base_branch) = current_branch_name(&default_cwd).await else {
+                anyhow::bail!(
+                    "codex exec sdd must run on a checked-out branch of a Git repository"
+                );
+            };
+            let pipeline = SddPipeline::new(
+                config.language,
+                spec,
+                args.branch,
+                base_branch,
+                args.test_commands,
+                args.max_iterations,
+            );
+            let summary = format!(
+                "SDD spec {} on branch {}",
+                args.spec.display(),
+                pipeline.branch()
+            );
+            (
+                InitialOperation::Sdd {
+                    pipeline: Box::new(pipeline),
+                },
+                summary,
+            )
+        }
         (Some(ExecCommand::Resume(args)), root_prompt, imgs) => {
             let prompt_arg = args
                 .prompt
@@ -528,32 +568,42 @@ pub async fn run_main(cli: Cli, codex_linux_sandbox_exe: Option<PathBuf>) -> any
         });
     }
 
+    let user_turn = |items: Vec<UserInput>, final_output_json_schema: Option<Value>| Op::UserTurn {
+        items,
+        cwd: default_cwd.clone(),
+        approval_policy: default_approval_policy,
+        sandbox_policy: default_sandbox_policy.clone(),
+        model: default_model.clone(),
+        effort: default_effort,
+        summary: default_summary,
+        final_output_json_schema,
+        collaboration_mode: None,
+        personality: None,
+    };
+
+    let mut sdd_pipeline: Option<Box<SddPipeline>> = None;
     match initial_operation {
         InitialOperation::UserTurn {
             items,
             output_schema,
         } => {
-            let task_id = thread
-                .submit(Op::UserTurn {
-                    items,
-                    cwd: default_cwd,
-                    approval_policy: default_approval_policy,
-                    sandbox_policy: default_sandbox_policy.clone(),
-                    model: default_model,
-                    effort: default_effort,
-                    summary: default_summary,
-                    final_output_json_schema: output_schema,
-                    collaboration_mode: None,
-                    personality: None,
-                })
-                .await?;
+            let task_id = thread.submit(user_turn(items, output_schema)).await?;
             info!("Sent prompt with event ID: {task_id}");
-            task_id
         }
         InitialOperation::Review { review_request } => {
             let task_id = thread.submit(Op::Review { review_request }).await?;
             info!("Sent review request with event ID: {task_id}");
-            task_id
+        }
+        InitialOperation::Sdd { mut pipeline } => {
+            let next = pipeline.start();
+            for progress in pipeline.take_progress() {
+                event_processor.process_sdd_progress(progress);
+            }
+            if let Some(op) = sdd_step_op(next, &user_turn) {
+                let task_id = thread.submit(op).await?;
+                info!("Started SDD pipeline with event ID: {task_id}");
+            }
+            sdd_pipeline = Some(pipeline);
         }
     };
 
@@ -562,6 +612,8 @@ pub async fn run_main(cli: Cli, codex_linux_sandbox_exe: Option<PathBuf>) -> any
     // exit with a non-zero status for automation-friendly signaling.
     let mut error_seen = false;
     let mut shutdown_requested = false;
+    let mut sdd_turn = SddTurnOutcome::default();
+    let mut sdd_outcome: Option<SddOutcome> = None;
     while let Some(envelope) = rx.recv().await {
         let ThreadEventEnvelope {
             thread_id,
@@ -571,6 +623,13 @@ pub async fn run_main(cli: Cli, codex_linux_sandbox_exe: Option<PathBuf>) -> any
         if matches!(event.msg, EventMsg::Error(_)) {
             error_seen = true;
         }
+        if thread_id == primary_thread_id && sdd_pipeline.is_some() {
+            match &event.msg {
+                EventMsg::Error(_) => sdd_turn.failed = true,
+                EventMsg::ExitedReviewMode(ev) => sdd_turn.review = ev.review_output.clone(),
+                _ => {}
+            }
+        }
         if shutdown_requested
             && !matches!(&event.msg, EventMsg::ShutdownComplete | EventMsg::Error(_))
         {
@@ -586,6 +645,25 @@ pub async fn run_main(cli: Cli, codex_linux_sandbox_exe: Option<PathBuf>) -> any
                 })
                 .await?;
         }
+        if let EventMsg::SamplingRequest(ev) = &event.msg {
+            // Nobody can approve sampling requests in exec mode.
+            thread
+                .submit(Op::ResolveSampling {
+                    server_name: ev.server_name.clone(),
+                    request_id: ev.id.clone(),
+                    decision: SamplingDecision::Decline,
+                })
+                .await?;
+        }
+        if let EventMsg::NetworkApprovalRequest(ev) = &event.msg {
+            // Nobody can answer network approvals in exec mode; keep the host blocked.
+            thread
+                .submit(Op::NetworkApproval {
+                    id: ev.id.clone(),
+                    decision: NetworkApprovalDecision::Deny,
+                })
+                .await?;
+        }
         if let EventMsg::McpStartupUpdate(update) = &event.msg
             && required_mcp_servers.contains(&update.server)
             && let codex_core::protocol::McpStartupStatus::Failed { error } = &update.status
@@ -603,10 +681,46 @@ pub async fn run_main(cli: Cli, codex_linux_sandbox_exe: Option<PathBuf>) -> any
         if thread_id != primary_thread_id && matches!(&event.msg, EventMsg::TurnComplete(_)) {
             continue;
         }
+        let turn_ended = matches!(
+            &event.msg,
+            EventMsg::TurnComplete(_) | EventMsg::TurnAborted(_)
+        );
+        let interrupted = matches!(&event.msg, EventMsg::TurnAborted(_));
         let shutdown = event_processor.process_event(event);
         if thread_id != primary_thread_id && matches!(shutdown, CodexStatus::InitiateShutdown) {
             continue;
         }
+        if thread_id == primary_thread_id
+            && turn_ended
+            && !shutdown_requested
+            && let Some(pipeline) = sdd_pipeline.as_mut()
+        {
+            // An interrupted turn stops the pipeline and leaves the repository as it is.
+            let mut next = if interrupted {
+                pipeline.interrupt()
+            } else {
+                pipeline.on_turn_finished(std::mem::take(&mut sdd_turn))
+            };
+            while let SddNext::RunTests(commands) = &next {
+                for progress in pipeline.take_progress() {
+                    event_processor.process_sdd_progress(progress);
+                }
+                let failure = sdd::run_test_commands(commands, &default_cwd, |progress| {
+                    event_processor.process_sdd_progress(progress)
+                })
+                .await;
+                next = pipeline.on_tests_finished(failure);
+            }
+            for progress in pipeline.take_progress() {
+                event_processor.process_sdd_progress(progress);
+            }
+            if let SddNext::Finished(outcome) = next {
+                sdd_outcome = Some(outcome);
+            } else if let Some(op) = sdd_step_op(next, &user_turn) {
+                thread.submit(op).await?;
+                continue;
+            }
+        }
         match shutdown {
             CodexStatus::Running => continue,
             CodexStatus::InitiateShutdown => {
@@ -620,13 +734,34 @@ pub async fn run_main(cli: Cli, codex_linux_sandbox_exe: Option<PathBuf>) -> any
         }
     }
     event_processor.print_final_output();
-    if error_seen {
+    if error_seen || sdd_outcome.is_some_and(|outcome| outcome != SddOutcome::Merged) {
         std::process::exit(1);
     }
 
     Ok(())
 }
 
+/// Converts the next SDD pipeline step into the op that performs it. Acceptance commands run in
+/// the driver and a finished pipeline has nothing left to submit.
+fn sdd_step_op(
+    next: SddNext,
+    user_turn: impl Fn(Vec<UserInput>, Option<Value>) -> Op,
+) -> Option<Op> {
+    match next {
+        SddNext::UserTurn(text) => Some(user_turn(
+            vec![UserInput::Text {
+                text,
+                // SDD prompts are synthesized; no UI element ranges to preserve.
+                text_elements: Vec::new(),
+            }],
+            None,
+        )),
+        SddNext::Review(review_request) => Some(Op::Review { review_request }),
+        SddNext::GitAction(action) => Some(Op::SddGitAction { action }),
+        SddNext::RunTests(_) | SddNext::Finished(_) => None,
+    }
+}
+
 fn spawn_thread_listener(
     thread_id: codex_protocol::ThreadId,
     thread: Arc<codex_core::CodexThread>,
@@ -0,0 +1,638 @@
+//! Headless driver for `codex exec sdd`.
+//!
+//! The pipeline mirrors the interactive `/sdd-develop` flow without asking the user anything:
+//! create the `sdd/` branch, plan, develop, review, run the acceptance commands, and finally
+//! merge or abandon the branch. [`SddPipeline`] only decides what to do next; `run_main` submits
+//! the resulting ops and reports each turn back.
+
+use std::path::Path;
+use std::process::Stdio;
+
+use codex_core::i18n::tr;
+use codex_core::i18n::tr_args;
+use codex_core::protocol::ReviewOutputEvent;
+use codex_core::protocol::ReviewRequest;
+use codex_core::protocol::ReviewTarget;
+use codex_core::protocol::SddGitAction;
+use codex_protocol::config_types::Language;
+
+use crate::exec_events::SddOutcome;
+use crate::exec_events::SddStage;
+
+const SDD_BRANCH_PREFIX: &str = "sdd/";
+
+/// Keep the tail of failing command output short enough to quote back to the model.
+const MAX_TEST_OUTPUT_CHARS: usize = 4000;
+
+/// Progress reported to the event processors while the pipeline runs.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum SddProgress {
+    StageStarted {
+        stage: SddStage,
+        iteration: u32,
+    },
+    StageCompleted {
+        stage: SddStage,
+        iteration: u32,
+        success: bool,
+        summary: Option<String>,
+    },
+    TestCommandStarted {
+        command: String,
+    },
+    TestCommandFinished {
+        command: String,
+        exit_code: Option<i32>,
+        output: String,
+    },
+    Finished {
+        branch: String,
+        base_branch: String,
+        outcome: SddOutcome,
+        reason: Option<String>,
+    },
+}
+
+/// What the driver should do after a pipeline transition.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum SddNext {
+    UserTurn(String),
+    Review(ReviewRequest),
+    GitAction(SddGitAction),
+    RunTests(Vec<String>),
+    Finished(SddOutcome),
+}
+
+/// Result of the turn submitted for the current stage.
+#[derive(Debug, Default)]
+pub(crate) struct SddTurnOutcome {
+    /// An `Error` event was reported or the turn was aborted.
+    pub(crate) failed: bool,
+    /// Output of the review task, present only for review turns.
+    pub(crate) review: Option<ReviewOutputEvent>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct SddTestFailure {
+    pub(crate) command: String,
+    pub(crate) exit_code: Option<i32>,
+    pub(crate) output: String,
+}
+
+pub(crate) struct SddPipeline {
+    language: Language,
+    spec: String,
+    branch: String,
+    base_branch: String,
+    commit_message: String,
+    test_commands: Vec<String>,
+    max_iterations: u32,
+    iteration: u32,
+    stage: SddStage,
+    fix_prompt: String,
+    abandon_reason: Option<String>,
+    progress: Vec<SddProgress>,
+}
+
+impl SddPipeline {
+    pub(crate) fn new(
+        language: Language,
+        spec: String,
+        branch: Option<String>,
+        base_branch: String,
+        test_commands: Vec<String>,
+        max_iterations: u32,
+    ) -> Self {
+        let slug = sdd_slug(&spec_title(&spec));
+        let branch = match branch {
+            Some(branch) if branch.starts_with(SDD_BRANCH_PREFIX) => branch,
+            Some(branch) => format!("{SDD_BRANCH_PREFIX}{branch}"),
+            None => format!("{SDD_BRANCH_PREFIX}{slug}"),
+        };
+        Self {
+            language,
+            spec,
+            branch,
+            base_branch,
+            commit_message: format!("sdd: {slug}"),
+            test_commands,
+            max_iterations: max_iterations.max(1),
+            iteration: 1,
+            stage: SddStage::CreateBranch,
+            fix_prompt: String::new(),
+            abandon_reason: None,
+            progress: Vec::new(),
+        }
+    }
+
+    pub(crate) fn branch(&self) -> &str {
+        &self.branch
+    }
+
+    pub(crate) fn take_progress(&mut self) -> Vec<SddProgress> {
+        std::mem::take(&mut self.progress)
+    }
+
+    pub(crate) fn start(&mut self) -> SddNext {
+        self.enter(SddStage::CreateBranch)
+    }
+
+    /// Advances the pipeline after the turn submitted for the current stage completed.
+    pub(crate) fn on_turn_finished(&mut self, outcome: SddTurnOutcome) -> SddNext {
+        match self.stage {
+            SddStage::CreateBranch => {
+                if outcome.failed {
+                    self.complete_stage(false, None);
+                    // Nothing was created, so there is nothing to clean up.
+                    return self.finish(
+                        SddOutcome::Failed,
+                        Some("failed to create the SDD branch".to_string()),
+                    );
+                }
+                self.complete_stage(true, None);
+                self.enter(SddStage::Plan)
+            }
+            SddStage::Plan | SddStage::Develop | SddStage::Fix => {
+                if outcome.failed {
+                    self.complete_stage(false, None);
+                    return self.abandon(format!("{} turn failed", stage_label(self.stage)));
+                }
+                self.complete_stage(true, None);
+                match self.stage {
+                    SddStage::Plan => self.enter(SddStage::Develop),
+                    _ => self.enter(SddStage::Review),
+                }
+            }
+            SddStage::Review => {
+                let Some(review) = outcome.review.filter(|_| !outcome.failed) else {
+                    self.complete_stage(false, Some("review produced no result".to_string()));
+                    return self.abandon("review produced no result".to_string());
+                };
+                let verdict = review_verdict(&review);
+                if review_blocks_merge(&review) {
+                    self.complete_stage(false, Some(verdict.clone()));
+                    let prompt = tr_args(
+                        self.language,
+                        "prompt.sdd_fix_review",
+                        &[("findings", &format_findings(&review))],
+                    );
+                    return self.retry_or_abandon(prompt, verdict);
+                }
+                self.complete_stage(true, Some(verdict));
+                if self.test_commands.is_empty() {
+                    self.enter(SddStage::Merge)
+                } else {
+                    self.enter(SddStage::Test)
+                }
+            }
+            SddStage::Test => {
+                // Acceptance commands run outside of a turn; see `on_tests_finished`.
+                self.finish(
+                    SddOutcome::Failed,
+                    Some("unexpected turn while running tests".to_string()),
+                )
+            }
+            SddStage::Merge => {
+                if outcome.failed {
+                    self.complete_stage(false, None);
+                    return self.finish(
+                        SddOutcome::Failed,
+                        Some(format!(
+                            "failed to merge {} into {}",
+                            self.branch, self.base_branch
+                        )),
+                    );
+                }
+                self.complete_stage(true, None);
+                self.finish(SddOutcome::Merged, None)
+            }
+            SddStage::Abandon => {
+                let reason = self.abandon_reason.take();
+                if outcome.failed {
+                    self.complete_stage(false, None);
+                    return self.finish(
+                        SddOutcome::Failed,
+                        Some(format!("failed to delete {}", self.branch)),
+                    );
+                }
+                self.complete_stage(true, None);
+                self.finish(SddOutcome::Abandoned, reason)
+            }
+        }
+    }
+
+    /// Stops the pipeline after the user interrupted the current turn, leaving the repository
+    /// on whatever branch it is on.
+    pub(crate) fn interrupt(&mut self) -> SddNext {
+        self.complete_stage(false, Some("interrupted".to_string()));
+        self.finish(SddOutcome::Failed, Some("interrupted".to_string()))
+    }
+
+    /// Advances the pipeline after the acceptance commands ran; `failure` is the first command
+    /// that did not exit successfully.
+    pub(crate) fn on_tests_finished(&mut self, failure: Option<SddTestFailure>) -> SddNext {
+        let Some(failure) = failure else {
+            self.complete_stage(true, None);
+            return self.enter(SddStage::Merge);
+        };
+        let exit_code = failure
+            .exit_code
+            .map_or_else(|| "none".to_string(), |code| code.to_string());
+        let summary = format!("`{}` exited with {exit_code}", failure.command);
+        self.complete_stage(false, Some(summary.clone()));
+        let prompt = tr_args(
+            self.language,
+            "prompt.sdd_fix_tests",
+            &[
+                ("command", &failure.command),
+                ("exit_code", &exit_code),
+                (
+                    "output",
+                    &tail_chars(&failure.output, MAX_TEST_OUTPUT_CHARS),
+                ),
+            ],
+        );
+        self.retry_or_abandon(prompt, summary)
+    }
+
+    fn retry_or_abandon(&mut self, prompt: String, reason: String) -> SddNext {
+        if self.iteration >= self.max_iterations {
+            return self.abandon(format!(
+                "{reason} after {} development round(s)",
+                self.iteration
+            ));
+        }
+        self.iteration += 1;
+        self.fix_prompt = prompt;
+        self.enter(SddStage::Fix)
+    }
+
+    fn abandon(&mut self, reason: String) -> SddNext {
+        self.abandon_reason = Some(reason);
+        self.enter(SddStage::Abandon)
+    }
+
+    fn enter(&mut self, stage: SddStage) -> SddNext {
+        self.stage = stage;
+        self.push_stage_started();
+        match stage {
+            SddStage::CreateBranch => SddNext::GitAction(SddGitAction::CreateBranch {
+                name: self.branch.clone(),
+                base: self.base_branch.clone(),
+            }),
+            SddStage::Plan => SddNext::UserTurn(self.build_prompt("prompt.sdd_plan")),
+            SddStage::Develop => SddNext::UserTurn(self.build_prompt("prompt.sdd_execute")),
+            SddStage::Review => SddNext::Review(ReviewRequest {
+                target: ReviewTarget::BaseBranch {
+                    branch: self.base_branch.clone(),
+                },
+                user_facing_hint: None,
+            }),
+            SddStage::Fix => SddNext::UserTurn(std::mem::take(&mut self.fix_prompt)),
+            SddStage::Test => SddNext::RunTests(self.test_commands.clone()),
+            SddStage::Merge => SddNext::GitAction(SddGitAction::FinalizeMerge {
+                name: self.branch.clone(),
+                base: self.base_branch.clone(),
+                commit_message: self.commit_message.clone(),
+            }),
+            SddStage::Abandon => SddNext::GitAction(SddGitAction::AbandonBranch {
+                name: self.branch.clone(),
+                base: self.base_branch.clone(),
+            }),
+        }
+    }
+
+    fn finish(&mut self, outcome: SddOutcome, reason: Option<String>) -> SddNext {
+        self.progress.push(SddProgress::Finished {
+            branch: self.branch.clone(),
+            base_branch: self.base_branch.clone(),
+            outcome,
+            reason,
+        });
+        SddNext::Finished(outcome)
+    }
+
+    fn push_stage_started(&mut self) {
+        self.progress.push(SddProgress::StageStarted {
+            stage: self.stage,
+            iteration: self.iteration,
+        });
+    }
+
+    fn complete_stage(&mut self, success: bool, summary: Option<String>) {
+        self.progress.push(SddProgress::StageCompleted {
+            stage: self.stage,
+            iteration: self.iteration,
+            success,
+            summary,
+        });
+    }
+
+    fn build_prompt(&self, template_key: &'static str) -> String {
+        format!(
+            "{}\n\n{}\n\n{}\n{}\n{}\n{}",
+            tr(self.language, template_key).trim(),
+            tr(self.language, "prompt.sdd_headless").trim(),
+            tr(self.language, "chatwidget.sdd.requirement_label"),
+            self.spec.trim(),
+            tr(self.language, "chatwidget.sdd.branch_label"),
+            self.branch
+        )
+    }
+}
+
+/// Runs the acceptance commands in order and stops at the first one that fails.
+pub(crate) async fn run_test_commands(
+    commands: &[String],
+    cwd: &Path,
+    mut report: impl FnMut(SddProgress),
+) -> Option<SddTestFailure> {
+    for command in commands {
+        report(SddProgress::TestCommandStarted {
+            command: command.clone(),
+        });
+        let (exit_code, output) = run_shell_command(command, cwd).await;
+        report(SddProgress::TestCommandFinished {
+            command: command.clone(),
+            exit_code,
+            output: output.clone(),
+        });
+        if exit_code != Some(0) {
+            return Some(SddTestFailure {
+                command: command.clone(),
+                exit_code,
+                output,
+            });
+        }
+    }
+    None
+}
+
+async fn run_shell_command(command: &str, cwd: &Path) -> (Option<i32>, String) {
+    let mut cmd = if cfg!(windows) {
+        let mut cmd = tokio::process::Command::new("cmd");
+        cmd.arg("/C").arg(command);
+        cmd
+    } else {
+        let mut cmd = tokio::process::Command::new("sh");
+        cmd.arg("-c").arg(command);
+        cmd
+    };
+    let output = cmd
+        .current_dir(cwd)
+        .stdin(Stdio::null())
+        .kill_on_drop(true)
+        .output()
+        .await;
+    match output {
+        Ok(output) => {
+            let mut text = String::from_utf8_lossy(&output.stdout).into_owned();
+            text.push_str(&String::from_utf8_lossy(&output.stderr));
+            (output.status.code(), text)
+        }
+        Err(err) => (None, format!("failed to spawn `{command}`: {err}")),
+    }
+}
+
+/// A review blocks the merge when it judges the patch incorrect or reports a P0/P1 finding.
+fn review_blocks_merge(review: &ReviewOutputEvent) -> bool {
+    review
+        .overall_correctness
+        .trim()
+        .eq_ignore_ascii_case("patch is incorrect")
+        || review.findings.iter().any(|finding| finding.priority <= 1)
+}
+
+fn review_verdict(review: &ReviewOutputEvent) -> String {
+    let correctness = review.overall_correctness.trim();
+    let correctness = if correctness.is_empty() {
+        "no verdict"
+    } else {
+        correctness
+    };
+    format!("{correctness} ({} finding(s))", review.findings.len())
+}
+
+fn format_findings(review: &ReviewOutputEvent) -> String {
+    let mut out = String::new();
+    if !review.overall_explanation.trim().is_empty() {
+        out.push_str(review.overall_explanation.trim());
+        out.push('\n');
+    }
+    for finding in &review.findings {
+        let location = &finding.code_location;
+        out.push_str(&format!(
+            "- [P{}] {} ({}:{}-{})\n  {}\n",
+            finding.priority,
+            finding.title.trim(),
+            location.absolute_file_path.display(),
+            location.line_range.start,
+            location.line_range.end,
+            finding.body.trim().replace('\n', "\n  "),
+        ));
+    }
+    out.trim_end().to_string()
+}
+
+pub(crate) fn stage_label(stage: SddStage) -> &'static str {
+    match stage {
+        SddStage::CreateBranch => "create-branch",
+        SddStage::Plan => "plan",
+        SddStage::Develop => "develop",
+        SddStage::Review => "review",
+        SddStage::Fix => "fix",
+        SddStage::Test => "test",
+        SddStage::Merge => "merge",
+        SddStage::Abandon => "abandon",
+    }
+}
+
+/// Uses the first Markdown heading (or first non-empty line) as the spec title.
+fn spec_title(spec: &str) -> String {
+    let mut lines = spec.lines().map(str::trim).filter(|line| !line.is_empty());
+    let first = lines.clone().next().unwrap_or_default();
+    lines
+        .find(|line| line.starts_with('#'))
+        .unwrap_or(first)
+        .trim_start_matches('#')
+        .trim()
+        .to_string()
+}
+
+/// Same slug rules as the interactive `/sdd-develop` branch names.
+fn sdd_slug(description: &str) -> String {
+    let mut slug = String::new();
+    let mut prev_dash = false;
+    for ch in description.chars() {
+        if ch.is_ascii_alphanumeric() {
+            slug.push(ch.to_ascii_lowercase());
+            prev_dash = false;
+        } else if !prev_dash {
+            slug.push('-');
+            prev_dash = true;
+        }
+        if slug.len() >= 32 {
+            break;
+        }
+    }
+    let slug = slug.trim_matches('-').to_string();
+    if slug.is_empty() {
+        "task".to_string()
+    } else {
+        slug
+    }
+}
+
+fn tail_chars(text: &str, max_chars: usize) -> String {
+    let count = text.chars().count();
+    if count <= max_chars {
+        return text.to_string();
+    }
+    text.chars().skip(count - max_chars).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use codex_core::protocol::ReviewCodeLocation;
+    use codex_core::protocol::ReviewFinding;
+    use codex_core::protocol::ReviewLineRange;
+    use pretty_assertions::assert_eq;
+    use std::path::PathBuf;
+
+    fn pipeline(test_commands: Vec<String>, max_iterations: u32) -> SddPipeline {
+        SddPipeline::new(
+            Language::En,
+            "# Add login page\n\nUsers can sign in.".to_string(),
+            None,
+            "main".to_string(),
+            test_commands,
+            max_iterations,
+        )
+    }
+
+    fn ok() -> SddTurnOutcome {
+        SddTurnOutcome::default()
+    }
+
+    fn reviewed(priority: Option<i32>) -> SddTurnOutcome {
+        SddTurnOutcome {
+            failed: false,
+            review: Some(ReviewOutputEvent {
+                findings: priority
+                    .map(|priority| ReviewFinding {
+                        title: "Missing CSRF check".to_string(),
+                        body: "The form posts without a token.".to_string(),
+                        confidence_score: 0.9,
+                        priority,
+                        code_location: ReviewCodeLocation {
+                            absolute_file_path: PathBuf::from("/repo/src/login.rs"),
+                            line_range: ReviewLineRange { start: 10, end: 12 },
+                        },
+                    })
+                    .into_iter()
+                    .collect(),
+                overall_correctness: "patch is correct".to_string(),
+                ..Default::default()
+            }),
+        }
+    }
+
+    fn stage_of(next: &SddNext) -> &'static str {
+        match next {
+            SddNext::UserTurn(_) => "turn",
+            SddNext::Review(_) => "review",
+            SddNext::GitAction(SddGitAction::CreateBranch { .. }) => "create",
+            SddNext::GitAction(SddGitAction::FinalizeMerge { .. }) => "merge",
+            SddNext::GitAction(SddGitAction::AbandonBranch { .. }) => "abandon",
+            SddNext::GitAction(SddGitAction::SwitchBranch { .. }) => "switch",
+            SddNext::RunTests(_) => "tests",
+            SddNext::Finished(_) => "finished",
+        }
+    }
+
+    #[test]
+    fn approved_review_and_passing_tests_merge_the_branch() {
+        let mut pipeline = pipeline(vec!["cargo test".to_string()], 3);
+        assert_eq!(pipeline.branch(), "sdd/add-login-page");
+
+        let mut steps = vec![stage_of(&pipeline.start())];
+        for outcome in [ok(), ok(), ok(), reviewed(Some(2))] {
+            steps.push(stage_of(&pipeline.on_turn_finished(outcome)));
+        }
+        steps.push(stage_of(&pipeline.on_tests_finished(None)));
+        let finished = pipeline.on_turn_finished(ok());
+
+        assert_eq!(
+            steps,
+            vec!["create", "turn", "turn", "review", "tests", "merge"]
+        );
+        assert_eq!(finished, SddNext::Finished(SddOutcome::Merged));
+        assert_eq!(
+            pipeline.take_progress().last(),
+            Some(&SddProgress::Finished {
+                branch: "sdd/add-login-page".to_string(),
+                base_branch: "main".to_string(),
+                outcome: SddOutcome::Merged,
+                reason: None,
+            })
+        );
+    }
+
+    #[test]
+    fn blocking_findings_are_fixed_until_iterations_run_out() {
+        let mut pipeline = pipeline(Vec::new(), 2);
+        pipeline.start();
+        pipeline.on_turn_finished(ok());
+        pipeline.on_turn_finished(ok());
+        pipeline.on_turn_finished(ok());
+
+        let SddNext::UserTurn(fix_prompt) = pipeline.on_turn_finished(reviewed(Some(1))) else {
+            panic!("blocking review should request a fix turn");
+        };
+        assert!(fix_prompt.contains("- [P1] Missing CSRF check (/repo/src/login.rs:10-12)"));
+
+        assert_eq!(stage_of(&pipeline.on_turn_finished(ok())), "review");
+        assert_eq!(
+            stage_of(&pipeline.on_turn_finished(reviewed(Some(0)))),
+            "abandon"
+        );
+        assert_eq!(
+            pipeline.on_turn_finished(ok()),
+            SddNext::Finished(SddOutcome::Abandoned)
+        );
+    }
+
+    #[test]
+    fn failing_test_command_requests_a_fix_with_output_tail() {
+        let mut pipeline = pipeline(vec!["cargo test".to_string()], 3);
+        pipeline.start();
+        for outcome in [ok(), ok(), ok(), reviewed(None)] {
+            pipeline.on_turn_finished(outcome);
+        }
+
+        let SddNext::UserTurn(prompt) = pipeline.on_tests_finished(Some(SddTestFailure {
+            command: "cargo test".to_string(),
+            exit_code: Some(101),
+            output: "test login ... FAILED".to_string(),
+        })) else {
+            panic!("failing tests should request a fix turn");
+        };
+        assert!(prompt.contains("`cargo test` failed (exit code 101)"));
+        assert!(prompt.contains("test login ... FAILED"));
+    }
+
+    #[test]
+    fn failed_branch_creation_stops_without_cleanup() {
+        let mut pipeline = pipeline(Vec::new(), 3);
+        pipeline.start();
+
+        assert_eq!(
+            pipeline.on_turn_finished(SddTurnOutcome {
+                failed: true,
+                review: None,
+            }),
+            SddNext::Finished(SddOutcome::Failed)
+        );
+    }
+}
@@ -0,0 +1,342 @@
+use std::fmt::Write as _;
+use std::fs;
+use std::path::Path;
+use std::path::PathBuf;
+
+use anyhow::Context;
+use anyhow::Result;
+use anyhow::bail;
+use clap::Parser;
+use serde::Serialize;
+
+use crate::Decision;
+use crate::Policy;
+use crate::PolicyParser;
+use crate::RuleMatch;
+use crate::rule::MatchContext;
+
+/// Arguments for tracing how one or more execpolicy files decide a command.
+#[derive(Debug, Parser, Clone)]
+pub struct ExecPolicyExplainCommand {
+    /// Paths to execpolicy rule files to evaluate (repeatable). `codex execpolicy explain`
+    /// defaults to the rules Codex itself loads.
+    #[arg(short = 'r', long = "rules", value_name = "PATH")]
+    pub rules: Vec<PathBuf>,
+
+    /// Print the trace as JSON instead of human-readable text.
+    #[arg(long)]
+    pub json: bool,
+
+    /// Pretty-print the JSON output (implies `--json`).
+    #[arg(long)]
+    pub pretty: bool,
+
+    /// Directory the command would run in; relative path arguments resolve against it.
+    #[arg(long, value_name = "DIR")]
+    pub cwd: Option<PathBuf>,
+
+    /// Workspace root that `path_rule` arguments must stay within.
+    #[arg(long, value_name = "DIR")]
+    pub workspace_root: Option<PathBuf>,
+
+    /// Command tokens to explain.
+    #[arg(
+        value_name = "COMMAND",
+        required = true,
+        trailing_var_arg = true,
+        allow_hyphen_values = true
+    )]
+    pub command: Vec<String>,
+}
+
+impl ExecPolicyExplainCommand {
+    /// Load each policy file, trace the command through every candidate rule, and print the result.
+    pub fn run(&self) -> Result<()> {
+        if self.rules.is_empty() {
+            bail!("no rule files given; pass one or more --rules PATH");
+        }
+        let policies = self.load_rules()?;
+        let explanation = explain_command(&policies, &self.command, &self.match_context());
+        self.print(&explanation)
+    }
+
+    /// Loads the files passed with `--rules`, each tagged with its path.
+    pub fn load_rules(&self) -> Result<Vec<(String, Policy)>> {
+        self.rules
+            .iter()
+            .map(|path| Ok((path.to_string_lossy().to_string(), load_policy(path)?)))
+            .collect()
+    }
+
+    pub fn match_context(&self) -> MatchContext<'_> {
+        MatchContext {
+            cwd: self.cwd.as_deref(),
+            workspace_root: self.workspace_root.as_deref(),
+        }
+    }
+
+    /// Prints `explanation` in the format selected by `--json` / `--pretty`.
+    pub fn print(&self, explanation: &Explanation) -> Result<()> {
+        let output = if self.pretty {
+            serde_json::to_string_pretty(explanation)?
+        } else if self.json {
+            serde_json::to_string(explanation)?
+        } else {
+            format_explanation_text(explanation)
+        };
+        println!("{output}");
+
+        Ok(())
+    }
+
+    /// Prints the explanations of the commands a shell script was split into.
+    /// A single explanation prints exactly like [`Self::print`]; several print
+    /// as a JSON array, or as text followed by the combined decision.
+    pub fn print_all(&self, explanations: &[Explanation]) -> Result<()> {
+        if let [explanation] = explanations {
+            return self.print(explanation);
+        }
+        let output = if self.pretty {
+            serde_json::to_string_pretty(explanations)?
+        } else if self.json {
+            serde_json::to_string(explanations)?
+        } else {
+            let mut out = format!(
+                "The shell script runs {} commands; the strictest decision applies.",
+                explanations.len()
+            );
+            for explanation in explanations {
+                let _ = write!(out, "\n\n{}", format_explanation_text(explanation));
+            }
+            let decision = explanations
+                .iter()
+                .filter_map(Explanation::final_decision)
+                .max();
+            let _ = write!(
+                out,
+                "\n\nOverall decision: {}",
+                decision.map_or("(none)", Decision::as_str)
+            );
+            out
+        };
+        println!("{output}");
+
+        Ok(())
+    }
+}
+
+/// Full trace of how a command was evaluated.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Explanation {
+    pub command: Vec<String>,
+    /// Every policy file that was loaded, in evaluation order.
+    pub policy_files: Vec<PolicyFileTrace>,
+    /// Every rule keyed by the command's program, in evaluation order.
+    pub candidates: Vec<RuleTrace>,
+    /// Whether no rule matched, so `Policy::check` would defer to its heuristics fallback.
+    pub heuristics_fallback: bool,
+    /// The fallback heuristic that decided, when the caller supplied one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub heuristic: Option<HeuristicTrace>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub decision: Option<Decision>,
+    /// How the matching rules' decisions were combined into `decision`.
+    pub decision_reason: String,
+}
+
+impl Explanation {
+    /// The rules' decision, or the fallback heuristic's when no rule matched.
+    pub fn final_decision(&self) -> Option<Decision> {
+        self.decision
+            .or_else(|| self.heuristic.as_ref().map(|heuristic| heuristic.decision))
+    }
+
+    /// Records which fallback heuristic decides the command when no rule matched.
+    pub fn with_heuristic(mut self, heuristic: impl FnOnce(&[String]) -> HeuristicTrace) -> Self {
+        if self.heuristics_fallback {
+            self.heuristic = Some(heuristic(&self.command));
+        }
+        self
+    }
+}
+
+/// A fallback heuristic's decision for a command no rule matched.
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HeuristicTrace {
+    /// Identifier of the heuristic, e.g. `known_safe_command`.
+    pub name: String,
+    pub decision: Decision,
+    pub reason: String,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PolicyFileTrace {
+    pub path: String,
+    pub rule_count: usize,
+}
+
+#[derive(Clone, Debug, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RuleTrace {
+    /// Policy file that defined the rule.
+    pub source: String,
+    /// The rule rendered in policy-file syntax.
+    pub rule: String,
+    pub matched: bool,
+    pub reason: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rule_match: Option<RuleMatch>,
+}
+
+/// Traces `cmd` through `policies`, each tagged with the file it was loaded
+/// from. Policies are evaluated in order, just as if they had been merged.
+pub fn explain_command(
+    policies: &[(String, Policy)],
+    cmd: &[String],
+    context: &MatchContext<'_>,
+) -> Explanation {
+    let policy_files = policies
+        .iter()
+        .map(|(path, policy)| PolicyFileTrace {
+            path: path.clone(),
+            rule_count: policy
+                .rules()
+                .iter_all()
+                .map(|(_, rules)| rules.len())
+                .sum(),
+        })
+        .collect();
+
+    let candidates: Vec<RuleTrace> = match cmd.first() {
+        Some(program) => policies
+            .iter()
+            .flat_map(|(path, policy)| {
+                policy
+                    .rules()
+                    .get_vec(program)
+                    .into_iter()
+                    .flatten()
+                    .map(move |rule| {
+                        let explanation = rule.explain(cmd, context);
+                        RuleTrace {
+                            source: path.clone(),
+                            rule: rule.render(),
+                            matched: explanation.rule_match.is_some(),
+                            reason: explanation.reason,
+                            rule_match: explanation.rule_match,
+                        }
+                    })
+            })
+            .collect(),
+        None => Vec::new(),
+    };
+
+    let decisions: Vec<Decision> = candidates
+        .iter()
+        .filter_map(|candidate| candidate.rule_match.as_ref().map(RuleMatch::decision))
+        .collect();
+    let decision = decisions.iter().copied().max();
+    let decision_reason = match decision {
+        None if candidates.is_empty() => format!(
+            "no rules are defined for program `{}`; the heuristics fallback decides",
+            cmd.first().map(String::as_str).unwrap_or_default()
+        ),
+        None => "no candidate rule matched; the heuristics fallback decides".to_string(),
+        Some(decision) => {
+            let deciding = decisions.iter().filter(|d| **d == decision).count();
+            format!(
+                "strictest of {} matching rule(s) (forbidden > prompt > allow); {deciding} rule(s) decided {}",
+                decisions.len(),
+                decision.as_str()
+            )
+        }
+    };
+
+    Explanation {
+        command: cmd.to_vec(),
+        policy_files,
+        candidates,
+        heuristics_fallback: decision.is_none(),
+        heuristic: None,
+        decision,
+        decision_reason,
+    }
+}
+
+/// Renders an [`Explanation`] for humans.
+pub fn format_explanation_text(explanation: &Explanation) -> String {
+    let command = shlex::try_join(explanation.command.iter().map(String::as_str))
+        .unwrap_or_else(|_| explanation.command.join(" "));
+    let mut out = String::new();
+    let _ = writeln!(out, "Command: {command}");
+
+    let _ = writeln!(out, "\nPolicy files:");
+    for (index, file) in explanation.policy_files.iter().enumerate() {
+        let _ = writeln!(
+            out,
+            "  {}. {} ({} rules)",
+            index + 1,
+            file.path,
+            file.rule_count
+        );
+    }
+
+    let program = explanation
+        .command
+        .first()
+        .map(String::as_str)
+        .unwrap_or_default();
+    let _ = writeln!(out, "\nCandidate rules for `{program}`:");
+    if explanation.candidates.is_empty() {
+        let _ = writeln!(out, "  (none)");
+    }
+    for candidate in &explanation.candidates {
+        let marker = if candidate.matched { "match" } else { "skip " };
+        let _ = writeln!(out, "  [{marker}] {}: {}", candidate.source, candidate.rule);
+        let _ = writeln!(out, "          {}", candidate.reason);
+    }
+
+    let _ = writeln!(out);
+    match explanation.decision {
+        Some(decision) => {
+            let _ = writeln!(out, "Decision: {}", decision.as_str());
+        }
+        None => {
+            let _ = writeln!(out, "Decision: (none)");
+        }
+    }
+    let _ = writeln!(out, "  {}", explanation.decision_reason);
+    let _ = write!(
+        out,
+        "Heuristics fallback: {}",
+        if explanation.heuristics_fallback {
+            "used"
+        } else {
+            "not used"
+        }
+    );
+    if let Some(heuristic) = &explanation.heuristic {
+        let _ = write!(
+            out,
+            "\n  {} decided {}: {}",
+            heuristic.name,
+            heuristic.decision.as_str(),
+            heuristic.reason
+        );
+    }
+    out
+}
+
+fn load_policy(policy_path: &Path) -> Result<Policy> {
+    let policy_file_contents = fs::read_to_string(policy_path)
+        .with_context(|| format!("failed to read policy at {}", policy_path.display()))?;
+    let policy_identifier = policy_path.to_string_lossy().to_string();
+    let mut parser = PolicyParser::new();
+    parser
+        .parse(&policy_identifier, &policy_file_contents)
+        .with_context(|| format!("failed to parse policy at {}", policy_path.display()))?;
+    Ok(parser.build())
+}
@@ -2,6 +2,7 @@ pub mod amend;
 pub mod decision;
 pub mod error;
 pub mod execpolicycheck;
+pub mod execpolicyexplain;
 pub mod parser;
 pub mod policy;
 pub mod rule;
@@ -15,9 +16,12 @@ pub use error::Result;
 pub use error::TextPosition;
 pub use error::TextRange;
 pub use execpolicycheck::ExecPolicyCheckCommand;
+pub use execpolicyexplain::ExecPolicyExplainCommand;
 pub use parser::PolicyParser;
 pub use policy::Evaluation;
 pub use policy::Policy;
+pub use rule::MatchContext;
 pub use rule::Rule;
+pub use rule::RuleExplanation;
 pub use rule::RuleMatch;
 pub use rule::RuleRef;
@@ -1,6 +1,7 @@
 use anyhow::Result;
 use clap::Parser;
 use codex_execpolicy::execpolicycheck::ExecPolicyCheckCommand;
+use codex_execpolicy::execpolicyexplain::ExecPolicyExplainCommand;
 
 /// CLI for evaluating exec policies
 #[derive(Parser)]
@@ -8,11 +9,14 @@ use codex_execpolicy::execpolicycheck::ExecPolicyCheckCommand;
 enum Cli {
     /// Evaluate a command against a policy.
     Check(ExecPolicyCheckCommand),
+    /// Trace which rules matched a command and why.
+    Explain(ExecPolicyExplainCommand),
 }
 
 fn main() -> Result<()> {
     let cli = Cli::parse();
     match cli {
         Cli::Check(cmd) => cmd.run(),
+        Cli::Explain(cmd) => cmd.run(),
     }
 }
@@ -1,4 +1,5 @@
 use multimap::MultiMap;
+use regex_lite::Regex;
 use shlex;
 use starlark::any::ProvidesStaticType;
 use starlark::environment::GlobalsBuilder;
@@ -18,9 +19,12 @@ use std::sync::Arc;
 use crate::decision::Decision;
 use crate::error::Error;
 use crate::error::Result;
+use crate::rule::FlagRule;
+use crate::rule::PathRule;
 use crate::rule::PatternToken;
 use crate::rule::PrefixPattern;
 use crate::rule::PrefixRule;
+use crate::rule::RegexRule;
 use crate::rule::RuleRef;
 use crate::rule::validate_match_examples;
 use crate::rule::validate_not_match_examples;
@@ -205,6 +209,59 @@ fn policy_builder<'v, 'a>(eval: &Evaluator<'v, 'a, '_>) -> RefMut<'a, PolicyBuil
         .borrow_mut()
 }
 
+fn parse_justification(justification: Option<&str>) -> Result<Option<String>> {
+    match justification {
+        Some(raw) if raw.trim().is_empty() => Err(Error::InvalidRule(
+            "justification cannot be empty".to_string(),
+        )),
+        Some(raw) => Ok(Some(raw.to_string())),
+        None => Ok(None),
+    }
+}
+
+/// Expands `pattern` into one rule per alternative of its first token, checks
+/// the `match`/`not_match` examples against them, and registers the rules.
+fn add_rules<'v>(
+    pattern: UnpackList<Value<'v>>,
+    r#match: Option<UnpackList<Value<'v>>>,
+    not_match: Option<UnpackList<Value<'v>>>,
+    eval: &mut Evaluator<'v, '_, '_>,
+    make_rule: impl Fn(PrefixPattern) -> RuleRef,
+) -> Result<()> {
+    let pattern_tokens = parse_pattern(pattern)?;
+
+    let matches: Vec<Vec<String>> = r#match.map(parse_examples).transpose()?.unwrap_or_default();
+    let not_matches: Vec<Vec<String>> = not_match
+        .map(parse_examples)
+        .transpose()?
+        .unwrap_or_default();
+
+    let mut builder = policy_builder(eval);
+
+    let (first_token, remaining_tokens) = pattern_tokens
+        .split_first()
+        .ok_or_else(|| Error::InvalidPattern("pattern cannot be empty".to_string()))?;
+
+    let rest: Arc<[PatternToken]> = remaining_tokens.to_vec().into();
+
+    let rules: Vec<RuleRef> = first_token
+        .alternatives()
+        .iter()
+        .map(|head| {
+            make_rule(PrefixPattern {
+                first: Arc::from(head.as_str()),
+                rest: rest.clone(),
+            })
+        })
+        .collect();
+
+    validate_not_match_examples(&rules, &not_matches)?;
+    validate_match_examples(&rules, &matches)?;
+
+    rules.into_iter().for_each(|rule| builder.add_rule(rule));
+    Ok(())
+}
+
 #[starlark_module]
 fn policy_builtins(builder: &mut GlobalsBuilder) {
     fn prefix_rule<'v>(
@@ -219,51 +276,103 @@ fn policy_builtins(builder: &mut GlobalsBuilder) {
             Some(raw) => Decision::parse(raw)?,
             None => Decision::Allow,
         };
+        let justification = parse_justification(justification)?;
 
-        let justification = match justification {
-            Some(raw) if raw.trim().is_empty() => {
-                return Err(Error::InvalidRule("justification cannot be empty".to_string()).into());
-            }
-            Some(raw) => Some(raw.to_string()),
-            None => None,
-        };
-
-        let pattern_tokens = parse_pattern(pattern)?;
+        add_rules(pattern, r#match, not_match, eval, |pattern| {
+            Arc::new(PrefixRule {
+                pattern,
+                decision,
+                justification: justification.clone(),
+            }) as RuleRef
+        })?;
+        Ok(NoneType)
+    }
 
-        let matches: Vec<Vec<String>> =
-            r#match.map(parse_examples).transpose()?.unwrap_or_default();
-        let not_matches: Vec<Vec<String>> = not_match
-            .map(parse_examples)
-            .transpose()?
-            .unwrap_or_default();
+    fn flag_rule<'v>(
+        pattern: UnpackList<Value<'v>>,
+        flags: UnpackList<&'v str>,
+        decision: &'v str,
+        r#match: Option<UnpackList<Value<'v>>>,
+        not_match: Option<UnpackList<Value<'v>>>,
+        justification: Option<&'v str>,
+        eval: &mut Evaluator<'v, '_, '_>,
+    ) -> anyhow::Result<NoneType> {
+        let decision = Decision::parse(decision)?;
+        let justification = parse_justification(justification)?;
+        let flags: Vec<String> = flags.items.into_iter().map(str::to_string).collect();
+        if flags.is_empty() {
+            return Err(Error::InvalidRule("flags cannot be empty".to_string()).into());
+        }
+        if let Some(flag) = flags.iter().find(|flag| !flag.starts_with('-')) {
+            return Err(
+                Error::InvalidRule(format!("flag must start with `-` (got {flag:?})")).into(),
+            );
+        }
 
-        let mut builder = policy_builder(eval);
+        add_rules(pattern, r#match, not_match, eval, |pattern| {
+            Arc::new(FlagRule {
+                pattern,
+                flags: flags.clone(),
+                decision,
+                justification: justification.clone(),
+            }) as RuleRef
+        })?;
+        Ok(NoneType)
+    }
 
-        let (first_token, remaining_tokens) = pattern_tokens
-            .split_first()
-            .ok_or_else(|| Error::InvalidPattern("pattern cannot be empty".to_string()))?;
+    fn path_rule<'v>(
+        pattern: UnpackList<Value<'v>>,
+        decision: &'v str,
+        r#match: Option<UnpackList<Value<'v>>>,
+        not_match: Option<UnpackList<Value<'v>>>,
+        justification: Option<&'v str>,
+        eval: &mut Evaluator<'v, '_, '_>,
+    ) -> anyhow::Result<NoneType> {
+        let decision = Decision::parse(decision)?;
+        let justification = parse_justification(justification)?;
 
-        let rest: Arc<[PatternToken]> = remaining_tokens.to_vec().into();
+        add_rules(pattern, r#match, not_match, eval, |pattern| {
+            Arc::new(PathRule {
+                pattern,
+                decision,
+                justification: justification.clone(),
+            }) as RuleRef
+        })?;
+        Ok(NoneType)
+    }
 
-        let rules: Vec<RuleRef> = first_token
-            .alternatives()
-            .iter()
-            .map(|head| {
-                Arc::new(PrefixRule {
-                    pattern: PrefixPattern {
-                        first: Arc::from(head.as_str()),
-                        rest: rest.clone(),
-                    },
-                    decision,
-                    justification: justification.clone(),
-                }) as RuleRef
+    #[expect(clippy::too_many_arguments)]
+    fn regex_rule<'v>(
+        pattern: UnpackList<Value<'v>>,
+        regex: &'v str,
+        decision: &'v str,
+        index: Option<i32>,
+        r#match: Option<UnpackList<Value<'v>>>,
+        not_match: Option<UnpackList<Value<'v>>>,
+        justification: Option<&'v str>,
+        eval: &mut Evaluator<'v, '_, '_>,
+    ) -> anyhow::Result<NoneType> {
+        let decision = Decision::parse(decision)?;
+        let justification = parse_justification(justification)?;
+        let regex = Regex::new(regex)
+            .map_err(|err| Error::InvalidRule(format!("invalid regex {regex:?}: {err}")))?;
+        let index = index
+            .map(|index| {
+                usize::try_from(index).map_err(|_| {
+                    Error::InvalidRule(format!("index must be non-negative (got {index})"))
+                })
             })
-            .collect();
-
-        validate_not_match_examples(&rules, &not_matches)?;
-        validate_match_examples(&rules, &matches)?;
+            .transpose()?;
 
-        rules.into_iter().for_each(|rule| builder.add_rule(rule));
+        add_rules(pattern, r#match, not_match, eval, |pattern| {
+            Arc::new(RegexRule {
+                pattern,
+                regex: regex.clone(),
+                index,
+                decision,
+                justification: justification.clone(),
+            }) as RuleRef
+        })?;
         Ok(NoneType)
     }
 }
@@ -1,6 +1,7 @@
 use crate::decision::Decision;
 use crate::error::Error;
 use crate::error::Result;
+use crate::rule::MatchContext;
 use crate::rule::PatternToken;
 use crate::rule::PrefixPattern;
 use crate::rule::PrefixRule;
@@ -81,7 +82,22 @@ impl Policy {
     where
         F: Fn(&[String]) -> Decision,
     {
-        let matched_rules = self.matches_for_command(cmd, Some(heuristics_fallback));
+        self.check_with_context(cmd, &MatchContext::default(), heuristics_fallback)
+    }
+
+    /// Like [`Policy::check`], letting context-aware rules such as
+    /// `path_rule` see where the command runs.
+    pub fn check_with_context<F>(
+        &self,
+        cmd: &[String],
+        context: &MatchContext<'_>,
+        heuristics_fallback: &F,
+    ) -> Evaluation
+    where
+        F: Fn(&[String]) -> Decision,
+    {
+        let matched_rules =
+            self.matches_for_command_with_context(cmd, context, Some(heuristics_fallback));
         Evaluation::from_matches(matched_rules)
     }
 
@@ -91,6 +107,21 @@ impl Policy {
         commands: Commands,
         heuristics_fallback: &F,
     ) -> Evaluation
+    where
+        Commands: IntoIterator,
+        Commands::Item: AsRef<[String]>,
+        F: Fn(&[String]) -> Decision,
+    {
+        self.check_multiple_with_context(commands, &MatchContext::default(), heuristics_fallback)
+    }
+
+    /// Like [`Policy::check_multiple`], evaluating every command in `context`.
+    pub fn check_multiple_with_context<Commands, F>(
+        &self,
+        commands: Commands,
+        context: &MatchContext<'_>,
+        heuristics_fallback: &F,
+    ) -> Evaluation
     where
         Commands: IntoIterator,
         Commands::Item: AsRef<[String]>,
@@ -99,7 +130,11 @@ impl Policy {
         let matched_rules: Vec<RuleMatch> = commands
             .into_iter()
             .flat_map(|command| {
-                self.matches_for_command(command.as_ref(), Some(heuristics_fallback))
+                self.matches_for_command_with_context(
+                    command.as_ref(),
+                    context,
+                    Some(heuristics_fallback),
+                )
             })
             .collect();
 
@@ -117,12 +152,27 @@ impl Policy {
         &self,
         cmd: &[String],
         heuristics_fallback: HeuristicsFallback<'_>,
+    ) -> Vec<RuleMatch> {
+        self.matches_for_command_with_context(cmd, &MatchContext::default(), heuristics_fallback)
+    }
+
+    /// Like [`Policy::matches_for_command`], evaluating rules in `context`.
+    pub fn matches_for_command_with_context(
+        &self,
+        cmd: &[String],
+        context: &MatchContext<'_>,
+        heuristics_fallback: HeuristicsFallback<'_>,
     ) -> Vec<RuleMatch> {
         let matched_rules: Vec<RuleMatch> = match cmd.first() {
             Some(first) => self
                 .rules_by_program
                 .get_vec(first)
-                .map(|rules| rules.iter().filter_map(|rule| rule.matches(cmd)).collect())
+                .map(|rules| {
+                    rules
+                        .iter()
+                        .filter_map(|rule| rule.matches_with_context(cmd, context))
+                        .collect()
+                })
                 .unwrap_or_default(),
             None => Vec::new(),
         };
@@ -157,9 +207,7 @@ pub struct Evaluation {
 
 impl Evaluation {
     pub fn is_match(&self) -> bool {
-        self.matched_rules
-            .iter()
-            .any(|rule_match| !matches!(rule_match, RuleMatch::HeuristicsRuleMatch { .. }))
+        self.matched_rules.iter().any(RuleMatch::is_policy_match)
     }
 
     /// Caller is responsible for ensuring that `matched_rules` is non-empty.
@@ -1,11 +1,15 @@
 use crate::decision::Decision;
 use crate::error::Error;
 use crate::error::Result;
+use regex_lite::Regex;
 use serde::Deserialize;
 use serde::Serialize;
 use shlex::try_join;
 use std::any::Any;
 use std::fmt::Debug;
+use std::path::Component;
+use std::path::Path;
+use std::path::PathBuf;
 use std::sync::Arc;
 
 /// Matches a single command token, either a fixed string or one of several allowed alternatives.
@@ -29,6 +33,27 @@ impl PatternToken {
             Self::Alts(alternatives) => alternatives,
         }
     }
+
+    fn describe(&self) -> String {
+        match self {
+            Self::Single(expected) => format!("`{expected}`"),
+            Self::Alts(alternatives) => format!(
+                "one of {}",
+                alternatives
+                    .iter()
+                    .map(|alt| format!("`{alt}`"))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+        }
+    }
+
+    fn to_json(&self) -> serde_json::Value {
+        match self {
+            Self::Single(expected) => serde_json::Value::from(expected.as_str()),
+            Self::Alts(alternatives) => serde_json::Value::from(alternatives.clone()),
+        }
+    }
 }
 
 /// Prefix matcher for commands with support for alternative match tokens.
@@ -54,6 +79,67 @@ impl PrefixPattern {
 
         Some(cmd[..pattern_length].to_vec())
     }
+
+    /// Describes the first token of `cmd` that keeps it from matching this
+    /// pattern. Only meaningful when [`PrefixPattern::matches_prefix`] fails.
+    pub fn mismatch_reason(&self, cmd: &[String]) -> String {
+        let Some(program) = cmd.first() else {
+            return "command is empty".to_string();
+        };
+        if program != self.first.as_ref() {
+            return format!("program `{program}` is not `{}`", self.first);
+        }
+        for (index, pattern_token) in self.rest.iter().enumerate() {
+            let index = index + 1;
+            match cmd.get(index) {
+                None => {
+                    return format!(
+                        "command ends before token {index}, expected {}",
+                        pattern_token.describe()
+                    );
+                }
+                Some(token) if !pattern_token.matches(token) => {
+                    return format!(
+                        "token {index} is `{token}`, expected {}",
+                        pattern_token.describe()
+                    );
+                }
+                Some(_) => {}
+            }
+        }
+        "prefix matched".to_string()
+    }
+
+    fn to_json(&self) -> serde_json::Value {
+        std::iter::once(serde_json::Value::from(self.first.as_ref()))
+            .chain(self.rest.iter().map(PatternToken::to_json))
+            .collect()
+    }
+}
+
+/// Renders a rule as the Starlark call that would define it; `args` are
+/// already-rendered keyword arguments placed between `pattern` and `decision`.
+fn render_rule_call(
+    name: &str,
+    pattern: &PrefixPattern,
+    args: &[String],
+    decision: Decision,
+    justification: Option<&str>,
+) -> String {
+    let mut rendered = vec![format!("pattern={}", pattern.to_json())];
+    rendered.extend(args.iter().cloned());
+    rendered.push(format!("decision=\"{}\"", decision.as_str()));
+    if let Some(justification) = justification {
+        rendered.push(format!(
+            "justification={}",
+            serde_json::Value::from(justification)
+        ));
+    }
+    format!("{name}({})", rendered.join(", "))
+}
+
+fn render_prefix(matched_prefix: &[String]) -> String {
+    try_join(matched_prefix.iter().map(String::as_str)).unwrap_or_else(|_| matched_prefix.join(" "))
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
@@ -70,6 +156,36 @@ pub enum RuleMatch {
         #[serde(skip_serializing_if = "Option::is_none")]
         justification: Option<String>,
     },
+    /// A `flag_rule` matched because `matched_token` appeared after the prefix.
+    FlagRuleMatch {
+        #[serde(rename = "matchedPrefix")]
+        matched_prefix: Vec<String>,
+        #[serde(rename = "matchedToken")]
+        matched_token: String,
+        decision: Decision,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        justification: Option<String>,
+    },
+    /// A `path_rule` matched because `matched_token` resolves outside the workspace root.
+    PathRuleMatch {
+        #[serde(rename = "matchedPrefix")]
+        matched_prefix: Vec<String>,
+        #[serde(rename = "matchedToken")]
+        matched_token: String,
+        decision: Decision,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        justification: Option<String>,
+    },
+    /// A `regex_rule` matched because `matched_token` matched its regex.
+    RegexRuleMatch {
+        #[serde(rename = "matchedPrefix")]
+        matched_prefix: Vec<String>,
+        #[serde(rename = "matchedToken")]
+        matched_token: String,
+        decision: Decision,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        justification: Option<String>,
+    },
     HeuristicsRuleMatch {
         command: Vec<String>,
         decision: Decision,
@@ -79,12 +195,59 @@ pub enum RuleMatch {
 impl RuleMatch {
     pub fn decision(&self) -> Decision {
         match self {
-            Self::PrefixRuleMatch { decision, .. } => *decision,
-            Self::HeuristicsRuleMatch { decision, .. } => *decision,
+            Self::PrefixRuleMatch { decision, .. }
+            | Self::FlagRuleMatch { decision, .. }
+            | Self::PathRuleMatch { decision, .. }
+            | Self::RegexRuleMatch { decision, .. }
+            | Self::HeuristicsRuleMatch { decision, .. } => *decision,
+        }
+    }
+
+    /// Whether this match came from a policy rule rather than the heuristics fallback.
+    pub fn is_policy_match(&self) -> bool {
+        !matches!(self, Self::HeuristicsRuleMatch { .. })
+    }
+
+    pub fn matched_prefix(&self) -> Option<&[String]> {
+        match self {
+            Self::PrefixRuleMatch { matched_prefix, .. }
+            | Self::FlagRuleMatch { matched_prefix, .. }
+            | Self::PathRuleMatch { matched_prefix, .. }
+            | Self::RegexRuleMatch { matched_prefix, .. } => Some(matched_prefix),
+            Self::HeuristicsRuleMatch { .. } => None,
+        }
+    }
+
+    /// The argv token that triggered a flag, path, or regex rule.
+    pub fn matched_token(&self) -> Option<&str> {
+        match self {
+            Self::FlagRuleMatch { matched_token, .. }
+            | Self::PathRuleMatch { matched_token, .. }
+            | Self::RegexRuleMatch { matched_token, .. } => Some(matched_token),
+            Self::PrefixRuleMatch { .. } | Self::HeuristicsRuleMatch { .. } => None,
+        }
+    }
+
+    pub fn justification(&self) -> Option<&str> {
+        match self {
+            Self::PrefixRuleMatch { justification, .. }
+            | Self::FlagRuleMatch { justification, .. }
+            | Self::PathRuleMatch { justification, .. }
+            | Self::RegexRuleMatch { justification, .. } => justification.as_deref(),
+            Self::HeuristicsRuleMatch { .. } => None,
         }
     }
 }
 
+/// Runtime facts that rules may consult in addition to argv.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct MatchContext<'a> {
+    /// Directory the command runs in; relative path arguments resolve against it.
+    pub cwd: Option<&'a Path>,
+    /// Root that `path_rule` arguments must stay within.
+    pub workspace_root: Option<&'a Path>,
+}
+
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct PrefixRule {
     pub pattern: PrefixPattern,
@@ -97,7 +260,59 @@ pub trait Rule: Any + Debug + Send + Sync {
 
     fn matches(&self, cmd: &[String]) -> Option<RuleMatch>;
 
+    /// Like [`Rule::matches`], with access to the command's runtime context.
+    /// Rules that only inspect argv can rely on the default.
+    fn matches_with_context(
+        &self,
+        cmd: &[String],
+        _context: &MatchContext<'_>,
+    ) -> Option<RuleMatch> {
+        self.matches(cmd)
+    }
+
     fn as_any(&self) -> &dyn Any;
+
+    /// Renders the rule in policy-file syntax for diagnostics.
+    fn render(&self) -> String {
+        format!("{self:?}")
+    }
+
+    /// Explains why the rule did or did not match `cmd`.
+    fn explain(&self, cmd: &[String], context: &MatchContext<'_>) -> RuleExplanation {
+        match self.matches_with_context(cmd, context) {
+            Some(rule_match) => RuleExplanation {
+                rule_match: Some(rule_match),
+                reason: "rule matched".to_string(),
+            },
+            None => RuleExplanation {
+                rule_match: None,
+                reason: "rule did not match".to_string(),
+            },
+        }
+    }
+}
+
+/// Outcome of evaluating a single rule, with a human-readable reason.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RuleExplanation {
+    pub rule_match: Option<RuleMatch>,
+    pub reason: String,
+}
+
+impl RuleExplanation {
+    fn not_matched(reason: String) -> Self {
+        Self {
+            rule_match: None,
+            reason,
+        }
+    }
+
+    fn matched(rule_match: RuleMatch, reason: String) -> Self {
+        Self {
+            rule_match: Some(rule_match),
+            reason,
+        }
+    }
 }
 
 pub type RuleRef = Arc<dyn Rule>;
@@ -120,6 +335,316 @@ impl Rule for PrefixRule {
     fn as_any(&self) -> &dyn Any {
         self
     }
+
+    fn render(&self) -> String {
+        render_rule_call(
+            "prefix_rule",
+            &self.pattern,
+            &[],
+            self.decision,
+            self.justification.as_deref(),
+        )
+    }
+
+    fn explain(&self, cmd: &[String], _context: &MatchContext<'_>) -> RuleExplanation {
+        match self.matches(cmd) {
+            Some(rule_match) => {
+                let prefix = render_prefix(rule_match.matched_prefix().unwrap_or_default());
+                RuleExplanation::matched(rule_match, format!("prefix `{prefix}` matched"))
+            }
+            None => RuleExplanation::not_matched(self.pattern.mismatch_reason(cmd)),
+        }
+    }
+}
+
+/// Matches when any of `flags` appears anywhere after the prefix, either as an
+/// exact token or in `--flag=value` form.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FlagRule {
+    pub pattern: PrefixPattern,
+    pub flags: Vec<String>,
+    pub decision: Decision,
+    pub justification: Option<String>,
+}
+
+impl FlagRule {
+    fn flag_matches(&self, token: &str) -> bool {
+        self.flags.iter().any(|flag| {
+            token == flag
+                || (flag.starts_with("--")
+                    && token
+                        .strip_prefix(flag.as_str())
+                        .is_some_and(|rest| rest.starts_with('=')))
+        })
+    }
+}
+
+impl Rule for FlagRule {
+    fn program(&self) -> &str {
+        self.pattern.first.as_ref()
+    }
+
+    fn matches(&self, cmd: &[String]) -> Option<RuleMatch> {
+        let matched_prefix = self.pattern.matches_prefix(cmd)?;
+        let matched_token = cmd[matched_prefix.len()..]
+            .iter()
+            .find(|token| self.flag_matches(token))?;
+        Some(RuleMatch::FlagRuleMatch {
+            matched_prefix,
+            matched_token: matched_token.clone(),
+            decision: self.decision,
+            justification: self.justification.clone(),
+        })
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn render(&self) -> String {
+        render_rule_call(
+            "flag_rule",
+            &self.pattern,
+            &[format!(
+                "flags={}",
+                serde_json::Value::from(self.flags.clone())
+            )],
+            self.decision,
+            self.justification.as_deref(),
+        )
+    }
+
+    fn explain(&self, cmd: &[String], _context: &MatchContext<'_>) -> RuleExplanation {
+        let Some(matched_prefix) = self.pattern.matches_prefix(cmd) else {
+            return RuleExplanation::not_matched(self.pattern.mismatch_reason(cmd));
+        };
+        let prefix = render_prefix(&matched_prefix);
+        match self.matches(cmd) {
+            Some(rule_match) => {
+                let token = rule_match.matched_token().unwrap_or_default().to_string();
+                RuleExplanation::matched(
+                    rule_match,
+                    format!("flag `{token}` appears after prefix `{prefix}`"),
+                )
+            }
+            None => RuleExplanation::not_matched(format!(
+                "no flag in {} appears after prefix `{prefix}`",
+                serde_json::Value::from(self.flags.clone())
+            )),
+        }
+    }
+}
+
+/// Matches when a path argument after the prefix resolves outside the
+/// workspace root.
+///
+/// Path arguments are every non-flag token plus the value of `--opt=value`
+/// flags whose value contains a `/`. Resolution is lexical; symlinks are not
+/// followed. Without a workspace root in the [`MatchContext`], absolute paths,
+/// `~` paths, and relative paths that climb above their starting directory are
+/// all treated as outside.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PathRule {
+    pub pattern: PrefixPattern,
+    pub decision: Decision,
+    pub justification: Option<String>,
+}
+
+impl PathRule {
+    fn path_arguments(args: &[String]) -> impl Iterator<Item = &str> {
+        args.iter().filter_map(|arg| {
+            if !arg.starts_with('-') {
+                Some(arg.as_str())
+            } else {
+                arg.split_once('=')
+                    .map(|(_, value)| value)
+                    .filter(|value| value.contains('/'))
+            }
+        })
+    }
+
+    fn is_outside_workspace(path: &str, context: &MatchContext<'_>) -> bool {
+        if path.starts_with('~') {
+            return true;
+        }
+        let path = Path::new(path);
+        let resolved = match (path.is_absolute(), context.cwd.or(context.workspace_root)) {
+            (true, _) => normalize_lexically(path),
+            (false, Some(base)) => normalize_lexically(&base.join(path)),
+            (false, None) => {
+                return normalize_lexically(path)
+                    .components()
+                    .next()
+                    .is_some_and(|component| component == Component::ParentDir);
+            }
+        };
+        match context.workspace_root {
+            Some(root) => !resolved.starts_with(normalize_lexically(root)),
+            None => resolved.is_absolute(),
+        }
+    }
+}
+
+impl Rule for PathRule {
+    fn program(&self) -> &str {
+        self.pattern.first.as_ref()
+    }
+
+    fn matches(&self, cmd: &[String]) -> Option<RuleMatch> {
+        self.matches_with_context(cmd, &MatchContext::default())
+    }
+
+    fn matches_with_context(
+        &self,
+        cmd: &[String],
+        context: &MatchContext<'_>,
+    ) -> Option<RuleMatch> {
+        let matched_prefix = self.pattern.matches_prefix(cmd)?;
+        let matched_token = Self::path_arguments(&cmd[matched_prefix.len()..])
+            .find(|path| Self::is_outside_workspace(path, context))?;
+        Some(RuleMatch::PathRuleMatch {
+            matched_prefix,
+            matched_token: matched_token.to_string(),
+            decision: self.decision,
+            justification: self.justification.clone(),
+        })
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn render(&self) -> String {
+        render_rule_call(
+            "path_rule",
+            &self.pattern,
+            &[],
+            self.decision,
+            self.justification.as_deref(),
+        )
+    }
+
+    fn explain(&self, cmd: &[String], context: &MatchContext<'_>) -> RuleExplanation {
+        if self.pattern.matches_prefix(cmd).is_none() {
+            return RuleExplanation::not_matched(self.pattern.mismatch_reason(cmd));
+        }
+        let root = context.workspace_root.map_or_else(
+            || "the workspace root".to_string(),
+            |root| format!("`{}`", root.display()),
+        );
+        match self.matches_with_context(cmd, context) {
+            Some(rule_match) => {
+                let token = rule_match.matched_token().unwrap_or_default().to_string();
+                RuleExplanation::matched(
+                    rule_match,
+                    format!("path `{token}` resolves outside {root}"),
+                )
+            }
+            None => {
+                RuleExplanation::not_matched(format!("every path argument stays within {root}"))
+            }
+        }
+    }
+}
+
+/// Matches when a single token after the prefix matches `regex`. When
+/// `index` is set, only the token at that argv position is tested.
+#[derive(Clone, Debug)]
+pub struct RegexRule {
+    pub pattern: PrefixPattern,
+    pub regex: Regex,
+    pub index: Option<usize>,
+    pub decision: Decision,
+    pub justification: Option<String>,
+}
+
+impl Rule for RegexRule {
+    fn program(&self) -> &str {
+        self.pattern.first.as_ref()
+    }
+
+    fn matches(&self, cmd: &[String]) -> Option<RuleMatch> {
+        let matched_prefix = self.pattern.matches_prefix(cmd)?;
+        let matched_token = match self.index {
+            Some(index) if index < matched_prefix.len() => return None,
+            Some(index) => cmd.get(index).filter(|token| self.regex.is_match(token))?,
+            None => cmd[matched_prefix.len()..]
+                .iter()
+                .find(|token| self.regex.is_match(token))?,
+        };
+        Some(RuleMatch::RegexRuleMatch {
+            matched_prefix,
+            matched_token: matched_token.clone(),
+            decision: self.decision,
+            justification: self.justification.clone(),
+        })
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn render(&self) -> String {
+        let mut args = vec![format!(
+            "regex={}",
+            serde_json::Value::from(self.regex.as_str())
+        )];
+        if let Some(index) = self.index {
+            args.push(format!("index={index}"));
+        }
+        render_rule_call(
+            "regex_rule",
+            &self.pattern,
+            &args,
+            self.decision,
+            self.justification.as_deref(),
+        )
+    }
+
+    fn explain(&self, cmd: &[String], _context: &MatchContext<'_>) -> RuleExplanation {
+        let Some(matched_prefix) = self.pattern.matches_prefix(cmd) else {
+            return RuleExplanation::not_matched(self.pattern.mismatch_reason(cmd));
+        };
+        let regex = self.regex.as_str();
+        match (self.matches(cmd), self.index) {
+            (Some(rule_match), _) => {
+                let token = rule_match.matched_token().unwrap_or_default().to_string();
+                RuleExplanation::matched(rule_match, format!("token `{token}` matches /{regex}/"))
+            }
+            (None, Some(index)) => RuleExplanation::not_matched(match cmd.get(index) {
+                Some(token) if index >= matched_prefix.len() => {
+                    format!("token {index} `{token}` does not match /{regex}/")
+                }
+                Some(_) => format!("token {index} is part of the prefix"),
+                None => format!("command has no token {index}"),
+            }),
+            (None, None) => RuleExplanation::not_matched(format!(
+                "no token after prefix `{}` matches /{regex}/",
+                render_prefix(&matched_prefix)
+            )),
+        }
+    }
+}
+
+fn normalize_lexically(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                if matches!(
+                    normalized.components().next_back(),
+                    Some(Component::Normal(_))
+                ) {
+                    normalized.pop();
+                } else if !normalized.has_root() {
+                    normalized.push(component);
+                }
+            }
+            _ => normalized.push(component),
+        }
+    }
+    normalized
 }
 
 /// Count how many rules match each provided example and error if any example is unmatched.
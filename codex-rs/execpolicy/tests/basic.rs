@@ -1,5 +1,6 @@
 use std::any::Any;
 use std::fs;
+use std::path::Path;
 use std::sync::Arc;
 
 use anyhow::Context;
@@ -7,11 +8,15 @@ use anyhow::Result;
 use codex_execpolicy::Decision;
 use codex_execpolicy::Error;
 use codex_execpolicy::Evaluation;
+use codex_execpolicy::MatchContext;
 use codex_execpolicy::Policy;
 use codex_execpolicy::PolicyParser;
 use codex_execpolicy::RuleMatch;
 use codex_execpolicy::RuleRef;
 use codex_execpolicy::blocking_append_allow_prefix_rule;
+use codex_execpolicy::execpolicyexplain::HeuristicTrace;
+use codex_execpolicy::execpolicyexplain::explain_command;
+use codex_execpolicy::execpolicyexplain::format_explanation_text;
 use codex_execpolicy::rule::PatternToken;
 use codex_execpolicy::rule::PrefixPattern;
 use codex_execpolicy::rule::PrefixRule;
@@ -572,3 +577,317 @@ fn heuristics_match_is_returned_when_no_policy_matches() {
         evaluation
     );
 }
+
+#[test]
+fn flag_rule_matches_flag_anywhere_after_prefix() -> Result<()> {
+    let policy_src = r#"
+flag_rule(
+    pattern = ["git", "push"],
+    flags = ["--force", "-f"],
+    decision = "forbidden",
+    justification = "force pushes rewrite shared history",
+    match = ["git push origin main --force", "git push --force=yes"],
+    not_match = ["git push origin main", "git push --force-with-lease"],
+)
+    "#;
+    let mut parser = PolicyParser::new();
+    parser.parse("test.rules", policy_src)?;
+    let policy = parser.build();
+
+    let evaluation = policy.check(
+        &tokens(&["git", "push", "origin", "-f", "main"]),
+        &allow_all,
+    );
+    assert_eq!(
+        Evaluation {
+            decision: Decision::Forbidden,
+            matched_rules: vec![RuleMatch::FlagRuleMatch {
+                matched_prefix: tokens(&["git", "push"]),
+                matched_token: "-f".to_string(),
+                decision: Decision::Forbidden,
+                justification: Some("force pushes rewrite shared history".to_string()),
+            }],
+        },
+        evaluation
+    );
+    Ok(())
+}
+
+#[test]
+fn path_rule_matches_arguments_outside_workspace_root() -> Result<()> {
+    let policy_src = r#"
+path_rule(
+    pattern = ["rm"],
+    decision = "prompt",
+    match = ["rm -rf /etc", "rm ../sibling/file"],
+    not_match = ["rm -rf build"],
+)
+    "#;
+    let mut parser = PolicyParser::new();
+    parser.parse("test.rules", policy_src)?;
+    let policy = parser.build();
+
+    let context = MatchContext {
+        cwd: Some(Path::new("/work/repo/src")),
+        workspace_root: Some(Path::new("/work/repo")),
+    };
+    let inside = policy.check_with_context(
+        &tokens(&["rm", "-rf", "../target", "/work/repo/tmp"]),
+        &context,
+        &allow_all,
+    );
+    assert_eq!(
+        Evaluation {
+            decision: Decision::Allow,
+            matched_rules: vec![RuleMatch::HeuristicsRuleMatch {
+                command: tokens(&["rm", "-rf", "../target", "/work/repo/tmp"]),
+                decision: Decision::Allow,
+            }],
+        },
+        inside
+    );
+
+    let outside = policy.check_with_context(
+        &tokens(&["rm", "-rf", "build", "../../other"]),
+        &context,
+        &allow_all,
+    );
+    assert_eq!(
+        Evaluation {
+            decision: Decision::Prompt,
+            matched_rules: vec![RuleMatch::PathRuleMatch {
+                matched_prefix: tokens(&["rm"]),
+                matched_token: "../../other".to_string(),
+                decision: Decision::Prompt,
+                justification: None,
+            }],
+        },
+        outside
+    );
+    Ok(())
+}
+
+#[test]
+fn regex_rule_matches_single_token() -> Result<()> {
+    let policy_src = r#"
+regex_rule(
+    pattern = ["curl"],
+    regex = "^https?://(localhost|127\\.0\\.0\\.1)",
+    decision = "allow",
+    not_match = ["curl https://example.com"],
+)
+regex_rule(
+    pattern = ["git", "checkout"],
+    regex = "^release/",
+    index = 2,
+    decision = "prompt",
+    match = ["git checkout release/1.0"],
+    not_match = ["git checkout main release/1.0"],
+)
+    "#;
+    let mut parser = PolicyParser::new();
+    parser.parse("test.rules", policy_src)?;
+    let policy = parser.build();
+
+    let evaluation = policy.check(
+        &tokens(&["curl", "-s", "http://localhost:8080/health"]),
+        &prompt_all,
+    );
+    assert_eq!(
+        Evaluation {
+            decision: Decision::Allow,
+            matched_rules: vec![RuleMatch::RegexRuleMatch {
+                matched_prefix: tokens(&["curl"]),
+                matched_token: "http://localhost:8080/health".to_string(),
+                decision: Decision::Allow,
+                justification: None,
+            }],
+        },
+        evaluation
+    );
+    Ok(())
+}
+
+#[test]
+fn structured_rules_reject_invalid_arguments() {
+    for (policy_src, expected) in [
+        (
+            r#"flag_rule(pattern = ["git"], flags = [], decision = "prompt")"#,
+            "invalid rule: flags cannot be empty",
+        ),
+        (
+            r#"flag_rule(pattern = ["git"], flags = ["force"], decision = "prompt")"#,
+            "invalid rule: flag must start with `-`",
+        ),
+        (
+            r#"regex_rule(pattern = ["git"], regex = "(", decision = "prompt")"#,
+            "invalid rule: invalid regex",
+        ),
+    ] {
+        let mut parser = PolicyParser::new();
+        let err = parser
+            .parse("test.rules", policy_src)
+            .expect_err("expected parse error");
+        assert!(
+            err.to_string().contains(expected),
+            "{err} does not contain {expected}"
+        );
+    }
+}
+
+#[test]
+fn structured_rule_matches_serialize_matched_token() -> Result<()> {
+    let rule_match = RuleMatch::FlagRuleMatch {
+        matched_prefix: tokens(&["git", "push"]),
+        matched_token: "--force".to_string(),
+        decision: Decision::Forbidden,
+        justification: None,
+    };
+    assert_eq!(
+        serde_json::json!({
+            "flagRuleMatch": {
+                "matchedPrefix": ["git", "push"],
+                "matchedToken": "--force",
+                "decision": "forbidden",
+            }
+        }),
+        serde_json::to_value(&rule_match)?
+    );
+    Ok(())
+}
+
+#[test]
+fn explain_traces_every_candidate_rule_with_its_source() -> Result<()> {
+    let mut base = PolicyParser::new();
+    base.parse(
+        "base.rules",
+        r#"
+prefix_rule(pattern = ["git", "status"])
+prefix_rule(pattern = ["git"], decision = "prompt")
+prefix_rule(pattern = ["ls"])
+"#,
+    )?;
+    let mut team = PolicyParser::new();
+    team.parse(
+        "team.rules",
+        r#"
+flag_rule(pattern = ["git", "push"], flags = ["--force"], decision = "forbidden")
+"#,
+    )?;
+    let policies = vec![
+        ("base.rules".to_string(), base.build()),
+        ("team.rules".to_string(), team.build()),
+    ];
+
+    let explanation = explain_command(
+        &policies,
+        &tokens(&["git", "push", "--force"]),
+        &MatchContext::default(),
+    );
+    let summary: Vec<(&str, bool, &str)> = explanation
+        .candidates
+        .iter()
+        .map(|candidate| {
+            (
+                candidate.source.as_str(),
+                candidate.matched,
+                candidate.reason.as_str(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        vec![
+            ("base.rules", false, "token 1 is `push`, expected `status`"),
+            ("base.rules", true, "prefix `git` matched"),
+            (
+                "team.rules",
+                true,
+                "flag `--force` appears after prefix `git push`"
+            ),
+        ],
+        summary
+    );
+    assert_eq!(
+        explanation.candidates[2].rule,
+        r#"flag_rule(pattern=["git","push"], flags=["--force"], decision="forbidden")"#
+    );
+    assert_eq!(
+        (
+            explanation.policy_files[0].rule_count,
+            explanation.policy_files[1].rule_count
+        ),
+        (3, 1)
+    );
+    assert_eq!(Some(Decision::Forbidden), explanation.decision);
+    assert!(!explanation.heuristics_fallback);
+
+    let text = format_explanation_text(&explanation);
+    assert!(text.contains(
+        "[skip ] base.rules: prefix_rule(pattern=[\"git\",\"status\"], decision=\"allow\")"
+    ));
+    assert!(text.contains("Decision: forbidden"));
+    assert!(text.contains("Heuristics fallback: not used"));
+    Ok(())
+}
+
+#[test]
+fn explain_reports_heuristics_fallback_when_nothing_matches() -> Result<()> {
+    let mut parser = PolicyParser::new();
+    parser.parse("test.rules", r#"prefix_rule(pattern = ["git", "status"])"#)?;
+    let policies = vec![("test.rules".to_string(), parser.build())];
+
+    let explanation = explain_command(&policies, &tokens(&["git"]), &MatchContext::default());
+    assert_eq!(
+        serde_json::json!({
+            "command": ["git"],
+            "policyFiles": [{"path": "test.rules", "ruleCount": 1}],
+            "candidates": [{
+                "source": "test.rules",
+                "rule": "prefix_rule(pattern=[\"git\",\"status\"], decision=\"allow\")",
+                "matched": false,
+                "reason": "command ends before token 1, expected `status`",
+            }],
+            "heuristicsFallback": true,
+            "decisionReason": "no candidate rule matched; the heuristics fallback decides",
+        }),
+        serde_json::to_value(&explanation)?
+    );
+    Ok(())
+}
+
+#[test]
+fn explain_reports_which_heuristic_decided() -> Result<()> {
+    let mut parser = PolicyParser::new();
+    parser.parse("test.rules", r#"prefix_rule(pattern = ["git", "push"])"#)?;
+    let policies = vec![("test.rules".to_string(), parser.build())];
+    let heuristic = |cmd: &[String]| HeuristicTrace {
+        name: "known_safe_command".to_string(),
+        decision: Decision::Allow,
+        reason: format!("`{}` only reads state", cmd.join(" ")),
+    };
+
+    let matched = explain_command(
+        &policies,
+        &tokens(&["git", "push"]),
+        &MatchContext::default(),
+    )
+    .with_heuristic(heuristic);
+    assert_eq!(None, matched.heuristic);
+    assert_eq!(matched.decision, matched.final_decision());
+
+    let unmatched = explain_command(&policies, &tokens(&["ls"]), &MatchContext::default())
+        .with_heuristic(heuristic);
+    assert_eq!(
+        serde_json::json!({
+            "name": "known_safe_command",
+            "decision": "allow",
+            "reason": "`ls` only reads state",
+        }),
+        serde_json::to_value(&unmatched)?["heuristic"]
+    );
+    assert_eq!(Some(Decision::Allow), unmatched.final_decision());
+    assert!(format_explanation_text(&unmatched).ends_with(
+        "Heuristics fallback: used\n  known_safe_command decided allow: `ls` only reads state"
+    ));
+    Ok(())
+}
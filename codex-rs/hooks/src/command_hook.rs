@@ -0,0 +1,285 @@
+use std::process::Stdio;
+use std::sync::Arc;
+use std::time::Duration;
+
+use serde::Deserialize;
+use serde_json::Value;
+use tokio::io::AsyncWriteExt;
+use wildmatch::WildMatchPattern;
+
+use crate::Hook;
+use crate::HookOutcome;
+use crate::HookPayload;
+use crate::command_from_argv;
+
+pub const DEFAULT_COMMAND_HOOK_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// How a command hook receives the serialized [`HookPayload`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CommandHookInput {
+    /// The payload JSON is written to stdin.
+    #[default]
+    Stdin,
+    /// The payload is exposed as `CODEX_HOOK_PAYLOAD`, alongside
+    /// `CODEX_HOOK_EVENT` and (for tool events) `CODEX_HOOK_TOOL_NAME`.
+    Env,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CommandHookConfig {
+    pub argv: Vec<String>,
+    /// Tool name or glob. When set, the hook only runs for tool events whose
+    /// tool name matches.
+    pub matcher: Option<String>,
+    pub timeout: Duration,
+    pub input: CommandHookInput,
+}
+
+/// Optional JSON object a hook command may print to stdout.
+#[derive(Debug, Default, Deserialize)]
+struct CommandHookResponse {
+    #[serde(default)]
+    decision: Option<CommandHookDecision>,
+    #[serde(default)]
+    reason: Option<String>,
+    #[serde(default)]
+    arguments: Option<Value>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum CommandHookDecision {
+    Continue,
+    Block,
+}
+
+/// Builds a hook that runs an external command with the hook payload.
+///
+/// A non-zero exit, a timeout, or a failure to spawn maps to
+/// [`HookOutcome::Stop`]. On success, stdout may contain
+/// `{"decision":"block","reason":"..."}` to stop (reported as
+/// [`HookOutcome::Deny`] when a reason is given) or
+/// `{"arguments":{...}}` to rewrite tool arguments. Any other stdout is ignored.
+pub fn command_hook(config: CommandHookConfig) -> Hook {
+    let config = Arc::new(config);
+    let matcher = config
+        .matcher
+        .as_deref()
+        .map(WildMatchPattern::<'*', '?'>::new)
+        .map(Arc::new);
+    Hook {
+        func: Arc::new(move |payload: &HookPayload| {
+            let config = Arc::clone(&config);
+            let skip = match (&matcher, payload.hook_event.tool_name()) {
+                (Some(matcher), Some(tool_name)) => !matcher.matches(tool_name),
+                _ => false,
+            };
+            let payload_json = serde_json::to_string(payload);
+            let event_name = payload.hook_event.kind().as_str();
+            let tool_name = payload.hook_event.tool_name().map(str::to_string);
+            let cwd = payload.cwd.clone();
+            Box::pin(async move {
+                if skip {
+                    return HookOutcome::Continue;
+                }
+                let Ok(payload_json) = payload_json else {
+                    return HookOutcome::Stop;
+                };
+                let Some(mut command) = command_from_argv(&config.argv) else {
+                    return HookOutcome::Stop;
+                };
+                command
+                    .current_dir(&cwd)
+                    .stdout(Stdio::piped())
+                    .stderr(Stdio::null())
+                    .kill_on_drop(true);
+                match config.input {
+                    CommandHookInput::Stdin => {
+                        command.stdin(Stdio::piped());
+                    }
+                    CommandHookInput::Env => {
+                        command
+                            .stdin(Stdio::null())
+                            .env("CODEX_HOOK_EVENT", event_name)
+                            .env("CODEX_HOOK_PAYLOAD", &payload_json);
+                        if let Some(tool_name) = &tool_name {
+                            command.env("CODEX_HOOK_TOOL_NAME", tool_name);
+                        }
+                    }
+                }
+
+                let run = async {
+                    let mut child = command.spawn()?;
+                    if let Some(mut stdin) = child.stdin.take() {
+                        // A hook that exits without reading stdin is not an error.
+                        let _ = stdin.write_all(payload_json.as_bytes()).await;
+                    }
+                    child.wait_with_output().await
+                };
+                match tokio::time::timeout(config.timeout, run).await {
+                    Ok(Ok(output)) if output.status.success() => {
+                        outcome_from_stdout(&String::from_utf8_lossy(&output.stdout))
+                    }
+                    Ok(Ok(_)) | Ok(Err(_)) | Err(_) => HookOutcome::Stop,
+                }
+            })
+        }),
+    }
+}
+
+fn outcome_from_stdout(stdout: &str) -> HookOutcome {
+    let stdout = stdout.trim();
+    if stdout.is_empty() {
+        return HookOutcome::Continue;
+    }
+    let Ok(response) = serde_json::from_str::<CommandHookResponse>(stdout) else {
+        return HookOutcome::Continue;
+    };
+    match (response.decision, response.arguments) {
+        (Some(CommandHookDecision::Block), _) => match response.reason {
+            Some(reason) => HookOutcome::Deny { reason },
+            None => HookOutcome::Stop,
+        },
+        (_, Some(arguments)) => HookOutcome::Modify { arguments },
+        (Some(CommandHookDecision::Continue) | None, None) => HookOutcome::Continue,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use chrono::TimeZone;
+    use chrono::Utc;
+    use codex_protocol::ThreadId;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    use super::*;
+    use crate::HookEvent;
+    use crate::HookEventBeforeToolCall;
+
+    fn tool_payload(tool_name: &str) -> HookPayload {
+        HookPayload {
+            session_id: ThreadId::new(),
+            cwd: std::env::temp_dir(),
+            triggered_at: Utc
+                .with_ymd_and_hms(2025, 1, 1, 0, 0, 0)
+                .single()
+                .expect("valid timestamp"),
+            hook_event: HookEvent::BeforeToolCall {
+                event: HookEventBeforeToolCall {
+                    thread_id: ThreadId::new(),
+                    turn_id: "turn-1".to_string(),
+                    call_id: "call-1".to_string(),
+                    tool_name: tool_name.to_string(),
+                    tool_input: json!({"command": ["terraform", "apply"]}),
+                },
+            },
+        }
+    }
+
+    fn sh_hook(script: &str, matcher: Option<&str>, input: CommandHookInput) -> Hook {
+        command_hook(CommandHookConfig {
+            argv: vec!["/bin/sh".to_string(), "-c".to_string(), script.to_string()],
+            matcher: matcher.map(str::to_string),
+            timeout: Duration::from_secs(5),
+            input,
+        })
+    }
+
+    #[test]
+    fn stdout_block_with_reason_denies() {
+        assert_eq!(
+            outcome_from_stdout(r#"{"decision":"block","reason":"no terraform"}"#),
+            HookOutcome::Deny {
+                reason: "no terraform".to_string()
+            }
+        );
+        assert_eq!(
+            outcome_from_stdout(r#"{"decision":"block"}"#),
+            HookOutcome::Stop
+        );
+        assert_eq!(
+            outcome_from_stdout(r#"{"arguments":{"command":["ls"]}}"#),
+            HookOutcome::Modify {
+                arguments: json!({"command": ["ls"]})
+            }
+        );
+        assert_eq!(outcome_from_stdout("not json"), HookOutcome::Continue);
+        assert_eq!(outcome_from_stdout(""), HookOutcome::Continue);
+    }
+
+    #[cfg(not(windows))]
+    #[tokio::test]
+    async fn command_hook_reads_payload_from_stdin() {
+        let hook = sh_hook(
+            r#"if grep -q terraform; then echo '{"decision":"block","reason":"terraform"}'; fi"#,
+            None,
+            CommandHookInput::Stdin,
+        );
+        let outcome = hook.execute(&tool_payload("shell")).await;
+        assert_eq!(
+            outcome,
+            HookOutcome::Deny {
+                reason: "terraform".to_string()
+            }
+        );
+    }
+
+    #[cfg(not(windows))]
+    #[tokio::test]
+    async fn command_hook_exposes_env_variables() {
+        let hook = sh_hook(
+            r#"test "$CODEX_HOOK_EVENT" = before_tool_call && test "$CODEX_HOOK_TOOL_NAME" = shell"#,
+            None,
+            CommandHookInput::Env,
+        );
+        assert_eq!(
+            hook.execute(&tool_payload("shell")).await,
+            HookOutcome::Continue
+        );
+    }
+
+    #[cfg(not(windows))]
+    #[tokio::test]
+    async fn command_hook_non_zero_exit_stops() {
+        let hook = sh_hook("exit 3", None, CommandHookInput::Stdin);
+        assert_eq!(
+            hook.execute(&tool_payload("shell")).await,
+            HookOutcome::Stop
+        );
+    }
+
+    #[cfg(not(windows))]
+    #[tokio::test]
+    async fn command_hook_skips_unmatched_tools() {
+        let hook = sh_hook("exit 1", Some("apply_*"), CommandHookInput::Stdin);
+        assert_eq!(
+            hook.execute(&tool_payload("shell")).await,
+            HookOutcome::Continue
+        );
+        assert_eq!(
+            hook.execute(&tool_payload("apply_patch")).await,
+            HookOutcome::Stop
+        );
+    }
+
+    #[cfg(not(windows))]
+    #[tokio::test]
+    async fn command_hook_times_out() {
+        let hook = command_hook(CommandHookConfig {
+            argv: vec![
+                "/bin/sh".to_string(),
+                "-c".to_string(),
+                "sleep 5".to_string(),
+            ],
+            matcher: None,
+            timeout: Duration::from_millis(100),
+            input: CommandHookInput::Stdin,
+        });
+        let mut payload = tool_payload("shell");
+        payload.cwd = PathBuf::from("/");
+        assert_eq!(hook.execute(&payload).await, HookOutcome::Stop);
+    }
+}
@@ -1,12 +1,23 @@
+mod command_hook;
 mod registry;
 mod types;
 mod user_notification;
 
+pub use command_hook::CommandHookConfig;
+pub use command_hook::CommandHookInput;
+pub use command_hook::DEFAULT_COMMAND_HOOK_TIMEOUT;
+pub use command_hook::command_hook;
 pub use registry::Hooks;
 pub use registry::command_from_argv;
 pub use types::Hook;
 pub use types::HookEvent;
 pub use types::HookEventAfterAgent;
+pub use types::HookEventAfterToolCall;
+pub use types::HookEventBeforeToolCall;
+pub use types::HookEventKind;
+pub use types::HookEventSessionEnd;
+pub use types::HookEventSessionStart;
+pub use types::HookEventUserPromptSubmit;
 pub use types::HookOutcome;
 pub use types::HookPayload;
 pub use user_notification::legacy_notify_json;
@@ -2,16 +2,22 @@ use tokio::process::Command;
 
 use crate::types::Hook;
 use crate::types::HookEvent;
+use crate::types::HookEventKind;
 use crate::types::HookOutcome;
 use crate::types::HookPayload;
 
 #[derive(Default, Clone)]
 pub struct Hooks {
     after_agent: Vec<Hook>,
+    before_tool_call: Vec<Hook>,
+    after_tool_call: Vec<Hook>,
+    user_prompt_submit: Vec<Hook>,
+    session_start: Vec<Hook>,
+    session_end: Vec<Hook>,
 }
 
 // Hooks are arbitrary, user-specified functions that are deterministically
-// executed after specific events in the Codex lifecycle.
+// executed at specific points in the Codex lifecycle.
 impl Hooks {
     pub fn new(notify: Option<Vec<String>>) -> Self {
         let after_agent = notify
@@ -19,23 +25,68 @@ impl Hooks {
             .map(crate::notify_hook)
             .into_iter()
             .collect();
-        Self { after_agent }
+        Self {
+            after_agent,
+            ..Self::default()
+        }
+    }
+
+    /// Appends `hook` to the hooks run for `kind`, after any already registered.
+    pub fn register(&mut self, kind: HookEventKind, hook: Hook) {
+        self.hooks_for_kind_mut(kind).push(hook);
+    }
+
+    pub fn has_hooks_for(&self, kind: HookEventKind) -> bool {
+        !self.hooks_for_kind(kind).is_empty()
+    }
+
+    fn hooks_for_kind(&self, kind: HookEventKind) -> &[Hook] {
+        match kind {
+            HookEventKind::AfterAgent => &self.after_agent,
+            HookEventKind::BeforeToolCall => &self.before_tool_call,
+            HookEventKind::AfterToolCall => &self.after_tool_call,
+            HookEventKind::UserPromptSubmit => &self.user_prompt_submit,
+            HookEventKind::SessionStart => &self.session_start,
+            HookEventKind::SessionEnd => &self.session_end,
+        }
     }
 
-    fn hooks_for_event(&self, hook_event: &HookEvent) -> &[Hook] {
-        match hook_event {
-            HookEvent::AfterAgent { .. } => &self.after_agent,
+    fn hooks_for_kind_mut(&mut self, kind: HookEventKind) -> &mut Vec<Hook> {
+        match kind {
+            HookEventKind::AfterAgent => &mut self.after_agent,
+            HookEventKind::BeforeToolCall => &mut self.before_tool_call,
+            HookEventKind::AfterToolCall => &mut self.after_tool_call,
+            HookEventKind::UserPromptSubmit => &mut self.user_prompt_submit,
+            HookEventKind::SessionStart => &mut self.session_start,
+            HookEventKind::SessionEnd => &mut self.session_end,
         }
     }
 
-    pub async fn dispatch(&self, hook_payload: HookPayload) {
-        // TODO(gt): support interrupting program execution by returning a result here.
-        for hook in self.hooks_for_event(&hook_payload.hook_event) {
-            let outcome = hook.execute(&hook_payload).await;
-            if matches!(outcome, HookOutcome::Stop) {
-                break;
+    /// Runs every hook registered for the payload's event in order.
+    ///
+    /// The first `Stop` or `Deny` short-circuits and is returned as-is. For
+    /// `BeforeToolCall`, `Modify` outcomes rewrite the arguments seen by later
+    /// hooks and the last rewrite is returned; other events ignore `Modify`.
+    pub async fn dispatch(&self, hook_payload: HookPayload) -> HookOutcome {
+        let kind = hook_payload.hook_event.kind();
+        let mut hook_payload = hook_payload;
+        let mut modified = None;
+        for hook in self.hooks_for_kind(kind) {
+            match hook.execute(&hook_payload).await {
+                HookOutcome::Continue => {}
+                outcome @ (HookOutcome::Stop | HookOutcome::Deny { .. }) => return outcome,
+                HookOutcome::Modify { arguments } => {
+                    if let HookEvent::BeforeToolCall { event } = &mut hook_payload.hook_event {
+                        event.tool_input = arguments.clone();
+                        modified = Some(arguments);
+                    }
+                }
             }
         }
+        match modified {
+            Some(arguments) => HookOutcome::Modify { arguments },
+            None => HookOutcome::Continue,
+        }
     }
 }
 
@@ -70,6 +121,7 @@ mod tests {
 
     use super::*;
     use crate::types::HookEventAfterAgent;
+    use crate::types::HookEventBeforeToolCall;
 
     const CWD: &str = "/tmp";
     const INPUT_MESSAGE: &str = "hello";
@@ -98,6 +150,7 @@ mod tests {
         Hook {
             func: Arc::new(move |_| {
                 let calls = Arc::clone(&calls);
+                let outcome = outcome.clone();
                 Box::pin(async move {
                     calls.fetch_add(1, Ordering::SeqCst);
                     outcome
@@ -107,7 +160,30 @@ mod tests {
     }
 
     fn hooks_for_after_agent(hooks: Vec<Hook>) -> Hooks {
-        Hooks { after_agent: hooks }
+        Hooks {
+            after_agent: hooks,
+            ..Hooks::default()
+        }
+    }
+
+    fn before_tool_call_payload(tool_input: serde_json::Value) -> HookPayload {
+        HookPayload {
+            session_id: ThreadId::new(),
+            cwd: PathBuf::from(CWD),
+            triggered_at: Utc
+                .with_ymd_and_hms(2025, 1, 1, 0, 0, 0)
+                .single()
+                .expect("valid timestamp"),
+            hook_event: HookEvent::BeforeToolCall {
+                event: HookEventBeforeToolCall {
+                    thread_id: ThreadId::new(),
+                    turn_id: "turn-tool".to_string(),
+                    call_id: "call-1".to_string(),
+                    tool_name: "shell".to_string(),
+                    tool_input,
+                },
+            },
+        }
     }
 
     #[test]
@@ -193,6 +269,95 @@ mod tests {
         assert_eq!(calls.load(Ordering::SeqCst), 1);
     }
 
+    #[tokio::test]
+    async fn dispatch_returns_deny_reason_and_skips_remaining_hooks() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let mut hooks = Hooks::default();
+        hooks.register(
+            HookEventKind::BeforeToolCall,
+            counting_hook(
+                &calls,
+                HookOutcome::Deny {
+                    reason: "terraform apply is not allowed".to_string(),
+                },
+            ),
+        );
+        hooks.register(
+            HookEventKind::BeforeToolCall,
+            counting_hook(&calls, HookOutcome::Continue),
+        );
+
+        let outcome = hooks
+            .dispatch(before_tool_call_payload(serde_json::json!({})))
+            .await;
+        assert_eq!(
+            outcome,
+            HookOutcome::Deny {
+                reason: "terraform apply is not allowed".to_string(),
+            }
+        );
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn dispatch_threads_modified_arguments_through_later_hooks() {
+        let mut hooks = Hooks::default();
+        hooks.register(
+            HookEventKind::BeforeToolCall,
+            Hook {
+                func: Arc::new(|_| {
+                    Box::pin(async {
+                        HookOutcome::Modify {
+                            arguments: serde_json::json!({"command": ["ls"]}),
+                        }
+                    })
+                }),
+            },
+        );
+        hooks.register(
+            HookEventKind::BeforeToolCall,
+            Hook {
+                func: Arc::new(|payload: &HookPayload| {
+                    let seen = match &payload.hook_event {
+                        HookEvent::BeforeToolCall { event } => event.tool_input.clone(),
+                        _ => serde_json::Value::Null,
+                    };
+                    Box::pin(async move {
+                        assert_eq!(seen, serde_json::json!({"command": ["ls"]}));
+                        HookOutcome::Continue
+                    })
+                }),
+            },
+        );
+
+        let outcome = hooks
+            .dispatch(before_tool_call_payload(
+                serde_json::json!({"command": ["rm", "-rf", "/"]}),
+            ))
+            .await;
+        assert_eq!(
+            outcome,
+            HookOutcome::Modify {
+                arguments: serde_json::json!({"command": ["ls"]}),
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn dispatch_ignores_modify_outside_before_tool_call() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let hooks = hooks_for_after_agent(vec![counting_hook(
+            &calls,
+            HookOutcome::Modify {
+                arguments: serde_json::json!({}),
+            },
+        )]);
+
+        let outcome = hooks.dispatch(hook_payload("5")).await;
+        assert_eq!(outcome, HookOutcome::Continue);
+        assert!(!hooks.has_hooks_for(HookEventKind::BeforeToolCall));
+    }
+
     #[cfg(not(windows))]
     #[tokio::test]
     async fn hook_executes_program_with_payload_argument_unix() -> Result<()> {
@@ -6,8 +6,10 @@ use chrono::SecondsFormat;
 use chrono::Utc;
 use codex_protocol::ThreadId;
 use futures::future::BoxFuture;
+use serde::Deserialize;
 use serde::Serialize;
 use serde::Serializer;
+use serde_json::Value;
 
 pub type HookFn = Arc<dyn for<'a> Fn(&'a HookPayload) -> BoxFuture<'a, HookOutcome> + Send + Sync>;
 
@@ -49,6 +51,51 @@ pub struct HookEventAfterAgent {
     pub last_assistant_message: Option<String>,
 }
 
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub struct HookEventBeforeToolCall {
+    pub thread_id: ThreadId,
+    pub turn_id: String,
+    pub call_id: String,
+    pub tool_name: String,
+    /// Tool arguments as the model sent them. Freeform tools receive a JSON string.
+    pub tool_input: Value,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub struct HookEventAfterToolCall {
+    pub thread_id: ThreadId,
+    pub turn_id: String,
+    pub call_id: String,
+    pub tool_name: String,
+    pub tool_input: Value,
+    pub success: bool,
+    /// Truncated text of the tool output returned to the model.
+    pub output_preview: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub struct HookEventUserPromptSubmit {
+    pub thread_id: ThreadId,
+    pub turn_id: String,
+    pub input_messages: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub struct HookEventSessionStart {
+    pub thread_id: ThreadId,
+    pub model: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub struct HookEventSessionEnd {
+    pub thread_id: ThreadId,
+}
+
 fn serialize_triggered_at<S>(value: &DateTime<Utc>, serializer: S) -> Result<S::Ok, S::Error>
 where
     S: Serializer,
@@ -63,13 +110,93 @@ pub enum HookEvent {
         #[serde(flatten)]
         event: HookEventAfterAgent,
     },
+    BeforeToolCall {
+        #[serde(flatten)]
+        event: HookEventBeforeToolCall,
+    },
+    AfterToolCall {
+        #[serde(flatten)]
+        event: HookEventAfterToolCall,
+    },
+    UserPromptSubmit {
+        #[serde(flatten)]
+        event: HookEventUserPromptSubmit,
+    },
+    SessionStart {
+        #[serde(flatten)]
+        event: HookEventSessionStart,
+    },
+    SessionEnd {
+        #[serde(flatten)]
+        event: HookEventSessionEnd,
+    },
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+impl HookEvent {
+    pub fn kind(&self) -> HookEventKind {
+        match self {
+            HookEvent::AfterAgent { .. } => HookEventKind::AfterAgent,
+            HookEvent::BeforeToolCall { .. } => HookEventKind::BeforeToolCall,
+            HookEvent::AfterToolCall { .. } => HookEventKind::AfterToolCall,
+            HookEvent::UserPromptSubmit { .. } => HookEventKind::UserPromptSubmit,
+            HookEvent::SessionStart { .. } => HookEventKind::SessionStart,
+            HookEvent::SessionEnd { .. } => HookEventKind::SessionEnd,
+        }
+    }
+
+    /// Tool name for tool-call events, used by hooks that only apply to some tools.
+    pub fn tool_name(&self) -> Option<&str> {
+        match self {
+            HookEvent::BeforeToolCall { event } => Some(&event.tool_name),
+            HookEvent::AfterToolCall { event } => Some(&event.tool_name),
+            HookEvent::AfterAgent { .. }
+            | HookEvent::UserPromptSubmit { .. }
+            | HookEvent::SessionStart { .. }
+            | HookEvent::SessionEnd { .. } => None,
+        }
+    }
+}
+
+/// Lifecycle points at which hooks can be registered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HookEventKind {
+    AfterAgent,
+    BeforeToolCall,
+    AfterToolCall,
+    UserPromptSubmit,
+    SessionStart,
+    SessionEnd,
+}
+
+impl HookEventKind {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            HookEventKind::AfterAgent => "after_agent",
+            HookEventKind::BeforeToolCall => "before_tool_call",
+            HookEventKind::AfterToolCall => "after_tool_call",
+            HookEventKind::UserPromptSubmit => "user_prompt_submit",
+            HookEventKind::SessionStart => "session_start",
+            HookEventKind::SessionEnd => "session_end",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
 pub enum HookOutcome {
     Continue,
-    #[allow(dead_code)]
+    /// Skip the remaining hooks. For `BeforeToolCall` and `UserPromptSubmit`
+    /// this also cancels the action.
     Stop,
+    /// Cancel the action and report `reason` back to the model or user.
+    Deny {
+        reason: String,
+    },
+    /// Replace the tool arguments before execution. Only honored for
+    /// `BeforeToolCall`; later hooks observe the rewritten arguments.
+    Modify {
+        arguments: Value,
+    },
 }
 
 #[cfg(test)]
@@ -84,6 +211,7 @@ mod tests {
 
     use super::HookEvent;
     use super::HookEventAfterAgent;
+    use super::HookEventBeforeToolCall;
     use super::HookPayload;
 
     #[test]
@@ -123,4 +251,44 @@ mod tests {
 
         assert_eq!(actual, expected);
     }
+
+    #[test]
+    fn before_tool_call_payload_serializes_tool_input() {
+        let session_id = ThreadId::new();
+        let thread_id = ThreadId::new();
+        let payload = HookPayload {
+            session_id,
+            cwd: PathBuf::from("tmp"),
+            triggered_at: Utc
+                .with_ymd_and_hms(2025, 1, 1, 0, 0, 0)
+                .single()
+                .expect("valid timestamp"),
+            hook_event: HookEvent::BeforeToolCall {
+                event: HookEventBeforeToolCall {
+                    thread_id,
+                    turn_id: "turn-1".to_string(),
+                    call_id: "call-1".to_string(),
+                    tool_name: "shell".to_string(),
+                    tool_input: json!({"command": ["terraform", "apply"]}),
+                },
+            },
+        };
+
+        let actual = serde_json::to_value(payload).expect("serialize hook payload");
+        let expected = json!({
+            "session_id": session_id.to_string(),
+            "cwd": "tmp",
+            "triggered_at": "2025-01-01T00:00:00Z",
+            "hook_event": {
+                "event_type": "before_tool_call",
+                "thread_id": thread_id.to_string(),
+                "turn_id": "turn-1",
+                "call_id": "call-1",
+                "tool_name": "shell",
+                "tool_input": {"command": ["terraform", "apply"]},
+            },
+        });
+
+        assert_eq!(actual, expected);
+    }
 }
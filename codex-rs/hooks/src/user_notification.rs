@@ -29,15 +29,24 @@ enum UserNotification {
 }
 
 pub fn legacy_notify_json(hook_event: &HookEvent, cwd: &Path) -> Result<String, serde_json::Error> {
-    serde_json::to_string(&match hook_event {
-        HookEvent::AfterAgent { event } => UserNotification::AgentTurnComplete {
-            thread_id: event.thread_id.to_string(),
-            turn_id: event.turn_id.clone(),
-            cwd: cwd.display().to_string(),
-            input_messages: event.input_messages.clone(),
-            last_assistant_message: event.last_assistant_message.clone(),
-        },
-    })
+    match hook_event {
+        HookEvent::AfterAgent { event } => {
+            serde_json::to_string(&UserNotification::AgentTurnComplete {
+                thread_id: event.thread_id.to_string(),
+                turn_id: event.turn_id.clone(),
+                cwd: cwd.display().to_string(),
+                input_messages: event.input_messages.clone(),
+                last_assistant_message: event.last_assistant_message.clone(),
+            })
+        }
+        // The legacy notify contract only covers turn completion; newer events
+        // fall back to the hook payload's event shape.
+        HookEvent::BeforeToolCall { .. }
+        | HookEvent::AfterToolCall { .. }
+        | HookEvent::UserPromptSubmit { .. }
+        | HookEvent::SessionStart { .. }
+        | HookEvent::SessionEnd { .. } => serde_json::to_string(hook_event),
+    }
 }
 
 pub fn notify_hook(argv: Vec<String>) -> Hook {
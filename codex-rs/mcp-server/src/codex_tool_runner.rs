@@ -18,7 +18,9 @@ use codex_core::protocol::ApplyPatchApprovalRequestEvent;
 use codex_core::protocol::Event;
 use codex_core::protocol::EventMsg;
 use codex_core::protocol::ExecApprovalRequestEvent;
+use codex_core::protocol::NetworkApprovalDecision;
 use codex_core::protocol::Op;
+use codex_core::protocol::SamplingDecision;
 use codex_core::protocol::Submission;
 use codex_core::protocol::TurnCompleteEvent;
 use codex_protocol::ThreadId;
@@ -257,6 +259,33 @@ async fn run_codex_tool_session_inner(
                         // TODO: forward elicitation requests to the client?
                         continue;
                     }
+                    EventMsg::SamplingRequest(ev) => {
+                        // MCP clients cannot approve sampling requests from other servers.
+                        if let Err(err) = thread
+                            .submit(Op::ResolveSampling {
+                                server_name: ev.server_name,
+                                request_id: ev.id,
+                                decision: SamplingDecision::Decline,
+                            })
+                            .await
+                        {
+                            tracing::error!("failed to submit ResolveSampling: {err}");
+                        }
+                        continue;
+                    }
+                    EventMsg::NetworkApprovalRequest(ev) => {
+                        // MCP clients cannot answer network approvals; keep the host blocked.
+                        if let Err(err) = thread
+                            .submit(Op::NetworkApproval {
+                                id: ev.id,
+                                decision: NetworkApprovalDecision::Deny,
+                            })
+                            .await
+                        {
+                            tracing::error!("failed to submit NetworkApproval: {err}");
+                        }
+                        continue;
+                    }
                     EventMsg::ApplyPatchApprovalRequest(ApplyPatchApprovalRequestEvent {
                         call_id,
                         turn_id: _,
@@ -319,8 +348,11 @@ async fn run_codex_tool_session_inner(
                     | EventMsg::AgentReasoningSectionBreak(_)
                     | EventMsg::McpToolCallBegin(_)
                     | EventMsg::McpToolCallEnd(_)
+                    | EventMsg::McpToolCallApproval(_)
                     | EventMsg::McpListToolsResponse(_)
                     | EventMsg::ListCustomPromptsResponse(_)
+                    | EventMsg::ListMcpPromptsResponse(_)
+                    | EventMsg::GetMcpPromptResponse(_)
                     | EventMsg::ListSkillsResponse(_)
                     | EventMsg::ListRemoteSkillsResponse(_)
                     | EventMsg::RemoteSkillDownloaded(_)
@@ -351,6 +383,7 @@ async fn run_codex_tool_session_inner(
                     | EventMsg::SkillsUpdateAvailable
                     | EventMsg::UndoStarted(_)
                     | EventMsg::UndoCompleted(_)
+                    | EventMsg::CheckpointCreated(_)
                     | EventMsg::ExitedReviewMode(_)
                     | EventMsg::RequestUserInput(_)
                     | EventMsg::DynamicToolCallRequest(_)
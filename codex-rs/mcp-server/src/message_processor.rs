@@ -2,11 +2,15 @@ use std::collections::HashMap;
 use std::path::PathBuf;
 
 use codex_core::AuthManager;
+use codex_core::NewThread;
 use codex_core::ThreadManager;
 use codex_core::config::Config;
+use codex_core::config::ConfigOverrides;
 use codex_core::default_client::USER_AGENT_SUFFIX;
 use codex_core::default_client::get_codex_user_agent;
+use codex_core::protocol::Op;
 use codex_core::protocol::Submission;
+use codex_core::read_session_meta_line;
 use codex_protocol::ThreadId;
 use codex_protocol::protocol::SessionSource;
 use rmcp::model::CallToolRequestParams;
@@ -21,7 +25,9 @@ use rmcp::model::JsonRpcError;
 use rmcp::model::JsonRpcNotification;
 use rmcp::model::JsonRpcRequest;
 use rmcp::model::JsonRpcResponse;
+use rmcp::model::PromptsCapability;
 use rmcp::model::RequestId;
+use rmcp::model::ResourcesCapability;
 use rmcp::model::ServerCapabilities;
 use rmcp::model::ToolsCapability;
 use serde_json::json;
@@ -33,12 +39,22 @@ use crate::codex_tool_config::CodexToolCallParam;
 use crate::codex_tool_config::CodexToolCallReplyParam;
 use crate::codex_tool_config::create_tool_for_codex_tool_call_param;
 use crate::codex_tool_config::create_tool_for_codex_tool_call_reply_param;
+use crate::codex_tool_runner::create_call_tool_result_with_thread_id;
 use crate::outgoing_message::OutgoingMessageSender;
+use crate::prompts;
+use crate::thread_tool_config::ForkThreadParam;
+use crate::thread_tool_config::InterruptThreadParam;
+use crate::thread_tool_config::ListThreadsParam;
+use crate::thread_tool_config::create_tool_for_fork_thread;
+use crate::thread_tool_config::create_tool_for_interrupt_thread;
+use crate::thread_tool_config::create_tool_for_list_threads;
+use crate::threads;
 
 pub(crate) struct MessageProcessor {
     outgoing: Arc<OutgoingMessageSender>,
     initialized: bool,
     codex_linux_sandbox_exe: Option<PathBuf>,
+    config: Arc<Config>,
     thread_manager: Arc<ThreadManager>,
     running_requests_id_to_codex_uuid: Arc<Mutex<HashMap<RequestId, ThreadId>>>,
 }
@@ -66,6 +82,7 @@ impl MessageProcessor {
             outgoing,
             initialized: false,
             codex_linux_sandbox_exe,
+            config,
             thread_manager,
             running_requests_id_to_codex_uuid: Arc::new(Mutex::new(HashMap::new())),
         }
@@ -83,13 +100,14 @@ impl MessageProcessor {
                 self.handle_ping(request_id).await;
             }
             ClientRequest::ListResourcesRequest(params) => {
-                self.handle_list_resources(params.params);
+                self.handle_list_resources(request_id, params.params).await;
             }
             ClientRequest::ListResourceTemplatesRequest(params) => {
-                self.handle_list_resource_templates(params.params);
+                self.handle_list_resource_templates(request_id, params.params)
+                    .await;
             }
             ClientRequest::ReadResourceRequest(params) => {
-                self.handle_read_resource(params.params);
+                self.handle_read_resource(request_id, params.params).await;
             }
             ClientRequest::SubscribeRequest(params) => {
                 self.handle_subscribe(params.params);
@@ -98,10 +116,10 @@ impl MessageProcessor {
                 self.handle_unsubscribe(params.params);
             }
             ClientRequest::ListPromptsRequest(params) => {
-                self.handle_list_prompts(params.params);
+                self.handle_list_prompts(request_id, params.params).await;
             }
             ClientRequest::GetPromptRequest(params) => {
-                self.handle_get_prompt(params.params);
+                self.handle_get_prompt(request_id, params.params).await;
             }
             ClientRequest::ListToolsRequest(params) => {
                 self.handle_list_tools(request_id, params.params).await;
@@ -238,6 +256,8 @@ impl MessageProcessor {
                 tools: Some(ToolsCapability {
                     list_changed: Some(true),
                 }),
+                resources: Some(ResourcesCapability::default()),
+                prompts: Some(PromptsCapability::default()),
                 ..Default::default()
             },
             instructions: None,
@@ -272,16 +292,93 @@ impl MessageProcessor {
         self.outgoing.send_response(id, json!({})).await;
     }
 
-    fn handle_list_resources(&self, params: Option<rmcp::model::PaginatedRequestParams>) {
+    async fn handle_list_resources(
+        &self,
+        id: RequestId,
+        params: Option<rmcp::model::PaginatedRequestParams>,
+    ) {
         tracing::info!("resources/list -> params: {:?}", params);
+        let cursor = params.and_then(|params| params.cursor);
+        match threads::list_threads(&self.config, &self.thread_manager, None, cursor.as_deref())
+            .await
+        {
+            Ok(page) => {
+                let resources: Vec<serde_json::Value> =
+                    page.threads.iter().map(threads::thread_resource).collect();
+                let mut result = json!({ "resources": resources });
+                if let Some(next_cursor) = page.next_cursor {
+                    result["nextCursor"] = json!(next_cursor);
+                }
+                self.outgoing.send_response(id, result).await;
+            }
+            Err(err) => {
+                self.outgoing
+                    .send_error(id, ErrorData::invalid_params(format!("{err:#}"), None))
+                    .await;
+            }
+        }
     }
 
-    fn handle_list_resource_templates(&self, params: Option<rmcp::model::PaginatedRequestParams>) {
+    async fn handle_list_resource_templates(
+        &self,
+        id: RequestId,
+        params: Option<rmcp::model::PaginatedRequestParams>,
+    ) {
         tracing::info!("resources/templates/list -> params: {:?}", params);
+        self.outgoing
+            .send_response(
+                id,
+                json!({ "resourceTemplates": [threads::thread_resource_template()] }),
+            )
+            .await;
     }
 
-    fn handle_read_resource(&self, params: rmcp::model::ReadResourceRequestParams) {
+    async fn handle_read_resource(
+        &self,
+        id: RequestId,
+        params: rmcp::model::ReadResourceRequestParams,
+    ) {
         tracing::info!("resources/read -> params: {:?}", params);
+        let uri = params.uri;
+        let Some(thread_id) = threads::parse_thread_uri(&uri) else {
+            self.outgoing
+                .send_error(
+                    id,
+                    ErrorData::resource_not_found(
+                        format!("unknown resource: {uri}"),
+                        Some(json!({ "uri": uri })),
+                    ),
+                )
+                .await;
+            return;
+        };
+        match threads::read_thread_markdown(&self.config, thread_id).await {
+            Ok(text) => {
+                self.outgoing
+                    .send_response(
+                        id,
+                        json!({
+                            "contents": [{
+                                "uri": uri,
+                                "mimeType": "text/markdown",
+                                "text": text,
+                            }],
+                        }),
+                    )
+                    .await;
+            }
+            Err(err) => {
+                self.outgoing
+                    .send_error(
+                        id,
+                        ErrorData::resource_not_found(
+                            format!("{err:#}"),
+                            Some(json!({ "uri": uri })),
+                        ),
+                    )
+                    .await;
+            }
+        }
     }
 
     fn handle_subscribe(&self, params: rmcp::model::SubscribeRequestParams) {
@@ -292,12 +389,31 @@ impl MessageProcessor {
         tracing::info!("resources/unsubscribe -> params: {:?}", params);
     }
 
-    fn handle_list_prompts(&self, params: Option<rmcp::model::PaginatedRequestParams>) {
+    async fn handle_list_prompts(
+        &self,
+        id: RequestId,
+        params: Option<rmcp::model::PaginatedRequestParams>,
+    ) {
         tracing::info!("prompts/list -> params: {:?}", params);
+        let prompts = prompts::list_prompts(&self.config, &self.thread_manager).await;
+        self.outgoing
+            .send_response(id, json!({ "prompts": prompts }))
+            .await;
     }
 
-    fn handle_get_prompt(&self, params: rmcp::model::GetPromptRequestParams) {
+    async fn handle_get_prompt(&self, id: RequestId, params: rmcp::model::GetPromptRequestParams) {
         tracing::info!("prompts/get -> params: {:?}", params);
+        match prompts::get_prompt(
+            &self.config,
+            &self.thread_manager,
+            &params.name,
+            params.arguments.as_ref(),
+        )
+        .await
+        {
+            Ok(result) => self.outgoing.send_response(id, result).await,
+            Err(err) => self.outgoing.send_error(id, err).await,
+        }
     }
 
     async fn handle_list_tools(
@@ -311,6 +427,9 @@ impl MessageProcessor {
             tools: vec![
                 create_tool_for_codex_tool_call_param(),
                 create_tool_for_codex_tool_call_reply_param(),
+                create_tool_for_list_threads(),
+                create_tool_for_interrupt_thread(),
+                create_tool_for_fork_thread(),
             ],
             next_cursor: None,
         };
@@ -330,6 +449,9 @@ impl MessageProcessor {
                 self.handle_tool_call_codex_session_reply(id, arguments)
                     .await
             }
+            "codex-list-threads" => self.handle_tool_call_list_threads(id, arguments).await,
+            "codex-interrupt" => self.handle_tool_call_interrupt(id, arguments).await,
+            "codex-fork" => self.handle_tool_call_fork(id, arguments).await,
             _ => {
                 let result = CallToolResult {
                     content: vec![rmcp::model::Content::text(format!("Unknown tool '{name}'"))],
@@ -514,6 +636,243 @@ impl MessageProcessor {
         });
     }
 
+    async fn handle_tool_call_list_threads(
+        &self,
+        id: RequestId,
+        arguments: Option<rmcp::model::JsonObject>,
+    ) {
+        let params = match parse_tool_arguments::<ListThreadsParam>(arguments) {
+            Ok(params) => params.unwrap_or_default(),
+            Err(result) => {
+                self.outgoing.send_response(id, result).await;
+                return;
+            }
+        };
+        let page = match threads::list_threads(
+            &self.config,
+            &self.thread_manager,
+            params.limit.map(|limit| limit as usize),
+            params.cursor.as_deref(),
+        )
+        .await
+        {
+            Ok(page) => page,
+            Err(err) => {
+                self.outgoing
+                    .send_response(id, error_tool_result(format!("{err:#}")))
+                    .await;
+                return;
+            }
+        };
+        let mut structured_content = json!({ "threads": page.threads });
+        if let Some(next_cursor) = page.next_cursor {
+            structured_content["nextCursor"] = json!(next_cursor);
+        }
+        let result = CallToolResult {
+            content: vec![rmcp::model::Content::text(structured_content.to_string())],
+            structured_content: Some(structured_content),
+            is_error: None,
+            meta: None,
+        };
+        self.outgoing.send_response(id, result).await;
+    }
+
+    async fn handle_tool_call_interrupt(
+        &self,
+        id: RequestId,
+        arguments: Option<rmcp::model::JsonObject>,
+    ) {
+        let params = match parse_tool_arguments::<InterruptThreadParam>(arguments) {
+            Ok(Some(params)) => params,
+            Ok(None) => {
+                self.outgoing
+                    .send_response(
+                        id,
+                        error_tool_result(
+                            "Missing arguments for codex-interrupt tool-call; the `threadId` field is required.",
+                        ),
+                    )
+                    .await;
+                return;
+            }
+            Err(result) => {
+                self.outgoing.send_response(id, result).await;
+                return;
+            }
+        };
+        let thread_id = match ThreadId::from_string(&params.thread_id) {
+            Ok(thread_id) => thread_id,
+            Err(err) => {
+                self.outgoing
+                    .send_response(
+                        id,
+                        error_tool_result(format!("Failed to parse threadId: {err}")),
+                    )
+                    .await;
+                return;
+            }
+        };
+        let result = match self.thread_manager.get_thread(thread_id).await {
+            Ok(thread) => match thread.submit(Op::Interrupt).await {
+                Ok(_) => create_call_tool_result_with_thread_id(
+                    thread_id,
+                    format!("Interrupted thread {thread_id}"),
+                    None,
+                ),
+                Err(err) => create_call_tool_result_with_thread_id(
+                    thread_id,
+                    format!("Failed to interrupt thread {thread_id}: {err}"),
+                    Some(true),
+                ),
+            },
+            Err(_) => create_call_tool_result_with_thread_id(
+                thread_id,
+                format!("Thread {thread_id} is not running in this server"),
+                Some(true),
+            ),
+        };
+        self.outgoing.send_response(id, result).await;
+    }
+
+    async fn handle_tool_call_fork(
+        &self,
+        request_id: RequestId,
+        arguments: Option<rmcp::model::JsonObject>,
+    ) {
+        let params = match parse_tool_arguments::<ForkThreadParam>(arguments) {
+            Ok(Some(params)) => params,
+            Ok(None) => {
+                self.outgoing
+                    .send_response(
+                        request_id,
+                        error_tool_result(
+                            "Missing arguments for codex-fork tool-call; the `threadId` field is required.",
+                        ),
+                    )
+                    .await;
+                return;
+            }
+            Err(result) => {
+                self.outgoing.send_response(request_id, result).await;
+                return;
+            }
+        };
+        let source_thread_id = match ThreadId::from_string(&params.thread_id) {
+            Ok(thread_id) => thread_id,
+            Err(err) => {
+                self.outgoing
+                    .send_response(
+                        request_id,
+                        error_tool_result(format!("Failed to parse threadId: {err}")),
+                    )
+                    .await;
+                return;
+            }
+        };
+
+        let loaded_rollout_path = match self.thread_manager.get_thread(source_thread_id).await {
+            Ok(thread) => thread.rollout_path(),
+            Err(_) => None,
+        };
+        let rollout_path = match loaded_rollout_path {
+            Some(path) => path,
+            None => match threads::find_rollout_path(&self.config, source_thread_id).await {
+                Ok(path) => path,
+                Err(err) => {
+                    self.outgoing
+                        .send_response(
+                            request_id,
+                            create_call_tool_result_with_thread_id(
+                                source_thread_id,
+                                format!("{err:#}"),
+                                Some(true),
+                            ),
+                        )
+                        .await;
+                    return;
+                }
+            },
+        };
+
+        // Fork into the source thread's working directory rather than the
+        // server's.
+        let cwd = read_session_meta_line(&rollout_path)
+            .await
+            .map(|meta_line| meta_line.meta.cwd)
+            .unwrap_or_else(|_| self.config.cwd.clone());
+        let overrides = ConfigOverrides {
+            cwd: Some(cwd),
+            codex_linux_sandbox_exe: self.codex_linux_sandbox_exe.clone(),
+            ..Default::default()
+        };
+        let config = match Config::load_with_cli_overrides_and_harness_overrides(
+            Vec::new(),
+            overrides,
+        )
+        .await
+        {
+            Ok(config) => config,
+            Err(err) => {
+                self.outgoing
+                    .send_response(
+                        request_id,
+                        error_tool_result(format!(
+                            "Failed to load Codex configuration for the fork: {err}"
+                        )),
+                    )
+                    .await;
+                return;
+            }
+        };
+
+        let NewThread {
+            thread_id, thread, ..
+        } = match self
+            .thread_manager
+            .fork_thread(usize::MAX, config, rollout_path)
+            .await
+        {
+            Ok(new_thread) => new_thread,
+            Err(err) => {
+                self.outgoing
+                    .send_response(
+                        request_id,
+                        create_call_tool_result_with_thread_id(
+                            source_thread_id,
+                            format!("Failed to fork thread {source_thread_id}: {err}"),
+                            Some(true),
+                        ),
+                    )
+                    .await;
+                return;
+            }
+        };
+
+        let Some(prompt) = params.prompt else {
+            let result = create_call_tool_result_with_thread_id(
+                thread_id,
+                format!("Forked thread {source_thread_id} into {thread_id}"),
+                None,
+            );
+            self.outgoing.send_response(request_id, result).await;
+            return;
+        };
+
+        let outgoing = self.outgoing.clone();
+        let running_requests_id_to_codex_uuid = self.running_requests_id_to_codex_uuid.clone();
+        tokio::spawn(async move {
+            crate::codex_tool_runner::run_codex_tool_session_reply(
+                thread_id,
+                thread,
+                outgoing,
+                request_id,
+                prompt,
+                running_requests_id_to_codex_uuid,
+            )
+            .await;
+        });
+    }
+
     fn handle_set_level(&self, params: rmcp::model::SetLevelRequestParams) {
         tracing::info!("logging/setLevel -> params: {:?}", params);
     }
@@ -596,3 +955,23 @@ impl MessageProcessor {
         tracing::info!("notifications/initialized");
     }
 }
+
+/// Parses tool-call arguments into `T`, returning the error result to send
+/// back when they do not match the tool's schema.
+fn parse_tool_arguments<T: serde::de::DeserializeOwned>(
+    arguments: Option<rmcp::model::JsonObject>,
+) -> Result<Option<T>, CallToolResult> {
+    arguments
+        .map(|arguments| serde_json::from_value(serde_json::Value::Object(arguments)))
+        .transpose()
+        .map_err(|err| error_tool_result(format!("Failed to parse tool arguments: {err}")))
+}
+
+fn error_tool_result(text: impl Into<String>) -> CallToolResult {
+    CallToolResult {
+        content: vec![rmcp::model::Content::text(text.into())],
+        structured_content: None,
+        is_error: Some(true),
+        meta: None,
+    }
+}
@@ -0,0 +1,238 @@
+//! The user's custom prompts (`$CODEX_HOME/prompts/*.md`) and enabled skills,
+//! exposed as MCP prompts named `prompts:<name>` and `skills:<name>`.
+
+use codex_core::ThreadManager;
+use codex_core::config::Config;
+use codex_core::custom_prompts::PROMPT_ARGUMENTS_PLACEHOLDER;
+use codex_core::custom_prompts::PromptPlaceholder;
+use codex_core::custom_prompts::discover_prompts_in;
+use codex_core::custom_prompts::expand_prompt_placeholders;
+use codex_core::custom_prompts::prompt_argument_names;
+use codex_core::custom_prompts::prompt_has_numeric_placeholders;
+use codex_protocol::custom_prompts::CustomPrompt;
+use codex_protocol::custom_prompts::PROMPTS_CMD_PREFIX;
+use rmcp::model::ErrorData;
+use rmcp::model::JsonObject;
+use serde_json::Value;
+use serde_json::json;
+
+const SKILLS_PROMPT_PREFIX: &str = "skills";
+
+const DEFAULT_ARGUMENTS_DESCRIPTION: &str = "Space-separated arguments for $1..$9 and $ARGUMENTS";
+
+pub(crate) async fn list_prompts(config: &Config, thread_manager: &ThreadManager) -> Vec<Value> {
+    let mut prompts: Vec<Value> = discover_prompts_in(&config.codex_home.join("prompts"))
+        .await
+        .iter()
+        .map(custom_prompt_entry)
+        .collect();
+
+    let outcome = thread_manager.skills_manager().skills_for_config(config);
+    prompts.extend(
+        outcome
+            .skills
+            .iter()
+            .filter(|skill| outcome.is_skill_enabled(skill))
+            .map(|skill| {
+                json!({
+                    "name": format!("{SKILLS_PROMPT_PREFIX}:{}", skill.name),
+                    "description": skill
+                        .short_description
+                        .clone()
+                        .unwrap_or_else(|| skill.description.clone()),
+                })
+            }),
+    );
+    prompts
+}
+
+fn custom_prompt_entry(prompt: &CustomPrompt) -> Value {
+    let mut arguments: Vec<Value> = prompt_argument_names(&prompt.content)
+        .into_iter()
+        .map(|name| json!({ "name": name, "required": true }))
+        .collect();
+    if prompt_has_numeric_placeholders(&prompt.content) {
+        arguments.push(json!({
+            "name": PROMPT_ARGUMENTS_PLACEHOLDER,
+            "description": prompt
+                .argument_hint
+                .clone()
+                .unwrap_or_else(|| DEFAULT_ARGUMENTS_DESCRIPTION.to_string()),
+            "required": false,
+        }));
+    }
+    let mut entry = json!({
+        "name": format!("{PROMPTS_CMD_PREFIX}:{}", prompt.name),
+        "arguments": arguments,
+    });
+    if let Some(description) = &prompt.description {
+        entry["description"] = json!(description);
+    }
+    entry
+}
+
+/// Resolves `prompts/get` into an MCP `GetPromptResult`.
+pub(crate) async fn get_prompt(
+    config: &Config,
+    thread_manager: &ThreadManager,
+    name: &str,
+    arguments: Option<&JsonObject>,
+) -> Result<Value, ErrorData> {
+    let unknown = || ErrorData::invalid_params(format!("unknown prompt: {name}"), None);
+    let (prefix, prompt_name) = name.split_once(':').ok_or_else(unknown)?;
+    let (description, text) = match prefix {
+        PROMPTS_CMD_PREFIX => {
+            let prompt = discover_prompts_in(&config.codex_home.join("prompts"))
+                .await
+                .into_iter()
+                .find(|prompt| prompt.name == prompt_name)
+                .ok_or_else(unknown)?;
+            let text = expand_custom_prompt(&prompt.content, arguments)?;
+            (prompt.description, text)
+        }
+        SKILLS_PROMPT_PREFIX => {
+            let outcome = thread_manager.skills_manager().skills_for_config(config);
+            let skill = outcome
+                .skills
+                .iter()
+                .find(|skill| skill.name == prompt_name && outcome.is_skill_enabled(skill))
+                .ok_or_else(unknown)?;
+            let text = tokio::fs::read_to_string(&skill.path)
+                .await
+                .map_err(|err| {
+                    ErrorData::internal_error(
+                        format!("failed to read skill {}: {err}", skill.path.display()),
+                        None,
+                    )
+                })?;
+            (Some(skill.description.clone()), text)
+        }
+        _ => return Err(unknown()),
+    };
+
+    let mut result = json!({
+        "messages": [{
+            "role": "user",
+            "content": { "type": "text", "text": text },
+        }],
+    });
+    if let Some(description) = description {
+        result["description"] = json!(description);
+    }
+    Ok(result)
+}
+
+fn argument_value(arguments: Option<&JsonObject>, name: &str) -> Option<String> {
+    match arguments?.get(name)? {
+        Value::String(value) => Some(value.clone()),
+        Value::Null => None,
+        other => Some(other.to_string()),
+    }
+}
+
+/// Expands named (`$NAME`) and positional (`$1..$9`, `$ARGUMENTS`)
+/// placeholders. Positional values come from the `ARGUMENTS` argument, split
+/// with shell quoting rules.
+fn expand_custom_prompt(
+    content: &str,
+    arguments: Option<&JsonObject>,
+) -> Result<String, ErrorData> {
+    let missing: Vec<String> = prompt_argument_names(content)
+        .into_iter()
+        .filter(|name| argument_value(arguments, name).is_none())
+        .collect();
+    if !missing.is_empty() {
+        return Err(ErrorData::invalid_params(
+            format!("missing prompt arguments: {}", missing.join(", ")),
+            None,
+        ));
+    }
+    let positional_raw =
+        argument_value(arguments, PROMPT_ARGUMENTS_PLACEHOLDER).unwrap_or_default();
+    let positional: Vec<String> = shlex::Shlex::new(&positional_raw).collect();
+
+    Ok(expand_prompt_placeholders(
+        content,
+        |out, placeholder| match placeholder {
+            PromptPlaceholder::Positional(index) => {
+                if let Some(value) = positional.get(index) {
+                    out.push_str(value);
+                }
+                true
+            }
+            PromptPlaceholder::Arguments => {
+                out.push_str(&positional.join(" "));
+                true
+            }
+            PromptPlaceholder::Named(name) => match argument_value(arguments, name) {
+                Some(value) => {
+                    out.push_str(&value);
+                    true
+                }
+                None => false,
+            },
+        },
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn args(value: Value) -> JsonObject {
+        match value {
+            Value::Object(map) => map,
+            _ => unreachable!("arguments must be an object"),
+        }
+    }
+
+    #[test]
+    fn expands_named_and_positional_placeholders() {
+        let arguments = args(json!({
+            "TICKET": "ENG-42",
+            "ARGUMENTS": "src/lib.rs \"docs/read me.md\"",
+        }));
+
+        let text = expand_custom_prompt(
+            "Fix $TICKET in $1 and $2. All: $ARGUMENTS. Cost: $$5.",
+            Some(&arguments),
+        )
+        .expect("expansion");
+
+        assert_eq!(
+            text,
+            "Fix ENG-42 in src/lib.rs and docs/read me.md. All: src/lib.rs docs/read me.md. Cost: $$5."
+        );
+    }
+
+    #[test]
+    fn missing_named_argument_is_an_error() {
+        let err = expand_custom_prompt("Fix $TICKET", None).expect_err("missing argument");
+
+        assert_eq!(err.message, "missing prompt arguments: TICKET");
+    }
+
+    #[test]
+    fn custom_prompt_entry_lists_arguments() {
+        let prompt = CustomPrompt {
+            name: "fix".to_string(),
+            path: "/tmp/fix.md".into(),
+            content: "Fix $TICKET using $1".to_string(),
+            description: Some("Fix a ticket".to_string()),
+            argument_hint: Some("[files]".to_string()),
+        };
+
+        assert_eq!(
+            custom_prompt_entry(&prompt),
+            json!({
+                "name": "prompts:fix",
+                "description": "Fix a ticket",
+                "arguments": [
+                    { "name": "TICKET", "required": true },
+                    { "name": "ARGUMENTS", "description": "[files]", "required": false },
+                ],
+            })
+        );
+    }
+}
@@ -0,0 +1,164 @@
+//! Parameters and tool definitions for the thread management tools:
+//! `codex-list-threads`, `codex-interrupt` and `codex-fork`.
+
+use std::sync::Arc;
+
+use rmcp::model::JsonObject;
+use rmcp::model::Tool;
+use schemars::JsonSchema;
+use schemars::r#gen::SchemaSettings;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::codex_tool_config::codex_tool_output_schema;
+use crate::codex_tool_config::create_tool_input_schema;
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ListThreadsParam {
+    /// Maximum number of threads to return (default 25, at most 100).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub limit: Option<u32>,
+
+    /// Cursor returned as `nextCursor` by a previous call.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cursor: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct InterruptThreadParam {
+    /// The thread whose current turn should be interrupted.
+    pub thread_id: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct ForkThreadParam {
+    /// The thread to fork. Stored threads that are not loaded can be forked too.
+    pub thread_id: String,
+
+    /// Optional prompt to run on the new thread right away.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub prompt: Option<String>,
+}
+
+fn input_schema<T: JsonSchema>(panic_message: &str) -> Arc<JsonObject> {
+    let schema = SchemaSettings::draft2019_09()
+        .with(|s| {
+            s.inline_subschemas = true;
+            s.option_add_null_type = false;
+        })
+        .into_generator()
+        .into_root_schema_for::<T>();
+    create_tool_input_schema(schema, panic_message)
+}
+
+fn list_threads_output_schema() -> Arc<JsonObject> {
+    let schema = serde_json::json!({
+        "type": "object",
+        "properties": {
+            "threads": {
+                "type": "array",
+                "items": {
+                    "type": "object",
+                    "properties": {
+                        "threadId": { "type": "string" },
+                        "preview": { "type": "string" },
+                        "cwd": { "type": "string" },
+                        "createdAt": { "type": "string" },
+                        "updatedAt": { "type": "string" },
+                        "state": { "type": "string", "enum": ["running", "idle", "stored"] }
+                    },
+                    "required": ["threadId", "preview", "state"]
+                }
+            },
+            "nextCursor": { "type": "string" }
+        },
+        "required": ["threads"],
+    });
+    match schema {
+        serde_json::Value::Object(map) => Arc::new(map),
+        _ => unreachable!("json literal must be an object"),
+    }
+}
+
+/// Builds the `codex-list-threads` tool definition.
+pub(crate) fn create_tool_for_list_threads() -> Tool {
+    Tool {
+        name: "codex-list-threads".into(),
+        title: Some("List Codex Threads".to_string()),
+        input_schema: input_schema::<ListThreadsParam>("list threads schema should serialize"),
+        output_schema: Some(list_threads_output_schema()),
+        description: Some(
+            "List Codex threads, most recently updated first. Each thread is also readable as the resource codex://threads/<threadId>."
+                .into(),
+        ),
+        annotations: None,
+        icons: None,
+        meta: None,
+    }
+}
+
+/// Builds the `codex-interrupt` tool definition.
+pub(crate) fn create_tool_for_interrupt_thread() -> Tool {
+    Tool {
+        name: "codex-interrupt".into(),
+        title: Some("Interrupt Codex Thread".to_string()),
+        input_schema: input_schema::<InterruptThreadParam>(
+            "interrupt thread schema should serialize",
+        ),
+        output_schema: Some(codex_tool_output_schema()),
+        description: Some("Interrupt the running turn of a Codex thread.".into()),
+        annotations: None,
+        icons: None,
+        meta: None,
+    }
+}
+
+/// Builds the `codex-fork` tool definition.
+pub(crate) fn create_tool_for_fork_thread() -> Tool {
+    Tool {
+        name: "codex-fork".into(),
+        title: Some("Fork Codex Thread".to_string()),
+        input_schema: input_schema::<ForkThreadParam>("fork thread schema should serialize"),
+        output_schema: Some(codex_tool_output_schema()),
+        description: Some(
+            "Start a new Codex thread with the full history of an existing one, optionally running a prompt on it."
+                .into(),
+        ),
+        annotations: None,
+        icons: None,
+        meta: None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn verify_fork_tool_json_schema() {
+        let tool = create_tool_for_fork_thread();
+        let tool_json = serde_json::to_value(&tool).expect("tool serializes");
+
+        assert_eq!(
+            tool_json["inputSchema"],
+            serde_json::json!({
+                "properties": {
+                    "prompt": {
+                        "description": "Optional prompt to run on the new thread right away.",
+                        "type": "string"
+                    },
+                    "threadId": {
+                        "description": "The thread to fork. Stored threads that are not loaded can be forked too.",
+                        "type": "string"
+                    }
+                },
+                "required": ["threadId"],
+                "type": "object"
+            })
+        );
+    }
+}
@@ -0,0 +1,221 @@
+//! Stored and running Codex threads, exposed as MCP resources and through the
+//! `codex-list-threads` tool.
+
+use std::path::PathBuf;
+
+use anyhow::Context;
+use anyhow::Result;
+use anyhow::anyhow;
+use codex_core::RolloutRecorder;
+use codex_core::ThreadManager;
+use codex_core::ThreadSortKey;
+use codex_core::config::Config;
+use codex_core::find_archived_thread_path_by_id_str;
+use codex_core::find_thread_path_by_id_str;
+use codex_core::parse_cursor;
+use codex_core::protocol::AgentStatus;
+use codex_core::thread_export::ThreadExportFormat;
+use codex_core::thread_export::export_thread;
+use codex_protocol::ThreadId;
+use serde::Serialize;
+use serde_json::Value;
+use serde_json::json;
+
+/// Scheme and prefix of thread resource URIs: `codex://threads/<id>`.
+pub(crate) const THREAD_URI_PREFIX: &str = "codex://threads/";
+
+/// Page size used when the client does not ask for one.
+pub(crate) const DEFAULT_THREAD_PAGE_SIZE: usize = 25;
+
+/// Upper bound on the page size a client can request.
+const MAX_THREAD_PAGE_SIZE: usize = 100;
+
+/// Longest first-message preview included in a thread summary.
+const PREVIEW_MAX_CHARS: usize = 120;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub(crate) enum ThreadState {
+    /// Loaded in this server and currently running a turn.
+    Running,
+    /// Loaded in this server and waiting for input.
+    Idle,
+    /// Only present on disk; `codex-reply` cannot continue it until forked.
+    Stored,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ThreadSummary {
+    pub(crate) thread_id: ThreadId,
+    pub(crate) preview: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) cwd: Option<PathBuf>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) created_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) updated_at: Option<String>,
+    pub(crate) state: ThreadState,
+}
+
+/// One page of threads, most recently updated first.
+pub(crate) struct ThreadPage {
+    pub(crate) threads: Vec<ThreadSummary>,
+    pub(crate) next_cursor: Option<String>,
+}
+
+pub(crate) async fn list_threads(
+    config: &Config,
+    thread_manager: &ThreadManager,
+    page_size: Option<usize>,
+    cursor: Option<&str>,
+) -> Result<ThreadPage> {
+    let cursor = cursor
+        .map(|token| parse_cursor(token).ok_or_else(|| anyhow!("invalid cursor: {token}")))
+        .transpose()?;
+    let page_size = page_size
+        .unwrap_or(DEFAULT_THREAD_PAGE_SIZE)
+        .clamp(1, MAX_THREAD_PAGE_SIZE);
+    let page = RolloutRecorder::list_threads(
+        config,
+        page_size,
+        cursor.as_ref(),
+        ThreadSortKey::UpdatedAt,
+        &[],
+        None,
+        config.model_provider_id.as_str(),
+    )
+    .await
+    .context("failed to list threads")?;
+
+    let mut threads = Vec::with_capacity(page.items.len());
+    for item in page.items {
+        let Some(thread_id) = item.thread_id else {
+            continue;
+        };
+        let state = match thread_manager.get_thread(thread_id).await {
+            Ok(thread) => match thread.agent_status().await {
+                AgentStatus::Running => ThreadState::Running,
+                _ => ThreadState::Idle,
+            },
+            Err(_) => ThreadState::Stored,
+        };
+        threads.push(ThreadSummary {
+            thread_id,
+            preview: preview(item.first_user_message.as_deref()),
+            cwd: item.cwd,
+            created_at: item.created_at,
+            updated_at: item.updated_at,
+            state,
+        });
+    }
+    let next_cursor = page
+        .next_cursor
+        .and_then(|cursor| serde_json::to_value(cursor).ok())
+        .and_then(|value| value.as_str().map(str::to_owned));
+    Ok(ThreadPage {
+        threads,
+        next_cursor,
+    })
+}
+
+fn preview(first_user_message: Option<&str>) -> String {
+    let line = first_user_message
+        .and_then(|message| message.lines().find(|line| !line.trim().is_empty()))
+        .unwrap_or_default()
+        .trim();
+    if line.chars().count() <= PREVIEW_MAX_CHARS {
+        return line.to_string();
+    }
+    let truncated: String = line.chars().take(PREVIEW_MAX_CHARS - 1).collect();
+    format!("{truncated}…")
+}
+
+pub(crate) fn thread_uri(thread_id: ThreadId) -> String {
+    format!("{THREAD_URI_PREFIX}{thread_id}")
+}
+
+/// Converts a thread summary into an MCP `Resource`.
+pub(crate) fn thread_resource(summary: &ThreadSummary) -> Value {
+    let title = if summary.preview.is_empty() {
+        summary.thread_id.to_string()
+    } else {
+        summary.preview.clone()
+    };
+    let mut resource = json!({
+        "uri": thread_uri(summary.thread_id),
+        "name": summary.thread_id.to_string(),
+        "title": title,
+        "mimeType": "text/markdown",
+    });
+    if let Some(cwd) = &summary.cwd {
+        resource["description"] = json!(format!("Codex thread in {}", cwd.display()));
+    }
+    resource
+}
+
+/// The `codex://threads/{threadId}` resource template.
+pub(crate) fn thread_resource_template() -> Value {
+    json!({
+        "uriTemplate": format!("{THREAD_URI_PREFIX}{{threadId}}"),
+        "name": "thread",
+        "title": "Codex thread",
+        "description": "Markdown transcript of a Codex thread.",
+        "mimeType": "text/markdown",
+    })
+}
+
+/// Parses the thread id out of a `codex://threads/<id>` URI.
+pub(crate) fn parse_thread_uri(uri: &str) -> Option<ThreadId> {
+    let id = uri.strip_prefix(THREAD_URI_PREFIX)?;
+    ThreadId::from_string(id.trim_end_matches('/')).ok()
+}
+
+/// Locates the rollout for `thread_id`, including archived threads.
+pub(crate) async fn find_rollout_path(config: &Config, thread_id: ThreadId) -> Result<PathBuf> {
+    let codex_home = &config.codex_home;
+    let thread_id_str = thread_id.to_string();
+    let path = match find_thread_path_by_id_str(codex_home, &thread_id_str).await? {
+        Some(path) => Some(path),
+        None => find_archived_thread_path_by_id_str(codex_home, &thread_id_str).await?,
+    };
+    path.ok_or_else(|| anyhow!("no rollout found for thread id {thread_id}"))
+}
+
+/// Renders the thread as a Markdown transcript, with configured secrets
+/// redacted.
+pub(crate) async fn read_thread_markdown(config: &Config, thread_id: ThreadId) -> Result<String> {
+    let path = find_rollout_path(config, thread_id).await?;
+    export_thread(config, &path, ThreadExportFormat::Markdown)
+        .await
+        .with_context(|| format!("failed to export thread {thread_id}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn parses_thread_uris() {
+        let thread_id = ThreadId::new();
+
+        assert_eq!(parse_thread_uri(&thread_uri(thread_id)), Some(thread_id));
+        assert_eq!(parse_thread_uri("codex://threads/not-a-uuid"), None);
+        assert_eq!(parse_thread_uri("file:///tmp/thread.md"), None);
+    }
+
+    #[test]
+    fn preview_uses_first_non_empty_line() {
+        assert_eq!(
+            preview(Some("\n  fix the build\nthen test")),
+            "fix the build"
+        );
+        assert_eq!(preview(None), "");
+
+        let long = "x".repeat(PREVIEW_MAX_CHARS + 10);
+        let shortened = preview(Some(&long));
+        assert_eq!(shortened.chars().count(), PREVIEW_MAX_CHARS);
+        assert!(shortened.ends_with('…'));
+    }
+}
@@ -166,6 +166,8 @@ impl McpProcess {
             result,
             &json!({
                 "capabilities": {
+                    "prompts": {},
+                    "resources": {},
                     "tools": {
                         "listChanged": true
                     }
@@ -213,7 +215,8 @@ impl McpProcess {
         .await
     }
 
-    async fn send_request(
+    /// Sends an arbitrary request and returns its id.
+    pub async fn send_request(
         &mut self,
         method: &str,
         params: Option<serde_json::Value>,
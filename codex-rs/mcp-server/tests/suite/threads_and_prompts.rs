@@ -0,0 +1,133 @@
+use std::path::Path;
+
+use mcp_test_support::McpProcess;
+use pretty_assertions::assert_eq;
+use rmcp::model::RequestId;
+use serde_json::json;
+use tempfile::TempDir;
+use tokio::time::timeout;
+
+const DEFAULT_READ_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(20);
+
+async fn start(codex_home: &Path) -> anyhow::Result<McpProcess> {
+    std::fs::write(
+        codex_home.join("config.toml"),
+        r#"
+model = "mock-model"
+
+[features]
+remote_models = false
+"#,
+    )?;
+    let mut mcp_process = McpProcess::new(codex_home).await?;
+    timeout(DEFAULT_READ_TIMEOUT, mcp_process.initialize()).await??;
+    Ok(mcp_process)
+}
+
+async fn request(
+    mcp_process: &mut McpProcess,
+    method: &str,
+    params: Option<serde_json::Value>,
+) -> anyhow::Result<serde_json::Value> {
+    let id = mcp_process.send_request(method, params).await?;
+    let response = timeout(
+        DEFAULT_READ_TIMEOUT,
+        mcp_process.read_stream_until_response_message(RequestId::Number(id)),
+    )
+    .await??;
+    Ok(response.result)
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn custom_prompts_are_listed_and_expanded() -> anyhow::Result<()> {
+    let codex_home = TempDir::new()?;
+    let prompts_dir = codex_home.path().join("prompts");
+    std::fs::create_dir_all(&prompts_dir)?;
+    std::fs::write(
+        prompts_dir.join("fix.md"),
+        "---\ndescription: Fix a ticket\n---\nFix $TICKET in $1.",
+    )?;
+    let mut mcp_process = start(codex_home.path()).await?;
+
+    let listed = request(&mut mcp_process, "prompts/list", None).await?;
+    assert_eq!(
+        listed["prompts"][0],
+        json!({
+            "name": "prompts:fix",
+            "description": "Fix a ticket",
+            "arguments": [
+                { "name": "TICKET", "required": true },
+                {
+                    "name": "ARGUMENTS",
+                    "description": "Space-separated arguments for $1..$9 and $ARGUMENTS",
+                    "required": false,
+                },
+            ],
+        })
+    );
+
+    let prompt = request(
+        &mut mcp_process,
+        "prompts/get",
+        Some(json!({
+            "name": "prompts:fix",
+            "arguments": { "TICKET": "ENG-42", "ARGUMENTS": "src/lib.rs" },
+        })),
+    )
+    .await?;
+    assert_eq!(
+        prompt,
+        json!({
+            "description": "Fix a ticket",
+            "messages": [{
+                "role": "user",
+                "content": { "type": "text", "text": "Fix ENG-42 in src/lib.rs." },
+            }],
+        })
+    );
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn threads_are_exposed_as_resources_and_tools() -> anyhow::Result<()> {
+    let codex_home = TempDir::new()?;
+    let mut mcp_process = start(codex_home.path()).await?;
+
+    let templates = request(&mut mcp_process, "resources/templates/list", None).await?;
+    assert_eq!(
+        templates["resourceTemplates"][0]["uriTemplate"],
+        json!("codex://threads/{threadId}")
+    );
+
+    let resources = request(&mut mcp_process, "resources/list", None).await?;
+    assert_eq!(resources, json!({ "resources": [] }));
+
+    let tools = request(&mut mcp_process, "tools/list", None).await?;
+    let names: Vec<&str> = tools["tools"]
+        .as_array()
+        .expect("tools array")
+        .iter()
+        .filter_map(|tool| tool["name"].as_str())
+        .collect();
+    assert_eq!(
+        names,
+        vec![
+            "codex",
+            "codex-reply",
+            "codex-list-threads",
+            "codex-interrupt",
+            "codex-fork",
+        ]
+    );
+
+    let listed = request(
+        &mut mcp_process,
+        "tools/call",
+        Some(json!({ "name": "codex-list-threads", "arguments": { "limit": 5 } })),
+    )
+    .await?;
+    assert_eq!(listed["structuredContent"], json!({ "threads": [] }));
+
+    Ok(())
+}
@@ -1,3 +1,4 @@
+use crate::audit::audit_query_from_params;
 use crate::config::NetworkMode;
 use crate::responses::json_response;
 use crate::responses::text_response;
@@ -22,7 +23,7 @@ use tracing::error;
 use tracing::info;
 
 pub async fn run_admin_api(state: Arc<NetworkProxyState>, addr: SocketAddr) -> Result<()> {
-    // Debug-only admin API (health/config/patterns/blocked + mode/reload). Policy is config-driven
+    // Debug-only admin API (health/config/patterns/blocked/audit + mode/reload). Policy is config-driven
     // and constraint-enforced; this endpoint should not become a second policy/approval plane.
     let listener = TcpListener::build()
         .bind(addr)
@@ -96,6 +97,21 @@ async fn handle_admin_request(
                 text_response(StatusCode::INTERNAL_SERVER_ERROR, "error")
             }
         },
+        ("GET", "/audit") => match state.audit_log() {
+            None => text_response(StatusCode::NOT_FOUND, "audit log not enabled"),
+            Some(audit_log) => {
+                match audit_query_from_params(req.uri().query(), audit_log.thread_id()) {
+                    Ok(query) => match audit_log.query(&query).await {
+                        Ok(entries) => json_response(&AuditResponse { entries }),
+                        Err(err) => {
+                            error!("failed to query audit log: {err}");
+                            text_response(StatusCode::INTERNAL_SERVER_ERROR, "error")
+                        }
+                    },
+                    Err(err) => text_response(StatusCode::BAD_REQUEST, &err),
+                }
+            }
+        },
         ("POST", "/mode") => {
             let mut body = req.into_body();
             let mut buf: Vec<u8> = Vec::new();
@@ -169,6 +185,11 @@ struct BlockedResponse<T> {
     blocked: T,
 }
 
+#[derive(Debug, Serialize)]
+struct AuditResponse<T> {
+    entries: T,
+}
+
 #[derive(Debug, Serialize)]
 struct ModeUpdateResponse {
     status: &'static str,
@@ -0,0 +1,290 @@
+//! Persistent audit trail of proxy decisions.
+//!
+//! Every allow/deny decision is handed to a background task that batches inserts into the
+//! `network_audit` table of the `codex-state` SQLite database, tagged with the thread that owns
+//! this proxy instance. Recording never blocks the request path: when the queue is full, entries
+//! are dropped rather than delaying traffic.
+
+use anyhow::Result;
+use codex_state::NetworkAuditDecision;
+use codex_state::NetworkAuditEntry;
+use codex_state::NetworkAuditQuery;
+use codex_state::NetworkAuditRow;
+use codex_state::StateRuntime;
+use std::sync::Arc;
+use std::sync::Once;
+use std::time::Duration;
+use time::OffsetDateTime;
+use tokio::sync::mpsc;
+use tracing::warn;
+
+const AUDIT_QUEUE_CAPACITY: usize = 512;
+const AUDIT_BATCH_SIZE: usize = 64;
+const AUDIT_FLUSH_INTERVAL: Duration = Duration::from_millis(250);
+const AUDIT_RETENTION: time::Duration = time::Duration::days(90);
+const DEFAULT_QUERY_LIMIT: usize = 100;
+const MAX_QUERY_LIMIT: usize = 1000;
+
+static RETENTION_CLEANUP: Once = Once::new();
+
+#[derive(Clone)]
+pub struct NetworkAuditLog {
+    state_db: Arc<StateRuntime>,
+    thread_id: Option<String>,
+    sender: mpsc::Sender<NetworkAuditEntry>,
+}
+
+impl std::fmt::Debug for NetworkAuditLog {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("NetworkAuditLog")
+            .field("thread_id", &self.thread_id)
+            .finish_non_exhaustive()
+    }
+}
+
+/// A single decision, before it is stamped with a timestamp and the owning thread id.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct NetworkAuditEvent {
+    pub protocol: String,
+    pub host: String,
+    pub port: Option<u16>,
+    pub method: Option<String>,
+    pub decision: NetworkAuditDecision,
+    pub reason: Option<String>,
+    pub client: Option<String>,
+}
+
+impl NetworkAuditLog {
+    /// Start the background writer. Must be called from within a Tokio runtime.
+    pub fn start(state_db: Arc<StateRuntime>, thread_id: Option<String>) -> Self {
+        let (sender, receiver) = mpsc::channel(AUDIT_QUEUE_CAPACITY);
+        tokio::spawn(run_inserter(Arc::clone(&state_db), receiver));
+        // Every session starts its own audit log; pruning once per process is enough.
+        RETENTION_CLEANUP.call_once(|| {
+            tokio::spawn(run_retention_cleanup(Arc::clone(&state_db)));
+        });
+        Self {
+            state_db,
+            thread_id,
+            sender,
+        }
+    }
+
+    pub fn thread_id(&self) -> Option<&str> {
+        self.thread_id.as_deref()
+    }
+
+    pub(crate) fn record(&self, event: NetworkAuditEvent) {
+        let now = OffsetDateTime::now_utc();
+        let NetworkAuditEvent {
+            protocol,
+            host,
+            port,
+            method,
+            decision,
+            reason,
+            client,
+        } = event;
+        let entry = NetworkAuditEntry {
+            ts: now.unix_timestamp(),
+            ts_nanos: i64::from(now.nanosecond()),
+            thread_id: self.thread_id.clone(),
+            protocol,
+            host,
+            port: port.map(i64::from),
+            method,
+            decision,
+            reason,
+            client,
+        };
+        if self.sender.try_send(entry).is_err() {
+            warn!("network audit queue is full; dropping entry");
+        }
+    }
+
+    pub(crate) async fn query(&self, query: &NetworkAuditQuery) -> Result<Vec<NetworkAuditRow>> {
+        self.state_db.query_network_audit(query).await
+    }
+}
+
+/// Builds a query from admin API parameters (`thread_id`, `host`, `decision`, `since`,
+/// `before_id`, `limit`). Results are newest first and, unless `thread_id` is given, scoped to
+/// `default_thread_id`; `thread_id=all` lifts the thread filter. Passing the last `id` of a page
+/// as `before_id` fetches the next, older page.
+pub(crate) fn audit_query_from_params(
+    params: Option<&str>,
+    default_thread_id: Option<&str>,
+) -> std::result::Result<NetworkAuditQuery, String> {
+    let mut query = NetworkAuditQuery {
+        limit: Some(DEFAULT_QUERY_LIMIT),
+        descending: true,
+        ..NetworkAuditQuery::default()
+    };
+    let mut all_threads = false;
+    for (key, value) in url::form_urlencoded::parse(params.unwrap_or_default().as_bytes()) {
+        match key.as_ref() {
+            "thread_id" if value == "all" => all_threads = true,
+            "thread_id" => query.thread_ids.push(value.into_owned()),
+            "host" => query.host_like.push(value.into_owned()),
+            "decision" => {
+                query.decision =
+                    Some(NetworkAuditDecision::parse(&value).map_err(|err| err.to_string())?);
+            }
+            "since" => query.from_ts = Some(parse_param(&key, &value)?),
+            "before_id" => query.before_id = Some(parse_param(&key, &value)?),
+            "limit" => {
+                let limit: usize = parse_param(&key, &value)?;
+                query.limit = Some(limit.min(MAX_QUERY_LIMIT));
+            }
+            _ => return Err(format!("unknown query parameter: {key}")),
+        }
+    }
+    if all_threads {
+        query.thread_ids.clear();
+    } else if query.thread_ids.is_empty()
+        && let Some(thread_id) = default_thread_id
+    {
+        query.thread_ids.push(thread_id.to_string());
+    }
+    Ok(query)
+}
+
+fn parse_param<T: std::str::FromStr>(key: &str, value: &str) -> std::result::Result<T, String> {
+    value
+        .parse()
+        .map_err(|_| format!("invalid value for {key}: {value}"))
+}
+
+async fn run_inserter(
+    state_db: Arc<StateRuntime>,
+    mut receiver: mpsc::Receiver<NetworkAuditEntry>,
+) {
+    let mut buffer = Vec::with_capacity(AUDIT_BATCH_SIZE);
+    let mut ticker = tokio::time::interval(AUDIT_FLUSH_INTERVAL);
+    loop {
+        tokio::select! {
+            maybe_entry = receiver.recv() => {
+                match maybe_entry {
+                    Some(entry) => {
+                        buffer.push(entry);
+                        if buffer.len() >= AUDIT_BATCH_SIZE {
+                            flush(&state_db, &mut buffer).await;
+                        }
+                    }
+                    None => {
+                        flush(&state_db, &mut buffer).await;
+                        break;
+                    }
+                }
+            }
+            _ = ticker.tick() => {
+                flush(&state_db, &mut buffer).await;
+            }
+        }
+    }
+}
+
+async fn flush(state_db: &StateRuntime, buffer: &mut Vec<NetworkAuditEntry>) {
+    if buffer.is_empty() {
+        return;
+    }
+    let entries = buffer.split_off(0);
+    if let Err(err) = state_db.insert_network_audit_entries(&entries).await {
+        warn!(
+            "failed to persist {} network audit entries: {err}",
+            entries.len()
+        );
+    }
+}
+
+async fn run_retention_cleanup(state_db: Arc<StateRuntime>) {
+    let cutoff = OffsetDateTime::now_utc() - AUDIT_RETENTION;
+    let _ = state_db
+        .delete_network_audit_before(cutoff.unix_timestamp())
+        .await;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn audit_query_defaults_to_owning_thread() {
+        let query = audit_query_from_params(None, Some("thread-1")).unwrap();
+        assert_eq!(query.thread_ids, vec!["thread-1".to_string()]);
+        assert_eq!(query.limit, Some(DEFAULT_QUERY_LIMIT));
+        assert!(query.descending);
+
+        let query = audit_query_from_params(
+            Some("thread_id=all&host=openai&decision=deny&since=100&limit=5000"),
+            Some("thread-1"),
+        )
+        .unwrap();
+        assert_eq!(query.thread_ids, Vec::<String>::new());
+        assert_eq!(query.host_like, vec!["openai".to_string()]);
+        assert_eq!(query.decision, Some(NetworkAuditDecision::Deny));
+        assert_eq!(query.from_ts, Some(100));
+        assert_eq!(query.limit, Some(MAX_QUERY_LIMIT));
+
+        let query = audit_query_from_params(Some("before_id=42"), None).unwrap();
+        assert_eq!((query.before_id, query.after_id), (Some(42), None));
+
+        assert_eq!(
+            audit_query_from_params(Some("decision=maybe"), None).unwrap_err(),
+            "invalid network audit decision: maybe"
+        );
+        assert_eq!(
+            audit_query_from_params(Some("limit=-1"), None).unwrap_err(),
+            "invalid value for limit: -1"
+        );
+    }
+
+    #[tokio::test]
+    async fn recorded_events_are_persisted_with_thread_id() {
+        let codex_home = tempfile::tempdir().unwrap();
+        let state_db = StateRuntime::init(
+            codex_home.path().to_path_buf(),
+            "test-provider".to_string(),
+            None,
+        )
+        .await
+        .unwrap();
+        let audit_log = NetworkAuditLog::start(Arc::clone(&state_db), Some("thread-1".to_string()));
+
+        audit_log.record(NetworkAuditEvent {
+            protocol: "http-connect".to_string(),
+            host: "example.com".to_string(),
+            port: Some(443),
+            method: Some("CONNECT".to_string()),
+            decision: NetworkAuditDecision::Deny,
+            reason: Some("not_allowed".to_string()),
+            client: None,
+        });
+
+        let query = NetworkAuditQuery {
+            thread_ids: vec!["thread-1".to_string()],
+            ..NetworkAuditQuery::default()
+        };
+        let mut rows = Vec::new();
+        for _ in 0..40 {
+            rows = audit_log.query(&query).await.unwrap();
+            if !rows.is_empty() {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(50)).await;
+        }
+
+        assert_eq!(rows.len(), 1);
+        let row = &rows[0];
+        assert_eq!(
+            (
+                row.host.as_str(),
+                row.port,
+                row.decision.as_str(),
+                row.reason.as_deref()
+            ),
+            ("example.com", Some(443), "deny", Some("not_allowed"))
+        );
+    }
+}
@@ -41,6 +41,9 @@ pub struct NetworkProxySettings {
     #[serde(default)]
     pub allow_unix_sockets: Vec<String>,
     pub allow_local_binding: bool,
+    /// Per-host method/path rules, evaluated after the domain allow/deny lists.
+    #[serde(default)]
+    pub host_rules: Vec<HostRule>,
 }
 
 impl Default for NetworkProxySettings {
@@ -60,10 +63,41 @@ impl Default for NetworkProxySettings {
             denied_domains: Vec::new(),
             allow_unix_sockets: Vec::new(),
             allow_local_binding: true,
+            host_rules: Vec::new(),
         }
     }
 }
 
+/// Narrows which methods and paths may be used against matching hosts.
+///
+/// Once any `allow` rule covers a host, requests to that host must match one of its allow rules,
+/// and a matching allow rule takes precedence over `mode`. `deny` rules always win.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct HostRule {
+    /// Domain pattern, using the same syntax as `allowed_domains`.
+    pub host: String,
+    #[serde(default)]
+    pub action: HostRuleAction,
+    /// HTTP methods the rule applies to (case-insensitive); empty means any method. Methods are
+    /// only visible for plain HTTP: opaque tunnels (HTTPS `CONNECT`, SOCKS5) are evaluated as
+    /// `CONNECT`, so an allow rule for an HTTPS host must list `CONNECT`, which then admits every
+    /// request inside the tunnel.
+    #[serde(default)]
+    pub methods: Vec<String>,
+    /// Request path globs (`*` also matches `/`); empty means any path. Rules with paths never
+    /// match opaque tunnels, whose paths the proxy cannot see.
+    #[serde(default)]
+    pub paths: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum HostRuleAction {
+    #[default]
+    Allow,
+    Deny,
+}
+
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default)]
 #[serde(rename_all = "lowercase")]
 pub enum NetworkMode {
@@ -345,6 +379,7 @@ mod tests {
                 denied_domains: Vec::new(),
                 allow_unix_sockets: Vec::new(),
                 allow_local_binding: true,
+                host_rules: Vec::new(),
             }
         );
     }
@@ -367,6 +402,39 @@ mod tests {
         assert_eq!(config.network, expected);
     }
 
+    #[test]
+    fn host_rules_deserialize_with_allow_default() {
+        let config: NetworkProxyConfig = serde_json::from_str(
+            r#"{
+                "network": {
+                    "host_rules": [
+                        { "host": "registry.npmjs.org", "methods": ["GET"] },
+                        { "host": "github.com", "action": "deny", "paths": ["/login"] }
+                    ]
+                }
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            config.network.host_rules,
+            vec![
+                HostRule {
+                    host: "registry.npmjs.org".to_string(),
+                    action: HostRuleAction::Allow,
+                    methods: vec!["GET".to_string()],
+                    paths: Vec::new(),
+                },
+                HostRule {
+                    host: "github.com".to_string(),
+                    action: HostRuleAction::Deny,
+                    methods: Vec::new(),
+                    paths: vec!["/login".to_string()],
+                },
+            ]
+        );
+    }
+
     #[test]
     fn parse_host_port_defaults_for_empty_string() {
         assert!(parse_host_port("", 1234).is_err());
@@ -1,4 +1,5 @@
 use crate::config::NetworkMode;
+use crate::network_policy::HostRuleDecision;
 use crate::network_policy::NetworkDecision;
 use crate::network_policy::NetworkDecisionSource;
 use crate::network_policy::NetworkPolicyDecider;
@@ -7,6 +8,7 @@ use crate::network_policy::NetworkPolicyRequest;
 use crate::network_policy::NetworkPolicyRequestArgs;
 use crate::network_policy::NetworkProtocol;
 use crate::network_policy::evaluate_host_policy;
+use crate::network_policy::evaluate_host_rules;
 use crate::policy::normalize_host;
 use crate::reasons::REASON_METHOD_NOT_ALLOWED;
 use crate::reasons::REASON_NOT_ALLOWED;
@@ -18,6 +20,7 @@ use crate::responses::blocked_text_response_with_policy;
 use crate::responses::json_response;
 use crate::responses::policy_decision_prefix;
 use crate::runtime::unix_socket_permissions_supported;
+use crate::state::AllowedRequest;
 use crate::state::BlockedRequest;
 use crate::state::BlockedRequestArgs;
 use crate::state::NetworkProxyState;
@@ -184,6 +187,7 @@ async fn http_connect_accept(
         port: authority.port,
         client_addr: client.clone(),
         method: Some("CONNECT".to_string()),
+        path: None,
         command: None,
         exec_policy_hint: None,
     });
@@ -205,6 +209,7 @@ async fn http_connect_accept(
             let _ = app_state
                 .record_blocked(BlockedRequest::new(BlockedRequestArgs {
                     host: host.clone(),
+                    port: Some(authority.port),
                     reason: reason.clone(),
                     client: client.clone(),
                     method: Some("CONNECT".to_string()),
@@ -226,12 +231,40 @@ async fn http_connect_accept(
         }
     }
 
+    let host_rule = evaluate_host_rules(&app_state, &request)
+        .await
+        .map_err(|err| internal_error("failed to evaluate host rules", err))?;
+    if let HostRuleDecision::Denied(reason) = host_rule {
+        let details = PolicyDecisionDetails {
+            decision: NetworkPolicyDecision::Deny,
+            reason,
+            source: NetworkDecisionSource::HostRule,
+            protocol: NetworkProtocol::HttpsConnect,
+            host: &host,
+            port: authority.port,
+        };
+        let _ = app_state
+            .record_blocked(BlockedRequest::new(BlockedRequestArgs {
+                host: host.clone(),
+                port: Some(authority.port),
+                reason: reason.to_string(),
+                client: client.clone(),
+                method: Some("CONNECT".to_string()),
+                mode: None,
+                protocol: "http-connect".to_string(),
+            }))
+            .await;
+        let client = client.as_deref().unwrap_or_default();
+        warn!("CONNECT blocked by host rule (client={client}, host={host}, reason={reason})");
+        return Err(blocked_text_with_details(reason, &details));
+    }
+
     let mode = app_state
         .network_mode()
         .await
         .map_err(|err| internal_error("failed to read network mode", err))?;
 
-    if mode == NetworkMode::Limited {
+    if mode == NetworkMode::Limited && host_rule != HostRuleDecision::Allowed {
         let details = PolicyDecisionDetails {
             decision: NetworkPolicyDecision::Deny,
             reason: REASON_METHOD_NOT_ALLOWED,
@@ -243,6 +276,7 @@ async fn http_connect_accept(
         let _ = app_state
             .record_blocked(BlockedRequest::new(BlockedRequestArgs {
                 host: host.clone(),
+                port: Some(authority.port),
                 reason: REASON_METHOD_NOT_ALLOWED.to_string(),
                 client: client.clone(),
                 method: Some("CONNECT".to_string()),
@@ -258,6 +292,14 @@ async fn http_connect_accept(
         ));
     }
 
+    app_state.record_allowed(AllowedRequest {
+        host,
+        port: Some(authority.port),
+        client,
+        method: Some("CONNECT".to_string()),
+        protocol: "http-connect".to_string(),
+    });
+
     req.extensions_mut().insert(ProxyTarget(authority));
     req.extensions_mut().insert(mode);
 
@@ -427,6 +469,13 @@ async fn http_plain_proxy(
 
         return match app_state.is_unix_socket_allowed(&socket_path).await {
             Ok(true) => {
+                app_state.record_allowed(AllowedRequest {
+                    host: socket_path.clone(),
+                    port: None,
+                    client: client.clone(),
+                    method: Some(req.method().as_str().to_string()),
+                    protocol: "http-unix-socket".to_string(),
+                });
                 let client = client.as_deref().unwrap_or_default();
                 info!("unix socket allowed (client={client}, path={socket_path})");
                 match proxy_via_unix_socket(req, &socket_path).await {
@@ -490,6 +539,7 @@ async fn http_plain_proxy(
         port,
         client_addr: client.clone(),
         method: Some(req.method().as_str().to_string()),
+        path: Some(req.uri().path().to_string()),
         command: None,
         exec_policy_hint: None,
     });
@@ -511,6 +561,7 @@ async fn http_plain_proxy(
             let _ = app_state
                 .record_blocked(BlockedRequest::new(BlockedRequestArgs {
                     host: host.clone(),
+                    port: Some(port),
                     reason: reason.clone(),
                     client: client.clone(),
                     method: Some(req.method().as_str().to_string()),
@@ -529,7 +580,44 @@ async fn http_plain_proxy(
         }
     }
 
-    if !method_allowed {
+    let host_rule = match evaluate_host_rules(&app_state, &request).await {
+        Ok(host_rule) => host_rule,
+        Err(err) => {
+            error!("failed to evaluate host rules for {host}: {err}");
+            return Ok(text_response(StatusCode::INTERNAL_SERVER_ERROR, "error"));
+        }
+    };
+    if let HostRuleDecision::Denied(reason) = host_rule {
+        let details = PolicyDecisionDetails {
+            decision: NetworkPolicyDecision::Deny,
+            reason,
+            source: NetworkDecisionSource::HostRule,
+            protocol: NetworkProtocol::Http,
+            host: &host,
+            port,
+        };
+        let _ = app_state
+            .record_blocked(BlockedRequest::new(BlockedRequestArgs {
+                host: host.clone(),
+                port: Some(port),
+                reason: reason.to_string(),
+                client: client.clone(),
+                method: Some(req.method().as_str().to_string()),
+                mode: None,
+                protocol: "http".to_string(),
+            }))
+            .await;
+        let client = client.as_deref().unwrap_or_default();
+        let method = req.method();
+        let path = req.uri().path();
+        warn!(
+            "request blocked by host rule (client={client}, host={host}, method={method}, path={path}, reason={reason})"
+        );
+        return Ok(json_blocked(&host, reason, Some(&details)));
+    }
+
+    // A matching host allow rule takes precedence over the global mode.
+    if !method_allowed && host_rule != HostRuleDecision::Allowed {
         let details = PolicyDecisionDetails {
             decision: NetworkPolicyDecision::Deny,
             reason: REASON_METHOD_NOT_ALLOWED,
@@ -541,6 +629,7 @@ async fn http_plain_proxy(
         let _ = app_state
             .record_blocked(BlockedRequest::new(BlockedRequestArgs {
                 host: host.clone(),
+                port: Some(port),
                 reason: REASON_METHOD_NOT_ALLOWED.to_string(),
                 client: client.clone(),
                 method: Some(req.method().as_str().to_string()),
@@ -560,6 +649,13 @@ async fn http_plain_proxy(
         ));
     }
 
+    app_state.record_allowed(AllowedRequest {
+        host: host.clone(),
+        port: Some(port),
+        client: client.clone(),
+        method: Some(req.method().as_str().to_string()),
+        protocol: "http".to_string(),
+    });
     let client = client.as_deref().unwrap_or_default();
     let method = req.method();
     info!("request allowed (client={client}, host={host}, method={method})");
@@ -662,6 +758,7 @@ async fn proxy_disabled_response(
     let _ = app_state
         .record_blocked(BlockedRequest::new(BlockedRequestArgs {
             host: blocked_host,
+            port: Some(port),
             reason: REASON_PROXY_DISABLED.to_string(),
             client,
             method,
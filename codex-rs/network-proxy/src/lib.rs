@@ -1,6 +1,7 @@
 #![deny(clippy::print_stdout, clippy::print_stderr)]
 
 mod admin;
+mod audit;
 mod config;
 mod http_proxy;
 mod network_policy;
@@ -13,6 +14,9 @@ mod socks5;
 mod state;
 mod upstream;
 
+pub use audit::NetworkAuditLog;
+pub use config::HostRule;
+pub use config::HostRuleAction;
 pub use config::NetworkMode;
 pub use config::NetworkProxyConfig;
 pub use config::host_and_port_from_network_addr;
@@ -32,6 +36,7 @@ pub use proxy::NetworkProxyHandle;
 pub use proxy::PROXY_URL_ENV_KEYS;
 pub use proxy::has_proxy_url_env_vars;
 pub use proxy::proxy_url_env_value;
+pub use reasons::REASON_USER_DENIED;
 pub use runtime::ConfigReloader;
 pub use runtime::ConfigState;
 pub use runtime::NetworkProxyState;
@@ -1,9 +1,18 @@
+use crate::config::HostRule;
+use crate::config::HostRuleAction;
+use crate::policy::compile_globset;
+use crate::reasons::REASON_HOST_RULE_DENIED;
+use crate::reasons::REASON_HOST_RULE_NOT_ALLOWED;
 use crate::reasons::REASON_POLICY_DENIED;
 use crate::runtime::HostBlockDecision;
 use crate::runtime::HostBlockReason;
 use crate::state::NetworkProxyState;
+use anyhow::Context;
 use anyhow::Result;
 use async_trait::async_trait;
+use globset::GlobBuilder;
+use globset::GlobSet;
+use globset::GlobSetBuilder;
 use std::future::Future;
 use std::sync::Arc;
 
@@ -45,6 +54,7 @@ impl NetworkPolicyDecision {
 pub enum NetworkDecisionSource {
     BaselinePolicy,
     ModeGuard,
+    HostRule,
     ProxyState,
     Decider,
 }
@@ -54,6 +64,7 @@ impl NetworkDecisionSource {
         match self {
             Self::BaselinePolicy => "baseline_policy",
             Self::ModeGuard => "mode_guard",
+            Self::HostRule => "host_rule",
             Self::ProxyState => "proxy_state",
             Self::Decider => "decider",
         }
@@ -67,6 +78,8 @@ pub struct NetworkPolicyRequest {
     pub port: u16,
     pub client_addr: Option<String>,
     pub method: Option<String>,
+    /// Request path, when the protocol exposes one (plain HTTP only).
+    pub path: Option<String>,
     pub command: Option<String>,
     pub exec_policy_hint: Option<String>,
 }
@@ -77,6 +90,7 @@ pub struct NetworkPolicyRequestArgs {
     pub port: u16,
     pub client_addr: Option<String>,
     pub method: Option<String>,
+    pub path: Option<String>,
     pub command: Option<String>,
     pub exec_policy_hint: Option<String>,
 }
@@ -89,6 +103,7 @@ impl NetworkPolicyRequest {
             port,
             client_addr,
             method,
+            path,
             command,
             exec_policy_hint,
         } = args;
@@ -98,6 +113,7 @@ impl NetworkPolicyRequest {
             port,
             client_addr,
             method,
+            path,
             command,
             exec_policy_hint,
         }
@@ -200,6 +216,126 @@ pub(crate) async fn evaluate_host_policy(
     }
 }
 
+/// Outcome of matching a request against `network.host_rules`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HostRuleDecision {
+    /// No host rule covers the request; `network.mode` decides.
+    Unmatched,
+    /// An allow rule covers the request and takes precedence over `network.mode`.
+    Allowed,
+    /// A deny rule matched, or the host has allow rules and none of them matched.
+    Denied(&'static str),
+}
+
+/// Compiled form of `network.host_rules`.
+#[derive(Clone, Debug, Default)]
+pub struct HostRuleSet {
+    rules: Vec<CompiledHostRule>,
+}
+
+#[derive(Clone, Debug)]
+struct CompiledHostRule {
+    hosts: GlobSet,
+    action: HostRuleAction,
+    methods: Vec<String>,
+    paths: Option<GlobSet>,
+}
+
+impl CompiledHostRule {
+    /// `path` is `None` for opaque tunnels, which only path-less rules can match.
+    fn matches(&self, method: &str, path: Option<&str>) -> bool {
+        let method_matches = self.methods.is_empty()
+            || self
+                .methods
+                .iter()
+                .any(|allowed| allowed.eq_ignore_ascii_case(method));
+        let path_matches = match (&self.paths, path) {
+            (None, _) => true,
+            (Some(paths), Some(path)) => paths.is_match(path),
+            (Some(_), None) => false,
+        };
+        method_matches && path_matches
+    }
+}
+
+impl HostRuleSet {
+    pub fn compile(rules: &[HostRule]) -> Result<Self> {
+        let rules = rules
+            .iter()
+            .map(|rule| {
+                let hosts = compile_globset(std::slice::from_ref(&rule.host))?;
+                let paths = if rule.paths.is_empty() {
+                    None
+                } else {
+                    let mut builder = GlobSetBuilder::new();
+                    for path in &rule.paths {
+                        builder.add(
+                            GlobBuilder::new(path)
+                                .literal_separator(false)
+                                .build()
+                                .with_context(|| format!("invalid host rule path: {path}"))?,
+                        );
+                    }
+                    Some(builder.build()?)
+                };
+                Ok(CompiledHostRule {
+                    hosts,
+                    action: rule.action,
+                    methods: rule.methods.clone(),
+                    paths,
+                })
+            })
+            .collect::<Result<_>>()?;
+        Ok(Self { rules })
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    /// Evaluate a request to `host`. Opaque tunnels pass `CONNECT` as the method and no path.
+    pub fn evaluate(&self, host: &str, method: &str, path: Option<&str>) -> HostRuleDecision {
+        let mut covered_by_allow_rule = false;
+        let mut allowed = false;
+        for rule in self.rules.iter().filter(|rule| rule.hosts.is_match(host)) {
+            let matches = rule.matches(method, path);
+            match rule.action {
+                HostRuleAction::Deny if matches => {
+                    return HostRuleDecision::Denied(REASON_HOST_RULE_DENIED);
+                }
+                HostRuleAction::Deny => {}
+                HostRuleAction::Allow => {
+                    covered_by_allow_rule = true;
+                    allowed |= matches;
+                }
+            }
+        }
+
+        match (covered_by_allow_rule, allowed) {
+            (false, _) => HostRuleDecision::Unmatched,
+            (true, true) => HostRuleDecision::Allowed,
+            (true, false) => HostRuleDecision::Denied(REASON_HOST_RULE_NOT_ALLOWED),
+        }
+    }
+}
+
+/// Evaluate the per-host method/path rules for a request whose host already passed
+/// [`evaluate_host_policy`].
+pub(crate) async fn evaluate_host_rules(
+    state: &NetworkProxyState,
+    request: &NetworkPolicyRequest,
+) -> Result<HostRuleDecision> {
+    let method = match request.protocol {
+        NetworkProtocol::Http => request.method.as_deref().unwrap_or("GET"),
+        NetworkProtocol::HttpsConnect | NetworkProtocol::Socks5Tcp | NetworkProtocol::Socks5Udp => {
+            "CONNECT"
+        }
+    };
+    state
+        .host_rule_decision(&request.host, method, request.path.as_deref())
+        .await
+}
+
 fn map_decider_decision(decision: NetworkDecision) -> NetworkDecision {
     match decision {
         NetworkDecision::Allow => NetworkDecision::Allow,
@@ -226,6 +362,127 @@ mod tests {
     use std::sync::atomic::AtomicUsize;
     use std::sync::atomic::Ordering;
 
+    fn host_rule(host: &str, action: HostRuleAction, methods: &[&str], paths: &[&str]) -> HostRule {
+        HostRule {
+            host: host.to_string(),
+            action,
+            methods: methods.iter().map(ToString::to_string).collect(),
+            paths: paths.iter().map(ToString::to_string).collect(),
+        }
+    }
+
+    #[test]
+    fn host_rules_restrict_methods_and_paths_per_host() {
+        let rules = HostRuleSet::compile(&[
+            host_rule("registry.npmjs.org", HostRuleAction::Allow, &["GET"], &[]),
+            host_rule("api.internal", HostRuleAction::Allow, &["get"], &[]),
+            host_rule(
+                "api.internal",
+                HostRuleAction::Allow,
+                &["POST"],
+                &["/v1/builds/*"],
+            ),
+            host_rule("**.github.com", HostRuleAction::Deny, &[], &["/login"]),
+        ])
+        .unwrap();
+
+        assert_eq!(
+            rules.evaluate("registry.npmjs.org", "GET", Some("/left-pad")),
+            HostRuleDecision::Allowed
+        );
+        assert_eq!(
+            rules.evaluate("registry.npmjs.org", "PUT", Some("/left-pad")),
+            HostRuleDecision::Denied(REASON_HOST_RULE_NOT_ALLOWED)
+        );
+        assert_eq!(
+            rules.evaluate("api.internal", "POST", Some("/v1/builds/42/retry")),
+            HostRuleDecision::Allowed
+        );
+        assert_eq!(
+            rules.evaluate("api.internal", "POST", Some("/v1/deploys")),
+            HostRuleDecision::Denied(REASON_HOST_RULE_NOT_ALLOWED)
+        );
+        assert_eq!(
+            rules.evaluate("github.com", "GET", Some("/login")),
+            HostRuleDecision::Denied(REASON_HOST_RULE_DENIED)
+        );
+        assert_eq!(
+            rules.evaluate("github.com", "GET", Some("/openai/codex")),
+            HostRuleDecision::Unmatched
+        );
+        assert_eq!(
+            rules.evaluate("example.com", "DELETE", Some("/")),
+            HostRuleDecision::Unmatched
+        );
+    }
+
+    #[test]
+    fn host_rules_with_paths_never_match_opaque_tunnels() {
+        let rules = HostRuleSet::compile(&[
+            host_rule("api.internal", HostRuleAction::Allow, &[], &["/v1/*"]),
+            host_rule("github.com", HostRuleAction::Deny, &[], &["/login"]),
+            host_rule(
+                "registry.npmjs.org",
+                HostRuleAction::Allow,
+                &["GET", "CONNECT"],
+                &[],
+            ),
+        ])
+        .unwrap();
+
+        assert_eq!(
+            rules.evaluate("api.internal", "CONNECT", None),
+            HostRuleDecision::Denied(REASON_HOST_RULE_NOT_ALLOWED)
+        );
+        assert_eq!(
+            rules.evaluate("github.com", "CONNECT", None),
+            HostRuleDecision::Unmatched
+        );
+        assert_eq!(
+            rules.evaluate("registry.npmjs.org", "CONNECT", None),
+            HostRuleDecision::Allowed
+        );
+    }
+
+    #[tokio::test]
+    async fn evaluate_host_rules_treats_tunnels_as_connect() {
+        let state = network_proxy_state_for_policy(NetworkProxySettings {
+            allowed_domains: vec!["registry.npmjs.org".to_string()],
+            host_rules: vec![host_rule(
+                "registry.npmjs.org",
+                HostRuleAction::Allow,
+                &["GET"],
+                &[],
+            )],
+            ..NetworkProxySettings::default()
+        });
+        let request = |protocol, method: Option<&str>| {
+            NetworkPolicyRequest::new(NetworkPolicyRequestArgs {
+                protocol,
+                host: "registry.npmjs.org".to_string(),
+                port: 443,
+                client_addr: None,
+                method: method.map(str::to_string),
+                path: None,
+                command: None,
+                exec_policy_hint: None,
+            })
+        };
+
+        assert_eq!(
+            evaluate_host_rules(&state, &request(NetworkProtocol::Http, Some("GET")))
+                .await
+                .unwrap(),
+            HostRuleDecision::Allowed
+        );
+        assert_eq!(
+            evaluate_host_rules(&state, &request(NetworkProtocol::Socks5Tcp, None))
+                .await
+                .unwrap(),
+            HostRuleDecision::Denied(REASON_HOST_RULE_NOT_ALLOWED)
+        );
+    }
+
     #[tokio::test]
     async fn evaluate_host_policy_invokes_decider_for_not_allowed() {
         let state = network_proxy_state_for_policy(NetworkProxySettings::default());
@@ -246,6 +503,7 @@ mod tests {
             port: 80,
             client_addr: None,
             method: Some("GET".to_string()),
+            path: None,
             command: None,
             exec_policy_hint: None,
         });
@@ -279,6 +537,7 @@ mod tests {
             port: 80,
             client_addr: None,
             method: Some("GET".to_string()),
+            path: None,
             command: None,
             exec_policy_hint: None,
         });
@@ -319,6 +578,7 @@ mod tests {
             port: 80,
             client_addr: None,
             method: Some("GET".to_string()),
+            path: None,
             command: None,
             exec_policy_hint: None,
         });
@@ -1,6 +1,9 @@
 pub(crate) const REASON_DENIED: &str = "denied";
+pub(crate) const REASON_HOST_RULE_DENIED: &str = "host_rule_denied";
+pub(crate) const REASON_HOST_RULE_NOT_ALLOWED: &str = "host_rule_not_allowed";
 pub(crate) const REASON_METHOD_NOT_ALLOWED: &str = "method_not_allowed";
 pub(crate) const REASON_NOT_ALLOWED: &str = "not_allowed";
 pub(crate) const REASON_NOT_ALLOWED_LOCAL: &str = "not_allowed_local";
 pub(crate) const REASON_POLICY_DENIED: &str = "policy_denied";
 pub(crate) const REASON_PROXY_DISABLED: &str = "proxy_disabled";
+pub const REASON_USER_DENIED: &str = "user_denied";
@@ -2,9 +2,12 @@ use crate::network_policy::NetworkDecisionSource;
 use crate::network_policy::NetworkPolicyDecision;
 use crate::network_policy::NetworkProtocol;
 use crate::reasons::REASON_DENIED;
+use crate::reasons::REASON_HOST_RULE_DENIED;
+use crate::reasons::REASON_HOST_RULE_NOT_ALLOWED;
 use crate::reasons::REASON_METHOD_NOT_ALLOWED;
 use crate::reasons::REASON_NOT_ALLOWED;
 use crate::reasons::REASON_NOT_ALLOWED_LOCAL;
+use crate::reasons::REASON_USER_DENIED;
 use rama_http::Body;
 use rama_http::Response;
 use rama_http::StatusCode;
@@ -64,6 +67,8 @@ pub fn blocked_header_value(reason: &str) -> &'static str {
         REASON_NOT_ALLOWED | REASON_NOT_ALLOWED_LOCAL => "blocked-by-allowlist",
         REASON_DENIED => "blocked-by-denylist",
         REASON_METHOD_NOT_ALLOWED => "blocked-by-method-policy",
+        REASON_HOST_RULE_DENIED | REASON_HOST_RULE_NOT_ALLOWED => "blocked-by-host-rule",
+        REASON_USER_DENIED => "blocked-by-user",
         _ => "blocked-by-policy",
     }
 }
@@ -78,6 +83,15 @@ pub fn blocked_message(reason: &str) -> &'static str {
         REASON_METHOD_NOT_ALLOWED => {
             "Codex blocked this request: method not allowed in limited mode."
         }
+        REASON_HOST_RULE_DENIED => {
+            "Codex blocked this request: method or path denied by a host rule."
+        }
+        REASON_HOST_RULE_NOT_ALLOWED => {
+            "Codex blocked this request: method or path not allowed by this host's rules."
+        }
+        REASON_USER_DENIED => {
+            "Codex blocked this request: the user denied network access to this domain."
+        }
         _ => "Codex blocked this request by network policy.",
     }
 }
@@ -1,5 +1,9 @@
+use crate::audit::NetworkAuditEvent;
+use crate::audit::NetworkAuditLog;
 use crate::config::NetworkMode;
 use crate::config::NetworkProxyConfig;
+use crate::network_policy::HostRuleDecision;
+use crate::network_policy::HostRuleSet;
 use crate::policy::Host;
 use crate::policy::is_loopback_host;
 use crate::policy::is_non_public_ip;
@@ -15,6 +19,7 @@ use crate::state::validate_policy_against_constraints;
 use anyhow::Context;
 use anyhow::Result;
 use async_trait::async_trait;
+use codex_state::NetworkAuditDecision;
 use codex_utils_absolute_path::AbsolutePathBuf;
 use globset::GlobSet;
 use serde::Serialize;
@@ -66,6 +71,7 @@ pub enum HostBlockDecision {
 #[derive(Clone, Debug, Serialize)]
 pub struct BlockedRequest {
     pub host: String,
+    pub port: Option<u16>,
     pub reason: String,
     pub client: Option<String>,
     pub method: Option<String>,
@@ -76,6 +82,7 @@ pub struct BlockedRequest {
 
 pub struct BlockedRequestArgs {
     pub host: String,
+    pub port: Option<u16>,
     pub reason: String,
     pub client: Option<String>,
     pub method: Option<String>,
@@ -87,6 +94,7 @@ impl BlockedRequest {
     pub fn new(args: BlockedRequestArgs) -> Self {
         let BlockedRequestArgs {
             host,
+            port,
             reason,
             client,
             method,
@@ -95,6 +103,7 @@ impl BlockedRequest {
         } = args;
         Self {
             host,
+            port,
             reason,
             client,
             method,
@@ -105,11 +114,20 @@ impl BlockedRequest {
     }
 }
 
+pub struct AllowedRequest {
+    pub host: String,
+    pub port: Option<u16>,
+    pub client: Option<String>,
+    pub method: Option<String>,
+    pub protocol: String,
+}
+
 #[derive(Clone)]
 pub struct ConfigState {
     pub config: NetworkProxyConfig,
     pub allow_set: GlobSet,
     pub deny_set: GlobSet,
+    pub host_rules: HostRuleSet,
     pub constraints: NetworkProxyConstraints,
     pub blocked: VecDeque<BlockedRequest>,
 }
@@ -129,6 +147,7 @@ pub trait ConfigReloader: Send + Sync {
 pub struct NetworkProxyState {
     state: Arc<RwLock<ConfigState>>,
     reloader: Arc<dyn ConfigReloader>,
+    audit_log: Option<NetworkAuditLog>,
 }
 
 impl std::fmt::Debug for NetworkProxyState {
@@ -144,6 +163,7 @@ impl Clone for NetworkProxyState {
         Self {
             state: self.state.clone(),
             reloader: self.reloader.clone(),
+            audit_log: self.audit_log.clone(),
         }
     }
 }
@@ -153,9 +173,20 @@ impl NetworkProxyState {
         Self {
             state: Arc::new(RwLock::new(state)),
             reloader,
+            audit_log: None,
         }
     }
 
+    /// Persist every allow/deny decision made through this state to `audit_log`.
+    pub fn with_audit_log(mut self, audit_log: NetworkAuditLog) -> Self {
+        self.audit_log = Some(audit_log);
+        self
+    }
+
+    pub fn audit_log(&self) -> Option<&NetworkAuditLog> {
+        self.audit_log.as_ref()
+    }
+
     pub async fn current_cfg(&self) -> Result<NetworkProxyConfig> {
         // Callers treat `NetworkProxyState` as a live view of policy. We reload-on-demand so edits to
         // `config.toml` (including Codex-managed writes) take effect without a restart.
@@ -275,6 +306,15 @@ impl NetworkProxyState {
     }
 
     pub async fn record_blocked(&self, entry: BlockedRequest) -> Result<()> {
+        self.record_audit(NetworkAuditEvent {
+            protocol: entry.protocol.clone(),
+            host: entry.host.clone(),
+            port: entry.port,
+            method: entry.method.clone(),
+            decision: NetworkAuditDecision::Deny,
+            reason: Some(entry.reason.clone()),
+            client: entry.client.clone(),
+        });
         self.reload_if_needed().await?;
         let mut guard = self.state.write().await;
         guard.blocked.push_back(entry);
@@ -284,6 +324,32 @@ impl NetworkProxyState {
         Ok(())
     }
 
+    /// Record a request that passed every policy check.
+    pub fn record_allowed(&self, request: AllowedRequest) {
+        let AllowedRequest {
+            host,
+            port,
+            client,
+            method,
+            protocol,
+        } = request;
+        self.record_audit(NetworkAuditEvent {
+            protocol,
+            host,
+            port,
+            method,
+            decision: NetworkAuditDecision::Allow,
+            reason: None,
+            client,
+        });
+    }
+
+    fn record_audit(&self, event: NetworkAuditEvent) {
+        if let Some(audit_log) = self.audit_log.as_ref() {
+            audit_log.record(event);
+        }
+    }
+
     /// Drain and return the buffered blocked-request entries in FIFO order.
     pub async fn drain_blocked(&self) -> Result<Vec<BlockedRequest>> {
         self.reload_if_needed().await?;
@@ -339,6 +405,17 @@ impl NetworkProxyState {
         Ok(guard.config.network.mode.allows_method(method))
     }
 
+    pub async fn host_rule_decision(
+        &self,
+        host: &str,
+        method: &str,
+        path: Option<&str>,
+    ) -> Result<HostRuleDecision> {
+        self.reload_if_needed().await?;
+        let guard = self.state.read().await;
+        Ok(guard.host_rules.evaluate(host, method, path))
+    }
+
     pub async fn allow_upstream_proxy(&self) -> Result<bool> {
         self.reload_if_needed().await?;
         let guard = self.state.read().await;
@@ -526,6 +603,8 @@ impl ConfigReloader for NoopReloader {
 mod tests {
     use super::*;
 
+    use crate::config::HostRule;
+    use crate::config::HostRuleAction;
     use crate::config::NetworkProxyConfig;
     use crate::config::NetworkProxySettings;
     use crate::policy::compile_globset;
@@ -731,6 +810,44 @@ mod tests {
         assert!(validate_policy_against_constraints(&config, &constraints).is_err());
     }
 
+    #[test]
+    fn validate_policy_against_constraints_disallows_write_host_rules_in_limited_mode() {
+        let constraints = NetworkProxyConstraints {
+            mode: Some(NetworkMode::Limited),
+            ..NetworkProxyConstraints::default()
+        };
+        let host_rule = |methods: &[&str]| HostRule {
+            host: "api.internal".to_string(),
+            action: HostRuleAction::Allow,
+            methods: methods.iter().map(ToString::to_string).collect(),
+            paths: Vec::new(),
+        };
+        let config = |host_rules| NetworkProxyConfig {
+            network: NetworkProxySettings {
+                enabled: true,
+                mode: NetworkMode::Limited,
+                host_rules,
+                ..NetworkProxySettings::default()
+            },
+        };
+
+        assert!(
+            validate_policy_against_constraints(
+                &config(vec![host_rule(&["get", "HEAD"])]),
+                &constraints
+            )
+            .is_ok()
+        );
+        assert!(
+            validate_policy_against_constraints(&config(vec![host_rule(&["POST"])]), &constraints)
+                .is_err()
+        );
+        assert!(
+            validate_policy_against_constraints(&config(vec![host_rule(&[])]), &constraints)
+                .is_err()
+        );
+    }
+
     #[test]
     fn validate_policy_against_constraints_disallows_widening_mode() {
         let constraints = NetworkProxyConstraints {
@@ -1,4 +1,5 @@
 use crate::config::NetworkMode;
+use crate::network_policy::HostRuleDecision;
 use crate::network_policy::NetworkDecision;
 use crate::network_policy::NetworkDecisionSource;
 use crate::network_policy::NetworkPolicyDecider;
@@ -7,11 +8,13 @@ use crate::network_policy::NetworkPolicyRequest;
 use crate::network_policy::NetworkPolicyRequestArgs;
 use crate::network_policy::NetworkProtocol;
 use crate::network_policy::evaluate_host_policy;
+use crate::network_policy::evaluate_host_rules;
 use crate::policy::normalize_host;
 use crate::reasons::REASON_METHOD_NOT_ALLOWED;
 use crate::reasons::REASON_PROXY_DISABLED;
 use crate::responses::PolicyDecisionDetails;
 use crate::responses::blocked_message_with_policy;
+use crate::state::AllowedRequest;
 use crate::state::BlockedRequest;
 use crate::state::BlockedRequestArgs;
 use crate::state::NetworkProxyState;
@@ -163,6 +166,7 @@ async fn handle_socks5_tcp(
             let _ = app_state
                 .record_blocked(BlockedRequest::new(BlockedRequestArgs {
                     host: host.clone(),
+                    port: Some(port),
                     reason: REASON_PROXY_DISABLED.to_string(),
                     client: client.clone(),
                     method: None,
@@ -193,6 +197,7 @@ async fn handle_socks5_tcp(
             let _ = app_state
                 .record_blocked(BlockedRequest::new(BlockedRequestArgs {
                     host: host.clone(),
+                    port: Some(port),
                     reason: REASON_METHOD_NOT_ALLOWED.to_string(),
                     client: client.clone(),
                     method: None,
@@ -219,6 +224,7 @@ async fn handle_socks5_tcp(
         port,
         client_addr: client.clone(),
         method: None,
+        path: None,
         command: None,
         exec_policy_hint: None,
     });
@@ -240,6 +246,7 @@ async fn handle_socks5_tcp(
             let _ = app_state
                 .record_blocked(BlockedRequest::new(BlockedRequestArgs {
                     host: host.clone(),
+                    port: Some(port),
                     reason: reason.clone(),
                     client: client.clone(),
                     method: None,
@@ -261,6 +268,15 @@ async fn handle_socks5_tcp(
         }
     }
 
+    enforce_host_rules(&app_state, &request, "socks5").await?;
+    app_state.record_allowed(AllowedRequest {
+        host,
+        port: Some(port),
+        client,
+        method: None,
+        protocol: "socks5".to_string(),
+    });
+
     tcp_connector.serve(req).await
 }
 
@@ -300,6 +316,7 @@ async fn inspect_socks5_udp(
             let _ = state
                 .record_blocked(BlockedRequest::new(BlockedRequestArgs {
                     host: host.clone(),
+                    port: Some(port),
                     reason: REASON_PROXY_DISABLED.to_string(),
                     client: client.clone(),
                     method: None,
@@ -330,6 +347,7 @@ async fn inspect_socks5_udp(
             let _ = state
                 .record_blocked(BlockedRequest::new(BlockedRequestArgs {
                     host: host.clone(),
+                    port: Some(port),
                     reason: REASON_METHOD_NOT_ALLOWED.to_string(),
                     client: client.clone(),
                     method: None,
@@ -352,6 +370,7 @@ async fn inspect_socks5_udp(
         port,
         client_addr: client.clone(),
         method: None,
+        path: None,
         command: None,
         exec_policy_hint: None,
     });
@@ -373,6 +392,7 @@ async fn inspect_socks5_udp(
             let _ = state
                 .record_blocked(BlockedRequest::new(BlockedRequestArgs {
                     host: host.clone(),
+                    port: Some(port),
                     reason: reason.clone(),
                     client: client.clone(),
                     method: None,
@@ -384,10 +404,20 @@ async fn inspect_socks5_udp(
             warn!("SOCKS UDP blocked (client={client}, host={host}, reason={reason})");
             Err(policy_denied_error(&reason, &details))
         }
-        Ok(NetworkDecision::Allow) => Ok(RelayResponse {
-            maybe_payload: Some(payload),
-            extensions,
-        }),
+        Ok(NetworkDecision::Allow) => {
+            enforce_host_rules(&state, &request, "socks5-udp").await?;
+            state.record_allowed(AllowedRequest {
+                host,
+                port: Some(port),
+                client,
+                method: None,
+                protocol: "socks5-udp".to_string(),
+            });
+            Ok(RelayResponse {
+                maybe_payload: Some(payload),
+                extensions,
+            })
+        }
         Err(err) => {
             error!("failed to evaluate UDP host: {err}");
             Err(io::Error::other("proxy error"))
@@ -395,6 +425,46 @@ async fn inspect_socks5_udp(
     }
 }
 
+/// SOCKS5 traffic is opaque, so hosts covered by host rules are only reachable through rules that
+/// allow `CONNECT`.
+async fn enforce_host_rules(
+    state: &NetworkProxyState,
+    request: &NetworkPolicyRequest,
+    protocol_label: &str,
+) -> io::Result<()> {
+    let reason = match evaluate_host_rules(state, request).await {
+        Ok(HostRuleDecision::Denied(reason)) => reason,
+        Ok(HostRuleDecision::Allowed | HostRuleDecision::Unmatched) => return Ok(()),
+        Err(err) => {
+            error!("failed to evaluate host rules: {err}");
+            return Err(io::Error::other("proxy error"));
+        }
+    };
+    let details = PolicyDecisionDetails {
+        decision: NetworkPolicyDecision::Deny,
+        reason,
+        source: NetworkDecisionSource::HostRule,
+        protocol: request.protocol,
+        host: &request.host,
+        port: request.port,
+    };
+    let _ = state
+        .record_blocked(BlockedRequest::new(BlockedRequestArgs {
+            host: request.host.clone(),
+            port: Some(request.port),
+            reason: reason.to_string(),
+            client: request.client_addr.clone(),
+            method: None,
+            mode: None,
+            protocol: protocol_label.to_string(),
+        }))
+        .await;
+    let client = request.client_addr.as_deref().unwrap_or_default();
+    let host = &request.host;
+    warn!("SOCKS blocked by host rule (client={client}, host={host}, reason={reason})");
+    Err(policy_denied_error(reason, &details))
+}
+
 fn policy_denied_error(reason: &str, details: &PolicyDecisionDetails<'_>) -> io::Error {
     io::Error::new(
         io::ErrorKind::PermissionDenied,
@@ -1,11 +1,14 @@
+use crate::config::HostRuleAction;
 use crate::config::NetworkMode;
 use crate::config::NetworkProxyConfig;
+use crate::network_policy::HostRuleSet;
 use crate::policy::DomainPattern;
 use crate::policy::compile_globset;
 use crate::runtime::ConfigState;
 use serde::Deserialize;
 use std::collections::HashSet;
 
+pub use crate::runtime::AllowedRequest;
 pub use crate::runtime::BlockedRequest;
 pub use crate::runtime::BlockedRequestArgs;
 pub use crate::runtime::NetworkProxyState;
@@ -54,10 +57,12 @@ pub fn build_config_state(
 ) -> anyhow::Result<ConfigState> {
     let deny_set = compile_globset(&config.network.denied_domains)?;
     let allow_set = compile_globset(&config.network.allowed_domains)?;
+    let host_rules = HostRuleSet::compile(&config.network.host_rules)?;
     Ok(ConfigState {
         config,
         allow_set,
         deny_set,
+        host_rules,
         constraints,
         blocked: std::collections::VecDeque::new(),
     })
@@ -115,6 +120,33 @@ pub fn validate_policy_against_constraints(
         })?;
     }
 
+    if constraints.mode == Some(NetworkMode::Limited) {
+        // Matching allow rules take precedence over `mode`, so they must not grant write
+        // methods (or opaque tunnels) when managed config pins the proxy to limited mode.
+        validate(config.network.host_rules.clone(), |candidate| {
+            let invalid: Vec<String> = candidate
+                .iter()
+                .filter(|rule| rule.action == HostRuleAction::Allow)
+                .filter(|rule| {
+                    rule.methods.is_empty()
+                        || rule.methods.iter().any(|method| {
+                            !NetworkMode::Limited.allows_method(&method.to_ascii_uppercase())
+                        })
+                })
+                .map(|rule| rule.host.clone())
+                .collect();
+            if invalid.is_empty() {
+                Ok(())
+            } else {
+                Err(invalid_value(
+                    "network.host_rules",
+                    format!("{invalid:?}"),
+                    "allow rules limited to GET, HEAD, or OPTIONS (mode pinned to limited by managed config)",
+                ))
+            }
+        })?;
+    }
+
     let allow_upstream_proxy = constraints.allow_upstream_proxy;
     validate(
         config.network.allow_upstream_proxy,
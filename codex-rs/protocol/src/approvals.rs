@@ -59,6 +59,44 @@ pub struct ExecApprovalRequestEvent {
     pub parsed_cmd: Vec<ParsedCommand>,
 }
 
+/// Raised by the network proxy when a sandboxed process tries to reach a host
+/// that is not in `network.allowed_domains`. The connection stays paused until
+/// the client answers with `Op::NetworkApproval`.
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema, TS)]
+pub struct NetworkApprovalRequestEvent {
+    /// Identifier used to answer this request.
+    pub id: String,
+    /// Turn ID whose sandboxed process triggered the request.
+    #[serde(default)]
+    pub turn_id: String,
+    /// Destination host, normalized to lowercase.
+    pub host: String,
+    /// Destination port.
+    pub port: u16,
+    /// Proxy protocol that carried the request (`http`, `https_connect`, `socks5_tcp`, `socks5_udp`).
+    pub protocol: String,
+    /// HTTP method, when the protocol exposes one.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[ts(optional)]
+    pub method: Option<String>,
+}
+
+/// User's decision in response to a `NetworkApprovalRequestEvent`.
+#[derive(Debug, Default, Clone, Copy, Deserialize, Serialize, PartialEq, Eq, JsonSchema, TS)]
+#[serde(rename_all = "snake_case")]
+pub enum NetworkApprovalDecision {
+    /// Allow only the pending connection.
+    AllowOnce,
+    /// Allow this host for the remainder of the session.
+    AllowForSession,
+    /// Allow this host for the session and add it to `network.allowed_domains`
+    /// in the user's `config.toml`.
+    AllowAndPersist,
+    /// Block the pending connection.
+    #[default]
+    Deny,
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize, JsonSchema, TS)]
 pub struct ElicitationRequestEvent {
     pub server_name: String,
@@ -78,6 +116,41 @@ pub enum ElicitationAction {
     Cancel,
 }
 
+/// An MCP server asks Codex to run a completion (`sampling/createMessage`) on
+/// its behalf. The request only proceeds once the user approves it.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, JsonSchema, TS)]
+pub struct SamplingRequestEvent {
+    pub server_name: String,
+    #[ts(type = "string | number")]
+    pub id: RequestId,
+    /// Conversation the server wants completed.
+    pub messages: Vec<SamplingMessage>,
+    /// System prompt requested by the server, if any.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[ts(optional)]
+    pub system_prompt: Option<String>,
+    /// Output cap for the completion, after applying the server's configured
+    /// `sampling.max_tokens`.
+    pub max_tokens: u32,
+}
+
+/// One message of a sampling request, reduced to its text.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, JsonSchema, TS)]
+pub struct SamplingMessage {
+    /// `user` or `assistant`.
+    pub role: String,
+    pub text: String,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq, Eq, JsonSchema, TS)]
+#[serde(rename_all = "lowercase")]
+pub enum SamplingDecision {
+    /// Run the completion with the session's model and return it to the server.
+    Approve,
+    /// Reject the request; the server receives an error.
+    Decline,
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize, JsonSchema, TS)]
 pub struct ApplyPatchApprovalRequestEvent {
     /// Responses API call id for the associated patch apply call, if available.
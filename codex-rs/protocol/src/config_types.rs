@@ -1,4 +1,11 @@
+use std::collections::HashSet;
+use std::sync::Mutex;
+use std::sync::OnceLock;
+use std::sync::PoisonError;
+
 use schemars::JsonSchema;
+use schemars::r#gen::SchemaGenerator;
+use schemars::schema::Schema;
 use serde::Deserialize;
 use serde::Serialize;
 use strum_macros::Display;
@@ -49,13 +56,69 @@ pub enum Verbosity {
     High,
 }
 
-#[derive(Debug, Serialize, Default, Clone, Copy, PartialEq, Eq, Display, JsonSchema, TS)]
-#[serde(rename_all = "kebab-case")]
-#[strum(serialize_all = "kebab-case")]
+/// UI language. English and Simplified Chinese are built in; any other locale is served by a
+/// catalog loaded from `$CODEX_HOME/i18n/<tag>.toml` at startup.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Language {
     #[default]
     En,
     ZhCn,
+    /// A locale without a built-in catalog, e.g. `ja` or `zh-tw`.
+    Locale(LocaleTag),
+}
+
+impl Language {
+    /// Parses a locale tag such as `zh_CN`, `zh-Hant` or `ja`. Returns `None` when `raw` is not
+    /// a well-formed tag.
+    pub fn parse(raw: &str) -> Option<Self> {
+        let normalized = raw.trim().replace('_', "-").to_lowercase();
+        match normalized.as_str() {
+            "en" | "en-us" => Some(Self::En),
+            "zh-cn" | "zh-hans" | "zh" => Some(Self::ZhCn),
+            "zh-hant" => Some(Self::Locale(LocaleTag::intern("zh-tw"))),
+            tag if is_valid_locale_tag(tag) => Some(Self::Locale(LocaleTag::intern(tag))),
+            _ => None,
+        }
+    }
+
+    /// Canonical lowercase tag, as written to `config.toml`.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::En => "en",
+            Self::ZhCn => "zh-cn",
+            Self::Locale(tag) => tag.as_str(),
+        }
+    }
+
+    /// The next language to consult when a key is missing, e.g. `zh-tw` → `zh-cn` → `en`.
+    /// Drops the last subtag of the locale; `en` ends the chain.
+    pub fn parent(self) -> Option<Self> {
+        match self {
+            Self::En => None,
+            Self::ZhCn => Some(Self::En),
+            Self::Locale(tag) => Some(
+                tag.as_str()
+                    .rsplit_once('-')
+                    .and_then(|(parent, _)| Self::parse(parent))
+                    .unwrap_or(Self::En),
+            ),
+        }
+    }
+}
+
+impl std::fmt::Display for Language {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Serialize for Language {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
 }
 
 impl<'de> Deserialize<'de> for Language {
@@ -64,13 +127,61 @@ impl<'de> Deserialize<'de> for Language {
         D: serde::Deserializer<'de>,
     {
         let raw = String::deserialize(deserializer)?;
-        let normalized = raw.trim().to_lowercase();
-        Ok(match normalized.as_str() {
-            "en" | "en-us" | "en_us" => Self::En,
-            "zh-cn" | "zh_cn" | "zh-hans" | "zh" => Self::ZhCn,
-            _ => Self::En,
-        })
+        Ok(Self::parse(&raw).unwrap_or_default())
+    }
+}
+
+impl JsonSchema for Language {
+    fn schema_name() -> String {
+        "Language".to_string()
+    }
+
+    fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+        let mut schema = <String>::json_schema(generator).into_object();
+        schema.metadata().description = Some(
+            "Locale tag such as `en`, `zh-cn` or `ja`. Locales other than `en` and `zh-cn` need a catalog in `$CODEX_HOME/i18n/<tag>.toml`."
+                .to_string(),
+        );
+        schema.into()
+    }
+}
+
+/// Interned, lowercase locale tag. Interning keeps [`Language`] `Copy`; the set of locales a
+/// process sees is small and fixed at startup.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LocaleTag(&'static str);
+
+impl LocaleTag {
+    fn intern(tag: &str) -> Self {
+        static INTERNED: OnceLock<Mutex<HashSet<&'static str>>> = OnceLock::new();
+        let mut interned = INTERNED
+            .get_or_init(Default::default)
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        if let Some(existing) = interned.get(tag) {
+            return Self(existing);
+        }
+        let leaked: &'static str = Box::leak(tag.to_string().into_boxed_str());
+        interned.insert(leaked);
+        Self(leaked)
     }
+
+    pub fn as_str(self) -> &'static str {
+        self.0
+    }
+}
+
+/// Accepts simplified BCP 47 tags: a 2–3 letter language followed by alphanumeric subtags.
+fn is_valid_locale_tag(tag: &str) -> bool {
+    let mut parts = tag.split('-');
+    let Some(language) = parts.next() else {
+        return false;
+    };
+    (2..=3).contains(&language.len())
+        && language.chars().all(|c| c.is_ascii_lowercase())
+        && parts.all(|part| {
+            (2..=8).contains(&part.len()) && part.chars().all(|c| c.is_ascii_alphanumeric())
+        })
 }
 
 #[derive(
@@ -332,6 +443,21 @@ mod tests {
     use super::*;
     use pretty_assertions::assert_eq;
 
+    #[test]
+    fn language_parses_locale_tags_and_falls_back_to_parents() {
+        let zh_tw = Language::parse("zh_TW").expect("zh-tw parses");
+        assert_eq!(zh_tw.as_str(), "zh-tw");
+        assert_eq!(Language::parse("zh-Hant"), Some(zh_tw));
+        assert_eq!(zh_tw.parent(), Some(Language::ZhCn));
+        assert_eq!(Language::ZhCn.parent(), Some(Language::En));
+        assert_eq!(Language::parse("ja").and_then(Language::parent), Some(Language::En));
+        assert_eq!(Language::En.parent(), None);
+        assert_eq!(Language::parse("not a tag"), None);
+
+        let configured: Language = serde_json::from_str("\"pt-BR\"").expect("deserialize");
+        assert_eq!(serde_json::to_string(&configured).expect("serialize"), "\"pt-br\"");
+    }
+
     #[test]
     fn apply_mask_can_clear_optional_fields() {
         let mode = CollaborationMode {
@@ -24,6 +24,8 @@ use crate::dynamic_tools::DynamicToolResponse;
 use crate::dynamic_tools::DynamicToolSpec;
 use crate::items::TurnItem;
 use crate::mcp::CallToolResult;
+use crate::mcp::GetPromptResult as McpGetPromptResult;
+use crate::mcp::Prompt as McpPrompt;
 use crate::mcp::RequestId;
 use crate::mcp::Resource as McpResource;
 use crate::mcp::ResourceTemplate as McpResourceTemplate;
@@ -53,6 +55,11 @@ pub use crate::approvals::ApplyPatchApprovalRequestEvent;
 pub use crate::approvals::ElicitationAction;
 pub use crate::approvals::ExecApprovalRequestEvent;
 pub use crate::approvals::ExecPolicyAmendment;
+pub use crate::approvals::NetworkApprovalDecision;
+pub use crate::approvals::NetworkApprovalRequestEvent;
+pub use crate::approvals::SamplingDecision;
+pub use crate::approvals::SamplingMessage;
+pub use crate::approvals::SamplingRequestEvent;
 pub use crate::request_user_input::RequestUserInputEvent;
 
 /// Open/close tags for special user-input blocks. Used across crates to avoid
@@ -217,6 +224,14 @@ pub enum Op {
         decision: ReviewDecision,
     },
 
+    /// Answer a network access request raised by the sandbox network proxy.
+    NetworkApproval {
+        /// The id of the `NetworkApprovalRequestEvent` being answered.
+        id: String,
+        /// The user's decision in response to the request.
+        decision: NetworkApprovalDecision,
+    },
+
     /// Resolve an MCP elicitation request.
     ResolveElicitation {
         /// Name of the MCP server that issued the request.
@@ -227,6 +242,16 @@ pub enum Op {
         decision: ElicitationAction,
     },
 
+    /// Resolve an MCP sampling request.
+    ResolveSampling {
+        /// Name of the MCP server that issued the request.
+        server_name: String,
+        /// Request identifier from the MCP server.
+        request_id: RequestId,
+        /// User's decision for the request.
+        decision: SamplingDecision,
+    },
+
     /// Resolve a request_user_input tool call.
     #[serde(rename = "user_input_answer", alias = "request_user_input_response")]
     UserInputAnswer {
@@ -266,6 +291,19 @@ pub enum Op {
     /// Request the list of available custom prompts.
     ListCustomPrompts,
 
+    /// Request the prompts offered by connected MCP servers.
+    /// Reply is delivered via `EventMsg::ListMcpPromptsResponse`.
+    ListMcpPrompts,
+
+    /// Fetch a prompt from an MCP server with the given argument values.
+    /// Reply is delivered via `EventMsg::GetMcpPromptResponse`.
+    GetMcpPrompt {
+        server: String,
+        name: String,
+        #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+        arguments: HashMap<String, String>,
+    },
+
     /// Request the list of skills for the provided `cwd` values or the session default.
     ListSkills {
         /// Working directories to scope repo skills discovery.
@@ -307,6 +345,15 @@ pub enum Op {
     /// responsible for undoing any edits on disk.
     ThreadRollback { num_turns: u32 },
 
+    /// Write a structured checkpoint to `.codex/checkpoint.md`, capture a ghost
+    /// snapshot of the working tree and record a named marker in the rollout so
+    /// the thread can later be restarted from it.
+    Checkpoint {
+        /// Marker name; a timestamp-based name is used when omitted.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        name: Option<String>,
+    },
+
     /// Request a code review from the agent.
     Review { review_request: ReviewRequest },
 
@@ -802,6 +849,10 @@ pub enum EventMsg {
 
     McpToolCallEnd(McpToolCallEndEvent),
 
+    /// Outcome of the approval check for an MCP tool call. Only emitted when a
+    /// configured policy or a prompt decided the call; persisted in the rollout.
+    McpToolCallApproval(McpToolCallApprovalEvent),
+
     WebSearchBegin(WebSearchBeginEvent),
 
     WebSearchEnd(WebSearchEndEvent),
@@ -828,8 +879,12 @@ pub enum EventMsg {
 
     ElicitationRequest(ElicitationRequestEvent),
 
+    SamplingRequest(SamplingRequestEvent),
+
     ApplyPatchApprovalRequest(ApplyPatchApprovalRequestEvent),
 
+    NetworkApprovalRequest(NetworkApprovalRequestEvent),
+
     /// Notification advising the user that something they are using has been
     /// deprecated and should be phased out.
     DeprecationNotice(DeprecationNoticeEvent),
@@ -840,6 +895,10 @@ pub enum EventMsg {
 
     UndoCompleted(UndoCompletedEvent),
 
+    /// A named checkpoint was recorded. Persisted in the rollout as the marker
+    /// that `/resume` and `/fork` restart from.
+    CheckpointCreated(CheckpointCreatedEvent),
+
     /// Notification that a model stream experienced an error or disconnect
     /// and the system is handling it (e.g., retrying with backoff).
     StreamError(StreamErrorEvent),
@@ -862,6 +921,12 @@ pub enum EventMsg {
     /// List of custom prompts available to the agent.
     ListCustomPromptsResponse(ListCustomPromptsResponseEvent),
 
+    /// Prompts offered by connected MCP servers.
+    ListMcpPromptsResponse(ListMcpPromptsResponseEvent),
+
+    /// Messages returned by an MCP server for `Op::GetMcpPrompt`.
+    GetMcpPromptResponse(GetMcpPromptResponseEvent),
+
     /// List of skills available to the agent.
     ListSkillsResponse(ListSkillsResponseEvent),
 
@@ -1509,6 +1574,40 @@ impl McpToolCallEndEvent {
     }
 }
 
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq, Eq, JsonSchema, TS)]
+#[serde(rename_all = "snake_case")]
+pub enum McpToolCallApprovalDecision {
+    Approved,
+    /// Approved, and further calls to the tool are approved for the session.
+    ApprovedForSession,
+    /// Approved, and `policy = "always"` was written to config.toml.
+    ApprovedAndPersisted,
+    Denied,
+    Cancelled,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq, Eq, JsonSchema, TS)]
+#[serde(rename_all = "snake_case")]
+pub enum McpToolCallApprovalSource {
+    /// The `policy` configured for the server or tool.
+    Policy,
+    /// An `allow` rule matched the call arguments.
+    ArgumentRule,
+    /// The user answered an approval prompt.
+    User,
+    /// The user approved the tool earlier in the session.
+    Session,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema, TS, PartialEq)]
+pub struct McpToolCallApprovalEvent {
+    /// Identifier of the tool call the decision applies to.
+    pub call_id: String,
+    pub invocation: McpInvocation,
+    pub decision: McpToolCallApprovalDecision,
+    pub source: McpToolCallApprovalSource,
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize, JsonSchema, TS)]
 pub struct WebSearchBeginEvent {
     pub call_id: String,
@@ -2000,7 +2099,7 @@ pub struct ViewImageToolCallEvent {
     pub path: PathBuf,
 }
 
-#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, JsonSchema, TS)]
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq, Hash, JsonSchema, TS)]
 #[serde(rename_all = "snake_case")]
 pub enum ExecOutputStream {
     Stdout,
@@ -2059,6 +2158,16 @@ pub struct UndoCompletedEvent {
     pub message: Option<String>,
 }
 
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema, TS)]
+pub struct CheckpointCreatedEvent {
+    pub name: String,
+    /// File the checkpoint entry was appended to.
+    pub path: PathBuf,
+    /// Ghost commit capturing the working tree, when the cwd is a Git repository.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ghost_commit: Option<String>,
+}
+
 #[derive(Debug, Clone, Deserialize, Serialize, JsonSchema, TS)]
 pub struct ThreadRolledBackEvent {
     /// Number of user turns that were removed from context.
@@ -2200,6 +2309,22 @@ pub struct ListCustomPromptsResponseEvent {
     pub custom_prompts: Vec<CustomPrompt>,
 }
 
+/// Response payload for `Op::ListMcpPrompts`.
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema, TS)]
+pub struct ListMcpPromptsResponseEvent {
+    /// Known prompts grouped by server name.
+    pub prompts: HashMap<String, Vec<McpPrompt>>,
+}
+
+/// Response payload for `Op::GetMcpPrompt`.
+#[derive(Debug, Clone, Deserialize, Serialize, JsonSchema, TS)]
+pub struct GetMcpPromptResponseEvent {
+    pub server: String,
+    pub name: String,
+    /// Messages to expand into the user turn. Note this could be an error.
+    pub result: Result<McpGetPromptResult, String>,
+}
+
 /// Response payload for `Op::ListSkills`.
 #[derive(Debug, Clone, Deserialize, Serialize, JsonSchema, TS)]
 pub struct ListSkillsResponseEvent {
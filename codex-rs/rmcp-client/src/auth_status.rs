@@ -13,6 +13,7 @@ use tracing::debug;
 
 use crate::OAuthCredentialsStoreMode;
 use crate::oauth::has_oauth_tokens;
+use crate::oauth::oauth_base_url;
 use crate::utils::apply_default_headers;
 use crate::utils::build_default_headers;
 
@@ -20,7 +21,8 @@ const DISCOVERY_TIMEOUT: Duration = Duration::from_secs(5);
 const OAUTH_DISCOVERY_HEADER: &str = "MCP-Protocol-Version";
 const OAUTH_DISCOVERY_VERSION: &str = "2024-11-05";
 
-/// Determine the authentication status for a streamable HTTP MCP server.
+/// Determine the authentication status for a URL-based (streamable HTTP, SSE or
+/// WebSocket) MCP server.
 pub async fn determine_streamable_http_auth_status(
     server_name: &str,
     url: &str,
@@ -51,13 +53,13 @@ pub async fn determine_streamable_http_auth_status(
     }
 }
 
-/// Attempt to determine whether a streamable HTTP MCP server advertises OAuth login.
+/// Attempt to determine whether a URL-based MCP server advertises OAuth login.
 pub async fn supports_oauth_login(url: &str) -> Result<bool> {
     supports_oauth_login_with_headers(url, &HeaderMap::new()).await
 }
 
 async fn supports_oauth_login_with_headers(url: &str, default_headers: &HeaderMap) -> Result<bool> {
-    let base_url = Url::parse(url)?;
+    let base_url = Url::parse(&oauth_base_url(url))?;
 
     // Use no_proxy to avoid a bug in the system-configuration crate that
     // can result in a panic. See #8912.
@@ -0,0 +1,267 @@
+//! MCP test server for the 2024-11-05 HTTP+SSE transport. It prints the
+//! address it listens on as the first line of stdout; clients open
+//! `http://<addr>/sse` and post messages to the endpoint it announces.
+
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::convert::Infallible;
+use std::io::ErrorKind;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
+
+use axum::Router;
+use axum::body::Body;
+use axum::body::Bytes;
+use axum::extract::State;
+use axum::http::HeaderValue;
+use axum::http::Request;
+use axum::http::StatusCode;
+use axum::http::Uri;
+use axum::http::header::AUTHORIZATION;
+use axum::http::header::CACHE_CONTROL;
+use axum::http::header::CONTENT_TYPE;
+use axum::middleware;
+use axum::middleware::Next;
+use axum::response::Response;
+use axum::routing::get;
+use axum::routing::post;
+use futures::StreamExt;
+use futures::channel::mpsc;
+use rmcp::ErrorData as McpError;
+use rmcp::ServiceExt;
+use rmcp::handler::server::ServerHandler;
+use rmcp::model::CallToolRequestParams;
+use rmcp::model::CallToolResult;
+use rmcp::model::ClientJsonRpcMessage;
+use rmcp::model::JsonObject;
+use rmcp::model::ListToolsResult;
+use rmcp::model::PaginatedRequestParams;
+use rmcp::model::ServerCapabilities;
+use rmcp::model::ServerInfo;
+use rmcp::model::ServerJsonRpcMessage;
+use rmcp::model::Tool;
+use serde::Deserialize;
+use serde_json::json;
+
+#[derive(Clone)]
+struct TestToolServer {
+    tools: Arc<Vec<Tool>>,
+}
+
+impl TestToolServer {
+    fn new() -> Self {
+        Self {
+            tools: Arc::new(vec![Self::echo_tool()]),
+        }
+    }
+
+    fn echo_tool() -> Tool {
+        #[expect(clippy::expect_used)]
+        let schema: JsonObject = serde_json::from_value(json!({
+            "type": "object",
+            "properties": {
+                "message": { "type": "string" }
+            },
+            "required": ["message"],
+            "additionalProperties": false
+        }))
+        .expect("echo tool schema should deserialize");
+
+        Tool::new(
+            Cow::Borrowed("echo"),
+            Cow::Borrowed("Echo back the provided message."),
+            Arc::new(schema),
+        )
+    }
+}
+
+#[derive(Deserialize)]
+struct EchoArgs {
+    message: String,
+}
+
+impl ServerHandler for TestToolServer {
+    fn get_info(&self) -> ServerInfo {
+        ServerInfo {
+            capabilities: ServerCapabilities::builder().enable_tools().build(),
+            ..ServerInfo::default()
+        }
+    }
+
+    fn list_tools(
+        &self,
+        _request: Option<PaginatedRequestParams>,
+        _context: rmcp::service::RequestContext<rmcp::service::RoleServer>,
+    ) -> impl std::future::Future<Output = Result<ListToolsResult, McpError>> + Send + '_ {
+        let tools = self.tools.clone();
+        async move {
+            Ok(ListToolsResult {
+                tools: (*tools).clone(),
+                next_cursor: None,
+                meta: None,
+            })
+        }
+    }
+
+    async fn call_tool(
+        &self,
+        request: CallToolRequestParams,
+        _context: rmcp::service::RequestContext<rmcp::service::RoleServer>,
+    ) -> Result<CallToolResult, McpError> {
+        match request.name.as_ref() {
+            "echo" => {
+                let args: EchoArgs = match request.arguments {
+                    Some(arguments) => serde_json::from_value(serde_json::Value::Object(
+                        arguments.into_iter().collect(),
+                    ))
+                    .map_err(|err| McpError::invalid_params(err.to_string(), None))?,
+                    None => {
+                        return Err(McpError::invalid_params(
+                            "missing arguments for echo tool",
+                            None,
+                        ));
+                    }
+                };
+                Ok(CallToolResult {
+                    content: Vec::new(),
+                    structured_content: Some(json!({ "echo": args.message })),
+                    is_error: Some(false),
+                    meta: None,
+                })
+            }
+            other => Err(McpError::invalid_params(
+                format!("unknown tool: {other}"),
+                None,
+            )),
+        }
+    }
+}
+
+/// Open SSE sessions, keyed by the `sessionId` in their message endpoint.
+#[derive(Clone, Default)]
+struct Sessions {
+    next_id: Arc<AtomicU64>,
+    inboxes: Arc<Mutex<HashMap<String, mpsc::UnboundedSender<ClientJsonRpcMessage>>>>,
+}
+
+/// Starts an MCP session whose messages are streamed back on this response.
+/// The first event names the endpoint the client posts its messages to.
+async fn open_stream(State(sessions): State<Sessions>) -> Response {
+    let session_id = sessions.next_id.fetch_add(1, Ordering::Relaxed).to_string();
+    let (client_tx, client_rx) = mpsc::unbounded::<ClientJsonRpcMessage>();
+    let (server_tx, server_rx) = mpsc::unbounded::<ServerJsonRpcMessage>();
+    sessions
+        .inboxes
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner)
+        .insert(session_id.clone(), client_tx);
+
+    tokio::spawn(async move {
+        match TestToolServer::new().serve((server_tx, client_rx)).await {
+            Ok(running) => {
+                let _ = running.waiting().await;
+            }
+            Err(err) => eprintln!("SSE session failed to initialize: {err}"),
+        }
+    });
+
+    let endpoint = futures::stream::once(futures::future::ready(format!(
+        "event: endpoint\ndata: /messages?sessionId={session_id}\n\n"
+    )));
+    let messages = server_rx.filter_map(|message| {
+        futures::future::ready(match serde_json::to_string(&message) {
+            Ok(data) => Some(format!("event: message\ndata: {data}\n\n")),
+            Err(err) => {
+                eprintln!("failed to serialize MCP message: {err}");
+                None
+            }
+        })
+    });
+    let body = Body::from_stream(endpoint.chain(messages).map(Ok::<_, Infallible>));
+
+    let mut response = Response::new(body);
+    let headers = response.headers_mut();
+    headers.insert(CONTENT_TYPE, HeaderValue::from_static("text/event-stream"));
+    headers.insert(CACHE_CONTROL, HeaderValue::from_static("no-cache"));
+    response
+}
+
+async fn post_message(State(sessions): State<Sessions>, uri: Uri, body: Bytes) -> StatusCode {
+    let Some(session_id) = uri.query().and_then(|query| {
+        query
+            .split('&')
+            .find_map(|pair| pair.strip_prefix("sessionId="))
+    }) else {
+        return StatusCode::BAD_REQUEST;
+    };
+    let Ok(message) = serde_json::from_slice::<ClientJsonRpcMessage>(&body) else {
+        return StatusCode::BAD_REQUEST;
+    };
+    let inboxes = sessions
+        .inboxes
+        .lock()
+        .unwrap_or_else(std::sync::PoisonError::into_inner);
+    match inboxes.get(session_id) {
+        Some(inbox) if inbox.unbounded_send(message).is_ok() => StatusCode::ACCEPTED,
+        _ => StatusCode::NOT_FOUND,
+    }
+}
+
+fn parse_bind_addr() -> Result<SocketAddr, Box<dyn std::error::Error>> {
+    let bind_addr =
+        std::env::var("MCP_SSE_BIND_ADDR").unwrap_or_else(|_| "127.0.0.1:0".to_string());
+    Ok(bind_addr.parse()?)
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let bind_addr = parse_bind_addr()?;
+    let listener = match tokio::net::TcpListener::bind(&bind_addr).await {
+        Ok(listener) => listener,
+        Err(err) if err.kind() == ErrorKind::PermissionDenied => {
+            eprintln!(
+                "failed to bind to {bind_addr}: {err}. make sure the process has network access"
+            );
+            return Ok(());
+        }
+        Err(err) => return Err(err.into()),
+    };
+    let local_addr = listener.local_addr()?;
+    println!("{local_addr}");
+    eprintln!("starting rmcp SSE test server on http://{local_addr}/sse");
+
+    let router = Router::new()
+        .route("/sse", get(open_stream))
+        .route("/messages", post(post_message))
+        .with_state(Sessions::default());
+
+    // Both the event stream and posted messages must carry the token.
+    let router = if let Ok(token) = std::env::var("MCP_EXPECT_BEARER") {
+        let expected = Arc::new(format!("Bearer {token}"));
+        router.layer(middleware::from_fn_with_state(expected, require_bearer))
+    } else {
+        router
+    };
+
+    axum::serve(listener, router).await?;
+    Ok(())
+}
+
+async fn require_bearer(
+    State(expected): State<Arc<String>>,
+    request: Request<Body>,
+    next: Next,
+) -> Result<Response, StatusCode> {
+    if request
+        .headers()
+        .get(AUTHORIZATION)
+        .is_some_and(|value| value.as_bytes() == expected.as_bytes())
+    {
+        Ok(next.run(request).await)
+    } else {
+        Err(StatusCode::UNAUTHORIZED)
+    }
+}
@@ -7,11 +7,19 @@ use rmcp::ServiceExt;
 use rmcp::handler::server::ServerHandler;
 use rmcp::model::CallToolRequestParams;
 use rmcp::model::CallToolResult;
+use rmcp::model::CreateMessageRequestParams;
+use rmcp::model::GetPromptRequestParams;
+use rmcp::model::GetPromptResult;
 use rmcp::model::JsonObject;
+use rmcp::model::ListPromptsResult;
 use rmcp::model::ListResourceTemplatesResult;
 use rmcp::model::ListResourcesResult;
 use rmcp::model::ListToolsResult;
 use rmcp::model::PaginatedRequestParams;
+use rmcp::model::Prompt;
+use rmcp::model::PromptArgument;
+use rmcp::model::PromptMessage;
+use rmcp::model::PromptMessageRole;
 use rmcp::model::RawResource;
 use rmcp::model::RawResourceTemplate;
 use rmcp::model::ReadResourceRequestParams;
@@ -31,6 +39,7 @@ struct TestToolServer {
     tools: Arc<Vec<Tool>>,
     resources: Arc<Vec<Resource>>,
     resource_templates: Arc<Vec<ResourceTemplate>>,
+    prompts: Arc<Vec<Prompt>>,
 }
 
 const MEMO_URI: &str = "memo://codex/example-note";
@@ -47,13 +56,16 @@ impl TestToolServer {
             Self::echo_tool(),
             Self::image_tool(),
             Self::image_scenario_tool(),
+            Self::sample_tool(),
         ];
         let resources = vec![Self::memo_resource()];
         let resource_templates = vec![Self::memo_template()];
+        let prompts = vec![Self::summarize_memo_prompt()];
         Self {
             tools: Arc::new(tools),
             resources: Arc::new(resources),
             resource_templates: Arc::new(resource_templates),
+            prompts: Arc::new(prompts),
         }
     }
 
@@ -148,6 +160,27 @@ impl TestToolServer {
         )
     }
 
+    /// Asks the client to sample a completion for `prompt` and returns its text.
+    fn sample_tool() -> Tool {
+        #[expect(clippy::expect_used)]
+        let schema: JsonObject = serde_json::from_value(json!({
+            "type": "object",
+            "properties": {
+                "prompt": { "type": "string" },
+                "max_tokens": { "type": "integer" }
+            },
+            "required": ["prompt"],
+            "additionalProperties": false
+        }))
+        .expect("sample tool schema should deserialize");
+
+        Tool::new(
+            Cow::Borrowed("sample"),
+            Cow::Borrowed("Request a completion from the client via sampling/createMessage."),
+            Arc::new(schema),
+        )
+    }
+
     fn memo_resource() -> Resource {
         let raw = RawResource {
             uri: MEMO_URI.to_string(),
@@ -176,6 +209,19 @@ impl TestToolServer {
         ResourceTemplate::new(raw, None)
     }
 
+    fn summarize_memo_prompt() -> Prompt {
+        Prompt::new(
+            "summarize-memo",
+            Some("Summarize the example memo for a given audience."),
+            Some(vec![PromptArgument {
+                name: "audience".to_string(),
+                title: None,
+                description: Some("Who the summary is written for.".to_string()),
+                required: Some(true),
+            }]),
+        )
+    }
+
     fn memo_text() -> &'static str {
         MEMO_CONTENT
     }
@@ -205,6 +251,13 @@ enum ImageScenario {
     TextOnly,
 }
 
+#[derive(Deserialize)]
+struct SampleArgs {
+    prompt: String,
+    #[serde(default)]
+    max_tokens: Option<u32>,
+}
+
 #[derive(Deserialize, Debug)]
 struct ImageScenarioArgs {
     scenario: ImageScenario,
@@ -221,6 +274,7 @@ impl ServerHandler for TestToolServer {
                 .enable_tools()
                 .enable_tool_list_changed()
                 .enable_resources()
+                .enable_prompts()
                 .build(),
             ..ServerInfo::default()
         }
@@ -290,10 +344,54 @@ impl ServerHandler for TestToolServer {
         }
     }
 
+    async fn list_prompts(
+        &self,
+        _request: Option<PaginatedRequestParams>,
+        _context: rmcp::service::RequestContext<rmcp::service::RoleServer>,
+    ) -> Result<ListPromptsResult, McpError> {
+        Ok(ListPromptsResult {
+            prompts: (*self.prompts).clone(),
+            next_cursor: None,
+            meta: None,
+        })
+    }
+
+    async fn get_prompt(
+        &self,
+        GetPromptRequestParams {
+            name, arguments, ..
+        }: GetPromptRequestParams,
+        _context: rmcp::service::RequestContext<rmcp::service::RoleServer>,
+    ) -> Result<GetPromptResult, McpError> {
+        if name != "summarize-memo" {
+            return Err(McpError::invalid_params(
+                format!("unknown prompt: {name}"),
+                None,
+            ));
+        }
+        let Some(audience) = arguments
+            .as_ref()
+            .and_then(|arguments| arguments.get("audience"))
+            .and_then(serde_json::Value::as_str)
+        else {
+            return Err(McpError::invalid_params(
+                "missing required argument: audience",
+                None,
+            ));
+        };
+        Ok(GetPromptResult {
+            description: Some("Summarize the example memo.".to_string()),
+            messages: vec![PromptMessage::new_text(
+                PromptMessageRole::User,
+                format!("Summarize {MEMO_URI} for {audience}."),
+            )],
+        })
+    }
+
     async fn call_tool(
         &self,
         request: CallToolRequestParams,
-        _context: rmcp::service::RequestContext<rmcp::service::RoleServer>,
+        context: rmcp::service::RequestContext<rmcp::service::RoleServer>,
     ) -> Result<CallToolResult, McpError> {
         match request.name.as_ref() {
             "echo" => {
@@ -348,6 +446,33 @@ impl ServerHandler for TestToolServer {
                 let args = Self::parse_call_args::<ImageScenarioArgs>(&request, "image_scenario")?;
                 Self::image_scenario_result(args)
             }
+            "sample" => {
+                let args = Self::parse_call_args::<SampleArgs>(&request, "sample")?;
+                let params: CreateMessageRequestParams = serde_json::from_value(json!({
+                    "messages": [{
+                        "role": "user",
+                        "content": { "type": "text", "text": args.prompt },
+                    }],
+                    "systemPrompt": "You are a concise reviewer.",
+                    "maxTokens": args.max_tokens.unwrap_or(256),
+                }))
+                .map_err(|err| McpError::internal_error(err.to_string(), None))?;
+                let result = context
+                    .peer
+                    .create_message(params)
+                    .await
+                    .map_err(|err| McpError::internal_error(err.to_string(), None))?;
+                let result = serde_json::to_value(result)
+                    .map_err(|err| McpError::internal_error(err.to_string(), None))?;
+                let text = result
+                    .pointer("/content/text")
+                    .and_then(serde_json::Value::as_str)
+                    .unwrap_or_default()
+                    .to_string();
+                Ok(CallToolResult::success(vec![rmcp::model::Content::text(
+                    text,
+                )]))
+            }
             other => Err(McpError::invalid_params(
                 format!("unknown tool: {other}"),
                 None,
@@ -0,0 +1,224 @@
+//! MCP test server for the WebSocket transport: one JSON-RPC message per
+//! text frame. It prints the address it listens on as the first line of
+//! stdout; clients connect to `ws://<addr>/`.
+
+use std::borrow::Cow;
+use std::io::ErrorKind;
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+use futures::SinkExt;
+use futures::StreamExt;
+use futures::channel::mpsc;
+use rmcp::ErrorData as McpError;
+use rmcp::ServiceExt;
+use rmcp::handler::server::ServerHandler;
+use rmcp::model::CallToolRequestParams;
+use rmcp::model::CallToolResult;
+use rmcp::model::ClientJsonRpcMessage;
+use rmcp::model::JsonObject;
+use rmcp::model::ListToolsResult;
+use rmcp::model::PaginatedRequestParams;
+use rmcp::model::ServerCapabilities;
+use rmcp::model::ServerInfo;
+use rmcp::model::ServerJsonRpcMessage;
+use rmcp::model::Tool;
+use serde::Deserialize;
+use serde_json::json;
+use tokio::net::TcpListener;
+use tokio::net::TcpStream;
+use tokio_tungstenite::tungstenite::Message;
+use tokio_tungstenite::tungstenite::handshake::server::ErrorResponse;
+use tokio_tungstenite::tungstenite::handshake::server::Request;
+use tokio_tungstenite::tungstenite::handshake::server::Response;
+use tokio_tungstenite::tungstenite::http::StatusCode;
+use tokio_tungstenite::tungstenite::http::header::AUTHORIZATION;
+
+#[derive(Clone)]
+struct TestToolServer {
+    tools: Arc<Vec<Tool>>,
+}
+
+impl TestToolServer {
+    fn new() -> Self {
+        Self {
+            tools: Arc::new(vec![Self::echo_tool()]),
+        }
+    }
+
+    fn echo_tool() -> Tool {
+        #[expect(clippy::expect_used)]
+        let schema: JsonObject = serde_json::from_value(json!({
+            "type": "object",
+            "properties": {
+                "message": { "type": "string" }
+            },
+            "required": ["message"],
+            "additionalProperties": false
+        }))
+        .expect("echo tool schema should deserialize");
+
+        Tool::new(
+            Cow::Borrowed("echo"),
+            Cow::Borrowed("Echo back the provided message."),
+            Arc::new(schema),
+        )
+    }
+}
+
+#[derive(Deserialize)]
+struct EchoArgs {
+    message: String,
+}
+
+impl ServerHandler for TestToolServer {
+    fn get_info(&self) -> ServerInfo {
+        ServerInfo {
+            capabilities: ServerCapabilities::builder().enable_tools().build(),
+            ..ServerInfo::default()
+        }
+    }
+
+    fn list_tools(
+        &self,
+        _request: Option<PaginatedRequestParams>,
+        _context: rmcp::service::RequestContext<rmcp::service::RoleServer>,
+    ) -> impl std::future::Future<Output = Result<ListToolsResult, McpError>> + Send + '_ {
+        let tools = self.tools.clone();
+        async move {
+            Ok(ListToolsResult {
+                tools: (*tools).clone(),
+                next_cursor: None,
+                meta: None,
+            })
+        }
+    }
+
+    async fn call_tool(
+        &self,
+        request: CallToolRequestParams,
+        _context: rmcp::service::RequestContext<rmcp::service::RoleServer>,
+    ) -> Result<CallToolResult, McpError> {
+        match request.name.as_ref() {
+            "echo" => {
+                let args: EchoArgs = match request.arguments {
+                    Some(arguments) => serde_json::from_value(serde_json::Value::Object(
+                        arguments.into_iter().collect(),
+                    ))
+                    .map_err(|err| McpError::invalid_params(err.to_string(), None))?,
+                    None => {
+                        return Err(McpError::invalid_params(
+                            "missing arguments for echo tool",
+                            None,
+                        ));
+                    }
+                };
+                Ok(CallToolResult {
+                    content: Vec::new(),
+                    structured_content: Some(json!({ "echo": args.message })),
+                    is_error: Some(false),
+                    meta: None,
+                })
+            }
+            other => Err(McpError::invalid_params(
+                format!("unknown tool: {other}"),
+                None,
+            )),
+        }
+    }
+}
+
+/// Runs one MCP session over an accepted connection. When `expected_bearer`
+/// is set, the handshake is rejected unless it carries that `Authorization`.
+async fn serve_connection(
+    stream: TcpStream,
+    expected_bearer: Option<Arc<String>>,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    let check_bearer = |request: &Request, response: Response| match &expected_bearer {
+        Some(expected)
+            if request
+                .headers()
+                .get(AUTHORIZATION)
+                .is_none_or(|value| value.as_bytes() != expected.as_bytes()) =>
+        {
+            let mut error = ErrorResponse::new(Some("missing or invalid bearer token".into()));
+            *error.status_mut() = StatusCode::UNAUTHORIZED;
+            Err(error)
+        }
+        _ => Ok(response),
+    };
+    let mut socket = tokio_tungstenite::accept_hdr_async(stream, check_bearer).await?;
+
+    let (client_tx, client_rx) = mpsc::unbounded::<ClientJsonRpcMessage>();
+    let (server_tx, mut server_rx) = mpsc::unbounded::<ServerJsonRpcMessage>();
+    tokio::spawn(async move {
+        match TestToolServer::new().serve((server_tx, client_rx)).await {
+            Ok(running) => {
+                let _ = running.waiting().await;
+            }
+            Err(err) => eprintln!("WebSocket session failed to initialize: {err}"),
+        }
+    });
+
+    loop {
+        tokio::select! {
+            outgoing = server_rx.next() => {
+                let Some(message) = outgoing else {
+                    let _ = socket.close(None).await;
+                    return Ok(());
+                };
+                let text = serde_json::to_string(&message)?;
+                socket.send(Message::Text(text.into())).await?;
+            }
+            incoming = socket.next() => {
+                let text = match incoming {
+                    Some(Ok(Message::Text(text))) => text.to_string(),
+                    Some(Ok(Message::Close(_))) | None => return Ok(()),
+                    Some(Ok(_)) => continue,
+                    Some(Err(err)) => return Err(err.into()),
+                };
+                let message = serde_json::from_str::<ClientJsonRpcMessage>(&text)?;
+                if client_tx.unbounded_send(message).is_err() {
+                    return Ok(());
+                }
+            }
+        }
+    }
+}
+
+fn parse_bind_addr() -> Result<SocketAddr, Box<dyn std::error::Error>> {
+    let bind_addr =
+        std::env::var("MCP_WEBSOCKET_BIND_ADDR").unwrap_or_else(|_| "127.0.0.1:0".to_string());
+    Ok(bind_addr.parse()?)
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let bind_addr = parse_bind_addr()?;
+    let listener = match TcpListener::bind(&bind_addr).await {
+        Ok(listener) => listener,
+        Err(err) if err.kind() == ErrorKind::PermissionDenied => {
+            eprintln!(
+                "failed to bind to {bind_addr}: {err}. make sure the process has network access"
+            );
+            return Ok(());
+        }
+        Err(err) => return Err(err.into()),
+    };
+    let local_addr = listener.local_addr()?;
+    println!("{local_addr}");
+    eprintln!("starting rmcp WebSocket test server on ws://{local_addr}/");
+
+    let expected_bearer = std::env::var("MCP_EXPECT_BEARER")
+        .ok()
+        .map(|token| Arc::new(format!("Bearer {token}")));
+    loop {
+        let (stream, _) = listener.accept().await?;
+        let expected_bearer = expected_bearer.clone();
+        tokio::spawn(async move {
+            if let Err(err) = serve_connection(stream, expected_bearer).await {
+                eprintln!("WebSocket connection failed: {err}");
+            }
+        });
+    }
+}
@@ -4,7 +4,10 @@ mod oauth;
 mod perform_oauth_login;
 mod program_resolver;
 mod rmcp_client;
+mod sse_client_transport;
+mod url_transport;
 mod utils;
+mod websocket_client_transport;
 
 pub use auth_status::determine_streamable_http_auth_status;
 pub use auth_status::supports_oauth_login;
@@ -23,5 +26,8 @@ pub use rmcp_client::Elicitation;
 pub use rmcp_client::ElicitationResponse;
 pub use rmcp_client::ListToolsWithConnectorIdResult;
 pub use rmcp_client::RmcpClient;
+pub use rmcp_client::Sampling;
+pub use rmcp_client::SamplingResponse;
 pub use rmcp_client::SendElicitation;
+pub use rmcp_client::SendSampling;
 pub use rmcp_client::ToolWithConnectorId;
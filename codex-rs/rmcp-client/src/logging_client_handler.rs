@@ -6,6 +6,8 @@ use rmcp::model::CancelledNotificationParam;
 use rmcp::model::ClientInfo;
 use rmcp::model::CreateElicitationRequestParams;
 use rmcp::model::CreateElicitationResult;
+use rmcp::model::CreateMessageRequestParams;
+use rmcp::model::CreateMessageResult;
 use rmcp::model::LoggingLevel;
 use rmcp::model::LoggingMessageNotificationParam;
 use rmcp::model::ProgressNotificationParam;
@@ -18,18 +20,25 @@ use tracing::info;
 use tracing::warn;
 
 use crate::rmcp_client::SendElicitation;
+use crate::rmcp_client::SendSampling;
 
 #[derive(Clone)]
 pub(crate) struct LoggingClientHandler {
     client_info: ClientInfo,
     send_elicitation: Arc<SendElicitation>,
+    send_sampling: Option<Arc<SendSampling>>,
 }
 
 impl LoggingClientHandler {
-    pub(crate) fn new(client_info: ClientInfo, send_elicitation: SendElicitation) -> Self {
+    pub(crate) fn new(
+        client_info: ClientInfo,
+        send_elicitation: SendElicitation,
+        send_sampling: Option<SendSampling>,
+    ) -> Self {
         Self {
             client_info,
             send_elicitation: Arc::new(send_elicitation),
+            send_sampling: send_sampling.map(Arc::new),
         }
     }
 }
@@ -45,6 +54,22 @@ impl ClientHandler for LoggingClientHandler {
             .map_err(|err| rmcp::ErrorData::internal_error(err.to_string(), None))
     }
 
+    async fn create_message(
+        &self,
+        request: CreateMessageRequestParams,
+        context: RequestContext<RoleClient>,
+    ) -> Result<CreateMessageResult, rmcp::ErrorData> {
+        let Some(send_sampling) = self.send_sampling.as_ref() else {
+            return Err(rmcp::ErrorData::invalid_request(
+                "sampling is not supported by this client",
+                None,
+            ));
+        };
+        send_sampling(context.id, request)
+            .await
+            .map_err(|err| rmcp::ErrorData::internal_error(err.to_string(), None))
+    }
+
     async fn on_cancelled(
         &self,
         params: CancelledNotificationParam,
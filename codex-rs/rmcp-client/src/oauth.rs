@@ -373,6 +373,34 @@ impl OAuthPersistor {
 
         self.persist_if_needed().await
     }
+
+    /// Returns the current access token, refreshing and persisting it first if
+    /// it is about to expire. Used by transports that attach the token
+    /// themselves rather than going through `AuthClient`.
+    pub(crate) async fn access_token(&self) -> Result<String> {
+        self.refresh_if_needed().await?;
+        let manager = self.inner.authorization_manager.clone();
+        let guard = manager.lock().await;
+        guard.get_access_token().await.with_context(|| {
+            format!(
+                "failed to get OAuth access token for server {}",
+                self.inner.server_name
+            )
+        })
+    }
+}
+
+/// Returns the URL to run OAuth discovery and login against. WebSocket servers
+/// publish their OAuth metadata over HTTP(S) on the same host, so `ws://` and
+/// `wss://` map to `http://` and `https://`; other URLs are used as-is.
+pub(crate) fn oauth_base_url(url: &str) -> String {
+    if let Some(rest) = url.strip_prefix("wss://") {
+        format!("https://{rest}")
+    } else if let Some(rest) = url.strip_prefix("ws://") {
+        format!("http://{rest}")
+    } else {
+        url.to_string()
+    }
 }
 
 const FALLBACK_FILENAME: &str = ".credentials.json";
@@ -818,6 +846,22 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn oauth_base_url_maps_websocket_schemes_to_http() {
+        assert_eq!(
+            oauth_base_url("wss://gateway.example.com/mcp"),
+            "https://gateway.example.com/mcp"
+        );
+        assert_eq!(
+            oauth_base_url("ws://localhost:8080"),
+            "http://localhost:8080"
+        );
+        assert_eq!(
+            oauth_base_url("https://example.com/sse"),
+            "https://example.com/sse"
+        );
+    }
+
     #[test]
     fn refresh_expires_in_from_timestamp_restores_future_durations() {
         let mut tokens = sample_tokens();
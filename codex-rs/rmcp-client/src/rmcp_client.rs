@@ -9,6 +9,7 @@ use std::time::Duration;
 use anyhow::Result;
 use anyhow::anyhow;
 use futures::FutureExt;
+use futures::TryFutureExt;
 use futures::future::BoxFuture;
 use oauth2::TokenResponse;
 use reqwest::header::HeaderMap;
@@ -18,11 +19,16 @@ use rmcp::model::ClientNotification;
 use rmcp::model::ClientRequest;
 use rmcp::model::CreateElicitationRequestParams;
 use rmcp::model::CreateElicitationResult;
+use rmcp::model::CreateMessageRequestParams;
+use rmcp::model::CreateMessageResult;
 use rmcp::model::CustomNotification;
 use rmcp::model::CustomRequest;
 use rmcp::model::Extensions;
+use rmcp::model::GetPromptRequestParams;
+use rmcp::model::GetPromptResult;
 use rmcp::model::InitializeRequestParams;
 use rmcp::model::InitializeResult;
+use rmcp::model::ListPromptsResult;
 use rmcp::model::ListResourceTemplatesResult;
 use rmcp::model::ListResourcesResult;
 use rmcp::model::ListToolsResult;
@@ -55,11 +61,16 @@ use crate::logging_client_handler::LoggingClientHandler;
 use crate::oauth::OAuthCredentialsStoreMode;
 use crate::oauth::OAuthPersistor;
 use crate::oauth::StoredOAuthTokens;
+use crate::oauth::oauth_base_url;
 use crate::program_resolver;
+use crate::sse_client_transport::SseClientTransport;
+use crate::url_transport::BearerAuth;
+use crate::url_transport::ChannelTransport;
 use crate::utils::apply_default_headers;
 use crate::utils::build_default_headers;
 use crate::utils::create_env_for_mcp_server;
 use crate::utils::run_with_timeout;
+use crate::websocket_client_transport::WebSocketClientTransport;
 
 enum PendingTransport {
     ChildProcess {
@@ -73,6 +84,14 @@ enum PendingTransport {
         transport: StreamableHttpClientTransport<AuthClient<reqwest::Client>>,
         oauth_persistor: OAuthPersistor,
     },
+    Sse {
+        transport: SseClientTransport,
+        oauth_persistor: Option<OAuthPersistor>,
+    },
+    WebSocket {
+        transport: WebSocketClientTransport,
+        oauth_persistor: Option<OAuthPersistor>,
+    },
 }
 
 enum ClientState {
@@ -153,6 +172,14 @@ pub type SendElicitation = Box<
     dyn Fn(RequestId, Elicitation) -> BoxFuture<'static, Result<ElicitationResponse>> + Send + Sync,
 >;
 
+pub type Sampling = CreateMessageRequestParams;
+pub type SamplingResponse = CreateMessageResult;
+
+/// Interface for running `sampling/createMessage` requests on behalf of the
+/// server and awaiting the completion.
+pub type SendSampling =
+    Box<dyn Fn(RequestId, Sampling) -> BoxFuture<'static, Result<SamplingResponse>> + Send + Sync>;
+
 pub struct ToolWithConnectorId {
     pub tool: Tool,
     pub connector_id: Option<String>,
@@ -314,15 +341,74 @@ impl RmcpClient {
         })
     }
 
+    /// Client for a server speaking the 2024-11-05 HTTP+SSE transport. The
+    /// event stream is opened during [`RmcpClient::initialize`].
+    pub async fn new_sse_client(
+        server_name: &str,
+        url: &str,
+        bearer_token: Option<String>,
+        http_headers: Option<HashMap<String, String>>,
+        env_http_headers: Option<HashMap<String, String>>,
+        store_mode: OAuthCredentialsStoreMode,
+    ) -> Result<Self> {
+        let default_headers = build_default_headers(http_headers, env_http_headers)?;
+        let auth =
+            resolve_bearer_auth(server_name, url, bearer_token, store_mode, &default_headers)
+                .await?;
+        let http_client =
+            apply_default_headers(reqwest::Client::builder(), &default_headers).build()?;
+        let oauth_persistor = auth.oauth_persistor();
+        let transport = SseClientTransport::new(url, http_client, auth)?;
+        Ok(Self {
+            state: Mutex::new(ClientState::Connecting {
+                transport: Some(PendingTransport::Sse {
+                    transport,
+                    oauth_persistor,
+                }),
+            }),
+        })
+    }
+
+    /// Client for a server speaking MCP over a WebSocket. The connection is
+    /// opened during [`RmcpClient::initialize`].
+    pub async fn new_websocket_client(
+        server_name: &str,
+        url: &str,
+        bearer_token: Option<String>,
+        http_headers: Option<HashMap<String, String>>,
+        env_http_headers: Option<HashMap<String, String>>,
+        store_mode: OAuthCredentialsStoreMode,
+    ) -> Result<Self> {
+        let default_headers = build_default_headers(http_headers, env_http_headers)?;
+        let auth =
+            resolve_bearer_auth(server_name, url, bearer_token, store_mode, &default_headers)
+                .await?;
+        let oauth_persistor = auth.oauth_persistor();
+        let transport = WebSocketClientTransport::new(url, default_headers, auth);
+        Ok(Self {
+            state: Mutex::new(ClientState::Connecting {
+                transport: Some(PendingTransport::WebSocket {
+                    transport,
+                    oauth_persistor,
+                }),
+            }),
+        })
+    }
+
     /// Perform the initialization handshake with the MCP server.
     /// https://modelcontextprotocol.io/specification/2025-06-18/basic/lifecycle#initialization
+    ///
+    /// `send_sampling` should be set when `params` advertise the sampling
+    /// capability; without it, `sampling/createMessage` requests are rejected.
     pub async fn initialize(
         &self,
         params: InitializeRequestParams,
         timeout: Option<Duration>,
         send_elicitation: SendElicitation,
+        send_sampling: Option<SendSampling>,
     ) -> Result<InitializeResult> {
-        let client_handler = LoggingClientHandler::new(params.clone(), send_elicitation);
+        let client_handler =
+            LoggingClientHandler::new(params.clone(), send_elicitation, send_sampling);
 
         let (transport, oauth_persistor, process_group_guard) = {
             let mut guard = self.state.lock().await;
@@ -332,12 +418,16 @@ impl RmcpClient {
                         transport,
                         process_group_guard,
                     }) => (
-                        service::serve_client(client_handler.clone(), transport).boxed(),
+                        service::serve_client(client_handler.clone(), transport)
+                            .map_err(anyhow::Error::from)
+                            .boxed(),
                         None,
                         process_group_guard,
                     ),
                     Some(PendingTransport::StreamableHttp { transport }) => (
-                        service::serve_client(client_handler.clone(), transport).boxed(),
+                        service::serve_client(client_handler.clone(), transport)
+                            .map_err(anyhow::Error::from)
+                            .boxed(),
                         None,
                         None,
                     ),
@@ -345,10 +435,28 @@ impl RmcpClient {
                         transport,
                         oauth_persistor,
                     }) => (
-                        service::serve_client(client_handler.clone(), transport).boxed(),
+                        service::serve_client(client_handler.clone(), transport)
+                            .map_err(anyhow::Error::from)
+                            .boxed(),
                         Some(oauth_persistor),
                         None,
                     ),
+                    Some(PendingTransport::Sse {
+                        transport,
+                        oauth_persistor,
+                    }) => (
+                        connect_and_serve(client_handler.clone(), transport.connect()).boxed(),
+                        oauth_persistor,
+                        None,
+                    ),
+                    Some(PendingTransport::WebSocket {
+                        transport,
+                        oauth_persistor,
+                    }) => (
+                        connect_and_serve(client_handler.clone(), transport.connect()).boxed(),
+                        oauth_persistor,
+                        None,
+                    ),
                     None => return Err(anyhow!("client already initializing")),
                 },
                 ClientState::Ready { .. } => return Err(anyhow!("client already initialized")),
@@ -483,6 +591,33 @@ impl RmcpClient {
         Ok(result)
     }
 
+    pub async fn list_prompts(
+        &self,
+        params: Option<PaginatedRequestParams>,
+        timeout: Option<Duration>,
+    ) -> Result<ListPromptsResult> {
+        self.refresh_oauth_if_needed().await;
+        let service = self.service().await?;
+
+        let fut = service.list_prompts(params);
+        let result = run_with_timeout(fut, timeout, "prompts/list").await?;
+        self.persist_oauth_tokens().await;
+        Ok(result)
+    }
+
+    pub async fn get_prompt(
+        &self,
+        params: GetPromptRequestParams,
+        timeout: Option<Duration>,
+    ) -> Result<GetPromptResult> {
+        self.refresh_oauth_if_needed().await;
+        let service = self.service().await?;
+        let fut = service.get_prompt(params);
+        let result = run_with_timeout(fut, timeout, "prompts/get").await?;
+        self.persist_oauth_tokens().await;
+        Ok(result)
+    }
+
     pub async fn call_tool(
         &self,
         name: String,
@@ -580,6 +715,15 @@ impl RmcpClient {
     }
 }
 
+/// Connects a channel-based transport and runs the MCP handshake over it.
+async fn connect_and_serve(
+    client_handler: LoggingClientHandler,
+    connect: impl Future<Output = Result<ChannelTransport>>,
+) -> Result<RunningService<RoleClient, LoggingClientHandler>> {
+    let transport = connect.await?;
+    Ok(service::serve_client(client_handler, transport).await?)
+}
+
 async fn create_oauth_transport_and_runtime(
     server_name: &str,
     url: &str,
@@ -590,9 +734,33 @@ async fn create_oauth_transport_and_runtime(
     StreamableHttpClientTransport<AuthClient<reqwest::Client>>,
     OAuthPersistor,
 )> {
+    let (auth_client, runtime) = create_oauth_client_and_runtime(
+        server_name,
+        url,
+        initial_tokens,
+        credentials_store,
+        default_headers,
+    )
+    .await?;
+
+    let transport = StreamableHttpClientTransport::with_client(
+        auth_client,
+        StreamableHttpClientTransportConfig::with_uri(url.to_string()),
+    );
+
+    Ok((transport, runtime))
+}
+
+async fn create_oauth_client_and_runtime(
+    server_name: &str,
+    url: &str,
+    initial_tokens: StoredOAuthTokens,
+    credentials_store: OAuthCredentialsStoreMode,
+    default_headers: HeaderMap,
+) -> Result<(AuthClient<reqwest::Client>, OAuthPersistor)> {
     let http_client =
         apply_default_headers(reqwest::Client::builder(), &default_headers).build()?;
-    let mut oauth_state = OAuthState::new(url.to_string(), Some(http_client.clone())).await?;
+    let mut oauth_state = OAuthState::new(oauth_base_url(url), Some(http_client.clone())).await?;
 
     oauth_state
         .set_credentials(
@@ -612,11 +780,6 @@ async fn create_oauth_transport_and_runtime(
     let auth_client = AuthClient::new(http_client, manager);
     let auth_manager = auth_client.auth_manager.clone();
 
-    let transport = StreamableHttpClientTransport::with_client(
-        auth_client,
-        StreamableHttpClientTransportConfig::with_uri(url.to_string()),
-    );
-
     let runtime = OAuthPersistor::new(
         server_name.to_string(),
         url.to_string(),
@@ -625,5 +788,58 @@ async fn create_oauth_transport_and_runtime(
         Some(initial_tokens),
     );
 
-    Ok((transport, runtime))
+    Ok((auth_client, runtime))
+}
+
+/// Picks the bearer token for the SSE and WebSocket transports: the configured
+/// token if there is one, otherwise stored OAuth tokens.
+async fn resolve_bearer_auth(
+    server_name: &str,
+    url: &str,
+    bearer_token: Option<String>,
+    store_mode: OAuthCredentialsStoreMode,
+    default_headers: &HeaderMap,
+) -> Result<BearerAuth> {
+    if let Some(bearer_token) = bearer_token {
+        return Ok(BearerAuth::Static(bearer_token));
+    }
+
+    let initial_tokens = match load_oauth_tokens(server_name, url, store_mode) {
+        Ok(Some(tokens)) => tokens,
+        Ok(None) => return Ok(BearerAuth::None),
+        Err(err) => {
+            warn!("failed to read tokens for server `{server_name}`: {err}");
+            return Ok(BearerAuth::None);
+        }
+    };
+
+    match create_oauth_client_and_runtime(
+        server_name,
+        url,
+        initial_tokens.clone(),
+        store_mode,
+        default_headers.clone(),
+    )
+    .await
+    {
+        Ok((_, oauth_persistor)) => Ok(BearerAuth::OAuth(oauth_persistor)),
+        Err(err)
+            if err
+                .downcast_ref::<AuthError>()
+                .is_some_and(|auth_err| matches!(auth_err, AuthError::NoAuthorizationSupport)) =>
+        {
+            warn!(
+                "OAuth metadata discovery is unavailable for MCP server `{server_name}`; falling back to stored bearer token authentication"
+            );
+            Ok(BearerAuth::Static(
+                initial_tokens
+                    .token_response
+                    .0
+                    .access_token()
+                    .secret()
+                    .to_string(),
+            ))
+        }
+        Err(err) => Err(err),
+    }
 }
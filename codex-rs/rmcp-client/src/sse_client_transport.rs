@@ -0,0 +1,182 @@
+//! Client side of the HTTP+SSE transport from the 2024-11-05 MCP revision.
+//! https://modelcontextprotocol.io/specification/2024-11-05/basic/transports#http-with-sse
+//!
+//! The server sends messages as `message` events on a long-lived `GET` stream.
+//! Its first event is `endpoint`, which names the URL the client `POST`s its
+//! own messages to.
+
+use anyhow::Context;
+use anyhow::Result;
+use anyhow::anyhow;
+use eventsource_stream::Eventsource;
+use futures::StreamExt;
+use futures::channel::mpsc;
+use reqwest::Client;
+use reqwest::Url;
+use reqwest::header::ACCEPT;
+use rmcp::model::ClientJsonRpcMessage;
+use rmcp::model::ServerJsonRpcMessage;
+use tracing::warn;
+
+use crate::url_transport::BearerAuth;
+use crate::url_transport::ChannelTransport;
+
+const ENDPOINT_EVENT: &str = "endpoint";
+const MESSAGE_EVENT: &str = "message";
+
+pub(crate) struct SseClientTransport {
+    url: Url,
+    http_client: Client,
+    auth: BearerAuth,
+}
+
+impl SseClientTransport {
+    pub(crate) fn new(url: &str, http_client: Client, auth: BearerAuth) -> Result<Self> {
+        let url = Url::parse(url).with_context(|| format!("invalid SSE URL {url}"))?;
+        Ok(Self {
+            url,
+            http_client,
+            auth,
+        })
+    }
+
+    /// Opens the event stream and waits for the `endpoint` event.
+    pub(crate) async fn connect(self) -> Result<ChannelTransport> {
+        let mut request = self
+            .http_client
+            .get(self.url.clone())
+            .header(ACCEPT, "text/event-stream");
+        if let Some(token) = self.auth.token().await? {
+            request = request.bearer_auth(token);
+        }
+        let response = request
+            .send()
+            .await
+            .with_context(|| format!("failed to open SSE stream at {}", self.url))?
+            .error_for_status()?;
+        let mut events = response.bytes_stream().eventsource();
+
+        let endpoint = loop {
+            match events.next().await {
+                Some(Ok(event)) if event.event == ENDPOINT_EVENT => {
+                    break endpoint_url(&self.url, &event.data)?;
+                }
+                Some(Ok(_)) => continue,
+                Some(Err(err)) => {
+                    return Err(anyhow!(
+                        "SSE stream failed before the endpoint event: {err}"
+                    ));
+                }
+                None => return Err(anyhow!("SSE stream closed before the endpoint event")),
+            }
+        };
+
+        let (incoming_tx, incoming_rx) = mpsc::unbounded();
+        tokio::spawn(async move {
+            while let Some(event) = events.next().await {
+                let event = match event {
+                    Ok(event) => event,
+                    Err(err) => {
+                        warn!("MCP SSE stream failed: {err}");
+                        break;
+                    }
+                };
+                if !event.event.is_empty() && event.event != MESSAGE_EVENT {
+                    continue;
+                }
+                match serde_json::from_str::<ServerJsonRpcMessage>(&event.data) {
+                    Ok(message) => {
+                        if incoming_tx.unbounded_send(message).is_err() {
+                            break;
+                        }
+                    }
+                    Err(err) => warn!("ignoring malformed MCP message on SSE stream: {err}"),
+                }
+            }
+        });
+
+        let (outgoing_tx, mut outgoing_rx) = mpsc::unbounded::<ClientJsonRpcMessage>();
+        let http_client = self.http_client;
+        let auth = self.auth;
+        tokio::spawn(async move {
+            // Messages are posted one at a time so the server sees them in order.
+            while let Some(message) = outgoing_rx.next().await {
+                if let Err(err) = post_message(&http_client, &auth, &endpoint, &message).await {
+                    warn!("failed to send MCP message to {endpoint}: {err}");
+                }
+            }
+        });
+
+        Ok((outgoing_tx, incoming_rx))
+    }
+}
+
+/// Resolves the `endpoint` event against the stream URL. The endpoint must
+/// share the stream's origin, because messages posted to it carry the same
+/// bearer token and headers.
+fn endpoint_url(stream_url: &Url, data: &str) -> Result<Url> {
+    let endpoint = stream_url
+        .join(data.trim())
+        .with_context(|| format!("invalid endpoint event from SSE server: {data}"))?;
+    if endpoint.origin() != stream_url.origin() {
+        return Err(anyhow!(
+            "SSE server sent endpoint {endpoint} on a different origin than {stream_url}"
+        ));
+    }
+    Ok(endpoint)
+}
+
+async fn post_message(
+    http_client: &Client,
+    auth: &BearerAuth,
+    endpoint: &Url,
+    message: &ClientJsonRpcMessage,
+) -> Result<()> {
+    let mut request = http_client.post(endpoint.clone()).json(message);
+    if let Some(token) = auth.token().await? {
+        request = request.bearer_auth(token);
+    }
+    request.send().await?.error_for_status()?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn stream_url() -> Url {
+        Url::parse("https://mcp.example.com:8443/sse").expect("valid URL")
+    }
+
+    #[test]
+    fn endpoint_resolves_relative_to_stream_url() {
+        assert_eq!(
+            endpoint_url(&stream_url(), " /messages?session=1\n")
+                .expect("same-origin endpoint")
+                .as_str(),
+            "https://mcp.example.com:8443/messages?session=1"
+        );
+        assert_eq!(
+            endpoint_url(&stream_url(), "https://mcp.example.com:8443/other")
+                .expect("same-origin endpoint")
+                .as_str(),
+            "https://mcp.example.com:8443/other"
+        );
+    }
+
+    #[test]
+    fn endpoint_on_another_origin_is_rejected() {
+        for endpoint in [
+            "https://attacker.example.com/messages",
+            "http://mcp.example.com:8443/messages",
+            "https://mcp.example.com/messages",
+            "//attacker.example.com/messages",
+        ] {
+            assert!(
+                endpoint_url(&stream_url(), endpoint).is_err(),
+                "{endpoint} should be rejected"
+            );
+        }
+    }
+}
@@ -0,0 +1,43 @@
+//! Pieces shared by the SSE and WebSocket transports.
+
+use anyhow::Result;
+use futures::channel::mpsc;
+use rmcp::model::ClientJsonRpcMessage;
+use rmcp::model::ServerJsonRpcMessage;
+
+use crate::oauth::OAuthPersistor;
+
+/// Outgoing and incoming halves of a connected transport, in the
+/// `(Sink, Stream)` shape `rmcp::service::serve_client` accepts.
+pub(crate) type ChannelTransport = (
+    mpsc::UnboundedSender<ClientJsonRpcMessage>,
+    mpsc::UnboundedReceiver<ServerJsonRpcMessage>,
+);
+
+/// Source of the `Authorization: Bearer` token. These transports attach it
+/// themselves instead of going through `AuthClient`.
+#[derive(Clone)]
+pub(crate) enum BearerAuth {
+    None,
+    Static(String),
+    OAuth(OAuthPersistor),
+}
+
+impl BearerAuth {
+    /// The token to send right now. OAuth tokens are refreshed first when they
+    /// are about to expire.
+    pub(crate) async fn token(&self) -> Result<Option<String>> {
+        match self {
+            BearerAuth::None => Ok(None),
+            BearerAuth::Static(token) => Ok(Some(token.clone())),
+            BearerAuth::OAuth(persistor) => persistor.access_token().await.map(Some),
+        }
+    }
+
+    pub(crate) fn oauth_persistor(&self) -> Option<OAuthPersistor> {
+        match self {
+            BearerAuth::OAuth(persistor) => Some(persistor.clone()),
+            BearerAuth::None | BearerAuth::Static(_) => None,
+        }
+    }
+}
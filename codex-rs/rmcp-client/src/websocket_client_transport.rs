@@ -0,0 +1,116 @@
+//! MCP over WebSocket: each JSON-RPC message is one text frame. Headers and
+//! the bearer token are sent with the opening handshake.
+
+use anyhow::Context;
+use anyhow::Result;
+use codex_utils_rustls_provider::ensure_rustls_crypto_provider;
+use futures::SinkExt;
+use futures::StreamExt;
+use futures::channel::mpsc;
+use reqwest::header::AUTHORIZATION;
+use reqwest::header::HeaderMap;
+use reqwest::header::HeaderValue;
+use rmcp::model::ClientJsonRpcMessage;
+use rmcp::model::ServerJsonRpcMessage;
+use tokio_tungstenite::tungstenite::Message;
+use tokio_tungstenite::tungstenite::client::IntoClientRequest;
+use tracing::warn;
+
+use crate::url_transport::BearerAuth;
+use crate::url_transport::ChannelTransport;
+
+pub(crate) struct WebSocketClientTransport {
+    url: String,
+    headers: HeaderMap,
+    auth: BearerAuth,
+}
+
+impl WebSocketClientTransport {
+    pub(crate) fn new(url: &str, headers: HeaderMap, auth: BearerAuth) -> Self {
+        Self {
+            url: url.to_string(),
+            headers,
+            auth,
+        }
+    }
+
+    /// Performs the WebSocket handshake and starts relaying frames.
+    pub(crate) async fn connect(self) -> Result<ChannelTransport> {
+        ensure_rustls_crypto_provider();
+
+        let mut request = self
+            .url
+            .as_str()
+            .into_client_request()
+            .with_context(|| format!("invalid WebSocket URL {}", self.url))?;
+        request.headers_mut().extend(self.headers);
+        if let Some(token) = self.auth.token().await? {
+            let value = HeaderValue::from_str(&format!("Bearer {token}"))
+                .context("bearer token is not a valid header value")?;
+            request.headers_mut().insert(AUTHORIZATION, value);
+        }
+        let (mut socket, _response) = tokio_tungstenite::connect_async(request)
+            .await
+            .with_context(|| format!("failed to connect to {}", self.url))?;
+
+        let (incoming_tx, incoming_rx) = mpsc::unbounded();
+        let (outgoing_tx, mut outgoing_rx) = mpsc::unbounded::<ClientJsonRpcMessage>();
+        let url = self.url;
+        tokio::spawn(async move {
+            loop {
+                tokio::select! {
+                    outgoing = outgoing_rx.next() => {
+                        let Some(message) = outgoing else {
+                            let _ = socket.close(None).await;
+                            break;
+                        };
+                        let text = match serde_json::to_string(&message) {
+                            Ok(text) => text,
+                            Err(err) => {
+                                warn!("failed to serialize MCP message for {url}: {err}");
+                                continue;
+                            }
+                        };
+                        if let Err(err) = socket.send(Message::Text(text.into())).await {
+                            warn!("failed to send MCP message to {url}: {err}");
+                            break;
+                        }
+                    }
+                    incoming = socket.next() => {
+                        let text = match incoming {
+                            Some(Ok(Message::Text(text))) => text.to_string(),
+                            Some(Ok(Message::Binary(bytes))) => {
+                                String::from_utf8_lossy(&bytes).into_owned()
+                            }
+                            Some(Ok(Message::Ping(payload))) => {
+                                if socket.send(Message::Pong(payload)).await.is_err() {
+                                    break;
+                                }
+                                continue;
+                            }
+                            Some(Ok(Message::Close(_))) | None => break,
+                            Some(Ok(_)) => continue,
+                            Some(Err(err)) => {
+                                warn!("MCP WebSocket connection to {url} failed: {err}");
+                                break;
+                            }
+                        };
+                        match serde_json::from_str::<ServerJsonRpcMessage>(&text) {
+                            Ok(message) => {
+                                if incoming_tx.unbounded_send(message).is_err() {
+                                    let _ = socket.close(None).await;
+                                    break;
+                                }
+                            }
+                            Err(err) => {
+                                warn!("ignoring malformed MCP message from {url}: {err}");
+                            }
+                        }
+                    }
+                }
+            }
+        });
+
+        Ok((outgoing_tx, incoming_rx))
+    }
+}
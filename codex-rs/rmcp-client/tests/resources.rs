@@ -71,6 +71,7 @@ async fn rmcp_client_can_list_and_read_resources() -> anyhow::Result<()> {
                 }
                 .boxed()
             }),
+            None,
         )
         .await?;
 
@@ -0,0 +1,119 @@
+use std::ffi::OsString;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use codex_rmcp_client::ElicitationAction;
+use codex_rmcp_client::ElicitationResponse;
+use codex_rmcp_client::RmcpClient;
+use codex_rmcp_client::SamplingResponse;
+use codex_utils_cargo_bin::CargoBinError;
+use futures::FutureExt as _;
+use pretty_assertions::assert_eq;
+use rmcp::model::ClientCapabilities;
+use rmcp::model::ElicitationCapability;
+use rmcp::model::Implementation;
+use rmcp::model::InitializeRequestParams;
+use rmcp::model::ProtocolVersion;
+use serde_json::Value;
+use serde_json::json;
+
+fn stdio_server_bin() -> Result<PathBuf, CargoBinError> {
+    codex_utils_cargo_bin::cargo_bin("test_stdio_server")
+}
+
+fn init_params() -> InitializeRequestParams {
+    InitializeRequestParams {
+        meta: None,
+        capabilities: ClientCapabilities {
+            experimental: None,
+            roots: None,
+            sampling: ClientCapabilities::builder()
+                .enable_sampling()
+                .build()
+                .sampling,
+            elicitation: Some(ElicitationCapability {
+                schema_validation: None,
+            }),
+            tasks: None,
+        },
+        client_info: Implementation {
+            name: "codex-test".into(),
+            version: "0.0.0-test".into(),
+            title: Some("Codex rmcp sampling test".into()),
+            icons: None,
+            website_url: None,
+        },
+        protocol_version: ProtocolVersion::V_2025_06_18,
+    }
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn rmcp_client_answers_sampling_requests() -> anyhow::Result<()> {
+    let client = RmcpClient::new_stdio_client(
+        stdio_server_bin()?.into(),
+        Vec::<OsString>::new(),
+        None,
+        &[],
+        None,
+    )
+    .await?;
+
+    let requests: Arc<Mutex<Vec<Value>>> = Arc::new(Mutex::new(Vec::new()));
+    let recorded = Arc::clone(&requests);
+    client
+        .initialize(
+            init_params(),
+            Some(Duration::from_secs(5)),
+            Box::new(|_, _| {
+                async {
+                    Ok(ElicitationResponse {
+                        action: ElicitationAction::Accept,
+                        content: Some(json!({})),
+                    })
+                }
+                .boxed()
+            }),
+            Some(Box::new(move |_, request| {
+                let recorded = Arc::clone(&recorded);
+                async move {
+                    recorded
+                        .lock()
+                        .expect("requests lock")
+                        .push(serde_json::to_value(&request)?);
+                    let response: SamplingResponse = serde_json::from_value(json!({
+                        "role": "assistant",
+                        "content": { "type": "text", "text": "Looks good to me." },
+                        "model": "test-model",
+                        "stopReason": "endTurn",
+                    }))?;
+                    Ok(response)
+                }
+                .boxed()
+            })),
+        )
+        .await?;
+
+    let result = client
+        .call_tool(
+            "sample".to_string(),
+            Some(json!({ "prompt": "Review this diff.", "max_tokens": 64 })),
+            Some(Duration::from_secs(5)),
+        )
+        .await?;
+
+    assert_eq!(
+        serde_json::to_value(&result.content)?,
+        json!([{ "type": "text", "text": "Looks good to me." }])
+    );
+    let requests = requests.lock().expect("requests lock").clone();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0]["maxTokens"], json!(64));
+    assert_eq!(
+        requests[0]["messages"][0]["content"]["text"],
+        json!("Review this diff.")
+    );
+
+    Ok(())
+}
@@ -0,0 +1,176 @@
+use std::process::Stdio;
+use std::time::Duration;
+
+use anyhow::Context;
+use codex_rmcp_client::ElicitationAction;
+use codex_rmcp_client::ElicitationResponse;
+use codex_rmcp_client::OAuthCredentialsStoreMode;
+use codex_rmcp_client::RmcpClient;
+use futures::FutureExt as _;
+use pretty_assertions::assert_eq;
+use rmcp::model::ClientCapabilities;
+use rmcp::model::Implementation;
+use rmcp::model::InitializeRequestParams;
+use rmcp::model::ProtocolVersion;
+use serde_json::json;
+use tokio::io::AsyncBufReadExt;
+use tokio::io::BufReader;
+use tokio::process::Child;
+use tokio::process::Command;
+
+const BEARER_TOKEN: &str = "transport-test-token";
+
+/// A test server child process and the address it reported on stdout.
+struct TestServer {
+    _child: Child,
+    addr: String,
+}
+
+async fn spawn_server(bin: &str) -> anyhow::Result<TestServer> {
+    let mut child = Command::new(codex_utils_cargo_bin::cargo_bin(bin)?)
+        .env("MCP_EXPECT_BEARER", BEARER_TOKEN)
+        .stdout(Stdio::piped())
+        .kill_on_drop(true)
+        .spawn()?;
+    let stdout = child.stdout.take().context("test server stdout")?;
+    let addr = tokio::time::timeout(
+        Duration::from_secs(5),
+        BufReader::new(stdout).lines().next_line(),
+    )
+    .await
+    .context("timed out waiting for the test server address")??
+    .with_context(|| format!("{bin} exited before reporting its address"))?;
+    Ok(TestServer {
+        _child: child,
+        addr,
+    })
+}
+
+fn init_params() -> InitializeRequestParams {
+    InitializeRequestParams {
+        meta: None,
+        capabilities: ClientCapabilities::default(),
+        client_info: Implementation {
+            name: "codex-test".into(),
+            version: "0.0.0-test".into(),
+            title: Some("Codex rmcp transport test".into()),
+            icons: None,
+            website_url: None,
+        },
+        protocol_version: ProtocolVersion::V_2025_06_18,
+    }
+}
+
+async fn initialize(client: &RmcpClient) -> anyhow::Result<()> {
+    client
+        .initialize(
+            init_params(),
+            Some(Duration::from_secs(5)),
+            Box::new(|_, _| {
+                async {
+                    Ok(ElicitationResponse {
+                        action: ElicitationAction::Decline,
+                        content: None,
+                    })
+                }
+                .boxed()
+            }),
+            None,
+        )
+        .await?;
+    Ok(())
+}
+
+/// Lists tools and calls `echo`, covering requests in both directions.
+async fn assert_echo_round_trip(client: &RmcpClient) -> anyhow::Result<()> {
+    let tools = client
+        .list_tools(None, Some(Duration::from_secs(5)))
+        .await?;
+    let names: Vec<String> = tools
+        .tools
+        .iter()
+        .map(|tool| tool.name.to_string())
+        .collect();
+    assert_eq!(names, vec!["echo".to_string()]);
+
+    let result = client
+        .call_tool(
+            "echo".to_string(),
+            Some(json!({ "message": "ping" })),
+            Some(Duration::from_secs(5)),
+        )
+        .await?;
+    assert_eq!(result.structured_content, Some(json!({ "echo": "ping" })));
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn sse_client_follows_endpoint_and_round_trips_messages() -> anyhow::Result<()> {
+    let server = spawn_server("test_sse_server").await?;
+    let client = RmcpClient::new_sse_client(
+        "sse-test",
+        &format!("http://{}/sse", server.addr),
+        Some(BEARER_TOKEN.to_string()),
+        None,
+        None,
+        OAuthCredentialsStoreMode::File,
+    )
+    .await?;
+
+    // The server rejects both the stream and the posted messages without
+    // the bearer token, so a completed handshake means both carried it.
+    initialize(&client).await?;
+    assert_echo_round_trip(&client).await
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn sse_client_fails_with_wrong_bearer_token() -> anyhow::Result<()> {
+    let server = spawn_server("test_sse_server").await?;
+    let client = RmcpClient::new_sse_client(
+        "sse-test",
+        &format!("http://{}/sse", server.addr),
+        Some("wrong-token".to_string()),
+        None,
+        None,
+        OAuthCredentialsStoreMode::File,
+    )
+    .await?;
+
+    assert!(initialize(&client).await.is_err());
+    Ok(())
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn websocket_client_round_trips_messages() -> anyhow::Result<()> {
+    let server = spawn_server("test_websocket_server").await?;
+    let client = RmcpClient::new_websocket_client(
+        "websocket-test",
+        &format!("ws://{}/", server.addr),
+        Some(BEARER_TOKEN.to_string()),
+        None,
+        None,
+        OAuthCredentialsStoreMode::File,
+    )
+    .await?;
+
+    // The handshake is refused unless it carries the bearer token.
+    initialize(&client).await?;
+    assert_echo_round_trip(&client).await
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 1)]
+async fn websocket_client_fails_with_wrong_bearer_token() -> anyhow::Result<()> {
+    let server = spawn_server("test_websocket_server").await?;
+    let client = RmcpClient::new_websocket_client(
+        "websocket-test",
+        &format!("ws://{}/", server.addr),
+        Some("wrong-token".to_string()),
+        None,
+        None,
+        OAuthCredentialsStoreMode::File,
+    )
+    .await?;
+
+    assert!(initialize(&client).await.is_err());
+    Ok(())
+}
@@ -0,0 +1,192 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::path::PathBuf;
+
+use anyhow::Context;
+use anyhow::Result;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+use tracing::warn;
+
+use super::SecretListEntry;
+use super::SecretName;
+use super::SecretScope;
+use super::SecretsBackend;
+
+const DEFAULT_ENV_FILENAME: &str = "secrets.env";
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[schemars(deny_unknown_fields)]
+pub struct EnvFileSecretsConfig {
+    /// Dotenv file to read. Defaults to `CODEX_HOME/secrets/secrets.env`.
+    pub path: Option<PathBuf>,
+}
+
+/// Reads global secrets from a dotenv file.
+///
+/// The file is managed outside of Codex (typically provisioned by CI), so the backend is
+/// read-only. On unix the file must not be readable or writable by group or others.
+#[derive(Debug, Clone)]
+pub struct EnvFileSecretsBackend {
+    path: PathBuf,
+}
+
+impl EnvFileSecretsBackend {
+    pub fn new(codex_home: &Path, config: &EnvFileSecretsConfig) -> Self {
+        let path = config
+            .path
+            .clone()
+            .unwrap_or_else(|| codex_home.join("secrets").join(DEFAULT_ENV_FILENAME));
+        Self { path }
+    }
+
+    fn load(&self) -> Result<BTreeMap<String, String>> {
+        if !self.path.exists() {
+            return Ok(BTreeMap::new());
+        }
+        ensure_private_permissions(&self.path)?;
+
+        let iter = dotenvy::from_path_iter(&self.path)
+            .with_context(|| format!("failed to open secrets file at {}", self.path.display()))?;
+        let mut secrets = BTreeMap::new();
+        for item in iter {
+            let (key, value) = item.with_context(|| {
+                format!("failed to parse secrets file at {}", self.path.display())
+            })?;
+            secrets.insert(key, value);
+        }
+        Ok(secrets)
+    }
+
+    fn read_only_error(&self) -> anyhow::Error {
+        anyhow::anyhow!(
+            "the env-file secrets backend is read-only; edit {} instead",
+            self.path.display()
+        )
+    }
+}
+
+impl SecretsBackend for EnvFileSecretsBackend {
+    fn set(&self, _scope: &SecretScope, _name: &SecretName, _value: &str) -> Result<()> {
+        Err(self.read_only_error())
+    }
+
+    fn get(&self, scope: &SecretScope, name: &SecretName) -> Result<Option<String>> {
+        // A dotenv file has no notion of environments; every entry is global.
+        if *scope != SecretScope::Global {
+            return Ok(None);
+        }
+        Ok(self.load()?.remove(name.as_str()))
+    }
+
+    fn delete(&self, _scope: &SecretScope, _name: &SecretName) -> Result<bool> {
+        Err(self.read_only_error())
+    }
+
+    fn list(&self, scope_filter: Option<&SecretScope>) -> Result<Vec<SecretListEntry>> {
+        if scope_filter.is_some_and(|scope| *scope != SecretScope::Global) {
+            return Ok(Vec::new());
+        }
+        let mut entries = Vec::new();
+        for key in self.load()?.into_keys() {
+            match SecretName::new(&key) {
+                Ok(name) => entries.push(SecretListEntry {
+                    scope: SecretScope::Global,
+                    name,
+                }),
+                Err(_) => warn!("skipping invalid secret name in env file: {key}"),
+            }
+        }
+        Ok(entries)
+    }
+}
+
+#[cfg(unix)]
+fn ensure_private_permissions(path: &Path) -> Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+
+    let metadata = std::fs::metadata(path)
+        .with_context(|| format!("failed to stat secrets file at {}", path.display()))?;
+    let mode = metadata.permissions().mode() & 0o777;
+    anyhow::ensure!(
+        mode & 0o077 == 0,
+        "secrets file at {} has permissions {mode:03o}; run `chmod 600 {}`",
+        path.display(),
+        path.display()
+    );
+    Ok(())
+}
+
+#[cfg(not(unix))]
+fn ensure_private_permissions(_path: &Path) -> Result<()> {
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+
+    fn write_env_file(dir: &Path, contents: &str) -> PathBuf {
+        let path = dir.join("ci.env");
+        std::fs::write(&path, contents).expect("write env file");
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o600))
+                .expect("chmod env file");
+        }
+        path
+    }
+
+    #[test]
+    fn reads_global_secrets_from_env_file() -> Result<()> {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let path = write_env_file(
+            dir.path(),
+            "# team tokens\nNPM_TOKEN=npm-123\nGITHUB_TOKEN=\"gh 456\"\nlower_case=skipped\n",
+        );
+        let backend =
+            EnvFileSecretsBackend::new(dir.path(), &EnvFileSecretsConfig { path: Some(path) });
+        let name = SecretName::new("GITHUB_TOKEN")?;
+
+        assert_eq!(
+            backend.get(&SecretScope::Global, &name)?,
+            Some("gh 456".to_string())
+        );
+        assert_eq!(
+            backend.get(&SecretScope::environment("repo")?, &name)?,
+            None
+        );
+        let names: Vec<String> = backend
+            .list(None)?
+            .into_iter()
+            .map(|entry| entry.name.to_string())
+            .collect();
+        assert_eq!(names, vec!["GITHUB_TOKEN", "NPM_TOKEN"]);
+        assert!(backend.set(&SecretScope::Global, &name, "value").is_err());
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn rejects_group_readable_env_file() -> Result<()> {
+        use std::os::unix::fs::PermissionsExt;
+
+        let dir = tempfile::tempdir().expect("tempdir");
+        let path = write_env_file(dir.path(), "NPM_TOKEN=npm-123\n");
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o640))?;
+        let backend =
+            EnvFileSecretsBackend::new(dir.path(), &EnvFileSecretsConfig { path: Some(path) });
+
+        let error = backend
+            .get(&SecretScope::Global, &SecretName::new("NPM_TOKEN")?)
+            .expect_err("must reject group-readable file");
+        assert!(
+            error.to_string().contains("has permissions 640"),
+            "unexpected error: {error:#}"
+        );
+        Ok(())
+    }
+}
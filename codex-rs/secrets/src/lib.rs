@@ -12,9 +12,18 @@ use serde::Serialize;
 use sha2::Digest;
 use sha2::Sha256;
 
+mod env_file;
 mod local;
+mod pass;
+mod vault;
 
+pub use env_file::EnvFileSecretsBackend;
+pub use env_file::EnvFileSecretsConfig;
 pub use local::LocalSecretsBackend;
+pub use pass::PassSecretsBackend;
+pub use pass::PassSecretsConfig;
+pub use vault::VaultSecretsBackend;
+pub use vault::VaultSecretsConfig;
 
 const KEYRING_SERVICE: &str = "codex";
 
@@ -77,10 +86,35 @@ pub struct SecretListEntry {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema, Default)]
-#[serde(rename_all = "lowercase")]
+#[serde(rename_all = "kebab-case")]
 pub enum SecretsBackendKind {
+    /// Encrypted file under `CODEX_HOME/secrets`, keyed by a passphrase stored in the OS keyring.
     #[default]
     Local,
+    /// Read-only dotenv file that must not be accessible to group or others.
+    EnvFile,
+    /// The standard unix password manager, `pass(1)`.
+    Pass,
+    /// A HashiCorp Vault KV v2-compatible HTTP API.
+    Vault,
+}
+
+/// `[secrets]` configuration: which backend stores secrets and how to reach it.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[schemars(deny_unknown_fields)]
+pub struct SecretsConfig {
+    /// Backend used to store and resolve secrets.
+    #[serde(default)]
+    pub backend: SecretsBackendKind,
+    /// Settings for `backend = "env-file"`.
+    #[serde(default)]
+    pub env_file: EnvFileSecretsConfig,
+    /// Settings for `backend = "pass"`.
+    #[serde(default)]
+    pub pass: PassSecretsConfig,
+    /// Settings for `backend = "vault"`.
+    #[serde(default)]
+    pub vault: VaultSecretsConfig,
 }
 
 pub trait SecretsBackend: Send + Sync {
@@ -96,26 +130,53 @@ pub struct SecretsManager {
 }
 
 impl SecretsManager {
-    pub fn new(codex_home: PathBuf, backend_kind: SecretsBackendKind) -> Self {
-        let backend: Arc<dyn SecretsBackend> = match backend_kind {
-            SecretsBackendKind::Local => {
-                let keyring_store: Arc<dyn KeyringStore> = Arc::new(DefaultKeyringStore);
-                Arc::new(LocalSecretsBackend::new(codex_home, keyring_store))
-            }
+    /// Creates a manager for `backend_kind` using the default settings for that backend.
+    pub fn new(codex_home: PathBuf, backend_kind: SecretsBackendKind) -> Result<Self> {
+        let config = SecretsConfig {
+            backend: backend_kind,
+            ..SecretsConfig::default()
         };
-        Self { backend }
+        Self::from_config(codex_home, &config)
+    }
+
+    /// Creates a manager for the backend selected by `config`.
+    pub fn from_config(codex_home: PathBuf, config: &SecretsConfig) -> Result<Self> {
+        let keyring_store: Arc<dyn KeyringStore> = Arc::new(DefaultKeyringStore);
+        Self::from_config_with_keyring_store(codex_home, config, keyring_store)
     }
 
     pub fn new_with_keyring_store(
         codex_home: PathBuf,
         backend_kind: SecretsBackendKind,
         keyring_store: Arc<dyn KeyringStore>,
-    ) -> Self {
-        let backend: Arc<dyn SecretsBackend> = match backend_kind {
+    ) -> Result<Self> {
+        let config = SecretsConfig {
+            backend: backend_kind,
+            ..SecretsConfig::default()
+        };
+        Self::from_config_with_keyring_store(codex_home, &config, keyring_store)
+    }
+
+    pub fn from_config_with_keyring_store(
+        codex_home: PathBuf,
+        config: &SecretsConfig,
+        keyring_store: Arc<dyn KeyringStore>,
+    ) -> Result<Self> {
+        let backend: Arc<dyn SecretsBackend> = match config.backend {
             SecretsBackendKind::Local => {
                 Arc::new(LocalSecretsBackend::new(codex_home, keyring_store))
             }
+            SecretsBackendKind::EnvFile => {
+                Arc::new(EnvFileSecretsBackend::new(&codex_home, &config.env_file))
+            }
+            SecretsBackendKind::Pass => Arc::new(PassSecretsBackend::new(&config.pass)),
+            SecretsBackendKind::Vault => Arc::new(VaultSecretsBackend::new(&config.vault)?),
         };
+        Ok(Self::with_backend(backend))
+    }
+
+    /// Wraps an already constructed backend.
+    pub fn with_backend(backend: Arc<dyn SecretsBackend>) -> Self {
         Self { backend }
     }
 
@@ -175,6 +236,36 @@ fn get_git_repo_root(base_dir: &Path) -> Option<PathBuf> {
     None
 }
 
+/// Inverse of [`SecretScope::canonical_key`].
+pub(crate) fn parse_canonical_key(canonical_key: &str) -> Option<SecretListEntry> {
+    let mut parts = canonical_key.split('/');
+    let scope_kind = parts.next()?;
+    match scope_kind {
+        "global" => {
+            let name = parts.next()?;
+            if parts.next().is_some() {
+                return None;
+            }
+            let name = SecretName::new(name).ok()?;
+            Some(SecretListEntry {
+                scope: SecretScope::Global,
+                name,
+            })
+        }
+        "env" => {
+            let environment_id = parts.next()?;
+            let name = parts.next()?;
+            if parts.next().is_some() {
+                return None;
+            }
+            let name = SecretName::new(name).ok()?;
+            let scope = SecretScope::environment(environment_id.to_string()).ok()?;
+            Some(SecretListEntry { scope, name })
+        }
+        _ => None,
+    }
+}
+
 pub(crate) fn compute_keyring_account(codex_home: &Path) -> String {
     let canonical = codex_home
         .canonicalize()
@@ -225,7 +316,7 @@ mod tests {
             codex_home.path().to_path_buf(),
             SecretsBackendKind::Local,
             keyring,
-        );
+        )?;
         let scope = SecretScope::Global;
         let name = SecretName::new("GITHUB_TOKEN")?;
 
@@ -240,4 +331,22 @@ mod tests {
         assert_eq!(manager.get(&scope, &name)?, None);
         Ok(())
     }
+
+    #[test]
+    fn secrets_config_parses_backend_settings() {
+        let config: SecretsConfig = serde_json::from_value(serde_json::json!({
+            "backend": "env-file",
+            "env_file": { "path": "/etc/codex/ci.env" },
+            "vault": { "address": "http://127.0.0.1:8200", "mount": "kv" },
+        }))
+        .expect("config should parse");
+
+        assert_eq!(config.backend, SecretsBackendKind::EnvFile);
+        assert_eq!(
+            config.env_file.path,
+            Some(PathBuf::from("/etc/codex/ci.env"))
+        );
+        assert_eq!(config.vault.mount.as_deref(), Some("kv"));
+        assert_eq!(config.pass, PassSecretsConfig::default());
+    }
 }
@@ -32,6 +32,7 @@ use super::SecretScope;
 use super::SecretsBackend;
 use super::compute_keyring_account;
 use super::keyring_service;
+use super::parse_canonical_key;
 
 const SECRETS_VERSION: u8 = 1;
 const LOCAL_SECRETS_FILENAME: &str = "local.age";
@@ -300,35 +301,6 @@ fn decrypt_with_passphrase(ciphertext: &[u8], passphrase: &SecretString) -> Resu
     decrypt(&identity, ciphertext).context("failed to decrypt secrets file")
 }
 
-fn parse_canonical_key(canonical_key: &str) -> Option<SecretListEntry> {
-    let mut parts = canonical_key.split('/');
-    let scope_kind = parts.next()?;
-    match scope_kind {
-        "global" => {
-            let name = parts.next()?;
-            if parts.next().is_some() {
-                return None;
-            }
-            let name = SecretName::new(name).ok()?;
-            Some(SecretListEntry {
-                scope: SecretScope::Global,
-                name,
-            })
-        }
-        "env" => {
-            let environment_id = parts.next()?;
-            let name = parts.next()?;
-            if parts.next().is_some() {
-                return None;
-            }
-            let name = SecretName::new(name).ok()?;
-            let scope = SecretScope::environment(environment_id.to_string()).ok()?;
-            Some(SecretListEntry { scope, name })
-        }
-        _ => None,
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,272 @@
+use std::io::Write;
+use std::path::Path;
+use std::path::PathBuf;
+use std::process::Command;
+use std::process::Output;
+use std::process::Stdio;
+
+use anyhow::Context;
+use anyhow::Result;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+use tracing::warn;
+
+use super::SecretListEntry;
+use super::SecretName;
+use super::SecretScope;
+use super::SecretsBackend;
+use super::parse_canonical_key;
+
+const DEFAULT_PASS_COMMAND: &str = "pass";
+const DEFAULT_PASS_PREFIX: &str = "codex";
+const PASSWORD_STORE_DIR_ENV_VAR: &str = "PASSWORD_STORE_DIR";
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[schemars(deny_unknown_fields)]
+pub struct PassSecretsConfig {
+    /// `pass` executable to run. Defaults to `pass` on `PATH`.
+    pub command: Option<PathBuf>,
+    /// Password store directory. Defaults to `$PASSWORD_STORE_DIR`, then `~/.password-store`.
+    pub store_dir: Option<PathBuf>,
+    /// Folder inside the password store that holds Codex secrets. Defaults to `codex`.
+    pub prefix: Option<String>,
+}
+
+/// Stores secrets in a `pass(1)` password store.
+///
+/// Entries live at `<prefix>/global/<NAME>` and `<prefix>/env/<environment>/<NAME>`, and only
+/// the first line of an entry is used as the secret value, following the `pass` convention.
+#[derive(Debug, Clone)]
+pub struct PassSecretsBackend {
+    command: PathBuf,
+    store_dir: Option<PathBuf>,
+    prefix: String,
+}
+
+impl PassSecretsBackend {
+    pub fn new(config: &PassSecretsConfig) -> Self {
+        let store_dir = config.store_dir.clone().or_else(|| {
+            std::env::var_os(PASSWORD_STORE_DIR_ENV_VAR)
+                .map(PathBuf::from)
+                .or_else(|| dirs::home_dir().map(|home| home.join(".password-store")))
+        });
+        Self {
+            command: config
+                .command
+                .clone()
+                .unwrap_or_else(|| PathBuf::from(DEFAULT_PASS_COMMAND)),
+            store_dir,
+            prefix: config
+                .prefix
+                .as_deref()
+                .map(|prefix| prefix.trim_matches('/'))
+                .filter(|prefix| !prefix.is_empty())
+                .unwrap_or(DEFAULT_PASS_PREFIX)
+                .to_string(),
+        }
+    }
+
+    fn entry_path(&self, scope: &SecretScope, name: &SecretName) -> String {
+        format!("{}/{}", self.prefix, scope.canonical_key(name))
+    }
+
+    fn run(&self, args: &[&str], stdin: Option<&str>) -> Result<Output> {
+        let mut command = Command::new(&self.command);
+        command
+            .args(args)
+            .stdin(if stdin.is_some() {
+                Stdio::piped()
+            } else {
+                Stdio::null()
+            })
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped());
+        if let Some(store_dir) = &self.store_dir {
+            command.env(PASSWORD_STORE_DIR_ENV_VAR, store_dir);
+        }
+        let mut child = command
+            .spawn()
+            .with_context(|| format!("failed to run {}", self.command.display()))?;
+        if let Some(input) = stdin
+            && let Some(mut child_stdin) = child.stdin.take()
+        {
+            child_stdin
+                .write_all(input.as_bytes())
+                .with_context(|| format!("failed to write to {}", self.command.display()))?;
+        }
+        child
+            .wait_with_output()
+            .with_context(|| format!("failed to wait for {}", self.command.display()))
+    }
+
+    fn list_store_entries(&self, dir: &Path, entries: &mut Vec<String>) -> Result<()> {
+        let read_dir = match std::fs::read_dir(dir) {
+            Ok(read_dir) => read_dir,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(()),
+            Err(err) => {
+                return Err(err)
+                    .with_context(|| format!("failed to read password store {}", dir.display()));
+            }
+        };
+        for entry in read_dir {
+            let path = entry?.path();
+            if path.is_dir() {
+                self.list_store_entries(&path, entries)?;
+            } else if path.extension().is_some_and(|ext| ext == "gpg")
+                && let Some(store_dir) = &self.store_dir
+                && let Ok(relative) = path.with_extension("").strip_prefix(store_dir)
+            {
+                let relative = relative
+                    .components()
+                    .map(|component| component.as_os_str().to_string_lossy())
+                    .collect::<Vec<_>>()
+                    .join("/");
+                entries.push(relative);
+            }
+        }
+        Ok(())
+    }
+}
+
+impl SecretsBackend for PassSecretsBackend {
+    fn set(&self, scope: &SecretScope, name: &SecretName, value: &str) -> Result<()> {
+        anyhow::ensure!(!value.is_empty(), "secret value must not be empty");
+        let path = self.entry_path(scope, name);
+        let output = self.run(
+            &["insert", "--multiline", "--force", &path],
+            Some(&format!("{value}\n")),
+        )?;
+        ensure_success(&output, "insert", &path)
+    }
+
+    fn get(&self, scope: &SecretScope, name: &SecretName) -> Result<Option<String>> {
+        let path = self.entry_path(scope, name);
+        let output = self.run(&["show", &path], None)?;
+        if !output.status.success() {
+            if is_missing_entry(&output) {
+                return Ok(None);
+            }
+            ensure_success(&output, "show", &path)?;
+        }
+        let stdout = String::from_utf8(output.stdout)
+            .with_context(|| format!("pass entry {path} is not valid UTF-8"))?;
+        Ok(stdout
+            .lines()
+            .next()
+            .filter(|value| !value.is_empty())
+            .map(ToString::to_string))
+    }
+
+    fn delete(&self, scope: &SecretScope, name: &SecretName) -> Result<bool> {
+        let path = self.entry_path(scope, name);
+        let output = self.run(&["rm", "--force", &path], None)?;
+        if !output.status.success() && is_missing_entry(&output) {
+            return Ok(false);
+        }
+        ensure_success(&output, "rm", &path)?;
+        Ok(true)
+    }
+
+    fn list(&self, scope_filter: Option<&SecretScope>) -> Result<Vec<SecretListEntry>> {
+        let Some(store_dir) = &self.store_dir else {
+            anyhow::bail!("could not determine the password store directory; set pass.store_dir");
+        };
+        let mut paths = Vec::new();
+        self.list_store_entries(&store_dir.join(&self.prefix), &mut paths)?;
+        paths.sort();
+
+        let mut entries = Vec::new();
+        for path in paths {
+            let Some(canonical_key) = path
+                .strip_prefix(&self.prefix)
+                .and_then(|rest| rest.strip_prefix('/'))
+            else {
+                continue;
+            };
+            let Some(entry) = parse_canonical_key(canonical_key) else {
+                warn!("skipping unrecognized pass entry: {path}");
+                continue;
+            };
+            if let Some(scope) = scope_filter
+                && entry.scope != *scope
+            {
+                continue;
+            }
+            entries.push(entry);
+        }
+        Ok(entries)
+    }
+}
+
+fn is_missing_entry(output: &Output) -> bool {
+    String::from_utf8_lossy(&output.stderr).contains("is not in the password store")
+}
+
+fn ensure_success(output: &Output, action: &str, path: &str) -> Result<()> {
+    anyhow::ensure!(
+        output.status.success(),
+        "pass {action} {path} failed: {}",
+        String::from_utf8_lossy(&output.stderr).trim()
+    );
+    Ok(())
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use std::os::unix::fs::PermissionsExt;
+
+    /// Minimal stand-in for `pass` that stores entries as plaintext `.gpg` files.
+    const FAKE_PASS: &str = r#"#!/bin/sh
+set -e
+cmd="$1"; shift
+while [ "${1#--}" != "$1" ]; do shift; done
+file="$PASSWORD_STORE_DIR/$1.gpg"
+case "$cmd" in
+  insert) mkdir -p "$(dirname "$file")"; cat > "$file" ;;
+  show|rm)
+    if [ ! -f "$file" ]; then echo "Error: $1 is not in the password store." >&2; exit 1; fi
+    if [ "$cmd" = show ]; then cat "$file"; else rm "$file"; fi ;;
+esac
+"#;
+
+    #[test]
+    fn round_trips_through_pass_command() -> Result<()> {
+        let dir = tempfile::tempdir().expect("tempdir");
+        let command = dir.path().join("pass");
+        std::fs::write(&command, FAKE_PASS)?;
+        std::fs::set_permissions(&command, std::fs::Permissions::from_mode(0o755))?;
+        let backend = PassSecretsBackend::new(&PassSecretsConfig {
+            command: Some(command),
+            store_dir: Some(dir.path().join("store")),
+            prefix: None,
+        });
+        let scope = SecretScope::environment("repo")?;
+        let name = SecretName::new("NPM_TOKEN")?;
+
+        assert_eq!(backend.get(&scope, &name)?, None);
+        backend.set(&scope, &name, "npm-123")?;
+        backend.set(&SecretScope::Global, &name, "npm-global")?;
+        assert_eq!(backend.get(&scope, &name)?, Some("npm-123".to_string()));
+        assert!(
+            dir.path()
+                .join("store/codex/env/repo/NPM_TOKEN.gpg")
+                .exists()
+        );
+        assert_eq!(
+            backend.list(Some(&scope))?,
+            vec![SecretListEntry {
+                scope: scope.clone(),
+                name: name.clone(),
+            }]
+        );
+        assert_eq!(backend.list(None)?.len(), 2);
+
+        assert!(backend.delete(&scope, &name)?);
+        assert!(!backend.delete(&scope, &name)?);
+        assert_eq!(backend.get(&scope, &name)?, None);
+        Ok(())
+    }
+}
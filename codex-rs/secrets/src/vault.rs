@@ -0,0 +1,363 @@
+use std::time::Duration;
+
+use anyhow::Context;
+use anyhow::Result;
+use reqwest::StatusCode;
+use reqwest::blocking::Client;
+use reqwest::blocking::RequestBuilder;
+use reqwest::blocking::Response;
+use schemars::JsonSchema;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::json;
+use tracing::warn;
+
+use super::SecretListEntry;
+use super::SecretName;
+use super::SecretScope;
+use super::SecretsBackend;
+use super::parse_canonical_key;
+
+const DEFAULT_VAULT_MOUNT: &str = "secret";
+const DEFAULT_VAULT_PATH_PREFIX: &str = "codex";
+const DEFAULT_VAULT_TOKEN_ENV_VAR: &str = "VAULT_TOKEN";
+const VAULT_ADDR_ENV_VAR: &str = "VAULT_ADDR";
+const VAULT_REQUEST_TIMEOUT: Duration = Duration::from_secs(10);
+/// Field of the KV v2 secret data that holds the secret value.
+const VALUE_FIELD: &str = "value";
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[schemars(deny_unknown_fields)]
+pub struct VaultSecretsConfig {
+    /// Base URL of the server, e.g. `https://vault.example.com:8200`. Defaults to `$VAULT_ADDR`.
+    pub address: Option<String>,
+    /// Mount point of the KV v2 secrets engine. Defaults to `secret`.
+    pub mount: Option<String>,
+    /// Path under the mount that holds Codex secrets. Defaults to `codex`.
+    pub path_prefix: Option<String>,
+    /// Environment variable that holds the access token. Defaults to `VAULT_TOKEN`.
+    pub token_env_var: Option<String>,
+    /// Namespace sent as `X-Vault-Namespace`, for Vault Enterprise and HCP Vault.
+    pub namespace: Option<String>,
+}
+
+/// Stores secrets in a HashiCorp Vault KV v2-compatible HTTP API.
+///
+/// Each secret is a KV entry at `<mount>/<path_prefix>/global/<NAME>` or
+/// `<mount>/<path_prefix>/env/<environment>/<NAME>` whose data is `{"value": "<secret>"}`.
+/// Requests use a blocking HTTP client, so callers on an async runtime must use
+/// `spawn_blocking`.
+#[derive(Clone)]
+pub struct VaultSecretsBackend {
+    client: Client,
+    address: String,
+    mount: String,
+    path_prefix: String,
+    token: String,
+    namespace: Option<String>,
+}
+
+impl std::fmt::Debug for VaultSecretsBackend {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("VaultSecretsBackend")
+            .field("address", &self.address)
+            .field("mount", &self.mount)
+            .field("path_prefix", &self.path_prefix)
+            .field("namespace", &self.namespace)
+            .finish_non_exhaustive()
+    }
+}
+
+#[derive(Deserialize)]
+struct VaultReadResponse {
+    data: VaultReadData,
+}
+
+#[derive(Deserialize)]
+struct VaultReadData {
+    data: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Deserialize)]
+struct VaultListResponse {
+    data: VaultListData,
+}
+
+#[derive(Deserialize)]
+struct VaultListData {
+    keys: Vec<String>,
+}
+
+impl VaultSecretsBackend {
+    pub fn new(config: &VaultSecretsConfig) -> Result<Self> {
+        let address = match &config.address {
+            Some(address) => address.clone(),
+            None => std::env::var(VAULT_ADDR_ENV_VAR).with_context(|| {
+                format!("vault.address is not configured and {VAULT_ADDR_ENV_VAR} is not set")
+            })?,
+        };
+        let token_env_var = config
+            .token_env_var
+            .as_deref()
+            .unwrap_or(DEFAULT_VAULT_TOKEN_ENV_VAR);
+        let token = std::env::var(token_env_var)
+            .with_context(|| format!("{token_env_var} must be set to use the vault backend"))?;
+        let client = Client::builder()
+            .timeout(VAULT_REQUEST_TIMEOUT)
+            .build()
+            .context("failed to build vault HTTP client")?;
+        Ok(Self {
+            client,
+            address: address.trim_end_matches('/').to_string(),
+            mount: trimmed_or(config.mount.as_deref(), DEFAULT_VAULT_MOUNT),
+            path_prefix: trimmed_or(config.path_prefix.as_deref(), DEFAULT_VAULT_PATH_PREFIX),
+            token,
+            namespace: config.namespace.clone(),
+        })
+    }
+
+    fn url(&self, kind: &str, path: &str) -> String {
+        format!(
+            "{}/v1/{}/{kind}/{}/{path}",
+            self.address, self.mount, self.path_prefix
+        )
+    }
+
+    fn send(&self, request: RequestBuilder) -> Result<Response> {
+        let mut request = request.header("X-Vault-Token", &self.token);
+        if let Some(namespace) = &self.namespace {
+            request = request.header("X-Vault-Namespace", namespace);
+        }
+        request.send().context("vault request failed")
+    }
+
+    fn list_keys(&self, folder: &str, keys: &mut Vec<String>) -> Result<()> {
+        let url = format!("{}?list=true", self.url("metadata", folder));
+        let response = self.send(self.client.get(&url))?;
+        if response.status() == StatusCode::NOT_FOUND {
+            return Ok(());
+        }
+        let response: VaultListResponse = ensure_success(response, "list", folder)?
+            .json()
+            .context("failed to parse vault list response")?;
+        for key in response.data.keys {
+            let path = format!("{folder}{key}");
+            if key.ends_with('/') {
+                self.list_keys(&path, keys)?;
+            } else {
+                keys.push(path);
+            }
+        }
+        Ok(())
+    }
+}
+
+impl SecretsBackend for VaultSecretsBackend {
+    fn set(&self, scope: &SecretScope, name: &SecretName, value: &str) -> Result<()> {
+        anyhow::ensure!(!value.is_empty(), "secret value must not be empty");
+        let path = scope.canonical_key(name);
+        let body = json!({ "data": { VALUE_FIELD: value } });
+        let response = self.send(self.client.post(self.url("data", &path)).json(&body))?;
+        ensure_success(response, "write", &path)?;
+        Ok(())
+    }
+
+    fn get(&self, scope: &SecretScope, name: &SecretName) -> Result<Option<String>> {
+        let path = scope.canonical_key(name);
+        let response = self.send(self.client.get(self.url("data", &path)))?;
+        if response.status() == StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+        let response: VaultReadResponse = ensure_success(response, "read", &path)?
+            .json()
+            .context("failed to parse vault read response")?;
+        match response.data.data.get(VALUE_FIELD) {
+            Some(serde_json::Value::String(value)) => Ok(Some(value.clone())),
+            Some(_) => anyhow::bail!("vault secret {path} has a non-string `{VALUE_FIELD}` field"),
+            None => anyhow::bail!("vault secret {path} has no `{VALUE_FIELD}` field"),
+        }
+    }
+
+    fn delete(&self, scope: &SecretScope, name: &SecretName) -> Result<bool> {
+        if self.get(scope, name)?.is_none() {
+            return Ok(false);
+        }
+        let path = scope.canonical_key(name);
+        // Deleting the metadata removes every version, not just the latest one.
+        let response = self.send(self.client.delete(self.url("metadata", &path)))?;
+        ensure_success(response, "delete", &path)?;
+        Ok(true)
+    }
+
+    fn list(&self, scope_filter: Option<&SecretScope>) -> Result<Vec<SecretListEntry>> {
+        let mut keys = Vec::new();
+        self.list_keys("", &mut keys)?;
+        keys.sort();
+
+        let mut entries = Vec::new();
+        for key in keys {
+            let Some(entry) = parse_canonical_key(&key) else {
+                warn!("skipping unrecognized vault secret: {key}");
+                continue;
+            };
+            if let Some(scope) = scope_filter
+                && entry.scope != *scope
+            {
+                continue;
+            }
+            entries.push(entry);
+        }
+        Ok(entries)
+    }
+}
+
+fn trimmed_or(value: Option<&str>, default: &str) -> String {
+    value
+        .map(|value| value.trim_matches('/'))
+        .filter(|value| !value.is_empty())
+        .unwrap_or(default)
+        .to_string()
+}
+
+fn ensure_success(response: Response, action: &str, path: &str) -> Result<Response> {
+    let status = response.status();
+    if status.is_success() {
+        return Ok(response);
+    }
+    let body = response.text().unwrap_or_default();
+    anyhow::bail!("vault {action} of {path} failed with {status}: {body}");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pretty_assertions::assert_eq;
+    use std::collections::BTreeMap;
+    use std::sync::Arc;
+    use std::sync::Mutex;
+    use tiny_http::Header;
+    use tiny_http::Method;
+    use tiny_http::Server;
+
+    const TEST_TOKEN_ENV_VAR: &str = "CODEX_SECRETS_TEST_VAULT_TOKEN";
+
+    /// Serves the subset of the KV v2 API used by the backend from an in-memory map.
+    fn start_mock_vault() -> String {
+        let server = Server::http("127.0.0.1:0").expect("bind mock vault");
+        let address = format!("http://{}", server.server_addr());
+        let store: Arc<Mutex<BTreeMap<String, String>>> = Arc::default();
+        std::thread::spawn(move || {
+            for mut request in server.incoming_requests() {
+                let authorized = request.headers().iter().any(|header| {
+                    header.field.equiv("X-Vault-Token") && header.value.as_str() == "test-token"
+                });
+                let url = request.url().to_string();
+                let (path, query) = url.split_once('?').unwrap_or((url.as_str(), ""));
+                let mut body = String::new();
+                let _ = request.as_reader().read_to_string(&mut body);
+                let mut store = store.lock().expect("store lock");
+                let (status, response) = if !authorized {
+                    (403, json!({ "errors": ["permission denied"] }))
+                } else if let Some(key) = path.strip_prefix("/v1/kv/data/") {
+                    match request.method() {
+                        Method::Get => match store.get(key) {
+                            Some(value) => (
+                                200,
+                                json!({ "data": { "data": { "value": value }, "metadata": {} } }),
+                            ),
+                            None => (404, json!({ "errors": [] })),
+                        },
+                        Method::Post => {
+                            let body: serde_json::Value =
+                                serde_json::from_str(&body).expect("json body");
+                            let value = body["data"]["value"].as_str().expect("value");
+                            store.insert(key.to_string(), value.to_string());
+                            (200, json!({ "data": { "version": 1 } }))
+                        }
+                        _ => (405, json!({ "errors": [] })),
+                    }
+                } else if let Some(key) = path.strip_prefix("/v1/kv/metadata/") {
+                    match request.method() {
+                        Method::Get if query == "list=true" => {
+                            let mut keys: Vec<String> = store
+                                .keys()
+                                .filter_map(|stored| stored.strip_prefix(key))
+                                .map(|rest| match rest.split_once('/') {
+                                    Some((folder, _)) => format!("{folder}/"),
+                                    None => rest.to_string(),
+                                })
+                                .collect();
+                            keys.dedup();
+                            if keys.is_empty() {
+                                (404, json!({ "errors": [] }))
+                            } else {
+                                (200, json!({ "data": { "keys": keys } }))
+                            }
+                        }
+                        Method::Delete => {
+                            store.remove(key);
+                            (204, json!({}))
+                        }
+                        _ => (405, json!({ "errors": [] })),
+                    }
+                } else {
+                    (404, json!({ "errors": [] }))
+                };
+                let response = tiny_http::Response::from_string(response.to_string())
+                    .with_status_code(status)
+                    .with_header(
+                        Header::from_bytes(&b"Content-Type"[..], &b"application/json"[..])
+                            .expect("header"),
+                    );
+                let _ = request.respond(response);
+            }
+        });
+        address
+    }
+
+    #[test]
+    fn round_trips_through_kv_v2_api() -> Result<()> {
+        let address = start_mock_vault();
+        // SAFETY: this is the only test that touches this variable.
+        unsafe { std::env::set_var(TEST_TOKEN_ENV_VAR, "test-token") };
+        let backend = VaultSecretsBackend::new(&VaultSecretsConfig {
+            address: Some(address),
+            mount: Some("kv".to_string()),
+            path_prefix: Some("team/codex".to_string()),
+            token_env_var: Some(TEST_TOKEN_ENV_VAR.to_string()),
+            namespace: None,
+        })?;
+        let scope = SecretScope::environment("repo")?;
+        let name = SecretName::new("NPM_TOKEN")?;
+
+        assert_eq!(backend.get(&scope, &name)?, None);
+        assert_eq!(backend.list(None)?, Vec::new());
+        backend.set(&scope, &name, "npm-123")?;
+        backend.set(&SecretScope::Global, &name, "npm-global")?;
+        assert_eq!(backend.get(&scope, &name)?, Some("npm-123".to_string()));
+        assert_eq!(
+            backend.list(None)?,
+            vec![
+                SecretListEntry {
+                    scope: scope.clone(),
+                    name: name.clone(),
+                },
+                SecretListEntry {
+                    scope: SecretScope::Global,
+                    name: name.clone(),
+                },
+            ]
+        );
+
+        assert!(backend.delete(&scope, &name)?);
+        assert!(!backend.delete(&scope, &name)?);
+        assert_eq!(
+            backend.list(Some(&SecretScope::Global))?,
+            vec![SecretListEntry {
+                scope: SecretScope::Global,
+                name,
+            }]
+        );
+        Ok(())
+    }
+}
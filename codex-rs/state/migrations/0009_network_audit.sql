@@ -0,0 +1,17 @@
+CREATE TABLE network_audit (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    ts INTEGER NOT NULL,
+    ts_nanos INTEGER NOT NULL,
+    thread_id TEXT,
+    protocol TEXT NOT NULL,
+    host TEXT NOT NULL,
+    port INTEGER,
+    method TEXT,
+    decision TEXT NOT NULL,
+    reason TEXT,
+    client TEXT
+);
+
+CREATE INDEX idx_network_audit_ts ON network_audit(ts DESC, ts_nanos DESC, id DESC);
+CREATE INDEX idx_network_audit_thread_id ON network_audit(thread_id);
+CREATE INDEX idx_network_audit_host ON network_audit(host);
@@ -0,0 +1,8 @@
+CREATE VIRTUAL TABLE thread_search USING fts5(
+    thread_id UNINDEXED,
+    kind UNINDEXED,
+    content,
+    tokenize = 'trigram'
+);
+
+ALTER TABLE threads ADD COLUMN search_indexed INTEGER NOT NULL DEFAULT 0;
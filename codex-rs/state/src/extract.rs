@@ -1,4 +1,7 @@
 use crate::model::ThreadMetadata;
+use crate::model::ThreadSearchEntry;
+use crate::model::ThreadSearchKind;
+use codex_protocol::models::LocalShellAction;
 use codex_protocol::models::ResponseItem;
 use codex_protocol::protocol::EventMsg;
 use codex_protocol::protocol::RolloutItem;
@@ -108,6 +111,144 @@ fn user_message_preview(user: &UserMessageEvent) -> Option<String> {
     None
 }
 
+/// Extract the searchable text of rollout items: user and agent messages,
+/// commands run and files touched by edit tools.
+pub(crate) fn thread_search_entries(items: &[RolloutItem]) -> Vec<ThreadSearchEntry> {
+    let mut entries = Vec::new();
+    for item in items {
+        match item {
+            RolloutItem::EventMsg(EventMsg::UserMessage(user)) => {
+                push_search_entry(
+                    &mut entries,
+                    ThreadSearchKind::UserMessage,
+                    strip_user_message_prefix(user.message.as_str()),
+                );
+            }
+            RolloutItem::EventMsg(EventMsg::AgentMessage(agent)) => {
+                push_search_entry(
+                    &mut entries,
+                    ThreadSearchKind::AgentMessage,
+                    agent.message.as_str(),
+                );
+            }
+            RolloutItem::ResponseItem(item) => push_tool_call_entries(&mut entries, item),
+            RolloutItem::SessionMeta(_)
+            | RolloutItem::TurnContext(_)
+            | RolloutItem::Compacted(_)
+            | RolloutItem::EventMsg(_) => {}
+        }
+    }
+    entries
+}
+
+fn push_tool_call_entries(entries: &mut Vec<ThreadSearchEntry>, item: &ResponseItem) {
+    match item {
+        ResponseItem::LocalShellCall {
+            action: LocalShellAction::Exec(exec),
+            ..
+        } => {
+            push_search_entry(
+                entries,
+                ThreadSearchKind::Command,
+                &shell_command_text(&exec.command),
+            );
+        }
+        ResponseItem::FunctionCall {
+            name, arguments, ..
+        } => {
+            let Ok(arguments) = serde_json::from_str::<Value>(arguments) else {
+                return;
+            };
+            match name.as_str() {
+                "exec_command" => {
+                    if let Some(cmd) = arguments.get("cmd").and_then(Value::as_str) {
+                        push_search_entry(entries, ThreadSearchKind::Command, cmd);
+                    }
+                }
+                "shell" => {
+                    if let Some(command) = arguments.get("command").and_then(Value::as_array) {
+                        let command = command
+                            .iter()
+                            .filter_map(Value::as_str)
+                            .map(str::to_string)
+                            .collect::<Vec<_>>();
+                        push_search_entry(
+                            entries,
+                            ThreadSearchKind::Command,
+                            &shell_command_text(&command),
+                        );
+                    }
+                }
+                "shell_command" | "Bash" => {
+                    if let Some(command) = arguments.get("command").and_then(Value::as_str) {
+                        push_search_entry(entries, ThreadSearchKind::Command, command);
+                    }
+                }
+                "Write" | "Edit" => {
+                    if let Some(path) = arguments.get("file_path").and_then(Value::as_str) {
+                        push_search_entry(entries, ThreadSearchKind::File, path);
+                    }
+                }
+                "NotebookEdit" => {
+                    if let Some(path) = arguments.get("notebook_path").and_then(Value::as_str) {
+                        push_search_entry(entries, ThreadSearchKind::File, path);
+                    }
+                }
+                "apply_patch" => {
+                    if let Some(input) = arguments.get("input").and_then(Value::as_str) {
+                        push_patch_file_entries(entries, input);
+                    }
+                }
+                _ => {}
+            }
+        }
+        ResponseItem::CustomToolCall { name, input, .. } if name == "apply_patch" => {
+            push_patch_file_entries(entries, input);
+        }
+        _ => {}
+    }
+}
+
+/// Render an argv vector, unwrapping `bash -lc "<script>"` style invocations
+/// to the script itself.
+fn shell_command_text(command: &[String]) -> String {
+    match command {
+        [_, flag, script] if flag == "-c" || flag == "-lc" => script.clone(),
+        _ => command.join(" "),
+    }
+}
+
+fn push_patch_file_entries(entries: &mut Vec<ThreadSearchEntry>, patch: &str) {
+    const FILE_HEADERS: [&str; 4] = [
+        "*** Add File: ",
+        "*** Update File: ",
+        "*** Delete File: ",
+        "*** Move to: ",
+    ];
+    for line in patch.lines() {
+        if let Some(path) = FILE_HEADERS
+            .iter()
+            .find_map(|header| line.strip_prefix(header))
+        {
+            push_search_entry(entries, ThreadSearchKind::File, path);
+        }
+    }
+}
+
+fn push_search_entry(entries: &mut Vec<ThreadSearchEntry>, kind: ThreadSearchKind, text: &str) {
+    let content = text.trim();
+    if content.is_empty() {
+        return;
+    }
+    let entry = ThreadSearchEntry {
+        kind,
+        content: content.to_string(),
+    };
+    if !entries.contains(&entry) {
+        entries.push(entry);
+    }
+}
+
 pub(crate) fn enum_to_string<T: Serialize>(value: &T) -> String {
     match serde_json::to_value(value) {
         Ok(Value::String(s)) => s,
@@ -119,12 +260,19 @@ pub(crate) fn enum_to_string<T: Serialize>(value: &T) -> String {
 #[cfg(test)]
 mod tests {
     use super::apply_rollout_item;
+    use super::thread_search_entries;
     use crate::model::ThreadMetadata;
+    use crate::model::ThreadSearchEntry;
+    use crate::model::ThreadSearchKind;
     use chrono::DateTime;
     use chrono::Utc;
     use codex_protocol::ThreadId;
     use codex_protocol::models::ContentItem;
+    use codex_protocol::models::LocalShellAction;
+    use codex_protocol::models::LocalShellExecAction;
+    use codex_protocol::models::LocalShellStatus;
     use codex_protocol::models::ResponseItem;
+    use codex_protocol::protocol::AgentMessageEvent;
     use codex_protocol::protocol::EventMsg;
     use codex_protocol::protocol::RolloutItem;
     use codex_protocol::protocol::USER_MESSAGE_BEGIN;
@@ -207,6 +355,69 @@ mod tests {
         assert_eq!(metadata.title, "");
     }
 
+    #[test]
+    fn thread_search_entries_cover_messages_commands_and_files() {
+        let items = vec![
+            RolloutItem::EventMsg(EventMsg::UserMessage(UserMessageEvent {
+                message: format!("<context>{USER_MESSAGE_BEGIN} fix the flaky test"),
+                images: None,
+                local_images: vec![],
+                text_elements: vec![],
+            })),
+            RolloutItem::ResponseItem(ResponseItem::FunctionCall {
+                id: None,
+                name: "shell".to_string(),
+                arguments: r#"{"command":["bash","-lc","cargo test -p codex-state"]}"#.to_string(),
+                call_id: "call-1".to_string(),
+            }),
+            RolloutItem::ResponseItem(ResponseItem::LocalShellCall {
+                id: None,
+                call_id: None,
+                status: LocalShellStatus::Completed,
+                action: LocalShellAction::Exec(LocalShellExecAction {
+                    command: vec!["git".to_string(), "status".to_string()],
+                    timeout_ms: None,
+                    working_directory: None,
+                    env: None,
+                    user: None,
+                }),
+            }),
+            RolloutItem::ResponseItem(ResponseItem::CustomToolCall {
+                id: None,
+                status: None,
+                call_id: "call-2".to_string(),
+                name: "apply_patch".to_string(),
+                input: "*** Begin Patch\n*** Update File: src/lib.rs\n@@\n-a\n+b\n*** End Patch"
+                    .to_string(),
+            }),
+            RolloutItem::ResponseItem(ResponseItem::FunctionCall {
+                id: None,
+                name: "Write".to_string(),
+                arguments: r#"{"file_path":"/repo/README.md","content":"hi"}"#.to_string(),
+                call_id: "call-3".to_string(),
+            }),
+            RolloutItem::EventMsg(EventMsg::AgentMessage(AgentMessageEvent {
+                message: "The test is fixed.".to_string(),
+            })),
+        ];
+
+        let entry = |kind, content: &str| ThreadSearchEntry {
+            kind,
+            content: content.to_string(),
+        };
+        assert_eq!(
+            thread_search_entries(&items),
+            vec![
+                entry(ThreadSearchKind::UserMessage, "fix the flaky test"),
+                entry(ThreadSearchKind::Command, "cargo test -p codex-state"),
+                entry(ThreadSearchKind::Command, "git status"),
+                entry(ThreadSearchKind::File, "src/lib.rs"),
+                entry(ThreadSearchKind::File, "/repo/README.md"),
+                entry(ThreadSearchKind::AgentMessage, "The test is fixed."),
+            ]
+        );
+    }
+
     fn metadata_for_test() -> ThreadMetadata {
         let id = ThreadId::from_string(&Uuid::from_u128(42).to_string()).expect("thread id");
         let created_at = DateTime::<Utc>::from_timestamp(1_735_689_600, 0).expect("timestamp");
@@ -14,6 +14,10 @@ mod runtime;
 pub use model::LogEntry;
 pub use model::LogQuery;
 pub use model::LogRow;
+pub use model::NetworkAuditDecision;
+pub use model::NetworkAuditEntry;
+pub use model::NetworkAuditQuery;
+pub use model::NetworkAuditRow;
 pub use model::Phase2JobClaimOutcome;
 /// Preferred entrypoint: owns configuration and metrics.
 pub use runtime::StateRuntime;
@@ -34,6 +38,9 @@ pub use model::Stage1Output;
 pub use model::Stage1StartupClaimParams;
 pub use model::ThreadMetadata;
 pub use model::ThreadMetadataBuilder;
+pub use model::ThreadSearchEntry;
+pub use model::ThreadSearchHit;
+pub use model::ThreadSearchKind;
 pub use model::ThreadsPage;
 pub use runtime::STATE_DB_FILENAME;
 pub use runtime::STATE_DB_VERSION;
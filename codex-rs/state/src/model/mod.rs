@@ -1,7 +1,9 @@
 mod backfill_state;
 mod log;
 mod memories;
+mod network_audit;
 mod thread_metadata;
+mod thread_search;
 
 pub use backfill_state::BackfillState;
 pub use backfill_state::BackfillStatus;
@@ -13,6 +15,10 @@ pub use memories::Stage1JobClaim;
 pub use memories::Stage1JobClaimOutcome;
 pub use memories::Stage1Output;
 pub use memories::Stage1StartupClaimParams;
+pub use network_audit::NetworkAuditDecision;
+pub use network_audit::NetworkAuditEntry;
+pub use network_audit::NetworkAuditQuery;
+pub use network_audit::NetworkAuditRow;
 pub use thread_metadata::Anchor;
 pub use thread_metadata::BackfillStats;
 pub use thread_metadata::ExtractionOutcome;
@@ -20,6 +26,9 @@ pub use thread_metadata::SortKey;
 pub use thread_metadata::ThreadMetadata;
 pub use thread_metadata::ThreadMetadataBuilder;
 pub use thread_metadata::ThreadsPage;
+pub use thread_search::ThreadSearchEntry;
+pub use thread_search::ThreadSearchHit;
+pub use thread_search::ThreadSearchKind;
 
 pub(crate) use memories::Stage1OutputRow;
 pub(crate) use thread_metadata::ThreadRow;
@@ -0,0 +1,74 @@
+use anyhow::Result;
+use serde::Serialize;
+use sqlx::FromRow;
+
+/// One network proxy decision, as written to the `network_audit` table.
+#[derive(Clone, Debug, Serialize)]
+pub struct NetworkAuditEntry {
+    pub ts: i64,
+    pub ts_nanos: i64,
+    pub thread_id: Option<String>,
+    /// Proxy protocol label, e.g. `http`, `http-connect`, `socks5`, `socks5-udp`.
+    pub protocol: String,
+    pub host: String,
+    pub port: Option<i64>,
+    pub method: Option<String>,
+    pub decision: NetworkAuditDecision,
+    pub reason: Option<String>,
+    pub client: Option<String>,
+}
+
+#[derive(Clone, Debug, FromRow, Serialize)]
+pub struct NetworkAuditRow {
+    pub id: i64,
+    pub ts: i64,
+    pub ts_nanos: i64,
+    pub thread_id: Option<String>,
+    pub protocol: String,
+    pub host: String,
+    pub port: Option<i64>,
+    pub method: Option<String>,
+    pub decision: String,
+    pub reason: Option<String>,
+    pub client: Option<String>,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct NetworkAuditQuery {
+    pub thread_ids: Vec<String>,
+    /// Substring matches on `host`.
+    pub host_like: Vec<String>,
+    pub decision: Option<NetworkAuditDecision>,
+    pub from_ts: Option<i64>,
+    pub to_ts: Option<i64>,
+    pub after_id: Option<i64>,
+    /// Only rows with a smaller id; the cursor for paging through `descending` results.
+    pub before_id: Option<i64>,
+    pub limit: Option<usize>,
+    pub descending: bool,
+}
+
+/// Outcome recorded for an audited network request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum NetworkAuditDecision {
+    Allow,
+    Deny,
+}
+
+impl NetworkAuditDecision {
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            NetworkAuditDecision::Allow => "allow",
+            NetworkAuditDecision::Deny => "deny",
+        }
+    }
+
+    pub fn parse(value: &str) -> Result<Self> {
+        match value {
+            "allow" => Ok(Self::Allow),
+            "deny" => Ok(Self::Deny),
+            _ => Err(anyhow::anyhow!("invalid network audit decision: {value}")),
+        }
+    }
+}
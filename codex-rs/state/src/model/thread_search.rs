@@ -0,0 +1,52 @@
+use std::ops::Range;
+
+use super::ThreadMetadata;
+
+/// Which part of a thread a search entry was extracted from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ThreadSearchKind {
+    UserMessage,
+    AgentMessage,
+    Command,
+    File,
+}
+
+impl ThreadSearchKind {
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            ThreadSearchKind::UserMessage => "user_message",
+            ThreadSearchKind::AgentMessage => "agent_message",
+            ThreadSearchKind::Command => "command",
+            ThreadSearchKind::File => "file",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "user_message" => Some(ThreadSearchKind::UserMessage),
+            "agent_message" => Some(ThreadSearchKind::AgentMessage),
+            "command" => Some(ThreadSearchKind::Command),
+            "file" => Some(ThreadSearchKind::File),
+            _ => None,
+        }
+    }
+}
+
+/// One searchable piece of text extracted from a rollout.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ThreadSearchEntry {
+    pub kind: ThreadSearchKind,
+    pub content: String,
+}
+
+/// Best match of a full-text query within one thread.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ThreadSearchHit {
+    pub thread: ThreadMetadata,
+    /// Kind of the entry the snippet was taken from.
+    pub kind: ThreadSearchKind,
+    /// Single-line excerpt of the matching entry.
+    pub snippet: String,
+    /// Byte ranges of `snippet` that matched a query term, in order.
+    pub highlights: Vec<Range<usize>>,
+}
@@ -43,6 +43,10 @@ const METRIC_DB_INIT: &str = "codex.db.init";
 
 mod memories;
 // Memory-specific CRUD and phase job lifecycle methods live in `runtime/memories.rs`.
+mod network_audit;
+// Network proxy audit log inserts and queries live in `runtime/network_audit.rs`.
+mod thread_search;
+// Full-text thread search indexing and queries live in `runtime/thread_search.rs`.
 
 #[derive(Clone)]
 pub struct StateRuntime {
@@ -570,10 +574,14 @@ ON CONFLICT(thread_id, position) DO NOTHING
         if items.is_empty() {
             return Ok(());
         }
-        let mut metadata = self
-            .get_thread(builder.id)
-            .await?
-            .unwrap_or_else(|| builder.build(&self.default_provider));
+        let existing = self.get_thread(builder.id).await?;
+        // Items of a thread seen for the first time, starting with its session
+        // meta, cover the rollout from the beginning.
+        let starts_rollout = existing.is_none()
+            && items
+                .iter()
+                .any(|item| matches!(item, RolloutItem::SessionMeta(_)));
+        let mut metadata = existing.unwrap_or_else(|| builder.build(&self.default_provider));
         metadata.rollout_path = builder.rollout_path.clone();
         for item in items {
             apply_rollout_item(&mut metadata, item, &self.default_provider);
@@ -600,6 +608,15 @@ ON CONFLICT(thread_id, position) DO NOTHING
             }
             return Err(err);
         }
+        if let Err(err) = self
+            .append_thread_search_entries(builder.id, items, starts_rollout)
+            .await
+        {
+            if let Some(otel) = otel {
+                otel.counter(DB_ERROR_METRIC, 1, &[("stage", "thread_search")]);
+            }
+            return Err(err);
+        }
         Ok(())
     }
 
@@ -900,6 +917,11 @@ mod tests {
     use super::StateRuntime;
     use super::ThreadMetadata;
     use super::state_db_filename;
+    use crate::NetworkAuditDecision;
+    use crate::NetworkAuditEntry;
+    use crate::NetworkAuditQuery;
+    use crate::ThreadMetadataBuilder;
+    use crate::ThreadSearchKind;
     use crate::model::Phase2JobClaimOutcome;
     use crate::model::Stage1JobClaimOutcome;
     use crate::model::Stage1StartupClaimParams;
@@ -907,8 +929,13 @@ mod tests {
     use chrono::Duration;
     use chrono::Utc;
     use codex_protocol::ThreadId;
+    use codex_protocol::protocol::AgentMessageEvent;
     use codex_protocol::protocol::AskForApproval;
+    use codex_protocol::protocol::EventMsg;
+    use codex_protocol::protocol::RolloutItem;
     use codex_protocol::protocol::SandboxPolicy;
+    use codex_protocol::protocol::SessionSource;
+    use codex_protocol::protocol::UserMessageEvent;
     use pretty_assertions::assert_eq;
     use sqlx::Row;
     use std::path::Path;
@@ -1982,6 +2009,215 @@ WHERE kind = 'memory_stage1'
         let _ = tokio::fs::remove_dir_all(codex_home).await;
     }
 
+    #[tokio::test]
+    async fn network_audit_entries_round_trip_with_filters() {
+        let codex_home = unique_temp_dir();
+        let runtime = StateRuntime::init(codex_home.clone(), "test-provider".to_string(), None)
+            .await
+            .expect("initialize runtime");
+
+        let entry = |ts: i64, thread_id: &str, host: &str, decision| NetworkAuditEntry {
+            ts,
+            ts_nanos: 0,
+            thread_id: Some(thread_id.to_string()),
+            protocol: "http-connect".to_string(),
+            host: host.to_string(),
+            port: Some(443),
+            method: Some("CONNECT".to_string()),
+            decision,
+            reason: (decision == NetworkAuditDecision::Deny).then(|| "not_allowed".to_string()),
+            client: None,
+        };
+        runtime
+            .insert_network_audit_entries(&[
+                entry(
+                    100,
+                    "thread-a",
+                    "api.openai.com",
+                    NetworkAuditDecision::Allow,
+                ),
+                entry(200, "thread-a", "evil.example", NetworkAuditDecision::Deny),
+                entry(
+                    300,
+                    "thread-b",
+                    "api.openai.com",
+                    NetworkAuditDecision::Allow,
+                ),
+            ])
+            .await
+            .expect("insert audit entries");
+
+        let rows = runtime
+            .query_network_audit(&NetworkAuditQuery {
+                thread_ids: vec!["thread-a".to_string()],
+                ..NetworkAuditQuery::default()
+            })
+            .await
+            .expect("query by thread");
+        assert_eq!(
+            rows.iter().map(|row| row.host.as_str()).collect::<Vec<_>>(),
+            vec!["api.openai.com", "evil.example"]
+        );
+
+        let denied = runtime
+            .query_network_audit(&NetworkAuditQuery {
+                decision: Some(NetworkAuditDecision::Deny),
+                ..NetworkAuditQuery::default()
+            })
+            .await
+            .expect("query by decision");
+        assert_eq!(denied.len(), 1);
+        assert_eq!(denied[0].reason.as_deref(), Some("not_allowed"));
+        assert_eq!(denied[0].port, Some(443));
+
+        let latest = runtime
+            .query_network_audit(&NetworkAuditQuery {
+                host_like: vec!["openai".to_string()],
+                limit: Some(1),
+                descending: true,
+                ..NetworkAuditQuery::default()
+            })
+            .await
+            .expect("query latest by host");
+        assert_eq!(latest[0].thread_id.as_deref(), Some("thread-b"));
+
+        let page = |before_id| NetworkAuditQuery {
+            before_id,
+            limit: Some(2),
+            descending: true,
+            ..NetworkAuditQuery::default()
+        };
+        let first_page = runtime
+            .query_network_audit(&page(None))
+            .await
+            .expect("query first page");
+        let second_page = runtime
+            .query_network_audit(&page(first_page.last().map(|row| row.id)))
+            .await
+            .expect("query second page");
+        assert_eq!(
+            first_page
+                .iter()
+                .chain(&second_page)
+                .map(|row| row.ts)
+                .collect::<Vec<_>>(),
+            vec![300, 200, 100]
+        );
+
+        let deleted = runtime
+            .delete_network_audit_before(250)
+            .await
+            .expect("delete old entries");
+        assert_eq!(deleted, 2);
+
+        let _ = tokio::fs::remove_dir_all(codex_home).await;
+    }
+
+    #[tokio::test]
+    async fn thread_search_matches_indexed_threads_only() {
+        let codex_home = unique_temp_dir();
+        let runtime = StateRuntime::init(codex_home.clone(), "test-provider".to_string(), None)
+            .await
+            .expect("initialize runtime");
+        let user_message = |message: &str| {
+            RolloutItem::EventMsg(EventMsg::UserMessage(UserMessageEvent {
+                message: message.to_string(),
+                images: None,
+                local_images: vec![],
+                text_elements: vec![],
+            }))
+        };
+        let indexed_id = ThreadId::from_string(&Uuid::new_v4().to_string()).expect("thread id");
+        let partial_id = ThreadId::from_string(&Uuid::new_v4().to_string()).expect("thread id");
+        for thread_id in [indexed_id, partial_id] {
+            runtime
+                .upsert_thread(&test_thread_metadata(
+                    &codex_home,
+                    thread_id,
+                    codex_home.clone(),
+                ))
+                .await
+                .expect("upsert thread");
+        }
+        runtime
+            .replace_thread_search_entries(
+                indexed_id,
+                &[
+                    user_message("fix the LOGIN redirect"),
+                    RolloutItem::EventMsg(EventMsg::AgentMessage(AgentMessageEvent {
+                        message: "Updated src/auth.rs".to_string(),
+                    })),
+                ],
+            )
+            .await
+            .expect("index thread");
+
+        // Items appended to a thread that was never indexed in full are not
+        // searchable until the thread is indexed.
+        let builder = ThreadMetadataBuilder::new(
+            partial_id,
+            codex_home.join(format!("rollout-{partial_id}.jsonl")),
+            Utc::now(),
+            SessionSource::Cli,
+        );
+        runtime
+            .apply_rollout_items(&builder, &[user_message("the login page is broken")], None)
+            .await
+            .expect("apply rollout items");
+        let hits = runtime
+            .search_threads("login", 10, false)
+            .await
+            .expect("search threads");
+        assert_eq!(
+            hits.iter().map(|hit| hit.thread.id).collect::<Vec<_>>(),
+            vec![indexed_id]
+        );
+        assert_eq!(hits[0].kind, ThreadSearchKind::UserMessage);
+        assert_eq!(hits[0].snippet, "fix the LOGIN redirect");
+        assert_eq!(hits[0].highlights, vec![8..13]);
+        let missing = runtime
+            .list_threads_missing_search_index(10)
+            .await
+            .expect("list unindexed threads");
+        assert_eq!(
+            missing.iter().map(|thread| thread.id).collect::<Vec<_>>(),
+            vec![partial_id]
+        );
+
+        runtime
+            .replace_thread_search_entries(partial_id, &[user_message("the login page is broken")])
+            .await
+            .expect("index thread");
+        let hits = runtime
+            .search_threads("is LOGIN", 10, false)
+            .await
+            .expect("search threads");
+        assert_eq!(
+            hits.iter().map(|hit| hit.thread.id).collect::<Vec<_>>(),
+            vec![partial_id]
+        );
+        assert_eq!(hits[0].highlights, vec![4..9, 15..17]);
+        let hits = runtime
+            .search_threads("auth.rs", 10, false)
+            .await
+            .expect("search threads");
+        assert_eq!(
+            hits.iter()
+                .map(|hit| (hit.thread.id, hit.kind))
+                .collect::<Vec<_>>(),
+            vec![(indexed_id, ThreadSearchKind::AgentMessage)]
+        );
+        assert!(
+            runtime
+                .search_threads("login", 10, true)
+                .await
+                .expect("search archived threads")
+                .is_empty()
+        );
+
+        let _ = tokio::fs::remove_dir_all(codex_home).await;
+    }
+
     fn test_thread_metadata(
         codex_home: &Path,
         thread_id: ThreadId,
@@ -0,0 +1,100 @@
+use super::*;
+use crate::NetworkAuditEntry;
+use crate::NetworkAuditQuery;
+use crate::NetworkAuditRow;
+
+impl StateRuntime {
+    /// Insert a batch of network proxy decisions into the audit table.
+    pub async fn insert_network_audit_entries(
+        &self,
+        entries: &[NetworkAuditEntry],
+    ) -> anyhow::Result<()> {
+        if entries.is_empty() {
+            return Ok(());
+        }
+
+        let mut builder = QueryBuilder::<Sqlite>::new(
+            "INSERT INTO network_audit (ts, ts_nanos, thread_id, protocol, host, port, method, decision, reason, client) ",
+        );
+        builder.push_values(entries, |mut row, entry| {
+            row.push_bind(entry.ts)
+                .push_bind(entry.ts_nanos)
+                .push_bind(&entry.thread_id)
+                .push_bind(&entry.protocol)
+                .push_bind(&entry.host)
+                .push_bind(entry.port)
+                .push_bind(&entry.method)
+                .push_bind(entry.decision.as_str())
+                .push_bind(&entry.reason)
+                .push_bind(&entry.client);
+        });
+        builder.build().execute(self.pool.as_ref()).await?;
+        Ok(())
+    }
+
+    /// Delete audit entries older than `cutoff_ts` (unix seconds).
+    pub async fn delete_network_audit_before(&self, cutoff_ts: i64) -> anyhow::Result<u64> {
+        let result = sqlx::query("DELETE FROM network_audit WHERE ts < ?")
+            .bind(cutoff_ts)
+            .execute(self.pool.as_ref())
+            .await?;
+        Ok(result.rows_affected())
+    }
+
+    /// Query network proxy decisions with optional filters.
+    pub async fn query_network_audit(
+        &self,
+        query: &NetworkAuditQuery,
+    ) -> anyhow::Result<Vec<NetworkAuditRow>> {
+        let mut builder = QueryBuilder::<Sqlite>::new(
+            "SELECT id, ts, ts_nanos, thread_id, protocol, host, port, method, decision, reason, client FROM network_audit WHERE 1 = 1",
+        );
+        push_network_audit_filters(&mut builder, query);
+        if query.descending {
+            builder.push(" ORDER BY id DESC");
+        } else {
+            builder.push(" ORDER BY id ASC");
+        }
+        if let Some(limit) = query.limit {
+            builder.push(" LIMIT ").push_bind(limit as i64);
+        }
+
+        let rows = builder
+            .build_query_as::<NetworkAuditRow>()
+            .fetch_all(self.pool.as_ref())
+            .await?;
+        Ok(rows)
+    }
+}
+
+fn push_network_audit_filters<'a>(
+    builder: &mut QueryBuilder<'a, Sqlite>,
+    query: &'a NetworkAuditQuery,
+) {
+    if !query.thread_ids.is_empty() {
+        builder.push(" AND thread_id IN (");
+        let mut separated = builder.separated(", ");
+        for thread_id in &query.thread_ids {
+            separated.push_bind(thread_id.as_str());
+        }
+        separated.push_unseparated(")");
+    }
+    push_like_filters(builder, "host", &query.host_like);
+    if let Some(decision) = query.decision {
+        builder
+            .push(" AND decision = ")
+            .push_bind(decision.as_str());
+    }
+    if let Some(from_ts) = query.from_ts {
+        builder.push(" AND ts >= ").push_bind(from_ts);
+    }
+    if let Some(to_ts) = query.to_ts {
+        builder.push(" AND ts <= ").push_bind(to_ts);
+    }
+    if let Some(after_id) = query.after_id {
+        builder.push(" AND id > ").push_bind(after_id);
+    }
+    if let Some(before_id) = query.before_id {
+        builder.push(" AND id < ").push_bind(before_id);
+    }
+}
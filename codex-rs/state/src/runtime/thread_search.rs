@@ -0,0 +1,307 @@
+use super::*;
+use crate::ThreadSearchHit;
+use crate::ThreadSearchKind;
+use crate::extract::thread_search_entries;
+use std::ops::Range;
+
+/// Maximum length of a search snippet, in characters.
+const SNIPPET_CHARS: usize = 160;
+/// Characters of context kept before the first match of a snippet.
+const SNIPPET_LEADING_CHARS: usize = 40;
+/// The trigram tokenizer cannot match terms shorter than this; such terms are
+/// matched with `LIKE` instead.
+const MIN_MATCH_TERM_CHARS: usize = 3;
+
+impl StateRuntime {
+    /// Append the searchable text of newly recorded rollout items.
+    ///
+    /// The index of a thread is only extended once it is complete: either the
+    /// items start the rollout (`starts_rollout`) or the thread was indexed in
+    /// full by [`Self::replace_thread_search_entries`] before.
+    pub(super) async fn append_thread_search_entries(
+        &self,
+        thread_id: ThreadId,
+        items: &[RolloutItem],
+        starts_rollout: bool,
+    ) -> anyhow::Result<()> {
+        if !starts_rollout && !self.is_thread_search_indexed(thread_id).await? {
+            return Ok(());
+        }
+        let mut tx = self.pool.begin().await?;
+        insert_thread_search_entries(&mut tx, thread_id, items).await?;
+        if starts_rollout {
+            mark_thread_search_indexed(&mut tx, thread_id).await?;
+        }
+        tx.commit().await?;
+        Ok(())
+    }
+
+    /// Rebuild the search index of a thread from all of its rollout items.
+    pub async fn replace_thread_search_entries(
+        &self,
+        thread_id: ThreadId,
+        items: &[RolloutItem],
+    ) -> anyhow::Result<()> {
+        let mut tx = self.pool.begin().await?;
+        sqlx::query("DELETE FROM thread_search WHERE thread_id = ?")
+            .bind(thread_id.to_string())
+            .execute(&mut *tx)
+            .await?;
+        insert_thread_search_entries(&mut tx, thread_id, items).await?;
+        mark_thread_search_indexed(&mut tx, thread_id).await?;
+        tx.commit().await?;
+        Ok(())
+    }
+
+    /// List threads whose search index has not been built yet, most recently
+    /// updated first.
+    pub async fn list_threads_missing_search_index(
+        &self,
+        limit: usize,
+    ) -> anyhow::Result<Vec<ThreadMetadata>> {
+        let rows = sqlx::query(
+            r#"
+SELECT
+    id,
+    rollout_path,
+    created_at,
+    updated_at,
+    source,
+    model_provider,
+    cwd,
+    cli_version,
+    title,
+    sandbox_policy,
+    approval_mode,
+    tokens_used,
+    first_user_message,
+    archived_at,
+    git_sha,
+    git_branch,
+    git_origin_url
+FROM threads
+WHERE search_indexed = 0
+ORDER BY updated_at DESC, id DESC
+LIMIT ?
+            "#,
+        )
+        .bind(limit as i64)
+        .fetch_all(self.pool.as_ref())
+        .await?;
+        rows.into_iter()
+            .map(|row| ThreadRow::try_from_row(&row).and_then(ThreadMetadata::try_from))
+            .collect()
+    }
+
+    /// Full-text search over indexed threads, returning the best matching
+    /// entry of each thread, best threads first.
+    ///
+    /// Every whitespace-separated term of `query` must occur in the same
+    /// entry, matched as a case-insensitive substring.
+    pub async fn search_threads(
+        &self,
+        query: &str,
+        limit: usize,
+        archived_only: bool,
+    ) -> anyhow::Result<Vec<ThreadSearchHit>> {
+        let mut terms: Vec<&str> = Vec::new();
+        for term in query.split_whitespace() {
+            if !terms.contains(&term) {
+                terms.push(term);
+            }
+        }
+        if terms.is_empty() || limit == 0 {
+            return Ok(Vec::new());
+        }
+        let match_expression = terms
+            .iter()
+            .filter(|term| term.chars().count() >= MIN_MATCH_TERM_CHARS)
+            .map(|term| format!("\"{}\"", term.replace('"', "\"\"")))
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        let mut builder = QueryBuilder::<Sqlite>::new(
+            r#"
+SELECT
+    threads.id,
+    threads.rollout_path,
+    threads.created_at,
+    threads.updated_at,
+    threads.source,
+    threads.model_provider,
+    threads.cwd,
+    threads.cli_version,
+    threads.title,
+    threads.sandbox_policy,
+    threads.approval_mode,
+    threads.tokens_used,
+    threads.first_user_message,
+    threads.archived_at,
+    threads.git_sha,
+    threads.git_branch,
+    threads.git_origin_url,
+    matches.kind,
+    matches.content,
+    MIN(matches.rank) AS rank
+FROM (
+    SELECT thread_id, kind, content, "#,
+        );
+        if match_expression.is_empty() {
+            builder.push("0.0 AS rank FROM thread_search WHERE 1 = 1");
+        } else {
+            builder
+                // The hidden `rank` column holds the bm25 score of the match.
+                .push("rank FROM thread_search WHERE thread_search MATCH ")
+                .push_bind(match_expression);
+        }
+        for term in terms
+            .iter()
+            .filter(|term| term.chars().count() < MIN_MATCH_TERM_CHARS)
+        {
+            builder
+                .push(" AND content LIKE ")
+                .push_bind(format!("%{}%", escape_like(term)))
+                .push(" ESCAPE '\\'");
+        }
+        builder.push(
+            r#"
+) AS matches
+JOIN threads ON threads.id = matches.thread_id
+WHERE threads.first_user_message <> ''"#,
+        );
+        if archived_only {
+            builder.push(" AND threads.archived = 1");
+        } else {
+            builder.push(" AND threads.archived = 0");
+        }
+        // SQLite takes the bare `kind` and `content` columns from the row that
+        // holds the MIN, i.e. the best entry of each thread.
+        builder.push(
+            r#"
+GROUP BY matches.thread_id
+ORDER BY rank ASC, threads.updated_at DESC, threads.id DESC
+LIMIT "#,
+        );
+        builder.push_bind(limit as i64);
+
+        let rows = builder.build().fetch_all(self.pool.as_ref()).await?;
+        let mut hits = Vec::with_capacity(rows.len());
+        for row in rows {
+            let thread = ThreadRow::try_from_row(&row).and_then(ThreadMetadata::try_from)?;
+            let kind: String = row.try_get("kind")?;
+            let Some(kind) = ThreadSearchKind::parse(kind.as_str()) else {
+                warn!("unknown thread search entry kind `{kind}`");
+                continue;
+            };
+            let content: String = row.try_get("content")?;
+            let (snippet, highlights) = build_snippet(content.as_str(), &terms);
+            hits.push(ThreadSearchHit {
+                thread,
+                kind,
+                snippet,
+                highlights,
+            });
+        }
+        Ok(hits)
+    }
+
+    async fn is_thread_search_indexed(&self, thread_id: ThreadId) -> anyhow::Result<bool> {
+        let indexed: Option<i64> =
+            sqlx::query_scalar("SELECT search_indexed FROM threads WHERE id = ?")
+                .bind(thread_id.to_string())
+                .fetch_optional(self.pool.as_ref())
+                .await?;
+        Ok(indexed.is_some_and(|indexed| indexed != 0))
+    }
+}
+
+async fn insert_thread_search_entries(
+    tx: &mut sqlx::Transaction<'_, Sqlite>,
+    thread_id: ThreadId,
+    items: &[RolloutItem],
+) -> anyhow::Result<()> {
+    let entries = thread_search_entries(items);
+    if entries.is_empty() {
+        return Ok(());
+    }
+    let thread_id = thread_id.to_string();
+    let mut builder =
+        QueryBuilder::<Sqlite>::new("INSERT INTO thread_search (thread_id, kind, content) ");
+    builder.push_values(&entries, |mut row, entry| {
+        row.push_bind(thread_id.as_str())
+            .push_bind(entry.kind.as_str())
+            .push_bind(entry.content.as_str());
+    });
+    builder.build().execute(&mut **tx).await?;
+    Ok(())
+}
+
+async fn mark_thread_search_indexed(
+    tx: &mut sqlx::Transaction<'_, Sqlite>,
+    thread_id: ThreadId,
+) -> anyhow::Result<()> {
+    sqlx::query("UPDATE threads SET search_indexed = 1 WHERE id = ?")
+        .bind(thread_id.to_string())
+        .execute(&mut **tx)
+        .await?;
+    Ok(())
+}
+
+fn escape_like(term: &str) -> String {
+    let mut escaped = String::with_capacity(term.len());
+    for ch in term.chars() {
+        if matches!(ch, '\\' | '%' | '_') {
+            escaped.push('\\');
+        }
+        escaped.push(ch);
+    }
+    escaped
+}
+
+/// Cut a single-line excerpt of `content` around the first matching term and
+/// locate every term occurrence within it.
+fn build_snippet(content: &str, terms: &[&str]) -> (String, Vec<Range<usize>>) {
+    let text = content.split_whitespace().collect::<Vec<_>>().join(" ");
+    // ASCII lowercasing keeps byte offsets aligned with `text`.
+    let lowered = text.to_ascii_lowercase();
+    let terms = terms
+        .iter()
+        .map(|term| term.to_ascii_lowercase())
+        .collect::<Vec<_>>();
+    let first_match = terms
+        .iter()
+        .filter_map(|term| lowered.find(term.as_str()))
+        .min()
+        .unwrap_or(0);
+    let start = text[..first_match]
+        .char_indices()
+        .rev()
+        .nth(SNIPPET_LEADING_CHARS.saturating_sub(1))
+        .map_or(0, |(idx, _)| idx);
+    let end = text[start..]
+        .char_indices()
+        .nth(SNIPPET_CHARS)
+        .map_or(text.len(), |(idx, _)| start + idx);
+
+    let prefix = if start > 0 { "…" } else { "" };
+    let suffix = if end < text.len() { "…" } else { "" };
+    let window = &lowered[start..end];
+    let mut highlights = terms
+        .iter()
+        .flat_map(|term| {
+            window
+                .match_indices(term.as_str())
+                .map(|(idx, matched)| prefix.len() + idx..prefix.len() + idx + matched.len())
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+    highlights.sort_by_key(|range| (range.start, range.end));
+    let mut merged: Vec<Range<usize>> = Vec::with_capacity(highlights.len());
+    for range in highlights {
+        match merged.last_mut() {
+            Some(last) if range.start <= last.end => last.end = last.end.max(range.end),
+            _ => merged.push(range),
+        }
+    }
+    (format!("{prefix}{}{suffix}", &text[start..end]), merged)
+}
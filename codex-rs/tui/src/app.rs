@@ -10,6 +10,7 @@ use crate::bottom_pane::ApprovalRequest;
 use crate::bottom_pane::FeedbackAudience;
 use crate::bottom_pane::SelectionItem;
 use crate::bottom_pane::SelectionViewParams;
+use crate::bottom_pane::network_request_lines;
 use crate::bottom_pane::popup_consts::standard_popup_hint_line;
 use crate::chatwidget::ChatWidget;
 use crate::chatwidget::ExternalEditorState;
@@ -1359,7 +1360,9 @@ impl App {
                 tui.frame_requester().schedule_frame();
             }
             AppEvent::OpenResumePicker => {
-                match crate::resume_picker::run_resume_picker(tui, &self.config, false).await? {
+                match crate::resume_picker::run_resume_picker(tui, &self.config, false, None)
+                    .await?
+                {
                     SessionSelection::Resume(path) => {
                         let current_cwd = self.config.cwd.clone();
                         let resume_cwd = match crate::resolve_cwd_for_resume_or_fork(
@@ -1448,69 +1451,13 @@ impl App {
                 tui.frame_requester().schedule_frame();
             }
             AppEvent::ForkCurrentSession => {
-                self.otel_manager
-                    .counter("codex.thread.fork", 1, &[("source", "slash_command")]);
-                let summary = session_summary(
-                    self.chat_widget.token_usage(),
-                    self.chat_widget.thread_id(),
-                    self.chat_widget.thread_name(),
-                );
-                self.chat_widget
-                    .add_plain_history_lines(vec!["/fork".magenta().into()]);
-                if let Some(path) = self.chat_widget.rollout_path() {
-                    // Fresh threads expose a precomputed path, but the file is
-                    // materialized lazily on first user message.
-                    if path.exists() {
-                        match self
-                            .server
-                            .fork_thread(usize::MAX, self.config.clone(), path.clone())
-                            .await
-                        {
-                            Ok(forked) => {
-                                self.shutdown_current_thread().await;
-                                let init = self.chatwidget_init_for_forked_or_resumed_thread(
-                                    tui,
-                                    self.config.clone(),
-                                );
-                                self.chat_widget = ChatWidget::new_from_existing(
-                                    init,
-                                    forked.thread,
-                                    forked.session_configured,
-                                );
-                                self.reset_thread_event_state();
-                                if let Some(summary) = summary {
-                                    let mut lines: Vec<Line<'static>> =
-                                        vec![summary.usage_line.clone().into()];
-                                    if let Some(command) = summary.resume_command {
-                                        let spans = vec![
-                                            "To continue this session, run ".into(),
-                                            command.cyan(),
-                                        ];
-                                        lines.push(spans.into());
-                                    }
-                                    self.chat_widget.add_plain_history_lines(lines);
-                                }
-                            }
-                            Err(err) => {
-                                let path_display = path.display();
-                                self.chat_widget.add_error_message(format!(
-                                    "Failed to fork current session from {path_display}: {err}"
-                                ));
-                            }
-                        }
-                    } else {
-                        self.chat_widget.add_error_message(
-                            "A thread must contain at least one turn before it can be forked."
-                                .to_string(),
-                        );
-                    }
-                } else {
-                    self.chat_widget.add_error_message(
-                        "A thread must contain at least one turn before it can be forked."
-                            .to_string(),
-                    );
-                }
-
+                self.fork_current_session(tui, None).await;
+            }
+            AppEvent::ForkFromCheckpoint { name } => {
+                self.fork_current_session(tui, Some(name)).await;
+            }
+            AppEvent::ResumeFromCheckpoint { name } => {
+                self.resume_from_checkpoint(name).await;
                 tui.frame_requester().schedule_frame();
             }
             AppEvent::InsertHistoryCell(cell) => {
@@ -1625,13 +1572,27 @@ impl App {
             AppEvent::FileSearchResult { query, matches } => {
                 self.chat_widget.apply_file_search_result(query, matches);
             }
-            AppEvent::OpenSddPlanOptions
-            | AppEvent::SddPlanApproved
-            | AppEvent::SddPlanRework
-            | AppEvent::OpenSddDevOptions
-            | AppEvent::SddDevRequestMoreChanges
-            | AppEvent::SddDevMergeBranch
-            | AppEvent::SddDevAbandonBranch => {}
+            AppEvent::OpenSddPlanOptions => {
+                self.chat_widget.open_sdd_plan_options();
+            }
+            AppEvent::SddPlanApproved => {
+                self.chat_widget.on_sdd_plan_approved().await;
+            }
+            AppEvent::SddPlanRework => {
+                self.chat_widget.on_sdd_plan_rework();
+            }
+            AppEvent::OpenSddDevOptions => {
+                self.chat_widget.open_sdd_dev_options();
+            }
+            AppEvent::SddDevRequestMoreChanges => {
+                self.chat_widget.on_sdd_request_more_changes();
+            }
+            AppEvent::SddDevMergeBranch => {
+                self.chat_widget.on_sdd_merge_branch();
+            }
+            AppEvent::SddDevAbandonBranch => {
+                self.chat_widget.on_sdd_abandon_branch();
+            }
             AppEvent::RateLimitSnapshotFetched(snapshot) => {
                 self.chat_widget.on_rate_limit_snapshot(Some(snapshot));
             }
@@ -2332,6 +2293,43 @@ impl App {
                         self.config.language,
                     ));
                 }
+                ApprovalRequest::McpSampling {
+                    server_name,
+                    messages,
+                    ..
+                } => {
+                    let _ = tui.enter_alt_screen();
+                    let mut lines = vec![
+                        Line::from(vec!["Server: ".into(), server_name.bold()]),
+                        Line::from(""),
+                    ];
+                    lines.extend(messages.into_iter().map(|message| {
+                        Line::from(vec![
+                            format!("{}: ", message.role).dim(),
+                            message.text.into(),
+                        ])
+                    }));
+                    let paragraph = Paragraph::new(lines).wrap(Wrap { trim: false });
+                    self.overlay = Some(Overlay::new_static_with_renderables(
+                        vec![Box::new(paragraph)],
+                        "S A M P L I N G".to_string(),
+                        self.config.language,
+                    ));
+                }
+                ApprovalRequest::Network {
+                    host,
+                    port,
+                    protocol,
+                    method,
+                    ..
+                } => {
+                    let _ = tui.enter_alt_screen();
+                    self.overlay = Some(Overlay::new_static_with_lines(
+                        network_request_lines(&host, port, &protocol, method.as_deref()),
+                        "N E T W O R K".to_string(),
+                        self.config.language,
+                    ));
+                }
             },
             AppEvent::StatusLineSetup { items } => {
                 let ids = items.iter().map(ToString::to_string).collect::<Vec<_>>();
@@ -2506,6 +2504,114 @@ impl App {
         }
     }
 
+    /// Fork the current thread into a new one, either with its full history or from the
+    /// named checkpoint recorded by `/checkpoint`.
+    async fn fork_current_session(&mut self, tui: &mut tui::Tui, checkpoint: Option<String>) {
+        self.otel_manager
+            .counter("codex.thread.fork", 1, &[("source", "slash_command")]);
+        let summary = session_summary(
+            self.chat_widget.token_usage(),
+            self.chat_widget.thread_id(),
+            self.chat_widget.thread_name(),
+        );
+        let command = match checkpoint.as_deref() {
+            Some(name) => format!("/fork {name}"),
+            None => "/fork".to_string(),
+        };
+        self.chat_widget
+            .add_plain_history_lines(vec![command.magenta().into()]);
+        if let Some(path) = self.chat_widget.rollout_path() {
+            // Fresh threads expose a precomputed path, but the file is
+            // materialized lazily on first user message.
+            if path.exists() {
+                let forked = match checkpoint.as_deref() {
+                    Some(name) => {
+                        self.server
+                            .fork_thread_at_checkpoint(name, self.config.clone(), path.clone())
+                            .await
+                    }
+                    None => {
+                        self.server
+                            .fork_thread(usize::MAX, self.config.clone(), path.clone())
+                            .await
+                    }
+                };
+                match forked {
+                    Ok(forked) => {
+                        self.shutdown_current_thread().await;
+                        let init = self
+                            .chatwidget_init_for_forked_or_resumed_thread(tui, self.config.clone());
+                        self.chat_widget = ChatWidget::new_from_existing(
+                            init,
+                            forked.thread,
+                            forked.session_configured,
+                        );
+                        self.reset_thread_event_state();
+                        if let Some(summary) = summary {
+                            let mut lines: Vec<Line<'static>> =
+                                vec![summary.usage_line.clone().into()];
+                            if let Some(command) = summary.resume_command {
+                                let spans =
+                                    vec!["To continue this session, run ".into(), command.cyan()];
+                                lines.push(spans.into());
+                            }
+                            self.chat_widget.add_plain_history_lines(lines);
+                        }
+                    }
+                    Err(err) => {
+                        let path_display = path.display();
+                        self.chat_widget.add_error_message(format!(
+                            "Failed to fork current session from {path_display}: {err}"
+                        ));
+                    }
+                }
+            } else {
+                self.chat_widget.add_error_message(
+                    "A thread must contain at least one turn before it can be forked.".to_string(),
+                );
+            }
+        } else {
+            self.chat_widget.add_error_message(
+                "A thread must contain at least one turn before it can be forked.".to_string(),
+            );
+        }
+
+        tui.frame_requester().schedule_frame();
+    }
+
+    /// Roll the current thread back to the named checkpoint recorded by `/checkpoint`.
+    ///
+    /// Only the conversation is rewound; files in the workspace are left as they are. The
+    /// checkpoint's ghost commit is listed in `.codex/checkpoint.md` for restoring them by hand.
+    async fn resume_from_checkpoint(&mut self, name: String) {
+        self.chat_widget
+            .add_plain_history_lines(vec![format!("/resume {name}").magenta().into()]);
+        let Some(path) = self.chat_widget.rollout_path().filter(|path| path.exists()) else {
+            self.chat_widget
+                .add_error_message(format!("Checkpoint `{name}` was not found in this thread."));
+            return;
+        };
+        match self.server.turns_after_checkpoint(&name, path).await {
+            Ok(0) => self
+                .chat_widget
+                .add_info_message(format!("Already at checkpoint `{name}`."), None),
+            Ok(num_turns) => {
+                self.chat_widget.submit_op(Op::ThreadRollback { num_turns });
+                self.chat_widget
+                    .add_info_message(
+                        format!("Resumed the conversation from checkpoint `{name}`."),
+                        Some(
+                            "Workspace files were not changed; the checkpoint's snapshot commit is listed in .codex/checkpoint.md."
+                                .to_string(),
+                        ),
+                    );
+            }
+            Err(err) => self
+                .chat_widget
+                .add_error_message(format!("Failed to resume from checkpoint `{name}`: {err}")),
+        }
+    }
+
     async fn launch_external_editor(&mut self, tui: &mut tui::Tui) {
         let editor_cmd = match external_editor::resolve_editor_command() {
             Ok(cmd) => cmd,
@@ -67,6 +67,16 @@ pub(crate) enum AppEvent {
     /// Fork the current session into a new thread.
     ForkCurrentSession,
 
+    /// Fork the current session into a new thread starting from a named checkpoint.
+    ForkFromCheckpoint {
+        name: String,
+    },
+
+    /// Roll the current thread back to a named checkpoint.
+    ResumeFromCheckpoint {
+        name: String,
+    },
+
     /// Request to exit the application.
     ///
     /// Use `ShutdownFirst` for user-initiated quits so core cleanup runs and the
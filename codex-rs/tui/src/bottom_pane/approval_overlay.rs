@@ -20,8 +20,11 @@ use codex_core::features::Features;
 use codex_core::protocol::ElicitationAction;
 use codex_core::protocol::ExecPolicyAmendment;
 use codex_core::protocol::FileChange;
+use codex_core::protocol::NetworkApprovalDecision;
 use codex_core::protocol::Op;
 use codex_core::protocol::ReviewDecision;
+use codex_core::protocol::SamplingDecision;
+use codex_core::protocol::SamplingMessage;
 use codex_protocol::mcp::RequestId;
 use crossterm::event::KeyCode;
 use crossterm::event::KeyEvent;
@@ -55,6 +58,20 @@ pub(crate) enum ApprovalRequest {
         request_id: RequestId,
         message: String,
     },
+    McpSampling {
+        server_name: String,
+        request_id: RequestId,
+        messages: Vec<SamplingMessage>,
+        system_prompt: Option<String>,
+        max_tokens: u32,
+    },
+    Network {
+        id: String,
+        host: String,
+        port: u16,
+        protocol: String,
+        method: Option<String>,
+    },
 }
 
 /// Modal overlay asking the user to approve or deny one or more requests.
@@ -122,6 +139,14 @@ impl ApprovalOverlay {
                 elicitation_options(),
                 format!("{server_name} needs your approval."),
             ),
+            ApprovalVariant::McpSampling { server_name, .. } => (
+                sampling_options(),
+                format!("{server_name} wants to run a model request."),
+            ),
+            ApprovalVariant::Network { host, .. } => (
+                network_options(),
+                format!("Allow network access to {host}?"),
+            ),
         };
 
         let header = Box::new(ColumnRenderable::with([
@@ -182,6 +207,18 @@ impl ApprovalOverlay {
                 ) => {
                     self.handle_elicitation_decision(server_name, request_id, *decision);
                 }
+                (
+                    ApprovalVariant::McpSampling {
+                        server_name,
+                        request_id,
+                    },
+                    ApprovalDecision::McpSampling(decision),
+                ) => {
+                    self.handle_sampling_decision(server_name, request_id, *decision);
+                }
+                (ApprovalVariant::Network { id, .. }, ApprovalDecision::Network(decision)) => {
+                    self.handle_network_decision(id, *decision);
+                }
                 _ => {}
             }
         }
@@ -221,6 +258,28 @@ impl ApprovalOverlay {
             }));
     }
 
+    fn handle_sampling_decision(
+        &self,
+        server_name: &str,
+        request_id: &RequestId,
+        decision: SamplingDecision,
+    ) {
+        self.app_event_tx
+            .send(AppEvent::CodexOp(Op::ResolveSampling {
+                server_name: server_name.to_string(),
+                request_id: request_id.clone(),
+                decision,
+            }));
+    }
+
+    fn handle_network_decision(&self, id: &str, decision: NetworkApprovalDecision) {
+        self.app_event_tx
+            .send(AppEvent::CodexOp(Op::NetworkApproval {
+                id: id.to_string(),
+                decision,
+            }));
+    }
+
     fn advance_queue(&mut self) {
         if let Some(next) = self.queue.pop() {
             self.set_current(next);
@@ -296,6 +355,19 @@ impl BottomPaneView for ApprovalOverlay {
                         ElicitationAction::Cancel,
                     );
                 }
+                ApprovalVariant::McpSampling {
+                    server_name,
+                    request_id,
+                } => {
+                    self.handle_sampling_decision(
+                        server_name,
+                        request_id,
+                        SamplingDecision::Decline,
+                    );
+                }
+                ApprovalVariant::Network { id, .. } => {
+                    self.handle_network_decision(id, NetworkApprovalDecision::Deny);
+                }
             }
         }
         self.queue.clear();
@@ -405,6 +477,59 @@ impl From<ApprovalRequest> for ApprovalRequestState {
                     header: Box::new(header),
                 }
             }
+            ApprovalRequest::McpSampling {
+                server_name,
+                request_id,
+                messages,
+                system_prompt,
+                max_tokens,
+            } => {
+                let mut header = vec![Line::from(vec![
+                    "Server: ".into(),
+                    server_name.clone().bold(),
+                ])];
+                if let Some(system_prompt) = system_prompt {
+                    header.push(Line::from(vec!["System: ".into(), system_prompt.italic()]));
+                }
+                header.push(Line::from(""));
+                for message in messages {
+                    header.push(Line::from(vec![
+                        format!("{}: ", message.role).dim(),
+                        message.text.into(),
+                    ]));
+                }
+                header.push(Line::from(""));
+                header.push(Line::from(vec![
+                    "Max tokens: ".into(),
+                    max_tokens.to_string().into(),
+                ]));
+                Self {
+                    variant: ApprovalVariant::McpSampling {
+                        server_name,
+                        request_id,
+                    },
+                    header: Box::new(Paragraph::new(header).wrap(Wrap { trim: false })),
+                }
+            }
+            ApprovalRequest::Network {
+                id,
+                host,
+                port,
+                protocol,
+                method,
+            } => {
+                let header = Paragraph::new(network_request_lines(
+                    &host,
+                    port,
+                    &protocol,
+                    method.as_deref(),
+                ))
+                .wrap(Wrap { trim: false });
+                Self {
+                    variant: ApprovalVariant::Network { id, host },
+                    header: Box::new(header),
+                }
+            }
         }
     }
 }
@@ -423,12 +548,22 @@ enum ApprovalVariant {
         server_name: String,
         request_id: RequestId,
     },
+    McpSampling {
+        server_name: String,
+        request_id: RequestId,
+    },
+    Network {
+        id: String,
+        host: String,
+    },
 }
 
 #[derive(Clone)]
 enum ApprovalDecision {
     Review(ReviewDecision),
     McpElicitation(ElicitationAction),
+    McpSampling(SamplingDecision),
+    Network(NetworkApprovalDecision),
 }
 
 #[derive(Clone)]
@@ -527,6 +662,72 @@ fn elicitation_options() -> Vec<ApprovalOption> {
     ]
 }
 
+fn sampling_options() -> Vec<ApprovalOption> {
+    vec![
+        ApprovalOption {
+            label: "Yes, run this request".to_string(),
+            decision: ApprovalDecision::McpSampling(SamplingDecision::Approve),
+            display_shortcut: None,
+            additional_shortcuts: vec![key_hint::plain(KeyCode::Char('y'))],
+        },
+        ApprovalOption {
+            label: "No, decline".to_string(),
+            decision: ApprovalDecision::McpSampling(SamplingDecision::Decline),
+            display_shortcut: Some(key_hint::plain(KeyCode::Esc)),
+            additional_shortcuts: vec![key_hint::plain(KeyCode::Char('n'))],
+        },
+    ]
+}
+
+fn network_options() -> Vec<ApprovalOption> {
+    vec![
+        ApprovalOption {
+            label: "Yes, allow this request".to_string(),
+            decision: ApprovalDecision::Network(NetworkApprovalDecision::AllowOnce),
+            display_shortcut: None,
+            additional_shortcuts: vec![key_hint::plain(KeyCode::Char('y'))],
+        },
+        ApprovalOption {
+            label: "Yes, and allow this domain for the rest of the session".to_string(),
+            decision: ApprovalDecision::Network(NetworkApprovalDecision::AllowForSession),
+            display_shortcut: None,
+            additional_shortcuts: vec![key_hint::plain(KeyCode::Char('a'))],
+        },
+        ApprovalOption {
+            label: "Yes, and add this domain to allowed_domains in config.toml".to_string(),
+            decision: ApprovalDecision::Network(NetworkApprovalDecision::AllowAndPersist),
+            display_shortcut: None,
+            additional_shortcuts: vec![key_hint::plain(KeyCode::Char('p'))],
+        },
+        ApprovalOption {
+            label: "No, block this request".to_string(),
+            decision: ApprovalDecision::Network(NetworkApprovalDecision::Deny),
+            display_shortcut: Some(key_hint::plain(KeyCode::Esc)),
+            additional_shortcuts: vec![key_hint::plain(KeyCode::Char('n'))],
+        },
+    ]
+}
+
+/// Describes the destination of a network approval request.
+pub(crate) fn network_request_lines(
+    host: &str,
+    port: u16,
+    protocol: &str,
+    method: Option<&str>,
+) -> Vec<Line<'static>> {
+    let mut lines = vec![
+        Line::from(vec!["Host: ".into(), format!("{host}:{port}").bold()]),
+        Line::from(vec!["Protocol: ".into(), protocol.to_string().into()]),
+    ];
+    if let Some(method) = method {
+        lines.push(Line::from(vec![
+            "Method: ".into(),
+            method.to_string().into(),
+        ]));
+    }
+    lines
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -610,6 +811,78 @@ mod tests {
         );
     }
 
+    #[test]
+    fn network_session_option_emits_allow_for_session() {
+        let (tx, mut rx) = unbounded_channel::<AppEvent>();
+        let tx = AppEventSender::new(tx);
+        let mut view = ApprovalOverlay::new(
+            ApprovalRequest::Network {
+                id: "net-1".to_string(),
+                host: "pypi.org".to_string(),
+                port: 443,
+                protocol: "https_connect".to_string(),
+                method: Some("CONNECT".to_string()),
+            },
+            tx,
+            Features::with_defaults(),
+        );
+        view.handle_key_event(KeyEvent::new(KeyCode::Char('a'), KeyModifiers::NONE));
+
+        let mut decision = None;
+        while let Ok(ev) = rx.try_recv() {
+            if let AppEvent::CodexOp(Op::NetworkApproval { id, decision: d }) = ev {
+                assert_eq!(id, "net-1");
+                decision = Some(d);
+                break;
+            }
+        }
+        assert_eq!(decision, Some(NetworkApprovalDecision::AllowForSession));
+        assert!(view.is_complete());
+    }
+
+    #[test]
+    fn sampling_escape_declines_request() {
+        let (tx, mut rx) = unbounded_channel::<AppEvent>();
+        let tx = AppEventSender::new(tx);
+        let mut view = ApprovalOverlay::new(
+            ApprovalRequest::McpSampling {
+                server_name: "review-bot".to_string(),
+                request_id: RequestId::Integer(3),
+                messages: vec![SamplingMessage {
+                    role: "user".to_string(),
+                    text: "Review this diff.".to_string(),
+                }],
+                system_prompt: None,
+                max_tokens: 256,
+            },
+            tx,
+            Features::with_defaults(),
+        );
+        view.handle_key_event(KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE));
+
+        let mut resolved = None;
+        while let Ok(ev) = rx.try_recv() {
+            if let AppEvent::CodexOp(Op::ResolveSampling {
+                server_name,
+                request_id,
+                decision,
+            }) = ev
+            {
+                resolved = Some((server_name, request_id, decision));
+                break;
+            }
+        }
+        assert_eq!(
+            resolved,
+            Some((
+                "review-bot".to_string(),
+                RequestId::Integer(3),
+                SamplingDecision::Decline
+            ))
+        );
+        assert!(view.is_complete());
+    }
+
     #[test]
     fn header_includes_command_snippet() {
         let (tx, _rx) = unbounded_channel::<AppEvent>();
@@ -140,13 +140,15 @@ use super::paste_burst::PasteBurst;
 use super::skill_popup::MentionItem;
 use super::skill_popup::SkillPopup;
 use super::slash_commands;
+use crate::bottom_pane::mcp_prompts::McpPromptCommand;
+use crate::bottom_pane::mcp_prompts::find_mcp_prompt;
+use crate::bottom_pane::mcp_prompts::mcp_prompt_command_with_arg_placeholders;
+use crate::bottom_pane::mcp_prompts::parse_mcp_prompt_arguments;
 use crate::bottom_pane::paste_burst::FlushResult;
 use crate::bottom_pane::prompt_args::expand_custom_prompt;
 use crate::bottom_pane::prompt_args::expand_if_numeric_with_positional_args;
 use crate::bottom_pane::prompt_args::parse_slash_name;
-use crate::bottom_pane::prompt_args::prompt_argument_names;
 use crate::bottom_pane::prompt_args::prompt_command_with_arg_placeholders;
-use crate::bottom_pane::prompt_args::prompt_has_numeric_placeholders;
 use crate::render::Insets;
 use crate::render::RectExt;
 use crate::render::renderable::Renderable;
@@ -173,6 +175,8 @@ use crate::history_cell;
 use crate::ui_consts::LIVE_PREFIX_COLS;
 use codex_chatgpt::connectors;
 use codex_chatgpt::connectors::AppInfo;
+use codex_core::custom_prompts::prompt_argument_names;
+use codex_core::custom_prompts::prompt_has_numeric_placeholders;
 use codex_core::skills::model::SkillMetadata;
 use codex_file_search::FileMatch;
 use std::cell::RefCell;
@@ -201,6 +205,12 @@ pub enum InputResult {
     },
     Command(SlashCommand),
     CommandWithArgs(SlashCommand, String, Vec<TextElement>),
+    /// Fetch an MCP server prompt and submit its messages as the user turn.
+    McpPrompt {
+        server: String,
+        name: String,
+        arguments: HashMap<String, String>,
+    },
     None,
 }
 
@@ -290,6 +300,7 @@ pub(crate) struct ChatComposer {
     // When true, disables paste-burst logic and inserts characters immediately.
     disable_paste_burst: bool,
     custom_prompts: Vec<CustomPrompt>,
+    mcp_prompts: Vec<McpPromptCommand>,
     footer_mode: FooterMode,
     footer_hint_override: Option<Vec<(String, String)>>,
     footer_flash: Option<FooterFlash>,
@@ -390,6 +401,7 @@ impl ChatComposer {
             paste_burst: PasteBurst::default(),
             disable_paste_burst: false,
             custom_prompts: Vec::new(),
+            mcp_prompts: Vec::new(),
             footer_mode: FooterMode::ComposerEmpty,
             footer_hint_override: None,
             footer_flash: None,
@@ -1185,6 +1197,14 @@ impl ChatComposer {
                                 }
                             }
                         }
+                        CommandItem::McpPrompt(idx) => {
+                            if let Some(command) = popup.mcp_prompt(idx) {
+                                let (text, cursor) =
+                                    mcp_prompt_command_with_arg_placeholders(command);
+                                self.textarea.set_text_clearing_elements(&text);
+                                cursor_target = Some(cursor);
+                            }
+                        }
                     }
                     if let Some(pos) = cursor_target {
                         self.textarea.set_cursor(pos);
@@ -1272,6 +1292,30 @@ impl ChatComposer {
                             }
                             return (InputResult::None, true);
                         }
+                        CommandItem::McpPrompt(idx) => {
+                            let Some(command) = popup.mcp_prompt(idx) else {
+                                return (InputResult::None, true);
+                            };
+                            if command.prompt.arguments.is_empty() {
+                                let result = InputResult::McpPrompt {
+                                    server: command.server.clone(),
+                                    name: command.prompt.name.clone(),
+                                    arguments: HashMap::new(),
+                                };
+                                self.history
+                                    .record_local_submission(HistoryEntry::new(format!(
+                                        "/{}",
+                                        command.command()
+                                    )));
+                                self.textarea.set_text_clearing_elements("");
+                                return (result, true);
+                            }
+                            // Prompts with arguments need values first; insert `name=""` slots.
+                            let (text, cursor) = mcp_prompt_command_with_arg_placeholders(command);
+                            self.textarea.set_text_clearing_elements(&text);
+                            self.textarea.set_cursor(cursor);
+                            return (InputResult::None, true);
+                        }
                     }
                 }
                 // Fallback to default newline handling if no command selected.
@@ -2056,7 +2100,8 @@ impl ChatComposer {
                             .iter()
                             .any(|prompt| prompt.name == prompt_name)
                     })
-                    .unwrap_or(false);
+                    .unwrap_or(false)
+                    || find_mcp_prompt(&self.mcp_prompts, name).is_some();
                 if !is_builtin && !is_known_prompt {
                     let message = format!(
                         r#"Unrecognized command '/{name}'. Type "/" for a list of supported commands."#
@@ -2192,6 +2237,9 @@ impl ChatComposer {
         if let Some(result) = self.try_dispatch_slash_command_with_args() {
             return (result, true);
         }
+        if let Some(result) = self.try_dispatch_mcp_prompt() {
+            return (result, true);
+        }
 
         if let Some((text, text_elements)) = self.prepare_submission_text(true) {
             if should_queue {
@@ -2294,6 +2342,49 @@ impl ChatComposer {
         ))
     }
 
+    /// Check if the input invokes an MCP prompt (e.g., `/docs:review focus=tests`).
+    /// Returns Some(InputResult) if the input names a known MCP prompt, None otherwise.
+    fn try_dispatch_mcp_prompt(&mut self) -> Option<InputResult> {
+        if !self.slash_commands_enabled() {
+            return None;
+        }
+        let mut text = self.textarea.text().to_string();
+        if text.starts_with(' ') {
+            return None;
+        }
+        if !self.pending_pastes.is_empty() {
+            let (expanded, _) = Self::expand_pending_pastes(
+                &text,
+                self.textarea.text_elements(),
+                &self.pending_pastes,
+            );
+            text = expanded;
+        }
+
+        let (name, rest, _rest_offset) = parse_slash_name(&text)?;
+        let command = find_mcp_prompt(&self.mcp_prompts, name)?;
+        match parse_mcp_prompt_arguments(command, rest) {
+            Ok(arguments) => {
+                let result = InputResult::McpPrompt {
+                    server: command.server.clone(),
+                    name: command.prompt.name.clone(),
+                    arguments,
+                };
+                self.history
+                    .record_local_submission(HistoryEntry::new(text.trim().to_string()));
+                self.pending_pastes.clear();
+                self.textarea.set_text_clearing_elements("");
+                Some(result)
+            }
+            Err(err) => {
+                self.app_event_tx.send(AppEvent::InsertHistoryCell(Box::new(
+                    history_cell::new_error_event(err.user_message()),
+                )));
+                Some(InputResult::None)
+            }
+        }
+    }
+
     /// Expand pending placeholders and extract normalized inline-command args.
     ///
     /// Inline-arg commands are initially dispatched using the raw draft so command rejection does
@@ -2894,7 +2985,7 @@ impl ChatComposer {
                 .iter()
                 .any(|prompt| prompt.name == prompt_name);
         }
-        false
+        find_mcp_prompt(&self.mcp_prompts, name).is_some()
     }
 
     /// If the cursor is currently within a slash command on the first line,
@@ -2948,7 +3039,10 @@ impl ChatComposer {
 
         self.custom_prompts.iter().any(|prompt| {
             fuzzy_match(&format!("{PROMPTS_CMD_PREFIX}:{}", prompt.name), name).is_some()
-        })
+        }) || self
+            .mcp_prompts
+            .iter()
+            .any(|prompt| fuzzy_match(&prompt.command(), name).is_some())
     }
 
     /// Synchronize `self.command_popup` with the current text in the
@@ -3005,6 +3099,7 @@ impl ChatComposer {
                         },
                         self.language,
                     );
+                    command_popup.set_mcp_prompts(self.mcp_prompts.clone());
                     command_popup.on_composer_text_change(first_line.to_string());
                     self.active_popup = ActivePopup::Command(command_popup);
                 }
@@ -3018,6 +3113,13 @@ impl ChatComposer {
         }
     }
 
+    pub(crate) fn set_mcp_prompts(&mut self, prompts: Vec<McpPromptCommand>) {
+        self.mcp_prompts = prompts.clone();
+        if let ActivePopup::Command(popup) = &mut self.active_popup {
+            popup.set_mcp_prompts(prompts);
+        }
+    }
+
     /// Synchronize `self.file_search_popup` with the current text in the textarea.
     /// Note this is only called when self.active_popup is NOT Command.
     fn sync_file_search_popup(&mut self, query: String) {
@@ -5137,7 +5239,7 @@ mod tests {
                 Some(CommandItem::Builtin(cmd)) => {
                     assert_eq!(cmd.command(), "model")
                 }
-                Some(CommandItem::UserPrompt(_)) => {
+                Some(CommandItem::UserPrompt(_) | CommandItem::McpPrompt(_)) => {
                     panic!("unexpected prompt selected for '/mo'")
                 }
                 None => panic!("no selected command for '/mo'"),
@@ -5194,7 +5296,7 @@ mod tests {
                 Some(CommandItem::Builtin(cmd)) => {
                     assert_eq!(cmd.command(), "resume")
                 }
-                Some(CommandItem::UserPrompt(_)) => {
+                Some(CommandItem::UserPrompt(_) | CommandItem::McpPrompt(_)) => {
                     panic!("unexpected prompt selected for '/res'")
                 }
                 None => panic!("no selected command for '/res'"),
@@ -6641,6 +6743,61 @@ mod tests {
         assert!(composer.textarea.is_empty());
     }
 
+    #[test]
+    fn mcp_prompt_submission_dispatches_arguments() {
+        use codex_protocol::mcp::Prompt;
+        use codex_protocol::mcp::PromptArgument;
+
+        let (tx, _rx) = unbounded_channel::<AppEvent>();
+        let sender = AppEventSender::new(tx);
+        let mut composer = ChatComposer::new(
+            true,
+            sender,
+            false,
+            "Ask Codex to do anything".to_string(),
+            false,
+        );
+        composer.set_steer_enabled(true);
+
+        composer.set_mcp_prompts(vec![McpPromptCommand {
+            server: "docs".to_string(),
+            prompt: Prompt {
+                name: "review".to_string(),
+                title: None,
+                description: None,
+                arguments: vec![PromptArgument {
+                    name: "focus".to_string(),
+                    title: None,
+                    description: None,
+                    required: true,
+                }],
+            },
+        }]);
+
+        composer
+            .textarea
+            .set_text_clearing_elements(r#"/docs:review focus="error handling""#);
+
+        let (result, _needs_redraw) =
+            composer.handle_key_event(KeyEvent::new(KeyCode::Enter, KeyModifiers::NONE));
+
+        let InputResult::McpPrompt {
+            server,
+            name,
+            arguments,
+        } = result
+        else {
+            panic!("expected MCP prompt dispatch, got {result:?}");
+        };
+        assert_eq!(server, "docs");
+        assert_eq!(name, "review");
+        assert_eq!(
+            arguments,
+            HashMap::from([("focus".to_string(), "error handling".to_string())])
+        );
+        assert!(composer.textarea.is_empty());
+    }
+
     #[test]
     fn custom_prompt_submission_accepts_quoted_values() {
         let (tx, _rx) = unbounded_channel::<AppEvent>();
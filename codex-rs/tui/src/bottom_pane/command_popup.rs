@@ -2,12 +2,14 @@ use ratatui::buffer::Buffer;
 use ratatui::layout::Rect;
 use ratatui::widgets::WidgetRef;
 
+use super::mcp_prompts::McpPromptCommand;
 use super::popup_consts::MAX_POPUP_ROWS;
 use super::scroll_state::ScrollState;
 use super::selection_popup_common::GenericDisplayRow;
 use super::selection_popup_common::render_rows;
 use super::slash_commands;
 use crate::i18n::tr;
+use crate::i18n::tr_args;
 use crate::render::Insets;
 use crate::render::RectExt;
 use crate::slash_command::SlashCommand;
@@ -21,18 +23,22 @@ use std::collections::HashSet;
 // `approvals` is an alias of `permissions`.
 const ALIAS_COMMANDS: &[SlashCommand] = &[SlashCommand::Quit, SlashCommand::Approvals];
 
-/// A selectable item in the popup: either a built-in command or a user prompt.
+/// A selectable item in the popup: a built-in command, a user prompt or a
+/// prompt offered by an MCP server.
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub(crate) enum CommandItem {
     Builtin(SlashCommand),
     // Index into `prompts`
     UserPrompt(usize),
+    // Index into `mcp_prompts`
+    McpPrompt(usize),
 }
 
 pub(crate) struct CommandPopup {
     command_filter: String,
     builtins: Vec<(&'static str, SlashCommand)>,
     prompts: Vec<CustomPrompt>,
+    mcp_prompts: Vec<McpPromptCommand>,
     state: ScrollState,
     language: Language,
 }
@@ -70,6 +76,7 @@ impl CommandPopup {
             command_filter: String::new(),
             builtins,
             prompts,
+            mcp_prompts: Vec::new(),
             state: ScrollState::new(),
             language,
         }
@@ -86,6 +93,11 @@ impl CommandPopup {
         self.prompts = prompts;
     }
 
+    /// Replace the MCP prompts, which arrive already sorted by command name.
+    pub(crate) fn set_mcp_prompts(&mut self, prompts: Vec<McpPromptCommand>) {
+        self.mcp_prompts = prompts;
+    }
+
     pub(crate) fn set_language(&mut self, language: Language) {
         self.language = language;
     }
@@ -94,6 +106,10 @@ impl CommandPopup {
         self.prompts.get(idx)
     }
 
+    pub(crate) fn mcp_prompt(&self, idx: usize) -> Option<&McpPromptCommand> {
+        self.mcp_prompts.get(idx)
+    }
+
     /// Update the filter string based on the current composer text. The text
     /// passed in is expected to start with a leading '/'. Everything after the
     /// *first* '/' on the *first* line becomes the active filter that is used
@@ -152,6 +168,9 @@ impl CommandPopup {
             for idx in 0..self.prompts.len() {
                 out.push((CommandItem::UserPrompt(idx), None));
             }
+            for idx in 0..self.mcp_prompts.len() {
+                out.push((CommandItem::McpPrompt(idx), None));
+            }
             return out;
         }
 
@@ -198,6 +217,15 @@ impl CommandPopup {
                 prompt_prefix_len,
             );
         }
+        // MCP prompts match on "server:name" as well as the bare prompt name.
+        for (idx, p) in self.mcp_prompts.iter().enumerate() {
+            push_match(
+                CommandItem::McpPrompt(idx),
+                &p.command(),
+                Some(&p.prompt.name),
+                p.server.chars().count() + 1,
+            );
+        }
 
         out.extend(exact);
         out.extend(prefix);
@@ -230,6 +258,22 @@ impl CommandPopup {
                             description,
                         )
                     }
+                    CommandItem::McpPrompt(i) => {
+                        let prompt = &self.mcp_prompts[i];
+                        let description = prompt
+                            .prompt
+                            .description
+                            .clone()
+                            .or_else(|| prompt.prompt.title.clone())
+                            .unwrap_or_else(|| {
+                                tr_args(
+                                    self.language,
+                                    "command_popup.send_mcp_prompt",
+                                    &[("server", &prompt.server)],
+                                )
+                            });
+                        (format!("/{}", prompt.command()), description)
+                    }
                 };
                 GenericDisplayRow {
                     name,
@@ -300,7 +344,7 @@ mod tests {
         let matches = popup.filtered_items();
         let has_init = matches.iter().any(|item| match item {
             CommandItem::Builtin(cmd) => cmd.command() == "init",
-            CommandItem::UserPrompt(_) => false,
+            CommandItem::UserPrompt(_) | CommandItem::McpPrompt(_) => false,
         });
         assert!(
             has_init,
@@ -318,7 +362,9 @@ mod tests {
         let selected = popup.selected_item();
         match selected {
             Some(CommandItem::Builtin(cmd)) => assert_eq!(cmd.command(), "init"),
-            Some(CommandItem::UserPrompt(_)) => panic!("unexpected prompt selected for '/init'"),
+            Some(CommandItem::UserPrompt(_) | CommandItem::McpPrompt(_)) => {
+                panic!("unexpected prompt selected for '/init'")
+            }
             None => panic!("expected a selected command for exact match"),
         }
     }
@@ -330,7 +376,7 @@ mod tests {
         let matches = popup.filtered_items();
         match matches.first() {
             Some(CommandItem::Builtin(cmd)) => assert_eq!(cmd.command(), "model"),
-            Some(CommandItem::UserPrompt(_)) => {
+            Some(CommandItem::UserPrompt(_) | CommandItem::McpPrompt(_)) => {
                 panic!("unexpected prompt ranked before '/model' for '/mo'")
             }
             None => panic!("expected at least one match for '/mo'"),
@@ -347,7 +393,7 @@ mod tests {
             .into_iter()
             .filter_map(|item| match item {
                 CommandItem::Builtin(cmd) => Some(cmd.command()),
-                CommandItem::UserPrompt(_) => None,
+                CommandItem::UserPrompt(_) | CommandItem::McpPrompt(_) => None,
             })
             .collect();
         assert_eq!(cmds, vec!["model", "mention", "mcp"]);
@@ -458,7 +504,7 @@ mod tests {
             .into_iter()
             .filter_map(|item| match item {
                 CommandItem::Builtin(cmd) => Some(cmd.command()),
-                CommandItem::UserPrompt(_) => None,
+                CommandItem::UserPrompt(_) | CommandItem::McpPrompt(_) => None,
             })
             .collect();
         assert!(
@@ -489,7 +535,7 @@ mod tests {
             .into_iter()
             .filter_map(|item| match item {
                 CommandItem::Builtin(cmd) => Some(cmd.command()),
-                CommandItem::UserPrompt(_) => None,
+                CommandItem::UserPrompt(_) | CommandItem::McpPrompt(_) => None,
             })
             .collect();
         assert!(
@@ -564,7 +610,7 @@ mod tests {
             .into_iter()
             .filter_map(|item| match item {
                 CommandItem::Builtin(cmd) => Some(cmd.command()),
-                CommandItem::UserPrompt(_) => None,
+                CommandItem::UserPrompt(_) | CommandItem::McpPrompt(_) => None,
             })
             .collect();
         assert!(
@@ -0,0 +1,241 @@
+//! Prompts offered by MCP servers, surfaced as `/server:prompt` slash commands.
+
+use std::collections::HashMap;
+
+use codex_protocol::custom_prompts::PROMPTS_CMD_PREFIX;
+use codex_protocol::mcp::GetPromptResult;
+use codex_protocol::mcp::Prompt;
+use serde_json::Value;
+
+use super::prompt_args::PromptExpansionError;
+use super::prompt_args::parse_prompt_inputs;
+
+/// A prompt paired with the MCP server that offers it.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct McpPromptCommand {
+    pub(crate) server: String,
+    pub(crate) prompt: Prompt,
+}
+
+impl McpPromptCommand {
+    /// Command name without the leading `/`, e.g. `docs:release-notes`.
+    pub(crate) fn command(&self) -> String {
+        format!("{}:{}", self.server, self.prompt.name)
+    }
+}
+
+/// Flattens prompts grouped by server into slash commands sorted by name.
+///
+/// A server named after the custom prompts namespace is skipped so that
+/// `/prompts:<name>` keeps resolving to saved prompts.
+pub(crate) fn mcp_prompt_commands(prompts: HashMap<String, Vec<Prompt>>) -> Vec<McpPromptCommand> {
+    let mut commands: Vec<McpPromptCommand> = prompts
+        .into_iter()
+        .filter(|(server, _)| server != PROMPTS_CMD_PREFIX)
+        .flat_map(|(server, prompts)| {
+            prompts.into_iter().map(move |prompt| McpPromptCommand {
+                server: server.clone(),
+                prompt,
+            })
+        })
+        .collect();
+    commands.sort_by_key(McpPromptCommand::command);
+    commands
+}
+
+/// Looks up the command for a slash name such as `docs:release-notes`.
+pub(crate) fn find_mcp_prompt<'a>(
+    commands: &'a [McpPromptCommand],
+    name: &str,
+) -> Option<&'a McpPromptCommand> {
+    let (server, prompt_name) = name.split_once(':')?;
+    commands
+        .iter()
+        .find(|command| command.server == server && command.prompt.name == prompt_name)
+}
+
+/// Constructs the command text inserted when a prompt with arguments is
+/// selected, with an empty `name=""` per declared argument. Returns the text
+/// and the cursor position (inside the first pair of quotes).
+pub(crate) fn mcp_prompt_command_with_arg_placeholders(
+    command: &McpPromptCommand,
+) -> (String, usize) {
+    let mut text = format!("/{}", command.command());
+    let mut cursor = text.len();
+    for (i, argument) in command.prompt.arguments.iter().enumerate() {
+        text.push_str(&format!(" {}=\"\"", argument.name));
+        if i == 0 {
+            cursor = text.len() - 1;
+        }
+    }
+    (text, cursor)
+}
+
+/// Parses the `key=value` pairs that follow an MCP prompt command.
+///
+/// Empty values are dropped, so the placeholders inserted on selection do not
+/// satisfy required arguments.
+pub(crate) fn parse_mcp_prompt_arguments(
+    command: &McpPromptCommand,
+    rest: &str,
+) -> Result<HashMap<String, String>, PromptExpansionError> {
+    let command_name = format!("/{}", command.command());
+    let arguments: HashMap<String, String> = parse_prompt_inputs(rest, &[])
+        .map_err(|error| PromptExpansionError::Args {
+            command: command_name.clone(),
+            error,
+        })?
+        .into_iter()
+        .filter(|(_, value)| !value.text.is_empty())
+        .map(|(key, value)| (key, value.text))
+        .collect();
+    let missing: Vec<String> = command
+        .prompt
+        .arguments
+        .iter()
+        .filter(|argument| argument.required && !arguments.contains_key(&argument.name))
+        .map(|argument| argument.name.clone())
+        .collect();
+    if !missing.is_empty() {
+        return Err(PromptExpansionError::MissingArgs {
+            command: command_name,
+            missing,
+        });
+    }
+    Ok(arguments)
+}
+
+/// Joins the text of the messages returned by `prompts/get` into the user
+/// turn. Text embedded through resources is included; images and other binary
+/// content are skipped.
+pub(crate) fn mcp_prompt_text(result: &GetPromptResult) -> String {
+    result
+        .messages
+        .iter()
+        .filter_map(|message| prompt_content_text(&message.content))
+        .collect::<Vec<_>>()
+        .join("\n\n")
+}
+
+fn prompt_content_text(content: &Value) -> Option<String> {
+    let text = match content.get("type").and_then(Value::as_str)? {
+        "text" => content.get("text"),
+        "resource" => content
+            .pointer("/resource/text")
+            .or_else(|| content.pointer("/resource/resource/text")),
+        _ => None,
+    };
+    text.and_then(Value::as_str)
+        .filter(|text| !text.is_empty())
+        .map(str::to_string)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use codex_protocol::mcp::PromptArgument;
+    use codex_protocol::mcp::PromptMessage;
+    use codex_protocol::mcp::PromptMessageRole;
+    use pretty_assertions::assert_eq;
+    use serde_json::json;
+
+    fn review_command() -> McpPromptCommand {
+        McpPromptCommand {
+            server: "docs".to_string(),
+            prompt: Prompt {
+                name: "review".to_string(),
+                title: None,
+                description: None,
+                arguments: vec![
+                    PromptArgument {
+                        name: "focus".to_string(),
+                        title: None,
+                        description: None,
+                        required: true,
+                    },
+                    PromptArgument {
+                        name: "tone".to_string(),
+                        title: None,
+                        description: None,
+                        required: false,
+                    },
+                ],
+            },
+        }
+    }
+
+    #[test]
+    fn commands_skip_the_custom_prompts_namespace() {
+        let prompt = review_command().prompt;
+        let commands = mcp_prompt_commands(HashMap::from([
+            ("docs".to_string(), vec![prompt.clone()]),
+            (PROMPTS_CMD_PREFIX.to_string(), vec![prompt]),
+        ]));
+
+        assert_eq!(commands, vec![review_command()]);
+        assert_eq!(
+            find_mcp_prompt(&commands, "docs:review"),
+            Some(&review_command())
+        );
+    }
+
+    #[test]
+    fn placeholders_put_cursor_in_first_argument() {
+        let (text, cursor) = mcp_prompt_command_with_arg_placeholders(&review_command());
+
+        assert_eq!(text, r#"/docs:review focus="" tone="""#);
+        assert_eq!(cursor, r#"/docs:review focus=""#.len());
+    }
+
+    #[test]
+    fn empty_required_argument_is_missing() {
+        let err = parse_mcp_prompt_arguments(&review_command(), r#"focus="" tone=terse"#)
+            .expect_err("focus is required");
+
+        assert_eq!(
+            err.user_message(),
+            "Missing required args for /docs:review: focus. Provide as key=value (quote values with spaces)."
+        );
+    }
+
+    #[test]
+    fn arguments_drop_empty_optional_values() {
+        let arguments =
+            parse_mcp_prompt_arguments(&review_command(), r#"focus="error handling" tone="""#)
+                .expect("arguments");
+
+        assert_eq!(
+            arguments,
+            HashMap::from([("focus".to_string(), "error handling".to_string())])
+        );
+    }
+
+    #[test]
+    fn prompt_text_joins_text_and_embedded_resources() {
+        let result = GetPromptResult {
+            description: None,
+            messages: vec![
+                PromptMessage {
+                    role: PromptMessageRole::User,
+                    content: json!({ "type": "text", "text": "Review this change." }),
+                },
+                PromptMessage {
+                    role: PromptMessageRole::User,
+                    content: json!({
+                        "type": "resource",
+                        "resource": { "uri": "file:///guide.md", "text": "Style guide" },
+                    }),
+                },
+                PromptMessage {
+                    role: PromptMessageRole::User,
+                    content: json!({ "type": "image", "data": "AAAA", "mimeType": "image/png" }),
+                },
+            ],
+        };
+
+        assert_eq!(
+            mcp_prompt_text(&result),
+            "Review this change.\n\nStyle guide"
+        );
+    }
+}
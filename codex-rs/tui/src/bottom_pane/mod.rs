@@ -48,6 +48,7 @@ mod status_line_setup;
 pub(crate) use app_link_view::AppLinkView;
 pub(crate) use approval_overlay::ApprovalOverlay;
 pub(crate) use approval_overlay::ApprovalRequest;
+pub(crate) use approval_overlay::network_request_lines;
 pub(crate) use request_user_input::RequestUserInputOverlay;
 mod bottom_pane_view;
 
@@ -72,6 +73,7 @@ mod experimental_features_view;
 mod file_search_popup;
 mod footer;
 mod list_selection_view;
+mod mcp_prompts;
 mod prompt_args;
 mod skill_popup;
 mod skills_toggle_view;
@@ -79,6 +81,8 @@ mod slash_commands;
 pub(crate) use footer::CollaborationModeIndicator;
 pub(crate) use list_selection_view::ColumnWidthMode;
 pub(crate) use list_selection_view::SelectionViewParams;
+pub(crate) use mcp_prompts::mcp_prompt_commands;
+pub(crate) use mcp_prompts::mcp_prompt_text;
 pub(crate) use prompt_args::parse_slash_name;
 mod feedback_view;
 pub(crate) use feedback_view::FeedbackAudience;
@@ -750,6 +754,12 @@ impl BottomPane {
         self.request_redraw();
     }
 
+    /// Update MCP server prompts available for the slash popup.
+    pub(crate) fn set_mcp_prompts(&mut self, prompts: Vec<mcp_prompts::McpPromptCommand>) {
+        self.composer.set_mcp_prompts(prompts);
+        self.request_redraw();
+    }
+
     pub(crate) fn composer_is_empty(&self) -> bool {
         self.composer.is_empty()
     }
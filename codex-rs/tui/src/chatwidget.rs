@@ -38,6 +38,8 @@ use std::time::Instant;
 
 use crate::bottom_pane::StatusLineItem;
 use crate::bottom_pane::StatusLineSetupView;
+use crate::i18n::available_languages;
+use crate::i18n::language_name;
 use crate::i18n::tr;
 use crate::i18n::tr_args;
 use crate::status::RateLimitWindowDisplay;
@@ -68,6 +70,7 @@ use codex_core::protocol::AgentReasoningRawContentDeltaEvent;
 use codex_core::protocol::AgentReasoningRawContentEvent;
 use codex_core::protocol::ApplyPatchApprovalRequestEvent;
 use codex_core::protocol::BackgroundEventEvent;
+use codex_core::protocol::CheckpointCreatedEvent;
 use codex_core::protocol::CodexErrorInfo;
 use codex_core::protocol::CreditsSnapshot;
 use codex_core::protocol::DeprecationNoticeEvent;
@@ -80,7 +83,9 @@ use codex_core::protocol::ExecCommandEndEvent;
 use codex_core::protocol::ExecCommandOutputDeltaEvent;
 use codex_core::protocol::ExecCommandSource;
 use codex_core::protocol::ExitedReviewModeEvent;
+use codex_core::protocol::GetMcpPromptResponseEvent;
 use codex_core::protocol::ListCustomPromptsResponseEvent;
+use codex_core::protocol::ListMcpPromptsResponseEvent;
 use codex_core::protocol::ListSkillsResponseEvent;
 use codex_core::protocol::McpListToolsResponseEvent;
 use codex_core::protocol::McpStartupCompleteEvent;
@@ -93,6 +98,7 @@ use codex_core::protocol::PatchApplyBeginEvent;
 use codex_core::protocol::RateLimitSnapshot;
 use codex_core::protocol::ReviewRequest;
 use codex_core::protocol::ReviewTarget;
+use codex_core::protocol::SddGitAction;
 use codex_core::protocol::SkillMetadata as ProtocolSkillMetadata;
 use codex_core::protocol::StreamErrorEvent;
 use codex_core::protocol::TerminalInteractionEvent;
@@ -116,6 +122,8 @@ use codex_otel::RuntimeMetricsSummary;
 use codex_protocol::ThreadId;
 use codex_protocol::account::PlanType;
 use codex_protocol::approvals::ElicitationRequestEvent;
+use codex_protocol::approvals::NetworkApprovalRequestEvent;
+use codex_protocol::approvals::SamplingRequestEvent;
 use codex_protocol::config_types::CollaborationMode;
 use codex_protocol::config_types::CollaborationModeMask;
 use codex_protocol::config_types::Language;
@@ -182,6 +190,8 @@ use crate::bottom_pane::SelectionAction;
 use crate::bottom_pane::SelectionItem;
 use crate::bottom_pane::SelectionViewParams;
 use crate::bottom_pane::custom_prompt_view::CustomPromptView;
+use crate::bottom_pane::mcp_prompt_commands;
+use crate::bottom_pane::mcp_prompt_text;
 use crate::bottom_pane::popup_consts::standard_popup_hint_line;
 use crate::clipboard_paste::paste_image_to_temp_png;
 use crate::collab;
@@ -249,6 +259,32 @@ use strum::IntoEnumIterator;
 const USER_SHELL_COMMAND_HELP_TITLE: &str = "Prefix a command with ! to run it locally";
 const USER_SHELL_COMMAND_HELP_HINT: &str = "Example: !ls";
 const DEFAULT_OPENAI_BASE_URL: &str = "https://api.openai.com/v1";
+const SDD_BRANCH_PREFIX: &str = "sdd/";
+
+fn sdd_plan_prompt_template(language: Language) -> &'static str {
+    tr(language, "prompt.sdd_plan")
+}
+
+fn sdd_plan_parallels_prompt_template(language: Language) -> &'static str {
+    tr(language, "prompt.sdd_plan_parallels")
+}
+
+fn sdd_exec_prompt_template(language: Language) -> &'static str {
+    tr(language, "prompt.sdd_execute")
+}
+
+fn sdd_exec_parallels_prompt_template(language: Language) -> &'static str {
+    tr(language, "prompt.sdd_execute_parallels")
+}
+
+fn sdd_merge_prompt_template(language: Language) -> &'static str {
+    tr(language, "prompt.sdd_merge")
+}
+
+fn sdd_merge_parallels_prompt_template(language: Language) -> &'static str {
+    tr(language, "prompt.sdd_merge_parallels")
+}
+
 // Track information about an in-flight exec command.
 struct RunningCommand {
     command: Vec<String>,
@@ -430,6 +466,34 @@ enum RateLimitSwitchPromptState {
     Shown,
 }
 
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum SddDevelopStage {
+    AwaitPlanDecision,
+    AwaitDevDecision,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum SddWorkflow {
+    Standard,
+    Parallels,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct SddDevelopState {
+    workflow: SddWorkflow,
+    description: String,
+    branch_name: String,
+    base_branch: Option<String>,
+    stage: SddDevelopStage,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum SddGitPendingAction {
+    CreateBranch { description: String },
+    FinalizeMerge,
+    AbandonBranch,
+}
+
 #[derive(Debug, Clone, Default)]
 enum ConnectorsCacheState {
     #[default]
@@ -629,6 +693,18 @@ pub(crate) struct ChatWidget {
     // True once we've attempted a branch lookup for the current CWD.
     status_line_branch_lookup_complete: bool,
     external_editor_state: ExternalEditorState,
+    // State for the /sdd-develop workflow.
+    sdd_state: Option<SddDevelopState>,
+    // Pending plan-rework prompt prefix to prepend on the next user submission.
+    sdd_pending_plan_rework_prompt: Option<String>,
+    // When true, reopen plan options after the next task completes.
+    sdd_open_plan_options_after_task: bool,
+    // Pending SDD git action awaiting completion.
+    sdd_pending_git_action: Option<SddGitPendingAction>,
+    // Whether the last SDD git action reported a failure.
+    sdd_git_action_failed: bool,
+    // When true, start a fresh session after current turn completes (used for SDD abandon).
+    sdd_new_session_after_cleanup: bool,
 }
 
 /// Snapshot of active-cell state that affects transcript overlay rendering.
@@ -1386,6 +1462,68 @@ impl ChatWidget {
         });
 
         self.maybe_show_pending_rate_limit_prompt();
+
+        if let Some(action) = self.sdd_pending_git_action.take() {
+            let failed = self.sdd_git_action_failed;
+            self.sdd_git_action_failed = false;
+            if failed {
+                self.add_error_message(
+                    tr(self.config.language, "chatwidget.sdd.git_failed").to_string(),
+                );
+                return;
+            }
+            match action {
+                SddGitPendingAction::CreateBranch { description } => {
+                    let workflow = self
+                        .sdd_state
+                        .as_ref()
+                        .map_or(SddWorkflow::Standard, |state| state.workflow);
+                    let prompt = self.build_sdd_exec_prompt(&description, workflow);
+                    self.submit_user_message(prompt.into());
+                    if let Some(state) = self.sdd_state.as_mut() {
+                        state.stage = SddDevelopStage::AwaitDevDecision;
+                    }
+                    self.add_info_message(
+                        tr(self.config.language, "chatwidget.sdd.exec_sent").to_string(),
+                        Some(tr(self.config.language, "chatwidget.sdd.exec_sent_hint").to_string()),
+                    );
+                    self.open_sdd_dev_options();
+                }
+                SddGitPendingAction::FinalizeMerge => {
+                    self.sdd_state = None;
+                    self.add_info_message(
+                        tr(self.config.language, "chatwidget.sdd.merge_completed").to_string(),
+                        None,
+                    );
+                }
+                SddGitPendingAction::AbandonBranch => {
+                    self.sdd_state = None;
+                    self.sdd_new_session_after_cleanup = true;
+                    self.add_info_message(
+                        tr(self.config.language, "chatwidget.sdd.branch_deleted").to_string(),
+                        None,
+                    );
+                }
+            }
+        }
+
+        if self.sdd_open_plan_options_after_task {
+            self.sdd_open_plan_options_after_task = false;
+            if matches!(
+                self.sdd_state,
+                Some(SddDevelopState {
+                    stage: SddDevelopStage::AwaitPlanDecision,
+                    ..
+                })
+            ) {
+                self.open_sdd_plan_options();
+            }
+        }
+
+        if self.sdd_new_session_after_cleanup {
+            self.sdd_new_session_after_cleanup = false;
+            self.app_event_tx.send(AppEvent::NewSession);
+        }
     }
 
     fn maybe_prompt_plan_implementation(&mut self) {
@@ -1619,6 +1757,9 @@ impl ChatWidget {
     }
 
     fn on_error(&mut self, message: String) {
+        if self.sdd_pending_git_action.is_some() {
+            self.sdd_git_action_failed = true;
+        }
         self.finalize_turn();
         self.add_to_history(history_cell::new_error_event(message));
         self.request_redraw();
@@ -1697,6 +1838,8 @@ impl ChatWidget {
         self.mcp_startup_status = None;
         self.update_task_running_state();
         self.maybe_send_next_queued_input();
+        // Servers are ready now, so their prompts can be listed for the slash popup.
+        self.submit_op(Op::ListMcpPrompts);
         self.request_redraw();
     }
 
@@ -1825,6 +1968,22 @@ impl ChatWidget {
         );
     }
 
+    fn on_sampling_request(&mut self, ev: SamplingRequestEvent) {
+        let ev2 = ev.clone();
+        self.defer_or_handle(
+            |q| q.push_sampling(ev),
+            |s| s.handle_sampling_request_now(ev2),
+        );
+    }
+
+    fn on_network_approval_request(&mut self, ev: NetworkApprovalRequestEvent) {
+        let ev2 = ev.clone();
+        self.defer_or_handle(
+            |q| q.push_network_approval(ev),
+            |s| s.handle_network_approval_now(ev2),
+        );
+    }
+
     fn on_request_user_input(&mut self, ev: RequestUserInputEvent) {
         let ev2 = ev.clone();
         self.defer_or_handle(
@@ -1946,6 +2105,12 @@ impl ChatWidget {
     }
 
     fn on_exec_command_end(&mut self, ev: ExecCommandEndEvent) {
+        if self.sdd_pending_git_action.is_some()
+            && ev.source == ExecCommandSource::Agent
+            && ev.exit_code != 0
+        {
+            self.sdd_git_action_failed = true;
+        }
         if is_unified_exec_source(ev.source) {
             if let Some(process_id) = ev.process_id.as_deref()
                 && self
@@ -2158,6 +2323,17 @@ impl ChatWidget {
         }
     }
 
+    fn on_checkpoint_created(&mut self, event: CheckpointCreatedEvent) {
+        let CheckpointCreatedEvent { name, path, .. } = event;
+        let path = path.strip_prefix(&self.config.cwd).unwrap_or(&path);
+        self.add_info_message(
+            format!("Checkpoint `{name}` written to {}", path.display()),
+            Some(format!(
+                "Use /resume {name} or /fork {name} to restart from it."
+            )),
+        );
+    }
+
     fn on_stream_error(&mut self, message: String, additional_details: Option<String>) {
         if self.retry_status_header.is_none() {
             self.retry_status_header = Some(self.current_status_header.clone());
@@ -2431,6 +2607,44 @@ impl ChatWidget {
         self.request_redraw();
     }
 
+    pub(crate) fn handle_sampling_request_now(&mut self, ev: SamplingRequestEvent) {
+        self.flush_answer_stream_with_separator();
+
+        self.notify(Notification::SamplingRequested {
+            server_name: ev.server_name.clone(),
+        });
+
+        let request = ApprovalRequest::McpSampling {
+            server_name: ev.server_name,
+            request_id: ev.id,
+            messages: ev.messages,
+            system_prompt: ev.system_prompt,
+            max_tokens: ev.max_tokens,
+        };
+        self.bottom_pane
+            .push_approval_request(request, &self.config.features);
+        self.request_redraw();
+    }
+
+    pub(crate) fn handle_network_approval_now(&mut self, ev: NetworkApprovalRequestEvent) {
+        self.flush_answer_stream_with_separator();
+
+        self.notify(Notification::NetworkApprovalRequested {
+            host: ev.host.clone(),
+        });
+
+        let request = ApprovalRequest::Network {
+            id: ev.id,
+            host: ev.host,
+            port: ev.port,
+            protocol: ev.protocol,
+            method: ev.method,
+        };
+        self.bottom_pane
+            .push_approval_request(request, &self.config.features);
+        self.request_redraw();
+    }
+
     pub(crate) fn handle_request_user_input_now(&mut self, ev: RequestUserInputEvent) {
         self.flush_answer_stream_with_separator();
         self.bottom_pane.push_user_input_request(ev);
@@ -2680,6 +2894,12 @@ impl ChatWidget {
             status_line_branch_pending: false,
             status_line_branch_lookup_complete: false,
             external_editor_state: ExternalEditorState::Closed,
+            sdd_state: None,
+            sdd_pending_plan_rework_prompt: None,
+            sdd_open_plan_options_after_task: false,
+            sdd_pending_git_action: None,
+            sdd_git_action_failed: false,
+            sdd_new_session_after_cleanup: false,
         };
 
         widget.prefetch_rate_limits();
@@ -2846,6 +3066,12 @@ impl ChatWidget {
             status_line_branch_pending: false,
             status_line_branch_lookup_complete: false,
             external_editor_state: ExternalEditorState::Closed,
+            sdd_state: None,
+            sdd_pending_plan_rework_prompt: None,
+            sdd_open_plan_options_after_task: false,
+            sdd_pending_git_action: None,
+            sdd_git_action_failed: false,
+            sdd_new_session_after_cleanup: false,
         };
 
         widget.prefetch_rate_limits();
@@ -3001,6 +3227,12 @@ impl ChatWidget {
             status_line_branch_pending: false,
             status_line_branch_lookup_complete: false,
             external_editor_state: ExternalEditorState::Closed,
+            sdd_state: None,
+            sdd_pending_plan_rework_prompt: None,
+            sdd_open_plan_options_after_task: false,
+            sdd_pending_git_action: None,
+            sdd_git_action_failed: false,
+            sdd_new_session_after_cleanup: false,
         };
 
         widget.prefetch_rate_limits();
@@ -3162,6 +3394,17 @@ impl ChatWidget {
                 InputResult::CommandWithArgs(cmd, args, text_elements) => {
                     self.dispatch_command_with_args(cmd, args, text_elements);
                 }
+                InputResult::McpPrompt {
+                    server,
+                    name,
+                    arguments,
+                } => {
+                    self.submit_op(Op::GetMcpPrompt {
+                        server,
+                        name,
+                        arguments,
+                    });
+                }
                 InputResult::None => {}
             },
         }
@@ -3263,10 +3506,7 @@ impl ChatWidget {
                 self.submit_user_message(INIT_PROMPT.to_string().into());
             }
             SlashCommand::Checkpoint => {
-                self.add_info_message(
-                    "Checkpoint is not available in this build.".to_string(),
-                    None,
-                );
+                self.submit_op(Op::Checkpoint { name: None });
             }
             SlashCommand::Compact => {
                 self.clear_token_usage();
@@ -3283,16 +3523,10 @@ impl ChatWidget {
                 self.open_model_popup();
             }
             SlashCommand::Lang => {
-                self.add_info_message(
-                    "Language selection is not available in this build.".to_string(),
-                    None,
-                );
+                self.open_language_popup();
             }
             SlashCommand::Spec => {
-                self.add_info_message(
-                    "Spec options are not available in this build.".to_string(),
-                    None,
-                );
+                self.open_spec_popup();
             }
             SlashCommand::Personality => {
                 self.open_personality_popup();
@@ -3420,11 +3654,11 @@ impl ChatWidget {
             SlashCommand::Status => {
                 self.add_status_output();
             }
-            SlashCommand::SddDevelop | SlashCommand::SddDevelopParallels => {
-                self.add_info_message(
-                    "SDD workflow is not available in this build.".to_string(),
-                    None,
-                );
+            SlashCommand::SddDevelop => {
+                self.handle_sdd_develop_command(None, SddWorkflow::Standard);
+            }
+            SlashCommand::SddDevelopParallels => {
+                self.handle_sdd_develop_command(None, SddWorkflow::Parallels);
             }
             SlashCommand::DebugConfig => {
                 self.add_debug_config_output();
@@ -3562,6 +3796,43 @@ impl ChatWidget {
                     self.queue_user_message(user_message);
                 }
             }
+            SlashCommand::Checkpoint | SlashCommand::Resume | SlashCommand::Fork
+                if !trimmed.is_empty() =>
+            {
+                let Some((prepared_args, _prepared_elements)) =
+                    self.bottom_pane.prepare_inline_args_submission(false)
+                else {
+                    return;
+                };
+                let name = prepared_args.trim().to_string();
+                match cmd {
+                    SlashCommand::Checkpoint => {
+                        self.submit_op(Op::Checkpoint { name: Some(name) });
+                    }
+                    SlashCommand::Resume => {
+                        self.app_event_tx
+                            .send(AppEvent::ResumeFromCheckpoint { name });
+                    }
+                    _ => self
+                        .app_event_tx
+                        .send(AppEvent::ForkFromCheckpoint { name }),
+                }
+                self.bottom_pane.drain_pending_submission_state();
+            }
+            SlashCommand::SddDevelop | SlashCommand::SddDevelopParallels if !trimmed.is_empty() => {
+                let Some((prepared_args, _prepared_elements)) =
+                    self.bottom_pane.prepare_inline_args_submission(false)
+                else {
+                    return;
+                };
+                let workflow = if cmd == SlashCommand::SddDevelopParallels {
+                    SddWorkflow::Parallels
+                } else {
+                    SddWorkflow::Standard
+                };
+                self.handle_sdd_develop_command(Some(prepared_args), workflow);
+                self.bottom_pane.drain_pending_submission_state();
+            }
             SlashCommand::Review if !trimmed.is_empty() => {
                 let Some((prepared_args, _prepared_elements)) =
                     self.bottom_pane.prepare_inline_args_submission(false)
@@ -3582,6 +3853,534 @@ impl ChatWidget {
         }
     }
 
+    fn is_sdd_workflow_enabled(&mut self, workflow: SddWorkflow) -> bool {
+        if workflow == SddWorkflow::Parallels && !self.config.features.enabled(Feature::Collab) {
+            let language = self.config.language;
+            self.add_info_message(
+                tr(language, "chatwidget.sdd.collab_required").to_string(),
+                Some(tr(language, "chatwidget.sdd.collab_required_hint").to_string()),
+            );
+            return false;
+        }
+        true
+    }
+
+    fn handle_sdd_develop_command(&mut self, description: Option<String>, workflow: SddWorkflow) {
+        let language = self.config.language;
+        if get_git_repo_root(&self.config.cwd).is_none() {
+            self.add_info_message(
+                tr(language, "chatwidget.sdd.not_git_repo").to_string(),
+                None,
+            );
+            return;
+        }
+        if !self.is_sdd_workflow_enabled(workflow) {
+            return;
+        }
+
+        if let Some(desc) = description
+            .map(|d| d.trim().to_string())
+            .filter(|d| !d.is_empty())
+        {
+            self.sdd_pending_plan_rework_prompt = None;
+            self.sdd_open_plan_options_after_task = false;
+            self.sdd_pending_git_action = None;
+            self.sdd_git_action_failed = false;
+            let branch_name = self.sdd_branch_name(&desc);
+            self.sdd_state = Some(SddDevelopState {
+                workflow,
+                description: desc.clone(),
+                branch_name,
+                base_branch: None,
+                stage: SddDevelopStage::AwaitPlanDecision,
+            });
+            let prompt = self.build_sdd_plan_prompt(&desc, workflow);
+            self.submit_user_message(prompt.into());
+            let plan_request_hint = if workflow == SddWorkflow::Parallels {
+                tr(language, "chatwidget.sdd.plan_request_hint_parallels").to_string()
+            } else {
+                tr(language, "chatwidget.sdd.plan_request_hint").to_string()
+            };
+            self.add_info_message(
+                tr(language, "chatwidget.sdd.plan_request_sent").to_string(),
+                Some(plan_request_hint),
+            );
+            self.open_sdd_plan_options();
+            return;
+        }
+
+        match &self.sdd_state {
+            Some(SddDevelopState {
+                stage: SddDevelopStage::AwaitPlanDecision,
+                ..
+            }) => {
+                self.add_info_message(
+                    tr(language, "chatwidget.sdd.plan_stage").to_string(),
+                    Some(tr(language, "chatwidget.sdd.use_popup_hint").to_string()),
+                );
+                self.open_sdd_plan_options();
+            }
+            Some(SddDevelopState {
+                stage: SddDevelopStage::AwaitDevDecision,
+                ..
+            }) => {
+                self.add_info_message(
+                    tr(language, "chatwidget.sdd.dev_stage").to_string(),
+                    Some(tr(language, "chatwidget.sdd.use_popup_hint").to_string()),
+                );
+                self.open_sdd_dev_options();
+            }
+            None => {
+                let require_description = if workflow == SddWorkflow::Parallels {
+                    tr(language, "chatwidget.sdd.require_description_parallels")
+                } else {
+                    tr(language, "chatwidget.sdd.require_description")
+                };
+                self.add_info_message(require_description.to_string(), None);
+            }
+        }
+    }
+
+    pub(crate) fn open_sdd_plan_options(&mut self) {
+        let language = self.config.language;
+        if !matches!(
+            self.sdd_state,
+            Some(SddDevelopState {
+                stage: SddDevelopStage::AwaitPlanDecision,
+                ..
+            })
+        ) {
+            self.add_info_message(
+                tr(language, "chatwidget.sdd.no_plan_pending").to_string(),
+                None,
+            );
+            return;
+        }
+        let items = vec![
+            SelectionItem {
+                name: tr(language, "chatwidget.sdd.option.approve_plan").to_string(),
+                description: Some(
+                    tr(language, "chatwidget.sdd.option.approve_plan_desc").to_string(),
+                ),
+                actions: vec![Box::new(|tx| tx.send(AppEvent::SddPlanApproved))],
+                dismiss_on_select: true,
+                ..Default::default()
+            },
+            SelectionItem {
+                name: tr(language, "chatwidget.sdd.option.request_changes").to_string(),
+                description: Some(
+                    tr(language, "chatwidget.sdd.option.request_changes_desc").to_string(),
+                ),
+                actions: vec![Box::new(|tx| tx.send(AppEvent::SddPlanRework))],
+                dismiss_on_select: true,
+                ..Default::default()
+            },
+        ];
+        self.bottom_pane.show_selection_view(SelectionViewParams {
+            title: Some(tr(language, "chatwidget.sdd.plan_options.title").to_string()),
+            footer_hint: Some(standard_popup_hint_line(self.config.language)),
+            items,
+            header: Box::new(()),
+            ..Default::default()
+        });
+        self.request_redraw();
+    }
+
+    pub(crate) fn open_sdd_dev_options(&mut self) {
+        let language = self.config.language;
+        if !matches!(
+            self.sdd_state,
+            Some(SddDevelopState {
+                stage: SddDevelopStage::AwaitDevDecision,
+                ..
+            })
+        ) {
+            self.add_info_message(
+                tr(language, "chatwidget.sdd.no_dev_branch").to_string(),
+                None,
+            );
+            return;
+        }
+        let items = vec![
+            SelectionItem {
+                name: tr(language, "chatwidget.sdd.option.merge_pr").to_string(),
+                description: Some(tr(language, "chatwidget.sdd.option.merge_pr_desc").to_string()),
+                actions: vec![Box::new(|tx| tx.send(AppEvent::SddDevMergeBranch))],
+                dismiss_on_select: true,
+                ..Default::default()
+            },
+            SelectionItem {
+                name: tr(language, "chatwidget.sdd.option.continue_changes").to_string(),
+                description: Some(
+                    tr(language, "chatwidget.sdd.option.continue_changes_desc").to_string(),
+                ),
+                actions: vec![Box::new(|tx| tx.send(AppEvent::SddDevRequestMoreChanges))],
+                dismiss_on_select: true,
+                ..Default::default()
+            },
+            SelectionItem {
+                name: tr(language, "chatwidget.sdd.option.abandon").to_string(),
+                description: Some(tr(language, "chatwidget.sdd.option.abandon_desc").to_string()),
+                actions: vec![Box::new(|tx| tx.send(AppEvent::SddDevAbandonBranch))],
+                dismiss_on_select: true,
+                ..Default::default()
+            },
+        ];
+        self.bottom_pane.show_selection_view(SelectionViewParams {
+            title: Some(tr(language, "chatwidget.sdd.dev_options.title").to_string()),
+            footer_hint: Some(standard_popup_hint_line(self.config.language)),
+            items,
+            header: Box::new(()),
+            ..Default::default()
+        });
+        self.request_redraw();
+    }
+
+    pub(crate) async fn on_sdd_plan_approved(&mut self) {
+        let language = self.config.language;
+        let (description, workflow) = match self.sdd_state.as_ref() {
+            Some(state) if state.stage == SddDevelopStage::AwaitPlanDecision => {
+                (state.description.clone(), state.workflow)
+            }
+            Some(_) => {
+                self.add_info_message(
+                    tr(language, "chatwidget.sdd.plan_stage_required").to_string(),
+                    None,
+                );
+                return;
+            }
+            None => {
+                self.add_info_message(
+                    tr(language, "chatwidget.sdd.no_active_plan").to_string(),
+                    None,
+                );
+                return;
+            }
+        };
+
+        self.sdd_pending_plan_rework_prompt = None;
+        self.sdd_open_plan_options_after_task = false;
+
+        if workflow == SddWorkflow::Parallels {
+            if let Some(base_branch) = current_branch_name(&self.config.cwd).await
+                && let Some(state) = self.sdd_state.as_mut()
+            {
+                state.base_branch = Some(base_branch);
+            }
+            if let Some(state) = self.sdd_state.as_mut() {
+                state.stage = SddDevelopStage::AwaitDevDecision;
+            }
+            let prompt = self.build_sdd_exec_prompt(&description, workflow);
+            self.submit_user_message(prompt.into());
+            self.add_info_message(
+                tr(language, "chatwidget.sdd.exec_sent").to_string(),
+                Some(tr(language, "chatwidget.sdd.exec_sent_hint_parallels").to_string()),
+            );
+            self.open_sdd_dev_options();
+            return;
+        }
+
+        let branch_name = match self.sdd_state.as_ref() {
+            Some(state) => state.branch_name.clone(),
+            None => {
+                self.add_error_message(tr(language, "chatwidget.sdd.branch_unknown").to_string());
+                return;
+            }
+        };
+        let base_branch = match current_branch_name(&self.config.cwd).await {
+            Some(branch) => branch,
+            None => {
+                self.add_error_message(
+                    tr(language, "chatwidget.sdd.base_branch_unknown").to_string(),
+                );
+                return;
+            }
+        };
+        if let Some(state) = self.sdd_state.as_mut() {
+            state.base_branch = Some(base_branch.clone());
+        }
+
+        self.sdd_pending_git_action = Some(SddGitPendingAction::CreateBranch { description });
+        self.sdd_git_action_failed = false;
+        self.submit_op(Op::SddGitAction {
+            action: SddGitAction::CreateBranch {
+                name: branch_name,
+                base: base_branch,
+            },
+        });
+        self.add_info_message(
+            tr(language, "chatwidget.sdd.branch_create_started").to_string(),
+            None,
+        );
+    }
+
+    pub(crate) fn on_sdd_plan_rework(&mut self) {
+        let language = self.config.language;
+        let Some(state) = self.sdd_state.as_ref() else {
+            self.add_info_message(
+                tr(language, "chatwidget.sdd.no_active_plan_rework").to_string(),
+                None,
+            );
+            return;
+        };
+        if state.stage != SddDevelopStage::AwaitPlanDecision {
+            self.add_info_message(
+                tr(language, "chatwidget.sdd.not_in_plan_stage").to_string(),
+                None,
+            );
+            return;
+        }
+        self.sdd_pending_git_action = None;
+        self.sdd_git_action_failed = false;
+        let prompt = self.build_sdd_plan_rework_prompt(&state.description);
+        self.sdd_pending_plan_rework_prompt = Some(prompt);
+        self.set_composer_text(String::new(), Vec::new(), Vec::new());
+        self.add_info_message(
+            tr(language, "chatwidget.sdd.plan_rework_ready").to_string(),
+            Some(tr(language, "chatwidget.sdd.plan_rework_hint").to_string()),
+        );
+        self.request_redraw();
+    }
+
+    pub(crate) fn on_sdd_request_more_changes(&mut self) {
+        let language = self.config.language;
+        let Some(state) = self.sdd_state.as_ref() else {
+            self.add_info_message(
+                tr(language, "chatwidget.sdd.no_active_branch_changes").to_string(),
+                None,
+            );
+            return;
+        };
+        if state.stage != SddDevelopStage::AwaitDevDecision {
+            self.add_info_message(
+                tr(language, "chatwidget.sdd.not_in_dev_stage").to_string(),
+                None,
+            );
+            return;
+        }
+        let prefill = format!(
+            "{}\n{}\n\n{}\n",
+            tr(language, "chatwidget.sdd.continue_prompt_intro"),
+            state.description,
+            tr(language, "chatwidget.sdd.continue_prompt_details")
+        );
+        self.set_composer_text(prefill, Vec::new(), Vec::new());
+        self.add_info_message(
+            tr(language, "chatwidget.sdd.continue_prompt_ready").to_string(),
+            Some(tr(language, "chatwidget.sdd.continue_prompt_hint").to_string()),
+        );
+        self.request_redraw();
+    }
+
+    pub(crate) fn on_sdd_merge_branch(&mut self) {
+        let language = self.config.language;
+        let Some(state) = self.sdd_state.take() else {
+            self.add_info_message(
+                tr(language, "chatwidget.sdd.no_active_branch_merge").to_string(),
+                None,
+            );
+            return;
+        };
+        if state.stage != SddDevelopStage::AwaitDevDecision {
+            self.sdd_state = Some(state);
+            self.add_info_message(
+                tr(language, "chatwidget.sdd.not_in_dev_stage_merge").to_string(),
+                None,
+            );
+            return;
+        }
+        if state.workflow == SddWorkflow::Parallels {
+            let prompt =
+                self.build_sdd_merge_prompt(&state.description, &state.branch_name, state.workflow);
+            self.sdd_pending_git_action = None;
+            self.sdd_git_action_failed = false;
+            self.submit_user_message(prompt.into());
+            self.add_info_message(
+                tr(language, "chatwidget.sdd.merge_guidance_sent").to_string(),
+                Some(tr(language, "chatwidget.sdd.merge_guidance_hint_parallels").to_string()),
+            );
+            return;
+        }
+        let commit_message = self.sdd_commit_message(&state.description);
+        let branch_name = state.branch_name.clone();
+        let base_branch = match state.base_branch.clone() {
+            Some(base_branch) => base_branch,
+            None => {
+                self.sdd_state = Some(state);
+                self.add_error_message(
+                    tr(language, "chatwidget.sdd.base_branch_unknown").to_string(),
+                );
+                return;
+            }
+        };
+        self.sdd_state = Some(state);
+        self.sdd_pending_git_action = Some(SddGitPendingAction::FinalizeMerge);
+        self.sdd_git_action_failed = false;
+        self.submit_op(Op::SddGitAction {
+            action: SddGitAction::FinalizeMerge {
+                name: branch_name,
+                base: base_branch,
+                commit_message,
+            },
+        });
+        self.add_info_message(
+            tr(language, "chatwidget.sdd.merge_started").to_string(),
+            None,
+        );
+    }
+
+    pub(crate) fn on_sdd_abandon_branch(&mut self) {
+        let language = self.config.language;
+        let Some(state) = self.sdd_state.take() else {
+            self.add_info_message(
+                tr(language, "chatwidget.sdd.no_active_branch_abandon").to_string(),
+                None,
+            );
+            return;
+        };
+        if state.stage != SddDevelopStage::AwaitDevDecision {
+            self.sdd_state = Some(state);
+            self.add_info_message(
+                tr(language, "chatwidget.sdd.not_in_dev_stage_abandon").to_string(),
+                None,
+            );
+            return;
+        }
+        let branch_name = state.branch_name.clone();
+        let base_branch = match state.base_branch.clone() {
+            Some(base_branch) => base_branch,
+            None => {
+                self.sdd_state = Some(state);
+                self.add_error_message(
+                    tr(language, "chatwidget.sdd.base_branch_unknown").to_string(),
+                );
+                return;
+            }
+        };
+        self.sdd_state = Some(state);
+        self.sdd_pending_git_action = Some(SddGitPendingAction::AbandonBranch);
+        self.sdd_git_action_failed = false;
+        self.submit_op(Op::SddGitAction {
+            action: SddGitAction::AbandonBranch {
+                name: branch_name,
+                base: base_branch,
+            },
+        });
+        self.add_info_message(
+            tr(language, "chatwidget.sdd.branch_delete_started").to_string(),
+            None,
+        );
+    }
+
+    fn build_sdd_plan_prompt(&self, description: &str, workflow: SddWorkflow) -> String {
+        let template = match workflow {
+            SddWorkflow::Standard => sdd_plan_prompt_template(self.config.language),
+            SddWorkflow::Parallels => sdd_plan_parallels_prompt_template(self.config.language),
+        }
+        .trim();
+        let description_block = if workflow == SddWorkflow::Parallels {
+            match self.sdd_state.as_ref() {
+                Some(state) => format!(
+                    "{}\n{description}\n{}\n{}",
+                    tr(self.config.language, "chatwidget.sdd.requirement_label"),
+                    tr(self.config.language, "chatwidget.sdd.branch_label"),
+                    state.branch_name
+                ),
+                None => format!(
+                    "{}\n{description}",
+                    tr(self.config.language, "chatwidget.sdd.requirement_label")
+                ),
+            }
+        } else {
+            format!(
+                "{}\n{description}",
+                tr(self.config.language, "chatwidget.sdd.requirement_label")
+            )
+        };
+        if template.is_empty() {
+            description_block
+        } else {
+            format!("{template}\n\n{description_block}")
+        }
+    }
+
+    fn sdd_branch_name(&self, description: &str) -> String {
+        let slug = Self::sdd_slug(description);
+        format!("{SDD_BRANCH_PREFIX}{slug}")
+    }
+
+    fn sdd_commit_message(&self, description: &str) -> String {
+        let slug = Self::sdd_slug(description);
+        format!("sdd: {slug}")
+    }
+
+    fn sdd_slug(description: &str) -> String {
+        let mut slug = String::new();
+        let mut prev_dash = false;
+        for ch in description.chars() {
+            if ch.is_ascii_alphanumeric() {
+                slug.push(ch.to_ascii_lowercase());
+                prev_dash = false;
+            } else if !prev_dash {
+                slug.push('-');
+                prev_dash = true;
+            }
+            if slug.len() >= 32 {
+                break;
+            }
+        }
+        let slug = slug.trim_matches('-').to_string();
+        if slug.is_empty() {
+            "task".to_string()
+        } else {
+            slug
+        }
+    }
+
+    fn build_sdd_plan_rework_prompt(&self, _description: &str) -> String {
+        tr(self.config.language, "chatwidget.sdd.plan_rework_prompt").to_string()
+    }
+
+    fn build_sdd_exec_prompt(&self, description: &str, workflow: SddWorkflow) -> String {
+        let template = match workflow {
+            SddWorkflow::Standard => sdd_exec_prompt_template(self.config.language),
+            SddWorkflow::Parallels => sdd_exec_parallels_prompt_template(self.config.language),
+        }
+        .trim();
+        let description_block = format!(
+            "{}\n{description}",
+            tr(self.config.language, "chatwidget.sdd.requirement_label")
+        );
+        if template.is_empty() {
+            description_block
+        } else {
+            format!("{template}\n\n{description_block}")
+        }
+    }
+
+    fn build_sdd_merge_prompt(
+        &self,
+        description: &str,
+        branch_name: &str,
+        workflow: SddWorkflow,
+    ) -> String {
+        let template = match workflow {
+            SddWorkflow::Standard => sdd_merge_prompt_template(self.config.language),
+            SddWorkflow::Parallels => sdd_merge_parallels_prompt_template(self.config.language),
+        }
+        .trim();
+        let context_block = format!(
+            "{}\n{description}\n{}\n{branch_name}",
+            tr(self.config.language, "chatwidget.sdd.requirement_label"),
+            tr(self.config.language, "chatwidget.sdd.branch_label")
+        );
+        if template.is_empty() {
+            context_block
+        } else {
+            format!("{template}\n\n{context_block}")
+        }
+    }
+
     fn show_rename_prompt(&mut self) {
         let tx = self.app_event_tx.clone();
         let has_name = self
@@ -3691,7 +4490,10 @@ impl ChatWidget {
             text_elements,
             mention_bindings,
         } = user_message;
-        if text.is_empty() && local_images.is_empty() {
+        if text.is_empty()
+            && local_images.is_empty()
+            && self.sdd_pending_plan_rework_prompt.is_none()
+        {
             return;
         }
         if !local_images.is_empty() && !self.current_model_supports_images() {
@@ -3730,6 +4532,15 @@ impl ChatWidget {
             });
         }
 
+        if let Some(prefix) = self.sdd_pending_plan_rework_prompt.take() {
+            // The rework instructions travel with the message but stay out of the transcript.
+            self.sdd_open_plan_options_after_task = true;
+            items.push(UserInput::Text {
+                text: prefix,
+                text_elements: Vec::new(),
+            });
+        }
+
         if !text.is_empty() {
             items.push(UserInput::Text {
                 text: text.clone(),
@@ -4028,6 +4839,12 @@ impl ChatWidget {
             EventMsg::ElicitationRequest(ev) => {
                 self.on_elicitation_request(ev);
             }
+            EventMsg::SamplingRequest(ev) => {
+                self.on_sampling_request(ev);
+            }
+            EventMsg::NetworkApprovalRequest(ev) => {
+                self.on_network_approval_request(ev);
+            }
             EventMsg::RequestUserInput(ev) => {
                 self.on_request_user_input(ev);
             }
@@ -4045,6 +4862,8 @@ impl ChatWidget {
             EventMsg::GetHistoryEntryResponse(ev) => self.on_get_history_entry_response(ev),
             EventMsg::McpListToolsResponse(ev) => self.on_list_mcp_tools(ev),
             EventMsg::ListCustomPromptsResponse(ev) => self.on_list_custom_prompts(ev),
+            EventMsg::ListMcpPromptsResponse(ev) => self.on_list_mcp_prompts(ev),
+            EventMsg::GetMcpPromptResponse(ev) => self.on_get_mcp_prompt_response(ev),
             EventMsg::ListSkillsResponse(ev) => self.on_list_skills(ev),
             EventMsg::ListRemoteSkillsResponse(_) | EventMsg::RemoteSkillDownloaded(_) => {}
             EventMsg::SkillsUpdateAvailable => {
@@ -4061,6 +4880,7 @@ impl ChatWidget {
             }
             EventMsg::UndoStarted(ev) => self.on_undo_started(ev),
             EventMsg::UndoCompleted(ev) => self.on_undo_completed(ev),
+            EventMsg::CheckpointCreated(ev) => self.on_checkpoint_created(ev),
             EventMsg::StreamError(StreamErrorEvent {
                 message,
                 additional_details,
@@ -4100,6 +4920,7 @@ impl ChatWidget {
             | EventMsg::AgentMessageContentDelta(_)
             | EventMsg::ReasoningContentDelta(_)
             | EventMsg::ReasoningRawContentDelta(_)
+            | EventMsg::McpToolCallApproval(_)
             | EventMsg::DynamicToolCallRequest(_) => {}
             EventMsg::ItemCompleted(event) => {
                 let item = event.item;
@@ -4780,6 +5601,102 @@ impl ChatWidget {
         self.open_model_popup_with_presets(presets);
     }
 
+    pub(crate) fn open_language_popup(&mut self) {
+        let ui_language = self.config.language;
+        let items = available_languages()
+            .into_iter()
+            .map(|language| {
+                let description = match language {
+                    Language::En => {
+                        Some(tr(ui_language, "chatwidget.language_popup.english_desc").to_string())
+                    }
+                    Language::ZhCn => {
+                        Some(tr(ui_language, "chatwidget.language_popup.chinese_desc").to_string())
+                    }
+                    Language::Locale(tag) => Some(tag.as_str().to_string()),
+                };
+                SelectionItem {
+                    name: language_name(ui_language, language).to_string(),
+                    description,
+                    is_current: ui_language == language,
+                    actions: Self::language_selection_actions(language),
+                    dismiss_on_select: true,
+                    ..Default::default()
+                }
+            })
+            .collect();
+
+        self.bottom_pane.show_selection_view(SelectionViewParams {
+            title: Some(tr(ui_language, "chatwidget.language_popup.title").to_string()),
+            footer_hint: Some(standard_popup_hint_line(ui_language)),
+            items,
+            header: Box::new(()),
+            ..Default::default()
+        });
+    }
+
+    pub(crate) fn open_spec_popup(&mut self) {
+        let language = self.config.language;
+        let current = self.config.spec.parallel_priority;
+        let items = vec![
+            SelectionItem {
+                name: tr(language, "chatwidget.spec_popup.parallel_priority_on").to_string(),
+                description: Some(
+                    tr(language, "chatwidget.spec_popup.parallel_priority_on_desc").to_string(),
+                ),
+                is_current: current,
+                actions: Self::spec_parallel_priority_selection_actions(true),
+                dismiss_on_select: true,
+                ..Default::default()
+            },
+            SelectionItem {
+                name: tr(language, "chatwidget.spec_popup.parallel_priority_off").to_string(),
+                description: Some(
+                    tr(language, "chatwidget.spec_popup.parallel_priority_off_desc").to_string(),
+                ),
+                is_current: !current,
+                actions: Self::spec_parallel_priority_selection_actions(false),
+                dismiss_on_select: true,
+                ..Default::default()
+            },
+        ];
+
+        self.bottom_pane.show_selection_view(SelectionViewParams {
+            title: Some(tr(language, "chatwidget.spec_popup.title").to_string()),
+            subtitle: Some(tr(language, "chatwidget.spec_popup.subtitle").to_string()),
+            footer_hint: Some(standard_popup_hint_line(language)),
+            items,
+            header: Box::new(()),
+            ..Default::default()
+        });
+    }
+
+    fn language_selection_actions(language: Language) -> Vec<SelectionAction> {
+        vec![Box::new(move |tx| {
+            tx.send(AppEvent::UpdateLanguage(language));
+            tx.send(AppEvent::PersistLanguageSelection { language });
+        })]
+    }
+
+    fn spec_parallel_priority_selection_actions(enabled: bool) -> Vec<SelectionAction> {
+        vec![Box::new(move |tx| {
+            tx.send(AppEvent::CodexOp(Op::OverrideTurnContext {
+                cwd: None,
+                approval_policy: None,
+                sandbox_policy: None,
+                windows_sandbox_level: None,
+                model: None,
+                effort: None,
+                summary: None,
+                collaboration_mode: None,
+                personality: None,
+                spec_parallel_priority: Some(enabled),
+            }));
+            tx.send(AppEvent::UpdateSpecParallelPriority(enabled));
+            tx.send(AppEvent::PersistSpecParallelPriority { enabled });
+        })]
+    }
+
     pub(crate) fn open_personality_popup(&mut self) {
         if !self.is_session_configured() {
             self.add_info_message(
@@ -6796,6 +7713,37 @@ impl ChatWidget {
         self.bottom_pane.set_custom_prompts(ev.custom_prompts);
     }
 
+    fn on_list_mcp_prompts(&mut self, ev: ListMcpPromptsResponseEvent) {
+        let commands = mcp_prompt_commands(ev.prompts);
+        debug!("received {} MCP prompts", commands.len());
+        self.bottom_pane.set_mcp_prompts(commands);
+    }
+
+    /// Expand the fetched MCP prompt into the user turn, queueing it behind a
+    /// running task like any other submission.
+    fn on_get_mcp_prompt_response(&mut self, ev: GetMcpPromptResponseEvent) {
+        let GetMcpPromptResponseEvent {
+            server,
+            name,
+            result,
+        } = ev;
+        let text = match result {
+            Ok(result) => mcp_prompt_text(&result),
+            Err(err) => {
+                self.add_to_history(history_cell::new_error_event(format!(
+                    "Failed to get MCP prompt /{server}:{name}: {err}"
+                )));
+                self.request_redraw();
+                return;
+            }
+        };
+        if text.is_empty() {
+            self.on_warning(format!("MCP prompt /{server}:{name} returned no text"));
+            return;
+        }
+        self.queue_user_message(text.into());
+    }
+
     fn on_list_skills(&mut self, ev: ListSkillsResponseEvent) {
         self.set_skills_from_response(&ev);
     }
@@ -7104,6 +8052,8 @@ enum Notification {
     ExecApprovalRequested { command: String },
     EditApprovalRequested { cwd: PathBuf, changes: Vec<PathBuf> },
     ElicitationRequested { server_name: String },
+    SamplingRequested { server_name: String },
+    NetworkApprovalRequested { host: String },
 }
 
 impl Notification {
@@ -7130,6 +8080,12 @@ impl Notification {
             Notification::ElicitationRequested { server_name } => {
                 format!("Approval requested by {server_name}")
             }
+            Notification::SamplingRequested { server_name } => {
+                format!("Model request from {server_name}")
+            }
+            Notification::NetworkApprovalRequested { host } => {
+                format!("Network access requested: {}", truncate_text(host, 30))
+            }
         }
     }
 
@@ -7138,7 +8094,9 @@ impl Notification {
             Notification::AgentTurnComplete { .. } => "agent-turn-complete",
             Notification::ExecApprovalRequested { .. }
             | Notification::EditApprovalRequested { .. }
-            | Notification::ElicitationRequested { .. } => "approval-requested",
+            | Notification::ElicitationRequested { .. }
+            | Notification::SamplingRequested { .. }
+            | Notification::NetworkApprovalRequested { .. } => "approval-requested",
         }
     }
 
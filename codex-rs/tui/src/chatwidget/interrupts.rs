@@ -8,6 +8,8 @@ use codex_core::protocol::McpToolCallBeginEvent;
 use codex_core::protocol::McpToolCallEndEvent;
 use codex_core::protocol::PatchApplyEndEvent;
 use codex_protocol::approvals::ElicitationRequestEvent;
+use codex_protocol::approvals::NetworkApprovalRequestEvent;
+use codex_protocol::approvals::SamplingRequestEvent;
 use codex_protocol::request_user_input::RequestUserInputEvent;
 
 use super::ChatWidget;
@@ -17,6 +19,8 @@ pub(crate) enum QueuedInterrupt {
     ExecApproval(ExecApprovalRequestEvent),
     ApplyPatchApproval(ApplyPatchApprovalRequestEvent),
     Elicitation(ElicitationRequestEvent),
+    Sampling(SamplingRequestEvent),
+    NetworkApproval(NetworkApprovalRequestEvent),
     RequestUserInput(RequestUserInputEvent),
     ExecBegin(ExecCommandBeginEvent),
     ExecEnd(ExecCommandEndEvent),
@@ -55,6 +59,14 @@ impl InterruptManager {
         self.queue.push_back(QueuedInterrupt::Elicitation(ev));
     }
 
+    pub(crate) fn push_sampling(&mut self, ev: SamplingRequestEvent) {
+        self.queue.push_back(QueuedInterrupt::Sampling(ev));
+    }
+
+    pub(crate) fn push_network_approval(&mut self, ev: NetworkApprovalRequestEvent) {
+        self.queue.push_back(QueuedInterrupt::NetworkApproval(ev));
+    }
+
     pub(crate) fn push_user_input(&mut self, ev: RequestUserInputEvent) {
         self.queue.push_back(QueuedInterrupt::RequestUserInput(ev));
     }
@@ -85,6 +97,8 @@ impl InterruptManager {
                 QueuedInterrupt::ExecApproval(ev) => chat.handle_exec_approval_now(ev),
                 QueuedInterrupt::ApplyPatchApproval(ev) => chat.handle_apply_patch_approval_now(ev),
                 QueuedInterrupt::Elicitation(ev) => chat.handle_elicitation_request_now(ev),
+                QueuedInterrupt::Sampling(ev) => chat.handle_sampling_request_now(ev),
+                QueuedInterrupt::NetworkApproval(ev) => chat.handle_network_approval_now(ev),
                 QueuedInterrupt::RequestUserInput(ev) => chat.handle_request_user_input_now(ev),
                 QueuedInterrupt::ExecBegin(ev) => chat.handle_exec_begin_now(ev),
                 QueuedInterrupt::ExecEnd(ev) => chat.handle_exec_end_now(ev),
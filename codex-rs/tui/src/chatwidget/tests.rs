@@ -1121,6 +1121,12 @@ async fn make_chatwidget_manual(
         status_line_branch_pending: false,
         status_line_branch_lookup_complete: false,
         external_editor_state: ExternalEditorState::Closed,
+        sdd_state: None,
+        sdd_pending_plan_rework_prompt: None,
+        sdd_open_plan_options_after_task: false,
+        sdd_pending_git_action: None,
+        sdd_git_action_failed: false,
+        sdd_new_session_after_cleanup: false,
     };
     widget.set_model(&resolved_model);
     (widget, rx, op_rx)
@@ -1139,6 +1145,25 @@ fn next_submit_op(op_rx: &mut tokio::sync::mpsc::UnboundedReceiver<Op>) -> Op {
     }
 }
 
+fn drain_ops(op_rx: &mut tokio::sync::mpsc::UnboundedReceiver<Op>) -> Vec<Op> {
+    let mut ops = Vec::new();
+    while let Ok(op) = op_rx.try_recv() {
+        ops.push(op);
+    }
+    ops
+}
+
+fn find_text_input(op: &Op) -> Option<&str> {
+    if let Op::UserTurn { items, .. } = op {
+        items.iter().find_map(|item| match item {
+            UserInput::Text { text, .. } => Some(text.as_str()),
+            _ => None,
+        })
+    } else {
+        None
+    }
+}
+
 fn set_chatgpt_auth(chat: &mut ChatWidget) {
     chat.auth_manager =
         AuthManager::from_auth_for_testing(CodexAuth::create_dummy_chatgpt_auth_for_testing());
@@ -3281,6 +3306,15 @@ async fn slash_fork_requests_current_fork() {
     assert_matches!(rx.try_recv(), Ok(AppEvent::ForkCurrentSession));
 }
 
+#[tokio::test]
+async fn slash_checkpoint_submits_checkpoint_op() {
+    let (mut chat, _rx, mut op_rx) = make_chatwidget_manual(None).await;
+
+    chat.dispatch_command(SlashCommand::Checkpoint);
+
+    assert_matches!(op_rx.try_recv(), Ok(Op::Checkpoint { name: None }));
+}
+
 #[tokio::test]
 async fn slash_rollout_displays_current_path() {
     let (mut chat, mut rx, _op_rx) = make_chatwidget_manual(None).await;
@@ -3927,6 +3961,279 @@ async fn model_selection_popup_snapshot() {
     assert_snapshot!("model_selection_popup", popup);
 }
 
+#[tokio::test]
+async fn language_selection_popup_snapshot() {
+    let (mut chat, _rx, _op_rx) = make_chatwidget_manual(None).await;
+    chat.dispatch_command(SlashCommand::Lang);
+
+    let popup = render_bottom_popup(&chat, 80);
+    assert_snapshot!("language_selection_popup", popup);
+}
+
+#[tokio::test]
+async fn spec_popup_snapshot() {
+    let (mut chat, _rx, _op_rx) = make_chatwidget_manual(None).await;
+    chat.config.spec.parallel_priority = false;
+    chat.dispatch_command(SlashCommand::Spec);
+
+    let popup = render_bottom_popup(&chat, 80);
+    assert_snapshot!("spec_popup", popup);
+}
+
+#[tokio::test]
+async fn spec_popup_selection_overrides_turn_context() {
+    let (mut chat, mut rx, _op_rx) = make_chatwidget_manual(None).await;
+    chat.config.spec.parallel_priority = false;
+    chat.dispatch_command(SlashCommand::Spec);
+
+    let popup = render_bottom_popup(&chat, 80);
+    assert!(
+        popup.contains(tr(chat.config.language, "chatwidget.spec_popup.title")),
+        "expected spec popup title: {popup}"
+    );
+
+    chat.handle_key_event(KeyEvent::from(KeyCode::Char('1')));
+
+    assert_matches!(
+        rx.try_recv(),
+        Ok(AppEvent::CodexOp(Op::OverrideTurnContext {
+            spec_parallel_priority: Some(true),
+            ..
+        }))
+    );
+    assert_matches!(
+        rx.try_recv(),
+        Ok(AppEvent::UpdateSpecParallelPriority(true))
+    );
+    assert_matches!(
+        rx.try_recv(),
+        Ok(AppEvent::PersistSpecParallelPriority { enabled: true })
+    );
+}
+
+fn sdd_state_for_test(stage: SddDevelopStage) -> SddDevelopState {
+    SddDevelopState {
+        workflow: SddWorkflow::Standard,
+        description: "add login page".to_string(),
+        branch_name: "sdd/add-login-page".to_string(),
+        base_branch: Some("main".to_string()),
+        stage,
+    }
+}
+
+#[tokio::test]
+async fn sdd_plan_options_popup_snapshot() {
+    let (mut chat, _rx, _op_rx) = make_chatwidget_manual(None).await;
+    chat.sdd_state = Some(sdd_state_for_test(SddDevelopStage::AwaitPlanDecision));
+    chat.open_sdd_plan_options();
+
+    let popup = render_bottom_popup(&chat, 80);
+    assert_snapshot!("sdd_plan_options_popup", popup);
+}
+
+#[tokio::test]
+async fn sdd_dev_options_popup_snapshot() {
+    let (mut chat, _rx, _op_rx) = make_chatwidget_manual(None).await;
+    chat.sdd_state = Some(sdd_state_for_test(SddDevelopStage::AwaitDevDecision));
+    chat.open_sdd_dev_options();
+
+    let popup = render_bottom_popup(&chat, 80);
+    assert_snapshot!("sdd_dev_options_popup", popup);
+}
+
+#[tokio::test]
+async fn sdd_merge_branch_finalizes_and_clears_state() {
+    let (mut chat, _rx, mut op_rx) = make_chatwidget_manual(None).await;
+    chat.sdd_state = Some(sdd_state_for_test(SddDevelopStage::AwaitDevDecision));
+
+    chat.on_sdd_merge_branch();
+    let ops = drain_ops(&mut op_rx);
+    let Some(Op::SddGitAction {
+        action: SddGitAction::FinalizeMerge { name, base, .. },
+    }) = ops.first()
+    else {
+        panic!("merge should finalize the SDD branch, got {ops:?}");
+    };
+    assert_eq!(
+        (name.as_str(), base.as_str()),
+        ("sdd/add-login-page", "main")
+    );
+
+    chat.handle_codex_event(Event {
+        id: "sdd-git".into(),
+        msg: EventMsg::TurnComplete(TurnCompleteEvent {
+            last_agent_message: None,
+        }),
+    });
+    assert!(chat.sdd_state.is_none(), "merge should end the SDD flow");
+}
+
+#[tokio::test]
+async fn sdd_abandon_failure_keeps_branch_state() {
+    let (mut chat, mut rx, mut op_rx) = make_chatwidget_manual(None).await;
+    chat.sdd_state = Some(sdd_state_for_test(SddDevelopStage::AwaitDevDecision));
+
+    chat.on_sdd_abandon_branch();
+    assert_matches!(
+        drain_ops(&mut op_rx).as_slice(),
+        [Op::SddGitAction {
+            action: SddGitAction::AbandonBranch { .. },
+        }]
+    );
+
+    chat.handle_codex_event(Event {
+        id: "sdd-git".into(),
+        msg: EventMsg::Error(ErrorEvent {
+            message: "branch is checked out".to_string(),
+            codex_error_info: None,
+        }),
+    });
+    chat.handle_codex_event(Event {
+        id: "sdd-git".into(),
+        msg: EventMsg::TurnComplete(TurnCompleteEvent {
+            last_agent_message: None,
+        }),
+    });
+
+    let rendered = drain_insert_history(&mut rx)
+        .iter()
+        .map(|lines| lines_to_single_string(lines))
+        .collect::<Vec<_>>()
+        .join("\n");
+    assert!(
+        rendered.contains(tr(chat.config.language, "chatwidget.sdd.git_failed")),
+        "expected git failure message: {rendered}"
+    );
+    assert!(
+        chat.sdd_state.is_some(),
+        "failed abandon should keep the branch"
+    );
+}
+
+#[tokio::test]
+async fn slash_sdd_develop_parallels_requires_collab_feature() {
+    let (mut chat, mut rx, mut op_rx) = make_chatwidget_manual(None).await;
+    chat.thread_id = Some(ThreadId::new());
+    chat.config.features.disable(Feature::Collab);
+    chat.bottom_pane.set_composer_text(
+        "/sdd-develop-parallels implement parallels workflow".to_string(),
+        Vec::new(),
+        Vec::new(),
+    );
+    chat.handle_key_event(KeyEvent::from(KeyCode::Enter));
+
+    let rendered = drain_insert_history(&mut rx)
+        .iter()
+        .map(|lines| lines_to_single_string(lines))
+        .collect::<Vec<_>>()
+        .join("\n");
+    assert!(
+        rendered.contains(tr(chat.config.language, "chatwidget.sdd.collab_required")),
+        "expected collab-required message: {rendered}"
+    );
+    assert!(
+        chat.sdd_state.is_none(),
+        "workflow should not start when blocked"
+    );
+    assert!(
+        drain_ops(&mut op_rx)
+            .iter()
+            .all(|op| find_text_input(op).is_none()),
+        "blocked command should not send a prompt"
+    );
+}
+
+#[tokio::test]
+async fn sdd_develop_parallels_plan_approval_sends_execute_prompt_without_create_branch() {
+    let (mut chat, _rx, mut op_rx) = make_chatwidget_manual(None).await;
+    chat.thread_id = Some(ThreadId::new());
+    chat.config.features.enable(Feature::Collab);
+    chat.bottom_pane.set_composer_text(
+        "/sdd-develop-parallels implement parallels workflow".to_string(),
+        Vec::new(),
+        Vec::new(),
+    );
+    chat.handle_key_event(KeyEvent::from(KeyCode::Enter));
+
+    let plan_prompt = drain_ops(&mut op_rx)
+        .iter()
+        .find_map(find_text_input)
+        .map(str::to_string)
+        .expect("plan request should emit user input");
+    let plan_prefix = tr(chat.config.language, "prompt.sdd_plan_parallels")
+        .lines()
+        .next()
+        .expect("plan template should have first line");
+    assert!(
+        plan_prompt.contains(plan_prefix),
+        "expected parallels plan prompt, got: {plan_prompt}"
+    );
+
+    chat.on_sdd_plan_approved().await;
+    let approval_ops = drain_ops(&mut op_rx);
+    let exec_prompt = approval_ops
+        .iter()
+        .find_map(find_text_input)
+        .expect("plan approval should emit execute prompt");
+    let exec_prefix = tr(chat.config.language, "prompt.sdd_execute_parallels")
+        .lines()
+        .next()
+        .expect("execute template should have first line");
+    assert!(
+        exec_prompt.contains(exec_prefix),
+        "expected parallels execute prompt, got: {exec_prompt}"
+    );
+    assert!(
+        !approval_ops.iter().any(|op| matches!(
+            op,
+            Op::SddGitAction {
+                action: SddGitAction::CreateBranch { .. }
+            }
+        )),
+        "parallels approval should not trigger create-branch git action"
+    );
+}
+
+#[tokio::test]
+async fn sdd_develop_parallels_merge_sends_prompt_without_finalize_merge() {
+    let (mut chat, _rx, mut op_rx) = make_chatwidget_manual(None).await;
+    chat.thread_id = Some(ThreadId::new());
+    chat.config.features.enable(Feature::Collab);
+    chat.bottom_pane.set_composer_text(
+        "/sdd-develop-parallels implement parallels workflow".to_string(),
+        Vec::new(),
+        Vec::new(),
+    );
+    chat.handle_key_event(KeyEvent::from(KeyCode::Enter));
+    let _ = drain_ops(&mut op_rx);
+    chat.on_sdd_plan_approved().await;
+    let _ = drain_ops(&mut op_rx);
+
+    chat.on_sdd_merge_branch();
+    let merge_ops = drain_ops(&mut op_rx);
+    let merge_prompt = merge_ops
+        .iter()
+        .find_map(find_text_input)
+        .expect("merge should emit user input guidance");
+    let merge_prefix = tr(chat.config.language, "prompt.sdd_merge_parallels")
+        .lines()
+        .next()
+        .expect("merge template should have first line");
+    assert!(
+        merge_prompt.contains(merge_prefix),
+        "expected parallels merge prompt, got: {merge_prompt}"
+    );
+    assert!(
+        !merge_ops.iter().any(|op| matches!(
+            op,
+            Op::SddGitAction {
+                action: SddGitAction::FinalizeMerge { .. }
+            }
+        )),
+        "parallels merge should not trigger finalize-merge git action"
+    );
+}
+
 #[tokio::test]
 async fn personality_selection_popup_snapshot() {
     let (mut chat, _rx, _op_rx) = make_chatwidget_manual(Some("bengalfox")).await;
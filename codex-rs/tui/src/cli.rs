@@ -33,6 +33,10 @@ pub struct Cli {
     #[clap(skip)]
     pub resume_show_all: bool,
 
+    /// 内部用途：由 `codex resume --query` 设置的全文检索词，选择器只列出匹配的会话。
+    #[clap(skip)]
+    pub resume_query: Option<String>,
+
     // Internal controls set by the top-level `codex fork` subcommand.
     // These are not exposed as user flags on the base `codex` command.
     #[clap(skip)]
@@ -3,6 +3,7 @@ use codex_app_server_protocol::ConfigLayerSource;
 use codex_core::config::Config;
 use codex_core::config_loader::ConfigLayerStack;
 use codex_core::config_loader::ConfigLayerStackOrdering;
+use codex_core::config_loader::HostRuleAction;
 use codex_core::config_loader::NetworkConstraints;
 use codex_core::config_loader::RequirementSource;
 use codex_core::config_loader::ResidencyRequirement;
@@ -258,6 +259,7 @@ fn format_network_constraints(network: &NetworkConstraints) -> String {
         denied_domains,
         allow_unix_sockets,
         allow_local_binding,
+        host_rules,
     } = network;
 
     if let Some(enabled) = enabled {
@@ -297,6 +299,16 @@ fn format_network_constraints(network: &NetworkConstraints) -> String {
     if let Some(allow_local_binding) = allow_local_binding {
         parts.push(format!("allow_local_binding={allow_local_binding}"));
     }
+    if let Some(host_rules) = host_rules {
+        let rules: Vec<String> = host_rules
+            .iter()
+            .map(|rule| match rule.action {
+                HostRuleAction::Allow => format!("allow {}", rule.host),
+                HostRuleAction::Deny => format!("deny {}", rule.host),
+            })
+            .collect();
+        parts.push(format!("host_rules=[{}]", rules.join(", ")));
+    }
 
     join_or_empty(parts)
 }
@@ -1687,8 +1687,27 @@ pub(crate) fn new_mcp_tools_output(
                 http_headers,
                 env_http_headers,
                 ..
+            }
+            | McpServerTransportConfig::Sse {
+                url,
+                http_headers,
+                env_http_headers,
+                ..
+            }
+            | McpServerTransportConfig::WebSocket {
+                url,
+                http_headers,
+                env_http_headers,
+                ..
             } => {
                 lines.push(vec!["    • URL: ".into(), url.clone().into()].into());
+                if !matches!(
+                    cfg.transport,
+                    McpServerTransportConfig::StreamableHttp { .. }
+                ) {
+                    let transport = cfg.transport.transport_name();
+                    lines.push(vec!["    • Transport: ".into(), transport.into()].into());
+                }
                 if let Some(headers) = http_headers.as_ref()
                     && !headers.is_empty()
                 {
@@ -2546,6 +2565,8 @@ mod tests {
             enabled_tools: None,
             disabled_tools: None,
             scopes: None,
+            sampling: None,
+            approval: None,
         };
         let mut servers = config.mcp_servers.get().clone();
         servers.insert("docs".to_string(), stdio_config);
@@ -2569,6 +2590,8 @@ mod tests {
             enabled_tools: None,
             disabled_tools: None,
             scopes: None,
+            sampling: None,
+            approval: None,
         };
         servers.insert("http".to_string(), http_config);
         config
@@ -16,3 +16,11 @@ pub(crate) fn tr_list(language: Language, key: &'static str) -> &'static [String
 pub(crate) fn language_name(ui_language: Language, target: Language) -> &'static str {
     i18n::language_name(ui_language, target)
 }
+
+pub(crate) fn builtin_language(language: Language) -> Language {
+    i18n::builtin_language(language)
+}
+
+pub(crate) fn available_languages() -> Vec<Language> {
+    i18n::available_languages()
+}
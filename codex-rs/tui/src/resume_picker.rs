@@ -1,5 +1,6 @@
 use std::collections::HashMap;
 use std::collections::HashSet;
+use std::ops::Range;
 use std::path::Path;
 use std::path::PathBuf;
 use std::sync::Arc;
@@ -16,6 +17,7 @@ use codex_core::Cursor;
 use codex_core::INTERACTIVE_SESSION_SOURCES;
 use codex_core::RolloutRecorder;
 use codex_core::ThreadItem;
+use codex_core::ThreadSearchMatch;
 use codex_core::ThreadSortKey;
 use codex_core::ThreadsPage;
 use codex_core::config::Config;
@@ -39,6 +41,8 @@ use unicode_width::UnicodeWidthStr;
 
 const PAGE_SIZE: usize = 25;
 const LOAD_NEAR_THRESHOLD: usize = 5;
+/// Maximum number of sessions returned by a full-text search.
+const SEARCH_RESULT_LIMIT: usize = 100;
 #[derive(Debug, Clone)]
 pub enum SessionSelection {
     StartFresh,
@@ -93,6 +97,10 @@ enum BackgroundEvent {
         search_token: Option<usize>,
         page: std::io::Result<ThreadsPage>,
     },
+    SearchLoaded {
+        request_token: usize,
+        matches: std::io::Result<Vec<ThreadSearchMatch>>,
+    },
 }
 
 /// Interactive session picker that lists recorded rollout files with simple
@@ -111,12 +119,24 @@ enum BackgroundEvent {
 /// 1. Provider and source filtering at the backend (only interactive CLI sessions
 ///    for the current model provider).
 /// 2. Working-directory filtering at the picker (unless `--all` is passed).
+///
+/// With a `full_text_query` (`codex resume --query`), the picker lists the
+/// full-text search results of the state DB instead, best match first, and
+/// shows the matching snippet of each session.
 pub async fn run_resume_picker(
     tui: &mut Tui,
     config: &Config,
     show_all: bool,
+    full_text_query: Option<String>,
 ) -> Result<SessionSelection> {
-    run_session_picker(tui, config, show_all, SessionPickerAction::Resume).await
+    run_session_picker(
+        tui,
+        config,
+        show_all,
+        full_text_query,
+        SessionPickerAction::Resume,
+    )
+    .await
 }
 
 pub async fn run_fork_picker(
@@ -124,13 +144,14 @@ pub async fn run_fork_picker(
     config: &Config,
     show_all: bool,
 ) -> Result<SessionSelection> {
-    run_session_picker(tui, config, show_all, SessionPickerAction::Fork).await
+    run_session_picker(tui, config, show_all, None, SessionPickerAction::Fork).await
 }
 
 async fn run_session_picker(
     tui: &mut Tui,
     config: &Config,
     show_all: bool,
+    full_text_query: Option<String>,
     action: SessionPickerAction,
 ) -> Result<SessionSelection> {
     let alt = AltScreenGuard::enter(tui);
@@ -146,10 +167,26 @@ async fn run_session_picker(
 
     let config = config.clone();
     let loader_tx = bg_tx.clone();
+    let loader_query = full_text_query.clone();
     let page_loader: PageLoader = Arc::new(move |request: PageLoadRequest| {
         let tx = loader_tx.clone();
         let config = config.clone();
+        let full_text_query = loader_query.clone();
         tokio::spawn(async move {
+            if let Some(query) = full_text_query {
+                let matches = RolloutRecorder::search_threads(
+                    &config,
+                    query.as_str(),
+                    SEARCH_RESULT_LIMIT,
+                    false,
+                )
+                .await;
+                let _ = tx.send(BackgroundEvent::SearchLoaded {
+                    request_token: request.request_token,
+                    matches,
+                });
+                return;
+            }
             let provider_filter = vec![request.default_provider.clone()];
             let page = RolloutRecorder::list_threads(
                 &config,
@@ -178,6 +215,7 @@ async fn run_session_picker(
         filter_cwd,
         action,
     );
+    state.full_text_query = full_text_query;
     state.start_initial_load();
     state.request_frame();
 
@@ -265,6 +303,8 @@ struct PickerState {
     action: SessionPickerAction,
     sort_key: ThreadSortKey,
     thread_name_cache: HashMap<ThreadId, Option<String>>,
+    /// Full-text query whose search results replace the paged session list.
+    full_text_query: Option<String>,
 }
 
 struct PaginationState {
@@ -326,6 +366,15 @@ struct Row {
     updated_at: Option<DateTime<Utc>>,
     cwd: Option<PathBuf>,
     git_branch: Option<String>,
+    search_snippet: Option<SearchSnippet>,
+}
+
+/// Matching excerpt of a full-text search result.
+#[derive(Clone)]
+struct SearchSnippet {
+    text: String,
+    /// Byte ranges of `text` that matched the query.
+    highlights: Vec<Range<usize>>,
 }
 
 impl Row {
@@ -382,6 +431,7 @@ impl PickerState {
             action,
             sort_key: ThreadSortKey::CreatedAt,
             thread_name_cache: HashMap::new(),
+            full_text_query: None,
         }
     }
 
@@ -516,6 +566,22 @@ impl PickerState {
                 let completed_token = pending.search_token.or(search_token);
                 self.continue_search_if_token_matches(completed_token);
             }
+            BackgroundEvent::SearchLoaded {
+                request_token,
+                matches,
+            } => {
+                let pending = match self.pagination.loading {
+                    LoadingState::Pending(pending) => pending,
+                    LoadingState::Idle => return Ok(()),
+                };
+                if pending.request_token != request_token {
+                    return Ok(());
+                }
+                self.pagination.loading = LoadingState::Idle;
+                let matches = matches.map_err(color_eyre::Report::from)?;
+                self.ingest_search_matches(matches);
+                self.update_thread_names().await;
+            }
         }
         Ok(())
     }
@@ -551,6 +617,23 @@ impl PickerState {
         self.apply_filter();
     }
 
+    /// Search results come as a single ranked page; nothing is loaded after it.
+    fn ingest_search_matches(&mut self, matches: Vec<ThreadSearchMatch>) {
+        self.pagination.next_cursor = None;
+        self.pagination.num_scanned_files = matches.len();
+        for search_match in matches {
+            let mut row = head_to_row(&search_match.item);
+            row.search_snippet = Some(SearchSnippet {
+                text: search_match.snippet,
+                highlights: search_match.highlights,
+            });
+            if self.seen_paths.insert(row.path.clone()) {
+                self.all_rows.push(row);
+            }
+        }
+        self.apply_filter();
+    }
+
     async fn update_thread_names(&mut self) {
         let mut missing_ids = HashSet::new();
         for row in &self.all_rows {
@@ -820,6 +903,7 @@ fn head_to_row(item: &ThreadItem) -> Row {
         updated_at,
         cwd: item.cwd.clone(),
         git_branch: item.git_branch.clone(),
+        search_snippet: None,
     }
 }
 
@@ -854,7 +938,7 @@ fn draw_picker(tui: &mut Tui, state: &PickerState) -> std::io::Result<()> {
         .areas(area);
 
         // Header
-        let header_line: Line = vec![
+        let mut header_line: Line = vec![
             state.action.title().bold().cyan(),
             "  ".into(),
             "Sort:".dim(),
@@ -862,6 +946,12 @@ fn draw_picker(tui: &mut Tui, state: &PickerState) -> std::io::Result<()> {
             sort_key_label(state.sort_key).magenta(),
         ]
         .into();
+        if let Some(query) = state.full_text_query.as_deref() {
+            header_line.push_span("  ");
+            header_line.push_span("Matching:".dim());
+            header_line.push_span(" ");
+            header_line.push_span(Span::from(query.to_string()).cyan());
+        }
         frame.render_widget_ref(header_line, header);
 
         // Search line
@@ -1000,7 +1090,10 @@ fn render_list(
         if add_leading_gap {
             preview_width = preview_width.saturating_sub(2);
         }
-        let preview = truncate_text(row.display_preview(), preview_width);
+        let preview_spans = match row.search_snippet.as_ref() {
+            Some(snippet) => snippet_spans(snippet, preview_width),
+            None => vec![truncate_text(row.display_preview(), preview_width).into()],
+        };
         let mut spans: Vec<Span> = vec![marker];
         if let Some(created) = created_span {
             spans.push(created);
@@ -1021,7 +1114,7 @@ fn render_list(
         if add_leading_gap {
             spans.push("  ".into());
         }
-        spans.push(preview.into());
+        spans.extend(preview_spans);
 
         let line: Line = spans.into();
         let rect = Rect::new(area.x, y, area.width, 1);
@@ -1036,7 +1129,44 @@ fn render_list(
     }
 }
 
+/// Renders a search snippet truncated to `width`, with the matched ranges
+/// highlighted.
+fn snippet_spans(snippet: &SearchSnippet, width: usize) -> Vec<Span<'static>> {
+    let text = truncate_text(&snippet.text, width);
+    // Truncation keeps a prefix of the snippet, so ranges past it are dropped.
+    let visible_len = text.strip_suffix("...").map_or(text.len(), str::len);
+    let mut spans = Vec::new();
+    let mut cursor = 0;
+    for range in &snippet.highlights {
+        if range.end > visible_len || range.start < cursor {
+            continue;
+        }
+        if range.start > cursor {
+            spans.push(Span::from(text[cursor..range.start].to_string()));
+        }
+        spans.push(Span::from(text[range.clone()].to_string()).bold().yellow());
+        cursor = range.end;
+    }
+    if cursor < text.len() {
+        spans.push(Span::from(text[cursor..].to_string()));
+    }
+    spans
+}
+
 fn render_empty_state_line(state: &PickerState) -> Line<'static> {
+    if let Some(query) = state.full_text_query.as_deref()
+        && state.query.is_empty()
+    {
+        if state.pagination.loading.is_pending() {
+            return vec!["Searching…".italic().dim()].into();
+        }
+        return vec![
+            Span::from(format!("No sessions match \"{query}\""))
+                .italic()
+                .dim(),
+        ]
+        .into();
+    }
     if !state.query.is_empty() {
         if state.search_state.is_active()
             || (state.pagination.loading.is_pending() && state.pagination.next_cursor.is_some())
@@ -1556,6 +1686,7 @@ mod tests {
             updated_at: None,
             cwd: None,
             git_branch: None,
+            search_snippet: None,
         };
 
         assert_eq!(row.display_preview(), "My session");
@@ -1590,6 +1721,7 @@ mod tests {
                 updated_at: Some(now - Duration::seconds(42)),
                 cwd: None,
                 git_branch: None,
+                search_snippet: None,
             },
             Row {
                 path: PathBuf::from("/tmp/b.jsonl"),
@@ -1600,6 +1732,7 @@ mod tests {
                 updated_at: Some(now - Duration::minutes(35)),
                 cwd: None,
                 git_branch: None,
+                search_snippet: None,
             },
             Row {
                 path: PathBuf::from("/tmp/c.jsonl"),
@@ -1610,6 +1743,7 @@ mod tests {
                 updated_at: Some(now - Duration::hours(2)),
                 cwd: None,
                 git_branch: None,
+                search_snippet: None,
             },
         ];
         state.all_rows = rows.clone();
@@ -1868,6 +2002,7 @@ mod tests {
                 updated_at: Some(now - Duration::days(2)),
                 cwd: None,
                 git_branch: None,
+                search_snippet: None,
             },
             Row {
                 path: PathBuf::from("/tmp/b.jsonl"),
@@ -1878,6 +2013,7 @@ mod tests {
                 updated_at: Some(now - Duration::days(3)),
                 cwd: None,
                 git_branch: None,
+                search_snippet: None,
             },
         ];
         state.all_rows = rows.clone();
@@ -117,6 +117,9 @@ impl SlashCommand {
             SlashCommand::Review
                 | SlashCommand::Rename
                 | SlashCommand::Plan
+                | SlashCommand::Checkpoint
+                | SlashCommand::Resume
+                | SlashCommand::Fork
                 | SlashCommand::SddDevelop
                 | SlashCommand::SddDevelopParallels
         )
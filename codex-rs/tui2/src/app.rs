@@ -7,6 +7,7 @@ use crate::app_event::WindowsSandboxEnableMode;
 use crate::app_event::WindowsSandboxFallbackReason;
 use crate::app_event_sender::AppEventSender;
 use crate::bottom_pane::ApprovalRequest;
+use crate::bottom_pane::network_request_lines;
 use crate::chatwidget::ChatWidget;
 use crate::custom_terminal::Frame;
 use crate::diff_render::DiffSummary;
@@ -2320,6 +2321,20 @@ impl App {
                         self.config.language,
                     ));
                 }
+                ApprovalRequest::Network {
+                    host,
+                    port,
+                    protocol,
+                    method,
+                    ..
+                } => {
+                    let _ = tui.enter_alt_screen();
+                    self.overlay = Some(Overlay::new_static_with_lines(
+                        network_request_lines(&host, port, &protocol, method.as_deref()),
+                        tr(self.config.language, "app.overlay.network_title").to_string(),
+                        self.config.language,
+                    ));
+                }
             },
         }
         Ok(AppRunControl::Continue)
@@ -20,6 +20,7 @@ use codex_core::features::Features;
 use codex_core::protocol::ElicitationAction;
 use codex_core::protocol::ExecPolicyAmendment;
 use codex_core::protocol::FileChange;
+use codex_core::protocol::NetworkApprovalDecision;
 use codex_core::protocol::Op;
 use codex_core::protocol::ReviewDecision;
 use codex_protocol::config_types::Language;
@@ -56,6 +57,13 @@ pub(crate) enum ApprovalRequest {
         request_id: RequestId,
         message: String,
     },
+    Network {
+        id: String,
+        host: String,
+        port: u16,
+        protocol: String,
+        method: Option<String>,
+    },
 }
 
 /// Modal overlay asking the user to approve or deny one or more requests.
@@ -130,6 +138,10 @@ impl ApprovalOverlay {
                 elicitation_options(),
                 format!("{server_name} needs your approval."),
             ),
+            ApprovalVariant::Network { host, .. } => (
+                network_options(),
+                format!("Allow network access to {host}?"),
+            ),
         };
 
         let header = Box::new(ColumnRenderable::with([
@@ -190,6 +202,9 @@ impl ApprovalOverlay {
                 ) => {
                     self.handle_elicitation_decision(server_name, request_id, *decision);
                 }
+                (ApprovalVariant::Network { id, .. }, ApprovalDecision::Network(decision)) => {
+                    self.handle_network_decision(id, *decision);
+                }
                 _ => {}
             }
         }
@@ -233,6 +248,14 @@ impl ApprovalOverlay {
             }));
     }
 
+    fn handle_network_decision(&self, id: &str, decision: NetworkApprovalDecision) {
+        self.app_event_tx
+            .send(AppEvent::CodexOp(Op::NetworkApproval {
+                id: id.to_string(),
+                decision,
+            }));
+    }
+
     fn advance_queue(&mut self) {
         if let Some(next) = self.queue.pop() {
             self.set_current(next);
@@ -308,6 +331,9 @@ impl BottomPaneView for ApprovalOverlay {
                         ElicitationAction::Cancel,
                     );
                 }
+                ApprovalVariant::Network { id, .. } => {
+                    self.handle_network_decision(id, NetworkApprovalDecision::Deny);
+                }
             }
         }
         self.queue.clear();
@@ -417,6 +443,25 @@ impl From<ApprovalRequest> for ApprovalRequestState {
                     header: Box::new(header),
                 }
             }
+            ApprovalRequest::Network {
+                id,
+                host,
+                port,
+                protocol,
+                method,
+            } => {
+                let header = Paragraph::new(network_request_lines(
+                    &host,
+                    port,
+                    &protocol,
+                    method.as_deref(),
+                ))
+                .wrap(Wrap { trim: false });
+                Self {
+                    variant: ApprovalVariant::Network { id, host },
+                    header: Box::new(header),
+                }
+            }
         }
     }
 }
@@ -435,12 +480,17 @@ enum ApprovalVariant {
         server_name: String,
         request_id: RequestId,
     },
+    Network {
+        id: String,
+        host: String,
+    },
 }
 
 #[derive(Clone)]
 enum ApprovalDecision {
     Review(ReviewDecision),
     McpElicitation(ElicitationAction),
+    Network(NetworkApprovalDecision),
 }
 
 #[derive(Clone)]
@@ -539,6 +589,55 @@ fn elicitation_options() -> Vec<ApprovalOption> {
     ]
 }
 
+fn network_options() -> Vec<ApprovalOption> {
+    vec![
+        ApprovalOption {
+            label: "Yes, allow this request".to_string(),
+            decision: ApprovalDecision::Network(NetworkApprovalDecision::AllowOnce),
+            display_shortcut: None,
+            additional_shortcuts: vec![key_hint::plain(KeyCode::Char('y'))],
+        },
+        ApprovalOption {
+            label: "Yes, and allow this domain for the rest of the session".to_string(),
+            decision: ApprovalDecision::Network(NetworkApprovalDecision::AllowForSession),
+            display_shortcut: None,
+            additional_shortcuts: vec![key_hint::plain(KeyCode::Char('a'))],
+        },
+        ApprovalOption {
+            label: "Yes, and add this domain to allowed_domains in config.toml".to_string(),
+            decision: ApprovalDecision::Network(NetworkApprovalDecision::AllowAndPersist),
+            display_shortcut: None,
+            additional_shortcuts: vec![key_hint::plain(KeyCode::Char('p'))],
+        },
+        ApprovalOption {
+            label: "No, block this request".to_string(),
+            decision: ApprovalDecision::Network(NetworkApprovalDecision::Deny),
+            display_shortcut: Some(key_hint::plain(KeyCode::Esc)),
+            additional_shortcuts: vec![key_hint::plain(KeyCode::Char('n'))],
+        },
+    ]
+}
+
+/// Describes the destination of a network approval request.
+pub(crate) fn network_request_lines(
+    host: &str,
+    port: u16,
+    protocol: &str,
+    method: Option<&str>,
+) -> Vec<Line<'static>> {
+    let mut lines = vec![
+        Line::from(vec!["Host: ".into(), format!("{host}:{port}").bold()]),
+        Line::from(vec!["Protocol: ".into(), protocol.to_string().into()]),
+    ];
+    if let Some(method) = method {
+        lines.push(Line::from(vec![
+            "Method: ".into(),
+            method.to_string().into(),
+        ]));
+    }
+    lines
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -657,6 +756,36 @@ mod tests {
         assert!(rx.try_recv().is_ok());
     }
 
+    #[test]
+    fn network_session_option_emits_allow_for_session() {
+        let (tx, mut rx) = unbounded_channel::<AppEvent>();
+        let tx = AppEventSender::new(tx);
+        let mut view = ApprovalOverlay::new(
+            ApprovalRequest::Network {
+                id: "net-1".to_string(),
+                host: "pypi.org".to_string(),
+                port: 443,
+                protocol: "https_connect".to_string(),
+                method: Some("CONNECT".to_string()),
+            },
+            tx,
+            Features::with_defaults(),
+            Language::En,
+        );
+        view.handle_key_event(KeyEvent::new(KeyCode::Char('a'), KeyModifiers::NONE));
+
+        let mut decision = None;
+        while let Ok(ev) = rx.try_recv() {
+            if let AppEvent::CodexOp(Op::NetworkApproval { id, decision: d }) = ev {
+                assert_eq!(id, "net-1");
+                decision = Some(d);
+                break;
+            }
+        }
+        assert_eq!(decision, Some(NetworkApprovalDecision::AllowForSession));
+        assert!(view.is_complete());
+    }
+
     #[test]
     fn header_includes_command_snippet() {
         let (tx, _rx) = unbounded_channel::<AppEvent>();
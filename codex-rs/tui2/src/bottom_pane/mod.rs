@@ -37,6 +37,7 @@ use std::time::Duration;
 mod approval_overlay;
 pub(crate) use approval_overlay::ApprovalOverlay;
 pub(crate) use approval_overlay::ApprovalRequest;
+pub(crate) use approval_overlay::network_request_lines;
 mod bottom_pane_view;
 mod chat_composer;
 mod chat_composer_history;
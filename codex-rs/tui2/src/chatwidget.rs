@@ -79,6 +79,7 @@ use codex_core::protocol::RateLimitSnapshot;
 use codex_core::protocol::RequestUserInputEvent;
 use codex_core::protocol::ReviewRequest;
 use codex_core::protocol::ReviewTarget;
+use codex_core::protocol::SamplingDecision;
 use codex_core::protocol::SddGitAction;
 use codex_core::protocol::SkillsListEntry;
 use codex_core::protocol::StreamErrorEvent;
@@ -102,6 +103,7 @@ use codex_core::skills::model::SkillToolDependency;
 use codex_protocol::ThreadId;
 use codex_protocol::account::PlanType;
 use codex_protocol::approvals::ElicitationRequestEvent;
+use codex_protocol::approvals::NetworkApprovalRequestEvent;
 use codex_protocol::config_types::SandboxMode;
 use codex_protocol::parse_command::ParsedCommand;
 use codex_protocol::user_input::UserInput;
@@ -155,6 +157,7 @@ use crate::history_cell::AgentMessageCell;
 use crate::history_cell::HistoryCell;
 use crate::history_cell::McpToolCallCell;
 use crate::history_cell::PlainHistoryCell;
+use crate::i18n::available_languages;
 use crate::i18n::language_name;
 use crate::i18n::tr;
 use crate::i18n::tr_args;
@@ -1239,6 +1242,14 @@ impl ChatWidget {
         );
     }
 
+    fn on_network_approval_request(&mut self, ev: NetworkApprovalRequestEvent) {
+        let ev2 = ev.clone();
+        self.defer_or_handle(
+            |q| q.push_network_approval(ev),
+            |s| s.handle_network_approval_now(ev2),
+        );
+    }
+
     fn on_request_user_input(&mut self, ev: RequestUserInputEvent) {
         let ev2 = ev.clone();
         self.defer_or_handle(
@@ -1762,6 +1773,25 @@ impl ChatWidget {
         self.request_redraw();
     }
 
+    pub(crate) fn handle_network_approval_now(&mut self, ev: NetworkApprovalRequestEvent) {
+        self.flush_answer_stream_with_separator();
+
+        self.notify(Notification::NetworkApprovalRequested {
+            host: ev.host.clone(),
+        });
+
+        let request = ApprovalRequest::Network {
+            id: ev.id,
+            host: ev.host,
+            port: ev.port,
+            protocol: ev.protocol,
+            method: ev.method,
+        };
+        self.bottom_pane
+            .push_approval_request(request, &self.config.features);
+        self.request_redraw();
+    }
+
     fn request_user_input_history_lines(
         &self,
         ev: &RequestUserInputEvent,
@@ -3456,6 +3486,18 @@ impl ChatWidget {
             EventMsg::ElicitationRequest(ev) => {
                 self.on_elicitation_request(ev);
             }
+            EventMsg::NetworkApprovalRequest(ev) => {
+                self.on_network_approval_request(ev);
+            }
+            EventMsg::SamplingRequest(ev) => {
+                // tui2 has no sampling approval prompt yet; decline so the
+                // server is not left waiting.
+                self.submit_op(Op::ResolveSampling {
+                    server_name: ev.server_name,
+                    request_id: ev.id,
+                    decision: SamplingDecision::Decline,
+                });
+            }
             EventMsg::RequestUserInput(ev) => {
                 self.on_request_user_input(ev);
             }
@@ -3474,7 +3516,10 @@ impl ChatWidget {
             EventMsg::McpListToolsResponse(ev) => self.on_list_mcp_tools(ev),
             EventMsg::ListCustomPromptsResponse(ev) => self.on_list_custom_prompts(ev),
             EventMsg::ListSkillsResponse(ev) => self.on_list_skills(ev),
-            EventMsg::ListRemoteSkillsResponse(_) | EventMsg::RemoteSkillDownloaded(_) => {}
+            EventMsg::ListRemoteSkillsResponse(_)
+            | EventMsg::RemoteSkillDownloaded(_)
+            | EventMsg::ListMcpPromptsResponse(_)
+            | EventMsg::GetMcpPromptResponse(_) => {}
             EventMsg::SkillsUpdateAvailable => {
                 self.submit_op(Op::ListSkills {
                     cwds: Vec::new(),
@@ -3517,11 +3562,13 @@ impl ChatWidget {
             EventMsg::CollabResumeBegin(_) | EventMsg::CollabResumeEnd(_) => {}
             EventMsg::RawResponseItem(_)
             | EventMsg::ThreadRolledBack(_)
+            | EventMsg::CheckpointCreated(_)
             | EventMsg::ItemStarted(_)
             | EventMsg::ItemCompleted(_)
             | EventMsg::AgentMessageContentDelta(_)
             | EventMsg::ReasoningContentDelta(_)
             | EventMsg::ReasoningRawContentDelta(_)
+            | EventMsg::McpToolCallApproval(_)
             | EventMsg::DynamicToolCallRequest(_) => {}
         }
     }
@@ -3868,28 +3915,28 @@ impl ChatWidget {
     /// opens the full picker with every available preset.
     pub(crate) fn open_language_popup(&mut self) {
         let ui_language = self.config.language;
-        let items = vec![
-            SelectionItem {
-                name: language_name(ui_language, Language::En).to_string(),
-                description: Some(
-                    tr(ui_language, "chatwidget.language_popup.english_desc").to_string(),
-                ),
-                is_current: ui_language == Language::En,
-                actions: Self::language_selection_actions(Language::En),
-                dismiss_on_select: true,
-                ..Default::default()
-            },
-            SelectionItem {
-                name: language_name(ui_language, Language::ZhCn).to_string(),
-                description: Some(
-                    tr(ui_language, "chatwidget.language_popup.chinese_desc").to_string(),
-                ),
-                is_current: ui_language == Language::ZhCn,
-                actions: Self::language_selection_actions(Language::ZhCn),
-                dismiss_on_select: true,
-                ..Default::default()
-            },
-        ];
+        let items = available_languages()
+            .into_iter()
+            .map(|language| {
+                let description = match language {
+                    Language::En => {
+                        Some(tr(ui_language, "chatwidget.language_popup.english_desc").to_string())
+                    }
+                    Language::ZhCn => {
+                        Some(tr(ui_language, "chatwidget.language_popup.chinese_desc").to_string())
+                    }
+                    Language::Locale(tag) => Some(tag.as_str().to_string()),
+                };
+                SelectionItem {
+                    name: language_name(ui_language, language).to_string(),
+                    description,
+                    is_current: ui_language == language,
+                    actions: Self::language_selection_actions(language),
+                    dismiss_on_select: true,
+                    ..Default::default()
+                }
+            })
+            .collect();
 
         self.bottom_pane.show_selection_view(SelectionViewParams {
             title: Some(tr(ui_language, "chatwidget.language_popup.title").to_string()),
@@ -5698,6 +5745,7 @@ enum Notification {
     ExecApprovalRequested { command: String },
     EditApprovalRequested { cwd: PathBuf, changes: Vec<PathBuf> },
     ElicitationRequested { server_name: String },
+    NetworkApprovalRequested { host: String },
 }
 
 impl Notification {
@@ -5735,6 +5783,11 @@ impl Notification {
                 "chatwidget.notification.elicitation",
                 &[("server_name", server_name)],
             ),
+            Notification::NetworkApprovalRequested { host } => tr_args(
+                language,
+                "chatwidget.notification.network_approval",
+                &[("host", &truncate_text(host, 30))],
+            ),
         }
     }
 
@@ -5743,7 +5796,8 @@ impl Notification {
             Notification::AgentTurnComplete { .. } => "agent-turn-complete",
             Notification::ExecApprovalRequested { .. }
             | Notification::EditApprovalRequested { .. }
-            | Notification::ElicitationRequested { .. } => "approval-requested",
+            | Notification::ElicitationRequested { .. }
+            | Notification::NetworkApprovalRequested { .. } => "approval-requested",
         }
     }
 
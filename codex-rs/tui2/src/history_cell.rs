@@ -19,6 +19,7 @@ use crate::exec_cell::output_lines;
 use crate::exec_cell::spinner;
 use crate::exec_command::relativize_to_home;
 use crate::exec_command::strip_bash_lc_and_escape;
+use crate::i18n::builtin_language;
 use crate::i18n::tr;
 use crate::i18n::tr_args;
 use crate::markdown::append_markdown;
@@ -1084,7 +1085,7 @@ impl HistoryCell for SessionHeaderHistoryCell {
         let change_hint = tr(self.language, "history.session_header.change_model_hint");
         let model_label = tr(self.language, "history.session_header.model_label");
         let directory_label = tr(self.language, "history.session_header.directory_label");
-        let (model_spans, dir_spans) = match self.language {
+        let (model_spans, dir_spans) = match builtin_language(self.language) {
             Language::ZhCn => {
                 let mut model_spans: Vec<Span<'static>> = vec![
                     Span::from(model_label).dim(),
@@ -1106,7 +1107,7 @@ impl HistoryCell for SessionHeaderHistoryCell {
                 let dir_spans = vec![Span::from(dir_prefix).dim(), " ".into(), Span::from(dir)];
                 (model_spans, dir_spans)
             }
-            Language::En => {
+            Language::En | Language::Locale(_) => {
                 let label_width = UnicodeWidthStr::width(directory_label);
                 let model_label = format!("{model_label:<label_width$}");
                 let mut model_spans: Vec<Span<'static>> = vec![
@@ -1601,6 +1602,18 @@ pub(crate) fn new_mcp_tools_output(
                 http_headers,
                 env_http_headers,
                 ..
+            }
+            | McpServerTransportConfig::Sse {
+                url,
+                http_headers,
+                env_http_headers,
+                ..
+            }
+            | McpServerTransportConfig::WebSocket {
+                url,
+                http_headers,
+                env_http_headers,
+                ..
             } => {
                 lines.push(
                     vec![
@@ -2146,6 +2159,8 @@ mod tests {
             enabled_tools: None,
             disabled_tools: None,
             scopes: None,
+            sampling: None,
+            approval: None,
         };
         let mut servers = config.mcp_servers.get().clone();
         servers.insert("docs".to_string(), stdio_config);
@@ -2169,6 +2184,8 @@ mod tests {
             enabled_tools: None,
             disabled_tools: None,
             scopes: None,
+            sampling: None,
+            approval: None,
         };
         servers.insert("http".to_string(), http_config);
         config
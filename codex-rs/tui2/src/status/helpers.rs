@@ -1,4 +1,5 @@
 use crate::exec_command::relativize_to_home;
+use crate::i18n::builtin_language;
 use crate::i18n::tr;
 use crate::i18n::tr_args;
 use crate::text_formatting;
@@ -190,9 +191,9 @@ pub(crate) fn format_reset_timestamp(
     if dt.date_naive() == captured_at.date_naive() {
         time
     } else {
-        match language {
+        match builtin_language(language) {
             Language::ZhCn => format!("{time} · {}", dt.format("%-d %b")),
-            Language::En => format!("{time} on {}", dt.format("%-d %b")),
+            Language::En | Language::Locale(_) => format!("{time} on {}", dt.format("%-d %b")),
         }
     }
 }
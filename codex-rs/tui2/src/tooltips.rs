@@ -41,16 +41,19 @@ pub(crate) fn random_tooltip(language: Language) -> Option<String> {
 }
 
 fn pick_tooltip<R: Rng + ?Sized>(rng: &mut R, language: Language) -> Option<&'static str> {
-    let tooltips = match language {
-        Language::ZhCn => &*ALL_TOOLTIPS_ZH,
-        Language::En => &*ALL_TOOLTIPS_EN,
+    let tooltips: Vec<&'static str> = match language {
+        Language::ZhCn => ALL_TOOLTIPS_ZH.iter().map(String::as_str).collect(),
+        Language::En => ALL_TOOLTIPS_EN.iter().map(String::as_str).collect(),
+        Language::Locale(_) => tr_list(language, "tooltips.items")
+            .iter()
+            .map(String::as_str)
+            .chain(beta_tooltips())
+            .collect(),
     };
     if tooltips.is_empty() {
         None
     } else {
-        tooltips
-            .get(rng.random_range(0..tooltips.len()))
-            .map(String::as_str)
+        tooltips.get(rng.random_range(0..tooltips.len())).copied()
     }
 }
 